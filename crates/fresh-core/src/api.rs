@@ -1380,6 +1380,24 @@ pub enum PluginCommand {
         priority: i32,
     },
 
+    /// Set a styled sign in the gutter's indicator column. Like
+    /// `SetLineIndicator`, but the color may be a theme key (resolved at
+    /// render time) and the symbol can be bold.
+    SetLineSign {
+        buffer_id: BufferId,
+        /// Line number (0-indexed)
+        line: usize,
+        /// Namespace for grouping (e.g., "bookmarks", "test-status")
+        namespace: String,
+        /// Symbol to display (e.g., "●", "★", "✓")
+        symbol: String,
+        /// Foreground color; `None` uses the line number color
+        fg: Option<OverlayColorSpec>,
+        bold: bool,
+        /// Priority for display when multiple indicators exist (higher wins)
+        priority: i32,
+    },
+
     /// Batch set line indicators in the gutter's indicator column
     /// Optimized for setting many lines with the same namespace/symbol/color/priority
     SetLineIndicators {
@@ -2218,6 +2236,24 @@ pub enum PluginCommand {
     /// `SetRemoteIndicatorState`.
     ClearRemoteIndicatorState,
 
+    /// Show or update a plugin-owned status bar segment.
+    ///
+    /// Sent by the plugin runtime (not called directly by plugins) each
+    /// time a segment registered via `registerStatusBarSegment` renders
+    /// text different from what it last sent. `align` is `"left"` or
+    /// `"right"`; within a side, higher `priority` segments sit closer
+    /// to the built-in elements and are the last to be dropped when the
+    /// terminal is too narrow. An empty `text` hides the segment.
+    SetStatusBarSegment {
+        id: String,
+        align: String,
+        priority: i32,
+        text: String,
+    },
+
+    /// Remove a plugin status bar segment. No-op when the id is unknown.
+    RemoveStatusBarSegment { id: String },
+
     /// Spawn a process on the host, regardless of the currently
     /// installed authority.
     ///
@@ -2553,6 +2589,29 @@ pub struct JsDiagnostic {
     /// Source of the diagnostic (e.g., "typescript", "eslint")
     #[ts(optional)]
     pub source: Option<String>,
+    /// Diagnostic code (e.g., "E0308"), numeric codes as strings
+    #[ts(optional)]
+    pub code: Option<String>,
+    /// URL of the code's documentation (`codeDescription.href`)
+    #[serde(rename = "codeHref")]
+    #[ts(optional, rename = "codeHref")]
+    pub code_href: Option<String>,
+    /// Other locations relevant to the diagnostic (e.g., "first defined here")
+    #[serde(rename = "relatedInformation")]
+    #[ts(optional, rename = "relatedInformation")]
+    pub related_information: Option<Vec<JsDiagnosticRelatedInformation>>,
+}
+
+/// A location related to a diagnostic, from its `relatedInformation`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct JsDiagnosticRelatedInformation {
+    /// Document URI
+    pub uri: String,
+    /// Range in the document
+    pub range: JsRange,
+    /// Message explaining the relation
+    pub message: String,
 }
 
 /// Options for createVirtualBuffer
@@ -312,6 +312,15 @@ pub enum HookArgs {
         language: String,
     },
 
+    /// A breakpoint was set or cleared in a buffer
+    BreakpointsChanged {
+        buffer_id: BufferId,
+        /// The buffer's file, if it has one
+        path: Option<PathBuf>,
+        /// All breakpoint lines in the buffer (0-indexed, ascending)
+        lines: Vec<usize>,
+    },
+
     /// Request to inspect a theme key in the theme editor
     ThemeInspectKey {
         /// The name of the current theme
@@ -9,7 +9,13 @@
  * Key features:
  * - livePanel mode for reactive data updates
  * - Toggle between current file and all files (press 'a')
+ * - Severity filter (press 'e', 'w', 'i', 'h'), sort order (press 's') and
+ *   wrapping of long messages (press 'l'), initialized from
+ *   `editor.diagnostics_panel_severities`, `editor.diagnostics_panel_sort`
+ *   and `editor.diagnostics_panel_wrap`
  * - groupBy: "file" for organized display
+ * - relatedInformation listed as child entries under their diagnostic;
+ *   Enter on one jumps to the related location, possibly in another file
  * - syncWithEditor for bidirectional cursor sync
  */
 
@@ -26,12 +32,24 @@ interface DiagnosticItem {
   message: string;
   severity: number; // 1=error, 2=warning, 3=info, 4=hint
   source?: string;
+  code?: string;
+  // Set on related-information entries: the file of the diagnostic they
+  // belong to, which they are listed under
+  relatedTo?: string;
 }
 
+type SeverityName = "error" | "warning" | "info" | "hint";
+type SortOrder = "location" | "severity";
+
+const SEVERITY_NAMES: SeverityName[] = ["error", "warning", "info", "hint"];
+
 // State
 let showAllFiles = false;
 let sourceBufferId: number | null = null;
 let isOpen = false;
+let shownSeverities = new Set<SeverityName>(SEVERITY_NAMES);
+let sortOrder: SortOrder = "location";
+let wrapMessages = true;
 
 // Convert severity number to string
 function severityToString(severity: number): "error" | "warning" | "info" | "hint" {
@@ -49,6 +67,28 @@ function severityToString(severity: number): "error" | "warning" | "info" | "hin
   }
 }
 
+// Load the severity filter and sort order from config
+function loadPanelSettings(): void {
+  const config = editor.getConfig() as Record<string, unknown> | null;
+  const editorCfg = config?.editor as Record<string, unknown> | undefined;
+
+  const severities = editorCfg?.diagnostics_panel_severities;
+  if (Array.isArray(severities)) {
+    shownSeverities = new Set(
+      SEVERITY_NAMES.filter((name) => severities.includes(name))
+    );
+  }
+  sortOrder = editorCfg?.diagnostics_panel_sort === "severity" ? "severity" : "location";
+  wrapMessages = editorCfg?.diagnostics_panel_wrap !== false;
+}
+
+// Message as listed in the panel: all of it on one line when wrapping,
+// otherwise just its first line
+function panelMessage(message: string): string {
+  if (!wrapMessages) return message.split("\n")[0];
+  return message.split("\n").map((line) => line.trim()).join(" ");
+}
+
 // Convert file URI to file path using the editor's built-in URI handling
 function uriToPath(uri: string): string {
   if (!uri.startsWith("file://")) {
@@ -71,11 +111,14 @@ function getDiagnostics(): DiagnosticItem[] {
   }
 
   // Filter diagnostics by comparing decoded paths (avoids URI encoding mismatches)
-  const filtered = showAllFiles || !activePath
+  const inScope = showAllFiles || !activePath
     ? diagnostics
     : diagnostics.filter((d) => uriToPath(d.uri).replace(/\\/g, "/") === activePath);
+  const filtered = inScope.filter((d) =>
+    shownSeverities.has(severityToString(d.severity ?? 4))
+  );
 
-  // Sort by file, then line, then severity
+  // Sort by file, then line and severity in the chosen order
   filtered.sort((a, b) => {
     // File comparison
     if (a.uri !== b.uri) {
@@ -88,23 +131,50 @@ function getDiagnostics(): DiagnosticItem[] {
       }
       return a.uri < b.uri ? -1 : 1;
     }
-    // Line comparison
     const lineDiff = a.range.start.line - b.range.start.line;
-    if (lineDiff !== 0) return lineDiff;
-    // Severity comparison
-    return (a.severity ?? 4) - (b.severity ?? 4);
+    const severityDiff = (a.severity ?? 4) - (b.severity ?? 4);
+    if (sortOrder === "severity") {
+      return severityDiff !== 0 ? severityDiff : lineDiff;
+    }
+    return lineDiff !== 0 ? lineDiff : severityDiff;
   });
 
-  // Convert to DiagnosticItem
-  return filtered.map((diag) => ({
-    uri: diag.uri,
-    file: uriToPath(diag.uri),
-    line: diag.range.start.line + 1,
-    column: diag.range.start.character + 1,
-    message: diag.message.split("\n")[0], // First line only
-    severity: diag.severity ?? 4,
-    source: diag.source ?? undefined,
-  }));
+  // Convert to DiagnosticItem, each followed by its related information
+  return filtered.flatMap((diag) => {
+    const file = uriToPath(diag.uri);
+    const item: DiagnosticItem = {
+      uri: diag.uri,
+      file,
+      line: diag.range.start.line + 1,
+      column: diag.range.start.character + 1,
+      message: panelMessage(diag.message),
+      severity: diag.severity ?? 4,
+      source: diag.source ?? undefined,
+      code: diag.code ?? undefined,
+    };
+    const related = (diag.relatedInformation ?? []).map((info) => ({
+      uri: info.uri,
+      file: uriToPath(info.uri),
+      line: info.range.start.line + 1,
+      column: info.range.start.character + 1,
+      message: panelMessage(info.message),
+      severity: item.severity,
+      relatedTo: file,
+    }));
+    return [item, ...related];
+  });
+}
+
+// Panel label of a diagnostic, or of a related location: indented under
+// its diagnostic and naming the file, which may be another one
+function formatLabel(d: DiagnosticItem): string {
+  if (d.relatedTo !== undefined) {
+    const fileName = d.file.split("/").pop() ?? d.file;
+    return `  ↳ ${fileName}:${d.line}:${d.column} ${d.message}`;
+  }
+  return d.code
+    ? `${d.line}:${d.column} [${d.code}] ${d.message}`
+    : `${d.line}:${d.column} ${d.message}`;
 }
 
 // Create the live provider
@@ -114,16 +184,27 @@ const provider = createLiveProvider(getDiagnostics);
 const finder = new Finder<DiagnosticItem>(editor, {
   id: "diagnostics",
   format: (d) => ({
-    label: `${d.line}:${d.column} ${d.message}`,
+    label: formatLabel(d),
     location: {
       file: d.file,
       line: d.line,
       column: d.column,
     },
-    severity: severityToString(d.severity),
+    severity: d.relatedTo === undefined ? severityToString(d.severity) : undefined,
+    group: d.relatedTo,
     metadata: { uri: d.uri, message: d.message },
   }),
   groupBy: "file",
+  panelKeys: [
+    ["a", "diagnostics_toggle_all"],
+    ["e", "diagnostics_toggle_errors"],
+    ["w", "diagnostics_toggle_warnings"],
+    ["i", "diagnostics_toggle_info"],
+    ["h", "diagnostics_toggle_hints"],
+    ["s", "diagnostics_toggle_sort"],
+    ["l", "diagnostics_toggle_wrap"],
+  ],
+  panelHelp: editor.t("panel.help"),
   syncWithEditor: true,
   navigateOnCursorMove: true,
   // Diagnostics is a generic "list of locations" UX — route into
@@ -136,11 +217,28 @@ const finder = new Finder<DiagnosticItem>(editor, {
   },
 });
 
+// Label for the shown severities, e.g. "Errors, Warnings"
+function severityLabel(): string {
+  if (shownSeverities.size === SEVERITY_NAMES.length) {
+    return editor.t("panel.all_severities");
+  }
+  return SEVERITY_NAMES.filter((name) => shownSeverities.has(name))
+    .map((name) => editor.t(`panel.severity_${name}`))
+    .join(", ");
+}
+
+function sortLabel(): string {
+  return sortOrder === "severity"
+    ? editor.t("panel.sort_severity")
+    : editor.t("panel.sort_location");
+}
+
 // Get title based on current filter state
 function getTitle(): string {
-  const filterLabel = showAllFiles
+  const scopeLabel = showAllFiles
     ? editor.t("panel.all_files")
     : editor.t("panel.current_file");
+  const filterLabel = `${scopeLabel}; ${severityLabel()}; ${sortLabel()}`;
   return editor.t("panel.header", { filter: filterLabel });
 }
 
@@ -154,6 +252,8 @@ async function show_diagnostics_panel() : Promise<void> {
 
   // Capture source context
   sourceBufferId = editor.getActiveBufferId();
+  loadPanelSettings();
+  finder.setWrapLines(wrapMessages, getTitle());
 
   // Show the panel
   await finder.livePanel({
@@ -196,6 +296,76 @@ function diagnostics_toggle_all() : void {
 }
 registerHandler("diagnostics_toggle_all", diagnostics_toggle_all);
 
+// Show or hide one severity. The last shown severity can't be hidden.
+// The change is mirrored into the session config, so reopening the
+// panel keeps it.
+function toggleSeverity(name: SeverityName): void {
+  if (!isOpen) return;
+  if (shownSeverities.has(name)) {
+    if (shownSeverities.size === 1) return;
+    shownSeverities.delete(name);
+  } else {
+    shownSeverities.add(name);
+  }
+  editor.setSetting(
+    "editor.diagnostics_panel_severities",
+    SEVERITY_NAMES.filter((severity) => shownSeverities.has(severity))
+  );
+
+  finder.updateTitle(getTitle());
+  provider.notify();
+  editor.setStatus(editor.t("status.showing", { label: severityLabel() }));
+}
+
+function diagnostics_toggle_errors() : void {
+  toggleSeverity("error");
+}
+registerHandler("diagnostics_toggle_errors", diagnostics_toggle_errors);
+
+function diagnostics_toggle_warnings() : void {
+  toggleSeverity("warning");
+}
+registerHandler("diagnostics_toggle_warnings", diagnostics_toggle_warnings);
+
+function diagnostics_toggle_info() : void {
+  toggleSeverity("info");
+}
+registerHandler("diagnostics_toggle_info", diagnostics_toggle_info);
+
+function diagnostics_toggle_hints() : void {
+  toggleSeverity("hint");
+}
+registerHandler("diagnostics_toggle_hints", diagnostics_toggle_hints);
+
+function diagnostics_toggle_sort() : void {
+  if (!isOpen) return;
+
+  sortOrder = sortOrder === "location" ? "severity" : "location";
+  editor.setSetting("editor.diagnostics_panel_sort", sortOrder);
+
+  finder.updateTitle(getTitle());
+  provider.notify();
+  editor.setStatus(editor.t("status.showing", { label: sortLabel() }));
+}
+registerHandler("diagnostics_toggle_sort", diagnostics_toggle_sort);
+
+function wrapLabel(): string {
+  return wrapMessages ? editor.t("panel.wrap_on") : editor.t("panel.wrap_off");
+}
+
+// Soft-wrap long messages to the panel width, or cut them off
+function diagnostics_toggle_wrap() : void {
+  if (!isOpen) return;
+
+  wrapMessages = !wrapMessages;
+  editor.setSetting("editor.diagnostics_panel_wrap", wrapMessages);
+
+  finder.setWrapLines(wrapMessages, getTitle());
+  provider.notify();
+  editor.setStatus(editor.t("status.showing", { label: wrapLabel() }));
+}
+registerHandler("diagnostics_toggle_wrap", diagnostics_toggle_wrap);
+
 function diagnostics_refresh() : void {
   if (!isOpen) return;
 
@@ -3,8 +3,8 @@
 /**
  * Find References Plugin
  *
- * Displays LSP find references results using the Finder abstraction
- * with filter mode for unified prompt-based UX.
+ * Displays LSP find references results in a panel in the Utility Dock,
+ * alongside Diagnostics and Quickfix. Enter on a result jumps to it.
  */
 
 import { Finder, getRelativePath } from "./lib/finder.ts";
@@ -19,7 +19,7 @@ interface ReferenceLocation {
   content?: string;
 }
 
-// Create the finder instance - same UX as grep plugins
+// Create the finder instance - same panel UX as diagnostics
 const finder = new Finder<ReferenceLocation>(editor, {
   id: "references",
   format: (ref) => {
@@ -46,9 +46,6 @@ const finder = new Finder<ReferenceLocation>(editor, {
   useUtilityDock: true,
 });
 
-// Pending references for the current prompt
-let pendingRefs: ReferenceLocation[] = [];
-
 /**
  * Load line content for references
  */
@@ -98,15 +95,12 @@ editor.on("lsp_references", async (data) => {
   }
 
   // Load line content for descriptions
-  pendingRefs = await loadLineContent(data.locations);
-
-  // Use prompt mode with filter source - same UX as grep plugins
-  finder.prompt({
-    title: `References to '${data.symbol}' (${data.locations.length})`,
-    source: {
-      mode: "filter",
-      load: async () => pendingRefs,
-    },
+  const refs = await loadLineContent(data.locations);
+
+  await finder.panel({
+    title: `References to '${data.symbol}' (${refs.length})`,
+    items: refs,
+    ratio: 0.3,
   });
 });
 
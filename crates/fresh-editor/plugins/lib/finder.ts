@@ -51,6 +51,8 @@ export interface DisplayEntry {
   location?: Location;
   /** Severity for visual styling */
   severity?: "error" | "warning" | "info" | "hint";
+  /** File to list the entry under with `groupBy: "file"` (default: location.file) */
+  group?: string;
   /** Custom metadata */
   metadata?: unknown;
 }
@@ -133,6 +135,20 @@ export interface FinderConfig<T> {
    * Grep Quickfix) should opt in.
    */
   useUtilityDock?: boolean;
+
+  /** Panel-specific: extra [key, handler] bindings for the panel buffer */
+  panelKeys?: [string, string][];
+
+  /** Panel-specific: key hints appended to the panel's help footer */
+  panelHelp?: string;
+
+  /**
+   * Panel-specific: soft-wrap long items to the panel's width instead of
+   * truncating them. A wrapped item spans several screen rows but is
+   * still one buffer line, so Enter on any of its rows selects it.
+   * Left unset, the panel keeps the split's line-wrap setting.
+   */
+  wrapLines?: boolean;
 }
 
 /**
@@ -604,6 +620,17 @@ export class Finder<T> {
     }
   }
 
+  /**
+   * Turn soft-wrapping of long panel items on or off (see `wrapLines`)
+   */
+  setWrapLines(enabled: boolean, title: string): void {
+    this.config.wrapLines = enabled;
+    if (this.isPanelMode && this.panelState.bufferId !== null) {
+      this.editor.setLineWrap(this.panelState.bufferId, this.panelState.splitId, enabled);
+      this.refreshPanel(title);
+    }
+  }
+
   /**
    * Update panel title (for live panels)
    */
@@ -1049,6 +1076,7 @@ export class Finder<T> {
       [
         ["Return", `${this.handlerPrefix}_panel_select`],
         ["Escape", `${this.handlerPrefix}_panel_close`],
+        ...(this.config.panelKeys ?? []),
       ],
       true
     );
@@ -1174,6 +1202,11 @@ export class Finder<T> {
       if (result.bufferId !== null) {
         this.panelState.bufferId = result.bufferId;
         this.panelState.splitId = result.splitId ?? null;
+        // Also covers a reused Utility Dock split, which keeps its own
+        // wrap setting.
+        if (this.config.wrapLines !== undefined) {
+          this.editor.setLineWrap(result.bufferId, this.panelState.splitId, this.config.wrapLines);
+        }
         this.applyPanelHighlighting();
 
         const count = this.panelState.items.length;
@@ -1228,7 +1261,7 @@ export class Finder<T> {
 
       for (let i = 0; i < this.panelState.entries.length; i++) {
         const entry = this.panelState.entries[i];
-        const file = entry.location?.file ?? "(no file)";
+        const file = entry.group ?? entry.location?.file ?? "(no file)";
         if (!byFile.has(file)) {
           byFile.set(file, []);
         }
@@ -1266,8 +1299,9 @@ export class Finder<T> {
       text: "\n",
       properties: { type: "blank" },
     });
+    const extraHelp = this.config.panelHelp ? ` | ${this.config.panelHelp}` : "";
     entries.push({
-      text: "Enter:select | Esc:close\n",
+      text: `Enter:select | Esc:close${extraHelp}\n`,
       properties: { type: "help" },
     });
 
@@ -1291,7 +1325,7 @@ export class Finder<T> {
 
     let line = `${prefix}${entry.label}${desc}`;
     const maxLen = 100;
-    if (line.length > maxLen) {
+    if (!this.config.wrapLines && line.length > maxLen) {
       line = line.slice(0, maxLen - 3) + "...";
     }
 
@@ -441,6 +441,32 @@ type JsDiagnostic = {
 	* Source of the diagnostic (e.g., "typescript", "eslint")
 	*/
 	source?: string;
+	/**
+	* Diagnostic code (e.g., "E0308"), numeric codes as strings
+	*/
+	code?: string;
+	/**
+	* URL of the code's documentation (`codeDescription.href`)
+	*/
+	codeHref?: string;
+	/**
+	* Other locations relevant to the diagnostic (e.g., "first defined here")
+	*/
+	relatedInformation?: Array<JsDiagnosticRelatedInformation>;
+};
+type JsDiagnosticRelatedInformation = {
+	/**
+	* Document URI
+	*/
+	uri: string;
+	/**
+	* Range in the document
+	*/
+	range: JsRange;
+	/**
+	* Message explaining the relation
+	*/
+	message: string;
 };
 type JsRange = {
 	/**
@@ -1234,7 +1260,12 @@ interface EditorAPI {
 	*/
 	cancelAnimation(id: number): boolean;
 	/**
-	* Subscribe to an editor event
+	* Subscribe to an editor event. `eventName` is any hook name (e.g.
+	* `"cursor_moved"`, `"diagnostics_updated"`, `"after_file_save"`) or one
+	* of the event aliases `"buffer_opened"`, `"buffer_saved"` and
+	* `"text_changed"` (fired after inserts and deletes). Events are
+	* delivered in order, after the mutation that triggered them has been
+	* applied.
 	*/
 	on(eventName: string, handlerName: string): void;
 	/**
@@ -1801,6 +1832,19 @@ interface EditorAPI {
 	*/
 	setLineIndicator(bufferId: number, line: number, namespace: string, symbol: string, r: number, g: number, b: number, priority: number): boolean;
 	/**
+	* Set a styled sign in the gutter. Signs are anchored to the line's
+	* text, so they follow edits above them; when several namespaces put a
+	* sign on the same line, the highest `priority` is shown.
+	* 
+	* The `options` object accepts:
+	* * `fg` — an `[r, g, b]` array or a theme-key string (e.g.
+	* `"diagnostic.info_fg"`), resolved at render time. Defaults to the
+	* line number color.
+	* * `bold` — render the symbol bold (default `false`).
+	* * `priority` — default `0`.
+	*/
+	setLineSign(bufferId: number, line: number, namespace: string, symbol: string, options: Record<string, unknown>): boolean;
+	/**
 	* Batch set line indicators in the gutter
 	*/
 	setLineIndicators(bufferId: number, lines: number[], namespace: string, symbol: string, r: number, g: number, b: number, priority: number): boolean;
@@ -1974,6 +2018,26 @@ interface EditorAPI {
 	*/
 	clearRemoteIndicatorState(): void;
 	/**
+	* Register a status bar segment rendered by `handlerName`, a global
+	* function that takes no arguments and returns the segment's text
+	* (an empty string hides it). The handler runs right away and again
+	* after every editor event; the bar only updates when the text changes.
+	* 
+	* `align` is `"left"` or `"right"`. Within a side, higher `priority`
+	* segments sit closer to the built-in elements and are dropped last on
+	* narrow terminals. Registering an existing `id` replaces it.
+	* 
+	* ```ts
+	* registerHandler("git_branch_segment", () => `⎇ ${branch}`);
+	* editor.registerStatusBarSegment("git-branch", "right", 10, "git_branch_segment");
+	* ```
+	*/
+	registerStatusBarSegment(id: string, align: string, priority: number, handlerName: string): boolean;
+	/**
+	* Remove a status bar segment registered with `registerStatusBarSegment`.
+	*/
+	removeStatusBarSegment(id: string): boolean;
+	/**
 	* Wait for a process to complete and get its result (async)
 	*/
 	spawnProcessWait(processId: number): Promise<SpawnResult>;
@@ -2287,6 +2351,11 @@ interface HookEventMap {
 		buffer_id: number;
 		language: string;
 	};
+	breakpoints_changed: {
+		buffer_id: number;
+		path: string | null;
+		lines: number[];
+	};
 	theme_inspect_key: {
 		theme_name: string;
 		key: string;
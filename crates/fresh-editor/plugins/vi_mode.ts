@@ -832,6 +832,17 @@ function vi_center_cursor() : void {
 }
 registerHandler("vi_center_cursor", vi_center_cursor);
 
+// Diagnostic navigation (]d / [d)
+function vi_next_diagnostic() : void {
+  editor.executeAction("jump_to_next_error");
+}
+registerHandler("vi_next_diagnostic", vi_next_diagnostic);
+
+function vi_prev_diagnostic() : void {
+  editor.executeAction("jump_to_previous_error");
+}
+registerHandler("vi_prev_diagnostic", vi_prev_diagnostic);
+
 // Half page movements
 function vi_half_page_down() : void {
   // Approximate half page with multiple down movements
@@ -1703,6 +1714,8 @@ editor.defineMode("vi-normal", [
   ["C-u", "vi_half_page_up"],
   ["%", "vi_matching_bracket"],
   ["z z", "vi_center_cursor"],
+  ["] d", "vi_next_diagnostic"],
+  ["[ d", "vi_prev_diagnostic"],
 
   // Search
   ["/", "vi_search_forward"],
@@ -52,10 +52,23 @@ impl Editor {
     /// - Updates split manager (single source of truth for active buffer)
     /// - Adds buffer to active split's tabs (if not already there)
     /// - Syncs file explorer to the new active file (if visible)
+    /// - Auto-saves the buffer being left when `auto_save` is `on_focus_change`
     ///
     /// Use this instead of directly calling split_manager.set_active_buffer_id()
     /// to ensure all side effects happen consistently.
     pub(super) fn set_active_buffer(&mut self, buffer_id: BufferId) {
+        self.switch_active_buffer(buffer_id, true);
+    }
+
+    /// Like [`Self::set_active_buffer`], for switches the editor makes on
+    /// its own (opening or leaving a diff, preview or staging view, or
+    /// briefly focusing a buffer to save it). The buffer being left is not
+    /// auto-saved: the user did not move focus away from it.
+    pub(super) fn set_active_buffer_internal(&mut self, buffer_id: BufferId) {
+        self.switch_active_buffer(buffer_id, false);
+    }
+
+    fn switch_active_buffer(&mut self, buffer_id: BufferId, auto_save: bool) {
         if self.active_buffer() == buffer_id {
             return; // No change
         }
@@ -69,6 +82,9 @@ impl Editor {
 
         // Track the previous buffer for "Switch to Previous Tab" command
         let previous = self.active_buffer();
+        if auto_save {
+            self.auto_save_on_focus_change(previous);
+        }
 
         // If leaving a terminal buffer while in terminal mode, remember it should resume
         if self.terminal_mode && self.is_terminal_buffer(previous) {
@@ -251,6 +267,7 @@ impl Editor {
 
             // Handle buffer change side effects
             if previous_buffer != buffer_id {
+                self.auto_save_on_focus_change(previous_buffer);
                 self.position_history.commit_pending_movement();
                 if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
                     view_state.add_buffer(buffer_id);
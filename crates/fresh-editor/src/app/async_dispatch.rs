@@ -221,16 +221,28 @@ impl Editor {
                     }
                 }
                 AsyncMessage::LspFormatting {
-                    request_id: _,
+                    request_id,
                     uri,
                     edits,
                 } => {
-                    if !edits.is_empty() {
-                        if let Err(e) = self.apply_formatting_edits(&uri, edits) {
-                            tracing::error!("Failed to apply formatting: {}", e);
-                        }
+                    if let Err(e) = self.handle_formatting_response(request_id, &uri, edits) {
+                        tracing::error!("Failed to apply formatting: {}", e);
                     }
                 }
+                AsyncMessage::LspWillSaveWaitUntil {
+                    request_id,
+                    uri,
+                    edits,
+                } => {
+                    if let Err(e) =
+                        self.handle_will_save_wait_until_response(request_id, &uri, edits)
+                    {
+                        tracing::error!("Failed to apply willSaveWaitUntil edits: {}", e);
+                    }
+                }
+                AsyncMessage::LspWillRenameFiles { request_id, edit } => {
+                    self.handle_will_rename_files_response(request_id, edit);
+                }
                 AsyncMessage::LspPrepareRename {
                     request_id: _,
                     result,
@@ -506,6 +518,12 @@ impl Editor {
                     }
                     self.terminal_manager.close(terminal_id);
                 }
+                AsyncMessage::TaskOutput { task_id, text } => {
+                    self.handle_task_output(task_id, &text);
+                }
+                AsyncMessage::TaskExited { task_id, exit_code } => {
+                    self.handle_task_exited(task_id, exit_code);
+                }
 
                 AsyncMessage::LspServerRequest {
                     language,
@@ -623,6 +641,55 @@ impl Editor {
                         }
                     }
                 }
+                AsyncMessage::GitHeadBlobLoaded {
+                    buffer_id,
+                    path,
+                    content,
+                } => {
+                    self.handle_git_head_blob_loaded(buffer_id, &path, content);
+                }
+                AsyncMessage::GitRevertBlobLoaded {
+                    buffer_id,
+                    path,
+                    version,
+                    content,
+                } => {
+                    self.handle_git_revert_blob_loaded(buffer_id, &path, version, content);
+                }
+                AsyncMessage::GitBlameLoaded {
+                    buffer_id,
+                    path,
+                    blame,
+                } => {
+                    self.handle_git_blame_loaded(buffer_id, &path, blame);
+                }
+                AsyncMessage::GitBlameDiffed {
+                    buffer_id,
+                    content,
+                    version,
+                    origins,
+                } => {
+                    self.handle_git_blame_diffed(buffer_id, &content, version, origins);
+                }
+                AsyncMessage::GitGutterDiffed {
+                    buffer_id,
+                    base,
+                    version,
+                    hunks,
+                    line_count,
+                } => {
+                    self.handle_git_gutter_diffed(buffer_id, &base, version, &hunks, line_count);
+                }
+                AsyncMessage::GitIndexBlobLoaded {
+                    buffer_id,
+                    path,
+                    content,
+                } => {
+                    self.handle_git_index_blob_loaded(buffer_id, path, content);
+                }
+                AsyncMessage::GitHunksStaged { count, result } => {
+                    self.handle_git_hunks_staged(count, result);
+                }
                 AsyncMessage::PluginsDirLoaded {
                     dir,
                     errors,
@@ -700,8 +767,15 @@ impl Editor {
             let _s = tracing::info_span!("poll_file_tree_changes").entered();
             self.poll_file_tree_changes()
         };
+        let theme_changes = self.poll_theme_file_changes();
+        self.poll_watched_files();
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || theme_changes
     }
 }
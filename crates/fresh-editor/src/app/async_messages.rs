@@ -116,6 +116,7 @@ impl Editor {
         if let Some(pull) = self.stored_pull_diagnostics.get(uri) {
             merged.extend(pull.iter().cloned());
         }
+        let merged = crate::services::lsp::diagnostics::dedup_diagnostics(merged);
 
         // Update the merged view
         if merged.is_empty() {
@@ -125,7 +126,9 @@ impl Editor {
                 .insert(uri.to_string(), merged.clone());
         }
 
-        if let Some((buffer_id, updated)) = self.apply_diagnostics_to_buffer(uri, &merged) {
+        if self.diagnostics_hidden {
+            tracing::debug!("Diagnostics hidden, not applying to {}", uri);
+        } else if let Some((buffer_id, updated)) = self.apply_diagnostics_to_buffer(uri, &merged) {
             if updated {
                 tracing::info!(
                     "Applied {} diagnostics to buffer {:?} (overlays updated)",
@@ -864,6 +867,7 @@ impl Editor {
                         title,
                         message,
                         percentage,
+                        updated_at: std::time::Instant::now(),
                     },
                 );
             }
@@ -872,8 +876,12 @@ impl Editor {
                 percentage,
             } => {
                 if let Some(info) = self.lsp_progress.get_mut(&token) {
-                    info.message = message;
+                    // An omitted message keeps the previous one (LSP spec)
+                    if message.is_some() {
+                        info.message = message;
+                    }
                     info.percentage = percentage;
+                    info.updated_at = std::time::Instant::now();
                 }
             }
             LspProgressValue::End { .. } => {
@@ -0,0 +1,183 @@
+//! Bookkeeping for saves whose write runs on a background thread.
+//!
+//! Saving a very large file can take long enough to stall the render
+//! loop, so `Editor::start_save` hands the write to a worker thread (see
+//! `TextBuffer::begin_background_save`). The worker reports progress and
+//! its result over a channel; edits to the buffer are blocked until it
+//! finishes so the buffer still matches what was written.
+//!
+//! `BackgroundSaves` below owns only the per-buffer channel and progress.
+//! The orchestration lives in `file_operations.rs`.
+
+use crate::model::buffer::WrittenSave;
+use crate::model::event::BufferId;
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+/// Message from a save worker thread.
+pub(crate) enum SaveEvent {
+    /// Bytes written to the temp file so far
+    Progress(u64),
+    /// The temp file is complete (or the write failed)
+    Done(io::Result<WrittenSave>),
+}
+
+/// A save whose worker has finished, successfully or not.
+pub(crate) struct FinishedSave {
+    pub buffer_id: BufferId,
+    pub path: PathBuf,
+    /// Whether editing was already disabled before the save blocked it
+    pub was_editing_disabled: bool,
+    pub result: io::Result<WrittenSave>,
+}
+
+struct Active {
+    rx: Receiver<SaveEvent>,
+    path: PathBuf,
+    total_bytes: u64,
+    written_bytes: u64,
+    was_editing_disabled: bool,
+}
+
+/// Owner of the in-flight background saves, at most one per buffer.
+#[derive(Default)]
+pub(crate) struct BackgroundSaves {
+    active: HashMap<BufferId, Active>,
+}
+
+impl BackgroundSaves {
+    pub(crate) fn is_saving(&self, buffer_id: BufferId) -> bool {
+        self.active.contains_key(&buffer_id)
+    }
+
+    pub(crate) fn start(
+        &mut self,
+        buffer_id: BufferId,
+        path: PathBuf,
+        total_bytes: u64,
+        was_editing_disabled: bool,
+        rx: Receiver<SaveEvent>,
+    ) {
+        self.active.insert(
+            buffer_id,
+            Active {
+                rx,
+                path,
+                total_bytes,
+                written_bytes: 0,
+                was_editing_disabled,
+            },
+        );
+    }
+
+    /// Progress percent (0..=100) of the save of `buffer_id`, if one is running.
+    pub(crate) fn progress_percent(&self, buffer_id: BufferId) -> Option<usize> {
+        let active = self.active.get(&buffer_id)?;
+        if active.total_bytes == 0 {
+            return Some(100);
+        }
+        Some(((active.written_bytes.min(active.total_bytes) * 100) / active.total_bytes) as usize)
+    }
+
+    /// Path being written for `buffer_id`, if a save is running.
+    pub(crate) fn path(&self, buffer_id: BufferId) -> Option<&PathBuf> {
+        self.active.get(&buffer_id).map(|a| &a.path)
+    }
+
+    /// Drain worker messages without blocking. Returns the buffers whose
+    /// progress moved and the saves that finished (which are no longer
+    /// tracked). A worker that went away without reporting counts as failed.
+    pub(crate) fn poll(&mut self) -> (Vec<BufferId>, Vec<FinishedSave>) {
+        let mut progressed = Vec::new();
+        let mut finished_ids = Vec::new();
+        for (&buffer_id, active) in self.active.iter_mut() {
+            let mut moved = false;
+            loop {
+                match active.rx.try_recv() {
+                    Ok(SaveEvent::Progress(written)) => {
+                        active.written_bytes = written;
+                        moved = true;
+                    }
+                    Ok(SaveEvent::Done(result)) => {
+                        finished_ids.push((buffer_id, result));
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        finished_ids.push((
+                            buffer_id,
+                            Err(io::Error::other("save worker stopped unexpectedly")),
+                        ));
+                        break;
+                    }
+                }
+            }
+            if moved {
+                progressed.push(buffer_id);
+            }
+        }
+
+        let finished = finished_ids
+            .into_iter()
+            .filter_map(|(buffer_id, result)| {
+                let active = self.active.remove(&buffer_id)?;
+                Some(FinishedSave {
+                    buffer_id,
+                    path: active.path,
+                    was_editing_disabled: active.was_editing_disabled,
+                    result,
+                })
+            })
+            .collect::<Vec<_>>();
+        progressed.retain(|id| self.active.contains_key(id));
+        (progressed, finished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn progress_is_reported_until_done() {
+        let mut saves = BackgroundSaves::default();
+        let (tx, rx) = channel();
+        saves.start(BufferId(1), PathBuf::from("/f"), 200, false, rx);
+        assert!(saves.is_saving(BufferId(1)));
+        assert_eq!(saves.progress_percent(BufferId(1)), Some(0));
+
+        tx.send(SaveEvent::Progress(50)).unwrap();
+        let (progressed, finished) = saves.poll();
+        assert_eq!(progressed, vec![BufferId(1)]);
+        assert!(finished.is_empty());
+        assert_eq!(saves.progress_percent(BufferId(1)), Some(25));
+
+        let (progressed, finished) = saves.poll();
+        assert!(progressed.is_empty());
+        assert!(finished.is_empty());
+
+        tx.send(SaveEvent::Done(Err(io::Error::other("disk full"))))
+            .unwrap();
+        let (_, finished) = saves.poll();
+        assert_eq!(finished.len(), 1);
+        assert!(finished[0].result.is_err());
+        assert!(!saves.is_saving(BufferId(1)));
+        assert_eq!(saves.progress_percent(BufferId(1)), None);
+    }
+
+    #[test]
+    fn vanished_worker_counts_as_failed() {
+        let mut saves = BackgroundSaves::default();
+        let (tx, rx) = channel::<SaveEvent>();
+        saves.start(BufferId(3), PathBuf::from("/f"), 10, true, rx);
+        drop(tx);
+
+        let (_, finished) = saves.poll();
+        assert_eq!(finished.len(), 1);
+        assert!(finished[0].was_editing_disabled);
+        assert!(finished[0].result.is_err());
+    }
+}
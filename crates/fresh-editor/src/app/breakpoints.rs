@@ -0,0 +1,123 @@
+//! Breakpoints on `Editor`.
+//!
+//! Breakpoints are line indicators in the buffer's margin under
+//! [`BREAKPOINT_NAMESPACE_PREFIX`], so they are anchored to a byte position
+//! and follow their line through edits like any other indicator. They are
+//! toggled from the `breakpoint` gutter column or with the toggle command,
+//! and every change fires the `breakpoints_changed` hook with the buffer's
+//! full set of lines — the hook point a debug adapter plugin listens on.
+
+use ratatui::style::Color;
+
+use crate::model::event::BufferId;
+use crate::services::plugins::hooks::HookArgs;
+use crate::view::margin::{LineIndicator, BREAKPOINT_NAMESPACE_PREFIX};
+
+use super::Editor;
+
+/// Gutter glyph of a breakpoint.
+const BREAKPOINT_SYMBOL: &str = "◉";
+
+/// Priority over git and diagnostic signs sharing the classic indicator cell.
+const BREAKPOINT_PRIORITY: i32 = 20;
+
+impl Editor {
+    /// Toggle a breakpoint on the primary cursor's line.
+    pub(crate) fn toggle_breakpoint(&mut self) {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let Some(line) = self
+            .buffers
+            .get(&buffer_id)
+            .map(|state| state.buffer.get_line_number(position))
+        else {
+            return;
+        };
+        self.toggle_breakpoint_at_line(buffer_id, line);
+    }
+
+    /// Set a breakpoint on `line` (0-indexed) of a buffer, or clear the one
+    /// that is there.
+    pub(crate) fn toggle_breakpoint_at_line(&mut self, buffer_id: BufferId, line: usize) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let existing: Vec<_> = state
+            .margins
+            .line_indicator_positions(BREAKPOINT_NAMESPACE_PREFIX)
+            .into_iter()
+            .filter(|&(_, position)| state.buffer.get_line_number(position) == line)
+            .map(|(marker_id, _)| marker_id)
+            .collect();
+        if existing.is_empty() {
+            let Some(offset) = state.buffer.line_start_offset(line) else {
+                return;
+            };
+            state.margins.set_line_indicator(
+                offset,
+                BREAKPOINT_NAMESPACE_PREFIX.to_string(),
+                LineIndicator::new(BREAKPOINT_SYMBOL, Color::Red, BREAKPOINT_PRIORITY),
+            );
+        } else {
+            for marker_id in existing {
+                state
+                    .margins
+                    .remove_line_indicator(marker_id, BREAKPOINT_NAMESPACE_PREFIX);
+            }
+        }
+        self.fire_breakpoints_changed(buffer_id);
+    }
+
+    /// Set breakpoints on `lines` of a buffer without firing the hook, e.g.
+    /// when a workspace is restored. Lines past the end are skipped.
+    pub(crate) fn restore_breakpoints(&mut self, buffer_id: BufferId, lines: &[usize]) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        state
+            .margins
+            .clear_line_indicators_for_namespace(BREAKPOINT_NAMESPACE_PREFIX);
+        for &line in lines {
+            if let Some(offset) = state.buffer.line_start_offset(line) {
+                state.margins.set_line_indicator(
+                    offset,
+                    BREAKPOINT_NAMESPACE_PREFIX.to_string(),
+                    LineIndicator::new(BREAKPOINT_SYMBOL, Color::Red, BREAKPOINT_PRIORITY),
+                );
+            }
+        }
+    }
+
+    /// Lines (0-indexed, ascending, deduplicated) with a breakpoint.
+    pub fn breakpoint_lines(&self, buffer_id: BufferId) -> Vec<usize> {
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return Vec::new();
+        };
+        let mut lines: Vec<usize> = state
+            .margins
+            .line_indicator_positions(BREAKPOINT_NAMESPACE_PREFIX)
+            .into_iter()
+            .map(|(_, position)| state.buffer.get_line_number(position))
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+        lines
+    }
+
+    fn fire_breakpoints_changed(&mut self, buffer_id: BufferId) {
+        let path = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .cloned();
+        let lines = self.breakpoint_lines(buffer_id);
+        self.plugin_manager.run_hook(
+            "breakpoints_changed",
+            HookArgs::BreakpointsChanged {
+                buffer_id,
+                path,
+                lines,
+            },
+        );
+    }
+}
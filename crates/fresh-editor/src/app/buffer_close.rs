@@ -249,6 +249,8 @@ impl Editor {
         self.event_logs.remove(&id);
         self.seen_byte_ranges.remove(&id);
         self.buffer_metadata.remove(&id);
+        self.git_gutter_bases.remove(&id);
+        self.git_blames.remove(&id);
         if let Some((request_id, _, _)) = self.semantic_tokens_in_flight.remove(&id) {
             self.pending_semantic_token_requests.remove(&request_id);
         }
@@ -377,21 +379,7 @@ impl Editor {
             if let Some(state) = self.buffers.get(&buffer_id) {
                 if state.buffer.is_modified() {
                     // Buffer has unsaved changes - prompt for confirmation
-                    let name = self.get_buffer_display_name(buffer_id);
-                    let save_key = t!("prompt.key.save").to_string();
-                    let discard_key = t!("prompt.key.discard").to_string();
-                    let cancel_key = t!("prompt.key.cancel").to_string();
-                    self.start_prompt(
-                        t!(
-                            "prompt.buffer_modified",
-                            name = name,
-                            save_key = save_key,
-                            discard_key = discard_key,
-                            cancel_key = cancel_key
-                        )
-                        .to_string(),
-                        PromptType::ConfirmCloseBuffer { buffer_id },
-                    );
+                    self.start_close_modified_buffer_prompt(buffer_id);
                     return false;
                 }
             }
@@ -450,7 +438,73 @@ impl Editor {
         true
     }
 
-    /// Close all other tabs in a split, keeping only the specified buffer
+    /// Ask whether to save, discard or keep the changes of a modified buffer
+    /// that is being closed. The choices are typed as a key or picked from
+    /// the suggestion list; closing the last buffer also offers to save
+    /// everything and quit.
+    fn start_close_modified_buffer_prompt(&mut self, buffer_id: BufferId) {
+        use crate::input::commands::Suggestion;
+
+        let name = self.get_buffer_display_name(buffer_id);
+        let save_key = t!("prompt.key.save").to_string();
+        let discard_key = t!("prompt.key.discard").to_string();
+        let cancel_key = t!("prompt.key.cancel").to_string();
+        let quit_key = t!("prompt.key.quit").to_string();
+        let is_last = self.is_last_visible_buffer(buffer_id);
+
+        let message = if is_last {
+            t!(
+                "prompt.buffer_modified_last",
+                name = name,
+                save_key = save_key,
+                discard_key = discard_key,
+                cancel_key = cancel_key,
+                quit_key = quit_key
+            )
+        } else {
+            t!(
+                "prompt.buffer_modified",
+                name = name,
+                save_key = save_key,
+                discard_key = discard_key,
+                cancel_key = cancel_key
+            )
+        };
+
+        let mut choices = vec![
+            Suggestion::new(t!("prompt.choice.save").to_string()).with_value(save_key),
+            Suggestion::new(t!("prompt.choice.discard").to_string()).with_value(discard_key),
+            Suggestion::new(t!("prompt.choice.cancel").to_string()).with_value(cancel_key),
+        ];
+        if is_last {
+            choices.push(
+                Suggestion::new(t!("prompt.choice.save_all_and_quit").to_string())
+                    .with_value(quit_key),
+            );
+        }
+
+        self.start_prompt_with_suggestions(
+            message.to_string(),
+            PromptType::ConfirmCloseBuffer { buffer_id },
+            choices,
+        );
+        // Up/Down copy the highlighted choice's key into the input; start on
+        // Cancel, which is also what Enter on its own does
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(2);
+        }
+    }
+
+    /// Whether no other buffer would be left in the tab bars after closing
+    /// this one
+    pub(crate) fn is_last_visible_buffer(&self, buffer_id: BufferId) -> bool {
+        !self.buffer_metadata.iter().any(|(&id, meta)| {
+            id != buffer_id && !meta.hidden_from_tabs && !meta.synthetic_placeholder
+        })
+    }
+
+    /// Close all other tabs in a split, keeping only the specified buffer and
+    /// pinned tabs
     pub fn close_other_tabs_in_split(&mut self, keep_buffer_id: BufferId, split_id: LeafId) {
         // Get the split's open buffers
         let split_tabs = self
@@ -459,10 +513,10 @@ impl Editor {
             .map(|vs| vs.buffer_tab_ids_vec())
             .unwrap_or_default();
 
-        // Close all tabs except the one we want to keep
+        // Close all unpinned tabs except the one we want to keep
         let tabs_to_close: Vec<_> = split_tabs
             .iter()
-            .filter(|&&id| id != keep_buffer_id)
+            .filter(|&&id| id != keep_buffer_id && !self.is_tab_pinned(id))
             .copied()
             .collect();
 
@@ -497,8 +551,13 @@ impl Editor {
             return;
         };
 
-        // Close all tabs after the target
-        let tabs_to_close: Vec<_> = split_tabs.iter().skip(target_idx + 1).copied().collect();
+        // Close all unpinned tabs after the target
+        let tabs_to_close: Vec<_> = split_tabs
+            .iter()
+            .skip(target_idx + 1)
+            .filter(|&&id| !self.is_tab_pinned(id))
+            .copied()
+            .collect();
 
         let mut closed = 0;
         let mut skipped_modified = 0;
@@ -527,8 +586,13 @@ impl Editor {
             return;
         };
 
-        // Close all tabs before the target
-        let tabs_to_close: Vec<_> = split_tabs.iter().take(target_idx).copied().collect();
+        // Close all unpinned tabs before the target
+        let tabs_to_close: Vec<_> = split_tabs
+            .iter()
+            .take(target_idx)
+            .filter(|&&id| !self.is_tab_pinned(id))
+            .copied()
+            .collect();
 
         let mut closed = 0;
         let mut skipped_modified = 0;
@@ -543,14 +607,17 @@ impl Editor {
         self.set_batch_close_status_message(closed, skipped_modified);
     }
 
-    /// Close all tabs in a split
+    /// Close all unpinned tabs in a split
     pub fn close_all_tabs_in_split(&mut self, split_id: LeafId) {
-        // Get the split's open buffers
-        let split_tabs = self
+        // Get the split's open buffers, leaving pinned ones alone
+        let split_tabs: Vec<_> = self
             .split_view_states
             .get(&split_id)
             .map(|vs| vs.buffer_tab_ids_vec())
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&id| !self.is_tab_pinned(id))
+            .collect();
 
         let mut closed = 0;
         let mut skipped_modified = 0;
@@ -306,15 +306,54 @@ impl Editor {
         self.buffers.len()
     }
 
+    /// Number of buffers with unsaved changes.
+    pub fn modified_buffer_count(&self) -> usize {
+        self.buffers
+            .values()
+            .filter(|state| state.buffer.is_modified())
+            .count()
+    }
+
+    /// Bytes of buffer content currently held in memory, across all buffers.
+    pub fn loaded_buffer_bytes(&self) -> usize {
+        self.buffers
+            .values()
+            .map(|state| state.buffer.loaded_bytes())
+            .sum()
+    }
+
+    /// Large-file chunk cache counters, summed across all buffers.
+    pub fn chunk_cache_stats(&self) -> crate::model::buffer::ChunkCacheStats {
+        self.buffers
+            .values()
+            .fold(Default::default(), |total, state| {
+                let stats = state.buffer.chunk_cache_stats();
+                crate::model::buffer::ChunkCacheStats {
+                    hits: total.hits + stats.hits,
+                    misses: total.misses + stats.misses,
+                    resident: total.resident + stats.resident,
+                }
+            })
+    }
+
     /// The (split, buffer) tuple of the current preview tab, if any.
     /// Intended for tests that verify preview anchoring semantics.
     pub fn current_preview(&self) -> Option<(LeafId, BufferId)> {
         self.preview
     }
 
+    /// Navigate to a compiler-style `line:col` location in the active buffer.
+    ///
+    /// Both are 1-indexed. The column counts characters, so a tab is one
+    /// column however wide it renders.
+    pub fn goto_position(&mut self, line: usize, column: usize) {
+        self.goto_line_col(line, Some(column));
+    }
+
     /// Navigate to a specific line and column in the active buffer.
     ///
-    /// Line and column are 1-indexed (matching typical editor conventions).
+    /// Line and column are 1-indexed (matching typical editor conventions),
+    /// and the column counts characters.
     /// If the line is out of bounds, navigates to the last line.
     /// If the column is out of bounds, navigates to the end of the line.
     pub fn goto_line_col(&mut self, line: usize, column: Option<usize>) {
@@ -366,7 +405,9 @@ impl Editor {
                 // use exact line position
                 let max_line = state.buffer.line_count().unwrap_or(1).saturating_sub(1);
                 let actual_line = target_line.min(max_line);
-                state.buffer.line_col_to_position(actual_line, target_col)
+                state
+                    .buffer
+                    .line_char_col_to_position(actual_line, target_col)
             };
 
             // Preserve anchor if deselect_on_move is false (Emacs mark mode)
@@ -558,6 +599,56 @@ impl Editor {
         buffer_id
     }
 
+    /// Copy the selection into a new scratch buffer that uses the same
+    /// language, and switch to it. The original buffer is left untouched.
+    pub(super) fn extract_selection_to_new_buffer(&mut self) {
+        use crate::primitives::detected_language::DetectedLanguage;
+
+        let text = if self
+            .active_cursors()
+            .iter()
+            .any(|(_, cursor)| cursor.has_block_selection())
+        {
+            self.copy_block_selection_text()
+        } else {
+            let ranges: Vec<_> = self
+                .active_cursors()
+                .iter()
+                .filter_map(|(_, cursor)| cursor.selection_range())
+                .collect();
+            let state = self.active_state_mut();
+            ranges
+                .into_iter()
+                .map(|range| state.get_text_range(range.start, range.end))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        if text.is_empty() {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        }
+
+        let source_id = self.active_buffer();
+        let source_name = self.get_buffer_display_name(source_id);
+        let source_language = self.active_state().display_name.clone();
+        let name = t!("buffer.extract_name", name = &source_name).to_string();
+        let buffer_id = self.create_virtual_buffer(name.clone(), String::new(), false);
+        let detected = DetectedLanguage::from_syntax_name(
+            &source_language,
+            &self.grammar_registry,
+            &self.config.languages,
+        );
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            if let Some(detected) = detected {
+                state.apply_language(detected);
+            }
+            state.buffer.insert(0, &text);
+            state.buffer.clear_modified();
+        }
+        self.set_active_buffer(buffer_id);
+        self.set_status_message(t!("buffer.extracted", name = &name).to_string());
+    }
+
     /// Get the current mouse hover state for testing
     /// Returns Some((byte_position, screen_x, screen_y)) if hovering over text
     pub fn get_mouse_hover_state(&self) -> Option<(usize, u16, u16)> {
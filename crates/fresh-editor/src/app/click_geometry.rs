@@ -137,6 +137,21 @@ pub(crate) fn screen_to_buffer_position(
     Some(position)
 }
 
+/// Lines to auto-scroll while drag-selecting with the pointer on `row`.
+///
+/// Negative above `first_row`, positive below `last_row` and zero in
+/// between; the further past the edge the pointer is, the faster the view
+/// scrolls.
+pub(crate) fn drag_scroll_lines(row: u16, first_row: u16, last_row: u16) -> isize {
+    if row < first_row {
+        -((first_row - row) as isize)
+    } else if row > last_row {
+        (row - last_row) as isize
+    } else {
+        0
+    }
+}
+
 /// Check whether a gutter click at `target_position` should toggle a fold.
 ///
 /// Returns `Some(target_position)` (the byte to fold at) or `None` when the
@@ -151,6 +166,14 @@ pub(crate) fn fold_toggle_byte_from_position(
     if content_col >= gutter_width {
         return None;
     }
+    // Breakpoint targets toggle breakpoints, not folds
+    if state
+        .margins
+        .left_config
+        .toggles_breakpoint_at(content_col as usize)
+    {
+        return None;
+    }
 
     use crate::view::folding::indent_folding;
     let line_start = indent_folding::find_line_start_byte(&state.buffer, target_position);
@@ -257,4 +280,15 @@ mod tests {
         let pos = screen_to_buffer_position(1, 2, r, 3, &None, 0, false, None);
         assert_eq!(pos, None);
     }
+
+    #[test]
+    fn drag_scroll_lines_grows_with_distance_past_edge() {
+        assert_eq!(drag_scroll_lines(5, 2, 20), 0);
+        assert_eq!(drag_scroll_lines(2, 2, 20), 0);
+        assert_eq!(drag_scroll_lines(20, 2, 20), 0);
+        assert_eq!(drag_scroll_lines(1, 2, 20), -1);
+        assert_eq!(drag_scroll_lines(0, 2, 20), -2);
+        assert_eq!(drag_scroll_lines(21, 2, 20), 1);
+        assert_eq!(drag_scroll_lines(23, 2, 20), 3);
+    }
 }
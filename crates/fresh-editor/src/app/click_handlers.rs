@@ -190,6 +190,35 @@ impl Editor {
         None
     }
 
+    /// Whether a click at the screen position toggles a breakpoint in an
+    /// editor split's gutter.
+    pub(super) fn is_breakpoint_column_at(&self, col: u16, row: u16) -> bool {
+        self.cached_layout.split_areas.iter().any(
+            |(split_id, buffer_id, content_rect, _scrollbar_rect, _thumb_start, _thumb_end)| {
+                if row < content_rect.y || row >= content_rect.y + content_rect.height {
+                    return false;
+                }
+                let compose_width = self
+                    .split_view_states
+                    .get(split_id)
+                    .and_then(|vs| vs.compose_width);
+                let adjusted_rect = super::click_geometry::adjust_content_rect_for_compose(
+                    *content_rect,
+                    compose_width,
+                );
+                if col < adjusted_rect.x || col >= content_rect.x + content_rect.width {
+                    return false;
+                }
+                self.buffers.get(buffer_id).is_some_and(|state| {
+                    state
+                        .margins
+                        .left_config
+                        .toggles_breakpoint_at((col - adjusted_rect.x) as usize)
+                })
+            },
+        )
+    }
+
     /// Handle click in editor content area
     pub(super) fn handle_editor_click(
         &mut self,
@@ -322,7 +351,7 @@ impl Editor {
             .and_then(|vs| vs.compose_width);
 
         // Calculate clicked position in buffer
-        let (toggle_fold_byte, onclick_action, target_position, cursor_snapshot) =
+        let (toggle_fold_byte, toggle_breakpoint, onclick_action, target_position, cursor_snapshot) =
             if let Some(state) = self.buffers.get(&buffer_id) {
                 let gutter_width = state.margins.left_total_width() as u16;
 
@@ -345,6 +374,10 @@ impl Editor {
                     compose_width,
                 );
                 let content_col = col.saturating_sub(adjusted_rect.x);
+                let toggle_breakpoint = state
+                    .margins
+                    .left_config
+                    .toggles_breakpoint_at(content_col as usize);
                 let collapsed_header_bytes = self
                     .split_view_states
                     .get(&split_id)
@@ -390,6 +423,7 @@ impl Editor {
 
                 (
                     toggle_fold_byte,
+                    toggle_breakpoint,
                     onclick_action,
                     target_position,
                     cursor_snapshot,
@@ -398,6 +432,17 @@ impl Editor {
                 return Ok(());
             };
 
+        if toggle_breakpoint {
+            let line = self
+                .buffers
+                .get(&buffer_id)
+                .map(|state| state.buffer.get_line_number(target_position));
+            if let Some(line) = line {
+                self.toggle_breakpoint_at_line(buffer_id, line);
+            }
+            return Ok(());
+        }
+
         if toggle_fold_byte.is_some() {
             self.toggle_fold_at_byte(buffer_id, target_position);
             return Ok(());
@@ -420,6 +465,11 @@ impl Editor {
             return Ok(());
         }
 
+        // Clicking a `path:line:col` in a task output panel jumps there
+        if self.open_task_location_at(buffer_id, target_position) {
+            return Ok(());
+        }
+
         // Move cursor to clicked position (respect shift for selection)
         // Both modifiers supported since some terminals intercept shift+click.
         let extend_selection =
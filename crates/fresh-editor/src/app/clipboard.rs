@@ -2,13 +2,15 @@
 //!
 //! This module contains clipboard operations and multi-cursor actions:
 //! - Copy/cut/paste operations
+//! - Yank ring paste-cycling
 //! - Copy with formatting (HTML with syntax highlighting)
 //! - Multi-cursor add above/below/at next match
 
 use rust_i18n::t;
 
 use crate::input::multi_cursor::{
-    add_cursor_above, add_cursor_at_next_match, add_cursor_below, AddCursorResult,
+    add_cursor_above, add_cursor_at_next_match, add_cursor_below, select_all_occurrences,
+    AddCursorResult, SelectOccurrencesResult,
 };
 use crate::model::buffer_position::byte_to_2d;
 use crate::model::event::{CursorId, Event};
@@ -18,6 +20,20 @@ use crate::primitives::word_navigation::{
 
 use super::Editor;
 
+/// The text most recently inserted by a clipboard paste, which
+/// `paste_cycle_previous`/`paste_cycle_next` may replace with another yank
+/// ring entry.
+#[derive(Debug, Clone)]
+pub(crate) struct YankCycle {
+    buffer_id: crate::model::event::BufferId,
+    /// Byte range of the pasted text
+    range: std::ops::Range<usize>,
+    /// Yank ring index of the pasted text
+    ring_index: usize,
+    /// Event log position right after the paste; any later edit ends the cycle
+    log_index: usize,
+}
+
 // These are the clipboard and multi-cursor operations on Editor.
 //
 // MOTIVATION FOR SEPARATION:
@@ -103,7 +119,7 @@ impl Editor {
             }
 
             if !text.is_empty() {
-                self.clipboard.copy(text);
+                self.clipboard.copy_lines(text);
                 self.status_message = Some(t!("clipboard.copied_line").to_string());
             }
         }
@@ -117,7 +133,7 @@ impl Editor {
     ///
     /// This works for both small and large files by using line_iterator
     /// for iteration and only using 2D positions for column extraction.
-    fn copy_block_selection_text(&mut self) -> String {
+    pub(super) fn copy_block_selection_text(&mut self) -> String {
         let estimated_line_length = 120;
 
         // Collect block selection info from all cursors
@@ -510,8 +526,113 @@ impl Editor {
             None => return,
         };
 
-        // Use paste_text which handles line ending normalization
-        self.paste_text(text);
+        self.paste_from_ring(text);
+    }
+
+    /// Paste the newest yank ring entry, remembering where it went so that
+    /// it can be swapped by `paste_cycle_previous`/`paste_cycle_next`
+    fn paste_from_ring(&mut self, text: String) {
+        let linewise = self.clipboard.is_linewise() && text == self.clipboard.get_internal();
+        let buffer_id = self.active_buffer();
+        let pasted = self.insert_pasted_text(text, linewise);
+        self.yank_cycle = pasted.map(|range| YankCycle {
+            buffer_id,
+            range,
+            ring_index: 0,
+            log_index: self.active_event_log().current_index(),
+        });
+    }
+
+    /// Replace the text just pasted with the previous (older) yank ring entry
+    pub fn paste_cycle_previous(&mut self) {
+        self.paste_cycle(true);
+    }
+
+    /// Replace the text just pasted with the next (newer) yank ring entry
+    pub fn paste_cycle_next(&mut self) {
+        self.paste_cycle(false);
+    }
+
+    fn paste_cycle(&mut self, older: bool) {
+        let cycle = match self.yank_cycle.take() {
+            Some(cycle)
+                if cycle.buffer_id == self.active_buffer()
+                    && cycle.log_index == self.active_event_log().current_index()
+                    && self.active_cursors().count() == 1
+                    && self.active_cursors().primary().position == cycle.range.end =>
+            {
+                cycle
+            }
+            _ => {
+                self.status_message = Some(t!("clipboard.no_paste_to_cycle").to_string());
+                return;
+            }
+        };
+
+        let ring_len = self.clipboard.ring_len();
+        if ring_len == 0 {
+            return;
+        }
+        let ring_index = if older {
+            (cycle.ring_index + 1) % ring_len
+        } else {
+            (cycle.ring_index + ring_len - 1) % ring_len
+        };
+        let Some(entry) = self.clipboard.ring_entry(ring_index).cloned() else {
+            return;
+        };
+        let text = self.to_buffer_line_endings(&entry.text, entry.linewise);
+
+        let cursor_id = self.active_cursors().primary_id();
+        let deleted_text = self
+            .active_state_mut()
+            .get_text_range(cycle.range.start, cycle.range.end);
+        let start = cycle.range.start;
+        let end = start + text.len();
+        let events = vec![
+            Event::Delete {
+                range: cycle.range,
+                deleted_text,
+                cursor_id,
+            },
+            Event::Insert {
+                position: start,
+                text,
+                cursor_id,
+            },
+        ];
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Paste".to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+
+        self.yank_cycle = Some(YankCycle {
+            buffer_id: cycle.buffer_id,
+            range: start..end,
+            ring_index,
+            log_index: self.active_event_log().current_index(),
+        });
+        self.status_message = Some(
+            t!(
+                "clipboard.pasted_ring_entry",
+                index = ring_index + 1,
+                total = ring_len
+            )
+            .to_string(),
+        );
+    }
+
+    /// Normalize pasted text to the active buffer's line ending, terminating
+    /// line-wise text with a line ending
+    fn to_buffer_line_endings(&self, text: &str, linewise: bool) -> String {
+        let mut normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        if linewise && !normalized.ends_with('\n') {
+            normalized.push('\n');
+        }
+        match self.active_state().buffer.line_ending() {
+            crate::model::buffer::LineEnding::LF => normalized,
+            crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
+            crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
+        }
     }
 
     /// Paste text directly into the editor
@@ -524,8 +645,19 @@ impl Editor {
     /// - Atomic undo (single undo step for entire operation)
     /// - Routing to prompt if one is open
     pub fn paste_text(&mut self, paste_text: String) {
+        self.insert_pasted_text(paste_text, false);
+    }
+
+    /// Shared implementation of [`Self::paste_text`]. Line-wise text is
+    /// inserted at the start of each cursor's line. Returns the inserted byte
+    /// range when a single cursor pasted into a buffer.
+    fn insert_pasted_text(
+        &mut self,
+        paste_text: String,
+        linewise: bool,
+    ) -> Option<std::ops::Range<usize>> {
         if paste_text.is_empty() {
-            return;
+            return None;
         }
 
         // Normalize line endings: first convert all to LF, then to buffer's format
@@ -537,22 +669,17 @@ impl Editor {
             prompt.insert_str(&normalized);
             self.update_prompt_suggestions();
             self.status_message = Some(t!("clipboard.pasted").to_string());
-            return;
+            return None;
         }
 
         // If in terminal mode, send paste to the terminal PTY
         if self.terminal_mode {
             self.send_terminal_input(normalized.as_bytes());
-            return;
+            return None;
         }
 
         // Convert to buffer's line ending format
-        let buffer_line_ending = self.active_state().buffer.line_ending();
-        let paste_text = match buffer_line_ending {
-            crate::model::buffer::LineEnding::LF => normalized,
-            crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
-            crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
-        };
+        let paste_text = self.to_buffer_line_endings(&normalized, linewise);
 
         let mut events = Vec::new();
 
@@ -570,6 +697,7 @@ impl Editor {
             })
             .collect();
         cursor_data.sort_by_key(|(_, _, pos)| std::cmp::Reverse(*pos));
+        let cursor_count = cursor_data.len();
 
         // Get deleted text for each selection
         let cursor_data_with_text: Vec<_> = {
@@ -580,6 +708,15 @@ impl Editor {
                     let deleted_text = selection
                         .as_ref()
                         .map(|r| state.get_text_range(r.start, r.end));
+                    // Whole lines go above the cursor's line, not at its column
+                    let insert_position = if linewise && selection.is_none() {
+                        state
+                            .buffer
+                            .line_iterator(insert_position, 80)
+                            .current_position()
+                    } else {
+                        insert_position
+                    };
                     (cursor_id, selection, insert_position, deleted_text)
                 })
                 .collect()
@@ -601,6 +738,15 @@ impl Editor {
             });
         }
 
+        // Only a single-cursor paste can later be swapped by paste-cycling
+        let pasted_range = match cursor_count {
+            1 => events.last().and_then(|event| match event {
+                Event::Insert { position, text, .. } => Some(*position..*position + text.len()),
+                _ => None,
+            }),
+            _ => None,
+        };
+
         // Apply events with atomic undo using bulk edit for O(n) performance
         if events.len() > 1 {
             // Use optimized bulk edit for multi-cursor paste
@@ -612,6 +758,7 @@ impl Editor {
         }
 
         self.status_message = Some(t!("clipboard.pasted").to_string());
+        pasted_range
     }
 
     /// Set clipboard content for testing purposes
@@ -634,7 +781,7 @@ impl Editor {
         };
 
         // Use the same paste logic as the regular paste method
-        self.paste_text(paste_text);
+        self.paste_from_ring(paste_text);
     }
 
     /// Get clipboard content for testing purposes
@@ -735,6 +882,77 @@ impl Editor {
         }
     }
 
+    /// Put a selection on every occurrence of the word under the cursor
+    /// (or of the current selection), one cursor each, replacing any
+    /// existing secondary cursors. Applied as a single undoable batch.
+    pub fn select_all_occurrences(&mut self) {
+        let cursors = self.active_cursors().clone();
+        let state = self.active_state_mut();
+        let (ranges, primary_index, capped) = match select_all_occurrences(state, &cursors) {
+            SelectOccurrencesResult::Selected {
+                ranges,
+                primary_index,
+                capped,
+            } => (ranges, primary_index, capped),
+            SelectOccurrencesResult::Failed { message } => {
+                self.status_message = Some(message);
+                return;
+            }
+        };
+
+        let primary_id = cursors.primary_id();
+        let primary = cursors.primary();
+        let mut events = Vec::new();
+        for (cursor_id, cursor) in cursors.iter() {
+            if cursor_id != primary_id {
+                events.push(Event::RemoveCursor {
+                    cursor_id,
+                    position: cursor.position,
+                    anchor: cursor.anchor,
+                });
+            }
+        }
+        let primary_range = &ranges[primary_index];
+        events.push(Event::MoveCursor {
+            cursor_id: primary_id,
+            old_position: primary.position,
+            new_position: primary_range.end,
+            old_anchor: primary.anchor,
+            new_anchor: Some(primary_range.start),
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: 0,
+        });
+        let mut next_id = cursors.next_id().0;
+        for (i, range) in ranges.iter().enumerate() {
+            if i == primary_index {
+                continue;
+            }
+            events.push(Event::AddCursor {
+                cursor_id: CursorId(next_id),
+                position: range.end,
+                anchor: Some(range.start),
+            });
+            next_id += 1;
+        }
+
+        let batch = Event::Batch {
+            events,
+            description: "Select all occurrences".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+
+        self.status_message = Some(if capped {
+            t!(
+                "clipboard.selected_occurrences_capped",
+                count = ranges.len()
+            )
+            .to_string()
+        } else {
+            t!("clipboard.selected_occurrences", count = ranges.len()).to_string()
+        });
+    }
+
     /// Add a cursor above the primary cursor at the same column
     pub fn add_cursor_above(&mut self) {
         let cursors = self.active_cursors().clone();
@@ -0,0 +1,163 @@
+//! History of executed commands on `Editor`.
+//!
+//! Every command run from the command palette and every task started with
+//! "Run Task" is recorded with the time it ran. "Command History" lists
+//! them newest first in a read-only `*Command History*` panel in the
+//! Utility Dock; Enter on a line runs that entry again in the editor split
+//! the panel was opened from. The palette's own frecency ranking is kept
+//! separately by the command registry.
+
+use chrono::{DateTime, Local};
+use rust_i18n::t;
+
+use crate::input::keybindings::Action;
+use crate::model::event::BufferId;
+use crate::primitives::text_property::TextPropertyEntry;
+use crate::view::split::SplitRole;
+
+use super::Editor;
+
+/// Panel id of the history buffer in `panel_ids`.
+const PANEL_KEY: &str = "command-history";
+
+/// Entries kept, oldest dropped first.
+const MAX_ENTRIES: usize = 200;
+
+/// What running a history entry again does.
+#[derive(Debug, Clone)]
+pub(crate) enum CommandHistoryKind {
+    /// A command palette command
+    Command(Action),
+    /// A configured task, by name
+    Task(String),
+}
+
+/// A command that was executed.
+#[derive(Debug, Clone)]
+pub(crate) struct CommandHistoryEntry {
+    label: String,
+    kind: CommandHistoryKind,
+    time: DateTime<Local>,
+}
+
+impl Editor {
+    /// Record that a command ran. Opening the history itself isn't recorded.
+    pub(crate) fn record_command_history(&mut self, label: String, kind: CommandHistoryKind) {
+        if matches!(
+            kind,
+            CommandHistoryKind::Command(Action::ShowCommandHistory)
+        ) {
+            return;
+        }
+        self.command_history.insert(
+            0,
+            CommandHistoryEntry {
+                label,
+                kind,
+                time: Local::now(),
+            },
+        );
+        self.command_history.truncate(MAX_ENTRIES);
+        self.refresh_command_history_panel();
+    }
+
+    /// Show the history panel in the Utility Dock.
+    pub(crate) fn show_command_history(&mut self) {
+        let buffer_id = match self.command_history_panel() {
+            Some(buffer_id) => buffer_id,
+            None => {
+                let buffer_id = self.create_virtual_buffer_detached(
+                    "*Command History*".to_string(),
+                    "command-history".to_string(),
+                    true,
+                );
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.margins.configure_for_line_numbers(false);
+                    state.show_cursors = true;
+                    state.editing_disabled = true;
+                }
+                self.panel_ids.insert(PANEL_KEY.to_string(), buffer_id);
+                buffer_id
+            }
+        };
+        self.refresh_command_history_panel();
+        self.show_in_utility_dock(buffer_id);
+        if self.command_history.is_empty() {
+            self.set_status_message(t!("command_history.empty").to_string());
+        }
+    }
+
+    /// If the history panel is active, run the entry on the cursor's line
+    /// again. Returns whether the panel was active.
+    pub(crate) fn rerun_command_history_entry(&mut self) -> bool {
+        let buffer_id = self.active_buffer();
+        if self.command_history_panel() != Some(buffer_id) {
+            return false;
+        }
+        let position = self.active_cursors().primary().position;
+        let line = self.active_state().buffer.get_line_number(position);
+        let Some(entry) = self.command_history.get(line).cloned() else {
+            return true;
+        };
+
+        // Run it where the user works, not in the panel.
+        let dock = self.split_manager.find_leaf_by_role(SplitRole::UtilityDock);
+        let target = self
+            .split_manager
+            .root()
+            .leaf_split_ids()
+            .into_iter()
+            .find(|&leaf| Some(leaf) != dock);
+        if let Some(target) = target {
+            if let Some(target_buffer) = self.split_manager.buffer_for_split(target) {
+                self.focus_split(target, target_buffer);
+            }
+        }
+
+        match entry.kind {
+            CommandHistoryKind::Command(action) => {
+                self.record_command_history(
+                    entry.label,
+                    CommandHistoryKind::Command(action.clone()),
+                );
+                if let Err(e) = self.handle_action(action) {
+                    tracing::warn!("Re-running command from history failed: {e}");
+                }
+            }
+            CommandHistoryKind::Task(name) => self.run_task(&name),
+        }
+        true
+    }
+
+    /// The history panel's buffer, if it is still open.
+    fn command_history_panel(&mut self) -> Option<BufferId> {
+        let buffer_id = *self.panel_ids.get(PANEL_KEY)?;
+        if self.buffers.contains_key(&buffer_id) {
+            Some(buffer_id)
+        } else {
+            self.panel_ids.remove(PANEL_KEY);
+            None
+        }
+    }
+
+    /// Rewrite the history panel, one entry per line, if it is open.
+    fn refresh_command_history_panel(&mut self) {
+        let Some(buffer_id) = self.command_history_panel() else {
+            return;
+        };
+        let entries = self
+            .command_history
+            .iter()
+            .map(|entry| {
+                TextPropertyEntry::text(format!(
+                    "{}  {}\n",
+                    entry.time.format("%H:%M:%S"),
+                    entry.label
+                ))
+            })
+            .collect();
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::error!("Failed to update command history panel: {}", e);
+        }
+    }
+}
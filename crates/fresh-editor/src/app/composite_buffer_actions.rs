@@ -1229,6 +1229,96 @@ impl Editor {
                 0
             };
 
+        let (display_row, clamped_col) =
+            self.composite_position_at(col, row, split_id, buffer_id, content_rect, pane_idx);
+
+        // Update composite buffer's active pane
+        if let Some(composite) = self.composite_buffers.get_mut(&buffer_id) {
+            composite.active_pane = pane_idx;
+        }
+
+        // Update composite view state with click position
+        if let Some(view_state) = self.composite_view_states.get_mut(&(split_id, buffer_id)) {
+            view_state.focused_pane = pane_idx;
+            view_state.cursor_row = display_row;
+            view_state.cursor_column = clamped_col;
+            view_state.sticky_column = clamped_col;
+
+            // Clear selection on click (will start fresh selection on drag)
+            view_state.clear_selection();
+        }
+
+        // Store state for potential text selection drag
+        self.mouse_state.dragging_text_selection = true;
+        self.mouse_state.drag_selection_split = Some(split_id);
+        self.mouse_state.drag_selection_by_words = false;
+
+        // Sync cursor position to EditorState for status bar display
+        self.sync_editor_cursor_from_composite(split_id, buffer_id);
+
+        Ok(())
+    }
+
+    /// Extend the selection in a composite view to the pointer while
+    /// dragging. The selection stays in the pane the drag started in, and
+    /// the view scrolls when the pointer is above or below the content.
+    /// Returns true in that case, so the view keeps scrolling.
+    pub(crate) fn handle_composite_selection_drag(
+        &mut self,
+        col: u16,
+        row: u16,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+    ) -> AnyhowResult<bool> {
+        // The first row is the pane header
+        let first_row = content_rect.y + 1;
+        let last_row = (content_rect.y + content_rect.height.saturating_sub(1)).max(first_row);
+        let scroll_lines = super::click_geometry::drag_scroll_lines(row, first_row, last_row);
+        if scroll_lines != 0 {
+            self.composite_scroll(split_id, buffer_id, scroll_lines);
+        }
+        let row = row.clamp(first_row, last_row);
+
+        let Some(pane_idx) = self
+            .composite_view_states
+            .get(&(split_id, buffer_id))
+            .map(|vs| vs.focused_pane)
+        else {
+            return Ok(false);
+        };
+        let (display_row, clamped_col) =
+            self.composite_position_at(col, row, split_id, buffer_id, content_rect, pane_idx);
+        let max_row = self
+            .composite_buffers
+            .get(&buffer_id)
+            .map(|c| c.row_count().saturating_sub(1))
+            .unwrap_or(0);
+
+        if let Some(view_state) = self.composite_view_states.get_mut(&(split_id, buffer_id)) {
+            if !view_state.visual_mode {
+                view_state.start_visual_selection();
+            }
+            view_state.cursor_row = display_row.min(max_row);
+            view_state.cursor_column = clamped_col;
+            view_state.sticky_column = clamped_col;
+        }
+        self.sync_editor_cursor_from_composite(split_id, buffer_id);
+
+        Ok(scroll_lines != 0)
+    }
+
+    /// Display row and column in `pane_idx` under the screen cell, with the
+    /// column clamped to the length of that line.
+    fn composite_position_at(
+        &self,
+        col: u16,
+        row: u16,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+        pane_idx: usize,
+    ) -> (usize, usize) {
         // Calculate the clicked row (relative to scroll position)
         // Subtract 1 for the header row ("OLD (HEAD)" / "NEW (Working)")
         let content_row = row.saturating_sub(content_rect.y).saturating_sub(1) as usize;
@@ -1291,31 +1381,6 @@ impl Editor {
         };
 
         // Clamp click column to line length
-        let clamped_col = click_col.min(line_length);
-
-        // Update composite buffer's active pane
-        if let Some(composite) = self.composite_buffers.get_mut(&buffer_id) {
-            composite.active_pane = pane_idx;
-        }
-
-        // Update composite view state with click position
-        if let Some(view_state) = self.composite_view_states.get_mut(&(split_id, buffer_id)) {
-            view_state.focused_pane = pane_idx;
-            view_state.cursor_row = display_row;
-            view_state.cursor_column = clamped_col;
-            view_state.sticky_column = clamped_col;
-
-            // Clear selection on click (will start fresh selection on drag)
-            view_state.clear_selection();
-        }
-
-        // Store state for potential text selection drag
-        self.mouse_state.dragging_text_selection = false; // Disable regular text selection for composite
-        self.mouse_state.drag_selection_split = Some(split_id);
-
-        // Sync cursor position to EditorState for status bar display
-        self.sync_editor_cursor_from_composite(split_id, buffer_id);
-
-        Ok(())
+        (display_row, click_col.min(line_length))
     }
 }
@@ -0,0 +1,129 @@
+//! "Explain Diagnostic" on `Editor`.
+//!
+//! Looks up the stored LSP diagnostic under the primary cursor, preferring
+//! the most severe when several overlap. If the server linked the code to
+//! its documentation (`codeDescription.href`) with an http(s) link, the
+//! link is handed to the editor's `url_opener`; otherwise a popup shows the
+//! diagnostic's source, code, any other link and the full message, which
+//! the inline overlay and the panel truncate.
+
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+use ratatui::style::Style;
+use rust_i18n::t;
+
+use crate::services::lsp::diagnostics::diagnostic_code;
+use crate::view::popup::{Popup, PopupPosition};
+
+use super::lsp_requests::lsp_range_contains;
+use super::Editor;
+
+/// Rank of a severity, most severe first.
+fn severity_rank(severity: Option<DiagnosticSeverity>) -> u8 {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) => 0,
+        Some(DiagnosticSeverity::WARNING) => 1,
+        Some(DiagnosticSeverity::INFORMATION) => 2,
+        Some(DiagnosticSeverity::HINT) => 3,
+        _ => 4,
+    }
+}
+
+impl Editor {
+    /// Open the documentation of the diagnostic under the cursor, or show
+    /// its full text in a popup if it has no web link.
+    pub(crate) fn explain_diagnostic_under_cursor(&mut self) {
+        let Some(diagnostic) = self.diagnostic_under_cursor() else {
+            self.set_status_message(t!("diagnostics.none_under_cursor").to_string());
+            return;
+        };
+        // Only web links go to the opener; a server-supplied `file:` or
+        // custom-scheme href is shown in the popup instead
+        let href = diagnostic
+            .code_description
+            .as_ref()
+            .map(|description| &description.href);
+        let is_web = |href: &&lsp_types::Uri| {
+            href.scheme().is_some_and(|scheme| {
+                let scheme = scheme.as_str();
+                scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+            })
+        };
+        if let Some(href) = href.filter(is_web) {
+            let url = href.as_str().to_string();
+            match (self.url_opener)(&url) {
+                Ok(()) => self.set_status_message(t!("status.opening_url", url = url).to_string()),
+                Err(e) => self.set_status_message(
+                    t!("status.open_url_failed", error = e.to_string()).to_string(),
+                ),
+            }
+            return;
+        }
+
+        let mut header = diagnostic.source.clone().unwrap_or_default();
+        if let Some(code) = diagnostic_code(&diagnostic) {
+            if !header.is_empty() {
+                header.push(' ');
+            }
+            header.push_str(&format!("[{}]", code));
+        }
+        let mut lines = Vec::new();
+        if !header.is_empty() {
+            lines.push(header);
+            lines.push(String::new());
+        }
+        lines.extend(diagnostic.message.lines().map(str::to_string));
+        if let Some(href) = href {
+            lines.push(String::new());
+            lines.push(href.as_str().to_string());
+        }
+
+        let mut popup = Popup::text(lines, &self.theme);
+        popup.title = Some(t!("lsp.popup_diagnostic").to_string());
+        popup.transient = true;
+        popup.position = PopupPosition::BelowCursor;
+        popup.width = 60;
+        popup.max_height = 15;
+        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
+        popup.background_style = Style::default().bg(self.theme.popup_bg);
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show(popup);
+        }
+    }
+
+    /// The stored diagnostic covering the primary cursor, or failing that
+    /// one starting on the cursor's line; the most severe wins.
+    fn diagnostic_under_cursor(&self) -> Option<Diagnostic> {
+        let uri = self
+            .buffer_metadata
+            .get(&self.active_buffer())?
+            .file_uri()?;
+        let diagnostics = self.get_stored_diagnostics().get(uri.as_str())?;
+        let position = self.active_cursors().primary().position;
+        let (line, character) = self
+            .active_state()
+            .buffer
+            .position_to_lsp_position(position);
+        let (line, character) = (line as u32, character as u32);
+
+        let most_severe = |candidates: Vec<&Diagnostic>| {
+            candidates
+                .into_iter()
+                .min_by_key(|d| severity_rank(d.severity))
+                .cloned()
+        };
+        most_severe(
+            diagnostics
+                .iter()
+                .filter(|d| lsp_range_contains(&d.range, line, character))
+                .collect(),
+        )
+        .or_else(|| {
+            most_severe(
+                diagnostics
+                    .iter()
+                    .filter(|d| d.range.start.line == line)
+                    .collect(),
+            )
+        })
+    }
+}
@@ -229,6 +229,7 @@ impl Editor {
         // and theme dirs are all collected here and applied synchronously.
         let scan_result =
             crate::services::packages::scan_installed_packages(&dir_context.config_dir);
+        crate::services::spell_check::set_user_dictionary_dir(&dir_context.config_dir);
 
         // Apply package language configs (user config takes priority via or_insert)
         for (lang_id, lang_config) in &scan_result.language_configs {
@@ -288,6 +289,10 @@ impl Editor {
             .configure_for_line_numbers(config.editor.line_numbers);
         state.buffer_settings.tab_size = config.editor.tab_size;
         state.buffer_settings.auto_close = config.editor.auto_close;
+        state.buffer_settings.subword_movement = config.editor.subword_movement;
+        state.buffer_settings.indent_guides = config.editor.show_indent_guides;
+        state.buffer_settings.spell_check = config.editor.spell_check;
+        state.buffer_settings.underline_urls = config.editor.underline_urls;
         // Note: line_wrap_enabled is now stored in SplitViewState.viewport
         tracing::info!("EditorState created for buffer {:?}", buffer_id);
         buffers.insert(buffer_id, state);
@@ -695,6 +700,7 @@ impl Editor {
         let mut editor = Editor {
             buffers,
             event_logs,
+            next_undo_link: 0,
             next_buffer_id: 2,
             config: config_arc,
             config_snapshot_anchor,
@@ -718,6 +724,9 @@ impl Editor {
             pending_grammar_callbacks: Vec::new(),
             theme,
             theme_registry,
+            bundle_theme_dirs: scan_result.bundle_theme_dirs.clone(),
+            theme_file_watch: None,
+            last_theme_file_poll: time_source.now(),
             expanded_menus_cache: crate::view::ui::ExpandedMenusCache::default(),
             theme_cache,
             ansi_background: None,
@@ -725,6 +734,8 @@ impl Editor {
             background_fade: crate::primitives::ansi_background::DEFAULT_BACKGROUND_FADE,
             keybindings,
             clipboard: crate::services::clipboard::Clipboard::new(),
+            yank_cycle: None,
+            scope_selection: None,
             should_quit: false,
             should_detach: false,
             session_mode: false,
@@ -755,6 +766,7 @@ impl Editor {
             authority,
             pending_authority: None,
             remote_indicator_override: None,
+            plugin_status_segments: Vec::new(),
             local_filesystem: Arc::new(crate::model::filesystem::StdFileSystem),
             file_explorer_visible: false,
             file_explorer_sync_in_progress: false,
@@ -787,11 +799,17 @@ impl Editor {
             scheduled_completion_trigger: None,
             completion_service: crate::services::completion::CompletionService::new(),
             dabbrev_state: None,
+            snippet_session: None,
+            recent_buffer_cycle: None,
+            url_opener: open_url::open_in_browser,
             pending_goto_definition_request: None,
             hover: hover::HoverState::default(),
             pending_references_request: None,
             pending_references_symbol: String::new(),
             pending_signature_help_request: None,
+            format_on_save_requests: HashSet::new(),
+            pending_will_saves: HashMap::new(),
+            pending_file_rename: None,
             pending_code_actions_requests: HashSet::new(),
             pending_code_actions_server_names: HashMap::new(),
             pending_code_actions: None,
@@ -799,6 +817,8 @@ impl Editor {
             pending_folding_range_requests: HashMap::new(),
             folding_ranges_in_flight: HashMap::new(),
             folding_ranges_debounce: HashMap::new(),
+            git_gutter_bases: HashMap::new(),
+            git_blames: HashMap::new(),
             pending_semantic_token_requests: HashMap::new(),
             semantic_tokens_in_flight: HashMap::new(),
             pending_semantic_token_range_requests: HashMap::new(),
@@ -848,6 +868,14 @@ impl Editor {
                 }
                 histories
             },
+            recent_files: crate::input::recent_files::RecentFiles::load_from_file(
+                &dir_context.recent_files_path(),
+            )
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load recent files: {}", e);
+                crate::input::recent_files::RecentFiles::new()
+            }),
+            recent_files_save_due: None,
             pending_async_prompt_callback: None,
             pending_next_key_callbacks: std::collections::VecDeque::new(),
             key_capture_active: false,
@@ -863,6 +891,7 @@ impl Editor {
             stored_push_diagnostics: HashMap::new(),
             stored_pull_diagnostics: HashMap::new(),
             stored_diagnostics: Arc::new(HashMap::new()),
+            diagnostics_hidden: false,
             stored_folding_ranges: Arc::new(HashMap::new()),
             event_broadcaster: crate::model::control_event::EventBroadcaster::default(),
             bookmarks: bookmarks::BookmarkState::default(),
@@ -881,6 +910,8 @@ impl Editor {
             pending_auto_start_prompts: std::collections::HashSet::new(),
             lsp_auto_prompt_enabled: super::lsp_auto_prompt::default_enabled(),
             pending_close_buffer: None,
+            pending_workspace_edit: None,
+            hunk_staging: None,
             auto_revert_enabled: true,
             last_auto_revert_poll: time_source.now(),
             last_file_tree_poll: time_source.now(),
@@ -889,6 +920,7 @@ impl Editor {
             dir_mod_times: HashMap::new(),
             pending_file_poll_rx: None,
             pending_dir_poll_rx: None,
+            watched_files: super::watched_files::WatchedFiles::new(time_source.now()),
             file_rapid_change_counts: HashMap::new(),
             file_open_state: None,
             file_browser_layout: None,
@@ -923,6 +955,10 @@ impl Editor {
             update_checker,
             terminal_manager: crate::services::terminal::TerminalManager::new(),
             terminal_buffers: HashMap::new(),
+            task_runs: HashMap::new(),
+            next_task_id: 0,
+            command_history: Vec::new(),
+            toasts: crate::view::toast::ToastManager::new(),
             terminal_backing_files: HashMap::new(),
             terminal_log_files: HashMap::new(),
             ephemeral_terminals: std::collections::HashSet::new(),
@@ -947,6 +983,7 @@ impl Editor {
             completed_waits: Vec::new(),
             stdin_stream: stdin_stream::StdinStream::default(),
             line_scan: line_scan::LineScan::default(),
+            background_saves: background_save::BackgroundSaves::default(),
             search_scan: search_scan::SearchScan::default(),
             search_overlay_top_byte: None,
             review_hunks: Vec::new(),
@@ -962,6 +999,12 @@ impl Editor {
         // Apply clipboard configuration
         editor.clipboard.apply_config(&editor.config.clipboard);
 
+        if editor.config.editor.spell_check
+            && crate::services::spell_check::spell_checker().is_none()
+        {
+            editor.report_missing_dictionary();
+        }
+
         #[cfg(feature = "plugins")]
         {
             editor.update_plugin_state_snapshot();
@@ -853,26 +853,8 @@ impl Editor {
             };
 
             if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                let buffer = &mut state.buffer;
-                let view_state = self.split_view_states.get_mut(&split_id);
-
-                if let Some(view_state) = view_state {
-                    // Recenter viewport on cursor
-                    let cursor = *view_state.cursors.primary();
-                    let viewport_height = view_state.viewport.visible_line_count();
-                    let target_rows_from_top = viewport_height / 2;
-
-                    // Move backwards from cursor position target_rows_from_top lines
-                    let mut iter = buffer.line_iterator(cursor.position, 80);
-                    for _ in 0..target_rows_from_top {
-                        if iter.prev().is_none() {
-                            break;
-                        }
-                    }
-                    let new_top_byte = iter.current_position();
-                    view_state.viewport.top_byte = new_top_byte;
-                    // Mark to skip ensure_visible on next render so the scroll isn't undone
-                    view_state.viewport.set_skip_ensure_visible();
+                if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+                    view_state.center_cursor(&mut state.buffer, &state.marker_list);
                 }
             }
         }
@@ -57,6 +57,7 @@ impl Editor {
         }
 
         self.set_active_buffer(buffer_id);
+        self.record_recent_file(buffer_id);
 
         // If the initial empty buffer was replaced in-place with file content,
         // set_active_buffer is a no-op (same buffer ID). Fire buffer_activated
@@ -247,11 +248,12 @@ impl Editor {
         let mut state = if file_exists {
             // Load from canonical path (for I/O and dedup), detect language from
             // display path (for glob pattern matching against user-visible names).
-            let buffer = crate::model::buffer::Buffer::load_from_file(
+            let mut buffer = crate::model::buffer::Buffer::load_from_file(
                 &canonical_path,
                 self.config.editor.large_file_threshold_bytes as usize,
                 Arc::clone(&self.authority.filesystem),
             )?;
+            buffer.set_max_resident_chunks(self.config.editor.large_file_max_resident_chunks);
             let first_line = buffer.first_line_lossy();
             let detected =
                 crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
@@ -287,6 +289,10 @@ impl Editor {
             crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
         state.buffer_settings.auto_close = self.config.editor.auto_close;
         state.buffer_settings.auto_surround = self.config.editor.auto_surround;
+        state.buffer_settings.subword_movement = self.config.editor.subword_movement;
+        state.buffer_settings.indent_guides = self.config.editor.show_indent_guides;
+        state.buffer_settings.spell_check = self.config.editor.spell_check;
+        state.buffer_settings.underline_urls = self.config.editor.underline_urls;
         if let Some(lang_config) = self.config.languages.get(&state.language) {
             whitespace = whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
             state.buffer_settings.use_tabs =
@@ -312,6 +318,12 @@ impl Editor {
         }
         state.buffer_settings.whitespace = whitespace;
 
+        // .editorconfig overrides the editor and language config
+        state.editorconfig =
+            crate::services::editorconfig::resolve(self.authority.filesystem.as_ref(), path);
+        state.editorconfig.apply(&mut state.buffer_settings);
+        state.editorconfig.apply_format(&mut state.buffer);
+
         // Apply line_numbers default from config
         state
             .margins
@@ -396,6 +408,13 @@ impl Editor {
         // Track file for auto-revert and conflict detection
         self.watch_file(path);
 
+        self.load_git_gutter_base(buffer_id);
+
+        // Without a language server (or before it starts), fold locally
+        if !self.lsp_folding_ranges_available(buffer_id) {
+            self.apply_local_folding_ranges(buffer_id);
+        }
+
         // Fire AfterFileOpen hook for plugins
         self.plugin_manager.run_hook(
             "after_file_open",
@@ -448,11 +467,12 @@ impl Editor {
 
         // Load from canonical path (for I/O and dedup), detect language from
         // display path (for glob pattern matching against user-visible names).
-        let buffer = crate::model::buffer::Buffer::load_from_file(
+        let mut buffer = crate::model::buffer::Buffer::load_from_file(
             &canonical_path,
             self.config.editor.large_file_threshold_bytes as usize,
             Arc::clone(&self.local_filesystem),
         )?;
+        buffer.set_max_resident_chunks(self.config.editor.large_file_max_resident_chunks);
         let first_line = buffer.first_line_lossy();
         let detected =
             crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
@@ -558,6 +578,7 @@ impl Editor {
             Arc::clone(&self.authority.filesystem),
             crate::model::buffer::BufferConfig {
                 estimated_line_length: self.config.editor.estimated_line_length,
+                max_resident_chunks: self.config.editor.large_file_max_resident_chunks,
             },
         )?;
         let first_line = buffer.first_line_lossy();
@@ -642,6 +663,7 @@ impl Editor {
             Arc::clone(&self.authority.filesystem),
             crate::model::buffer::BufferConfig {
                 estimated_line_length: self.config.editor.estimated_line_length,
+                max_resident_chunks: self.config.editor.large_file_max_resident_chunks,
             },
         )?;
 
@@ -8,6 +8,7 @@
 //! - File modification time tracking
 //! - Save conflict detection
 
+use crate::config::AutoSaveMode;
 use crate::model::buffer::SudoSaveRequired;
 use crate::model::filesystem::FileSystem;
 use crate::view::file_tree::FileTreeView;
@@ -21,6 +22,7 @@ use crate::model::event::{BufferId, EventLog};
 use crate::services::lsp::manager::LspSpawnResult;
 use crate::state::EditorState;
 
+use super::background_save::{FinishedSave, SaveEvent};
 use super::{BufferMetadata, Editor};
 
 impl Editor {
@@ -83,6 +85,109 @@ impl Editor {
         }
     }
 
+    /// Save the active buffer, writing files larger than
+    /// `background_save_threshold_bytes` on a background thread.
+    ///
+    /// Used for interactive saves. Callers that need the file on disk when
+    /// this returns (save-and-close) use `save` instead.
+    pub fn start_save(&mut self) -> anyhow::Result<()> {
+        let buffer_id = self.active_buffer();
+        if self.background_saves.is_saving(buffer_id) {
+            self.set_status_message(t!("status.save_in_progress").to_string());
+            return Ok(());
+        }
+
+        let threshold = self.config.editor.background_save_threshold_bytes;
+        let buffer = &self.active_state().buffer;
+        let Some(path) = buffer.file_path().map(|p| p.to_path_buf()) else {
+            return self.save();
+        };
+        if threshold == 0 || (buffer.total_bytes() as u64) <= threshold {
+            return self.save();
+        }
+        let Some(job) = buffer.begin_background_save(&path)? else {
+            return self.save();
+        };
+
+        let total_bytes = job.total_bytes();
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("background-save".to_string())
+            .spawn(move || {
+                let progress_tx = tx.clone();
+                let result = job.run(|written| {
+                    // Receiver may have been dropped during shutdown — that's fine.
+                    if progress_tx.send(SaveEvent::Progress(written)).is_err() {}
+                });
+                if tx.send(SaveEvent::Done(result)).is_err() {}
+            })?;
+
+        // Block edits until the write finishes so the buffer keeps matching
+        // the snapshot being written.
+        let state = self.active_state_mut();
+        let was_editing_disabled = std::mem::replace(&mut state.editing_disabled, true);
+        self.background_saves
+            .start(buffer_id, path, total_bytes, was_editing_disabled, rx);
+        self.show_background_save_progress(buffer_id);
+        Ok(())
+    }
+
+    /// Apply progress and results reported by background save threads.
+    /// Returns `true` if the UI should re-render.
+    pub fn process_background_saves(&mut self) -> bool {
+        let (progressed, finished) = self.background_saves.poll();
+        for buffer_id in &progressed {
+            self.show_background_save_progress(*buffer_id);
+        }
+        let any_finished = !finished.is_empty();
+        for save in finished {
+            self.finish_background_save(save);
+        }
+        !progressed.is_empty() || any_finished
+    }
+
+    fn show_background_save_progress(&mut self, buffer_id: BufferId) {
+        let (Some(percent), Some(path)) = (
+            self.background_saves.progress_percent(buffer_id),
+            self.background_saves.path(buffer_id),
+        ) else {
+            return;
+        };
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.set_status_message(
+            t!("status.saving_progress", name = name, percent = percent).to_string(),
+        );
+    }
+
+    /// Move a finished background save into place, or report its failure.
+    /// On failure the buffer stays modified and editable.
+    fn finish_background_save(&mut self, save: FinishedSave) {
+        let Some(state) = self.buffers.get_mut(&save.buffer_id) else {
+            // Buffer closed mid-save: the user still asked for the write
+            if let Ok(written) = &save.result {
+                if let Err(e) = written.commit(&*self.authority.filesystem) {
+                    tracing::warn!("Failed to finish save of closed buffer: {e}");
+                }
+            }
+            return;
+        };
+        state.editing_disabled = save.was_editing_disabled;
+
+        let mut result = save
+            .result
+            .map_err(anyhow::Error::from)
+            .and_then(|written| state.buffer.finish_background_save(&written));
+        if result.is_ok() {
+            result = self.finalize_save_buffer(save.buffer_id, Some(save.path), false);
+        }
+        if let Err(e) = result {
+            self.set_status_message(t!("file.save_failed", error = e.to_string()).to_string());
+        }
+    }
+
     /// Internal helper to finalize save state (mark as saved, notify LSP, etc.)
     pub(crate) fn finalize_save(&mut self, path: Option<PathBuf>) -> anyhow::Result<()> {
         let buffer_id = self.active_buffer();
@@ -163,6 +268,10 @@ impl Editor {
             );
         }
 
+        // HEAD may have moved since the file was opened
+        self.load_git_gutter_base(buffer_id);
+        self.refresh_git_blame(buffer_id);
+
         // Fire AfterFileSave hook for plugins
         if let Some(ref p) = path {
             self.plugin_manager.run_hook(
@@ -206,7 +315,7 @@ impl Editor {
     /// Auto-save all modified buffers to their original files on disk
     /// Returns the number of buffers saved
     pub fn auto_save_persistent_buffers(&mut self) -> anyhow::Result<usize> {
-        if !self.config.editor.auto_save_enabled {
+        if self.config.editor.auto_save_mode() != AutoSaveMode::AfterDelay {
             return Ok(0);
         }
 
@@ -303,6 +412,54 @@ impl Editor {
         Ok(count)
     }
 
+    /// Save a buffer that focus is leaving, if it was modified and
+    /// `auto_save` is `on_focus_change`. Buffers without a file and
+    /// terminals are left alone.
+    pub(crate) fn auto_save_on_focus_change(&mut self, buffer_id: BufferId) {
+        if self.config.editor.auto_save_mode() != AutoSaveMode::OnFocusChange
+            || self.is_terminal_buffer(buffer_id)
+        {
+            return;
+        }
+        let Some(path) = self
+            .buffers
+            .get(&buffer_id)
+            .filter(|state| state.buffer.is_modified())
+            .and_then(|state| state.buffer.file_path())
+            .filter(|path| !path.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+        else {
+            return;
+        };
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let result = state
+            .buffer
+            .save()
+            .and_then(|()| self.finalize_save_buffer(buffer_id, Some(path.clone()), true));
+        match result {
+            Ok(()) => {}
+            Err(e) if e.downcast_ref::<SudoSaveRequired>().is_some() => {
+                tracing::debug!("Auto-save skipped for {} (sudo required)", path.display());
+            }
+            Err(e) => tracing::warn!("Auto-save failed for {}: {}", path.display(), e),
+        }
+    }
+
+    /// Save all modified file-backed buffers if `auto_save` is on at all,
+    /// e.g. before this client detaches from a session.
+    pub fn flush_auto_save(&mut self) {
+        if self.config.editor.auto_save_mode() == AutoSaveMode::Off {
+            return;
+        }
+        match self.save_all_on_exit() {
+            Ok(count) if count > 0 => tracing::info!("Auto-saved {} buffer(s)", count),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to auto-save: {}", e),
+        }
+    }
+
     /// Revert the active buffer to the last saved version on disk
     /// Returns Ok(true) if reverted, Ok(false) if no file path, Err on failure
     pub fn revert_file(&mut self) -> anyhow::Result<bool> {
@@ -398,6 +555,171 @@ impl Editor {
         Ok(true)
     }
 
+    /// Reload a buffer from its file on disk, discarding unsaved changes.
+    ///
+    /// The active buffer goes through `revert_file` so its viewport is kept;
+    /// any other buffer is reloaded without touching a split's view state.
+    /// Returns Ok(false) if the buffer has no file to reload from.
+    pub fn reload_buffer(&mut self, buffer_id: BufferId) -> anyhow::Result<bool> {
+        let Some(path) = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .map(PathBuf::from)
+        else {
+            return Ok(false);
+        };
+
+        if buffer_id == self.active_buffer() {
+            self.revert_file()
+        } else {
+            self.revert_buffer_by_id(buffer_id, &path)?;
+            Ok(true)
+        }
+    }
+
+    /// Ask what to do about a modified buffer whose file changed on disk:
+    /// reload it, keep the local version, or compare the two side by side.
+    fn prompt_file_changed_on_disk(&mut self, buffer_id: BufferId, path: &Path) {
+        let name = self.get_buffer_display_name(buffer_id);
+        let reload_key = t!("prompt.key.reload").to_string();
+        let keep_key = t!("prompt.key.keep").to_string();
+        let diff_key = t!("prompt.key.diff").to_string();
+        self.start_prompt(
+            t!(
+                "prompt.file_changed_on_disk",
+                name = name,
+                reload_key = reload_key,
+                keep_key = keep_key,
+                diff_key = diff_key
+            )
+            .to_string(),
+            PromptType::ConfirmFileChangedOnDisk {
+                buffer_id,
+                path: path.to_path_buf(),
+            },
+        );
+    }
+
+    /// Open a side-by-side view of a buffer's unsaved content against the
+    /// current content of its file on disk.
+    pub fn show_disk_diff(&mut self, buffer_id: BufferId, path: &Path) -> anyhow::Result<()> {
+        let disk_bytes = self.authority.filesystem.read_file(path)?;
+        let disk_text = String::from_utf8_lossy(&disk_bytes).into_owned();
+        let local_text = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.to_string())
+            .ok_or_else(|| anyhow::anyhow!("buffer is not fully loaded"))?;
+
+        self.open_disk_diff_view(buffer_id, buffer_id, true, &local_text, &disk_text);
+        Ok(())
+    }
+
+    /// Compare the active buffer with its file on disk in a read-only
+    /// side-by-side view. The buffer side is a snapshot of the current
+    /// content, so neither side can be edited from the diff.
+    pub fn diff_against_disk(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(path) = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .map(Path::to_path_buf)
+        else {
+            self.set_status_message(t!("buffer.no_file_to_diff").to_string());
+            return;
+        };
+        let local_text = match self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.to_string())
+        {
+            Some(text) => text,
+            None => {
+                self.set_status_message(
+                    t!(
+                        "buffer.disk_diff_failed",
+                        error = t!("error.buffer_not_loaded").to_string()
+                    )
+                    .to_string(),
+                );
+                return;
+            }
+        };
+        let disk_text = match self.authority.filesystem.read_file(&path) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(e) => {
+                self.set_status_message(
+                    t!("buffer.disk_diff_failed", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
+        let name = self.get_buffer_display_name(buffer_id);
+        let snapshot = self.create_virtual_buffer_detached(
+            t!("buffer.buffer_version_name", name = &name).to_string(),
+            "diff-view".to_string(),
+            true,
+        );
+        if let Some(state) = self.buffers.get_mut(&snapshot) {
+            state.buffer.insert(0, &local_text);
+            state.buffer.clear_modified();
+        }
+        self.open_disk_diff_view(buffer_id, snapshot, false, &local_text, &disk_text);
+    }
+
+    /// Show `local_pane` (holding `local_text`) next to a read-only copy of
+    /// `disk_text` in a new side-by-side composite and focus it
+    fn open_disk_diff_view(
+        &mut self,
+        buffer_id: BufferId,
+        local_pane: BufferId,
+        local_editable: bool,
+        local_text: &str,
+        disk_text: &str,
+    ) {
+        use crate::model::composite_buffer::{CompositeLayout, LineAlignment, SourcePane};
+        use crate::model::line_diff::diff_hunks;
+
+        let name = self.get_buffer_display_name(buffer_id);
+        let disk_buffer = self.create_virtual_buffer_detached(
+            t!("buffer.disk_version_name", name = &name).to_string(),
+            "diff-view".to_string(),
+            true,
+        );
+        if let Some(state) = self.buffers.get_mut(&disk_buffer) {
+            state.buffer.insert(0, disk_text);
+            state.buffer.clear_modified();
+        }
+
+        let hunks = diff_hunks(local_text.as_bytes(), disk_text.as_bytes());
+        let composite = self.create_composite_buffer(
+            t!("buffer.disk_diff_name", name = &name).to_string(),
+            "diff-view".to_string(),
+            CompositeLayout::SideBySide {
+                ratios: vec![0.5, 0.5],
+                show_separator: true,
+            },
+            vec![
+                SourcePane::new(
+                    local_pane,
+                    t!("buffer.disk_diff_local").to_string(),
+                    local_editable,
+                ),
+                SourcePane::new(disk_buffer, t!("buffer.disk_diff_disk").to_string(), false),
+            ],
+        );
+        let alignment = LineAlignment::from_hunks(
+            &hunks,
+            local_text.split('\n').count(),
+            disk_text.split('\n').count(),
+        );
+        self.set_composite_alignment(composite, alignment);
+        self.set_active_buffer_internal(composite);
+    }
+
     /// Toggle auto-revert mode
     pub fn toggle_auto_revert(&mut self) {
         self.auto_revert_enabled = !self.auto_revert_enabled;
@@ -1276,12 +1598,17 @@ impl Editor {
                 continue;
             }
 
-            // If buffer has local modifications, show a warning (don't auto-revert)
+            // If buffer has local modifications, ask before reloading. Another
+            // prompt may be open; the next poll asks again once it closes.
             if state.buffer.is_modified() {
-                self.status_message = Some(format!(
-                    "File {} changed on disk (buffer has unsaved changes)",
-                    path.display()
-                ));
+                if self.is_prompting() {
+                    self.status_message = Some(format!(
+                        "File {} changed on disk (buffer has unsaved changes)",
+                        path.display()
+                    ));
+                } else {
+                    self.prompt_file_changed_on_disk(buffer_id, &path);
+                }
                 continue;
             }
 
@@ -1300,24 +1627,10 @@ impl Editor {
                     continue;
                 }
 
-                // Check if this buffer is currently displayed in the active split
-                let is_active_buffer = buffer_id == self.active_buffer();
-
-                if is_active_buffer {
-                    // Use revert_file() which preserves viewport for active buffer
-                    if let Err(e) = self.revert_file() {
-                        tracing::error!("Failed to auto-revert file {:?}: {}", path, e);
-                    } else {
-                        tracing::info!("Auto-reverted file: {:?}", path);
-                    }
+                if let Err(e) = self.reload_buffer(buffer_id) {
+                    tracing::error!("Failed to auto-revert file {:?}: {}", path, e);
                 } else {
-                    // Use revert_buffer_by_id() which doesn't touch any viewport
-                    // This prevents corrupting the active split's viewport state
-                    if let Err(e) = self.revert_buffer_by_id(buffer_id, &path) {
-                        tracing::error!("Failed to auto-revert background file {:?}: {}", path, e);
-                    } else {
-                        tracing::info!("Auto-reverted file: {:?}", path);
-                    }
+                    tracing::info!("Auto-reverted file: {:?}", path);
                 }
 
                 // Update the modification time tracking for this file
@@ -0,0 +1,248 @@
+//! "Rename File" on `Editor`.
+//!
+//! Moves the active buffer's file on disk and re-points the buffer at the
+//! new path, so its tab and later saves follow. Language servers that
+//! advertise `workspace.fileOperations` hear about it: the rename waits for
+//! their `workspace/willRenameFiles` edits (e.g. updated imports), bounded
+//! by [`WILL_RENAME_FILES_TIMEOUT`], and `workspace/didRenameFiles` follows
+//! once the file has moved. Servers that had the file open get it closed
+//! under the old URI and reopened under the new one.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+
+use super::types::{LspUri, PendingFileRename};
+use super::Editor;
+
+/// How long a rename waits for `workspace/willRenameFiles` answers.
+const WILL_RENAME_FILES_TIMEOUT: Duration = Duration::from_millis(1500);
+
+impl Editor {
+    /// Rename the active buffer's file to `new_path`, relative to the
+    /// working directory unless absolute. Refuses to replace an existing
+    /// file.
+    pub fn rename_current_file(&mut self, new_path: &Path) {
+        if self.pending_file_rename.is_some() {
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let Some(old_path) = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(Path::to_path_buf)
+        else {
+            self.set_status_message(t!("file.rename_no_path").to_string());
+            return;
+        };
+        let new_path = if new_path.is_absolute() {
+            new_path.to_path_buf()
+        } else {
+            self.working_dir.join(new_path)
+        };
+        if new_path == old_path {
+            return;
+        }
+        if self.authority.filesystem.exists(&new_path) {
+            self.set_status_message(
+                t!(
+                    "file.rename_target_exists",
+                    path = new_path.display().to_string()
+                )
+                .to_string(),
+            );
+            return;
+        }
+
+        let requests = self.request_will_rename_files(buffer_id, &old_path, &new_path);
+        if requests.is_empty() {
+            self.finish_file_rename(old_path, new_path);
+            return;
+        }
+        tracing::debug!(
+            "Holding rename of {} for {} willRenameFiles request(s)",
+            old_path.display(),
+            requests.len()
+        );
+        self.pending_file_rename = Some(PendingFileRename {
+            requests,
+            old_path,
+            new_path,
+            deadline: Instant::now() + WILL_RENAME_FILES_TIMEOUT,
+        });
+    }
+
+    /// Handle a workspace/willRenameFiles response: apply its edits and,
+    /// once the last server has answered, move the file.
+    pub(crate) fn handle_will_rename_files_response(
+        &mut self,
+        request_id: u64,
+        edit: Option<lsp_types::WorkspaceEdit>,
+    ) {
+        let Some(pending) = self
+            .pending_file_rename
+            .as_mut()
+            .filter(|pending| pending.requests.contains(&request_id))
+        else {
+            tracing::debug!("Ignoring late willRenameFiles response {}", request_id);
+            return;
+        };
+        pending.requests.remove(&request_id);
+        let done = pending.requests.is_empty();
+
+        if let Some(edit) = edit {
+            if let Err(e) = self.apply_workspace_edit(edit) {
+                tracing::error!("Failed to apply willRenameFiles edits: {}", e);
+            }
+        }
+        if done {
+            if let Some(pending) = self.pending_file_rename.take() {
+                self.finish_file_rename(pending.old_path, pending.new_path);
+            }
+        }
+    }
+
+    /// Move the file of a rename whose willRenameFiles answers did not
+    /// arrive in time. Returns true if it was moved.
+    pub fn check_will_rename_timeout(&mut self) -> bool {
+        let expired = self
+            .pending_file_rename
+            .as_ref()
+            .is_some_and(|pending| Instant::now() >= pending.deadline);
+        if !expired {
+            return false;
+        }
+        tracing::warn!("willRenameFiles timed out, renaming without server edits");
+        if let Some(pending) = self.pending_file_rename.take() {
+            self.finish_file_rename(pending.old_path, pending.new_path);
+        }
+        true
+    }
+
+    /// Send workspace/willRenameFiles to the buffer's servers that want it.
+    /// Returns the ids of the requests sent.
+    fn request_will_rename_files(
+        &mut self,
+        buffer_id: BufferId,
+        old_path: &Path,
+        new_path: &Path,
+    ) -> HashSet<u64> {
+        let mut requests = HashSet::new();
+        let Some(files) = self.file_renames(old_path, new_path) else {
+            return requests;
+        };
+        let Some(language) = self.buffers.get(&buffer_id).map(|s| s.language.clone()) else {
+            return requests;
+        };
+        let Some(lsp) = self.lsp.as_mut() else {
+            return requests;
+        };
+        for sh in lsp.get_handles_mut(&language) {
+            if !(sh.capabilities.initialized && sh.capabilities.will_rename_files) {
+                continue;
+            }
+            let request_id = self.next_lsp_request_id;
+            match sh.handle.will_rename_files(request_id, files.clone()) {
+                Ok(()) => {
+                    self.next_lsp_request_id += 1;
+                    requests.insert(request_id);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to send willRenameFiles to '{}': {}", sh.name, e)
+                }
+            }
+        }
+        requests
+    }
+
+    /// Move the file on disk, re-point its buffer and tell the servers.
+    fn finish_file_rename(&mut self, old_path: PathBuf, new_path: PathBuf) {
+        if let Err(e) = self.authority.filesystem.rename(&old_path, &new_path) {
+            self.set_status_message(t!("file.rename_failed", error = e.to_string()).to_string());
+            return;
+        }
+        let old_uri = LspUri::from_host_path(&old_path, self.authority.path_translation.as_ref());
+        for buffer_id in self.relocate_buffers_for_rename(&old_path, &new_path) {
+            self.reopen_moved_buffer_in_lsp(buffer_id, old_uri.as_ref());
+        }
+        self.notify_did_rename_files(&old_path, &new_path);
+        self.set_status_message(
+            t!("file.renamed", path = new_path.display().to_string()).to_string(),
+        );
+    }
+
+    /// Move a buffer whose metadata now points at a new file (after a
+    /// rename or "Save As") over to it in its language servers: the servers
+    /// that had it open close `old_uri`, and the servers for its (possibly
+    /// new) language open the new URI, starting them if needed.
+    pub(crate) fn reopen_moved_buffer_in_lsp(
+        &mut self,
+        buffer_id: BufferId,
+        old_uri: Option<&LspUri>,
+    ) {
+        let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) else {
+            return;
+        };
+        if metadata.file_uri() == old_uri {
+            return;
+        }
+        let opened_with = std::mem::take(&mut metadata.lsp_opened_with);
+        let reopen = metadata.lsp_enabled;
+        let Some(path) = metadata.file_path().cloned() else {
+            return;
+        };
+        if let (Some(old_uri), Some(lsp)) = (old_uri, self.lsp.as_ref()) {
+            for sh in lsp.all_handles() {
+                if !opened_with.contains(&sh.handle.id()) {
+                    continue;
+                }
+                if let Err(e) = sh.handle.did_close(old_uri.as_uri().clone()) {
+                    tracing::warn!("Failed to send didClose to '{}': {}", sh.name, e);
+                }
+            }
+        }
+        if !reopen {
+            return;
+        }
+        let Some(mut metadata) = self.buffer_metadata.remove(&buffer_id) else {
+            return;
+        };
+        self.notify_lsp_file_opened(&path, buffer_id, &mut metadata);
+        self.buffer_metadata.insert(buffer_id, metadata);
+    }
+
+    /// Send workspace/didRenameFiles to every server that wants it.
+    fn notify_did_rename_files(&mut self, old_path: &Path, new_path: &Path) {
+        let Some(files) = self.file_renames(old_path, new_path) else {
+            return;
+        };
+        let Some(lsp) = self.lsp.as_ref() else {
+            return;
+        };
+        for sh in lsp.all_handles() {
+            if !(sh.capabilities.initialized && sh.capabilities.did_rename_files) {
+                continue;
+            }
+            if let Err(e) = sh.handle.did_rename_files(files.clone()) {
+                tracing::warn!("Failed to send didRenameFiles to '{}': {}", sh.name, e);
+            }
+        }
+    }
+
+    /// The rename as the LSP `FileRename` list, or `None` if either path
+    /// has no URI.
+    fn file_renames(&self, old_path: &Path, new_path: &Path) -> Option<Vec<lsp_types::FileRename>> {
+        let translation = self.authority.path_translation.as_ref();
+        let old_uri = LspUri::from_host_path(old_path, translation)?;
+        let new_uri = LspUri::from_host_path(new_path, translation)?;
+        Some(vec![lsp_types::FileRename {
+            old_uri: old_uri.as_str().to_string(),
+            new_uri: new_uri.as_str().to_string(),
+        }])
+    }
+}
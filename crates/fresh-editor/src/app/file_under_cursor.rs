@@ -0,0 +1,202 @@
+//! "Open file under cursor" on `Editor`.
+//!
+//! The path token around the cursor is the run of characters that can
+//! appear in a path, without the quotes, brackets and trailing punctuation
+//! it is usually wrapped in. A `:line` or `:line:col` suffix (as in
+//! compiler output and stack traces) moves the cursor there after opening.
+//! `~` and `$VAR`/`${VAR}` are expanded, and relative paths are looked up
+//! next to the buffer's file first, then in the working directory.
+
+use std::path::{Path, PathBuf};
+
+use rust_i18n::t;
+
+use crate::view::theme::expand_env_vars;
+
+use super::Editor;
+
+/// A path named in the text, with the 1-indexed line and column that
+/// followed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PathToken {
+    path: String,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+/// Whether `c` can be part of a path token.
+fn is_path_char(c: char) -> bool {
+    !c.is_whitespace()
+        && !matches!(
+            c,
+            '"' | '\'' | '`' | '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' | ',' | ';' | '|'
+        )
+}
+
+/// Find the path token around byte `offset` of `line`. The cursor may also
+/// sit just past the end of the token.
+fn path_token_at(line: &str, offset: usize) -> Option<PathToken> {
+    let offset = offset.min(line.len());
+    let at = |i: usize| line[i..].chars().next().is_some_and(is_path_char);
+    let offset = if offset < line.len() && at(offset) {
+        offset
+    } else {
+        let (prev, _) = line[..offset].char_indices().next_back()?;
+        if !at(prev) {
+            return None;
+        }
+        prev
+    };
+
+    let start = line[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_path_char(c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+    let end = line[offset..]
+        .char_indices()
+        .find(|&(_, c)| !is_path_char(c))
+        .map_or(line.len(), |(i, _)| offset + i);
+    let token = line[start..end].trim_end_matches(['.', ':']);
+
+    split_line_col(token)
+}
+
+/// Split a trailing `:line` or `:line:col` off `token`.
+fn split_line_col(token: &str) -> Option<PathToken> {
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    let mut path = token;
+    let mut numbers = Vec::new();
+    while numbers.len() < 2 {
+        match path.rsplit_once(':') {
+            Some((rest, number)) if is_number(number) && !rest.is_empty() => {
+                numbers.push(number.parse().ok()?);
+                path = rest;
+            }
+            _ => break,
+        }
+    }
+    if path.is_empty() {
+        return None;
+    }
+    let (line, column) = match numbers[..] {
+        [column, line] => (Some(line), Some(column)),
+        [line] => (Some(line), None),
+        _ => (None, None),
+    };
+    Some(PathToken {
+        path: path.to_string(),
+        line,
+        column,
+    })
+}
+
+impl Editor {
+    /// Open the file named by the path under the primary cursor, at its
+    /// `line:col` suffix if it has one.
+    pub(crate) fn open_file_under_cursor(&mut self) {
+        let state = self.active_state();
+        let position = self.active_cursors().primary().position;
+        let line = state.buffer.get_line_number(position);
+        let token = state.buffer.line_start_offset(line).and_then(|start| {
+            let bytes = state.buffer.get_line(line)?;
+            // Decode both halves separately so the cursor offset is measured
+            // in the decoded text even if invalid bytes were replaced.
+            let (before, after) = bytes.split_at((position - start).min(bytes.len()));
+            let before = String::from_utf8_lossy(before);
+            let text = format!("{}{}", before, String::from_utf8_lossy(after));
+            path_token_at(&text, before.len())
+        });
+        let Some(token) = token else {
+            self.set_status_message(t!("file.no_path_under_cursor").to_string());
+            return;
+        };
+
+        let Some(path) = self.resolve_path_under_cursor(&expand_env_vars(&token.path)) else {
+            self.set_status_message(
+                t!("file.not_found_under_cursor", path = token.path).to_string(),
+            );
+            return;
+        };
+        if let Err(e) = self.open_file(&path) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return;
+        }
+        if let Some(line) = token.line {
+            self.goto_line_col(line, token.column);
+        }
+    }
+
+    /// The existing file `path` names, relative to the active buffer's
+    /// directory or else the working directory.
+    fn resolve_path_under_cursor(&self, path: &str) -> Option<PathBuf> {
+        let path = Path::new(path);
+        let candidates = if path.is_absolute() {
+            vec![path.to_path_buf()]
+        } else {
+            let buffer_dir = self
+                .active_state()
+                .buffer
+                .file_path()
+                .and_then(|p| p.parent())
+                .map(|dir| dir.join(path));
+            buffer_dir
+                .into_iter()
+                .chain(std::iter::once(self.working_dir.join(path)))
+                .collect()
+        };
+        candidates.into_iter().find(|candidate| {
+            self.authority
+                .filesystem
+                .is_file(candidate)
+                .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(path: &str, line: Option<usize>, column: Option<usize>) -> Option<PathToken> {
+        Some(PathToken {
+            path: path.to_string(),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn test_path_token_at_extracts_around_cursor() {
+        let line = r#"see "src/main.rs:12:5", then"#;
+        assert_eq!(
+            path_token_at(line, 8),
+            token("src/main.rs", Some(12), Some(5))
+        );
+        assert_eq!(
+            path_token_at("open docs/a.md.", 15),
+            token("docs/a.md", None, None)
+        );
+        assert_eq!(
+            path_token_at("(~/notes.txt:3)", 1),
+            token("~/notes.txt", Some(3), None)
+        );
+        assert_eq!(path_token_at("a  b", 2), None);
+    }
+
+    #[test]
+    fn test_split_line_col() {
+        assert_eq!(split_line_col("foo.rs:3"), token("foo.rs", Some(3), None));
+        assert_eq!(
+            split_line_col("foo.rs:3:9"),
+            token("foo.rs", Some(3), Some(9))
+        );
+        assert_eq!(split_line_col("foo.rs"), token("foo.rs", None, None));
+        assert_eq!(
+            split_line_col("notes:draft"),
+            token("notes:draft", None, None)
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Native git blame annotations on `Editor`.
+//!
+//! `toggle_git_blame` runs `git blame --line-porcelain` on the active
+//! buffer's file on the async runtime and keeps the per-line commits
+//! together with the content that was blamed. When the buffer's version
+//! changed, it is re-diffed against that content with [`diff_hunks`] in the
+//! background, and the resulting line remap is cached for that version.
+//! Every line gets an inline virtual text at its start with the short hash
+//! and author. Lines that were edited since (or were never committed) are
+//! marked "uncommitted". Virtual text is not part of the buffer, so byte
+//! offsets are untouched. Saving re-runs the blame, since the file on disk
+//! changed.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ratatui::style::{Color, Style};
+use rust_i18n::t;
+
+use crate::model::composite_buffer::DiffHunk;
+use crate::model::event::BufferId;
+use crate::model::line_diff::diff_hunks;
+use crate::services::async_bridge::AsyncMessage;
+use crate::view::virtual_text::VirtualTextPosition;
+
+use super::Editor;
+
+/// String id prefix of the blame virtual texts, for bulk removal.
+const GIT_BLAME_ID_PREFIX: &str = "git-blame-native:";
+
+/// Width the author column is padded or truncated to.
+const AUTHOR_WIDTH: usize = 12;
+
+/// Length of the abbreviated commit hash.
+const SHORT_HASH_LEN: usize = 7;
+
+/// The commit that last touched a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BlameCommit {
+    hash: String,
+    author: String,
+}
+
+/// Result of one `git blame`: the content that was blamed and the commit
+/// for each of its lines (`None` for lines git reports as not committed
+/// yet).
+#[derive(Debug)]
+struct Blamed {
+    content: Arc<Vec<u8>>,
+    lines: Vec<Option<BlameCommit>>,
+}
+
+/// Blame state for one buffer with annotations turned on.
+#[derive(Debug, Default)]
+pub(crate) struct GitBlame {
+    /// `None` until the first `git blame` finishes
+    blamed: Option<Blamed>,
+    /// Report the outcome of the running blame in the status bar (the user
+    /// just turned blame on, as opposed to a refresh after saving)
+    announce: bool,
+    /// Buffer version and, for each of its lines, the blamed line it still
+    /// matches
+    origins: Option<(u64, Vec<Option<usize>>)>,
+    /// Buffer version a background diff is running for
+    diffing_version: Option<u64>,
+    /// Buffer and virtual text versions the annotations were drawn for
+    annotated: Option<(u64, u32)>,
+}
+
+/// Parse `git blame --line-porcelain` output into one entry per line.
+fn parse_line_porcelain(output: &str) -> Vec<Option<BlameCommit>> {
+    let mut lines = Vec::new();
+    let mut hash: Option<&str> = None;
+    let mut author = "";
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            let commit = hash.take().and_then(|hash| {
+                if hash.bytes().all(|b| b == b'0') {
+                    None
+                } else {
+                    Some(BlameCommit {
+                        hash: hash.chars().take(SHORT_HASH_LEN).collect(),
+                        author: author.to_string(),
+                    })
+                }
+            });
+            lines.push(commit);
+        } else if let Some(name) = line.strip_prefix("author ") {
+            author = name;
+        } else if hash.is_none() {
+            hash = line
+                .split(' ')
+                .next()
+                .filter(|h| h.len() == 40 && h.bytes().all(|b| b.is_ascii_hexdigit()));
+        }
+    }
+    lines
+}
+
+/// For each of the `line_count` current lines, the blamed line it still
+/// matches, or `None` if it is inside a hunk (edited or inserted since).
+fn line_origins(hunks: &[DiffHunk], line_count: usize) -> Vec<Option<usize>> {
+    let mut origins = Vec::with_capacity(line_count);
+    let mut old_line = 0;
+    for hunk in hunks {
+        while origins.len() < hunk.new_start.min(line_count) {
+            origins.push(Some(old_line));
+            old_line += 1;
+        }
+        origins.resize(origins.len() + hunk.new_count, None);
+        old_line = hunk.old_start + hunk.old_count;
+    }
+    while origins.len() < line_count {
+        origins.push(Some(old_line));
+        old_line += 1;
+    }
+    origins.truncate(line_count);
+    origins
+}
+
+/// Fixed-width annotation text for a line.
+fn annotation(commit: Option<&BlameCommit>) -> String {
+    match commit {
+        Some(commit) => {
+            let author: String = commit.author.chars().take(AUTHOR_WIDTH).collect();
+            format!(
+                "{:<hash$} {:<author$}",
+                commit.hash,
+                author,
+                hash = SHORT_HASH_LEN,
+                author = AUTHOR_WIDTH
+            )
+        }
+        None => format!(
+            "{:<width$}",
+            t!("buffer.blame_uncommitted"),
+            width = SHORT_HASH_LEN + 1 + AUTHOR_WIDTH
+        ),
+    }
+}
+
+impl Editor {
+    /// Show or hide blame annotations for the active buffer. The
+    /// annotations (or an error) appear once `git blame` finishes.
+    pub(crate) fn toggle_git_blame(&mut self) {
+        let buffer_id = self.active_buffer();
+        if self.git_blames.remove(&buffer_id).is_some() {
+            self.clear_git_blame_annotations(buffer_id);
+            self.set_status_message(t!("toggle.git_blame_hidden").to_string());
+            return;
+        }
+        self.git_blames.insert(
+            buffer_id,
+            GitBlame {
+                announce: true,
+                ..GitBlame::default()
+            },
+        );
+        if !self.load_git_blame(buffer_id) {
+            self.git_blames.remove(&buffer_id);
+            self.set_status_message(t!("status.blame_unavailable").to_string());
+        }
+    }
+
+    /// Re-run the blame for a buffer that has annotations shown, e.g. after
+    /// it was saved. The current annotations stay until it finishes.
+    pub(crate) fn refresh_git_blame(&mut self, buffer_id: BufferId) {
+        if self.git_blames.contains_key(&buffer_id) && !self.load_git_blame(buffer_id) {
+            self.git_blames.remove(&buffer_id);
+            self.clear_git_blame_annotations(buffer_id);
+        }
+    }
+
+    /// Start `git blame --line-porcelain -- ./<file>` next to the buffer's
+    /// file on the async runtime; [`Self::handle_git_blame_loaded`] stores
+    /// the result. Returns `false` when the buffer has no file or is too
+    /// large, or there is no runtime to run git on.
+    fn load_git_blame(&mut self, buffer_id: BufferId) -> bool {
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .cloned()
+        else {
+            return false;
+        };
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return false;
+        };
+        if state.buffer.len() as u64 > self.config.editor.large_file_threshold_bytes {
+            return false;
+        }
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return false;
+        };
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            return false;
+        };
+
+        let args = vec![
+            "blame".to_string(),
+            "--line-porcelain".to_string(),
+            "--".to_string(),
+            format!("./{}", file_name.to_string_lossy()),
+        ];
+        let cwd = Some(dir.to_string_lossy().to_string());
+        let sender = bridge.sender();
+        let spawner = self.authority.process_spawner.clone();
+        let filesystem = self.authority.filesystem.clone();
+        runtime.spawn(async move {
+            let blame = match spawner.spawn("git".to_string(), args, cwd).await {
+                Ok(output) if output.exit_code == 0 => filesystem
+                    .read_file(&path)
+                    .ok()
+                    .map(|content| (output.stdout, content)),
+                _ => None,
+            };
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::GitBlameLoaded {
+                buffer_id,
+                path,
+                blame,
+            });
+        });
+        true
+    }
+
+    /// Store a finished `git blame` (porcelain output and the blamed file
+    /// content) for a buffer that still shows blame for `path`. A failed
+    /// blame turns the annotations off.
+    pub(crate) fn handle_git_blame_loaded(
+        &mut self,
+        buffer_id: BufferId,
+        path: &PathBuf,
+        blame: Option<(String, Vec<u8>)>,
+    ) {
+        let current_path = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path());
+        if current_path != Some(path) {
+            return;
+        }
+        let Some(entry) = self.git_blames.get_mut(&buffer_id) else {
+            return;
+        };
+        let announce = std::mem::take(&mut entry.announce);
+        let Some((output, content)) = blame else {
+            self.git_blames.remove(&buffer_id);
+            self.clear_git_blame_annotations(buffer_id);
+            if announce {
+                self.set_status_message(t!("status.blame_unavailable").to_string());
+            }
+            return;
+        };
+        *entry = GitBlame {
+            blamed: Some(Blamed {
+                content: Arc::new(content),
+                lines: parse_line_porcelain(&output),
+            }),
+            ..GitBlame::default()
+        };
+        if announce {
+            self.set_status_message(t!("toggle.git_blame_shown").to_string());
+        }
+        self.update_git_blame(buffer_id);
+    }
+
+    /// Bring the blame annotations of a buffer up to date: start a
+    /// background diff if the buffer changed since its line remap was
+    /// computed, and redraw if the remap or the virtual texts changed since
+    /// the last draw. No-op for buffers without blame shown.
+    pub(crate) fn update_git_blame(&mut self, buffer_id: BufferId) {
+        let Some(blame) = self.git_blames.get_mut(&buffer_id) else {
+            return;
+        };
+        let Some(blamed) = &blame.blamed else {
+            return;
+        };
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return;
+        };
+        let version = state.buffer.version();
+        if blame.origins.as_ref().is_some_and(|(v, _)| *v == version) {
+            self.draw_git_blame(buffer_id);
+            return;
+        }
+        if blame.diffing_version.is_some()
+            || state.buffer.len() as u64 > self.config.editor.large_file_threshold_bytes
+        {
+            return;
+        }
+        let Some(current) = state.buffer.to_string() else {
+            return;
+        };
+        let content = blamed.content.clone();
+
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            let origins = blame_origins(&content, &current);
+            blame.origins = Some((version, origins));
+            self.draw_git_blame(buffer_id);
+            return;
+        };
+        blame.diffing_version = Some(version);
+        let sender = bridge.sender();
+        runtime.spawn_blocking(move || {
+            let origins = blame_origins(&content, &current);
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::GitBlameDiffed {
+                buffer_id,
+                content,
+                version,
+                origins,
+            });
+        });
+    }
+
+    /// Cache the line remap of a background diff and redraw, unless the
+    /// blame was re-run or the buffer changed since it started. A changed
+    /// buffer is diffed again on the next render.
+    pub(crate) fn handle_git_blame_diffed(
+        &mut self,
+        buffer_id: BufferId,
+        content: &Arc<Vec<u8>>,
+        version: u64,
+        origins: Vec<Option<usize>>,
+    ) {
+        let Some(blame) = self.git_blames.get_mut(&buffer_id) else {
+            return;
+        };
+        let same_blame = blame
+            .blamed
+            .as_ref()
+            .is_some_and(|blamed| Arc::ptr_eq(&blamed.content, content));
+        if !same_blame || blame.diffing_version != Some(version) {
+            return;
+        }
+        blame.diffing_version = None;
+        if self.buffers.get(&buffer_id).map(|s| s.buffer.version()) != Some(version) {
+            return;
+        }
+        blame.origins = Some((version, origins));
+        self.draw_git_blame(buffer_id);
+    }
+
+    /// Redraw the annotations from the cached line remap if the buffer or
+    /// its virtual texts changed since they were last drawn.
+    fn draw_git_blame(&mut self, buffer_id: BufferId) {
+        let Some(blame) = self.git_blames.get_mut(&buffer_id) else {
+            return;
+        };
+        let (Some(blamed), Some((version, origins))) = (&blame.blamed, &blame.origins) else {
+            return;
+        };
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        if state.buffer.version() != *version
+            || blame.annotated == Some((*version, state.virtual_texts.version()))
+        {
+            return;
+        }
+        let buffer_len = state.buffer.len();
+        let style = Style::default().fg(Color::Rgb(128, 128, 128));
+
+        state
+            .virtual_texts
+            .remove_by_prefix(&mut state.marker_list, GIT_BLAME_ID_PREFIX);
+        for (line, origin) in origins.iter().enumerate() {
+            let Some(byte_offset) = state.buffer.line_start_offset(line) else {
+                continue;
+            };
+            // The empty line after a trailing newline has no character to
+            // anchor to.
+            if byte_offset >= buffer_len {
+                continue;
+            }
+            let commit = origin
+                .and_then(|old| blamed.lines.get(old))
+                .and_then(|c| c.as_ref());
+            state.virtual_texts.add_with_id_and_theme_keys(
+                &mut state.marker_list,
+                byte_offset,
+                annotation(commit),
+                style,
+                Some("editor.line_number_fg".to_string()),
+                None,
+                VirtualTextPosition::BeforeChar,
+                -10,
+                format!("{}{}", GIT_BLAME_ID_PREFIX, line),
+            );
+        }
+        blame.annotated = Some((*version, state.virtual_texts.version()));
+    }
+
+    fn clear_git_blame_annotations(&mut self, buffer_id: BufferId) {
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state
+                .virtual_texts
+                .remove_by_prefix(&mut state.marker_list, GIT_BLAME_ID_PREFIX);
+        }
+    }
+}
+
+/// For each line of `current`, the line of the blamed `content` it still
+/// matches.
+fn blame_origins(content: &[u8], current: &str) -> Vec<Option<usize>> {
+    let hunks = diff_hunks(content, current.as_bytes());
+    line_origins(&hunks, current.split('\n').count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_porcelain() {
+        let output = "\
+1234567890abcdef1234567890abcdef12345678 1 1 1
+author Alice
+author-mail <alice@example.com>
+summary first
+filename a.txt
+\tone
+0000000000000000000000000000000000000000 2 2 1
+author Not Committed Yet
+filename a.txt
+\ttwo
+";
+        assert_eq!(
+            parse_line_porcelain(output),
+            vec![
+                Some(BlameCommit {
+                    hash: "1234567".to_string(),
+                    author: "Alice".to_string(),
+                }),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_origins_skip_edited_lines() {
+        let hunks = diff_hunks(b"a\nb\nc\nd\n", b"a\nB\nx\nc\nd\n");
+        assert_eq!(
+            line_origins(&hunks, 6),
+            vec![Some(0), None, None, Some(2), Some(3), Some(4)]
+        );
+    }
+
+    #[test]
+    fn test_line_origins_after_deletion() {
+        let hunks = diff_hunks(b"a\nb\nc\n", b"a\nc\n");
+        assert_eq!(line_origins(&hunks, 3), vec![Some(0), Some(2), Some(3)]);
+    }
+}
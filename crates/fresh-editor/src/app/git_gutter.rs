@@ -0,0 +1,473 @@
+//! Native git gutter on `Editor`.
+//!
+//! When `editor.show_git_gutter` is on, the file's blob at git HEAD is
+//! fetched in the background on open (and again after each save, in case
+//! HEAD moved) and kept as the diff base. Before each render a buffer whose
+//! version changed is re-diffed against that base with [`diff_hunks`] on
+//! the async runtime, one diff per buffer at a time, and the resulting
+//! hunks are turned into line indicators, so the signs follow unsaved
+//! edits. Buffers above `large_file_threshold_bytes` get no signs.
+//!
+//! The same base backs `revert_hunk_at_cursor`, which puts the HEAD lines of
+//! the hunk under the cursor back into the buffer. Without a gutter base the
+//! HEAD blob is fetched in the background first. Hunk staging (`git_stage`)
+//! reuses the byte-range logic.
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rust_i18n::t;
+
+use crate::model::composite_buffer::DiffHunk;
+use crate::model::event::{BufferId, Event};
+use crate::model::line_diff::diff_hunks;
+use crate::services::async_bridge::AsyncMessage;
+use crate::view::margin::LineIndicator;
+
+use super::Editor;
+
+/// Margin namespace for the native git gutter signs.
+const GIT_GUTTER_NAMESPACE: &str = "git-gutter-native";
+
+/// Above the plugin git gutter (10), below diagnostics.
+const GIT_GUTTER_PRIORITY: i32 = 15;
+
+/// Diff base for one buffer: the file content at git HEAD, the buffer
+/// version the current signs were computed from and the version a
+/// background diff is running for.
+#[derive(Debug)]
+pub(crate) struct GitGutterBase {
+    content: Arc<Vec<u8>>,
+    diffed_version: Option<u64>,
+    diffing_version: Option<u64>,
+}
+
+/// Sign kind for a line, derived from the hunk it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GitSign {
+    Added,
+    Modified,
+    Deleted,
+}
+
+/// Map hunks to per-line signs. Deletions are marked on the line that now
+/// follows the removed lines (the last line when they were removed from the
+/// end of the file).
+fn signs_for_hunks(hunks: &[DiffHunk], line_count: usize) -> Vec<(usize, GitSign)> {
+    let last_line = line_count.saturating_sub(1);
+    let mut signs = Vec::new();
+    for hunk in hunks {
+        let new_lines = hunk.new_start..hunk.new_start + hunk.new_count;
+        match (hunk.old_count, hunk.new_count) {
+            (0, _) => signs.extend(new_lines.map(|line| (line, GitSign::Added))),
+            (_, 0) => signs.push((hunk.new_start.min(last_line), GitSign::Deleted)),
+            _ => signs.extend(new_lines.map(|line| (line, GitSign::Modified))),
+        }
+    }
+    signs
+}
+
+/// Whether `line` falls in the hunk, using the same line the gutter puts a
+/// deletion sign on.
+fn hunk_contains_line(hunk: &DiffHunk, line: usize, line_count: usize) -> bool {
+    if hunk.new_count == 0 {
+        hunk.new_start.min(line_count.saturating_sub(1)) == line
+    } else {
+        line >= hunk.new_start && line < hunk.new_start + hunk.new_count
+    }
+}
+
+/// Byte range covered by `count` lines starting at `start` in `text`.
+///
+/// Lines own their trailing newline. A hunk that runs to the end of the text
+/// instead owns the newline *before* it, so the last line's missing or
+/// present trailing newline round-trips when one side replaces the other.
+pub(super) fn hunk_byte_range(
+    text: &[u8],
+    start: usize,
+    count: usize,
+    at_end: bool,
+) -> Range<usize> {
+    let mut line_starts = vec![0];
+    line_starts.extend(
+        text.iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+            .map(|(i, _)| i + 1),
+    );
+    // A virtual line past the end, one newline beyond the text.
+    let line_start = |line: usize| line_starts.get(line).copied().unwrap_or(text.len() + 1);
+
+    if at_end {
+        let begin = if start == 0 { 0 } else { line_start(start) - 1 };
+        begin..text.len()
+    } else {
+        line_start(start)..line_start(start + count)
+    }
+}
+
+impl Editor {
+    /// Fetch the HEAD version of a buffer's file as its git gutter base.
+    /// `git show` runs on the async runtime; the base is installed by
+    /// [`Self::handle_git_head_blob_loaded`] when it finishes.
+    pub(crate) fn load_git_gutter_base(&mut self, buffer_id: BufferId) {
+        if !self.config.editor.show_git_gutter {
+            return;
+        }
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .cloned()
+        else {
+            return;
+        };
+        let too_large = self.buffers.get(&buffer_id).is_some_and(|state| {
+            state.buffer.len() as u64 > self.config.editor.large_file_threshold_bytes
+        });
+        if too_large {
+            self.handle_git_head_blob_loaded(buffer_id, &path, None);
+            return;
+        }
+        self.spawn_git_head_blob(path, move |path, content| AsyncMessage::GitHeadBlobLoaded {
+            buffer_id,
+            path,
+            content,
+        });
+    }
+
+    /// Run `git show HEAD:./<file>` next to `path` on the async runtime and
+    /// send the message `done` builds from its output (`None` when the file
+    /// is not tracked or git failed). Uses the `ProcessSpawner` so it works
+    /// on both local and remote filesystems. Returns `false` when there is no
+    /// runtime to run git on.
+    fn spawn_git_head_blob(
+        &self,
+        path: PathBuf,
+        done: impl FnOnce(PathBuf, Option<Vec<u8>>) -> AsyncMessage + Send + 'static,
+    ) -> bool {
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return false;
+        };
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            return false;
+        };
+
+        let args = vec![
+            "show".to_string(),
+            format!("HEAD:./{}", file_name.to_string_lossy()),
+        ];
+        let cwd = Some(dir.to_string_lossy().to_string());
+        let sender = bridge.sender();
+        let spawner = self.authority.process_spawner.clone();
+        runtime.spawn(async move {
+            let content = match spawner.spawn("git".to_string(), args, cwd).await {
+                Ok(output) if output.exit_code == 0 => Some(output.stdout.into_bytes()),
+                _ => None,
+            };
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(done(path, content));
+        });
+        true
+    }
+
+    /// Install a fetched HEAD blob as the buffer's git gutter base. Buffers
+    /// whose file is not tracked (or not in a repository) get no base, which
+    /// clears their signs. Results for a buffer that was closed or now shows
+    /// another file are dropped.
+    pub(crate) fn handle_git_head_blob_loaded(
+        &mut self,
+        buffer_id: BufferId,
+        path: &Path,
+        content: Option<Vec<u8>>,
+    ) {
+        let current_path = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path());
+        if current_path.map(|p| p.as_path()) != Some(path) {
+            return;
+        }
+
+        match content {
+            Some(content) => {
+                self.git_gutter_bases.insert(
+                    buffer_id,
+                    GitGutterBase {
+                        content: Arc::new(content),
+                        diffed_version: None,
+                        diffing_version: None,
+                    },
+                );
+                self.update_git_gutter(buffer_id);
+            }
+            None => {
+                self.git_gutter_bases.remove(&buffer_id);
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state
+                        .margins
+                        .clear_line_indicators_for_namespace(GIT_GUTTER_NAMESPACE);
+                }
+            }
+        }
+    }
+
+    /// Whether a buffer has its git gutter base loaded
+    #[doc(hidden)]
+    pub fn has_git_gutter_base(&self, buffer_id: BufferId) -> bool {
+        self.git_gutter_bases.contains_key(&buffer_id)
+    }
+
+    /// Start re-diffing a buffer against its git base if it changed since
+    /// the last diff and no diff is running for it yet. No-op for buffers
+    /// without a git base.
+    pub(crate) fn update_git_gutter(&mut self, buffer_id: BufferId) {
+        let Some(base) = self.git_gutter_bases.get_mut(&buffer_id) else {
+            return;
+        };
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let version = state.buffer.version();
+        if base.diffed_version == Some(version) || base.diffing_version.is_some() {
+            return;
+        }
+        if state.buffer.len() as u64 > self.config.editor.large_file_threshold_bytes {
+            base.diffed_version = Some(version);
+            state
+                .margins
+                .clear_line_indicators_for_namespace(GIT_GUTTER_NAMESPACE);
+            return;
+        }
+        let Some(current) = state.buffer.to_string() else {
+            return;
+        };
+        let base_content = base.content.clone();
+
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            let hunks = diff_hunks(&base_content, current.as_bytes());
+            let line_count = current.split('\n').count();
+            base.diffed_version = Some(version);
+            self.set_git_gutter_signs(buffer_id, &hunks, line_count);
+            return;
+        };
+        base.diffing_version = Some(version);
+        let sender = bridge.sender();
+        runtime.spawn_blocking(move || {
+            let hunks = diff_hunks(&base_content, current.as_bytes());
+            let line_count = current.split('\n').count();
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::GitGutterDiffed {
+                buffer_id,
+                base: base_content,
+                version,
+                hunks,
+                line_count,
+            });
+        });
+    }
+
+    /// Install the signs of a background diff, unless the base was replaced
+    /// or the buffer changed since it started. A changed buffer is diffed
+    /// again on the next render.
+    pub(crate) fn handle_git_gutter_diffed(
+        &mut self,
+        buffer_id: BufferId,
+        base: &Arc<Vec<u8>>,
+        version: u64,
+        hunks: &[DiffHunk],
+        line_count: usize,
+    ) {
+        let Some(current_base) = self.git_gutter_bases.get_mut(&buffer_id) else {
+            return;
+        };
+        if !Arc::ptr_eq(&current_base.content, base)
+            || current_base.diffing_version != Some(version)
+        {
+            return;
+        }
+        current_base.diffing_version = None;
+        let current_version = self.buffers.get(&buffer_id).map(|s| s.buffer.version());
+        if current_version != Some(version) {
+            return;
+        }
+        current_base.diffed_version = Some(version);
+        self.set_git_gutter_signs(buffer_id, hunks, line_count);
+    }
+
+    /// Replace a buffer's git gutter signs with those for `hunks`.
+    fn set_git_gutter_signs(&mut self, buffer_id: BufferId, hunks: &[DiffHunk], line_count: usize) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let theme = &self.theme;
+
+        state
+            .margins
+            .clear_line_indicators_for_namespace(GIT_GUTTER_NAMESPACE);
+        for (line, sign) in signs_for_hunks(hunks, line_count) {
+            let (symbol, color) = match sign {
+                GitSign::Added => ("+", theme.file_status_added_fg),
+                GitSign::Modified => ("~", theme.file_status_modified_fg),
+                GitSign::Deleted => ("-", theme.file_status_deleted_fg),
+            };
+            let Some(byte_offset) = state.buffer.line_start_offset(line) else {
+                continue;
+            };
+            state.margins.set_line_indicator(
+                byte_offset,
+                GIT_GUTTER_NAMESPACE.to_string(),
+                LineIndicator::new(symbol, color, GIT_GUTTER_PRIORITY),
+            );
+        }
+    }
+
+    /// Replace the git hunk under the primary cursor with its HEAD lines, as
+    /// a single undoable edit. Uses the git gutter base when there is one;
+    /// otherwise HEAD is fetched in the background and the revert happens
+    /// when it arrives.
+    pub(crate) fn revert_hunk_at_cursor(&mut self) {
+        let buffer_id = self.active_buffer();
+        if let Some(base) = self.git_gutter_bases.get(&buffer_id) {
+            let base = base.content.clone();
+            self.revert_hunk_against(&base);
+            return;
+        }
+        let path = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .cloned();
+        let version = self.active_state().buffer.version();
+        let started = path.is_some_and(|path| {
+            self.spawn_git_head_blob(path, move |path, content| {
+                AsyncMessage::GitRevertBlobLoaded {
+                    buffer_id,
+                    path,
+                    version,
+                    content,
+                }
+            })
+        });
+        if !started {
+            self.set_status_message(t!("status.no_hunk_at_cursor").to_string());
+        }
+    }
+
+    /// Finish a hunk revert that had to fetch HEAD first. Dropped when the
+    /// user moved to another buffer or edited this one in the meantime.
+    pub(crate) fn handle_git_revert_blob_loaded(
+        &mut self,
+        buffer_id: BufferId,
+        path: &Path,
+        version: u64,
+        content: Option<Vec<u8>>,
+    ) {
+        let current_path = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path());
+        if self.active_buffer() != buffer_id
+            || current_path.map(|p| p.as_path()) != Some(path)
+            || self.active_state().buffer.version() != version
+        {
+            return;
+        }
+        match content {
+            Some(base) => self.revert_hunk_against(&base),
+            None => self.set_status_message(t!("status.no_hunk_at_cursor").to_string()),
+        }
+    }
+
+    /// Replace the hunk under the primary cursor, diffing the active buffer
+    /// against `base`, with the lines it had in `base`.
+    fn revert_hunk_against(&mut self, base: &[u8]) {
+        let Some(current) = self.active_state().buffer.to_string() else {
+            return;
+        };
+
+        let cursor_position = self.active_cursors().primary().position;
+        let cursor_line = self.active_state().buffer.get_line_number(cursor_position);
+        let old_line_count = base.split(|&b| b == b'\n').count();
+        let new_line_count = current.split('\n').count();
+        let hunks = diff_hunks(base, current.as_bytes());
+        let Some(hunk) = hunks
+            .iter()
+            .find(|h| hunk_contains_line(h, cursor_line, new_line_count))
+        else {
+            self.set_status_message(t!("status.no_hunk_at_cursor").to_string());
+            return;
+        };
+
+        let at_end = hunk.old_start + hunk.old_count == old_line_count
+            && hunk.new_start + hunk.new_count == new_line_count;
+        let old_range = hunk_byte_range(base, hunk.old_start, hunk.old_count, at_end);
+        let new_range = hunk_byte_range(current.as_bytes(), hunk.new_start, hunk.new_count, at_end);
+        let original = String::from_utf8_lossy(&base[old_range]).into_owned();
+
+        let cursor_id = self.active_cursors().primary_id();
+        let mut events = Vec::new();
+        if !new_range.is_empty() {
+            events.push(Event::Delete {
+                range: new_range.clone(),
+                deleted_text: current[new_range.clone()].to_string(),
+                cursor_id,
+            });
+        }
+        if !original.is_empty() {
+            events.push(Event::Insert {
+                position: new_range.start,
+                text: original,
+                cursor_id,
+            });
+        }
+
+        let batch = Event::Batch {
+            events,
+            description: "Revert hunk".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+        self.set_status_message(t!("status.hunk_reverted").to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revert(old: &str, new: &str) -> String {
+        let hunks = diff_hunks(old.as_bytes(), new.as_bytes());
+        assert_eq!(hunks.len(), 1, "expected a single hunk");
+        let hunk = &hunks[0];
+        let at_end = hunk.old_start + hunk.old_count == old.split('\n').count()
+            && hunk.new_start + hunk.new_count == new.split('\n').count();
+        let old_range = hunk_byte_range(old.as_bytes(), hunk.old_start, hunk.old_count, at_end);
+        let new_range = hunk_byte_range(new.as_bytes(), hunk.new_start, hunk.new_count, at_end);
+        format!(
+            "{}{}{}",
+            &new[..new_range.start],
+            &old[old_range],
+            &new[new_range.end..]
+        )
+    }
+
+    #[test]
+    fn test_revert_middle_line() {
+        assert_eq!(revert("a\nb\nc\n", "a\nB\nc\n"), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn test_revert_last_line_without_trailing_newline() {
+        assert_eq!(revert("a\nb", "a\nB"), "a\nb");
+        assert_eq!(revert("a\nb", "a\nb\nc"), "a\nb");
+        assert_eq!(revert("a\nb\n", "a\nB"), "a\nb\n");
+    }
+
+    #[test]
+    fn test_revert_deleted_lines() {
+        assert_eq!(revert("a\nb\nc\n", "a\nc\n"), "a\nb\nc\n");
+        assert_eq!(revert("a\nb", "a"), "a\nb");
+    }
+}
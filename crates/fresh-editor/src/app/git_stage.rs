@@ -0,0 +1,476 @@
+//! Interactive hunk staging, like `git add -p`.
+//!
+//! "Stage Hunks" diffs the active file's working tree content against its
+//! version in the git index and walks through the hunks one at a time in a
+//! side-by-side view. Each hunk can be staged, skipped or split into
+//! single-line changes. When the walk ends the index entry is replaced with
+//! the index content plus the staged hunks; cancelling leaves the index
+//! untouched. Git runs on the async runtime, so reading and writing the
+//! index never blocks the UI thread.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rust_i18n::t;
+
+use super::git_gutter::hunk_byte_range;
+use super::Editor;
+use crate::model::composite_buffer::{CompositeLayout, DiffHunk, LineAlignment, SourcePane};
+use crate::model::event::BufferId;
+use crate::model::filesystem::FileSystem;
+use crate::model::line_diff::diff_hunks;
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::remote::ProcessSpawner;
+use crate::view::prompt::PromptType;
+
+/// A hunk staging walk in progress
+pub(crate) struct HunkStaging {
+    path: PathBuf,
+    /// File content in the git index
+    index: Vec<u8>,
+    /// File content in the working tree
+    worktree: Vec<u8>,
+    hunks: Vec<DiffHunk>,
+    /// Hunk currently shown
+    current: usize,
+    /// Hunks chosen for staging, in file order
+    staged: Vec<DiffHunk>,
+    /// Diff view and the two snapshot buffers backing it
+    composite: BufferId,
+    preview_buffers: Vec<BufferId>,
+    /// Buffer that was active before the walk started
+    return_to: BufferId,
+}
+
+/// Split a hunk into one hunk per line pair, so each changed line can be
+/// staged on its own. Returns `None` for single-line hunks.
+fn split_hunk(hunk: &DiffHunk) -> Option<Vec<DiffHunk>> {
+    let lines = hunk.old_count.max(hunk.new_count);
+    if lines < 2 {
+        return None;
+    }
+    Some(
+        (0..lines)
+            .map(|i| {
+                DiffHunk::new(
+                    hunk.old_start + i.min(hunk.old_count),
+                    usize::from(i < hunk.old_count),
+                    hunk.new_start + i.min(hunk.new_count),
+                    usize::from(i < hunk.new_count),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// `old` with the given hunks (computed from `old` to `new`, in file order)
+/// replaced by their `new` lines
+fn apply_hunks(old: &[u8], new: &[u8], hunks: &[DiffHunk]) -> Vec<u8> {
+    let old_line_count = old.split(|&b| b == b'\n').count();
+    let new_line_count = new.split(|&b| b == b'\n').count();
+    let mut result = Vec::with_capacity(new.len());
+    let mut pos = 0;
+    for hunk in hunks {
+        let at_end = hunk.old_start + hunk.old_count == old_line_count
+            && hunk.new_start + hunk.new_count == new_line_count;
+        let old_range = hunk_byte_range(old, hunk.old_start, hunk.old_count, at_end);
+        let new_range = hunk_byte_range(new, hunk.new_start, hunk.new_count, at_end);
+        // A hunk at the end owns the newline before it; when the previous
+        // hunk already took that newline, skip it on both sides.
+        let overlap = pos.saturating_sub(old_range.start);
+        result.extend_from_slice(&old[pos.min(old_range.start)..old_range.start]);
+        result.extend_from_slice(&new[new_range.start + overlap..new_range.end]);
+        pos = old_range.end;
+    }
+    result.extend_from_slice(&old[pos..]);
+    result
+}
+
+impl Editor {
+    /// Start walking through the unstaged hunks of the active file
+    pub(crate) fn start_hunk_staging(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .cloned()
+        else {
+            self.set_status_message(t!("status.stage_no_file").to_string());
+            return;
+        };
+        if self
+            .buffers
+            .get(&buffer_id)
+            .is_some_and(|state| state.buffer.is_modified())
+        {
+            self.set_status_message(t!("status.stage_save_first").to_string());
+            return;
+        }
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return;
+        };
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            return;
+        };
+
+        let dir = dir.to_path_buf();
+        let index_path = format!(":./{}", file_name.to_string_lossy());
+        let sender = bridge.sender();
+        let spawner = self.authority.process_spawner.clone();
+        runtime.spawn(async move {
+            let content = run_git(spawner.as_ref(), &dir, &["show", &index_path])
+                .await
+                .ok()
+                .map(String::into_bytes);
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::GitIndexBlobLoaded {
+                buffer_id,
+                path,
+                content,
+            });
+        });
+    }
+
+    /// Continue starting a staging walk once the file's index blob is in.
+    /// Dropped when the user moved to another buffer in the meantime.
+    pub(crate) fn handle_git_index_blob_loaded(
+        &mut self,
+        buffer_id: BufferId,
+        path: PathBuf,
+        content: Option<Vec<u8>>,
+    ) {
+        if self.hunk_staging.is_some() || self.active_buffer() != buffer_id {
+            return;
+        }
+        let Some(index) = content else {
+            self.set_status_message(t!("status.stage_not_in_index").to_string());
+            return;
+        };
+        let worktree = match self.authority.filesystem.read_file(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.set_status_message(
+                    t!("status.stage_failed", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+        let hunks = diff_hunks(&index, &worktree);
+        if hunks.is_empty() {
+            self.set_status_message(t!("status.stage_no_changes").to_string());
+            return;
+        }
+
+        let name = self.get_buffer_display_name(buffer_id);
+        let mut preview_buffers = Vec::new();
+        let snapshots = [
+            (t!("buffer.index_version_name", name = &name), &index),
+            (t!("buffer.worktree_version_name", name = &name), &worktree),
+        ];
+        for (snapshot_name, content) in snapshots {
+            let snapshot = self.create_virtual_buffer_detached(
+                snapshot_name.to_string(),
+                "diff-view".to_string(),
+                true,
+            );
+            if let Some(state) = self.buffers.get_mut(&snapshot) {
+                state.buffer.insert(0, &String::from_utf8_lossy(content));
+                state.buffer.clear_modified();
+            }
+            preview_buffers.push(snapshot);
+        }
+        let composite = self.create_composite_buffer(
+            t!("buffer.stage_hunks_name", name = &name).to_string(),
+            "diff-view".to_string(),
+            CompositeLayout::SideBySide {
+                ratios: vec![0.5, 0.5],
+                show_separator: true,
+            },
+            vec![
+                SourcePane::new(
+                    preview_buffers[0],
+                    t!("buffer.stage_index").to_string(),
+                    false,
+                ),
+                SourcePane::new(
+                    preview_buffers[1],
+                    t!("buffer.stage_worktree").to_string(),
+                    false,
+                ),
+            ],
+        );
+        self.set_active_buffer_internal(composite);
+
+        self.hunk_staging = Some(HunkStaging {
+            path,
+            index,
+            worktree,
+            hunks,
+            current: 0,
+            staged: Vec::new(),
+            composite,
+            preview_buffers,
+            return_to: buffer_id,
+        });
+        self.show_staging_hunk();
+    }
+
+    /// Highlight the current hunk in the diff view and ask what to do with it
+    fn show_staging_hunk(&mut self) {
+        let Some(staging) = &self.hunk_staging else {
+            return;
+        };
+        let hunk = staging.hunks[staging.current].clone();
+        let alignment = LineAlignment::from_hunks(
+            std::slice::from_ref(&hunk),
+            staging.index.split(|&b| b == b'\n').count(),
+            staging.worktree.split(|&b| b == b'\n').count(),
+        );
+        let composite = staging.composite;
+        let position = staging.current + 1;
+        let total = staging.hunks.len();
+
+        self.set_composite_alignment(composite, alignment);
+        let split_id = self.split_manager.active_split();
+        if let Some(view_state) = self.get_composite_view_state(split_id, composite) {
+            view_state.cursor_row = 0;
+            view_state.scroll_row = 0;
+        }
+        // No-op when the hunk starts the file, whose header is row 0
+        self.composite_next_hunk(split_id, composite);
+
+        let stage_key = t!("prompt.key.stage").to_string();
+        let skip_key = t!("prompt.key.skip").to_string();
+        let split_key = t!("prompt.key.split").to_string();
+        let quit_key = t!("prompt.key.quit").to_string();
+        self.start_prompt(
+            t!(
+                "prompt.stage_hunk",
+                position = position,
+                total = total,
+                stage_key = stage_key,
+                skip_key = skip_key,
+                split_key = split_key,
+                quit_key = quit_key
+            )
+            .to_string(),
+            PromptType::StageHunk,
+        );
+    }
+
+    /// Handle the answer for the current hunk
+    pub(crate) fn handle_stage_hunk_prompt(&mut self, input: &str) {
+        let first_char = input.trim().to_lowercase().chars().next();
+        let first_of = |key: String| key.to_lowercase().chars().next();
+        let stage_first = first_of(t!("prompt.key.stage").to_string());
+        let skip_first = first_of(t!("prompt.key.skip").to_string());
+        let split_first = first_of(t!("prompt.key.split").to_string());
+        let quit_first = first_of(t!("prompt.key.quit").to_string());
+        let Some(staging) = self.hunk_staging.as_mut() else {
+            return;
+        };
+
+        if first_char == stage_first {
+            staging.staged.push(staging.hunks[staging.current].clone());
+            staging.current += 1;
+        } else if first_char == skip_first {
+            staging.current += 1;
+        } else if first_char == split_first {
+            match split_hunk(&staging.hunks[staging.current]) {
+                Some(pieces) => {
+                    let at = staging.current;
+                    staging.hunks.splice(at..=at, pieces);
+                }
+                None => self.set_status_message(t!("status.stage_cannot_split").to_string()),
+            }
+        } else if first_char == quit_first {
+            self.finish_hunk_staging();
+            return;
+        }
+
+        match &self.hunk_staging {
+            Some(staging) if staging.current >= staging.hunks.len() => self.finish_hunk_staging(),
+            _ => self.show_staging_hunk(),
+        }
+    }
+
+    /// Write the staged hunks to the index and close the diff view
+    fn finish_hunk_staging(&mut self) {
+        let Some(staging) = self.close_hunk_staging() else {
+            return;
+        };
+        if staging.staged.is_empty() {
+            self.set_status_message(t!("status.stage_nothing_staged").to_string());
+            return;
+        }
+        let content = apply_hunks(&staging.index, &staging.worktree, &staging.staged);
+        let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) else {
+            return;
+        };
+
+        let count = staging.staged.len();
+        let sender = bridge.sender();
+        let spawner = self.authority.process_spawner.clone();
+        let filesystem = self.authority.filesystem.clone();
+        runtime.spawn(async move {
+            let result =
+                write_git_index_blob(spawner.as_ref(), filesystem, &staging.path, &content).await;
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::GitHunksStaged { count, result });
+        });
+    }
+
+    /// Report the outcome of writing staged hunks to the index
+    pub(crate) fn handle_git_hunks_staged(&mut self, count: usize, result: Result<(), String>) {
+        match result {
+            Ok(()) => self.set_status_message(t!("status.stage_done", count = count).to_string()),
+            Err(e) => self.set_status_message(t!("status.stage_failed", error = e).to_string()),
+        }
+    }
+
+    /// Close the diff view without touching the index
+    pub(crate) fn cancel_hunk_staging(&mut self) {
+        if self.close_hunk_staging().is_some() {
+            self.set_status_message(t!("status.stage_cancelled").to_string());
+        }
+    }
+
+    fn close_hunk_staging(&mut self) -> Option<HunkStaging> {
+        let staging = self.hunk_staging.take()?;
+        if self.buffers.contains_key(&staging.return_to) {
+            self.set_active_buffer_internal(staging.return_to);
+        }
+        self.close_composite_buffer(staging.composite);
+        for &id in &staging.preview_buffers {
+            if let Err(e) = self.force_close_buffer(id) {
+                tracing::debug!("Failed to close staging preview {:?}: {}", id, e);
+            }
+        }
+        Some(staging)
+    }
+}
+
+/// Run git in `dir` and return its stdout, or its stderr on failure
+async fn run_git(
+    spawner: &dyn ProcessSpawner,
+    dir: &Path,
+    args: &[&str],
+) -> Result<String, String> {
+    let output = spawner
+        .spawn(
+            "git".to_string(),
+            args.iter().map(|arg| arg.to_string()).collect(),
+            Some(dir.to_string_lossy().to_string()),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if output.exit_code != 0 {
+        return Err(output.stderr.trim().to_string());
+    }
+    Ok(output.stdout)
+}
+
+/// Replace the file's index entry with `content`, keeping its mode.
+///
+/// The process spawner has no stdin, so the blob goes through a temporary
+/// file in the git directory.
+async fn write_git_index_blob(
+    spawner: &dyn ProcessSpawner,
+    filesystem: Arc<dyn FileSystem + Send + Sync>,
+    path: &Path,
+    content: &[u8],
+) -> Result<(), String> {
+    let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return Err(path.display().to_string());
+    };
+    let file_name = format!("./{}", file_name.to_string_lossy());
+
+    let staged_entry = run_git(spawner, dir, &["ls-files", "-s", "--", &file_name]).await?;
+    let mode = staged_entry
+        .split_whitespace()
+        .next()
+        .unwrap_or("100644")
+        .to_string();
+
+    let blob_path = run_git(
+        spawner,
+        dir,
+        &["rev-parse", "--git-path", "fresh-staged-blob"],
+    )
+    .await?;
+    let blob_path = dir.join(blob_path.trim());
+    filesystem
+        .write_file(&blob_path, content)
+        .map_err(|e| e.to_string())?;
+    let hashed = run_git(
+        spawner,
+        dir,
+        &[
+            "hash-object",
+            "-w",
+            "--no-filters",
+            &blob_path.to_string_lossy(),
+        ],
+    )
+    .await;
+    if let Err(e) = filesystem.remove_file(&blob_path) {
+        tracing::debug!("Failed to remove {:?}: {}", blob_path, e);
+    }
+    let sha = hashed?;
+
+    run_git(
+        spawner,
+        dir,
+        &[
+            "update-index",
+            "--cacheinfo",
+            &format!("{},{},{}", mode, sha.trim(), file_name),
+        ],
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(old: &str, new: &str, pick: impl Fn(usize) -> bool) -> String {
+        let hunks: Vec<DiffHunk> = diff_hunks(old.as_bytes(), new.as_bytes())
+            .into_iter()
+            .flat_map(|h| split_hunk(&h).unwrap_or_else(|| vec![h]))
+            .enumerate()
+            .filter(|(i, _)| pick(*i))
+            .map(|(_, h)| h)
+            .collect();
+        String::from_utf8(apply_hunks(old.as_bytes(), new.as_bytes(), &hunks)).unwrap()
+    }
+
+    #[test]
+    fn test_apply_some_hunks() {
+        let old = "a\nb\nc\nd\n";
+        let new = "a\nB\nc\nD\n";
+        assert_eq!(stage(old, new, |i| i == 0), "a\nB\nc\nd\n");
+        assert_eq!(stage(old, new, |i| i == 1), "a\nb\nc\nD\n");
+        assert_eq!(stage(old, new, |_| true), new);
+    }
+
+    #[test]
+    fn test_apply_split_hunk_at_end_without_newline() {
+        let old = "a\nb";
+        let new = "A\nB";
+        assert_eq!(stage(old, new, |i| i == 0), "A\nb");
+        assert_eq!(stage(old, new, |i| i == 1), "a\nB");
+        assert_eq!(stage(old, new, |_| true), new);
+    }
+
+    #[test]
+    fn test_apply_split_uneven_hunk() {
+        let old = "x\na\nb\ny\n";
+        let new = "x\nA\ny\n";
+        assert_eq!(stage(old, new, |i| i == 0), "x\nA\nb\ny\n");
+        assert_eq!(stage(old, new, |i| i == 1), "x\na\ny\n");
+        assert_eq!(stage(old, new, |_| true), new);
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! Owns five fields previously scattered on `Editor`:
 //!
-//! - In-flight LSP hover request: `(request_id, lsp_position)`. Used to
-//!   ignore stale responses from earlier mouse moves.
+//! - In-flight LSP hover requests (one per server) and their LSP position.
+//!   Used to ignore stale responses from earlier mouse moves and to merge
+//!   the answers of several servers into one card.
 //! - Highlighted-symbol range and its overlay handle. The handle is kept
 //!   so we can remove the old overlay before drawing a new one, or when
 //!   focus is lost.
@@ -16,17 +17,69 @@
 //! positioning the popup — stay on `Editor` as orchestrators. This module
 //! is pure data with no `Editor` or I/O access.
 
+use std::time::{Duration, Instant};
+
 use crate::view::overlay::OverlayHandle;
 
+/// How long to wait for every server of a hover batch before showing the
+/// answers that did arrive.
+pub(crate) const HOVER_RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// One server's answer to a hover request.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HoverResponse {
+    pub contents: String,
+    pub is_markdown: bool,
+    /// LSP range `((line, character), (line, character))` of the symbol.
+    pub range: Option<((u32, u32), (u32, u32))>,
+}
+
+impl HoverResponse {
+    /// Merge the answers of several servers: non-empty contents are stacked
+    /// in order, separated by a horizontal rule. The result is markdown if
+    /// any answer is, and takes the first symbol range given.
+    fn merge(responses: Vec<HoverResponse>) -> HoverResponse {
+        let range = responses.iter().find_map(|r| r.range);
+        let mut parts: Vec<HoverResponse> = responses
+            .into_iter()
+            .filter(|r| !r.contents.trim().is_empty())
+            .collect();
+        if parts.len() <= 1 {
+            return parts.pop().map_or(
+                HoverResponse {
+                    contents: String::new(),
+                    is_markdown: false,
+                    range,
+                },
+                |part| HoverResponse { range, ..part },
+            );
+        }
+        HoverResponse {
+            is_markdown: parts.iter().any(|r| r.is_markdown),
+            contents: parts
+                .into_iter()
+                .map(|r| r.contents.trim().to_string())
+                .collect::<Vec<_>>()
+                .join("\n\n---\n\n"),
+            range,
+        }
+    }
+}
+
 /// Owner of in-flight hover request and highlighted-symbol tracking.
 #[derive(Debug, Default)]
 pub(crate) struct HoverState {
-    /// LSP request id of the in-flight hover request, if any.
-    pending_request: Option<u64>,
-    /// LSP position `(line, character)` of the in-flight request. Retained
+    /// LSP request ids of the in-flight hover batch still to be answered,
+    /// one per server asked.
+    pending_requests: Vec<u64>,
+    /// LSP position `(line, character)` of the in-flight batch. Retained
     /// so the response handler can correlate diagnostics with the hover
     /// point and fuse them into the hover card.
     pending_position: Option<(u32, u32)>,
+    /// Answers received so far for the in-flight batch.
+    responses: Vec<HoverResponse>,
+    /// When to stop waiting for the rest of the in-flight batch.
+    deadline: Option<Instant>,
     /// Byte range `(start, end)` of the currently-highlighted symbol.
     /// Used by mouse-move handlers to detect "still on same symbol" and
     /// skip re-querying.
@@ -43,31 +96,90 @@ pub(crate) struct HoverState {
 impl HoverState {
     // ---- Pending-request correlation --------------------------------------
 
-    /// Record that a hover request with `request_id` was sent at LSP
-    /// position `(line, character)`.
-    pub(crate) fn record_request(&mut self, request_id: u64, line: u32, character: u32) {
-        self.pending_request = Some(request_id);
+    /// Record a batch of hover requests, one per server, sent at LSP
+    /// position `(line, character)` at time `now`. Replaces any earlier
+    /// batch.
+    pub(crate) fn record_requests(
+        &mut self,
+        request_ids: &[u64],
+        line: u32,
+        character: u32,
+        now: Instant,
+    ) {
+        self.pending_requests = request_ids.to_vec();
         self.pending_position = Some((line, character));
+        self.responses.clear();
+        self.deadline = Some(now + HOVER_RESPONSE_TIMEOUT);
     }
 
-    /// Claim a response as matching the in-flight request. If it matches,
-    /// both `pending_request` and `pending_position` are cleared, and the
-    /// position is returned for the caller's use (diagnostic correlation).
+    /// Claim a response as belonging to the in-flight batch. Once every
+    /// server of the batch has answered, the batch is cleared and its
+    /// position is returned with the merged answer, for the caller's use
+    /// (diagnostic correlation, then the popup).
     ///
-    /// Returns `None` if the response is stale — the caller should drop it.
-    pub(crate) fn claim_pending(&mut self, request_id: u64) -> Option<(u32, u32)> {
-        if self.pending_request != Some(request_id) {
+    /// Returns `None` while other answers are outstanding, and for stale
+    /// responses — the caller should drop those.
+    pub(crate) fn claim_response(
+        &mut self,
+        request_id: u64,
+        response: HoverResponse,
+    ) -> Option<((u32, u32), HoverResponse)> {
+        let index = self
+            .pending_requests
+            .iter()
+            .position(|&id| id == request_id)?;
+        self.pending_requests.remove(index);
+        self.responses.push(response);
+        if !self.pending_requests.is_empty() {
             return None;
         }
-        self.pending_request = None;
-        self.pending_position.take()
+        self.deadline = None;
+        let position = self.pending_position.take()?;
+        let responses = std::mem::take(&mut self.responses);
+        Some((position, HoverResponse::merge(responses)))
+    }
+
+    /// Give up on the servers of the in-flight batch that have not answered
+    /// by `now` past its deadline. Returns the batch's position and the
+    /// merged answers received so far, or `None` if it has not expired or
+    /// no server answered; either way an expired batch is dropped and later
+    /// answers are stale.
+    pub(crate) fn take_expired(&mut self, now: Instant) -> Option<((u32, u32), HoverResponse)> {
+        if self.deadline.is_none_or(|deadline| now < deadline) {
+            return None;
+        }
+        self.pending_requests.clear();
+        self.deadline = None;
+        let position = self.pending_position.take()?;
+        let responses = std::mem::take(&mut self.responses);
+        if responses.is_empty() {
+            return None;
+        }
+        Some((position, HoverResponse::merge(responses)))
     }
 
     /// Clear any in-flight request without consuming a position — used
     /// when focus is lost or the user cancels hover.
     pub(crate) fn clear_pending(&mut self) {
-        self.pending_request = None;
+        self.pending_requests.clear();
+        self.pending_position = None;
+        self.responses.clear();
+        self.deadline = None;
+    }
+
+    /// Whether a hover request is in flight.
+    pub(crate) fn has_pending(&self) -> bool {
+        !self.pending_requests.is_empty()
+    }
+
+    /// Drop the in-flight batch and return its request ids so the caller
+    /// can send `$/cancelRequest` for them. Answers arriving afterwards are
+    /// stale.
+    pub(crate) fn take_pending(&mut self) -> Vec<u64> {
         self.pending_position = None;
+        self.responses.clear();
+        self.deadline = None;
+        std::mem::take(&mut self.pending_requests)
     }
 
     // ---- Symbol range -----------------------------------------------------
@@ -107,47 +219,120 @@ impl HoverState {
 mod tests {
     use super::*;
 
+    fn plain(contents: &str) -> HoverResponse {
+        HoverResponse {
+            contents: contents.to_string(),
+            is_markdown: false,
+            range: None,
+        }
+    }
+
     #[test]
     fn default_is_empty() {
         let mut h = HoverState::default();
         assert_eq!(h.symbol_range(), None);
-        assert_eq!(h.claim_pending(42), None);
+        assert_eq!(h.claim_response(42, plain("x")), None);
     }
 
     #[test]
-    fn claim_pending_returns_position_and_clears_state() {
+    fn claim_response_returns_position_and_clears_state() {
         let mut h = HoverState::default();
-        h.record_request(7, 10, 20);
-        assert_eq!(h.claim_pending(7), Some((10, 20)));
+        h.record_requests(&[7], 10, 20, Instant::now());
+        assert_eq!(
+            h.claim_response(7, plain("fn x()")),
+            Some(((10, 20), plain("fn x()")))
+        );
         // Subsequent claim for the same id returns None — position drained.
-        assert_eq!(h.claim_pending(7), None);
+        assert_eq!(h.claim_response(7, plain("fn x()")), None);
     }
 
     #[test]
-    fn claim_pending_rejects_stale_response() {
+    fn claim_response_rejects_stale_response() {
         let mut h = HoverState::default();
-        h.record_request(7, 10, 20);
+        h.record_requests(&[7], 10, 20, Instant::now());
         // Older response arrives after a newer request went out.
-        assert_eq!(h.claim_pending(3), None);
-        // Correct one still works.
-        assert_eq!(h.claim_pending(7), Some((10, 20)));
+        assert_eq!(h.claim_response(3, plain("old")), None);
+        // Correct one still works, without the stale contents.
+        assert_eq!(
+            h.claim_response(7, plain("new")),
+            Some(((10, 20), plain("new")))
+        );
+    }
+
+    #[test]
+    fn claim_response_merges_answers_from_every_server() {
+        let mut h = HoverState::default();
+        h.record_requests(&[4, 5, 6], 1, 2, Instant::now());
+        let typed = HoverResponse {
+            contents: "```rust\nfn x()\n```".to_string(),
+            is_markdown: true,
+            range: Some(((1, 0), (1, 1))),
+        };
+        assert_eq!(h.claim_response(5, typed), None);
+        assert_eq!(h.claim_response(6, plain("  ")), None);
+        assert!(h.has_pending());
+
+        let (position, merged) = h.claim_response(4, plain("lint: x")).unwrap();
+        assert_eq!(position, (1, 2));
+        assert_eq!(merged.contents, "```rust\nfn x()\n```\n\n---\n\nlint: x");
+        assert!(merged.is_markdown);
+        assert_eq!(merged.range, Some(((1, 0), (1, 1))));
+        assert!(!h.has_pending());
     }
 
     #[test]
-    fn record_request_overwrites_previous_pending() {
+    fn record_requests_overwrites_previous_pending() {
         let mut h = HoverState::default();
-        h.record_request(1, 0, 0);
-        h.record_request(2, 5, 5);
-        assert_eq!(h.claim_pending(1), None);
-        assert_eq!(h.claim_pending(2), Some((5, 5)));
+        h.record_requests(&[1], 0, 0, Instant::now());
+        h.record_requests(&[2], 5, 5, Instant::now());
+        assert_eq!(h.claim_response(1, plain("a")), None);
+        assert_eq!(h.claim_response(2, plain("b")), Some(((5, 5), plain("b"))));
     }
 
     #[test]
     fn clear_pending_drops_without_returning_position() {
         let mut h = HoverState::default();
-        h.record_request(7, 10, 20);
+        h.record_requests(&[7], 10, 20, Instant::now());
         h.clear_pending();
-        assert_eq!(h.claim_pending(7), None);
+        assert_eq!(h.claim_response(7, plain("x")), None);
+    }
+
+    #[test]
+    fn take_pending_returns_ids_and_drops_late_response() {
+        let mut h = HoverState::default();
+        h.record_requests(&[7], 10, 20, Instant::now());
+        assert!(h.has_pending());
+        assert_eq!(h.take_pending(), vec![7]);
+        assert!(!h.has_pending());
+        assert!(h.take_pending().is_empty());
+        assert_eq!(h.claim_response(7, plain("x")), None);
+    }
+
+    #[test]
+    fn take_expired_shows_answers_received_so_far() {
+        let mut h = HoverState::default();
+        let start = Instant::now();
+        h.record_requests(&[4, 5], 1, 2, start);
+        assert_eq!(h.claim_response(4, plain("typed")), None);
+        assert_eq!(h.take_expired(start), None);
+        assert!(h.has_pending());
+
+        assert_eq!(
+            h.take_expired(start + HOVER_RESPONSE_TIMEOUT),
+            Some(((1, 2), plain("typed")))
+        );
+        assert!(!h.has_pending());
+        // The slow server's answer is stale now
+        assert_eq!(h.claim_response(5, plain("late")), None);
+    }
+
+    #[test]
+    fn take_expired_without_answers_drops_batch() {
+        let mut h = HoverState::default();
+        let start = Instant::now();
+        h.record_requests(&[7], 1, 2, start);
+        assert_eq!(h.take_expired(start + HOVER_RESPONSE_TIMEOUT), None);
+        assert!(!h.has_pending());
     }
 
     #[test]
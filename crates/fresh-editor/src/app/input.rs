@@ -83,7 +83,6 @@ impl Editor {
         query: String,
         matches: Vec<crate::services::live_grep_state::GrepMatch>,
     ) {
-        use crate::model::event::SplitDirection;
         use crate::primitives::text_property::TextPropertyEntry;
         use crate::view::split::SplitRole;
 
@@ -141,6 +140,23 @@ impl Editor {
             return;
         }
 
+        if !self.show_in_utility_dock(buffer_id) {
+            return;
+        }
+
+        self.set_status_message(format!(
+            "Quickfix exported: {} matches in dock",
+            matches.len()
+        ));
+    }
+
+    /// Show a (detached) buffer in the Utility Dock, creating the dock leaf
+    /// at the bottom of the layout if there isn't one yet, and focus it.
+    /// Returns `false` if the dock split could not be created.
+    pub(crate) fn show_in_utility_dock(&mut self, buffer_id: BufferId) -> bool {
+        use crate::model::event::SplitDirection;
+        use crate::view::split::SplitRole;
+
         // Place the buffer in the dock — reuse the existing dock leaf
         // if any; otherwise create one at the bottom (horizontal,
         // ratio 0.3) and tag it as the dock.
@@ -202,16 +218,12 @@ impl Editor {
                     self.split_manager.set_active_split(new_leaf);
                 }
                 Err(e) => {
-                    tracing::error!("Failed to create dock split for quickfix: {}", e);
-                    return;
+                    tracing::error!("Failed to create utility dock split: {}", e);
+                    return false;
                 }
             }
         }
-
-        self.set_status_message(format!(
-            "Quickfix exported: {} matches in dock",
-            matches.len()
-        ));
+        true
     }
 
     /// Whether editor-pane popups (LSP completion, hover, signature help,
@@ -464,7 +476,7 @@ impl Editor {
             // Check if the current popup is transient (hover, signature help).
             // Editor-level popups always take precedence over buffer popups
             // when both are visible — they're effectively modal overlays.
-            let (is_transient_popup, has_selection) = {
+            let (is_transient_popup, has_selection, is_signature_help) = {
                 let popup = self
                     .global_popups
                     .top()
@@ -472,9 +484,20 @@ impl Editor {
                 (
                     popup.is_some_and(|p| p.transient),
                     popup.is_some_and(|p| p.has_selection()),
+                    popup.is_some_and(|p| {
+                        p.resolver == crate::view::popup::PopupResolver::SignatureHelp
+                    }),
                 )
             };
 
+            // Signature help stays up while the call's arguments are typed;
+            // the closing parenthesis (or any non-character key) ends it.
+            let is_argument_key = is_signature_help
+                && !key_event.modifiers.intersects(
+                    crossterm::event::KeyModifiers::CONTROL | crossterm::event::KeyModifiers::ALT,
+                )
+                && matches!(key_event.code, crossterm::event::KeyCode::Char(c) if c != ')');
+
             // Don't dismiss if popup has selection and user is pressing Ctrl+C (let them copy first)
             let is_copy_key = key_event.code == crossterm::event::KeyCode::Char('c')
                 && key_event
@@ -495,7 +518,11 @@ impl Editor {
                 Some(crate::input::keybindings::Action::PopupFocus)
             );
 
-            if is_transient_popup && !(has_selection && is_copy_key) && !is_focus_popup_key {
+            if is_transient_popup
+                && !(has_selection && is_copy_key)
+                && !is_focus_popup_key
+                && !is_argument_key
+            {
                 // Dismiss the popup on any key press (except Ctrl+C with selection)
                 self.hide_popup();
                 tracing::debug!("Dismissed transient popup on key press");
@@ -701,8 +728,29 @@ impl Editor {
             self.reset_dabbrev_state();
         }
 
+        // End a Ctrl+Tab cycle on any other action.
+        if !matches!(
+            action,
+            Action::CycleRecentBuffers | Action::CycleRecentBuffersReverse
+        ) {
+            self.finish_recent_buffer_cycle();
+        }
+
+        // Tab and Shift+Tab move between the tabstops of an inserted snippet.
+        if self.handle_snippet_action(&action) {
+            return Ok(());
+        }
+        // Tab after a user snippet trigger expands the snippet.
+        if matches!(action, Action::InsertTab) && self.expand_user_snippet() {
+            return Ok(());
+        }
+        // Enter in the command history panel runs the entry again.
+        if matches!(action, Action::InsertNewline) && self.rerun_command_history_entry() {
+            return Ok(());
+        }
+
         match action {
-            Action::Quit => self.quit(),
+            Action::Quit => self.close_client(),
             Action::ForceQuit => {
                 self.should_quit = true;
             }
@@ -724,7 +772,9 @@ impl Editor {
                         t!("file.file_changed_prompt").to_string(),
                         PromptType::ConfirmSaveConflict,
                     );
-                } else if let Err(e) = self.save() {
+                } else if self.request_pre_save_edits() {
+                    // Saved once the language servers have answered
+                } else if let Err(e) = self.start_save() {
                     let msg = format!("{}", e);
                     self.status_message = Some(t!("file.save_failed", error = &msg).to_string());
                 }
@@ -750,11 +800,28 @@ impl Editor {
                 );
                 self.init_file_open_state();
             }
+            Action::RenameFile => {
+                let Some(current_path) = self.active_state().buffer.file_path().map(|p| {
+                    p.strip_prefix(&self.working_dir)
+                        .unwrap_or(p)
+                        .to_string_lossy()
+                        .to_string()
+                }) else {
+                    self.set_status_message(t!("file.rename_no_path").to_string());
+                    return Ok(());
+                };
+                self.start_prompt_with_initial_text(
+                    t!("file.rename_prompt").to_string(),
+                    PromptType::RenameCurrentFile,
+                    current_path,
+                );
+            }
             Action::Open => {
                 self.start_prompt(t!("file.open_prompt").to_string(), PromptType::OpenFile);
                 self.prefill_open_file_prompt();
                 self.init_file_open_state();
             }
+            Action::OpenRecentFile => self.start_open_recent_file_prompt(),
             Action::SwitchProject => {
                 self.start_prompt(
                     t!("file.switch_project_prompt").to_string(),
@@ -762,6 +829,8 @@ impl Editor {
                 );
                 self.init_folder_open_state();
             }
+            Action::OpenFileUnderCursor => self.open_file_under_cursor(),
+            Action::OpenUrlUnderCursor => self.open_url_under_cursor(),
             Action::GotoLine => {
                 let has_line_index = self
                     .buffers
@@ -792,6 +861,9 @@ impl Editor {
                 // - Properly closing the buffer
                 self.close_tab();
             }
+            Action::TogglePinTab => {
+                self.toggle_tab_pin(self.active_buffer());
+            }
             Action::Revert => {
                 // Check if buffer has unsaved changes - prompt for confirmation
                 if self.active_state().buffer.is_modified() {
@@ -815,6 +887,7 @@ impl Editor {
                     }
                 }
             }
+            Action::DiffAgainstDisk => self.diff_against_disk(),
             Action::ToggleAutoRevert => {
                 self.toggle_auto_revert();
             }
@@ -838,6 +911,9 @@ impl Editor {
                     );
                 }
             },
+            Action::RevertHunkAtCursor => self.revert_hunk_at_cursor(),
+            Action::StageHunks => self.start_hunk_staging(),
+            Action::ToggleGitBlame => self.toggle_git_blame(),
             Action::EnsureFinalNewline => match self.ensure_final_newline() {
                 Ok(true) => {
                     self.set_status_message(t!("whitespace.newline_added").to_string());
@@ -904,6 +980,20 @@ impl Editor {
                 }
                 self.paste()
             }
+            Action::PasteCyclePrevious => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.paste_cycle_previous()
+            }
+            Action::PasteCycleNext => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.paste_cycle_next()
+            }
             Action::YankWordForward => self.yank_word_forward(),
             Action::YankWordBackward => self.yank_word_backward(),
             Action::YankToLineEnd => self.yank_to_line_end(),
@@ -927,6 +1017,8 @@ impl Editor {
             Action::ShowStatusLog => {
                 self.open_status_log();
             }
+            Action::ShowNotificationLog => self.show_notification_log(),
+            Action::DismissNotifications => self.dismiss_toasts(),
             Action::ShowLspStatus => {
                 self.show_lsp_status_popup();
             }
@@ -1417,15 +1509,35 @@ impl Editor {
             Action::ToggleInlayHints => {
                 self.toggle_inlay_hints();
             }
+            Action::ToggleDiagnostics => {
+                self.toggle_diagnostics();
+            }
+            Action::ExplainDiagnostic => self.explain_diagnostic_under_cursor(),
             Action::DumpConfig => {
                 self.dump_config();
             }
+            Action::ReloadConfig => {
+                self.reload_config();
+            }
             Action::RedrawScreen => {
                 self.request_full_redraw();
             }
             Action::SelectTheme => {
                 self.start_select_theme_prompt();
             }
+            Action::SetTheme(name) => {
+                if name.is_empty() {
+                    self.start_select_theme_prompt();
+                } else {
+                    self.apply_theme(&name);
+                }
+            }
+            Action::ReloadTheme => {
+                self.reload_themes();
+                self.set_status_message(
+                    t!("view.theme_reloaded", theme = self.theme.name.clone()).to_string(),
+                );
+            }
             Action::InspectThemeAtCursor => {
                 self.inspect_theme_at_cursor();
             }
@@ -1496,11 +1608,14 @@ impl Editor {
                 self.find_selection_previous();
             }
             Action::AddCursorNextMatch => self.add_cursor_at_next_match(),
+            Action::SelectAllOccurrences => self.select_all_occurrences(),
             Action::AddCursorAbove => self.add_cursor_above(),
             Action::AddCursorBelow => self.add_cursor_below(),
             Action::NextBuffer => self.next_buffer(),
             Action::PrevBuffer => self.prev_buffer(),
             Action::SwitchToPreviousTab => self.switch_to_previous_tab(),
+            Action::CycleRecentBuffers => self.cycle_recent_buffers(true),
+            Action::CycleRecentBuffersReverse => self.cycle_recent_buffers(false),
             Action::SwitchToTabByName => self.start_switch_to_tab_prompt(),
 
             // Tab scrolling (manual scroll - don't auto-adjust)
@@ -1536,6 +1651,8 @@ impl Editor {
             Action::ToggleVerticalScrollbar => self.toggle_vertical_scrollbar(),
             Action::ToggleHorizontalScrollbar => self.toggle_horizontal_scrollbar(),
             Action::ToggleLineNumbers => self.toggle_line_numbers(),
+            Action::CycleLineNumberMode => self.cycle_line_number_mode(),
+            Action::ToggleBreakpoint => self.toggle_breakpoint(),
             Action::ToggleScrollSync => self.toggle_scroll_sync(),
             Action::ToggleMouseCapture => self.toggle_mouse_capture(),
             Action::ToggleMouseHover => self.toggle_mouse_hover(),
@@ -1572,6 +1689,24 @@ impl Editor {
             Action::SetLanguage => {
                 self.start_set_language_prompt();
             }
+            Action::ConvertIndentationToSpaces | Action::ConvertIndentationToTabs => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                let current = self
+                    .buffers
+                    .get(&self.active_buffer())
+                    .map(|s| s.buffer_settings.tab_size.to_string())
+                    .unwrap_or_else(|| "4".to_string());
+                self.start_prompt_with_initial_text(
+                    "Indent width: ".to_string(),
+                    PromptType::ConvertIndentation {
+                        to_spaces: action == Action::ConvertIndentationToSpaces,
+                    },
+                    current,
+                );
+            }
             Action::ToggleIndentationStyle => {
                 if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
                     state.buffer_settings.use_tabs = !state.buffer_settings.use_tabs;
@@ -1583,6 +1718,8 @@ impl Editor {
                     self.set_status_message(status.to_string());
                 }
             }
+            Action::ToggleSpellCheck => self.toggle_spell_check(),
+            Action::SpellingSuggestions => self.show_spelling_suggestions(),
             Action::ToggleTabIndicators | Action::ToggleWhitespaceIndicators => {
                 if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
                     state.buffer_settings.whitespace.toggle_all();
@@ -1710,12 +1847,24 @@ impl Editor {
             Action::ToggleComment => {
                 self.toggle_comment();
             }
+            Action::ToggleBlockComment => {
+                self.toggle_block_comment();
+            }
             Action::ToggleFold => {
                 self.toggle_fold_at_cursor();
             }
             Action::GoToMatchingBracket => {
                 self.goto_matching_bracket();
             }
+            Action::ExpandSelectionToScope => {
+                self.expand_selection_to_scope();
+            }
+            Action::ShrinkSelection => {
+                self.shrink_selection();
+            }
+            Action::ExtractSelectionToNewBuffer => {
+                self.extract_selection_to_new_buffer();
+            }
             Action::JumpToNextError => {
                 self.jump_to_next_error();
             }
@@ -1849,7 +1998,10 @@ impl Editor {
                 self.start_prompt("Record macro (0-9): ".to_string(), PromptType::RecordMacro);
             }
             Action::PromptPlayMacro => {
-                self.start_prompt("Play macro (0-9): ".to_string(), PromptType::PlayMacro);
+                self.start_prompt(
+                    "Play macro (0-9) [count]: ".to_string(),
+                    PromptType::PlayMacro,
+                );
             }
             Action::PlayLastMacro => {
                 if let Some(key) = self.macros.last_register() {
@@ -2098,6 +2250,8 @@ impl Editor {
                 // Run shell command on buffer/selection, replace content
                 self.start_shell_command_prompt(true);
             }
+            Action::RunTask => self.start_run_task_prompt(),
+            Action::ShowCommandHistory => self.show_command_history(),
             Action::OpenSettings => {
                 self.open_settings();
             }
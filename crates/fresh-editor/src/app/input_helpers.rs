@@ -1,21 +1,26 @@
 //! Miscellaneous input helpers on `Editor`.
 //!
-//! Tab navigation (switch_to_previous_tab, start_switch_to_tab_prompt),
-//! character insertion (handle_insert_char_prompt, handle_insert_char_editor,
-//! apply_action_as_events), cursor-movement tracking
-//! (track_cursor_movement), and composite-buffer key routing
-//! (try_route_composite_key). Small helpers grouped here so input.rs can
-//! contain only the central key/action dispatch.
+//! Tab navigation (switch_to_previous_tab, cycle_recent_buffers,
+//! start_switch_to_tab_prompt, start_hidden_tabs_prompt), character
+//! insertion (handle_insert_char_prompt, handle_insert_char_editor,
+//! apply_action_as_events), cursor-movement tracking (track_cursor_movement),
+//! and composite-buffer key routing (try_route_composite_key). Small helpers
+//! grouped here so input.rs can contain only the central key/action dispatch.
 
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
 
 use crate::input::keybindings::Action;
-use crate::model::event::{BufferId, Event};
+use crate::model::event::{BufferId, Event, LeafId};
 use crate::view::prompt::PromptType;
 
 use super::Editor;
 
+/// A Ctrl+Tab cycle ends when the next press comes later than this after
+/// the previous one. Terminals without key release events can't report
+/// letting go of Ctrl, so this stands in for it.
+const RECENT_BUFFER_CYCLE_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(800);
+
 impl Editor {
     /// Switch to the previously active tab in the current split.
     /// Handles both buffer tabs and group tabs via the focus-history LRU.
@@ -60,6 +65,107 @@ impl Editor {
         }
     }
 
+    /// Switch to the next (or, going backwards, the least) recently used
+    /// buffer of the current split. Consecutive calls keep walking the same
+    /// snapshot of the focus history; see [`super::RecentBufferCycle`].
+    pub(super) fn cycle_recent_buffers(&mut self, forward: bool) {
+        use crate::view::split::TabTarget;
+        let active_split = self.split_manager.active_split();
+        let now = self.time_source.now();
+        let stale = self.recent_buffer_cycle.as_ref().is_some_and(|cycle| {
+            cycle.split_id != active_split
+                || cycle.order[cycle.index] != self.active_buffer()
+                || now.saturating_duration_since(cycle.last_step) > RECENT_BUFFER_CYCLE_TIMEOUT
+        });
+        if stale {
+            self.finish_recent_buffer_cycle();
+        }
+
+        if self.recent_buffer_cycle.is_none() {
+            let Some(view_state) = self.split_view_states.get(&active_split) else {
+                return;
+            };
+            let mut order = vec![self.active_buffer()];
+            let recent = view_state
+                .focus_history
+                .iter()
+                .rev()
+                .filter_map(|target| match target {
+                    TabTarget::Buffer(id) => Some(*id),
+                    TabTarget::Group(_) => None,
+                });
+            // Tabs that were never focused come last, in tab bar order
+            for id in recent.chain(view_state.buffer_tab_ids_vec()) {
+                if view_state.has_buffer(id) && !order.contains(&id) {
+                    order.push(id);
+                }
+            }
+            if order.len() < 2 {
+                self.set_status_message(t!("status.no_previous_tab").to_string());
+                return;
+            }
+            self.recent_buffer_cycle = Some(super::RecentBufferCycle {
+                split_id: active_split,
+                order,
+                index: 0,
+                last_step: now,
+            });
+        }
+
+        let Some(cycle) = self.recent_buffer_cycle.as_mut() else {
+            return;
+        };
+        let len = cycle.order.len();
+        cycle.index = if forward {
+            (cycle.index + 1) % len
+        } else {
+            (cycle.index + len - 1) % len
+        };
+        cycle.last_step = now;
+        let buffer_id = cycle.order[cycle.index];
+        self.set_active_buffer(buffer_id);
+    }
+
+    /// Handle a key release, reported by terminals with keyboard
+    /// enhancement's event types enabled. Letting go of Ctrl ends a Ctrl+Tab
+    /// cycle, so two separate Ctrl+Tab presses flip between the last two
+    /// buffers.
+    pub fn handle_key_release(
+        &mut self,
+        code: crossterm::event::KeyCode,
+        modifiers: crossterm::event::KeyModifiers,
+    ) {
+        use crossterm::event::{KeyCode, KeyModifiers, ModifierKeyCode};
+        let ctrl_released = matches!(
+            code,
+            KeyCode::Modifier(ModifierKeyCode::LeftControl | ModifierKeyCode::RightControl)
+        ) || !modifiers.contains(KeyModifiers::CONTROL);
+        if ctrl_released {
+            self.finish_recent_buffer_cycle();
+        }
+    }
+
+    /// End a Ctrl+Tab cycle, making the buffer it landed on the most recent
+    /// one and the buffer it started from the next most recent
+    pub(crate) fn finish_recent_buffer_cycle(&mut self) {
+        let Some(cycle) = self.recent_buffer_cycle.take() else {
+            return;
+        };
+        let landed_on = cycle.order[cycle.index];
+        let Some(view_state) = self.split_view_states.get_mut(&cycle.split_id) else {
+            return;
+        };
+        // Focus moved some other way in the meantime
+        if view_state.active_buffer != landed_on {
+            return;
+        }
+        for &id in cycle.order.iter().rev() {
+            if id != landed_on {
+                view_state.push_focus(crate::view::split::TabTarget::Buffer(id));
+            }
+        }
+    }
+
     /// Start the switch-to-tab-by-name prompt with suggestions from open buffers
     pub(super) fn start_switch_to_tab_prompt(&mut self) {
         let active_split = self.split_manager.active_split();
@@ -81,7 +187,60 @@ impl Editor {
             .position(|&id| id == self.active_buffer())
             .unwrap_or(0);
 
-        let suggestions: Vec<crate::input::commands::Suggestion> = open_buffers
+        self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            "Switch to tab: ".to_string(),
+            PromptType::SwitchToTab,
+            self.tab_suggestions(&open_buffers),
+        ));
+
+        if let Some(prompt) = self.prompt.as_mut() {
+            if !prompt.suggestions.is_empty() {
+                prompt.selected_suggestion = Some(current_index);
+            }
+        }
+    }
+
+    /// Start the switch-to-tab prompt listing only the tabs of `split_id`
+    /// that don't fit in its tab bar
+    pub(super) fn start_hidden_tabs_prompt(&mut self, split_id: LeafId) {
+        let hidden_buffers: Vec<BufferId> = self
+            .cached_layout
+            .tab_layouts
+            .get(&split_id)
+            .map(|layout| {
+                layout
+                    .hidden_targets
+                    .iter()
+                    .filter_map(|target| target.as_buffer())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if hidden_buffers.is_empty() {
+            return;
+        }
+
+        // Hidden tabs are switched to in their own split
+        if let Some(buffer_id) = self
+            .split_view_states
+            .get(&split_id)
+            .map(|vs| vs.active_buffer)
+        {
+            self.focus_split(split_id, buffer_id);
+        }
+
+        self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            t!("prompt.hidden_tabs").to_string(),
+            PromptType::SwitchToTab,
+            self.tab_suggestions(&hidden_buffers),
+        ));
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(0);
+        }
+    }
+
+    /// Prompt suggestions for switching to one of `buffers`
+    fn tab_suggestions(&self, buffers: &[BufferId]) -> Vec<crate::input::commands::Suggestion> {
+        buffers
             .iter()
             .map(|&buffer_id| {
                 let display_name = self
@@ -112,19 +271,7 @@ impl Editor {
                     source: None,
                 }
             })
-            .collect();
-
-        self.prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Switch to tab: ".to_string(),
-            PromptType::SwitchToTab,
-            suggestions,
-        ));
-
-        if let Some(prompt) = self.prompt.as_mut() {
-            if !prompt.suggestions.is_empty() {
-                prompt.selected_suggestion = Some(current_index);
-            }
-        }
+            .collect()
     }
 
     /// Switch to a tab by its BufferId
@@ -251,10 +398,12 @@ impl Editor {
                 | Action::DeleteWordForward
                 | Action::DeleteLine
                 | Action::DuplicateLine
+                | Action::DuplicateSelection
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 | Action::DedentSelection
                 | Action::ToggleComment
+                | Action::ToggleBlockComment
         );
 
         if is_editing_action && self.is_editing_disabled() {
@@ -20,6 +20,14 @@ impl Editor {
         self.should_detach = false;
     }
 
+    /// Set the terminal color capability that rendered colors are downsampled to
+    pub fn set_color_capability(
+        &mut self,
+        capability: crate::view::color_support::ColorCapability,
+    ) {
+        self.color_capability = capability;
+    }
+
     /// Set session mode (use hardware cursor only, no REVERSED style for software cursor)
     pub fn set_session_mode(&mut self, session_mode: bool) {
         self.session_mode = session_mode;
@@ -77,10 +85,23 @@ impl Editor {
     }
 
     /// Take pending escape sequences, clearing the queue
+    ///
+    /// Includes any OSC 52 clipboard sequence, which is sent in-band so it
+    /// reaches the client's terminal along with the rendered output.
     pub fn take_pending_escape_sequences(&mut self) -> Vec<u8> {
+        if let Some(osc52) = self.clipboard.take_pending_osc52() {
+            self.pending_escape_sequences
+                .extend_from_slice(osc52.as_bytes());
+        }
         std::mem::take(&mut self.pending_escape_sequences)
     }
 
+    /// Take the request to ask the pasting client's terminal for its
+    /// clipboard via OSC 52 (session mode only)
+    pub fn take_osc52_query_request(&mut self) -> bool {
+        self.clipboard.take_osc52_query_request()
+    }
+
     /// Take pending clipboard data queued in session mode, clearing the request
     pub fn take_pending_clipboard(
         &mut self,
@@ -148,6 +169,30 @@ impl Editor {
         self.settings_state.as_ref().is_some_and(|s| s.visible)
     }
 
+    /// Close the editor from this client (the Quit action). Outside a
+    /// session this quits; in a session `session_close_action` decides
+    /// between detaching and quitting, asking by default.
+    pub fn close_client(&mut self) {
+        if !self.session_mode {
+            self.quit();
+            return;
+        }
+        match self.config.editor.session_close_action {
+            crate::config::SessionCloseAction::Ask => {
+                let msg = t!(
+                    "prompt.session_close",
+                    detach_key = t!("prompt.key.detach").to_string(),
+                    quit_key = t!("prompt.key.quit").to_string(),
+                    cancel_key = t!("prompt.key.cancel").to_string()
+                )
+                .to_string();
+                self.start_prompt(msg, PromptType::ConfirmSessionClose);
+            }
+            crate::config::SessionCloseAction::Detach => self.should_detach = true,
+            crate::config::SessionCloseAction::Quit => self.quit(),
+        }
+    }
+
     /// Request the editor to quit
     pub fn quit(&mut self) {
         // Check for unsaved buffers (all are auto-persisted when hot_exit is enabled)
@@ -206,16 +251,23 @@ impl Editor {
         }
     }
 
+    /// Whether any buffer has unsaved changes.
+    pub fn has_modified_buffers(&self) -> bool {
+        self.buffers
+            .values()
+            .any(|state| state.buffer.is_modified())
+    }
+
     /// Count modified buffers that would require a save prompt on quit.
     ///
     /// When `hot_exit` is enabled, unnamed buffers are excluded (they are
     /// automatically recovered across sessions), but file-backed modified
     /// buffers still trigger a prompt with a "recoverable" option.
-    /// When `auto_save_enabled` is true, file-backed buffers are excluded
+    /// When `auto_save` is on, file-backed buffers are excluded
     /// (they will be saved to disk on exit).
     fn count_modified_buffers_needing_prompt(&self) -> usize {
         let hot_exit = self.config.editor.hot_exit;
-        let auto_save = self.config.editor.auto_save_enabled;
+        let auto_save = self.config.editor.auto_save_mode() != crate::config::AutoSaveMode::Off;
 
         self.buffers
             .iter()
@@ -247,6 +299,13 @@ impl Editor {
         );
     }
 
+    /// Handle terminal focus lost event
+    pub fn focus_lost(&mut self) {
+        if self.config.editor.auto_save_mode() == crate::config::AutoSaveMode::OnFocusChange {
+            self.flush_auto_save();
+        }
+    }
+
     /// Resize all buffers to match new terminal size
     pub fn resize(&mut self, width: u16, height: u16) {
         // Update terminal dimensions for future buffer creation
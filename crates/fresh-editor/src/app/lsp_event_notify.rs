@@ -239,10 +239,15 @@ impl Editor {
             // Broadcast didSave to all handles for this language
             let mut any_sent = false;
             for sh in lsp.get_handles_mut(&language) {
-                if let Err(e) = sh
-                    .handle
-                    .did_save(uri.as_uri().clone(), Some(full_text.clone()))
-                {
+                // Before initialization the server's wishes are unknown; the
+                // notification is queued and sent with the text.
+                let caps = &sh.capabilities;
+                if caps.initialized && !caps.did_save {
+                    continue;
+                }
+                let text =
+                    (!caps.initialized || caps.did_save_include_text).then(|| full_text.clone());
+                if let Err(e) = sh.handle.did_save(uri.as_uri().clone(), text) {
                     tracing::warn!("Failed to send didSave to '{}': {}", sh.name, e);
                 } else {
                     any_sent = true;
@@ -251,7 +256,10 @@ impl Editor {
             if any_sent {
                 tracing::info!("Successfully sent didSave to LSP");
             } else {
-                tracing::warn!("notify_lsp_save: no LSP handles for {}", language);
+                tracing::debug!(
+                    "notify_lsp_save: no LSP server wants didSave for {}",
+                    language
+                );
             }
         } else {
             tracing::debug!("notify_lsp_save: no LSP manager available");
@@ -12,11 +12,17 @@
 
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::time::{Duration, Instant};
 
+/// How long a save waits for `textDocument/willSaveWaitUntil` answers
+/// before writing the file without them.
+const WILL_SAVE_WAIT_UNTIL_TIMEOUT: Duration = Duration::from_millis(1500);
+
 use lsp_types::TextDocumentContentChangeEvent;
 
+use crate::input::commands::Suggestion;
 use crate::model::event::{BufferId, Event};
 use crate::primitives::word_navigation::{find_word_end, find_word_start};
 use crate::view::prompt::{Prompt, PromptType};
@@ -24,6 +30,7 @@ use crate::view::prompt::{Prompt, PromptType};
 use crate::services::lsp::async_handler::LspHandle;
 use crate::types::LspFeature;
 
+use super::types::PendingWillSave;
 use super::{Editor, SemanticTokenRangeRequest};
 
 /// Ensure every line in a docstring is separated by a blank line.
@@ -36,11 +43,71 @@ fn space_doc_paragraphs(text: &str) -> String {
     text.replace("\n\n", "\x00").replace(['\n', '\x00'], "\n\n")
 }
 
+/// Byte range of a signature's parameter label within the signature label.
+///
+/// `LabelOffsets` are UTF-16 code unit offsets per the LSP spec, so they are
+/// converted to byte offsets here. `Simple` labels are located by searching
+/// after the opening parenthesis so a parameter named like the function
+/// itself is not matched in the function name.
+fn signature_param_range(
+    label: &str,
+    param: &lsp_types::ParameterLabel,
+) -> Option<std::ops::Range<usize>> {
+    match param {
+        lsp_types::ParameterLabel::Simple(text) => {
+            if text.is_empty() {
+                return None;
+            }
+            let search_from = label.find('(').map_or(0, |i| i + 1);
+            let start = search_from + label[search_from..].find(text.as_str())?;
+            Some(start..start + text.len())
+        }
+        lsp_types::ParameterLabel::LabelOffsets([start, end]) => {
+            let to_byte = |utf16: u32| {
+                let mut units = 0u32;
+                for (byte, ch) in label.char_indices() {
+                    if units >= utf16 {
+                        return Some(byte);
+                    }
+                    units += ch.len_utf16() as u32;
+                }
+                (units >= utf16).then_some(label.len())
+            };
+            let (start, end) = (to_byte(*start)?, to_byte(*end)?);
+            (start < end).then_some(start..end)
+        }
+    }
+}
+
+/// Width in bytes of a line's leading whitespace.
+fn line_indent_width(buffer: &crate::model::buffer::Buffer, line: usize) -> usize {
+    buffer
+        .get_line(line)
+        .map(|bytes| {
+            bytes
+                .iter()
+                .take_while(|b| matches!(b, b' ' | b'\t'))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Length in bytes of a line without its line ending.
+fn line_content_len(buffer: &crate::model::buffer::Buffer, line: usize) -> usize {
+    buffer
+        .get_line(line)
+        .map(|bytes| {
+            let text = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+            text.strip_suffix(b"\r").unwrap_or(text).len()
+        })
+        .unwrap_or(0)
+}
+
 /// Whether an LSP range (half-open end, like `[start, end)`) contains the given
 /// `(line, character)` LSP position. Zero-length ranges (start == end) are
 /// treated as containing their single anchor point so point-style diagnostics
 /// still match a hover that lands exactly on them.
-fn lsp_range_contains(range: &lsp_types::Range, line: u32, character: u32) -> bool {
+pub(super) fn lsp_range_contains(range: &lsp_types::Range, line: u32, character: u32) -> bool {
     let start = range.start;
     let end = range.end;
     // Before start?
@@ -112,20 +179,8 @@ impl Editor {
             String::new()
         };
 
-        let matches_prefix = |item: &lsp_types::CompletionItem| -> bool {
-            prefix.is_empty()
-                || item.label.to_lowercase().starts_with(&prefix)
-                || item
-                    .filter_text
-                    .as_ref()
-                    .map(|ft| ft.to_lowercase().starts_with(&prefix))
-                    .unwrap_or(false)
-        };
-
-        let filtered_items: Vec<&lsp_types::CompletionItem> =
-            items.iter().filter(|item| matches_prefix(item)).collect();
-
-        if filtered_items.is_empty() && self.completion_items.is_none() {
+        use crate::app::popup_actions::filter_completion_items;
+        if filter_completion_items(&items, &prefix).is_empty() && self.completion_items.is_none() {
             tracing::debug!("No completion items match prefix '{}'", prefix);
             return Ok(());
         }
@@ -143,10 +198,7 @@ impl Editor {
 
         // Rebuild popup from ALL merged items (not just the new batch)
         let all_items = self.completion_items.as_ref().unwrap();
-        let all_filtered: Vec<&lsp_types::CompletionItem> = all_items
-            .iter()
-            .filter(|item| matches_prefix(item))
-            .collect();
+        let all_filtered = filter_completion_items(all_items, &prefix);
 
         if all_filtered.is_empty() {
             tracing::debug!("No completion items match prefix '{}'", prefix);
@@ -214,17 +266,76 @@ impl Editor {
             return Ok(());
         }
 
-        // For now, just jump to the first location
-        let location = &locations[0];
+        if locations.len() > 1 {
+            self.start_goto_definition_picker(locations);
+        } else {
+            self.jump_to_lsp_location(&locations[0]);
+        }
+        Ok(())
+    }
+
+    /// Let the user pick one of several definition locations.
+    fn start_goto_definition_picker(&mut self, locations: Vec<lsp_types::Location>) {
+        let translation = self.authority.path_translation.clone();
+        let suggestions: Vec<Suggestion> = locations
+            .iter()
+            .enumerate()
+            .map(|(index, location)| {
+                let wire = crate::app::types::LspUri::from_wire(location.uri.clone());
+                let path = wire
+                    .to_host_path(translation.as_ref())
+                    .map(|p| {
+                        p.strip_prefix(&self.working_dir)
+                            .unwrap_or(&p)
+                            .display()
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| location.uri.as_str().to_string());
+                Suggestion {
+                    text: format!(
+                        "{}:{}:{}",
+                        path,
+                        location.range.start.line + 1,
+                        location.range.start.character + 1
+                    ),
+                    description: None,
+                    value: Some(index.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect();
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("lsp.select_definition", count = locations.len()).to_string(),
+            PromptType::GotoDefinition { locations },
+            suggestions,
+        ));
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(0);
+        }
+    }
+
+    /// Open the target of an LSP location and move the cursor there,
+    /// recording the current position in the jump list first so
+    /// "Navigate Back" returns to it.
+    pub(crate) fn jump_to_lsp_location(&mut self, location: &lsp_types::Location) {
+        self.position_history.commit_pending_movement();
+        let (position, anchor) = {
+            let primary = self.active_cursors().primary();
+            (primary.position, primary.anchor)
+        };
+        self.position_history
+            .record_movement(self.active_buffer(), position, anchor);
+        self.position_history.commit_pending_movement();
 
-        // Resolve the URI to a buffer. `open_lsp_uri_target` handles
-        // all three cases: host file under the workspace mount,
-        // container-only file fetched via `docker exec cat`, and
-        // unreachable (no file at the host path AND container fetch
-        // failed). The last case becomes a user-visible status
-        // message instead of a phantom empty buffer.
         let wire = crate::app::types::LspUri::from_wire(location.uri.clone());
-        let buffer_id = match self.open_lsp_uri_target(&wire) {
+        // The origin was recorded above; don't let `open_file` record it again.
+        self.suppress_position_history_once = true;
+        let open_result = self.open_lsp_uri_target(&wire);
+        self.suppress_position_history_once = false;
+        let buffer_id = match open_result {
             Ok(id) => id,
             Err(e) => {
                 if let Some(confirmation) =
@@ -236,7 +347,7 @@ impl Editor {
                         t!("file.error_opening", error = e.to_string()).to_string(),
                     );
                 }
-                return Ok(());
+                return;
             }
         };
 
@@ -297,14 +408,13 @@ impl Editor {
             )
             .to_string(),
         );
-
-        Ok(())
     }
 
     /// Check if there are any pending LSP requests
     pub fn has_pending_lsp_requests(&self) -> bool {
         !self.pending_completion_requests.is_empty()
             || self.pending_goto_definition_request.is_some()
+            || self.hover.has_pending()
     }
 
     /// Cancel any pending LSP requests
@@ -328,9 +438,15 @@ impl Editor {
             // Send cancellation to the LSP server
             self.send_lsp_cancel_request(request_id);
         }
+        for request_id in self.hover.take_pending() {
+            tracing::debug!("Canceling pending LSP hover request {}", request_id);
+            self.send_lsp_cancel_request(request_id);
+        }
     }
 
-    /// Send a cancel request to the LSP server for a specific request ID
+    /// Send a cancel request for a specific request ID to the LSP servers of
+    /// the active buffer's language. Request ids are unique across servers,
+    /// so servers that never received the request ignore the cancel.
     fn send_lsp_cancel_request(&mut self, request_id: u64) {
         // Get language from buffer state
         let buffer_id = self.active_buffer();
@@ -340,8 +456,8 @@ impl Editor {
 
         if let Some(lsp) = self.lsp.as_mut() {
             // Only send cancel if LSP is already running (no need to spawn just to cancel)
-            if let Some(handle) = lsp.get_handle_mut(&language) {
-                if let Err(e) = handle.cancel_request(request_id) {
+            for sh in lsp.get_handles_mut(&language) {
+                if let Err(e) = sh.handle.cancel_request(request_id) {
                     tracing::warn!("Failed to send LSP cancel request: {}", e);
                 } else {
                     tracing::debug!("Sent $/cancelRequest for request_id={}", request_id);
@@ -785,35 +901,14 @@ impl Editor {
             );
         }
 
-        let buffer_id = self.active_buffer();
-        let request_id = self.next_lsp_request_id;
-
-        // Use helper to ensure didOpen is sent before the request
-        let sent = self
-            .with_lsp_for_buffer(buffer_id, LspFeature::Hover, |handle, uri, _language| {
-                let result = handle.hover(
-                    request_id,
-                    uri.as_uri().clone(),
-                    line as u32,
-                    character as u32,
-                );
-                if result.is_ok() {
-                    tracing::info!(
-                        "Requested hover at {}:{}:{} (byte_pos={})",
-                        uri.as_str(),
-                        line,
-                        character,
-                        cursor_pos
-                    );
-                }
-                result.is_ok()
-            })
-            .unwrap_or(false);
-
+        let sent = self.send_hover_requests(cursor_pos, line, character);
         if sent {
-            self.next_lsp_request_id += 1;
-            self.hover
-                .record_request(request_id, line as u32, character as u32);
+            tracing::info!(
+                "Requested hover at {}:{} (byte_pos={})",
+                line,
+                character,
+                cursor_pos
+            );
         }
 
         Ok(())
@@ -841,38 +936,58 @@ impl Editor {
             );
         }
 
+        let sent = self.send_hover_requests(byte_pos, line, character);
+        if sent {
+            tracing::trace!(
+                "Mouse hover requested at {}:{} (byte_pos={})",
+                line,
+                character,
+                byte_pos
+            );
+        }
+
+        Ok(sent)
+    }
+
+    /// Send a hover request at LSP position `(line, character)` of the
+    /// active buffer to every server providing hover, and record them as
+    /// one batch whose answers are merged. Returns true if any was sent.
+    fn send_hover_requests(&mut self, byte_pos: usize, line: usize, character: usize) -> bool {
         let buffer_id = self.active_buffer();
-        let request_id = self.next_lsp_request_id;
 
-        // Use helper to ensure didOpen is sent before the request
-        let sent = self
-            .with_lsp_for_buffer(buffer_id, LspFeature::Hover, |handle, uri, _language| {
+        // Pre-allocate request IDs for all eligible servers
+        let base_request_id = self.next_lsp_request_id;
+        let counter = std::sync::atomic::AtomicU64::new(0);
+
+        let results = self.with_all_lsp_for_buffer_feature(
+            buffer_id,
+            LspFeature::Hover,
+            |handle, uri, _language| {
+                let idx = counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let request_id = base_request_id + idx;
                 let result = handle.hover(
                     request_id,
                     uri.as_uri().clone(),
                     line as u32,
                     character as u32,
                 );
-                if result.is_ok() {
-                    tracing::trace!(
-                        "Mouse hover requested at {}:{}:{} (byte_pos={})",
-                        uri.as_str(),
-                        line,
-                        character,
-                        byte_pos
-                    );
-                }
-                result.is_ok()
-            })
-            .unwrap_or(false);
-
-        if sent {
-            self.next_lsp_request_id += 1;
-            self.hover
-                .record_request(request_id, line as u32, character as u32);
-        }
+                (request_id, result.is_ok())
+            },
+        );
+        self.next_lsp_request_id = base_request_id + results.len() as u64;
 
-        Ok(sent)
+        let sent_ids: Vec<u64> = results
+            .into_iter()
+            .filter_map(|(request_id, ok)| ok.then_some(request_id))
+            .collect();
+        if sent_ids.is_empty() {
+            tracing::trace!("No hover-capable server for byte_pos={}", byte_pos);
+            return false;
+        }
+        let now = self.time_source.now();
+        self.hover
+            .record_requests(&sent_ids, line as u32, character as u32, now);
+        true
     }
 
     /// Handle hover response from LSP
@@ -883,13 +998,45 @@ impl Editor {
         is_markdown: bool,
         range: Option<((u32, u32), (u32, u32))>,
     ) {
-        // Check if this response is for the current pending request.
-        // `claim_pending` also drains the stored LSP position, which we keep
-        // around for diagnostic correlation below.
-        let Some(position) = self.hover.claim_pending(request_id) else {
-            tracing::debug!("Ignoring stale hover response: {}", request_id);
+        // Check if this response is for the current pending batch. The card
+        // is shown once every server asked has answered, with their answers
+        // merged. `claim_response` also drains the stored LSP position, which
+        // we keep around for diagnostic correlation below.
+        let response = super::hover::HoverResponse {
+            contents,
+            is_markdown,
+            range,
+        };
+        let Some((position, merged)) = self.hover.claim_response(request_id, response) else {
+            tracing::debug!(
+                "Hover response {} is stale or waiting for other servers",
+                request_id
+            );
             return;
         };
+        self.show_hover_response(position, merged);
+    }
+
+    /// Stop waiting for hover servers that have not answered in time and
+    /// show what the others returned. Returns true if a card was shown.
+    pub fn check_hover_timeout(&mut self) -> bool {
+        let now = self.time_source.now();
+        let Some((position, merged)) = self.hover.take_expired(now) else {
+            return false;
+        };
+        tracing::debug!("Hover timed out waiting for some servers; showing partial answers");
+        self.show_hover_response(position, merged);
+        true
+    }
+
+    /// Show the merged hover answer for LSP `position`, fused with any
+    /// diagnostics there.
+    fn show_hover_response(&mut self, position: (u32, u32), merged: super::hover::HoverResponse) {
+        let super::hover::HoverResponse {
+            contents,
+            is_markdown,
+            range,
+        } = merged;
         let hover_lsp_position = Some(position);
 
         // Gather any diagnostics whose range overlaps the hover position so
@@ -900,7 +1047,11 @@ impl Editor {
             .map(|pos| self.compose_hover_diagnostic_lines(pos))
             .unwrap_or_default();
 
-        if contents.is_empty() && diagnostic_lines.is_empty() {
+        // Servers answer "nothing here" in several shapes: `null`, an empty
+        // `MarkedString[]`, or markup that is only whitespace. Treat them all
+        // alike rather than showing an empty card.
+        let has_contents = !contents.trim().is_empty();
+        if !has_contents && diagnostic_lines.is_empty() {
             self.set_status_message(t!("lsp.no_hover").to_string());
             self.hover.set_symbol_range(None);
             return;
@@ -997,7 +1148,7 @@ impl Editor {
         use ratatui::style::Style;
         use unicode_width::UnicodeWidthStr;
 
-        let hover_lines: Vec<StyledLine> = if contents.is_empty() {
+        let hover_lines: Vec<StyledLine> = if !has_contents {
             Vec::new()
         } else if is_markdown {
             parse_markdown(&contents, &self.theme, Some(&self.grammar_registry))
@@ -1084,9 +1235,11 @@ impl Editor {
     /// Pre-style any diagnostics overlapping the hover position into lines
     /// ready to stack into the hover popup. Each diagnostic yields two or
     /// more styled lines:
-    ///   1. severity marker + label in `diagnostic_*_fg`, followed by
-    ///      `  (source)` dimmed — italic on theme-default foreground,
-    ///   2. one styled line per message line, in `popup_text_fg`.
+    ///   1. severity marker + label in `diagnostic_*_fg`, then the code as
+    ///      ` [code]` if there is one, followed by `  (source)` dimmed —
+    ///      italic on theme-default foreground,
+    ///   2. one styled line per message line, in `popup_text_fg`,
+    ///   3. one dimmed `↳ file:line:col message` line per related location.
     ///
     /// Multiple overlapping diagnostics are separated by a blank line.
     /// Returns an empty vec when there are no overlapping diagnostics,
@@ -1143,6 +1296,9 @@ impl Editor {
                 .add_modifier(Modifier::BOLD);
             let mut header = StyledLine::new();
             header.push(format!("{} {}", marker, label), header_style);
+            if let Some(code) = crate::services::lsp::diagnostics::diagnostic_code(diag) {
+                header.push(format!(" [{}]", code), Style::default().fg(severity_color));
+            }
             if let Some(source) = diag.source.as_deref().filter(|s| !s.is_empty()) {
                 // Dim italic source tag — reads as metadata, not as part
                 // of the diagnostic text.
@@ -1166,6 +1322,31 @@ impl Editor {
                 );
                 out.push(line);
             }
+
+            // Related locations, dimmed like the source tag. They may be
+            // in other files, so each names its file.
+            for info in diag.related_information.iter().flatten() {
+                let uri = super::types::LspUri::from_wire(info.location.uri.clone());
+                let file = uri
+                    .to_host_path(self.authority.path_translation.as_ref())
+                    .and_then(|path| {
+                        path.file_name()
+                            .map(|name| name.to_string_lossy().into_owned())
+                    })
+                    .unwrap_or_else(|| uri.as_str().to_string());
+                let mut line = StyledLine::new();
+                line.push(
+                    format!(
+                        "  ↳ {}:{}:{} {}",
+                        file,
+                        info.location.range.start.line + 1,
+                        info.location.range.start.character + 1,
+                        info.message.lines().next().unwrap_or_default()
+                    ),
+                    Style::default().fg(self.theme.tab_inactive_fg),
+                );
+                out.push(line);
+            }
         }
         out
     }
@@ -1393,57 +1574,45 @@ impl Editor {
             None => return,
         };
 
-        // Build the display content as markdown
-        let mut content = String::new();
-
-        // Add the signature label (function signature)
-        content.push_str(&signature.label);
-        content.push('\n');
-
-        // Add parameter highlighting info
         let active_param = signature_help
             .active_parameter
             .or(signature.active_parameter)
             .unwrap_or(0) as usize;
+        let param = signature
+            .parameters
+            .as_ref()
+            .and_then(|params| params.get(active_param));
 
-        // If there are parameters, highlight the active one
-        if let Some(params) = &signature.parameters {
-            if let Some(param) = params.get(active_param) {
-                // Get parameter label
-                let param_label = match &param.label {
-                    lsp_types::ParameterLabel::Simple(s) => s.clone(),
-                    lsp_types::ParameterLabel::LabelOffsets(offsets) => {
-                        // Extract substring from signature label
-                        let start = offsets[0] as usize;
-                        let end = offsets[1] as usize;
-                        if end <= signature.label.len() {
-                            signature.label[start..end].to_string()
-                        } else {
-                            String::new()
-                        }
-                    }
-                };
+        // The signature line itself, with the active parameter emphasized.
+        use crate::view::markdown::{parse_markdown, StyledLine};
+        use ratatui::style::{Modifier, Style};
 
-                if !param_label.is_empty() {
-                    content.push_str(&format!("\n> {}\n", param_label));
-                }
+        let label = &signature.label;
+        let text_style = Style::default().fg(self.theme.popup_text_fg);
+        let active_style = text_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let mut signature_line = StyledLine::new();
+        match param.and_then(|p| signature_param_range(label, &p.label)) {
+            Some(range) => {
+                signature_line.push(label[..range.start].to_string(), text_style);
+                signature_line.push(label[range.clone()].to_string(), active_style);
+                signature_line.push(label[range.end..].to_string(), text_style);
+            }
+            None => signature_line.push(label.clone(), text_style),
+        }
 
-                // Add parameter documentation if available
-                if let Some(doc) = &param.documentation {
-                    let doc_text = match doc {
-                        lsp_types::Documentation::String(s) => s.clone(),
-                        lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
-                    };
-                    if !doc_text.is_empty() {
-                        content.push('\n');
-                        content.push_str(&doc_text);
-                        content.push('\n');
-                    }
-                }
+        // Parameter and function documentation below it, as markdown
+        let mut content = String::new();
+        if let Some(doc) = param.and_then(|p| p.documentation.as_ref()) {
+            let doc_text = match doc {
+                lsp_types::Documentation::String(s) => s.clone(),
+                lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
+            };
+            if !doc_text.is_empty() {
+                content.push_str(&doc_text);
+                content.push('\n');
             }
         }
 
-        // Add function documentation if available
         if let Some(doc) = &signature.documentation {
             let doc_text = match doc {
                 lsp_types::Documentation::String(s) => s.clone(),
@@ -1455,13 +1624,24 @@ impl Editor {
             }
         }
 
+        let mut lines = vec![signature_line];
+        if !content.trim().is_empty() {
+            lines.push(StyledLine::new());
+            lines.extend(parse_markdown(
+                &content,
+                &self.theme,
+                Some(&self.grammar_registry),
+            ));
+        }
+
         // Create a popup with markdown rendering (like hover popup)
-        use crate::view::popup::{Popup, PopupPosition};
-        use ratatui::style::Style;
+        use crate::view::popup::{Popup, PopupContent, PopupPosition, PopupResolver};
 
-        let mut popup = Popup::markdown(&content, &self.theme, Some(&self.grammar_registry));
+        let mut popup = Popup::markdown("", &self.theme, Some(&self.grammar_registry));
+        popup.content = PopupContent::Markdown(lines);
         popup.title = Some(t!("lsp.popup_signature").to_string());
         popup.transient = true;
+        popup.resolver = PopupResolver::SignatureHelp;
         popup.position = PopupPosition::BelowCursor;
         popup.width = 60;
         popup.max_height = 20;
@@ -1469,8 +1649,16 @@ impl Editor {
         popup.background_style = Style::default().bg(self.theme.popup_bg);
         popup.focus_key_hint = self.popup_focus_key_hint();
 
-        // Show the popup
+        // Show the popup, replacing the previous signature help (typing a
+        // comma re-requests it for the next parameter)
         if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            if state
+                .popups
+                .top()
+                .is_some_and(|p| p.resolver == PopupResolver::SignatureHelp)
+            {
+                state.popups.hide();
+            }
             state.popups.show(popup);
             tracing::info!(
                 "Showing signature help popup for {} signatures",
@@ -1912,48 +2100,321 @@ impl Editor {
         }
     }
 
-    /// Apply formatting edits from textDocument/formatting response.
-    pub(crate) fn apply_formatting_edits(
+    /// Handle a textDocument/formatting or rangeFormatting response.
+    ///
+    /// A format-on-save answer belongs to a held save and is handled like a
+    /// willSaveWaitUntil answer; one arriving after that save timed out is
+    /// dropped.
+    pub(crate) fn handle_formatting_response(
         &mut self,
+        request_id: u64,
         uri: &str,
         edits: Vec<lsp_types::TextEdit>,
-    ) -> AnyhowResult<usize> {
-        // Find the buffer for this URI
-        let buffer_id = self
+    ) -> AnyhowResult<()> {
+        if self.format_on_save_requests.remove(&request_id) {
+            return self.handle_will_save_wait_until_response(request_id, uri, edits);
+        }
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        let Some(buffer_id) = self
             .buffer_metadata
             .iter()
             .find(|(_, meta)| meta.file_uri().map(|u| u.as_str() == uri).unwrap_or(false))
-            .map(|(id, _)| *id);
-
-        if let Some(buffer_id) = buffer_id {
-            let count = self.apply_lsp_text_edits(buffer_id, edits)?;
-            self.set_status_message(format!("Formatted ({} edits)", count));
-            Ok(count)
-        } else {
+            .map(|(id, _)| *id)
+        else {
             tracing::warn!("Cannot apply formatting: no buffer for URI {}", uri);
-            Ok(0)
+            return Ok(());
+        };
+
+        let count = self.apply_formatting_edits(buffer_id, edits)?;
+        self.set_status_message(format!("Formatted ({} edits)", count));
+        Ok(())
+    }
+
+    /// Apply formatting edits to a buffer as one undoable edit.
+    ///
+    /// Cursors keep their line and their column relative to the line's
+    /// indentation, so reindenting or rewriting the whole document (as many
+    /// formatters do) leaves them on the same code.
+    fn apply_formatting_edits(
+        &mut self,
+        buffer_id: BufferId,
+        edits: Vec<lsp_types::TextEdit>,
+    ) -> AnyhowResult<usize> {
+        let split_id = self
+            .split_manager
+            .splits_for_buffer(buffer_id)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| self.split_manager.active_split());
+
+        // (cursor, [position, anchor] as (line, column, indent width))
+        let mut logical_cursors = Vec::new();
+        if let (Some(state), Some(bvs)) = (
+            self.buffers.get(&buffer_id),
+            self.split_view_states
+                .get(&split_id)
+                .and_then(|vs| vs.keyed_states.get(&buffer_id)),
+        ) {
+            let logical = |pos: usize| {
+                let line = state.buffer.get_line_number(pos);
+                let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
+                (
+                    line,
+                    pos - line_start,
+                    line_indent_width(&state.buffer, line),
+                )
+            };
+            for (id, cursor) in bvs.cursors.iter() {
+                logical_cursors.push((id, logical(cursor.position), cursor.anchor.map(logical)));
+            }
         }
+
+        let count = self.apply_lsp_text_edits(buffer_id, edits)?;
+
+        if let (Some(state), Some(bvs)) = (
+            self.buffers.get(&buffer_id),
+            self.split_view_states
+                .get_mut(&split_id)
+                .and_then(|vs| vs.keyed_states.get_mut(&buffer_id)),
+        ) {
+            let restore = |(line, column, indent): (usize, usize, usize)| {
+                let last_line = state.buffer.get_line_number(state.buffer.len());
+                let line = line.min(last_line);
+                let new_indent = line_indent_width(&state.buffer, line);
+                let column = if column < indent {
+                    column.min(new_indent)
+                } else {
+                    column - indent + new_indent
+                };
+                state.buffer.line_col_to_position(line, column).min(
+                    state
+                        .buffer
+                        .line_start_offset(line)
+                        .unwrap_or(0)
+                        .saturating_add(line_content_len(&state.buffer, line)),
+                )
+            };
+            for (id, position, anchor) in logical_cursors {
+                if let Some(cursor) = bvs.cursors.get_mut(id) {
+                    cursor.position = restore(position);
+                    cursor.anchor = anchor.map(restore);
+                }
+            }
+        }
+
+        Ok(count)
     }
 
-    /// Request document formatting from LSP.
+    /// Request document formatting from LSP. With a selection, only the
+    /// selected range is formatted (`textDocument/rangeFormatting`) when the
+    /// server supports it.
     pub(crate) fn request_formatting(&mut self) {
+        let selection = self.active_cursors().primary().selection_range();
+        if let Err(message) = self.send_formatting_request(selection) {
+            self.set_status_message(message);
+        }
+    }
+
+    /// Ask the active buffer's servers for edits to apply before it is
+    /// saved: `textDocument/willSaveWaitUntil`, and formatting when the
+    /// language has `format_on_save` without an external formatter. Returns
+    /// true when the save is held back: the buffer is written once, after
+    /// every server has answered or `WILL_SAVE_WAIT_UNTIL_TIMEOUT` passed.
+    pub(crate) fn request_pre_save_edits(&mut self) -> bool {
         let buffer_id = self.active_buffer();
-        let metadata = match self.buffer_metadata.get(&buffer_id) {
-            Some(m) if m.lsp_enabled => m,
-            _ => {
-                self.set_status_message("LSP not available for this buffer".to_string());
-                return;
+        if self.pending_will_saves.contains_key(&buffer_id) {
+            return true;
+        }
+        let Some(uri) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|meta| meta.file_uri())
+            .cloned()
+        else {
+            return false;
+        };
+        let Some((language, version)) = self
+            .buffers
+            .get(&buffer_id)
+            .map(|state| (state.language.clone(), state.buffer.version()))
+        else {
+            return false;
+        };
+        let Some(lsp) = self.lsp.as_mut() else {
+            return false;
+        };
+
+        let mut requests = HashSet::new();
+        for sh in lsp.get_handles_mut(&language) {
+            if !(sh.capabilities.initialized && sh.capabilities.will_save_wait_until) {
+                continue;
+            }
+            let request_id = self.next_lsp_request_id;
+            match sh
+                .handle
+                .will_save_wait_until(request_id, uri.as_uri().clone())
+            {
+                Ok(()) => {
+                    self.next_lsp_request_id += 1;
+                    requests.insert(request_id);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to send willSaveWaitUntil to '{}': {}", sh.name, e)
+                }
+            }
+        }
+
+        let format_via_lsp = self
+            .config
+            .languages
+            .get(&language)
+            .is_some_and(|lang| lang.format_on_save && lang.formatter.is_none());
+        if format_via_lsp {
+            match self.send_formatting_request(None) {
+                Ok(request_id) => {
+                    self.format_on_save_requests.insert(request_id);
+                    requests.insert(request_id);
+                }
+                Err(message) => tracing::debug!("Skipping LSP format on save: {}", message),
             }
+        }
+        if requests.is_empty() {
+            return false;
+        }
+
+        tracing::debug!(
+            "Holding save of {} for {} pre-save request(s)",
+            uri.as_str(),
+            requests.len()
+        );
+        self.pending_will_saves.insert(
+            buffer_id,
+            PendingWillSave {
+                requests,
+                version,
+                deadline: Instant::now() + WILL_SAVE_WAIT_UNTIL_TIMEOUT,
+            },
+        );
+        true
+    }
+
+    /// Handle a textDocument/willSaveWaitUntil (or format-on-save)
+    /// response: apply its edits and, once the last server has answered,
+    /// write the buffer.
+    ///
+    /// Edits are dropped if the buffer changed after the save was requested,
+    /// since they were computed against the text being saved.
+    pub(crate) fn handle_will_save_wait_until_response(
+        &mut self,
+        request_id: u64,
+        uri: &str,
+        edits: Vec<lsp_types::TextEdit>,
+    ) -> AnyhowResult<()> {
+        let Some(buffer_id) = self
+            .pending_will_saves
+            .iter()
+            .find(|(_, pending)| pending.requests.contains(&request_id))
+            .map(|(id, _)| *id)
+        else {
+            tracing::debug!("Ignoring late pre-save response for {}", uri);
+            return Ok(());
         };
 
-        let uri = match metadata.file_uri() {
-            Some(u) => u.clone(),
-            None => return,
+        let version = self.buffers.get(&buffer_id).map(|s| s.buffer.version());
+        let expected = self.pending_will_saves[&buffer_id].version;
+        if !edits.is_empty() {
+            if version == Some(expected) {
+                self.apply_formatting_edits(buffer_id, edits)?;
+            } else {
+                tracing::debug!("Dropping pre-save edits for {}: buffer changed", uri);
+            }
+        }
+
+        let new_version = self.buffers.get(&buffer_id).map(|s| s.buffer.version());
+        let Some(pending) = self.pending_will_saves.get_mut(&buffer_id) else {
+            return Ok(());
         };
+        pending.requests.remove(&request_id);
+        if version == Some(expected) {
+            // Our own edits must not make the next server's answer stale
+            pending.version = new_version.unwrap_or(expected);
+        }
+        if pending.requests.is_empty() {
+            self.pending_will_saves.remove(&buffer_id);
+            self.save_held_buffer(buffer_id);
+        }
+        Ok(())
+    }
 
-        let language = match self.buffers.get(&buffer_id).map(|s| s.language.clone()) {
-            Some(l) => l,
-            None => return,
+    /// Write buffers whose pre-save answers did not arrive in time.
+    /// Returns true if any buffer was saved.
+    pub fn check_will_save_timeout(&mut self) -> bool {
+        let now = Instant::now();
+        let expired: Vec<BufferId> = self
+            .pending_will_saves
+            .iter()
+            .filter(|(_, pending)| now >= pending.deadline)
+            .map(|(id, _)| *id)
+            .collect();
+        for buffer_id in &expired {
+            tracing::warn!("willSaveWaitUntil timed out, saving without server edits");
+            self.pending_will_saves.remove(buffer_id);
+            self.save_held_buffer(*buffer_id);
+        }
+        !expired.is_empty()
+    }
+
+    /// Write a buffer whose save was held back for willSaveWaitUntil.
+    fn save_held_buffer(&mut self, buffer_id: BufferId) {
+        let result = if buffer_id == self.active_buffer() {
+            self.save()
+        } else if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            // The user moved on; save quietly like auto-save does
+            let path = state.buffer.file_path().map(|p| p.to_path_buf());
+            match state.buffer.save() {
+                Ok(()) => self.finalize_save_buffer(buffer_id, path, true),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            let msg = format!("{}", e);
+            self.status_message = Some(t!("file.save_failed", error = &msg).to_string());
+        }
+    }
+
+    /// Send a formatting request for the active buffer, for `range` (byte
+    /// offsets) or the whole document. Returns the request ID, or a status
+    /// message explaining why nothing was sent.
+    fn send_formatting_request(
+        &mut self,
+        range: Option<std::ops::Range<usize>>,
+    ) -> Result<u64, String> {
+        let buffer_id = self.active_buffer();
+        let unavailable = || "LSP not available for this buffer".to_string();
+        let uri = match self.buffer_metadata.get(&buffer_id) {
+            Some(m) if m.lsp_enabled => m.file_uri().ok_or_else(unavailable)?.clone(),
+            _ => return Err(unavailable()),
+        };
+        let state = self.buffers.get(&buffer_id).ok_or_else(unavailable)?;
+        let language = state.language.clone();
+        let lsp_range = range.map(|range| {
+            let (start_line, start_char) = state.buffer.position_to_lsp_position(range.start);
+            let (end_line, end_char) = state.buffer.position_to_lsp_position(range.end);
+            (
+                start_line as u32,
+                start_char as u32,
+                end_line as u32,
+                end_char as u32,
+            )
+        });
+        let whole_document = {
+            let (end_line, end_char) = state.buffer.position_to_lsp_position(state.buffer.len());
+            (0, 0, end_line as u32, end_char as u32)
         };
 
         let tab_size = self.config.editor.tab_size as u32;
@@ -1962,20 +2423,44 @@ impl Editor {
         self.next_lsp_request_id += 1;
         let request_id = self.next_lsp_request_id;
 
-        if let Some(lsp) = &mut self.lsp {
-            if let Some(sh) = lsp.handle_for_feature_mut(&language, LspFeature::Format) {
-                if let Err(e) = sh.handle.document_formatting(
+        let sh = self
+            .lsp
+            .as_mut()
+            .and_then(|lsp| lsp.handle_for_feature_mut(&language, LspFeature::Format))
+            .ok_or_else(|| "Formatting not supported by LSP server".to_string())?;
+
+        // Range formatting for a selection, or for the whole document when
+        // the server only implements range formatting.
+        let range = match lsp_range {
+            Some(range) if sh.capabilities.document_range_formatting => Some(range),
+            _ if !sh.capabilities.document_formatting => Some(whole_document),
+            _ => None,
+        };
+        let sent = match range {
+            Some((start_line, start_char, end_line, end_char)) => {
+                sh.handle.document_range_formatting(
                     request_id,
                     uri.as_uri().clone(),
+                    start_line,
+                    start_char,
+                    end_line,
+                    end_char,
                     tab_size,
                     insert_spaces,
-                ) {
-                    tracing::warn!("Failed to request formatting: {}", e);
-                }
-            } else {
-                self.set_status_message("Formatting not supported by LSP server".to_string());
+                )
             }
-        }
+            None => sh.handle.document_formatting(
+                request_id,
+                uri.as_uri().clone(),
+                tab_size,
+                insert_spaces,
+            ),
+        };
+
+        sent.map(|()| request_id).map_err(|e| {
+            tracing::warn!("Failed to request formatting: {}", e);
+            e
+        })
     }
 
     /// Handle find references response from LSP
@@ -2387,6 +2872,13 @@ impl Editor {
         );
 
         let mut total_changes = 0;
+        // Remember where each buffer's undo history stood, so the edits this
+        // call makes can be linked into one undo step afterwards.
+        let log_positions: HashMap<BufferId, usize> = self
+            .event_logs
+            .iter()
+            .map(|(id, log)| (*id, log.current_index()))
+            .collect();
 
         // Handle changes (map of URI -> Vec<TextEdit>)
         if let Some(changes) = workspace_edit.changes {
@@ -2444,6 +2936,14 @@ impl Editor {
             }
         }
 
+        let edited: Vec<BufferId> = self
+            .event_logs
+            .iter()
+            .filter(|(id, log)| log.current_index() != log_positions.get(id).copied().unwrap_or(0))
+            .map(|(id, _)| *id)
+            .collect();
+        self.link_undo_entries(&edited);
+
         Ok(total_changes)
     }
 
@@ -2454,6 +2954,9 @@ impl Editor {
         result: Result<lsp_types::WorkspaceEdit, String>,
     ) -> AnyhowResult<()> {
         match result {
+            Ok(workspace_edit) if self.config.editor.preview_workspace_edits => {
+                self.preview_workspace_edit(workspace_edit)?;
+            }
             Ok(workspace_edit) => {
                 let total_changes = self.apply_workspace_edit(workspace_edit)?;
                 self.status_message = Some(t!("lsp.renamed", count = total_changes).to_string());
@@ -3158,10 +3661,17 @@ impl Editor {
     }
 
     /// Request folding ranges for a buffer if supported and needed.
+    ///
+    /// Buffers whose language server doesn't provide folding ranges get
+    /// locally computed ones instead.
     pub(crate) fn request_folding_ranges_for_buffer(&mut self, buffer_id: BufferId) {
         if self.folding_ranges_in_flight.contains_key(&buffer_id) {
             return;
         }
+        if !self.lsp_folding_ranges_available(buffer_id) {
+            self.apply_local_folding_ranges(buffer_id);
+            return;
+        }
 
         let Some(metadata) = self.buffer_metadata.get(&buffer_id) else {
             return;
@@ -3223,9 +3733,62 @@ impl Editor {
         }
     }
 
+    /// Whether folding ranges for a buffer come from its language server.
+    pub(super) fn lsp_folding_ranges_available(&self, buffer_id: BufferId) -> bool {
+        let lsp_enabled = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|metadata| metadata.lsp_enabled);
+        let Some(language) = self.buffers.get(&buffer_id).map(|s| s.language.as_str()) else {
+            return false;
+        };
+        lsp_enabled
+            && self
+                .lsp
+                .as_ref()
+                .is_some_and(|lsp| lsp.folding_ranges_supported(language))
+    }
+
+    /// Replace a buffer's folding ranges with ones computed from its text
+    /// (bracket pairs or indentation, depending on the language). Large
+    /// files are left to the on-demand indent fallback.
+    pub(crate) fn apply_local_folding_ranges(&mut self, buffer_id: BufferId) {
+        use crate::view::folding::local_folding;
+
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        if state.buffer.is_large_file() {
+            return;
+        }
+        let Some(text) = state.buffer.to_string() else {
+            return;
+        };
+        let strategy = local_folding::strategy_for_language(&state.language);
+        let ranges = local_folding::folding_ranges(
+            text.as_bytes(),
+            strategy,
+            state.buffer_settings.tab_size,
+        );
+        state
+            .folding_ranges
+            .set_from_lsp(&state.buffer, &mut state.marker_list, ranges);
+    }
+
+    /// Whether full-document semantic tokens (with delta updates) are used
+    /// for a buffer: when enabled in the config, and always for buffers
+    /// without a built-in highlighter, where they are the only highlighting.
+    fn semantic_tokens_full_enabled(&self, buffer_id: BufferId) -> bool {
+        self.config.editor.enable_semantic_tokens_full
+            || self
+                .buffers
+                .get(&buffer_id)
+                .is_some_and(|state| !state.highlighter.has_highlighting())
+    }
+
     /// Request semantic tokens for a specific buffer if supported and needed.
     pub(crate) fn maybe_request_semantic_tokens(&mut self, buffer_id: BufferId) {
-        if !self.config.editor.enable_semantic_tokens_full {
+        if !self.semantic_tokens_full_enabled(buffer_id) {
             return;
         }
 
@@ -3330,7 +3893,7 @@ impl Editor {
 
     /// Schedule a full semantic token refresh for a buffer (debounced).
     pub(crate) fn schedule_semantic_tokens_full_refresh(&mut self, buffer_id: BufferId) {
-        if !self.config.editor.enable_semantic_tokens_full {
+        if !self.semantic_tokens_full_enabled(buffer_id) {
             return;
         }
 
@@ -3341,7 +3904,7 @@ impl Editor {
 
     /// Issue a debounced full semantic token request if the timer has elapsed.
     pub(crate) fn maybe_request_semantic_tokens_full_debounced(&mut self, buffer_id: BufferId) {
-        if !self.config.editor.enable_semantic_tokens_full {
+        if !self.semantic_tokens_full_enabled(buffer_id) {
             self.semantic_tokens_full_debounce.remove(&buffer_id);
             return;
         }
@@ -3531,7 +4094,7 @@ mod tests {
     fn test_fs() -> Arc<dyn crate::model::filesystem::FileSystem + Send + Sync> {
         Arc::new(StdFileSystem)
     }
-    use super::{lsp_range_contains, Editor};
+    use super::{lsp_range_contains, signature_param_range, Editor};
 
     fn range(sl: u32, sc: u32, el: u32, ec: u32) -> lsp_types::Range {
         lsp_types::Range {
@@ -3546,6 +4109,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_signature_param_range() {
+        use lsp_types::ParameterLabel;
+
+        let label = "fn add(a: i32, b: i32) -> i32";
+        let range = signature_param_range(label, &ParameterLabel::LabelOffsets([15, 21]));
+        assert_eq!(range.map(|r| &label[r]), Some("b: i32"));
+
+        // Simple labels are searched for inside the parentheses only
+        let label = "a(a: u8)";
+        let range = signature_param_range(label, &ParameterLabel::Simple("a".into()));
+        assert_eq!(range, Some(2..3));
+
+        // Offsets are UTF-16 code units, not bytes
+        let label = "f(é: u8, x: u8)";
+        let range = signature_param_range(label, &ParameterLabel::LabelOffsets([9, 14]));
+        assert_eq!(range.map(|r| &label[r]), Some("x: u8"));
+
+        // Out-of-range offsets are ignored
+        assert_eq!(
+            signature_param_range("f()", &ParameterLabel::LabelOffsets([2, 40])),
+            None
+        );
+    }
+
     #[test]
     fn test_lsp_range_contains_inclusive_start_exclusive_end() {
         let r = range(3, 10, 3, 20);
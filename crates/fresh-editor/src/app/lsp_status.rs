@@ -98,8 +98,9 @@ pub(crate) fn compose_lsp_status(
     //    rust-analyzer alternates between a 5-char "Roots" message and a
     //    60-char file path) and the indicator width would twitch every few
     //    hundred milliseconds.  Instead, show a stable "LSP " plus a 1-cell
-    //    Braille spinner advanced by wall-clock time.  The popup surfaces
-    //    the live progress text (see `show_lsp_status_popup`).
+    //    Braille spinner advanced by wall-clock time.  The live progress
+    //    text goes with the status messages instead (see
+    //    `compose_lsp_progress_message`) and in the status popup.
     if lsp_progress
         .values()
         .any(|info| info.language == current_language)
@@ -177,6 +178,36 @@ pub(crate) fn compose_lsp_status(
     (String::new(), LspIndicatorState::None)
 }
 
+/// Compose the progress text shown with the status messages while the
+/// current buffer's language servers report `$/progress` work.
+///
+/// Shows the most recently updated task as `title: message (NN%)`, with a
+/// count of the other tasks running alongside it. `None` when there is no
+/// progress for the language.
+pub(crate) fn compose_lsp_progress_message(
+    current_language: &str,
+    lsp_progress: &HashMap<String, LspProgressInfo>,
+) -> Option<String> {
+    let tasks: Vec<&LspProgressInfo> = lsp_progress
+        .values()
+        .filter(|info| info.language == current_language)
+        .collect();
+    let latest = tasks.iter().max_by_key(|info| info.updated_at)?;
+
+    let mut text = latest.title.clone();
+    if let Some(message) = latest.message.as_deref().filter(|m| !m.is_empty()) {
+        text.push_str(": ");
+        text.push_str(message);
+    }
+    if let Some(percentage) = latest.percentage {
+        text.push_str(&format!(" ({}%)", percentage));
+    }
+    if tasks.len() > 1 {
+        text.push_str(&format!(" (+{} more)", tasks.len() - 1));
+    }
+    Some(text)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,6 +222,7 @@ mod tests {
                 title: "indexing".to_string(),
                 message: None,
                 percentage: Some(42),
+                updated_at: std::time::Instant::now(),
             },
         );
         m
@@ -481,4 +513,30 @@ mod tests {
         );
         assert_eq!(state, LspIndicatorState::Off);
     }
+
+    #[test]
+    fn progress_message_shows_latest_task_and_count() {
+        let start = std::time::Instant::now();
+        let mut progress = progress_for("rust");
+        assert_eq!(
+            compose_lsp_progress_message("rust", &progress).as_deref(),
+            Some("indexing (42%)")
+        );
+        assert_eq!(compose_lsp_progress_message("python", &progress), None);
+
+        progress.insert(
+            "tok-2".to_string(),
+            LspProgressInfo {
+                language: "rust".to_string(),
+                title: "Fetching".to_string(),
+                message: Some("serde".to_string()),
+                percentage: None,
+                updated_at: start + std::time::Duration::from_secs(1),
+            },
+        );
+        assert_eq!(
+            compose_lsp_progress_message("rust", &progress).as_deref(),
+            Some("Fetching: serde (+1 more)")
+        );
+    }
 }
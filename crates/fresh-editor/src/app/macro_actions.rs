@@ -99,13 +99,22 @@ impl Editor {
 
         format!("{} → Play Macro", palette_key)
     }
-    /// Play back a recorded macro synchronously.
+
+    /// Play back a recorded macro once. See [`Self::replay_macro`].
+    pub(super) fn play_macro(&mut self, key: char) {
+        self.replay_macro(key, 1);
+    }
+
+    /// Play back a recorded macro `count` times, synchronously.
     ///
     /// All actions are executed in a tight loop. Between each action,
     /// `recompute_layout` is called so that visual-line movements
     /// (MoveLineEnd, etc.) see correct, up-to-date layout information.
     /// Drawing is deferred until the next render cycle.
-    pub(super) fn play_macro(&mut self, key: char) {
+    ///
+    /// Each replay is grouped into a single undo step, as long as the macro
+    /// leaves the same buffer active that it started in.
+    pub(super) fn replay_macro(&mut self, key: char, count: usize) {
         // Prevent recursive macro playback
         if self.macros.is_playing() {
             return;
@@ -124,11 +133,19 @@ impl Editor {
         let action_count = actions.len();
         let width = self.cached_layout.last_frame_width;
         let height = self.cached_layout.last_frame_height;
-        for action in actions {
-            if let Err(e) = self.handle_action(action) {
-                tracing::warn!("Macro action failed: {}", e);
+        for _ in 0..count.max(1) {
+            let buffer = self.active_buffer();
+            let log_start = self.active_event_log().current_index();
+            for action in actions.iter().cloned() {
+                if let Err(e) = self.handle_action(action) {
+                    tracing::warn!("Macro action failed: {}", e);
+                }
+                self.recompute_layout(width, height);
+            }
+            if self.active_buffer() == buffer {
+                self.active_event_log_mut()
+                    .group_since(log_start, format!("Macro {}", key));
             }
-            self.recompute_layout(width, height);
         }
         self.macros.end_play();
 
@@ -224,6 +241,7 @@ impl Editor {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
@@ -298,6 +316,7 @@ impl Editor {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
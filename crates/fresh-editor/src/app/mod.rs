@@ -2,8 +2,10 @@ mod action_events;
 mod active_focus;
 mod async_dispatch;
 mod async_messages;
+mod background_save;
 mod bookmark_actions;
 mod bookmarks;
+mod breakpoints;
 mod buffer_close;
 mod buffer_config_resolve;
 mod buffer_groups;
@@ -13,8 +15,10 @@ pub mod calibration_wizard;
 mod click_geometry;
 mod click_handlers;
 mod clipboard;
+mod command_history;
 mod composite_buffer_actions;
 mod dabbrev_actions;
+mod diagnostic_explain;
 mod diagnostic_jumps;
 mod editor_accessors;
 mod editor_init;
@@ -27,6 +31,11 @@ mod file_open_input;
 mod file_open_orchestrators;
 mod file_open_queue;
 mod file_operations;
+mod file_rename;
+mod file_under_cursor;
+mod git_blame;
+mod git_gutter;
+mod git_stage;
 mod help;
 mod help_actions;
 mod hover;
@@ -49,6 +58,7 @@ mod menu_context;
 mod mouse_input;
 mod navigation;
 mod on_save_actions;
+mod open_url;
 mod path_utils;
 mod plugin_commands;
 mod plugin_dispatch;
@@ -57,6 +67,7 @@ mod popup_dialogs;
 mod popup_overlay_actions;
 mod prompt_actions;
 mod prompt_lifecycle;
+mod recent_files;
 mod recovery_actions;
 mod regex_replace;
 mod render;
@@ -70,21 +81,29 @@ mod settings_actions;
 mod settings_prompts;
 mod shell_command;
 mod smart_home;
+mod snippet_session;
+mod spell_check_actions;
 mod split_actions;
 mod stdin_stream;
 mod tab_drag;
+mod tab_pins;
+mod task_runner;
 mod terminal;
 mod terminal_input;
 mod terminal_mouse;
 mod text_ops;
 mod theme_inspect;
+mod toasts;
 mod toggle_actions;
 pub mod types;
 mod undo_actions;
+mod user_snippets;
 mod view_actions;
 mod virtual_buffers;
 pub mod warning_domains;
+mod watched_files;
 pub mod workspace;
+mod workspace_edit_preview;
 
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
@@ -116,6 +135,9 @@ pub fn editor_tick(
     if editor.process_line_scan() {
         needs_render = true;
     }
+    if editor.process_background_saves() {
+        needs_render = true;
+    }
     let search_scan = {
         let _s = tracing::info_span!("process_search_scan").entered();
         editor.process_search_scan()
@@ -133,16 +155,32 @@ pub fn editor_tick(
     if editor.check_mouse_hover_timer() {
         needs_render = true;
     }
+    if editor.check_hover_timeout() {
+        needs_render = true;
+    }
+    if editor.check_drag_auto_scroll() {
+        needs_render = true;
+    }
     if editor.check_semantic_highlight_timer() {
         needs_render = true;
     }
     if editor.check_completion_trigger_timer() {
         needs_render = true;
     }
+    if editor.check_will_save_timeout() {
+        needs_render = true;
+    }
+    editor.check_recent_files_save();
+    if editor.check_will_rename_timeout() {
+        needs_render = true;
+    }
     editor.check_diagnostic_pull_timer();
     if editor.check_warning_log() {
         needs_render = true;
     }
+    if editor.check_toast_timers() {
+        needs_render = true;
+    }
     if editor.poll_stdin_streaming() {
         needs_render = true;
     }
@@ -293,6 +331,26 @@ pub struct DabbrevCycleState {
     pub index: usize,
 }
 
+/// State for cycling buffers in most-recently-used order (Ctrl+Tab style).
+///
+/// Repeated presses walk further back through a snapshot of the split's
+/// focus history without reordering it. The history is only updated when
+/// the cycle ends (Ctrl is released, the presses pause, or any other
+/// action is taken), so the buffer landed on becomes the most recent one
+/// and the buffer the cycle started from the next.
+#[derive(Debug, Clone)]
+pub struct RecentBufferCycle {
+    /// The split whose buffers are being cycled.
+    pub split_id: LeafId,
+    /// Buffers in most-recently-used order when the cycle started, with the
+    /// buffer that was active at that point first.
+    pub order: Vec<BufferId>,
+    /// Current index into `order`.
+    pub index: usize,
+    /// When the cycle last moved, for ending it after a pause.
+    pub last_step: std::time::Instant,
+}
+
 /// Snapshot of cursor and viewport state used to restore the original position
 /// when a goto-line preview is abandoned (cancel, or the user edits the input
 /// so it no longer targets a line).
@@ -332,6 +390,10 @@ pub struct Editor {
     /// Event log per buffer (for undo/redo)
     event_logs: HashMap<BufferId, EventLog>,
 
+    /// Id for the next group of writes across buffers that undo/redo as
+    /// one step (multi-file workspace edits)
+    next_undo_link: u64,
+
     /// Next buffer ID to assign
     next_buffer_id: usize,
 
@@ -404,6 +466,17 @@ pub struct Editor {
     /// `expanded_menus_cache` can detect a registry swap via `Arc::ptr_eq`.
     theme_registry: Arc<crate::view::theme::ThemeRegistry>,
 
+    /// Theme directories contributed by installed bundle packages, kept so
+    /// `reload_themes` rescans the same sources as startup
+    bundle_theme_dirs: Vec<PathBuf>,
+
+    /// Source file of the active theme and its last seen modification time,
+    /// polled so edits to a theme under development are applied live
+    theme_file_watch: Option<(PathBuf, Option<std::time::SystemTime>)>,
+
+    /// Last time we polled the active theme file for changes
+    last_theme_file_poll: std::time::Instant,
+
     /// Memoised `MenuConfig` with `DynamicSubmenu` items expanded against
     /// the current theme registry.
     expanded_menus_cache: crate::view::ui::ExpandedMenusCache,
@@ -426,6 +499,12 @@ pub struct Editor {
     /// Shared clipboard (handles both internal and system clipboard)
     clipboard: crate::services::clipboard::Clipboard,
 
+    /// Most recent clipboard paste, swappable by paste-cycling
+    yank_cycle: Option<clipboard::YankCycle>,
+
+    /// Selections stacked by expand-selection-to-scope, for shrinking back
+    scope_selection: Option<text_ops::ScopeSelectionHistory>,
+
     /// Should the editor quit?
     should_quit: bool,
 
@@ -555,6 +634,10 @@ pub struct Editor {
     /// `PluginCommand::SetRemoteIndicatorState`.
     pub remote_indicator_override: Option<crate::view::ui::status_bar::RemoteIndicatorOverride>,
 
+    /// Status bar segments registered by plugins, highest priority first.
+    /// See `PluginCommand::SetStatusBarSegment`.
+    plugin_status_segments: Vec<crate::view::ui::status_bar::PluginStatusSegment>,
+
     /// Local filesystem for editor-internal files (log files, status
     /// log). Stays separate from `authority` because these are the
     /// editor's own private state — they live on the host disk
@@ -662,6 +745,19 @@ pub struct Editor {
     /// dabbrev session. Reset when any other action is taken.
     dabbrev_state: Option<DabbrevCycleState>,
 
+    /// Tabstops of the snippet completion being filled in, if any.
+    /// Ended by Escape or by acting outside the current tabstop.
+    snippet_session: Option<snippet_session::SnippetSession>,
+
+    /// Most-recently-used buffer cycling state (Ctrl+Tab). `None` when not
+    /// cycling. Ended on Ctrl release, after a pause, or when any other
+    /// action is taken.
+    recent_buffer_cycle: Option<RecentBufferCycle>,
+
+    /// Launches URLs for "Open URL Under Cursor"; the system browser
+    /// unless replaced (in tests).
+    url_opener: open_url::UrlOpener,
+
     /// Pending LSP go-to-definition request ID (if any)
     pending_goto_definition_request: Option<u64>,
 
@@ -674,6 +770,17 @@ pub struct Editor {
     /// Pending LSP signature help request ID (if any)
     pending_signature_help_request: Option<u64>,
 
+    /// LSP formatting requests sent by format-on-save, answered as part of
+    /// a held save (`pending_will_saves`)
+    format_on_save_requests: HashSet<u64>,
+
+    /// Saves waiting for `textDocument/willSaveWaitUntil` or format-on-save
+    /// answers, by buffer
+    pending_will_saves: HashMap<BufferId, types::PendingWillSave>,
+
+    /// A "Rename File" waiting for `workspace/willRenameFiles` answers
+    pending_file_rename: Option<types::PendingFileRename>,
+
     /// Pending LSP code actions request IDs (supports merging from multiple servers)
     pending_code_actions_requests: HashSet<u64>,
 
@@ -706,6 +813,12 @@ pub struct Editor {
     /// Next time a folding range refresh is allowed for a buffer
     folding_ranges_debounce: HashMap<BufferId, Instant>,
 
+    /// Git HEAD content per buffer, used as the base for the native git gutter
+    git_gutter_bases: HashMap<BufferId, git_gutter::GitGutterBase>,
+
+    /// Blame data for buffers with git blame annotations shown
+    git_blames: HashMap<BufferId, git_blame::GitBlame>,
+
     /// Pending semantic token requests keyed by LSP request ID
     pending_semantic_token_requests: HashMap<u64, SemanticTokenFullRequest>,
 
@@ -835,6 +948,13 @@ pub struct Editor {
     /// This provides a generic history system that works for all prompt types including plugin prompts.
     prompt_histories: HashMap<String, crate::input::input_history::InputHistory>,
 
+    /// Recently opened files, persisted to the data directory
+    recent_files: crate::input::recent_files::RecentFiles,
+
+    /// When the changed recent files list is next written out, `None` while
+    /// it matches the file on disk
+    recent_files_save_due: Option<std::time::Instant>,
+
     /// Pending async prompt callback ID (for editor.prompt() API)
     /// When the prompt is confirmed, the callback is resolved with the input text.
     /// When cancelled, the callback is resolved with null.
@@ -904,6 +1024,10 @@ pub struct Editor {
     /// still references the previous map.
     stored_diagnostics: Arc<HashMap<String, Vec<lsp_types::Diagnostic>>>,
 
+    /// Diagnostics are hidden from buffers ("Toggle Diagnostics"); they are
+    /// still collected and re-applied when shown again.
+    diagnostics_hidden: bool,
+
     /// Stored LSP folding ranges per URI
     /// Maps file URI string to Vec of folding ranges for that file
     stored_folding_ranges: Arc<HashMap<String, Vec<lsp_types::FoldingRange>>>,
@@ -984,6 +1108,12 @@ pub struct Editor {
     /// Used when closing a modified buffer that needs to be saved first
     pending_close_buffer: Option<BufferId>,
 
+    /// LSP workspace edit shown as diffs, waiting for the user to confirm it
+    pending_workspace_edit: Option<workspace_edit_preview::PendingWorkspaceEdit>,
+
+    /// Interactive hunk staging walk, while its diff view is open
+    hunk_staging: Option<git_stage::HunkStaging>,
+
     /// Whether auto-revert mode is enabled (automatically reload files when changed on disk)
     auto_revert_enabled: bool,
 
@@ -1025,6 +1155,9 @@ pub struct Editor {
         )>,
     >,
 
+    /// Files polled for `workspace/didChangeWatchedFiles`
+    watched_files: watched_files::WatchedFiles,
+
     /// Tracks rapid file change events for debouncing
     /// Maps file path to (last event time, event count)
     file_rapid_change_counts: HashMap<PathBuf, (std::time::Instant, u32)>,
@@ -1085,6 +1218,18 @@ pub struct Editor {
     /// Maps buffer ID to terminal ID (for terminal buffers)
     terminal_buffers: HashMap<BufferId, crate::services::terminal::TerminalId>,
 
+    /// Running and finished tasks whose output panel is still open, by task id
+    task_runs: HashMap<u64, task_runner::TaskRun>,
+
+    /// Next id handed out by `run_task`
+    next_task_id: u64,
+
+    /// Executed palette commands and tasks, newest first
+    command_history: Vec<command_history::CommandHistoryEntry>,
+
+    /// Toast notifications shown in the corner, and the log of dismissed ones
+    toasts: crate::view::toast::ToastManager,
+
     /// Maps terminal ID to backing file path (for terminal content storage)
     terminal_backing_files: HashMap<crate::services::terminal::TerminalId, std::path::PathBuf>,
 
@@ -1179,6 +1324,9 @@ pub struct Editor {
     /// Incremental line scan state (for non-blocking progress during Go to Line)
     line_scan: line_scan::LineScan,
 
+    /// Saves of large files being written on a background thread
+    background_saves: background_save::BackgroundSaves,
+
     /// Incremental search scan state (for non-blocking search on large files)
     search_scan: search_scan::SearchScan,
 
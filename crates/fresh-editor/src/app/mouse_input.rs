@@ -18,11 +18,51 @@ use anyhow::Result as AnyhowResult;
 use ratatui::layout::Rect;
 use rust_i18n::t;
 
+/// How often the view scrolls while a selection drag holds the pointer
+/// past its edge.
+const DRAG_AUTO_SCROLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
 /// Returns true if (col, row) falls inside `rect`.
 fn in_rect(col: u16, row: u16, rect: Rect) -> bool {
     col >= rect.x && col < rect.x + rect.width && row >= rect.y && row < rect.y + rect.height
 }
 
+/// Move `position` by `lines` lines (negative is up), keeping its byte column
+/// where the target line is long enough. Stops at the first and last line.
+fn offset_by_lines(
+    buffer: &mut crate::model::buffer::Buffer,
+    position: usize,
+    lines: isize,
+) -> usize {
+    let mut iter = buffer.line_iterator(position, 80);
+    let column = position - iter.current_position();
+    let steps = if lines < 0 {
+        for _ in 0..lines.unsigned_abs() {
+            if iter.prev().is_none() {
+                break;
+            }
+        }
+        1
+    } else {
+        lines as usize + 1
+    };
+    // The first line returned is the current one; keep the last line reached
+    let mut target = None;
+    for _ in 0..steps {
+        match iter.next_line() {
+            Some(line) => target = Some(line),
+            None => break,
+        }
+    }
+    match target {
+        Some((line_start, content)) => {
+            let line_len = content.trim_end_matches(['\r', '\n']).len();
+            buffer.snap_to_char_boundary(line_start + column.min(line_len))
+        }
+        None => position,
+    }
+}
+
 impl Editor {
     /// Handle a mouse event.
     /// Returns true if a re-render is needed.
@@ -112,6 +152,11 @@ impl Editor {
 
         match mouse_event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
+                // Rapid clicks on the breakpoint column each toggle a breakpoint
+                if (is_double_click || is_triple_click) && self.is_breakpoint_column_at(col, row) {
+                    self.handle_mouse_click(col, row, mouse_event.modifiers)?;
+                    return Ok(true);
+                }
                 if is_double_click || is_triple_click {
                     if let Some((buffer_id, byte_pos)) =
                         self.fold_toggle_line_at_screen_position(col, row)
@@ -175,6 +220,7 @@ impl Editor {
                 self.mouse_state.drag_selection_anchor = None;
                 self.mouse_state.drag_selection_by_words = false;
                 self.mouse_state.drag_selection_word_end = None;
+                self.mouse_state.drag_auto_scroll = None;
                 // Clear popup scrollbar drag state
                 self.mouse_state.dragging_popup_scrollbar = None;
                 self.mouse_state.drag_start_popup_scroll = None;
@@ -267,6 +313,10 @@ impl Editor {
                 }
                 needs_render = true;
             }
+            MouseEventKind::Down(MouseButton::Middle) => {
+                self.handle_middle_click(col, row);
+                needs_render = true;
+            }
             _ => {
                 // Ignore other mouse events for now
             }
@@ -720,7 +770,7 @@ impl Editor {
             .is_some_and(|layout| layout.contains(col, row))
     }
 
-    /// Find the split whose content or scrollbar area contains (col, row).
+    /// Find the split whose content, scrollbar or minimap area contains (col, row).
     /// Returns the split id and its buffer id, or None if not over any split.
     pub(super) fn split_at_position(&self, col: u16, row: u16) -> Option<(LeafId, BufferId)> {
         for &(split_id, buffer_id, content_rect, scrollbar_rect, _, _) in
@@ -734,7 +784,11 @@ impl Editor {
                 return Some((split_id, buffer_id));
             }
         }
-        None
+        self.cached_layout
+            .minimap_areas
+            .iter()
+            .find(|(_, _, minimap_rect)| in_rect(col, row, *minimap_rect))
+            .map(|&(split_id, buffer_id, _)| (split_id, buffer_id))
     }
 
     /// Compute what hover target is at the given position
@@ -924,6 +978,7 @@ impl Editor {
                 }
                 Some(TabHit::ScrollLeft)
                 | Some(TabHit::ScrollRight)
+                | Some(TabHit::Overflow)
                 | Some(TabHit::BarBackground)
                 | None => {}
             }
@@ -1343,6 +1398,9 @@ impl Editor {
         if let Some(r) = self.handle_click_horizontal_scrollbar(col, row) {
             return r;
         }
+        if let Some(r) = self.handle_click_minimap(col, row) {
+            return r;
+        }
         if let Some(r) = self.handle_click_status_bar(col, row) {
             return r;
         }
@@ -1771,6 +1829,18 @@ impl Editor {
         Some(Ok(()))
     }
 
+    fn handle_click_minimap(&mut self, col: u16, row: u16) -> Option<AnyhowResult<()>> {
+        let (split_id, buffer_id, minimap_rect) = self
+            .cached_layout
+            .minimap_areas
+            .iter()
+            .find(|(_, _, minimap_rect)| in_rect(col, row, *minimap_rect))
+            .copied()?;
+
+        self.focus_split(split_id, buffer_id);
+        Some(self.handle_minimap_jump(row, split_id, buffer_id, minimap_rect))
+    }
+
     fn handle_click_horizontal_scrollbar(
         &mut self,
         col: u16,
@@ -2000,15 +2070,7 @@ impl Editor {
         let (split_id, hit) = tab_hit?;
         match hit {
             TabHit::CloseButton(target) => {
-                match target {
-                    crate::view::split::TabTarget::Buffer(buffer_id) => {
-                        self.focus_split(split_id, buffer_id);
-                        self.close_tab_in_split(buffer_id, split_id);
-                    }
-                    crate::view::split::TabTarget::Group(group_leaf) => {
-                        self.close_buffer_group_by_leaf(group_leaf);
-                    }
-                }
+                self.close_tab_target(split_id, target);
                 Some(Ok(()))
             }
             TabHit::TabName(target) => {
@@ -2058,10 +2120,43 @@ impl Editor {
                 }
                 Some(Ok(()))
             }
+            TabHit::Overflow => {
+                self.start_hidden_tabs_prompt(split_id);
+                Some(Ok(()))
+            }
             TabHit::BarBackground => None,
         }
     }
 
+    /// Close a tab the way its close button does
+    fn close_tab_target(&mut self, split_id: LeafId, target: crate::view::split::TabTarget) {
+        match target {
+            crate::view::split::TabTarget::Buffer(buffer_id) => {
+                self.focus_split(split_id, buffer_id);
+                self.close_tab_in_split(buffer_id, split_id);
+            }
+            crate::view::split::TabTarget::Group(group_leaf) => {
+                self.close_buffer_group_by_leaf(group_leaf);
+            }
+        }
+    }
+
+    /// Handle middle-click: closes the tab under the pointer
+    fn handle_middle_click(&mut self, col: u16, row: u16) {
+        let tab_hit =
+            self.cached_layout.tab_layouts.iter().find_map(
+                |(split_id, tab_layout)| match tab_layout.hit_test(col, row) {
+                    Some(TabHit::TabName(target) | TabHit::CloseButton(target)) => {
+                        Some((*split_id, target))
+                    }
+                    _ => None,
+                },
+            );
+        if let Some((split_id, target)) = tab_hit {
+            self.close_tab_target(split_id, target);
+        }
+    }
+
     /// Handle mouse drag event
     pub(super) fn handle_mouse_drag(&mut self, col: u16, row: u16) -> AnyhowResult<()> {
         // If dragging scrollbar, update scroll position
@@ -2258,7 +2353,9 @@ impl Editor {
 
         // If dragging to select text
         if self.mouse_state.dragging_text_selection {
-            self.handle_text_selection_drag(col, row)?;
+            let past_edge = self.handle_text_selection_drag(col, row)?;
+            self.mouse_state.drag_auto_scroll =
+                past_edge.then(|| (col, row, self.time_source.now()));
             return Ok(());
         }
 
@@ -2271,16 +2368,43 @@ impl Editor {
         Ok(())
     }
 
-    /// Handle text selection drag - extends selection from anchor to current position
-    fn handle_text_selection_drag(&mut self, col: u16, row: u16) -> AnyhowResult<()> {
+    /// Keep scrolling while a selection drag holds the pointer past the top
+    /// or bottom edge of the view, even though no drag events arrive while
+    /// the pointer stays put. Returns true if the view scrolled.
+    pub fn check_drag_auto_scroll(&mut self) -> bool {
+        let Some((col, row, last_scroll)) = self.mouse_state.drag_auto_scroll else {
+            return false;
+        };
+        if !self.mouse_state.dragging_text_selection {
+            self.mouse_state.drag_auto_scroll = None;
+            return false;
+        }
+        let now = self.time_source.now();
+        if now.saturating_duration_since(last_scroll) < DRAG_AUTO_SCROLL_INTERVAL {
+            return false;
+        }
+        match self.handle_text_selection_drag(col, row) {
+            Ok(past_edge) => {
+                self.mouse_state.drag_auto_scroll = past_edge.then_some((col, row, now));
+                true
+            }
+            Err(e) => {
+                tracing::debug!("Drag auto-scroll failed: {}", e);
+                self.mouse_state.drag_auto_scroll = None;
+                false
+            }
+        }
+    }
+
+    /// Handle text selection drag - extends selection from anchor to current position.
+    /// Returns true if the pointer is past the top or bottom edge, so the
+    /// view keeps scrolling while it stays there.
+    fn handle_text_selection_drag(&mut self, col: u16, row: u16) -> AnyhowResult<bool> {
         use crate::model::event::Event;
         use crate::primitives::word_navigation::{find_word_end, find_word_start};
 
         let Some(split_id) = self.mouse_state.drag_selection_split else {
-            return Ok(());
-        };
-        let Some(anchor_position) = self.mouse_state.drag_selection_anchor else {
-            return Ok(());
+            return Ok(false);
         };
 
         // Find the buffer and content rect for this split in one pass
@@ -2291,7 +2415,21 @@ impl Editor {
             .find(|(sid, _, _, _, _, _)| *sid == split_id)
             .map(|(_, bid, rect, _, _, _)| (*bid, *rect))
         else {
-            return Ok(());
+            return Ok(false);
+        };
+
+        if self.is_composite_buffer(buffer_id) {
+            return self.handle_composite_selection_drag(
+                col,
+                row,
+                split_id,
+                buffer_id,
+                content_rect,
+            );
+        }
+
+        let Some(anchor_position) = self.mouse_state.drag_selection_anchor else {
+            return Ok(false);
         };
 
         // Get cached view line mappings for this split
@@ -2316,6 +2454,12 @@ impl Editor {
             .get(&leaf_id)
             .and_then(|vs| vs.compose_width);
 
+        // Past the top or bottom edge, select from the edge row and reach
+        // further lines; moving the cursor there scrolls the view after it.
+        let last_row = content_rect.y + content_rect.height.saturating_sub(1);
+        let scroll_lines = super::click_geometry::drag_scroll_lines(row, content_rect.y, last_row);
+        let row = row.clamp(content_rect.y, last_row);
+
         // Calculate the target position from screen coordinates
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             let gutter_width = state.margins.left_total_width() as u16;
@@ -2330,7 +2474,12 @@ impl Editor {
                 true, // Allow gutter clicks for drag selection
                 compose_width,
             ) else {
-                return Ok(());
+                return Ok(false);
+            };
+            let target_position = if scroll_lines != 0 {
+                offset_by_lines(&mut state.buffer, target_position, scroll_lines)
+            } else {
+                target_position
             };
 
             // When drag started with double-click, snap to word boundaries.
@@ -2386,16 +2535,15 @@ impl Editor {
             if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
                 event_log.append(event.clone());
             }
-            if let Some(cursors) = self
-                .split_view_states
-                .get_mut(&leaf_id)
-                .map(|vs| &mut vs.cursors)
-            {
-                state.apply(cursors, &event);
+            if let Some(view_state) = self.split_view_states.get_mut(&leaf_id) {
+                state.apply(&mut view_state.cursors, &event);
+                if scroll_lines != 0 {
+                    view_state.viewport.clear_skip_ensure_visible();
+                }
             }
         }
 
-        Ok(())
+        Ok(scroll_lines != 0)
     }
 
     /// Handle file explorer border drag for resizing
@@ -2656,6 +2804,9 @@ impl Editor {
             TabContextMenuItem::CopyFullPath => {
                 self.copy_buffer_path(buffer_id, false);
             }
+            TabContextMenuItem::TogglePin => {
+                self.toggle_tab_pin(buffer_id);
+            }
         }
 
         Ok(())
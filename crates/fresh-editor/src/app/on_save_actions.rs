@@ -36,13 +36,23 @@ impl Editor {
         };
 
         let mut ran_any_action = false;
+        let settings = &self.active_state().buffer_settings;
+        let trim_trailing_whitespace = settings
+            .trim_trailing_whitespace
+            .unwrap_or(self.config.editor.trim_trailing_whitespace_on_save);
+        let insert_final_newline = settings
+            .insert_final_newline
+            .unwrap_or(self.config.editor.ensure_final_newline_on_save);
 
         // Run whitespace cleanup actions first (before formatter)
-        if self.config.editor.trim_trailing_whitespace_on_save && self.trim_trailing_whitespace()? {
+        if trim_trailing_whitespace && self.trim_trailing_whitespace()? {
             ran_any_action = true;
         }
 
-        if self.config.editor.ensure_final_newline_on_save && self.ensure_final_newline()? {
+        let trim_blank_lines = self.config.editor.trim_trailing_blank_lines_on_save;
+        if (insert_final_newline || trim_blank_lines)
+            && self.tidy_end_of_file(insert_final_newline, trim_blank_lines)?
+        {
             ran_any_action = true;
         }
 
@@ -62,7 +72,9 @@ impl Editor {
             None => return Ok(ran_any_action),
         };
 
-        // Run formatter if format_on_save is enabled
+        // Run formatter if format_on_save is enabled. Without one, the
+        // language server already formatted the buffer before it was written
+        // (see `request_pre_save_edits`).
         if lang_config.format_on_save {
             if let Some(ref formatter) = lang_config.formatter {
                 match self.run_formatter(formatter, &path) {
@@ -553,6 +565,20 @@ impl Editor {
     /// Ensure the buffer ends with a newline.
     /// Returns Ok(true) if a newline was added, Ok(false) if already ends with newline.
     pub fn ensure_final_newline(&mut self) -> Result<bool, String> {
+        self.tidy_end_of_file(true, false)
+    }
+
+    /// Normalize the end of the active buffer: drop blank lines after the
+    /// last non-blank one (`trim_blank_lines`) and/or add a missing final
+    /// newline (`insert_final_newline`). Only the tail of the buffer is
+    /// edited, as one undo step. A file without a final newline keeps it
+    /// that way unless `insert_final_newline` is set.
+    /// Returns Ok(true) if the buffer changed.
+    pub fn tidy_end_of_file(
+        &mut self,
+        insert_final_newline: bool,
+        trim_blank_lines: bool,
+    ) -> Result<bool, String> {
         let content = self.active_state().buffer.to_string().unwrap_or_default();
 
         // Empty buffers don't need a newline
@@ -560,16 +586,93 @@ impl Editor {
             return Ok(false);
         }
 
-        if content.ends_with('\n') {
+        let content_end = end_of_last_non_blank_line(&content);
+        let tail = &content[content_end..];
+        let mut new_tail = if !trim_blank_lines {
+            tail.to_string()
+        } else if content_end == 0 {
+            String::new()
+        } else {
+            first_line_ending(tail).to_string()
+        };
+        if insert_final_newline && !new_tail.ends_with(['\n', '\r']) {
+            new_tail.push_str(self.active_state().buffer.line_ending().as_str());
+        }
+        if new_tail == tail {
             return Ok(false);
         }
 
-        let with_newline = format!("{}\n", content);
-        self.replace_buffer_with_output(&with_newline)?;
+        // Keep what the old and new tails share, replace the rest
+        let common = tail
+            .bytes()
+            .zip(new_tail.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let edit_start = content_end + common;
+        let cursor_id = self.active_cursors().primary_id();
+        let cursor = *self.active_cursors().primary();
+        let mut events = Vec::new();
+        if common < tail.len() {
+            events.push(Event::Delete {
+                range: edit_start..content.len(),
+                deleted_text: tail[common..].to_string(),
+                cursor_id,
+            });
+        }
+        if common < new_tail.len() {
+            events.push(Event::Insert {
+                position: edit_start,
+                text: new_tail[common..].to_string(),
+                cursor_id,
+            });
+        }
+
+        // The edits move the cursor to the end of the file; put it back
+        let after_edit = edit_start + new_tail.len() - common;
+        let restored = cursor.position.min(edit_start);
+        if restored != after_edit {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: after_edit,
+                new_position: restored,
+                old_anchor: None,
+                new_anchor: cursor.anchor.map(|a| a.min(edit_start)),
+                old_sticky_column: 0,
+                new_sticky_column: cursor.sticky_column,
+            });
+        }
+        let batch = Event::Batch {
+            events,
+            description: "Tidy end of file".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
         Ok(true)
     }
 }
 
+/// Byte offset just past the content of the last line that has anything
+/// but whitespace, before its line ending. 0 if every line is blank.
+fn end_of_last_non_blank_line(content: &str) -> usize {
+    match content.rfind(|c: char| !c.is_whitespace()) {
+        Some(idx) => content[idx..]
+            .find(['\n', '\r'])
+            .map_or(content.len(), |offset| idx + offset),
+        None => 0,
+    }
+}
+
+/// The line ending `text` starts with, or "" if it has none
+fn first_line_ending(text: &str) -> &str {
+    let start = text.find(['\n', '\r']).unwrap_or(text.len());
+    let rest = &text[start..];
+    if rest.starts_with("\r\n") {
+        &rest[..2]
+    } else {
+        &rest[..rest.len().min(1)]
+    }
+}
+
 /// Check if a command exists in the system PATH.
 fn command_exists(command: &str) -> bool {
     // Use 'which' on Unix or 'where' on Windows to check if command exists
@@ -0,0 +1,55 @@
+//! "Open URL under cursor" on `Editor`.
+//!
+//! The URL is found with [`url_at`] on the cursor's line and handed to the
+//! editor's `url_opener`, which launches the system browser (`xdg-open`,
+//! `open` or `start`, via the `open` crate). Tests swap the opener out to
+//! record the URL instead.
+
+use rust_i18n::t;
+
+use crate::primitives::urls::url_at;
+
+use super::Editor;
+
+/// Launches a URL, e.g. in the system browser.
+pub type UrlOpener = fn(&str) -> std::io::Result<()>;
+
+/// Open `url` in the system browser without waiting for it.
+pub(crate) fn open_in_browser(url: &str) -> std::io::Result<()> {
+    #[cfg(feature = "runtime")]
+    return open::that_detached(url);
+    #[cfg(not(feature = "runtime"))]
+    {
+        let _ = url;
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+impl Editor {
+    /// Open the URL under the primary cursor in the browser.
+    pub(crate) fn open_url_under_cursor(&mut self) {
+        let state = self.active_state();
+        let position = self.active_cursors().primary().position;
+        let line = state.buffer.get_line_number(position);
+        let url = state.buffer.line_start_offset(line).and_then(|start| {
+            let bytes = state.buffer.get_line(line)?;
+            url_at(&String::from_utf8_lossy(&bytes), position - start).map(str::to_string)
+        });
+        let Some(url) = url else {
+            self.set_status_message(t!("status.no_url_under_cursor").to_string());
+            return;
+        };
+        match (self.url_opener)(&url) {
+            Ok(()) => self.set_status_message(t!("status.opening_url", url = url).to_string()),
+            Err(e) => self.set_status_message(
+                t!("status.open_url_failed", error = e.to_string()).to_string(),
+            ),
+        }
+    }
+
+    /// Replace the function that launches URLs, e.g. to record them in tests.
+    #[doc(hidden)]
+    pub fn set_url_opener(&mut self, opener: UrlOpener) {
+        self.url_opener = opener;
+    }
+}
@@ -1381,6 +1381,45 @@ impl Editor {
         }
     }
 
+    /// Handle SetLineSign command
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn handle_set_line_sign(
+        &mut self,
+        buffer_id: BufferId,
+        line: usize,
+        namespace: String,
+        symbol: String,
+        fg: Option<fresh_core::api::OverlayColorSpec>,
+        bold: bool,
+        priority: i32,
+    ) {
+        use fresh_core::api::OverlayColorSpec;
+
+        let line_number_fg = self.theme.line_number_fg;
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let Some(byte_offset) = state.buffer.line_start_offset(line) else {
+            return;
+        };
+        let indicator = match fg {
+            Some(OverlayColorSpec::Rgb(r, g, b)) => crate::view::margin::LineIndicator::new(
+                symbol,
+                ratatui::style::Color::Rgb(r, g, b),
+                priority,
+            ),
+            Some(OverlayColorSpec::ThemeKey(key)) => {
+                crate::view::margin::LineIndicator::new(symbol, line_number_fg, priority)
+                    .with_theme_key(key)
+            }
+            None => crate::view::margin::LineIndicator::new(symbol, line_number_fg, priority),
+        }
+        .with_bold(bold);
+        state
+            .margins
+            .set_line_indicator(byte_offset, namespace, indicator);
+    }
+
     /// Handle SetLineIndicators batch command
     pub(super) fn handle_set_line_indicators(
         &mut self,
@@ -1806,6 +1845,8 @@ impl Editor {
         // Convert LanguagePackConfig to the internal LanguageConfig format
         let lang_config = crate::config::LanguageConfig {
             comment_prefix: config.comment_prefix,
+            block_comment_start: config.block_comment_start,
+            block_comment_end: config.block_comment_end,
             auto_indent: config.auto_indent.unwrap_or(true),
             use_tabs: config.use_tabs,
             tab_size: config.tab_size,
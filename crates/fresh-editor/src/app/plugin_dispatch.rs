@@ -691,6 +691,17 @@ impl Editor {
             } => {
                 self.handle_set_line_indicator(buffer_id, line, namespace, symbol, color, priority);
             }
+            PluginCommand::SetLineSign {
+                buffer_id,
+                line,
+                namespace,
+                symbol,
+                fg,
+                bold,
+                priority,
+            } => {
+                self.handle_set_line_sign(buffer_id, line, namespace, symbol, fg, bold, priority);
+            }
             PluginCommand::SetLineIndicators {
                 buffer_id,
                 lines,
@@ -928,6 +939,19 @@ impl Editor {
                 self.remote_indicator_override = None;
             }
 
+            PluginCommand::SetStatusBarSegment {
+                id,
+                align,
+                priority,
+                text,
+            } => {
+                self.handle_set_status_bar_segment(id, align, priority, text);
+            }
+
+            PluginCommand::RemoveStatusBarSegment { id } => {
+                self.plugin_status_segments.retain(|seg| seg.id != id);
+            }
+
             PluginCommand::SpawnProcessWait {
                 process_id,
                 callback_id,
@@ -2741,6 +2765,49 @@ impl Editor {
         }
     }
 
+    fn handle_set_status_bar_segment(
+        &mut self,
+        id: String,
+        align: String,
+        priority: i32,
+        text: String,
+    ) {
+        use crate::view::ui::status_bar::{PluginSegmentAlign, PluginStatusSegment};
+
+        let Some(align) = PluginSegmentAlign::parse(&align) else {
+            tracing::warn!(
+                "setStatusBarSegment: invalid align '{}' for '{}'",
+                align,
+                id
+            );
+            return;
+        };
+        if let Some(seg) = self
+            .plugin_status_segments
+            .iter_mut()
+            .find(|seg| seg.id == id && seg.align == align && seg.priority == priority)
+        {
+            seg.text = text;
+            return;
+        }
+        self.plugin_status_segments.retain(|seg| seg.id != id);
+        // Highest priority first; ties keep registration order.
+        let index = self
+            .plugin_status_segments
+            .iter()
+            .position(|seg| seg.priority < priority)
+            .unwrap_or(self.plugin_status_segments.len());
+        self.plugin_status_segments.insert(
+            index,
+            PluginStatusSegment {
+                id,
+                align,
+                priority,
+                text,
+            },
+        );
+    }
+
     fn handle_spawn_process_wait(
         &mut self,
         process_id: u64,
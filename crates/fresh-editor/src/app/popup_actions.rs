@@ -3,6 +3,7 @@
 //! This module contains handlers for popup-related actions like confirmation and cancellation.
 
 use super::Editor;
+use crate::input::fuzzy::{fuzzy_match_prepared, PreparedPattern};
 use crate::model::event::Event;
 use crate::primitives::snippet::{expand_snippet, is_snippet};
 use crate::primitives::word_navigation::find_completion_word_start;
@@ -116,6 +117,20 @@ impl Editor {
                 }
             }
 
+            Some(PopupResolver::SpellSuggestion { start, end }) => {
+                let choice = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(choice) = choice {
+                    self.apply_spelling_suggestion(start, end, &choice);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
             Some(PopupResolver::RemoteIndicator) => {
                 let action_key = self
                     .active_state()
@@ -150,7 +165,7 @@ impl Editor {
                 PopupConfirmResult::Done
             }
 
-            Some(PopupResolver::None) | None => {
+            Some(PopupResolver::None | PopupResolver::SignatureHelp) | None => {
                 self.hide_popup();
                 PopupConfirmResult::Done
             }
@@ -159,13 +174,12 @@ impl Editor {
 
     /// Insert completion text, replacing the word prefix at cursor.
     /// If the text contains LSP snippet syntax, it will be expanded.
-    fn insert_completion_text(&mut self, text: String) {
+    pub(crate) fn insert_completion_text(&mut self, text: String) {
         // Check if this is a snippet and expand it
-        let (insert_text, cursor_offset) = if is_snippet(&text) {
-            let expanded = expand_snippet(&text);
-            (expanded.text, Some(expanded.cursor_offset))
-        } else {
-            (text, None)
+        let snippet = is_snippet(&text).then(|| expand_snippet(&text));
+        let (insert_text, cursor_offset) = match &snippet {
+            Some(expanded) => (expanded.text.clone(), Some(expanded.cursor_offset)),
+            None => (text, None),
         };
 
         let (cursor_id, cursor_pos, word_start) = {
@@ -229,6 +243,11 @@ impl Editor {
                 state.apply(cursors, &move_event);
             }
         }
+
+        // Select the first tabstop, if the snippet has any
+        if let Some(expanded) = &snippet {
+            self.start_snippet_session(insert_pos, expanded);
+        }
     }
 
     /// Apply additional_text_edits from the accepted completion item (e.g. auto-imports).
@@ -326,11 +345,11 @@ impl Editor {
                 self.completion_items = None;
             }
 
-            Some(PopupResolver::RemoteIndicator) => {
+            Some(PopupResolver::RemoteIndicator | PopupResolver::SpellSuggestion { .. }) => {
                 self.hide_popup();
             }
 
-            Some(PopupResolver::None) | None => {
+            Some(PopupResolver::None | PopupResolver::SignatureHelp) | None => {
                 self.hide_popup();
                 self.completion_items = None;
             }
@@ -455,8 +474,11 @@ impl Editor {
         self.refilter_completion_popup();
     }
 
-    /// Re-filter the completion popup based on current prefix.
-    /// If no items match, dismiss the popup.
+    /// Re-filter the stored completion items against the current prefix,
+    /// without a new LSP request. Backspace widens the prefix again, so
+    /// items filtered out earlier come back. If none of the LSP items match
+    /// any more, completion is re-requested; if nothing matches at all,
+    /// dismiss the popup.
     fn refilter_completion_popup(&mut self) {
         // Get stored LSP completion items (may be empty if no LSP).
         let lsp_items = self.completion_items.clone().unwrap_or_default();
@@ -477,22 +499,15 @@ impl Editor {
             String::new()
         };
 
-        // Filter LSP items
-        let filtered_lsp: Vec<&lsp_types::CompletionItem> = if prefix.is_empty() {
-            lsp_items.iter().collect()
-        } else {
-            lsp_items
-                .iter()
-                .filter(|item| {
-                    item.label.to_lowercase().starts_with(&prefix)
-                        || item
-                            .filter_text
-                            .as_ref()
-                            .map(|ft| ft.to_lowercase().starts_with(&prefix))
-                            .unwrap_or(false)
-                })
-                .collect()
-        };
+        let filtered_lsp = filter_completion_items(&lsp_items, &prefix);
+
+        // The server's list no longer has anything for this prefix: ask
+        // again at the new position instead of narrowing an exhausted list.
+        if filtered_lsp.is_empty() && !lsp_items.is_empty() {
+            self.hide_popup();
+            self.request_completion();
+            return;
+        }
 
         // Build combined items: LSP first, then buffer-word results.
         let mut all_popup_items = lsp_items_to_popup_items(&filtered_lsp);
@@ -563,6 +578,40 @@ pub(crate) fn build_completion_popup_from_items(
     }
 }
 
+/// LSP completion items matching the typed `prefix`, best match first.
+///
+/// Items are fuzzy-matched on their label and `filter_text`, so `clsm`
+/// still finds `calculate_sum`. Items the prefix starts rank above the
+/// rest, then by match score; ties keep the server's order. An empty
+/// prefix keeps every item.
+pub(crate) fn filter_completion_items<'a>(
+    items: &'a [lsp_types::CompletionItem],
+    prefix: &str,
+) -> Vec<&'a lsp_types::CompletionItem> {
+    if prefix.is_empty() {
+        return items.iter().collect();
+    }
+    let pattern = PreparedPattern::new(prefix);
+    let prefix = prefix.to_lowercase();
+    let mut ranked: Vec<(bool, i32, &lsp_types::CompletionItem)> = items
+        .iter()
+        .filter_map(|item| {
+            let texts = std::iter::once(item.label.as_str()).chain(item.filter_text.as_deref());
+            let mut best: Option<(bool, i32)> = None;
+            for text in texts {
+                let m = fuzzy_match_prepared(&pattern, text);
+                if m.matched {
+                    let key = (text.to_lowercase().starts_with(&prefix), m.score);
+                    best = Some(best.map_or(key, |b| b.max(key)));
+                }
+            }
+            best.map(|(starts, score)| (starts, score, item))
+        })
+        .collect();
+    ranked.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+    ranked.into_iter().map(|(_, _, item)| item).collect()
+}
+
 /// Convert LSP `CompletionItem`s to `PopupListItemData`s.
 pub(crate) fn lsp_items_to_popup_items(
     items: &[&lsp_types::CompletionItem],
@@ -16,6 +16,7 @@ use std::ops::Range;
 use rust_i18n::t;
 
 use crate::model::event::Event;
+use crate::view::toast::ToastSeverity;
 
 use super::Editor;
 
@@ -309,12 +310,14 @@ impl Editor {
                     // Use force_spawn since user explicitly confirmed
                     if lsp.force_spawn(&language, file_path.as_deref()).is_some() {
                         tracing::info!("LSP server for {} started (allowed once)", language);
-                        self.set_status_message(
+                        self.show_toast(
                             t!("lsp.server_started", language = language).to_string(),
+                            ToastSeverity::Info,
                         );
                     } else {
-                        self.set_status_message(
+                        self.show_toast(
                             t!("lsp.failed_to_start", language = language).to_string(),
+                            ToastSeverity::Error,
                         );
                     }
                 }
@@ -328,12 +331,14 @@ impl Editor {
                     // Use force_spawn since user explicitly confirmed
                     if lsp.force_spawn(&language, file_path.as_deref()).is_some() {
                         tracing::info!("LSP server for {} started (always allowed)", language);
-                        self.set_status_message(
+                        self.show_toast(
                             t!("lsp.server_started_auto", language = language).to_string(),
+                            ToastSeverity::Info,
                         );
                     } else {
-                        self.set_status_message(
+                        self.show_toast(
                             t!("lsp.failed_to_start", language = language).to_string(),
+                            ToastSeverity::Error,
                         );
                     }
                 }
@@ -4,6 +4,7 @@
 
 use rust_i18n::t;
 
+use super::command_history::CommandHistoryKind;
 use super::normalize_path;
 use super::BufferId;
 use super::BufferMetadata;
@@ -30,7 +31,8 @@ pub(super) fn parse_path_line_col(input: &str) -> (String, Option<usize>, Option
 
 /// Convert a parsed goto-line target into a concrete 1-based line number,
 /// clamped into `1..=max_line`. Relative offsets are applied to `current_line`
-/// with saturating arithmetic so users can't underflow past line 1.
+/// with saturating arithmetic so users can't underflow past line 1; percentages
+/// above 100 land on the last line.
 pub(super) fn resolve_goto_line_target(
     target: crate::input::quick_open::GotoLineTarget,
     current_line: usize,
@@ -46,6 +48,7 @@ pub(super) fn resolve_goto_line_target(
                 current_line.saturating_sub(delta.unsigned_abs())
             }
         }
+        GotoLineTarget::Percent(p) => max_line.saturating_mul(p.min(100)) / 100,
     };
     raw.clamp(1, max_line.max(1))
 }
@@ -103,6 +106,10 @@ impl Editor {
             PromptType::SaveFileAs => {
                 self.handle_save_file_as(&input);
             }
+            PromptType::RenameCurrentFile => {
+                let expanded_path = expand_tilde(&input);
+                self.rename_current_file(&normalize_path(&self.working_dir.join(expanded_path)));
+            }
             PromptType::Search => {
                 self.perform_search(&input);
             }
@@ -260,7 +267,28 @@ impl Editor {
                 );
             }
             PromptType::PlayMacro => {
-                self.handle_register_input(&input, |editor, c| editor.play_macro(c), "Macro");
+                // "<register> [count]": "1 5" plays macro 1 five times
+                let (register, count) = input
+                    .trim()
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((input.trim(), ""));
+                match count.trim() {
+                    "" => self.handle_register_input(
+                        register,
+                        |editor, c| editor.play_macro(c),
+                        "Macro",
+                    ),
+                    count => match count.parse::<usize>() {
+                        Ok(n) if n > 0 => self.handle_register_input(
+                            register,
+                            |editor, c| editor.replay_macro(c, n),
+                            "Macro",
+                        ),
+                        _ => self.set_status_message(
+                            t!("macro.invalid_count", count = count).to_string(),
+                        ),
+                    },
+                }
             }
             PromptType::SetBookmark => {
                 self.handle_register_input(&input, |editor, c| editor.set_bookmark(c), "Bookmark");
@@ -303,10 +331,22 @@ impl Editor {
                     self.set_status_message(t!("buffer.revert_cancelled").to_string());
                 }
             }
+            PromptType::ConfirmWorkspaceEdit => {
+                let input_lower = input.trim().to_lowercase();
+                let apply_key = t!("prompt.key.apply").to_string().to_lowercase();
+                if input_lower == apply_key || input_lower == "apply" {
+                    self.apply_previewed_workspace_edit();
+                } else {
+                    self.discard_workspace_edit_preview();
+                }
+            }
+            PromptType::StageHunk => {
+                self.handle_stage_hunk_prompt(&input);
+            }
             PromptType::ConfirmSaveConflict => {
                 let input_lower = input.trim().to_lowercase();
                 if input_lower == "o" || input_lower == "overwrite" {
-                    if let Err(e) = self.save() {
+                    if let Err(e) = self.start_save() {
                         self.set_status_message(
                             t!("file.save_failed", error = e.to_string()).to_string(),
                         );
@@ -315,6 +355,9 @@ impl Editor {
                     self.set_status_message(t!("buffer.save_cancelled").to_string());
                 }
             }
+            PromptType::ConfirmFileChangedOnDisk { buffer_id, path } => {
+                self.handle_confirm_file_changed_on_disk(&input, buffer_id, &path);
+            }
             PromptType::ConfirmSudoSave { info } => {
                 let input_lower = input.trim().to_lowercase();
                 if input_lower == "y" || input_lower == "yes" {
@@ -408,6 +451,7 @@ impl Editor {
                     return PromptResult::EarlyReturn;
                 }
             }
+            PromptType::ConfirmSessionClose => self.handle_confirm_session_close(&input),
             PromptType::LspRename {
                 original_text,
                 start_pos,
@@ -621,6 +665,14 @@ impl Editor {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::OpenRecentFile => {
+                let path = std::path::PathBuf::from(input.trim());
+                if let Err(e) = self.open_file(&path) {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                }
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -639,6 +691,17 @@ impl Editor {
             PromptType::SetTabSize => {
                 self.handle_set_tab_size(&input);
             }
+            PromptType::ConvertIndentation { to_spaces } => match input.trim().parse::<usize>() {
+                Ok(width) if width > 0 => self.retab_buffer(to_spaces, width),
+                Ok(_) => {
+                    self.set_status_message(t!("settings.tab_size_positive").to_string());
+                }
+                Err(_) => {
+                    self.set_status_message(
+                        t!("error.invalid_tab_size", input = input).to_string(),
+                    );
+                }
+            },
             PromptType::SetLineEnding => {
                 self.handle_set_line_ending(&input);
             }
@@ -651,6 +714,19 @@ impl Editor {
             PromptType::ShellCommand { replace } => {
                 self.handle_shell_command(&input, replace);
             }
+            PromptType::RunTask => {
+                self.run_task(input.trim());
+            }
+            PromptType::GotoDefinition { locations } => {
+                if let Some(location) = input
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| locations.get(index))
+                {
+                    self.jump_to_lsp_location(location);
+                }
+            }
             PromptType::AsyncPrompt => {
                 // Resolve the pending async prompt callback with the input text
                 if let Some(callback_id) = self.pending_async_prompt_callback.take() {
@@ -667,10 +743,17 @@ impl Editor {
     fn handle_save_file_as(&mut self, input: &str) {
         // Expand tilde to home directory first
         let expanded_path = expand_tilde(input);
-        let full_path = if expanded_path.is_absolute() {
-            normalize_path(&expanded_path)
+        self.save_buffer_as(&expanded_path);
+    }
+
+    /// Save the active buffer to `path`, relative to the working directory
+    /// unless absolute, and make the buffer track it. The file it was
+    /// loaded from is left as it is. Asks before replacing another file.
+    pub fn save_buffer_as(&mut self, path: &std::path::Path) {
+        let full_path = if path.is_absolute() {
+            normalize_path(path)
         } else {
-            normalize_path(&self.working_dir.join(&expanded_path))
+            normalize_path(&self.working_dir.join(path))
         };
 
         self.save_file_as_with_checks(full_path);
@@ -739,40 +822,48 @@ impl Editor {
                     after_save_len
                 );
 
-                let metadata = BufferMetadata::with_file(
-                    full_path.clone(),
-                    &full_path,
-                    &self.working_dir,
-                    self.authority.path_translation.as_ref(),
-                );
-                self.buffer_metadata.insert(self.active_buffer(), metadata);
-
-                // Auto-detect language if it's currently "text"
-                // This ensures syntax highlighting works immediately after "Save As"
+                // Re-detect the language from the new name so highlighting
+                // and language servers follow it. A name that says nothing
+                // about the language (e.g. `.txt`) keeps the current one.
+                let buffer_id = self.active_buffer();
                 let mut language_changed = false;
                 let mut new_language = String::new();
-                if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
-                    if state.language == "text" {
-                        let first_line = state.buffer.first_line_lossy();
-                        let detected =
-                            crate::primitives::detected_language::DetectedLanguage::from_path(
-                                &full_path,
-                                first_line.as_deref(),
-                                &self.grammar_registry,
-                                &self.config.languages,
-                            );
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    let first_line = state.buffer.first_line_lossy();
+                    let detected =
+                        crate::primitives::detected_language::DetectedLanguage::from_path(
+                            &full_path,
+                            first_line.as_deref(),
+                            &self.grammar_registry,
+                            &self.config.languages,
+                        );
+                    if detected.name != "text" && detected.name != state.language {
                         new_language = detected.name.clone();
                         state.apply_language(detected);
-                        language_changed = new_language != "text";
+                        language_changed = true;
                     }
                 }
+
+                let mut metadata = BufferMetadata::with_file(
+                    full_path.clone(),
+                    &full_path,
+                    &self.working_dir,
+                    self.authority.path_translation.as_ref(),
+                );
+                let old_uri = self.buffer_metadata.get(&buffer_id).and_then(|old| {
+                    metadata.lsp_opened_with = old.lsp_opened_with.clone();
+                    old.file_uri().cloned()
+                });
+                self.buffer_metadata.insert(buffer_id, metadata);
+                self.reopen_moved_buffer_in_lsp(buffer_id, old_uri.as_ref());
+
                 if language_changed {
                     #[cfg(feature = "plugins")]
                     self.update_plugin_state_snapshot();
                     self.plugin_manager.run_hook(
                         "language_changed",
                         crate::services::plugins::hooks::HookArgs::LanguageChanged {
-                            buffer_id: self.active_buffer(),
+                            buffer_id,
                             language: new_language,
                         },
                     );
@@ -1199,10 +1290,12 @@ impl Editor {
         let input_lower = input.trim().to_lowercase();
         let save_key = t!("prompt.key.save").to_string().to_lowercase();
         let discard_key = t!("prompt.key.discard").to_string().to_lowercase();
+        let quit_key = t!("prompt.key.quit").to_string().to_lowercase();
 
         let first_char = input_lower.chars().next();
         let save_first = save_key.chars().next();
         let discard_first = discard_key.chars().next();
+        let quit_first = quit_key.chars().next();
 
         if first_char == save_first {
             // Save and close
@@ -1214,15 +1307,15 @@ impl Editor {
 
             if has_path {
                 let old_active = self.active_buffer();
-                self.set_active_buffer(buffer_id);
+                self.set_active_buffer_internal(buffer_id);
                 if let Err(e) = self.save() {
                     self.set_status_message(
                         t!("file.save_failed", error = e.to_string()).to_string(),
                     );
-                    self.set_active_buffer(old_active);
+                    self.set_active_buffer_internal(old_active);
                     return true; // Early return
                 }
-                self.set_active_buffer(old_active);
+                self.set_active_buffer_internal(old_active);
                 if let Err(e) = self.force_close_buffer(buffer_id) {
                     self.set_status_message(
                         t!("file.cannot_close", error = e.to_string()).to_string(),
@@ -1245,12 +1338,60 @@ impl Editor {
             } else {
                 self.set_status_message(t!("buffer.changes_discarded").to_string());
             }
+        } else if first_char == quit_first && self.is_last_visible_buffer(buffer_id) {
+            // Closing the last buffer: save everything and quit
+            match self.save_all_on_exit() {
+                Ok(count) => {
+                    tracing::info!("Saved {} buffer(s) on exit", count);
+                    self.should_quit = true;
+                }
+                Err(e) => {
+                    self.set_status_message(
+                        t!("file.save_failed", error = e.to_string()).to_string(),
+                    );
+                    return true;
+                }
+            }
         } else {
             self.set_status_message(t!("buffer.close_cancelled").to_string());
         }
         false
     }
 
+    /// Handle ConfirmFileChangedOnDisk prompt.
+    fn handle_confirm_file_changed_on_disk(
+        &mut self,
+        input: &str,
+        buffer_id: BufferId,
+        path: &std::path::Path,
+    ) {
+        let first_char = input.trim().to_lowercase().chars().next();
+        let reload_key = t!("prompt.key.reload").to_string().to_lowercase();
+        let diff_key = t!("prompt.key.diff").to_string().to_lowercase();
+
+        if first_char == reload_key.chars().next() {
+            if let Err(e) = self.reload_buffer(buffer_id) {
+                self.set_status_message(
+                    t!("file.revert_failed", error = e.to_string()).to_string(),
+                );
+            }
+            return;
+        }
+
+        // Keeping the local version (or comparing first) acknowledges the
+        // disk change, so record its mtime to stop asking on every poll.
+        self.watch_file(path);
+        if first_char == diff_key.chars().next() {
+            if let Err(e) = self.show_disk_diff(buffer_id, path) {
+                self.set_status_message(
+                    t!("buffer.disk_diff_failed", error = e.to_string()).to_string(),
+                );
+            }
+        } else {
+            self.set_status_message(t!("buffer.disk_change_kept").to_string());
+        }
+    }
+
     /// Handle ConfirmQuitWithModified prompt. Returns true if early return is needed.
     fn handle_confirm_quit_modified(&mut self, input: &str) -> bool {
         let input_lower = input.trim().to_lowercase();
@@ -1290,6 +1431,22 @@ impl Editor {
         false
     }
 
+    /// Handle ConfirmSessionClose prompt: detach, quit, or cancel.
+    fn handle_confirm_session_close(&mut self, input: &str) {
+        let first_char = input.trim().to_lowercase().chars().next();
+        let detach_key = t!("prompt.key.detach").to_string().to_lowercase();
+        let quit_key = t!("prompt.key.quit").to_string().to_lowercase();
+
+        if first_char == detach_key.chars().next() {
+            self.should_detach = true;
+        } else if first_char == quit_key.chars().next() {
+            // Still asks about unsaved changes before ending the session
+            self.quit();
+        } else {
+            self.set_status_message(t!("buffer.close_cancelled").to_string());
+        }
+    }
+
     /// Handle StopLspServer prompt confirmation.
     ///
     /// Input format: `"language"` (stops all servers) or `"language/server_name"`
@@ -1448,16 +1605,24 @@ impl Editor {
         use crate::input::quick_open::QuickOpenResult;
 
         let context = self.build_quick_open_context();
+        let mut executed = None;
         let result = if let Some((provider, query)) =
             self.quick_open_registry.get_provider_for_input(input)
         {
             // Resolve the selected suggestion once, so providers don't recompute
             let suggestions = provider.suggestions(query, &context);
             let selected = selected_index.and_then(|i| suggestions.get(i));
-            provider.on_select(selected, query, &context)
+            let result = provider.on_select(selected, query, &context);
+            if let (QuickOpenResult::ExecuteAction(action), Some(selected)) = (&result, selected) {
+                executed = Some((selected.text.clone(), action.clone()));
+            }
+            result
         } else {
             QuickOpenResult::None
         };
+        if let Some((label, action)) = executed {
+            self.record_command_history(label, CommandHistoryKind::Command(action));
+        }
 
         self.execute_quick_open_result(result)
     }
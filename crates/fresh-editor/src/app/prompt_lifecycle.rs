@@ -200,7 +200,8 @@ impl Editor {
                 .and_then(|m| m.virtual_mode())
                 .map(|s| s.to_string()),
             has_lsp_config,
-            relative_line_numbers: self.config.editor.relative_line_numbers,
+            relative_line_numbers: self.config.editor.effective_line_number_mode()
+                != crate::config::LineNumberMode::Absolute,
         }
     }
 
@@ -232,18 +233,30 @@ impl Editor {
         // Relative input (`:+N`/`:-N`) is intentionally not previewed: the
         // target shifts on every digit typed, which is disorienting.
         let input = input.trim();
-        let target = Self::parse_quick_open_goto_line_target(input);
+        let target = self.parse_quick_open_goto_line_target(input);
         self.apply_goto_line_preview(target);
     }
 
     /// Parse a Quick Open input string for a `:<N>` goto-line preview target.
     /// Only absolute inputs are previewed; relative inputs return `None`.
-    pub(super) fn parse_quick_open_goto_line_target(input: &str) -> Option<usize> {
+    pub(super) fn parse_quick_open_goto_line_target(&self, input: &str) -> Option<usize> {
         let rest = input.strip_prefix(':')?;
-        match crate::input::quick_open::parse_goto_line_input(rest) {
-            Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
-            _ => None,
+        self.goto_line_preview_target(rest)
+    }
+
+    /// Line a goto-line input would land on, for previewing as the user
+    /// types. Absolute lines and percentages are clamped to the buffer like
+    /// on confirm; relative inputs aren't previewed and return `None`.
+    pub(super) fn goto_line_preview_target(&self, input: &str) -> Option<usize> {
+        use crate::input::quick_open::GotoLineTarget;
+        let target = crate::input::quick_open::parse_goto_line_input(input)?;
+        if matches!(target, GotoLineTarget::Relative(_)) {
+            return None;
         }
+        let max_line = self.active_state().buffer.line_count().unwrap_or(1);
+        Some(super::prompt_actions::resolve_goto_line_target(
+            target, 1, max_line,
+        ))
     }
 
     /// Apply a live goto-line preview: jump to `target_line` (saving the
@@ -719,6 +732,17 @@ impl Editor {
                     };
                     self.apply_event_to_active_buffer(&remove_overlay_event);
                 }
+                PromptType::ConfirmWorkspaceEdit => {
+                    self.discard_workspace_edit_preview();
+                }
+                PromptType::StageHunk => {
+                    self.cancel_hunk_staging();
+                }
+                PromptType::ConfirmFileChangedOnDisk { path, .. } => {
+                    // Dismissing counts as keeping the local version
+                    let path = path.clone();
+                    self.watch_file(&path);
+                }
                 PromptType::OpenFile | PromptType::SwitchProject | PromptType::SaveFileAs => {
                     // Clear file browser state
                     self.file_open_state = None;
@@ -865,6 +889,9 @@ impl Editor {
                     | PromptType::SelectTheme { .. }
                     | PromptType::SelectLocale
                     | PromptType::SwitchToTab
+                    | PromptType::OpenRecentFile
+                    | PromptType::RunTask
+                    | PromptType::GotoDefinition { .. }
                     | PromptType::SetLanguage
                     | PromptType::SetEncoding
                     | PromptType::SetLineEnding
@@ -1094,14 +1121,11 @@ impl Editor {
                 if let Some(history) = self.prompt_histories.get_mut("goto_line") {
                     history.reset_navigation();
                 }
-                // Live preview for absolute line numbers only. Signed
-                // (`+N`/`-N`) inputs are relative, and previewing them as the
-                // user types each digit is disorienting — preview only on
-                // Enter for those.
-                let target = match crate::input::quick_open::parse_goto_line_input(input.trim()) {
-                    Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
-                    _ => None,
-                };
+                // Live preview for absolute lines and percentages only.
+                // Signed (`+N`/`-N`) inputs are relative, and previewing them
+                // as the user types each digit is disorienting — preview only
+                // on Enter for those.
+                let target = self.goto_line_preview_target(&input);
                 self.apply_goto_line_preview(target);
             }
             PromptType::OpenFile | PromptType::SwitchProject | PromptType::SaveFileAs => {
@@ -1132,6 +1156,9 @@ impl Editor {
                 }
             }
             PromptType::SwitchToTab
+            | PromptType::OpenRecentFile
+            | PromptType::RunTask
+            | PromptType::GotoDefinition { .. }
             | PromptType::SelectTheme { .. }
             | PromptType::StopLspServer
             | PromptType::RestartLspServer
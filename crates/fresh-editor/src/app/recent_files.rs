@@ -0,0 +1,94 @@
+//! "Open Recent File" picker
+//!
+//! Every file opened from disk is recorded in the editor's
+//! [`RecentFiles`](crate::input::recent_files::RecentFiles) list. The list is
+//! written to the data directory a moment after it changes, so opening many
+//! files at once costs one write, and again on shutdown.
+
+use std::time::Duration;
+
+use super::Editor;
+use crate::input::commands::Suggestion;
+use crate::model::event::BufferId;
+use crate::view::prompt::{Prompt, PromptType};
+use rust_i18n::t;
+
+/// How long after a file is opened the recent files list is written out
+const RECENT_FILES_SAVE_DELAY: Duration = Duration::from_secs(2);
+
+impl Editor {
+    /// Recently opened files, most recent first
+    pub fn recent_files(&self) -> &[std::path::PathBuf] {
+        self.recent_files.paths()
+    }
+
+    /// Move the file behind `buffer_id` to the front of the recent files
+    /// list and schedule writing it out. Buffers without a file on disk are
+    /// skipped.
+    pub(crate) fn record_recent_file(&mut self, buffer_id: BufferId) {
+        let Some(path) = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .map(|path| path.to_path_buf())
+        else {
+            return;
+        };
+        if !self.authority.filesystem.exists(&path) {
+            return;
+        }
+
+        self.recent_files.push(path);
+        let now = self.time_source.now();
+        self.recent_files_save_due
+            .get_or_insert(now + RECENT_FILES_SAVE_DELAY);
+    }
+
+    /// Write the recent files list once its save delay has passed
+    pub fn check_recent_files_save(&mut self) {
+        let now = self.time_source.now();
+        if self.recent_files_save_due.is_some_and(|due| now >= due) {
+            self.save_recent_files();
+        }
+    }
+
+    /// Write the recent files list now if it changed since the last write.
+    /// Called on shutdown so the last opened files are not lost.
+    pub fn save_recent_files(&mut self) {
+        if self.recent_files_save_due.take().is_none() {
+            return;
+        }
+        let state_path = self.dir_context.recent_files_path();
+        if let Err(e) = self.recent_files.save_to_file(&state_path) {
+            tracing::warn!("Failed to save recent files: {}", e);
+        }
+    }
+
+    /// Start the prompt listing recently opened files, filtered by typing
+    pub(super) fn start_open_recent_file_prompt(&mut self) {
+        if self.recent_files.is_empty() {
+            self.set_status_message(t!("file.no_recent_files").to_string());
+            return;
+        }
+
+        let suggestions: Vec<Suggestion> = self
+            .recent_files
+            .paths()
+            .iter()
+            .map(|path| {
+                let text = path
+                    .strip_prefix(&self.working_dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string();
+                Suggestion::new(text).with_value(path.display().to_string())
+            })
+            .collect();
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("file.open_recent_prompt").to_string(),
+            PromptType::OpenRecentFile,
+            suggestions,
+        ));
+    }
+}
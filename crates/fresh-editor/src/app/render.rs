@@ -1,4 +1,4 @@
-use super::lsp_status::compose_lsp_status;
+use super::lsp_status::{compose_lsp_progress_message, compose_lsp_status};
 use super::*;
 use crate::config::FileExplorerSide;
 
@@ -32,6 +32,15 @@ impl Editor {
         // calling sync_scroll_groups, so that the sync reads the correct position.
         // Otherwise, cursor movements like 'G' (go to end) won't sync properly because
         // viewport.top_byte hasn't been updated yet.
+        // Apply the configured scroll margin to every view before anything
+        // below scrolls to the cursor
+        let scroll_offset = self.config.editor.scroll_offset;
+        for view_state in self.split_view_states.values_mut() {
+            for buf_state in view_state.keyed_states.values_mut() {
+                buf_state.viewport.scroll_offset = scroll_offset;
+            }
+        }
+
         let active_split = self.split_manager.active_split();
         {
             let _span = tracing::info_span!("pre_sync_ensure_visible").entered();
@@ -76,13 +85,21 @@ impl Editor {
             self.maybe_request_semantic_tokens_range(buffer_id, start_line, end_line);
             self.maybe_request_semantic_tokens_full_debounced(buffer_id);
             self.maybe_request_folding_ranges_debounced(buffer_id);
+            self.update_git_gutter(buffer_id);
+            self.update_git_blame(buffer_id);
         }
 
         {
             let _span = tracing::info_span!("prepare_for_render").entered();
+            let gutter_columns = &self.config.editor.gutter_columns;
             for (split_id, view_state) in &self.split_view_states {
                 if let Some(buffer_id) = self.split_manager.get_buffer_id((*split_id).into()) {
                     if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                        // Apply the configured gutter layout before the
+                        // renderer sizes the gutter
+                        if state.margins.left_config.columns != *gutter_columns {
+                            state.margins.set_gutter_columns(gutter_columns.clone());
+                        }
                         let top_byte = view_state.viewport.top_byte;
                         let height = view_state.viewport.height;
                         if let Err(e) = state.prepare_for_render(top_byte, height) {
@@ -477,6 +494,7 @@ impl Editor {
             view_line_mappings,
             horizontal_scrollbar_areas,
             grouped_separator_areas,
+            minimap_areas,
         ) = SplitRenderer::render_content(
             frame,
             editor_content_area,
@@ -501,13 +519,14 @@ impl Editor {
             hovered_close_split,
             hovered_maximize_split,
             is_maximized,
-            self.config.editor.relative_line_numbers,
+            self.config.editor.effective_line_number_mode(),
             self.tab_bar_visible,
             self.config.editor.use_terminal_bg,
             self.session_mode || !self.software_cursor_only,
             self.software_cursor_only,
             self.config.editor.show_vertical_scrollbar,
             self.config.editor.show_horizontal_scrollbar,
+            self.config.editor.show_minimap,
             self.config.editor.diagnostics_inline_text,
             self.config.editor.show_tilde,
             self.config.editor.highlight_current_column,
@@ -601,6 +620,7 @@ impl Editor {
 
         self.cached_layout.split_areas = split_areas;
         self.cached_layout.horizontal_scrollbar_areas = horizontal_scrollbar_areas;
+        self.cached_layout.minimap_areas = minimap_areas;
         self.cached_layout.tab_layouts = tab_layouts;
         self.cached_layout.close_split_areas = close_split_areas;
         self.cached_layout.maximize_split_areas = maximize_split_areas;
@@ -685,6 +705,8 @@ impl Editor {
             &self.config.lsp,
             &self.user_dismissed_lsp_languages,
         );
+        let lsp_progress_message =
+            compose_lsp_progress_message(&current_language, &self.lsp_progress);
         let theme = self.theme.clone();
         let keybindings_cloned = self.keybindings.read().unwrap().clone(); // Clone the keybindings
         let chord_state_cloned = self.chord_state.clone(); // Clone the chord state
@@ -767,6 +789,7 @@ impl Editor {
                 status_message: &status_message,
                 plugin_status_message: &plugin_status_message,
                 lsp_status: &lsp_status,
+                lsp_progress_message: lsp_progress_message.as_deref(),
                 lsp_indicator_state,
                 theme: &theme,
                 display_name: &display_name,
@@ -786,6 +809,7 @@ impl Editor {
                 // safe default for the rare path that builds the
                 // ctx but doesn't run `render_status`.
                 remote_indicator_on_bar: false,
+                plugin_segments: &self.plugin_status_segments,
             };
             let status_bar_layout = StatusBarRenderer::render_status_bar(
                 frame,
@@ -1112,6 +1136,10 @@ impl Editor {
             popup.render_with_hover(frame, popup_area, &theme_clone, hover_target.as_ref());
         }
 
+        // Toasts stack in the bottom-right corner of the editor area, above
+        // popups but below modal dialogs and the menu bar
+        crate::view::toast::render_toasts(frame, main_content_area, &self.toasts, &theme_clone);
+
         // Render menu bar last so dropdown appears on top of all other content
         // Update menu context with current editor state
         self.update_menu_context();
@@ -2053,7 +2081,7 @@ impl Editor {
                 let bg_fade = self.background_fade;
                 let estimated_line_length = self.config.editor.estimated_line_length;
                 let highlight_context_bytes = self.config.editor.highlight_context_bytes;
-                let relative_line_numbers = self.config.editor.relative_line_numbers;
+                let line_number_mode = self.config.editor.effective_line_number_mode();
                 let use_terminal_bg = self.config.editor.use_terminal_bg;
                 let session_mode = self.session_mode || !self.software_cursor_only;
                 let software_cursor_only = self.software_cursor_only;
@@ -2110,7 +2138,7 @@ impl Editor {
                         estimated_line_length,
                         highlight_context_bytes,
                         buffer_id,
-                        relative_line_numbers,
+                        line_number_mode,
                         use_terminal_bg,
                         session_mode,
                         software_cursor_only,
@@ -2532,13 +2560,14 @@ impl Editor {
             false, // lsp_waiting — not relevant for layout
             self.config.editor.estimated_line_length,
             self.config.editor.highlight_context_bytes,
-            self.config.editor.relative_line_numbers,
+            self.config.editor.effective_line_number_mode(),
             self.config.editor.use_terminal_bg,
             self.session_mode || !self.software_cursor_only,
             self.software_cursor_only,
             self.tab_bar_visible,
             self.config.editor.show_vertical_scrollbar,
             self.config.editor.show_horizontal_scrollbar,
+            self.config.editor.show_minimap,
             self.config.editor.diagnostics_inline_text,
             self.config.editor.show_tilde,
         );
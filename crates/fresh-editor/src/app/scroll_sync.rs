@@ -57,7 +57,12 @@ impl Editor {
         );
 
         let total_tabs_width: usize = tab_widths.iter().sum();
-        let max_visible_width = available_width as usize;
+        // A crowded tab bar gives up its last column to the overflow button
+        let max_visible_width = if total_tabs_width > available_width as usize {
+            (available_width as usize).saturating_sub(crate::view::ui::tabs::OVERFLOW_BUTTON_WIDTH)
+        } else {
+            available_width as usize
+        };
 
         // Determine the active target from the SplitViewState marker.
         let active_target = view_state.active_target();
@@ -581,6 +581,72 @@ impl Editor {
         Ok(())
     }
 
+    /// Handle a click on the minimap: centre the viewport on the buffer line
+    /// the clicked minimap row stands for.
+    pub(super) fn handle_minimap_jump(
+        &mut self,
+        row: u16,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        minimap_rect: ratatui::layout::Rect,
+    ) -> AnyhowResult<()> {
+        let minimap_height = minimap_rect.height as usize;
+        if minimap_height == 0 {
+            return Ok(());
+        }
+
+        // Large files get a blank minimap; fall back to proportional
+        // scrollbar positioning so the click still does something sensible.
+        let large_file_threshold = self.config.editor.large_file_threshold_bytes as usize;
+        let is_large = self
+            .buffers
+            .get(&buffer_id)
+            .is_some_and(|state| state.buffer.len() > large_file_threshold);
+        if is_large {
+            return self.handle_scrollbar_jump(0, row, split_id, buffer_id, minimap_rect);
+        }
+
+        let viewport_height = self
+            .split_view_states
+            .get(&split_id)
+            .map(|vs| vs.viewport.height as usize)
+            .unwrap_or(10);
+
+        let top_byte = if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            let buffer_len = state.buffer.len();
+            let total_lines = if buffer_len > 0 {
+                state.buffer.get_line_number(buffer_len - 1) + 1
+            } else {
+                1
+            };
+            let lines_per_row = crate::view::ui::split_rendering::minimap::minimap_lines_per_row(
+                total_lines,
+                minimap_height,
+            );
+            let relative_row = row.saturating_sub(minimap_rect.y) as usize;
+            let target_line = (relative_row * lines_per_row).min(total_lines - 1);
+            let top_line = target_line.saturating_sub(viewport_height / 2);
+            let line_start = state.buffer.line_start_offset(top_line).unwrap_or(0);
+
+            let max_top_byte =
+                Self::calculate_max_scroll_position(&mut state.buffer, viewport_height);
+            line_start.min(max_top_byte)
+        } else {
+            return Ok(());
+        };
+
+        if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+            view_state.viewport.top_byte = top_byte;
+            view_state.viewport.top_view_line_offset = 0;
+            // Skip ensure_visible so the scroll position isn't undone during render
+            view_state.viewport.set_skip_ensure_visible();
+        }
+
+        self.move_cursor_to_visible_area(split_id, buffer_id);
+
+        Ok(())
+    }
+
     /// Handle scrollbar jump (click on track) for composite buffers.
     /// Maps the click ratio to a row-based scroll position.
     fn handle_composite_scrollbar_jump(
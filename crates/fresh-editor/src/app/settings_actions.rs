@@ -139,28 +139,7 @@ impl Editor {
         *self.keybindings.write().unwrap() = KeybindingResolver::new(&self.config);
 
         // Update LSP configs
-        if let Some(ref mut lsp) = self.lsp {
-            for (language, lsp_configs) in &self.config.lsp {
-                lsp.set_language_configs(language.clone(), lsp_configs.as_slice().to_vec());
-            }
-            // Configure universal (global) LSP servers
-            let universal_servers: Vec<LspServerConfig> = self
-                .config
-                .universal_lsp
-                .values()
-                .flat_map(|lc| lc.as_slice().to_vec())
-                .filter(|c| c.enabled)
-                .collect();
-            lsp.set_universal_configs(universal_servers);
-        }
-
-        // Propagate editor config to all split and buffer view states
-        for view_state in self.split_view_states.values_mut() {
-            view_state.show_line_numbers = self.config.editor.line_numbers;
-            for buf_state in view_state.keyed_states.values_mut() {
-                buf_state.rulers = self.config.editor.rulers.clone();
-            }
-        }
+        self.apply_lsp_config();
 
         // Apply bar visibility changes immediately
         self.menu_bar_visible = self.config.editor.show_menu_bar;
@@ -195,12 +174,78 @@ impl Editor {
             }
         }
 
+        // Propagate rulers, tab_size, use_tabs, etc. to all open buffers
+        self.apply_config_to_open_buffers();
+
+        // Save ONLY the changes to disk (preserves external edits to the config file)
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+
+        let layer_name = match target_layer {
+            ConfigLayer::User => "User",
+            ConfigLayer::Project => "Project",
+            ConfigLayer::Session => "Session",
+            ConfigLayer::System => "System", // Should never happen
+        };
+
+        match resolver.save_changes_to_layer(&pending_changes, &pending_deletions, target_layer) {
+            Ok(()) => {
+                self.set_status_message(
+                    t!("settings.saved_to_layer", layer = layer_name).to_string(),
+                );
+                // Clear settings state entirely so next open creates fresh state
+                // from the updated config. This fixes issue #474 where reopening
+                // settings after save would show stale values.
+                self.settings_state = None;
+            }
+            Err(e) => {
+                self.set_status_message(
+                    t!("settings.failed_to_save", error = e.to_string()).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Push the current LSP server configs to the LSP manager
+    pub(super) fn apply_lsp_config(&mut self) {
+        if let Some(ref mut lsp) = self.lsp {
+            for (language, lsp_configs) in &self.config.lsp {
+                lsp.set_language_configs(language.clone(), lsp_configs.as_slice().to_vec());
+            }
+            // Configure universal (global) LSP servers
+            let universal_servers: Vec<LspServerConfig> = self
+                .config
+                .universal_lsp
+                .values()
+                .flat_map(|lc| lc.as_slice().to_vec())
+                .filter(|c| c.enabled)
+                .collect();
+            lsp.set_universal_configs(universal_servers);
+        }
+    }
+
+    /// Re-derive per-view and per-buffer settings from the current config
+    ///
+    /// Called after the config changes at runtime (settings UI, config
+    /// reload). Per-buffer overrides from `.editorconfig` are re-applied on top.
+    pub(super) fn apply_config_to_open_buffers(&mut self) {
+        // Propagate editor config to all split and buffer view states
+        for view_state in self.split_view_states.values_mut() {
+            view_state.show_line_numbers = self.config.editor.line_numbers;
+            for buf_state in view_state.keyed_states.values_mut() {
+                buf_state.rulers = self.config.editor.rulers.clone();
+            }
+        }
+
         // Propagate tab_size/use_tabs/auto_close/whitespace visibility to all open buffers
         // Each buffer resolves its settings from its language + the new global config
         for state in self.buffers.values_mut() {
             let mut whitespace =
                 crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
             state.buffer_settings.auto_close = self.config.editor.auto_close;
+            state.buffer_settings.subword_movement = self.config.editor.subword_movement;
+            state.buffer_settings.indent_guides = self.config.editor.show_indent_guides;
+            state.buffer_settings.spell_check = self.config.editor.spell_check;
+            state.buffer_settings.underline_urls = self.config.editor.underline_urls;
             if let Some(lang_config) = self.config.languages.get(&state.language) {
                 state.buffer_settings.tab_size =
                     lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
@@ -225,33 +270,7 @@ impl Editor {
                 state.buffer_settings.use_tabs = self.config.editor.use_tabs;
             }
             state.buffer_settings.whitespace = whitespace;
-        }
-
-        // Save ONLY the changes to disk (preserves external edits to the config file)
-        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
-
-        let layer_name = match target_layer {
-            ConfigLayer::User => "User",
-            ConfigLayer::Project => "Project",
-            ConfigLayer::Session => "Session",
-            ConfigLayer::System => "System", // Should never happen
-        };
-
-        match resolver.save_changes_to_layer(&pending_changes, &pending_deletions, target_layer) {
-            Ok(()) => {
-                self.set_status_message(
-                    t!("settings.saved_to_layer", layer = layer_name).to_string(),
-                );
-                // Clear settings state entirely so next open creates fresh state
-                // from the updated config. This fixes issue #474 where reopening
-                // settings after save would show stale values.
-                self.settings_state = None;
-            }
-            Err(e) => {
-                self.set_status_message(
-                    t!("settings.failed_to_save", error = e.to_string()).to_string(),
-                );
-            }
+            state.editorconfig.apply(&mut state.buffer_settings);
         }
     }
 
@@ -441,11 +441,12 @@ impl Editor {
         }
     }
 
-    /// Re-apply all stored diagnostics and semantic tokens with the current
-    /// theme colors. Both overlay types bake RGB values at creation time, so
-    /// they must be rebuilt when the theme changes.
-    pub(super) fn reapply_all_overlays(&mut self) {
-        // --- Diagnostics ---
+    /// Rebuild the diagnostic overlays of every buffer from the stored
+    /// diagnostics, unless diagnostics are hidden.
+    pub(super) fn reapply_stored_diagnostics(&mut self) {
+        if self.diagnostics_hidden {
+            return;
+        }
         crate::services::lsp::diagnostics::invalidate_cache_all();
         let entries: Vec<(String, Vec<lsp_types::Diagnostic>)> = self
             .stored_diagnostics
@@ -463,6 +464,14 @@ impl Editor {
                 }
             }
         }
+    }
+
+    /// Re-apply all stored diagnostics and semantic tokens with the current
+    /// theme colors. Both overlay types bake RGB values at creation time, so
+    /// they must be rebuilt when the theme changes.
+    pub(super) fn reapply_all_overlays(&mut self) {
+        // --- Diagnostics ---
+        self.reapply_stored_diagnostics();
 
         // --- Semantic tokens ---
         let buffer_ids: Vec<_> = self.buffers.keys().cloned().collect();
@@ -4,12 +4,14 @@
 //! - Run shell commands with buffer or selection content as stdin
 //! - Output results to a new buffer or replace the input content
 
-use std::io::Write;
 use std::process::{Command, Stdio};
 
+use tokio::io::AsyncWriteExt;
+
 use super::Editor;
 use crate::model::event::Event;
 use crate::services::process_hidden::HideWindow;
+use crate::services::process_limits::ProcessLimits;
 use crate::view::prompt::PromptType;
 use rust_i18n::t;
 
@@ -28,6 +30,9 @@ impl Editor {
 
     /// Execute a shell command with the current buffer/selection as stdin.
     /// Returns Ok(output) on success, Err(error_message) on failure.
+    ///
+    /// The child runs under the default [`ProcessLimits`], so a runaway
+    /// filter can't take the whole machine's memory with it.
     pub fn execute_shell_command(&mut self, command: &str) -> Result<String, String> {
         // Get the input text (selection or entire buffer)
         let input = self.get_shell_input();
@@ -35,27 +40,51 @@ impl Editor {
         // Detect the shell to use
         let shell = detect_shell();
 
-        // Execute the command
-        let mut child = Command::new(&shell)
-            .args(["-c", command])
+        let mut cmd = tokio::process::Command::new(&shell);
+        cmd.args(["-c", command])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .hide_window()
-            .spawn()
-            .map_err(|e| format!("Failed to spawn shell: {}", e))?;
-
-        // Write input to stdin
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin
-                .write_all(input.as_bytes())
-                .map_err(|e| format!("Failed to write to stdin: {}", e))?;
-        }
+            .kill_on_drop(true);
+        let post_spawn = ProcessLimits::default()
+            .apply_to_command(&mut cmd)
+            .map_err(|e| format!("Failed to apply process limits: {}", e))?;
+
+        let rt = self
+            .tokio_runtime
+            .as_ref()
+            .ok_or_else(|| "Failed to spawn shell: no async runtime".to_string())?;
+        let output = rt.block_on(async move {
+            let mut child = cmd
+                .spawn()
+                .map_err(|e| format!("Failed to spawn shell: {}", e))?;
+            if let Some(pid) = child.id() {
+                post_spawn.apply_to_child(pid);
+            }
 
-        // Wait for the command to complete
-        let output = child
-            .wait_with_output()
-            .map_err(|e| format!("Failed to wait for command: {}", e))?;
+            // Feed stdin from a separate task so a filter that writes more
+            // than a pipe buffer of output before draining its input can't
+            // deadlock against us.
+            let writer = child.stdin.take().map(|mut stdin| {
+                tokio::spawn(async move {
+                    // A filter may exit without reading all of its input
+                    // (e.g. `head`); the broken pipe is not an error.
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = stdin.write_all(input.as_bytes()).await;
+                })
+            });
+
+            let output = child
+                .wait_with_output()
+                .await
+                .map_err(|e| format!("Failed to wait for command: {}", e))?;
+            if let Some(writer) = writer {
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = writer.await;
+            }
+            Ok::<_, String>(output)
+        })?;
 
         if output.status.success() {
             String::from_utf8(output.stdout).map_err(|e| format!("Invalid UTF-8 in output: {}", e))
@@ -289,7 +318,7 @@ impl Editor {
 }
 
 /// Detect the shell to use for executing commands.
-fn detect_shell() -> String {
+pub(super) fn detect_shell() -> String {
     // Try SHELL environment variable first
     if let Ok(shell) = std::env::var("SHELL") {
         if !shell.is_empty() {
@@ -0,0 +1,214 @@
+//! Snippet tabstop navigation on `Editor`.
+//!
+//! Accepting a completion whose snippet has tabstops starts a
+//! [`SnippetSession`]. Every occurrence of every tabstop is a start/end pair
+//! of markers in the buffer's marker list, so the ranges follow edits. The
+//! current tabstop's occurrences are selected with one cursor each, so typing
+//! replaces the placeholder and edits its mirrors (`$1` used twice) at once.
+//!
+//! Tab (`insert_tab`) and Shift+Tab (`dedent_selection`) move between
+//! tabstops; Tab past the last one puts the cursor at `$0`. The session also
+//! ends on Escape and on the first action taken with the cursor outside the
+//! current tabstop.
+//!
+//! Markers move after text inserted at their own position, so the current
+//! tabstop's start markers are made sticky while it is being typed into,
+//! and the other tabstops' end markers are, so they don't grow into text
+//! typed right after them.
+
+use crate::input::keybindings::Action;
+use crate::model::cursor::Cursor;
+use crate::model::event::BufferId;
+use crate::model::marker::MarkerId;
+use crate::primitives::snippet::ExpandedSnippet;
+
+use super::Editor;
+
+/// Tabstops of a snippet being filled in.
+#[derive(Debug)]
+pub(crate) struct SnippetSession {
+    buffer_id: BufferId,
+    /// Start and end markers of each occurrence, per tabstop in order
+    stops: Vec<Vec<(MarkerId, MarkerId)>>,
+    /// Marker at `$0`, or the end of the snippet
+    exit: MarkerId,
+    /// Index into `stops` of the selected tabstop
+    current: usize,
+}
+
+impl Editor {
+    /// Start filling in the tabstops of `snippet`, just inserted at
+    /// `insert_pos` in the active buffer, by selecting the first one.
+    /// Snippets without tabstops have nothing to fill in.
+    pub(crate) fn start_snippet_session(&mut self, insert_pos: usize, snippet: &ExpandedSnippet) {
+        self.end_snippet_session();
+        if snippet.tab_stops.is_empty() {
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let state = self.active_state_mut();
+        let stops = snippet
+            .tab_stops
+            .iter()
+            .map(|stop| {
+                stop.ranges
+                    .iter()
+                    .map(|range| {
+                        let start = state.marker_list.create(insert_pos + range.start, true);
+                        let end = state.marker_list.create(insert_pos + range.end, false);
+                        state.marker_list.set_sticky(end, true);
+                        (start, end)
+                    })
+                    .collect()
+            })
+            .collect();
+        let exit = state
+            .marker_list
+            .create(insert_pos + snippet.cursor_offset, false);
+
+        self.snippet_session = Some(SnippetSession {
+            buffer_id,
+            stops,
+            exit,
+            current: 0,
+        });
+        self.select_snippet_stop(0);
+    }
+
+    /// Handle `action` as snippet navigation if a session is active.
+    /// Returns `true` if the action was consumed.
+    ///
+    /// Ends the session first if the cursor left the current tabstop or
+    /// another buffer is active, so the action then runs as usual.
+    pub(crate) fn handle_snippet_action(&mut self, action: &Action) -> bool {
+        if self.snippet_session.is_none() {
+            return false;
+        }
+        if !self.cursor_in_snippet_stop() {
+            self.end_snippet_session();
+            return false;
+        }
+        match action {
+            Action::InsertTab => {
+                self.next_snippet_stop();
+                true
+            }
+            Action::DedentSelection => {
+                if let Some(current) = self.snippet_session.as_ref().map(|s| s.current) {
+                    if current > 0 {
+                        self.select_snippet_stop(current - 1);
+                    }
+                }
+                true
+            }
+            Action::RemoveSecondaryCursors => {
+                self.end_snippet_session();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Select the next tabstop, or finish at `$0` after the last one.
+    fn next_snippet_stop(&mut self) {
+        let Some(session) = self.snippet_session.as_ref() else {
+            return;
+        };
+        let (next, exit) = (session.current + 1, session.exit);
+        if next < session.stops.len() {
+            self.select_snippet_stop(next);
+            return;
+        }
+        let exit = self.active_state().marker_list.get_position(exit);
+        if let Some(exit) = exit {
+            let cursors = self.active_cursors_mut();
+            cursors.remove_secondary();
+            *cursors.primary_mut() = Cursor::new(exit);
+        }
+        self.end_snippet_session();
+        self.ensure_active_cursor_visible_for_navigation(false);
+    }
+
+    /// Make `index` the current tabstop and select all its occurrences.
+    fn select_snippet_stop(&mut self, index: usize) {
+        let Some(session) = self.snippet_session.as_mut() else {
+            return;
+        };
+        let previous = session.current;
+        session.current = index;
+        let stops = session.stops.clone();
+        let marker_list = &mut self.active_state_mut().marker_list;
+        for &(start, end) in &stops[previous] {
+            marker_list.set_sticky(start, false);
+            marker_list.set_sticky(end, true);
+        }
+        for &(start, end) in &stops[index] {
+            marker_list.set_sticky(start, true);
+            marker_list.set_sticky(end, false);
+        }
+
+        let ranges = self.snippet_stop_ranges(index);
+        let cursors = self.active_cursors_mut();
+        cursors.remove_secondary();
+        for (i, &(start, end)) in ranges.iter().enumerate() {
+            let cursor = if start < end {
+                Cursor::with_selection(start, end)
+            } else {
+                Cursor::new(start)
+            };
+            if i == 0 {
+                *cursors.primary_mut() = cursor;
+            } else {
+                cursors.add(cursor);
+            }
+        }
+        self.ensure_active_cursor_visible_for_navigation(false);
+    }
+
+    /// Current byte ranges of the occurrences of tabstop `index`.
+    fn snippet_stop_ranges(&self, index: usize) -> Vec<(usize, usize)> {
+        let Some(session) = self.snippet_session.as_ref() else {
+            return Vec::new();
+        };
+        let Some(state) = self.buffers.get(&session.buffer_id) else {
+            return Vec::new();
+        };
+        session.stops[index]
+            .iter()
+            .filter_map(|&(start, end)| {
+                let start = state.marker_list.get_position(start)?;
+                let end = state.marker_list.get_position(end)?;
+                Some((start, end.max(start)))
+            })
+            .collect()
+    }
+
+    /// Whether the primary cursor is inside an occurrence of the current
+    /// tabstop of the active buffer's session.
+    fn cursor_in_snippet_stop(&self) -> bool {
+        let Some(session) = self.snippet_session.as_ref() else {
+            return false;
+        };
+        if session.buffer_id != self.active_buffer() {
+            return false;
+        }
+        let position = self.active_cursors().primary().position;
+        self.snippet_stop_ranges(session.current)
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&position))
+    }
+
+    /// Stop tabstop navigation and drop the session's markers.
+    pub(crate) fn end_snippet_session(&mut self) {
+        let Some(session) = self.snippet_session.take() else {
+            return;
+        };
+        if let Some(state) = self.buffers.get_mut(&session.buffer_id) {
+            for (start, end) in session.stops.into_iter().flatten() {
+                state.marker_list.delete(start);
+                state.marker_list.delete(end);
+            }
+            state.marker_list.delete(session.exit);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Spell check actions: toggling squiggles per buffer and the suggestions
+//! popup for the misspelled word under the cursor.
+
+use super::Editor;
+use crate::model::event::Event;
+use crate::services::spell_check::{add_to_user_dictionary, spell_checker, DICTIONARIES_DIR};
+use crate::view::popup::{Popup, PopupKind, PopupListItem, PopupPosition, PopupResolver};
+use rust_i18n::t;
+
+/// Maximum number of replacement words offered
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Popup row `data` for the "Add to dictionary" entry; replacement rows
+/// carry the replacement word itself, which never contains a space
+const ADD_TO_DICTIONARY: &str = "add to dictionary";
+
+impl Editor {
+    /// Toggle spell checking for the active buffer. Turning it on without
+    /// a dictionary leaves it off and says where to put one.
+    pub(super) fn toggle_spell_check(&mut self) {
+        let active_buffer = self.active_buffer();
+        let enabling = self
+            .buffers
+            .get(&active_buffer)
+            .is_some_and(|state| !state.buffer_settings.spell_check);
+        if enabling && spell_checker().is_none() {
+            self.report_missing_dictionary();
+            return;
+        }
+        let Some(state) = self.buffers.get_mut(&active_buffer) else {
+            return;
+        };
+        state.buffer_settings.spell_check = !state.buffer_settings.spell_check;
+        let status = if state.buffer_settings.spell_check {
+            t!("spell_check.enabled")
+        } else {
+            t!("spell_check.disabled")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Tell the user that no word list is installed and where one can go
+    pub(crate) fn report_missing_dictionary(&mut self) {
+        let dir = self.dir_context.config_dir.join(DICTIONARIES_DIR);
+        self.set_status_message(
+            t!("spell_check.no_dictionary", dir = dir.display().to_string()).to_string(),
+        );
+    }
+
+    /// Show replacements for the misspelled word under the primary cursor
+    pub(super) fn show_spelling_suggestions(&mut self) {
+        let cursor = self.active_cursors().primary().position;
+        let state = self.active_state();
+        let line = state.buffer.get_line_number(cursor);
+        let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
+        let line_bytes = state.buffer.get_line(line).unwrap_or_default();
+        let line_text = String::from_utf8_lossy(&line_bytes);
+        let Some(checker) = spell_checker() else {
+            self.report_missing_dictionary();
+            return;
+        };
+
+        let Some(word) = checker
+            .misspellings(&line_text)
+            .into_iter()
+            .map(|r| line_start + r.start..line_start + r.end)
+            .find(|r| r.start <= cursor && cursor <= r.end)
+        else {
+            self.set_status_message(t!("spell_check.no_misspelling").to_string());
+            return;
+        };
+        let word_text = line_text[word.start - line_start..word.end - line_start].to_string();
+
+        let mut items: Vec<PopupListItem> = checker
+            .suggestions(&word_text, MAX_SUGGESTIONS)
+            .into_iter()
+            .map(|suggestion| PopupListItem {
+                text: suggestion.clone(),
+                detail: None,
+                icon: None,
+                data: Some(suggestion),
+                disabled: false,
+            })
+            .collect();
+        if items.is_empty() {
+            items.push(PopupListItem {
+                text: t!("spell_check.no_suggestions").to_string(),
+                detail: None,
+                icon: None,
+                data: None,
+                disabled: true,
+            });
+        }
+        items.push(PopupListItem {
+            text: t!("spell_check.add_to_dictionary", word = &word_text).to_string(),
+            detail: None,
+            icon: None,
+            data: Some(ADD_TO_DICTIONARY.to_string()),
+            disabled: false,
+        });
+
+        let mut popup = Popup::list(items, &self.theme);
+        popup.kind = PopupKind::Action;
+        popup.title = Some(t!("spell_check.popup_title", word = &word_text).to_string());
+        popup.position = PopupPosition::BelowCursor;
+        popup.resolver = PopupResolver::SpellSuggestion {
+            start: word.start,
+            end: word.end,
+        };
+        popup.focused = true;
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show_or_replace(popup);
+        }
+    }
+
+    /// Apply the row picked in the suggestions popup for the word at
+    /// `start..end`
+    pub(super) fn apply_spelling_suggestion(&mut self, start: usize, end: usize, choice: &str) {
+        if end > self.active_state().buffer.len() {
+            return;
+        }
+        let word = self.active_state_mut().get_text_range(start, end);
+
+        if choice == ADD_TO_DICTIONARY {
+            let config_dir = self.dir_context.config_dir.clone();
+            match add_to_user_dictionary(&config_dir, &word) {
+                Ok(()) => {
+                    self.set_status_message(t!("spell_check.added", word = &word).to_string())
+                }
+                Err(e) => self.set_status_message(
+                    t!("spell_check.add_failed", error = e.to_string()).to_string(),
+                ),
+            }
+            // Drop the cached checks so the word loses its squiggles now
+            for state in self.buffers.values_mut() {
+                state.spell_check_overlay = Default::default();
+            }
+            return;
+        }
+
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        let cursor_id = self.active_cursors().primary_id();
+        let events = vec![
+            Event::Delete {
+                range: start..end,
+                deleted_text: word,
+                cursor_id,
+            },
+            Event::Insert {
+                position: start,
+                text: choice.to_string(),
+                cursor_id,
+            },
+        ];
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Fix spelling".to_string())
+        {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+}
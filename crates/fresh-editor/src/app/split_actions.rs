@@ -207,6 +207,9 @@ impl Editor {
         self.ensure_active_tab_visible(split_id, self.active_buffer(), self.effective_tabs_width());
 
         let buffer_id = self.active_buffer();
+        if previous_buffer != buffer_id {
+            self.auto_save_on_focus_change(previous_buffer);
+        }
 
         // Leaving a terminal buffer: stop capturing keyboard for the
         // terminal. Symmetric with the mouse-click path in `focus_split`.
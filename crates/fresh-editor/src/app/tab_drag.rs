@@ -168,6 +168,8 @@ impl Editor {
                     // Moving to a different split's tab bar
                     self.move_tab_to_split(buffer_id, source_split_id, target_split_id, insert_idx);
                 }
+                // A tab can't be dropped in between pinned tabs
+                self.keep_pinned_tabs_first(target_split_id);
             }
             TabDropZone::SplitLeft(target_split_id) => {
                 self.create_split_from_tab(
@@ -0,0 +1,64 @@
+//! Pinned tabs
+//!
+//! A pinned tab stays at the left of its split's tab bar and survives the
+//! batch close commands ("Close Others", "Close to the Right", ...). The
+//! flag lives on the buffer's metadata, so a buffer shown in several splits
+//! is pinned in all of them.
+
+use super::Editor;
+use crate::model::event::{BufferId, LeafId};
+use rust_i18n::t;
+
+impl Editor {
+    /// Whether the buffer's tab is pinned
+    pub fn is_tab_pinned(&self, buffer_id: BufferId) -> bool {
+        self.buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|m| m.pinned)
+    }
+
+    /// Pin or unpin a buffer's tab
+    pub fn toggle_tab_pin(&mut self, buffer_id: BufferId) {
+        let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) else {
+            return;
+        };
+        metadata.pinned = !metadata.pinned;
+        let pinned = metadata.pinned;
+        if pinned {
+            // Pinning is a commitment to keep the buffer around
+            self.promote_buffer_from_preview(buffer_id);
+        }
+
+        let splits: Vec<LeafId> = self
+            .split_view_states
+            .iter()
+            .filter(|(_, vs)| vs.has_buffer(buffer_id))
+            .map(|(&split_id, _)| split_id)
+            .collect();
+        for split_id in splits {
+            self.keep_pinned_tabs_first(split_id);
+        }
+
+        let name = self.get_buffer_display_name(buffer_id);
+        if pinned {
+            self.set_status_message(t!("buffer.tab_pinned", name = name).to_string());
+        } else {
+            self.set_status_message(t!("buffer.tab_unpinned", name = name).to_string());
+        }
+    }
+
+    /// Move pinned tabs to the front of a split's tab bar, keeping the
+    /// relative order of the pinned and of the unpinned tabs
+    pub(super) fn keep_pinned_tabs_first(&mut self, split_id: LeafId) {
+        let Some(view_state) = self.split_view_states.get_mut(&split_id) else {
+            return;
+        };
+        let buffer_metadata = &self.buffer_metadata;
+        view_state.open_buffers.sort_by_key(|target| {
+            !target
+                .as_buffer()
+                .and_then(|id| buffer_metadata.get(&id))
+                .is_some_and(|m| m.pinned)
+        });
+    }
+}
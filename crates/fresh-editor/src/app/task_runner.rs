@@ -0,0 +1,417 @@
+//! Build/run tasks on `Editor`.
+//!
+//! Tasks are configured by name under `tasks` in the config, each a shell
+//! command line. Running one spawns it under the default [`ProcessLimits`]
+//! and streams its stdout and stderr line by line (as
+//! `AsyncMessage::TaskOutput`) into a read-only `*Task: <name>*` buffer in
+//! the Utility Dock. Re-running a task that is still running kills the
+//! previous process first. Clicking an output line that contains a
+//! `path:line[:col]` location opens the file there.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use rust_i18n::t;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::input::commands::Suggestion;
+use crate::model::event::BufferId;
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::process_hidden::HideWindow;
+use crate::services::process_limits::ProcessLimits;
+use crate::view::prompt::{Prompt, PromptType};
+use crate::view::split::SplitRole;
+
+use super::command_history::CommandHistoryKind;
+use super::Editor;
+
+/// A task started with `run_task` whose output panel is still around.
+#[derive(Debug)]
+pub(crate) struct TaskRun {
+    name: String,
+    buffer_id: BufferId,
+    /// Directory the task ran in; relative locations resolve against it.
+    cwd: PathBuf,
+    /// Dropping this (when the run is replaced) kills the process if it is
+    /// still running.
+    _kill: tokio::sync::oneshot::Sender<()>,
+}
+
+/// A file location found in a line of task output. `line` and `column`
+/// are 1-indexed, as compilers print them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TaskLocation {
+    path: String,
+    line: usize,
+    column: Option<usize>,
+}
+
+/// Find the first `path:line` or `path:line:col` location in a line of
+/// output, e.g. `src/main.rs:3:5: error` or rustc's `  --> src/main.rs:3:5`.
+fn parse_location(text: &str) -> Option<TaskLocation> {
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    text.split_whitespace().find_map(|word| {
+        let word = word.trim_matches(|c| matches!(c, '(' | ')' | '[' | ']' | '"' | '\'' | ','));
+        // Keep a Windows drive prefix ("C:\...") attached to the path.
+        let has_drive = word.len() > 2
+            && word.as_bytes()[0].is_ascii_alphabetic()
+            && word.as_bytes()[1] == b':'
+            && matches!(word.as_bytes()[2], b'\\' | b'/');
+        let split_from = if has_drive { 2 } else { 0 };
+        let path_end = split_from + word[split_from..].find(':')?;
+        let path = &word[..path_end];
+        // A bare number (e.g. a "12:30" timestamp) is not a path.
+        if path.is_empty() || is_number(path) {
+            return None;
+        }
+
+        let mut rest = word[path_end + 1..].split(':');
+        let line = rest.next().filter(|s| is_number(s))?.parse().ok()?;
+        if line == 0 {
+            return None;
+        }
+        let column = rest
+            .next()
+            .filter(|s| is_number(s))
+            .and_then(|s| s.parse().ok());
+        Some(TaskLocation {
+            path: path.to_string(),
+            line,
+            column,
+        })
+    })
+}
+
+/// Forward each line read from `reader` to the editor as task output.
+fn forward_lines<R>(
+    reader: R,
+    task_id: u64,
+    sender: std::sync::mpsc::Sender<AsyncMessage>,
+) -> tokio::task::JoinHandle<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(reader);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let mut text = String::from_utf8_lossy(&line).into_owned();
+                    if !text.ends_with('\n') {
+                        text.push('\n');
+                    }
+                    if sender
+                        .send(AsyncMessage::TaskOutput { task_id, text })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+impl Editor {
+    /// Open a prompt listing the configured tasks.
+    pub(super) fn start_run_task_prompt(&mut self) {
+        if self.config.tasks.is_empty() {
+            self.set_status_message(t!("task.none_configured").to_string());
+            return;
+        }
+
+        let mut names: Vec<&String> = self.config.tasks.keys().collect();
+        names.sort();
+        let suggestions: Vec<Suggestion> = names
+            .into_iter()
+            .map(|name| Suggestion {
+                text: name.clone(),
+                description: Some(self.config.tasks[name].command.clone()),
+                value: Some(name.clone()),
+                disabled: false,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("task.select_prompt").to_string(),
+            PromptType::RunTask,
+            suggestions,
+        ));
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.selected_suggestion = Some(0);
+        }
+    }
+
+    /// Run the configured task `name`, streaming its output into its panel
+    /// in the Utility Dock. Re-running a task kills its previous process if
+    /// it is still running and reuses (and clears) its panel.
+    pub(crate) fn run_task(&mut self, name: &str) {
+        let Some(task) = self.config.tasks.get(name).cloned() else {
+            self.set_status_message(t!("task.unknown", name = name).to_string());
+            return;
+        };
+        let cwd = match &task.cwd {
+            Some(dir) => self.working_dir.join(dir),
+            None => self.working_dir.clone(),
+        };
+
+        let mut cmd = tokio::process::Command::new(super::shell_command::detect_shell());
+        cmd.args(["-c", &task.command])
+            .current_dir(&cwd)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .hide_window()
+            .kill_on_drop(true);
+        let post_spawn = match ProcessLimits::default().apply_to_command(&mut cmd) {
+            Ok(action) => action,
+            Err(e) => {
+                self.set_status_message(t!("task.start_failed", error = e.to_string()).to_string());
+                return;
+            }
+        };
+        let (Some(runtime), Some(bridge)) =
+            (self.tokio_runtime.as_ref(), self.async_bridge.as_ref())
+        else {
+            self.set_status_message(
+                t!("task.start_failed", error = "async runtime not available").to_string(),
+            );
+            return;
+        };
+        let sender = bridge.sender();
+        let task_id = self.next_task_id;
+        let (kill, mut killed) = tokio::sync::oneshot::channel::<()>();
+
+        runtime.spawn(async move {
+            let mut child = match cmd.spawn() {
+                Ok(child) => child,
+                Err(e) => {
+                    // The editor may already be shutting down; nothing to do then.
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = sender.send(AsyncMessage::TaskOutput {
+                        task_id,
+                        text: format!("{}\n", e),
+                    });
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = sender.send(AsyncMessage::TaskExited {
+                        task_id,
+                        exit_code: None,
+                    });
+                    return;
+                }
+            };
+            if let Some(pid) = child.id() {
+                post_spawn.apply_to_child(pid);
+            }
+
+            let readers = [
+                child
+                    .stdout
+                    .take()
+                    .map(|out| forward_lines(out, task_id, sender.clone())),
+                child
+                    .stderr
+                    .take()
+                    .map(|err| forward_lines(err, task_id, sender.clone())),
+            ];
+            let finished = tokio::select! {
+                status = async {
+                    for reader in readers.into_iter().flatten() {
+                        #[allow(clippy::let_underscore_must_use)]
+                        let _ = reader.await;
+                    }
+                    child.wait().await
+                } => Some(status.ok().and_then(|status| status.code())),
+                // The run was replaced, so its output and exit are no
+                // longer wanted.
+                _ = &mut killed => None,
+            };
+            let Some(exit_code) = finished else {
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = child.kill().await;
+                return;
+            };
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::TaskExited { task_id, exit_code });
+        });
+
+        self.next_task_id += 1;
+        self.record_command_history(
+            t!("command_history.task", name = name).to_string(),
+            CommandHistoryKind::Task(name.to_string()),
+        );
+        let buffer_id = self.task_panel_buffer(name);
+        self.task_runs.retain(|_, run| run.buffer_id != buffer_id);
+        self.task_runs.insert(
+            task_id,
+            TaskRun {
+                name: name.to_string(),
+                buffer_id,
+                cwd,
+                _kill: kill,
+            },
+        );
+        self.append_to_virtual_buffer(buffer_id, &format!("$ {}\n", task.command));
+        self.show_in_utility_dock(buffer_id);
+        self.set_status_message(t!("task.started", name = name).to_string());
+    }
+
+    /// The output buffer for task `name`: its existing panel emptied, or a
+    /// new detached one.
+    fn task_panel_buffer(&mut self, name: &str) -> BufferId {
+        let panel_key = format!("task:{}", name);
+        if let Some(&existing) = self.panel_ids.get(&panel_key) {
+            if self.buffers.contains_key(&existing)
+                && self
+                    .set_virtual_buffer_content(existing, Vec::new())
+                    .is_ok()
+            {
+                return existing;
+            }
+            self.panel_ids.remove(&panel_key);
+        }
+
+        let buffer_id = self.create_virtual_buffer_detached(
+            format!("*Task: {}*", name),
+            "task-output".to_string(),
+            true,
+        );
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.margins.configure_for_line_numbers(false);
+            state.show_cursors = true;
+            state.editing_disabled = true;
+        }
+        self.panel_ids.insert(panel_key, buffer_id);
+        buffer_id
+    }
+
+    /// Append streamed output to the task's panel. Output from a run that
+    /// has since been replaced by a re-run is dropped.
+    pub(super) fn handle_task_output(&mut self, task_id: u64, text: &str) {
+        if let Some(buffer_id) = self.task_runs.get(&task_id).map(|run| run.buffer_id) {
+            self.append_to_virtual_buffer(buffer_id, text);
+        }
+    }
+
+    /// Note the exit status at the end of the panel and in the status bar.
+    pub(super) fn handle_task_exited(&mut self, task_id: u64, exit_code: Option<i32>) {
+        let Some(run) = self.task_runs.get(&task_id) else {
+            return;
+        };
+        let (buffer_id, name) = (run.buffer_id, run.name.clone());
+        let code = exit_code.map_or_else(|| "?".to_string(), |code| code.to_string());
+        self.append_to_virtual_buffer(buffer_id, &format!("[exit code {}]\n", code));
+        let message = if exit_code == Some(0) {
+            t!("task.finished", name = &name)
+        } else {
+            t!("task.failed", name = &name, code = &code)
+        };
+        self.set_status_message(message.to_string());
+    }
+
+    /// If `buffer_id` is a task output panel and the line at `position`
+    /// names a file location, open the file at that location in the first
+    /// split outside the Utility Dock. Returns whether a location was opened.
+    pub(super) fn open_task_location_at(&mut self, buffer_id: BufferId, position: usize) -> bool {
+        let Some(cwd) = self
+            .task_runs
+            .values()
+            .find(|run| run.buffer_id == buffer_id)
+            .map(|run| run.cwd.clone())
+        else {
+            return false;
+        };
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return false;
+        };
+        let line = state.buffer.get_line_number(position);
+        let Some(location) = state
+            .buffer
+            .get_line(line)
+            .and_then(|bytes| parse_location(&String::from_utf8_lossy(&bytes)))
+        else {
+            return false;
+        };
+
+        let path = Path::new(&location.path);
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            cwd.join(path)
+        };
+
+        let dock = self.split_manager.find_leaf_by_role(SplitRole::UtilityDock);
+        let target = self
+            .split_manager
+            .root()
+            .leaf_split_ids()
+            .into_iter()
+            .find(|&leaf| Some(leaf) != dock);
+        if let Some(target) = target {
+            if let Some(target_buffer) = self.split_manager.buffer_for_split(target) {
+                self.focus_split(target, target_buffer);
+            }
+        }
+
+        if let Err(e) = self.open_file(&path) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return true;
+        }
+        match location.column {
+            Some(column) => self.goto_position(location.line, column),
+            None => self.goto_line_col(location.line, None),
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(path: &str, line: usize, column: Option<usize>) -> Option<TaskLocation> {
+        Some(TaskLocation {
+            path: path.to_string(),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn test_parse_location_compiler_styles() {
+        assert_eq!(
+            parse_location("foo.rs:3:1: error"),
+            location("foo.rs", 3, Some(1))
+        );
+        assert_eq!(
+            parse_location("  --> src/main.rs:12:5"),
+            location("src/main.rs", 12, Some(5))
+        );
+        assert_eq!(
+            parse_location("main.py:7: warning: unused"),
+            location("main.py", 7, None)
+        );
+        assert_eq!(
+            parse_location("    at (lib/app.js:40:17)"),
+            location("lib/app.js", 40, Some(17))
+        );
+        assert_eq!(
+            parse_location(r"C:\src\main.c:9:2: error"),
+            location(r"C:\src\main.c", 9, Some(2))
+        );
+    }
+
+    #[test]
+    fn test_parse_location_ignores_non_locations() {
+        assert_eq!(parse_location("error[E0425]: cannot find value"), None);
+        assert_eq!(parse_location("started at 12:30:01"), None);
+        assert_eq!(parse_location("see https://example.com:8080/x"), None);
+        assert_eq!(parse_location("Compiling fresh v0.1.0"), None);
+    }
+}
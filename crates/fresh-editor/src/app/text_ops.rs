@@ -5,12 +5,32 @@
 //! events to the active buffer. Pure decision logic for smart-home lives
 //! in `super::smart_home`; these methods are the cross-cutting drivers.
 
+use std::ops::Range;
+
 use rust_i18n::t;
 
-use crate::model::event::{Event, LeafId};
+use crate::model::event::{BufferId, CursorId, Event, LeafId};
+use crate::primitives::selection_scope::expand_selection;
 
 use super::Editor;
 
+/// Cursor position and anchor of every cursor, ordered by cursor id
+type CursorSnapshot = Vec<(CursorId, usize, Option<usize>)>;
+
+/// Selections saved by `expand_selection_to_scope` so that
+/// `shrink_selection` steps back through exactly the same ranges.
+#[derive(Debug, Clone)]
+pub(crate) struct ScopeSelectionHistory {
+    buffer_id: BufferId,
+    /// Buffer version the selections belong to; any edit ends the history
+    version: u64,
+    /// Cursors before each expansion, oldest first
+    steps: Vec<CursorSnapshot>,
+    /// Cursors as the latest expansion or shrink left them; moving them
+    /// any other way ends the history
+    current: CursorSnapshot,
+}
+
 impl Editor {
     /// Smart home: toggle between line start and first non-whitespace character
     pub(super) fn smart_home(&mut self) {
@@ -49,10 +69,10 @@ impl Editor {
         if let Some((line_start, line_content)) = iter.next_line() {
             // Find first non-whitespace character
             let first_non_ws = line_content
-                .chars()
-                .take_while(|c| *c != '\n')
-                .position(|c| !c.is_whitespace())
-                .map(|offset| line_start + offset)
+                .char_indices()
+                .take_while(|(_, c)| *c != '\n')
+                .find(|(_, c)| !c.is_whitespace())
+                .map(|(offset, _)| line_start + offset)
                 .unwrap_or(line_start);
 
             // Toggle: if at first non-ws, go to line start; otherwise go to first non-ws
@@ -114,12 +134,13 @@ impl Editor {
                 .visual_line_end(split_id, cursor_pos, false)
                 .unwrap_or(visual_start);
             let visual_len = visual_end.saturating_sub(visual_start);
+            // Byte offsets, so multi-byte whitespace (e.g. U+3000) and
+            // characters before it don't skew the target
             content
-                .chars()
-                .take(visual_len)
-                .take_while(|c| *c != '\n')
-                .position(|c| !c.is_whitespace())
-                .map(|offset| visual_start + offset)
+                .char_indices()
+                .take_while(|(offset, c)| *offset < visual_len && *c != '\n')
+                .find(|(_, c)| !c.is_whitespace())
+                .map(|(offset, _)| visual_start + offset)
                 .unwrap_or(visual_start)
         } else {
             visual_start
@@ -134,9 +155,15 @@ impl Editor {
     }
 
     /// Toggle comment on the current line or selection
+    ///
+    /// The prefix is inserted at the shallowest indentation among the
+    /// affected lines, and blank lines are left alone. If only some lines
+    /// are commented, the toggle comments all of them. Languages with block
+    /// markers but no line prefix (HTML, CSS) wrap the lines in a block
+    /// comment instead.
     pub(super) fn toggle_comment(&mut self) {
-        // Determine comment prefix from language config
-        // If no language detected or no comment prefix configured, do nothing
+        // Determine comment prefix from language config. Without one, fall
+        // back to block comments if the language has them, else do nothing
         let language = &self.active_state().language;
         let comment_prefix = self
             .config
@@ -153,7 +180,20 @@ impl Editor {
                     format!("{} ", prefix)
                 }
             }
-            None => return, // No comment prefix for this language, do nothing
+            None => {
+                if let Some((open, close)) = self.block_comment_tokens() {
+                    let cursor = *self.active_cursors().primary();
+                    let (start, end) = match cursor.selection_range() {
+                        Some(range) if !range.is_empty() => {
+                            (range.start, range.end.saturating_sub(1).max(range.start))
+                        }
+                        _ => (cursor.position, cursor.position),
+                    };
+                    let range = self.line_content_range(start, end);
+                    self.toggle_block_comment_range(range, &open, &close);
+                }
+                return;
+            }
         };
 
         let estimated_line_length = self.config.editor.estimated_line_length;
@@ -201,16 +241,35 @@ impl Editor {
                 .line_iterator(current_pos, estimated_line_length);
         }
 
+        // Inspect each line's indentation. Blank lines are neither checked
+        // nor modified, unless every line is blank.
+        let mut line_info = |blank_ok: bool| -> Vec<(usize, usize, String)> {
+            line_starts
+                .iter()
+                .filter_map(|&line_start| {
+                    let mut iter = state
+                        .buffer
+                        .line_iterator(line_start, estimated_line_length);
+                    let (_, content) = iter.next_line().unwrap_or((line_start, String::new()));
+                    let body = content.trim_end_matches(['\n', '\r']);
+                    if body.trim().is_empty() && !blank_ok {
+                        return None;
+                    }
+                    let indent = body.len() - body.trim_start().len();
+                    Some((line_start, indent, body[indent..].to_string()))
+                })
+                .collect()
+        };
+        let mut lines = line_info(false);
+        if lines.is_empty() {
+            lines = line_info(true);
+        }
+
         // Determine if we should comment or uncomment
         // If all lines are commented, uncomment; otherwise comment
-        let all_commented = line_starts.iter().all(|&line_start| {
-            let line_bytes = state
-                .buffer
-                .slice_bytes(line_start..buffer_len.min(line_start + comment_prefix.len() + 10));
-            let line_str = String::from_utf8_lossy(&line_bytes);
-            let trimmed = line_str.trim_start();
-            trimmed.starts_with(comment_prefix.trim())
-        });
+        let all_commented = lines
+            .iter()
+            .all(|(_, _, rest)| rest.starts_with(comment_prefix.trim()));
 
         let mut events = Vec::new();
         // Track (edit_position, byte_delta) for calculating new cursor positions
@@ -219,48 +278,36 @@ impl Editor {
 
         if all_commented {
             // Uncomment: remove comment prefix from each line
-            for &line_start in line_starts.iter().rev() {
-                let line_bytes = state
-                    .buffer
-                    .slice_bytes(line_start..buffer_len.min(line_start + 100));
-                let line_str = String::from_utf8_lossy(&line_bytes);
-
-                // Find where the comment prefix starts (after leading whitespace)
-                let leading_ws: usize = line_str
-                    .chars()
-                    .take_while(|c| c.is_whitespace() && *c != '\n')
-                    .map(|c| c.len_utf8())
-                    .sum();
-                let rest = &line_str[leading_ws..];
-
-                if rest.starts_with(comment_prefix.trim()) {
-                    let remove_len = if rest.starts_with(&comment_prefix) {
-                        comment_prefix.len()
-                    } else {
-                        comment_prefix.trim().len()
-                    };
-                    let deleted_text = String::from_utf8_lossy(&state.buffer.slice_bytes(
-                        line_start + leading_ws..line_start + leading_ws + remove_len,
-                    ))
-                    .to_string();
-                    events.push(Event::Delete {
-                        range: (line_start + leading_ws)..(line_start + leading_ws + remove_len),
-                        deleted_text,
-                        cursor_id,
-                    });
-                    position_deltas.push((line_start, -(remove_len as isize)));
-                }
+            for (line_start, indent, rest) in lines.iter().rev() {
+                let remove_len = if rest.starts_with(&comment_prefix) {
+                    comment_prefix.len()
+                } else {
+                    comment_prefix.trim().len()
+                };
+                let start = line_start + indent;
+                events.push(Event::Delete {
+                    range: start..start + remove_len,
+                    deleted_text: rest[..remove_len].to_string(),
+                    cursor_id,
+                });
+                position_deltas.push((start, -(remove_len as isize)));
             }
         } else {
-            // Comment: add comment prefix to each line
+            // Comment: add comment prefix to each line, aligned to the
+            // shallowest indentation so nested code stays nested
             let prefix_len = comment_prefix.len();
-            for &line_start in line_starts.iter().rev() {
+            let min_indent = lines
+                .iter()
+                .map(|(_, indent, _)| *indent)
+                .min()
+                .unwrap_or(0);
+            for (line_start, _, _) in lines.iter().rev() {
                 events.push(Event::Insert {
-                    position: line_start,
+                    position: line_start + min_indent,
                     text: comment_prefix.to_string(),
                     cursor_id,
                 });
-                position_deltas.push((line_start, prefix_len as isize));
+                position_deltas.push((line_start + min_indent, prefix_len as isize));
             }
         }
 
@@ -314,15 +361,161 @@ impl Editor {
         }
 
         self.set_status_message(
-            t!(
-                "lines.action",
-                action = action_desc,
-                count = line_starts.len()
-            )
-            .to_string(),
+            t!("lines.action", action = action_desc, count = lines.len()).to_string(),
         );
     }
 
+    /// Toggle a block comment around the selection, or around the current
+    /// line's text if nothing is selected.
+    pub(super) fn toggle_block_comment(&mut self) {
+        let Some((open, close)) = self.block_comment_tokens() else {
+            return;
+        };
+        let cursor = *self.active_cursors().primary();
+        let range = match cursor.selection_range() {
+            Some(range) if !range.is_empty() => range,
+            _ => self.line_content_range(cursor.position, cursor.position),
+        };
+        self.toggle_block_comment_range(range, &open, &close);
+    }
+
+    /// Block comment markers for the active buffer's language, if it has both.
+    fn block_comment_tokens(&self) -> Option<(String, String)> {
+        let lang_config = self.config.languages.get(&self.active_state().language)?;
+        Some((
+            lang_config.block_comment_start.clone()?,
+            lang_config.block_comment_end.clone()?,
+        ))
+    }
+
+    /// Byte range from the start of the line containing `start` to the end
+    /// of the line containing `end`, excluding its line ending.
+    fn line_content_range(&mut self, start: usize, end: usize) -> Range<usize> {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let line_start = state
+            .buffer
+            .line_iterator(start, estimated_line_length)
+            .current_position();
+        let mut iter = state.buffer.line_iterator(end, estimated_line_length);
+        let end_line_start = iter.current_position();
+        let line_end = iter
+            .next_line()
+            .map(|(_, content)| end_line_start + content.trim_end_matches(['\n', '\r']).len())
+            .unwrap_or(end_line_start);
+        line_start..line_end
+    }
+
+    /// Wrap `range` in `open ... close`, or unwrap it if its trimmed text is
+    /// already wrapped. Surrounding whitespace stays outside the markers.
+    fn toggle_block_comment_range(&mut self, range: Range<usize>, open: &str, close: &str) {
+        let cursor = *self.active_cursors().primary();
+        let cursor_id = self.active_cursors().primary_id();
+        let text = self
+            .active_state_mut()
+            .get_text_range(range.start, range.end);
+        let inner = text.trim();
+        let inner_start = range.start + (text.len() - text.trim_start().len());
+        let inner_end = inner_start + inner.len();
+
+        let mut events = Vec::new();
+        // (position, delta) of the open and close edits, in buffer order
+        let (open_edit, close_edit);
+        let commented = inner.len() >= open.len() + close.len()
+            && inner.starts_with(open)
+            && inner.ends_with(close);
+        if commented {
+            // Drop the markers along with one padding space each
+            let mut open_len = open.len();
+            if inner[open_len..].starts_with(' ') {
+                open_len += 1;
+            }
+            let mut close_len = close.len();
+            if inner[..inner.len() - close_len].ends_with(' ') && open_len + close_len < inner.len()
+            {
+                close_len += 1;
+            }
+            events.push(Event::Delete {
+                range: inner_end - close_len..inner_end,
+                deleted_text: inner[inner.len() - close_len..].to_string(),
+                cursor_id,
+            });
+            events.push(Event::Delete {
+                range: inner_start..inner_start + open_len,
+                deleted_text: inner[..open_len].to_string(),
+                cursor_id,
+            });
+            open_edit = (inner_start, -(open_len as isize));
+            close_edit = (inner_end - close_len, -(close_len as isize));
+        } else {
+            let open_text = format!("{} ", open);
+            let close_text = format!(" {}", close);
+            open_edit = (inner_start, open_text.len() as isize);
+            close_edit = (inner_end, close_text.len() as isize);
+            events.push(Event::Insert {
+                position: inner_end,
+                text: close_text,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: inner_start,
+                text: open_text,
+                cursor_id,
+            });
+        }
+
+        // Keep a selection covering the whole (un)commented text; otherwise
+        // keep the cursor on the same character.
+        let shift = |pos: usize| -> usize {
+            let mut new_pos = pos as isize;
+            for (at, delta) in [open_edit, close_edit] {
+                if delta >= 0 {
+                    // Text typed right before the opening marker moves with it
+                    if pos > at || (at == open_edit.0 && pos == at) {
+                        new_pos += delta;
+                    }
+                } else {
+                    let len = delta.unsigned_abs();
+                    if pos >= at + len {
+                        new_pos += delta;
+                    } else if pos > at {
+                        new_pos -= (pos - at) as isize;
+                    }
+                }
+            }
+            new_pos.max(0) as usize
+        };
+        let new_range_end = (range.end as isize + open_edit.1 + close_edit.1) as usize;
+        let (new_anchor, new_position) = match cursor.selection_range() {
+            Some(sel) if !sel.is_empty() => {
+                if cursor.position >= cursor.anchor.unwrap_or(0) {
+                    (Some(range.start), new_range_end)
+                } else {
+                    (Some(new_range_end), range.start)
+                }
+            }
+            _ => (None, shift(cursor.position)),
+        };
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0,
+        });
+
+        let description = if commented {
+            "Uncomment block"
+        } else {
+            "Comment block"
+        };
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+
     /// Go to matching bracket
     pub(super) fn goto_matching_bracket(&mut self) {
         let cursor = *self.active_cursors().primary();
@@ -474,4 +667,191 @@ impl Editor {
             self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
         }
     }
+
+    /// Rewrite the leading indentation of every line as tabs or as spaces,
+    /// at `width` columns per indent level, in one undoable edit. Whitespace
+    /// after the first non-blank character is never touched. When converting
+    /// to tabs, columns that don't fill a whole level stay as spaces.
+    pub fn retab_buffer(&mut self, to_spaces: bool, width: usize) {
+        let width = width.max(1);
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let cursor_id = self.active_cursors().primary_id();
+        let state = self.active_state_mut();
+
+        let mut edits = Vec::new();
+        let mut iter = state.buffer.line_iterator(0, estimated_line_length);
+        while let Some((line_start, content)) = iter.next_line() {
+            let indent_len = content.len() - content.trim_start_matches([' ', '\t']).len();
+            let indent = &content[..indent_len];
+            let retabbed = retab_indent(indent, to_spaces, width);
+            if retabbed != indent {
+                edits.push((line_start, indent.to_string(), retabbed));
+            }
+        }
+        state.buffer_settings.use_tabs = !to_spaces;
+        state.buffer_settings.tab_size = width;
+
+        if edits.is_empty() {
+            self.set_status_message(t!("settings.indentation_unchanged").to_string());
+            return;
+        }
+        let line_count = edits.len();
+
+        // Bottom-up so earlier offsets stay valid
+        let mut events = Vec::with_capacity(edits.len() * 2);
+        for (line_start, indent, retabbed) in edits.into_iter().rev() {
+            events.push(Event::Delete {
+                range: line_start..line_start + indent.len(),
+                deleted_text: indent,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: line_start,
+                text: retabbed,
+                cursor_id,
+            });
+        }
+        if let Some(bulk_edit) =
+            self.apply_events_as_bulk_edit(events, "Convert indentation".to_string())
+        {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+
+        let status = if to_spaces {
+            t!("settings.indentation_converted_spaces", count = line_count)
+        } else {
+            t!("settings.indentation_converted_tabs", count = line_count)
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Grow every cursor's selection to the next enclosing scope: word,
+    /// bracket contents, brackets, folding range, lines, whole buffer
+    pub(super) fn expand_selection_to_scope(&mut self) {
+        let buffer_id = self.active_buffer();
+        let before = self.cursor_snapshot();
+        let state = self.active_state();
+        let version = state.buffer.version();
+        let fold_lines: Vec<(usize, usize)> = state
+            .folding_ranges
+            .resolved(&state.buffer, &state.marker_list)
+            .iter()
+            .map(|r| (r.start_line as usize, r.end_line as usize))
+            .collect();
+        let after: CursorSnapshot = before
+            .iter()
+            .map(|&(cursor_id, position, anchor)| {
+                let selection = match anchor {
+                    Some(anchor) => anchor.min(position)..anchor.max(position),
+                    None => position..position,
+                };
+                match expand_selection(&state.buffer, selection, &fold_lines) {
+                    Some(scope) => (cursor_id, scope.end, Some(scope.start)),
+                    None => (cursor_id, position, anchor),
+                }
+            })
+            .collect();
+        if after == before {
+            return;
+        }
+
+        let mut history = match self.scope_selection.take() {
+            Some(history)
+                if history.buffer_id == buffer_id
+                    && history.version == version
+                    && history.current == before =>
+            {
+                history
+            }
+            _ => ScopeSelectionHistory {
+                buffer_id,
+                version,
+                steps: Vec::new(),
+                current: Vec::new(),
+            },
+        };
+        self.move_cursors_to(&after, "Expand selection");
+        history.steps.push(before);
+        history.current = self.cursor_snapshot();
+        self.scope_selection = Some(history);
+    }
+
+    /// Undo the latest `expand_selection_to_scope`, restoring the exact
+    /// selections it started from
+    pub(super) fn shrink_selection(&mut self) {
+        let buffer_id = self.active_buffer();
+        let current = self.cursor_snapshot();
+        let version = self.active_state().buffer.version();
+        let mut history = match self.scope_selection.take() {
+            Some(history)
+                if history.buffer_id == buffer_id
+                    && history.version == version
+                    && history.current == current =>
+            {
+                history
+            }
+            _ => return,
+        };
+        let Some(previous) = history.steps.pop() else {
+            return;
+        };
+        self.move_cursors_to(&previous, "Shrink selection");
+        history.current = self.cursor_snapshot();
+        self.scope_selection = Some(history);
+    }
+
+    fn cursor_snapshot(&self) -> CursorSnapshot {
+        let mut snapshot: CursorSnapshot = self
+            .active_cursors()
+            .iter()
+            .map(|(cursor_id, cursor)| (cursor_id, cursor.position, cursor.anchor))
+            .collect();
+        snapshot.sort_by_key(|(cursor_id, _, _)| cursor_id.0);
+        snapshot
+    }
+
+    /// Move each listed cursor to its position and anchor as one batch
+    fn move_cursors_to(&mut self, targets: &CursorSnapshot, description: &str) {
+        let cursors = self.active_cursors();
+        let events: Vec<Event> = targets
+            .iter()
+            .filter_map(|&(cursor_id, position, anchor)| {
+                let cursor = cursors.get(cursor_id)?;
+                Some(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: position,
+                    old_anchor: cursor.anchor,
+                    new_anchor: anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0,
+                })
+            })
+            .collect();
+        let batch = Event::Batch {
+            events,
+            description: description.to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+    }
+}
+
+/// Re-encode a run of leading whitespace, tabs advancing to the next
+/// multiple of `width`, as the same number of columns in spaces or tabs
+fn retab_indent(indent: &str, to_spaces: bool, width: usize) -> String {
+    let columns = indent.chars().fold(0, |column, c| {
+        if c == '\t' {
+            (column / width + 1) * width
+        } else {
+            column + 1
+        }
+    });
+    if to_spaces {
+        " ".repeat(columns)
+    } else {
+        let mut retabbed = "\t".repeat(columns / width);
+        retabbed.push_str(&" ".repeat(columns % width));
+        retabbed
+    }
 }
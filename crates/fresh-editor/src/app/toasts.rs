@@ -0,0 +1,106 @@
+//! Toast notifications on `Editor`.
+//!
+//! [`Editor::show_toast`] puts a message in the corner stack drawn by
+//! [`render_toasts`](crate::view::toast::render_toasts); `editor_tick`
+//! dismisses them when their timeout passes, measured on the editor's time
+//! source. "Notification Log" lists dismissed toasts newest first in a
+//! read-only `*Notifications*` panel in the Utility Dock.
+
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+use crate::primitives::text_property::TextPropertyEntry;
+use crate::view::toast::ToastSeverity;
+
+use super::Editor;
+
+/// Panel id of the log buffer in `panel_ids`.
+const PANEL_KEY: &str = "notification-log";
+
+impl Editor {
+    /// Show a toast notification in the corner of the editor.
+    pub fn show_toast(&mut self, message: String, severity: ToastSeverity) {
+        tracing::info!(target: "toast", "{}", message);
+        let now = self.time_source.now();
+        self.toasts.push(message, severity, now);
+        self.refresh_notification_log_panel();
+    }
+
+    /// Dismiss toasts whose timeout has passed. Returns true if a render is
+    /// needed.
+    pub fn check_toast_timers(&mut self) -> bool {
+        let now = self.time_source.now();
+        if !self.toasts.expire(now) {
+            return false;
+        }
+        self.refresh_notification_log_panel();
+        true
+    }
+
+    /// Dismiss all visible toasts.
+    pub(crate) fn dismiss_toasts(&mut self) {
+        if self.toasts.dismiss_all() {
+            self.refresh_notification_log_panel();
+        }
+    }
+
+    /// Show the log of dismissed toasts in the Utility Dock.
+    pub(crate) fn show_notification_log(&mut self) {
+        let buffer_id = match self.notification_log_panel() {
+            Some(buffer_id) => buffer_id,
+            None => {
+                let buffer_id = self.create_virtual_buffer_detached(
+                    "*Notifications*".to_string(),
+                    "notification-log".to_string(),
+                    true,
+                );
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.margins.configure_for_line_numbers(false);
+                    state.show_cursors = true;
+                    state.editing_disabled = true;
+                }
+                self.panel_ids.insert(PANEL_KEY.to_string(), buffer_id);
+                buffer_id
+            }
+        };
+        self.refresh_notification_log_panel();
+        self.show_in_utility_dock(buffer_id);
+        if self.toasts.log().next().is_none() {
+            self.set_status_message(t!("toast.log_empty").to_string());
+        }
+    }
+
+    /// The log panel's buffer, if it is still open.
+    fn notification_log_panel(&mut self) -> Option<BufferId> {
+        let buffer_id = *self.panel_ids.get(PANEL_KEY)?;
+        if self.buffers.contains_key(&buffer_id) {
+            Some(buffer_id)
+        } else {
+            self.panel_ids.remove(PANEL_KEY);
+            None
+        }
+    }
+
+    /// Rewrite the log panel, newest first, if it is open.
+    fn refresh_notification_log_panel(&mut self) {
+        let Some(buffer_id) = self.notification_log_panel() else {
+            return;
+        };
+        let entries = self
+            .toasts
+            .log()
+            .rev()
+            .map(|toast| {
+                TextPropertyEntry::text(format!(
+                    "{}  {:<7}  {}\n",
+                    toast.time.format("%H:%M:%S"),
+                    toast.severity.label(),
+                    toast.message
+                ))
+            })
+            .collect();
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::error!("Failed to update notification log panel: {}", e);
+        }
+    }
+}
@@ -6,15 +6,19 @@
 //! - Reset buffer settings
 //! - Config dump, save, and reload
 
-use crate::types::LspServerConfig;
 use rust_i18n::t;
 
-use crate::config::Config;
+use crate::config::{Config, LineNumberMode};
 use crate::config_io::{ConfigLayer, ConfigResolver};
 use crate::input::keybindings::KeybindingResolver;
 
 use super::Editor;
 
+/// How often the active theme's source file is checked for edits. Unrelated
+/// to `auto_revert_poll_interval_ms`: one small file is cheap to stat, and
+/// theme edits should show up quickly.
+const THEME_FILE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 impl Editor {
     /// Toggle line numbers in the gutter for the active split.
     ///
@@ -44,6 +48,22 @@ impl Editor {
         }
     }
 
+    /// Switch the gutter to the next line number mode: absolute → relative
+    /// → hybrid. The legacy `relative_line_numbers` switch is cleared so the
+    /// chosen mode is the one shown.
+    pub fn cycle_line_number_mode(&mut self) {
+        let mode = self.config.editor.effective_line_number_mode().next();
+        let editor = &mut self.config_mut().editor;
+        editor.line_number_mode = mode;
+        editor.relative_line_numbers = false;
+        let message = match mode {
+            LineNumberMode::Absolute => t!("toggle.line_number_mode_absolute"),
+            LineNumberMode::Relative => t!("toggle.line_number_mode_relative"),
+            LineNumberMode::Hybrid => t!("toggle.line_number_mode_hybrid"),
+        };
+        self.set_status_message(message.to_string());
+    }
+
     /// Toggle debug highlight mode for the active buffer
     /// When enabled, shows byte positions and highlight span info for debugging
     pub fn toggle_debug_highlights(&mut self) {
@@ -194,6 +214,9 @@ impl Editor {
             state.buffer_settings.auto_close = auto_close;
             state.buffer_settings.whitespace = whitespace;
             state.buffer_settings.word_characters = word_characters;
+            state.buffer_settings.trim_trailing_whitespace = None;
+            state.buffer_settings.insert_final_newline = None;
+            state.editorconfig.apply(&mut state.buffer_settings);
         }
 
         self.set_status_message(t!("toggle.buffer_settings_reset").to_string());
@@ -286,6 +309,23 @@ impl Editor {
         }
     }
 
+    /// Hide or show diagnostics (underlines, gutter signs, inline text and
+    /// status bar counts) in all buffers. Language servers keep running and
+    /// their diagnostics keep being collected, so showing them is instant.
+    pub fn toggle_diagnostics(&mut self) {
+        self.diagnostics_hidden = !self.diagnostics_hidden;
+        if self.diagnostics_hidden {
+            let ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
+            for state in self.buffers.values_mut() {
+                state.overlays.clear_namespace(&ns, &mut state.marker_list);
+            }
+            self.set_status_message(t!("toggle.diagnostics_hidden").to_string());
+        } else {
+            self.reapply_stored_diagnostics();
+            self.set_status_message(t!("toggle.diagnostics_shown").to_string());
+        }
+    }
+
     /// Dump the current configuration to the user's config file
     pub fn dump_config(&mut self) {
         // Create the config directory if it doesn't exist
@@ -354,21 +394,32 @@ impl Editor {
 
     /// Reload configuration from the config file
     ///
-    /// This reloads the config from disk, applies runtime changes (theme, keybindings),
-    /// and emits a config_changed event so plugins can update their state accordingly.
-    /// Uses the layered config system to properly merge with defaults.
+    /// This reloads the config from disk, applies runtime changes (theme, keybindings,
+    /// per-buffer settings, LSP servers) and emits a config_changed event so plugins
+    /// can update their state accordingly. Uses the layered config system to properly
+    /// merge with defaults. The outcome is reported in the status bar: a config that
+    /// fails to parse is left unapplied, and changes to settings that are only read
+    /// at startup are listed as needing a restart.
     pub fn reload_config(&mut self) {
-        let old_theme = self.config.theme.clone();
-        self.set_config(Config::load_with_layers(
-            &self.dir_context,
-            &self.working_dir,
-        ));
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        let new_config = match resolver.resolve() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Failed to reload config: {}", e);
+                self.set_status_message(
+                    t!("config.reload_failed", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+        let old_config = std::sync::Arc::clone(&self.config);
+        self.set_config(new_config);
 
         // Refresh cached raw user config for plugins
         self.set_user_config_raw(Config::read_user_config_raw(&self.working_dir));
 
         // Apply theme change if needed
-        if old_theme != self.config.theme {
+        if old_config.theme != self.config.theme {
             if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
                 self.theme = theme;
                 tracing::info!("Theme changed to '{}'", self.config.theme.0);
@@ -389,20 +440,61 @@ impl Editor {
         self.status_bar_visible = self.config.editor.show_status_bar;
         self.prompt_line_visible = self.config.editor.show_prompt_line;
 
-        // Update LSP configs
-        if let Some(ref mut lsp) = self.lsp {
-            for (language, lsp_configs) in &self.config.lsp {
-                lsp.set_language_configs(language.clone(), lsp_configs.as_slice().to_vec());
+        // Rulers, tab size, indentation style etc. of open buffers
+        self.apply_config_to_open_buffers();
+
+        // Update LSP configs. Newly enabled servers start the next time a
+        // matching file is opened; newly disabled ones are stopped now.
+        self.apply_lsp_config();
+        let disabled_languages: Vec<String> = old_config
+            .lsp
+            .iter()
+            .filter(|(language, old)| {
+                old.as_slice().iter().any(|c| c.enabled)
+                    && !self
+                        .config
+                        .lsp
+                        .get(*language)
+                        .is_some_and(|new| new.as_slice().iter().any(|c| c.enabled))
+            })
+            .map(|(language, _)| language.clone())
+            .collect();
+        for language in disabled_languages {
+            if !self
+                .lsp
+                .as_ref()
+                .is_some_and(|lsp| lsp.has_handles(&language))
+            {
+                continue;
             }
-            // Configure universal (global) LSP servers
-            let universal_servers: Vec<LspServerConfig> = self
-                .config
-                .universal_lsp
-                .values()
-                .flat_map(|lc| lc.as_slice().to_vec())
-                .filter(|c| c.enabled)
+            // didClose must reach the server before its handles are dropped
+            let buffer_ids: Vec<_> = self
+                .buffers
+                .iter()
+                .filter(|(_, s)| s.language == language)
+                .map(|(id, _)| *id)
                 .collect();
-            lsp.set_universal_configs(universal_servers);
+            for buffer_id in buffer_ids {
+                self.disable_lsp_for_buffer(buffer_id);
+            }
+            self.stop_lsp_server_and_cleanup(&language, None);
+        }
+
+        let restart_required = old_config.restart_required_changes(&self.config);
+        if restart_required.is_empty() {
+            self.set_status_message(t!("config.reloaded").to_string());
+        } else {
+            tracing::warn!(
+                "Config reloaded; restart required for: {}",
+                restart_required.join(", ")
+            );
+            self.set_status_message(
+                t!(
+                    "config.reloaded_restart_required",
+                    settings = restart_required.join(", ")
+                )
+                .to_string(),
+            );
         }
 
         // Emit event so plugins know config changed
@@ -423,7 +515,7 @@ impl Editor {
         use crate::view::theme::ThemeLoader;
 
         let theme_loader = ThemeLoader::new(self.dir_context.themes_dir());
-        self.theme_registry = std::sync::Arc::new(theme_loader.load_all(&[]));
+        self.theme_registry = std::sync::Arc::new(theme_loader.load_all(&self.bundle_theme_dirs));
         self.expanded_menus_cache.invalidate();
 
         // Update shared theme cache for plugin access
@@ -432,6 +524,9 @@ impl Editor {
         // Re-apply current theme if it still exists, otherwise it might have been updated
         if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
             self.theme = theme;
+            self.theme.set_terminal_cursor_color();
+            // Diagnostic and semantic token overlays bake colors at creation
+            self.reapply_all_overlays();
         }
 
         tracing::info!(
@@ -443,6 +538,38 @@ impl Editor {
         self.emit_event("themes_changed", serde_json::json!({}));
     }
 
+    /// Poll the active theme's source file (called from main loop)
+    ///
+    /// Reloads themes when a user theme file is modified on disk, so theme
+    /// edits show up without re-selecting the theme. Built-in and package
+    /// themes have no source file and are not watched.
+    /// Returns true if the theme was reloaded (requires re-render).
+    pub fn poll_theme_file_changes(&mut self) -> bool {
+        if self.time_source.elapsed_since(self.last_theme_file_poll) < THEME_FILE_POLL_INTERVAL {
+            return false;
+        }
+        self.last_theme_file_poll = self.time_source.now();
+
+        let Some(path) = self.theme_registry.source_path(&self.config.theme) else {
+            self.theme_file_watch = None;
+            return false;
+        };
+        // A single small local file — cheap enough to stat synchronously
+        let mtime = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+        let changed = match &self.theme_file_watch {
+            Some((watched, seen)) if *watched == path => *seen != mtime,
+            // Newly selected theme: start watching from its current state
+            _ => false,
+        };
+        self.theme_file_watch = Some((path, mtime));
+
+        if changed {
+            tracing::info!("Theme file changed, reloading '{}'", self.config.theme.0);
+            self.reload_themes();
+        }
+        changed
+    }
+
     /// Persist a single config change to the user config file.
     ///
     /// Used when toggling settings via menu/command palette so that
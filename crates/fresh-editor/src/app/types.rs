@@ -205,6 +205,12 @@ pub struct BufferMetadata {
     /// exploration flow only.
     pub is_preview: bool,
 
+    /// Whether this buffer's tab is pinned. Pinned tabs stay at the left of
+    /// the tab bar and are skipped by "Close Others", "Close to the Right",
+    /// "Close to the Left" and "Close All"; closing the tab itself still
+    /// works. Like `is_preview`, this is session-only state.
+    pub pinned: bool,
+
     /// Stable recovery ID for unnamed buffers.
     /// For file-backed buffers, recovery ID is computed from the path hash.
     /// For unnamed buffers, this is generated once and reused across auto-saves.
@@ -271,6 +277,7 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -292,6 +299,7 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -347,6 +355,7 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -386,6 +395,7 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -523,6 +533,7 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -542,6 +553,7 @@ impl BufferMetadata {
             hidden_from_tabs: true,
             synthetic_placeholder: false,
             is_preview: false,
+            pinned: false,
             recovery_id: None,
         }
     }
@@ -560,6 +572,32 @@ pub(super) struct LspProgressInfo {
     pub title: String,
     pub message: Option<String>,
     pub percentage: Option<u32>,
+    /// When the last begin/report arrived; the most recent one is shown.
+    pub updated_at: std::time::Instant,
+}
+
+/// A save held back until the buffer's language servers answer
+/// `textDocument/willSaveWaitUntil` and, for format-on-save, formatting.
+#[derive(Debug, Clone)]
+pub(super) struct PendingWillSave {
+    /// Requests still awaiting an answer
+    pub requests: std::collections::HashSet<u64>,
+    /// Buffer version the server edits apply to
+    pub version: u64,
+    /// When to stop waiting and save without the missing edits
+    pub deadline: std::time::Instant,
+}
+
+/// A file rename held back until language servers answer
+/// `workspace/willRenameFiles`.
+#[derive(Debug, Clone)]
+pub(super) struct PendingFileRename {
+    /// Requests still awaiting an answer
+    pub requests: std::collections::HashSet<u64>,
+    pub old_path: std::path::PathBuf,
+    pub new_path: std::path::PathBuf,
+    /// When to stop waiting and rename without the missing edits
+    pub deadline: std::time::Instant,
 }
 
 /// LSP message entry (for window messages and logs)
@@ -660,6 +698,8 @@ pub enum TabContextMenuItem {
     CopyRelativePath,
     /// Copy the tab's absolute file path
     CopyFullPath,
+    /// Pin or unpin the tab
+    TogglePin,
 }
 
 impl TabContextMenuItem {
@@ -673,6 +713,7 @@ impl TabContextMenuItem {
             Self::CloseAll,
             Self::CopyRelativePath,
             Self::CopyFullPath,
+            Self::TogglePin,
         ]
     }
 
@@ -686,6 +727,7 @@ impl TabContextMenuItem {
             Self::CloseAll => t!("tab.close_all").to_string(),
             Self::CopyRelativePath => t!("tab.copy_relative_path").to_string(),
             Self::CopyFullPath => t!("tab.copy_full_path").to_string(),
+            Self::TogglePin => t!("tab.toggle_pin").to_string(),
         }
     }
 }
@@ -1012,6 +1054,9 @@ pub(super) struct MouseState {
     pub drag_selection_by_words: bool,
     /// The end of the initially double-clicked word (used as anchor when dragging backward)
     pub drag_selection_word_end: Option<usize>,
+    /// Pointer position while a text selection drag holds it past the top
+    /// or bottom edge of the view, and when the view last scrolled for it
+    pub drag_auto_scroll: Option<(u16, u16, std::time::Instant)>,
     /// Tab drag state (for drag-to-split functionality)
     pub dragging_tab: Option<TabDragState>,
     /// Whether we're currently dragging a popup scrollbar (popup index)
@@ -1112,6 +1157,9 @@ pub(crate) struct CachedLayout {
     /// Horizontal scrollbar areas per split
     /// (split_id, buffer_id, horizontal_scrollbar_rect, max_content_width, thumb_start_col, thumb_end_col)
     pub horizontal_scrollbar_areas: Vec<(LeafId, BufferId, Rect, usize, usize, usize)>,
+    /// Minimap areas per split
+    /// (split_id, buffer_id, minimap_rect)
+    pub minimap_areas: Vec<(LeafId, BufferId, Rect)>,
     /// Split separator positions for drag resize
     /// (container_id, direction, x, y, length)
     pub separator_areas: Vec<(ContainerId, SplitDirection, u16, u16, u16)>,
@@ -1,6 +1,15 @@
 //! Undo and redo action handlers.
+//!
+//! Edits that span several buffers (e.g. an LSP rename applied as a
+//! `WorkspaceEdit`) tag their write entry in each buffer with a shared
+//! linked undo id. Undoing or redoing that entry in any of the buffers does
+//! the same in the others, so the whole edit reverts as one operation. The
+//! id lives on the log entry itself, so it goes away with the entry when
+//! redo history is truncated or the buffer is closed.
 
 use super::Editor;
+use crate::model::cursor::Cursors;
+use crate::model::event::{BufferId, Event, EventLog};
 use rust_i18n::t;
 
 impl Editor {
@@ -11,6 +20,19 @@ impl Editor {
             return;
         }
 
+        let link = self.active_event_log().undo_link();
+
+        self.undo_active_buffer();
+
+        if let Some(link) = link {
+            for buffer_id in self.linked_buffers(link, EventLog::undo_link) {
+                self.undo_linked_buffer(buffer_id);
+            }
+        }
+    }
+
+    /// Undo the last write in the active buffer's event log.
+    fn undo_active_buffer(&mut self) {
         let event_log = self.active_event_log_mut();
         let before_idx = event_log.current_index();
         let can_undo = event_log.can_undo();
@@ -53,6 +75,19 @@ impl Editor {
             return;
         }
 
+        let link = self.active_event_log().redo_link();
+
+        self.redo_active_buffer();
+
+        if let Some(link) = link {
+            for buffer_id in self.linked_buffers(link, EventLog::redo_link) {
+                self.redo_linked_buffer(buffer_id);
+            }
+        }
+    }
+
+    /// Reapply the next undone write in the active buffer's event log.
+    fn redo_active_buffer(&mut self) {
         let events = self.active_event_log_mut().redo();
 
         // Apply all events collected during redo
@@ -63,4 +98,107 @@ impl Editor {
         // Update modified status based on event log position
         self.update_modified_from_event_log();
     }
+
+    /// Make the last write in each of `buffer_ids` one undo step.
+    pub(crate) fn link_undo_entries(&mut self, buffer_ids: &[BufferId]) {
+        if buffer_ids.len() < 2 {
+            return;
+        }
+        let link = self.next_undo_link;
+        self.next_undo_link += 1;
+        for buffer_id in buffer_ids {
+            if let Some(log) = self.event_logs.get_mut(buffer_id) {
+                log.link_last_write(link);
+            }
+        }
+    }
+
+    /// Buffers other than the active one whose next undo/redo (per
+    /// `next_link`) is an entry of group `link`. Buffers edited since then
+    /// are left alone.
+    fn linked_buffers(
+        &self,
+        link: u64,
+        next_link: impl Fn(&EventLog) -> Option<u64>,
+    ) -> Vec<BufferId> {
+        let active = self.active_buffer();
+        self.event_logs
+            .iter()
+            .filter(|(id, log)| **id != active && next_link(log) == Some(link))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Undo the last write in a linked buffer without focusing it.
+    fn undo_linked_buffer(&mut self, buffer_id: BufferId) {
+        let Some(events) = self.event_logs.get_mut(&buffer_id).map(EventLog::undo) else {
+            return;
+        };
+        for (event, displaced_markers) in &events {
+            self.apply_linked_event(buffer_id, event);
+            if !displaced_markers.is_empty() && !matches!(event, Event::BulkEdit { .. }) {
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.restore_displaced_markers(displaced_markers);
+                }
+            }
+        }
+        self.finish_linked_step(buffer_id);
+    }
+
+    /// Reapply the next undone write in a linked buffer without focusing it.
+    fn redo_linked_buffer(&mut self, buffer_id: BufferId) {
+        let Some(events) = self.event_logs.get_mut(&buffer_id).map(EventLog::redo) else {
+            return;
+        };
+        for event in &events {
+            self.apply_linked_event(buffer_id, event);
+        }
+        self.finish_linked_step(buffer_id);
+    }
+
+    /// Apply an undo/redo event to a buffer that is not active. View events
+    /// (scrolling) belong to the active split and are skipped.
+    fn apply_linked_event(&mut self, buffer_id: BufferId, event: &Event) {
+        if matches!(
+            event,
+            Event::Scroll { .. } | Event::SetViewport { .. } | Event::Recenter
+        ) {
+            return;
+        }
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let mut detached = Cursors::new();
+        let cursors = self
+            .split_view_states
+            .values_mut()
+            .find_map(|vs| vs.keyed_states.get_mut(&buffer_id))
+            .map_or(&mut detached, |bvs| &mut bvs.cursors);
+        state.apply(cursors, event);
+    }
+
+    /// Bring a linked buffer's views, modified flag and language server up
+    /// to date after its undo/redo events were applied.
+    fn finish_linked_step(&mut self, buffer_id: BufferId) {
+        self.invalidate_layouts_for_buffer(buffer_id);
+        self.schedule_semantic_tokens_full_refresh(buffer_id);
+        self.schedule_folding_ranges_refresh(buffer_id);
+
+        let is_at_saved = self
+            .event_logs
+            .get(&buffer_id)
+            .is_some_and(|log| log.is_at_saved_position());
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        state.buffer.set_modified(!is_at_saved);
+        if let Some(text) = state.buffer.to_string() {
+            let full_change = vec![lsp_types::TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text,
+            }];
+            self.send_lsp_changes_for_buffer(buffer_id, full_change);
+        }
+    }
 }
@@ -0,0 +1,49 @@
+//! User snippet expansion on `Editor`.
+//!
+//! Snippets come from the `snippets` config, keyed by language and then by
+//! trigger word. Pressing Tab right after a trigger (with no completion
+//! popup open, which would take the key itself) replaces the trigger with
+//! the snippet body, which then goes through the same expansion and tabstop
+//! navigation as an accepted LSP snippet completion.
+
+use crate::primitives::word_navigation::find_completion_word_start;
+
+use super::Editor;
+
+impl Editor {
+    /// Expand the user snippet whose trigger is the word before the cursor.
+    /// Returns `false`, leaving the buffer alone, when there is no such
+    /// snippet for the buffer's language or there are several cursors or a
+    /// selection.
+    pub(crate) fn expand_user_snippet(&mut self) -> bool {
+        if self.is_editing_disabled() || self.active_cursors().count() > 1 {
+            return false;
+        }
+        let cursor = *self.active_cursors().primary();
+        if cursor.selection_range().is_some() {
+            return false;
+        }
+        let language = &self.active_state().language;
+        if !self.config.snippets.contains_key(language) {
+            return false;
+        }
+        let language = language.clone();
+        let state = self.active_state_mut();
+        let word_start = find_completion_word_start(&state.buffer, cursor.position);
+        if word_start == cursor.position {
+            return false;
+        }
+        let trigger = state.get_text_range(word_start, cursor.position);
+        let Some(body) = self
+            .config
+            .snippets
+            .get(&language)
+            .and_then(|snippets| snippets.get(&trigger))
+            .cloned()
+        else {
+            return false;
+        };
+        self.insert_completion_text(body);
+        true
+    }
+}
@@ -4,9 +4,10 @@
 //!   is_stdin_streaming: drive the StdinStream subsystem (extracted in
 //!   phase 2e), translating its outcomes into buffer extensions and
 //!   status messages.
-//! - create_virtual_buffer / set_virtual_buffer_content: helpers for
-//!   creating buffers backed by virtual content (LSP help text, plugin
-//!   panels, search results, etc.).
+//! - create_virtual_buffer / set_virtual_buffer_content /
+//!   append_to_virtual_buffer: helpers for creating buffers backed by
+//!   virtual content (LSP help text, plugin panels, search results, task
+//!   output, etc.).
 
 use std::path::Path;
 use std::sync::Arc;
@@ -87,6 +88,8 @@ impl Editor {
         state.buffer_settings.tab_size = self.config.editor.tab_size;
         state.buffer_settings.auto_close = self.config.editor.auto_close;
         state.buffer_settings.auto_surround = self.config.editor.auto_surround;
+        state.buffer_settings.subword_movement = self.config.editor.subword_movement;
+        state.buffer_settings.spell_check = self.config.editor.spell_check;
 
         // Apply line_numbers default from config
         state
@@ -440,4 +443,29 @@ impl Editor {
         }
         Ok(())
     }
+
+    /// Append plain text to the end of a virtual buffer, e.g. streamed
+    /// process output. Cursors sitting at the old end (without a
+    /// selection) move to the new end so the view keeps following the
+    /// output; cursors elsewhere stay put.
+    pub fn append_to_virtual_buffer(&mut self, buffer_id: BufferId, text: &str) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let old_len = state.buffer.len();
+        state.buffer.insert(old_len, text);
+        state.buffer.clear_modified();
+        let new_len = state.buffer.len();
+
+        for view_state in self.split_view_states.values_mut() {
+            let Some(buf_state) = view_state.keyed_states.get_mut(&buffer_id) else {
+                continue;
+            };
+            buf_state.cursors.map(|cursor| {
+                if cursor.position == old_len && cursor.anchor.is_none() {
+                    cursor.position = new_len;
+                }
+            });
+        }
+    }
 }
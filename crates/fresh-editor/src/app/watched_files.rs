@@ -0,0 +1,298 @@
+//! `workspace/didChangeWatchedFiles` for running language servers.
+//!
+//! The project root is walked recursively like the file tree would show
+//! it (hidden and gitignored entries are skipped), together with the
+//! directories of open files outside it. The walk runs on a background
+//! thread through the filesystem authority and records each file's mtime.
+//! Files that appeared, vanished or whose mtime moved since the previous
+//! walk are sent to every running server as created, deleted or changed.
+//! This does not depend on the file explorer, so it works with the explorer
+//! closed or the directories collapsed. Polling stats paths rather than
+//! inodes, so files saved by renaming a temp file over them are seen too.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Instant, SystemTime};
+
+use lsp_types::{FileChangeType, FileEvent};
+
+use crate::model::filesystem::FileSystem;
+use crate::view::file_tree::IgnorePatterns;
+
+use super::Editor;
+
+/// Most files one walk records. Past this the walk stops, so a huge tree
+/// costs a bounded amount of work per poll.
+const MAX_WATCHED_FILES: usize = 20_000;
+
+/// The watched files and their mtimes, as of one walk
+#[derive(Debug, Default)]
+struct Scan {
+    /// Directories the walk started from
+    roots: Vec<PathBuf>,
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+    /// Whether the walk stopped at [`MAX_WATCHED_FILES`]
+    truncated: bool,
+}
+
+/// Poll state for the watched files
+pub(crate) struct WatchedFiles {
+    /// Result of the last walk, or `None` before the first one finished
+    scan: Option<Scan>,
+    /// Receiver for a background walk in progress
+    pending: Option<mpsc::Receiver<Scan>>,
+    /// Last time a walk was started
+    last_poll: Instant,
+}
+
+impl WatchedFiles {
+    pub(crate) fn new(now: Instant) -> Self {
+        Self {
+            scan: None,
+            pending: None,
+            last_poll: now,
+        }
+    }
+}
+
+/// Walk `root` recursively, skipping hidden and gitignored entries and not
+/// following symlinked directories, then list the files directly in each
+/// of `extra_dirs`.
+fn scan_watched_files(fs: &dyn FileSystem, root: &Path, extra_dirs: &[PathBuf]) -> Scan {
+    let mut scan = Scan {
+        roots: std::iter::once(root.to_path_buf())
+            .chain(extra_dirs.iter().cloned())
+            .collect(),
+        ..Scan::default()
+    };
+    let mut ignores = IgnorePatterns::new();
+    let mut stack = vec![(root.to_path_buf(), true)];
+    stack.extend(extra_dirs.iter().map(|dir| (dir.clone(), false)));
+    stack.reverse();
+
+    while let Some((dir, recursive)) = stack.pop() {
+        if recursive {
+            if let Ok(contents) = fs.read_file(&dir.join(".gitignore")) {
+                ignores.load_gitignore_from_bytes(&dir, &contents, None);
+            }
+        }
+        let Ok(entries) = fs.read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let is_dir = entry.is_dir();
+            if ignores.is_ignored(&entry.path, is_dir) {
+                continue;
+            }
+            if is_dir {
+                if recursive && !entry.is_symlink() {
+                    stack.push((entry.path, true));
+                }
+                continue;
+            }
+            if scan.files.len() >= MAX_WATCHED_FILES {
+                scan.truncated = true;
+                return scan;
+            }
+            let mtime = fs.metadata(&entry.path).ok().and_then(|m| m.modified);
+            scan.files.insert(entry.path, mtime);
+        }
+    }
+    scan
+}
+
+/// Files created, changed or deleted between two walks. Deletions are left
+/// out when either walk was truncated, since a file past the cut is not
+/// gone.
+fn diff_scans(previous: &Scan, current: &Scan) -> Vec<(PathBuf, FileChangeType)> {
+    let mut changes: Vec<(PathBuf, FileChangeType)> = current
+        .files
+        .iter()
+        .filter_map(|(path, mtime)| match previous.files.get(path) {
+            None => Some((path.clone(), FileChangeType::CREATED)),
+            Some(old) if old != mtime => Some((path.clone(), FileChangeType::CHANGED)),
+            Some(_) => None,
+        })
+        .collect();
+    if !previous.truncated && !current.truncated {
+        changes.extend(
+            previous
+                .files
+                .keys()
+                .filter(|path| !current.files.contains_key(*path))
+                .map(|path| (path.clone(), FileChangeType::DELETED)),
+        );
+    }
+    changes
+}
+
+impl Editor {
+    /// Walk the watched files (called from the main loop, at the file tree's
+    /// poll interval) and notify language servers of changes.
+    pub(crate) fn poll_watched_files(&mut self) {
+        if let Some(rx) = &self.watched_files.pending {
+            match rx.try_recv() {
+                Ok(scan) => {
+                    self.watched_files.pending = None;
+                    self.process_watched_files_scan(scan);
+                }
+                Err(mpsc::TryRecvError::Empty) => return,
+                Err(mpsc::TryRecvError::Disconnected) => self.watched_files.pending = None,
+            }
+        }
+
+        let poll_interval =
+            std::time::Duration::from_millis(self.config.editor.file_tree_poll_interval_ms);
+        if self.time_source.elapsed_since(self.watched_files.last_poll) < poll_interval {
+            return;
+        }
+        self.watched_files.last_poll = self.time_source.now();
+
+        let any_server = self
+            .lsp
+            .as_ref()
+            .is_some_and(|lsp| lsp.all_handles().next().is_some());
+        if !any_server {
+            return;
+        }
+
+        let root = self.working_dir.clone();
+        let extra_dirs: Vec<PathBuf> = self
+            .buffer_metadata
+            .values()
+            .filter_map(|meta| meta.file_path())
+            .filter_map(|path| path.parent())
+            .filter(|dir| !dir.starts_with(&root))
+            .map(Path::to_path_buf)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let (tx, rx) = mpsc::channel();
+        let fs = self.authority.filesystem.clone();
+        std::thread::Builder::new()
+            .name("poll-watched-files".to_string())
+            .spawn(move || {
+                let scan = scan_watched_files(fs.as_ref(), &root, &extra_dirs);
+                // Receiver may have been dropped during shutdown — that's fine.
+                if tx.send(scan).is_err() {}
+            })
+            .ok();
+        self.watched_files.pending = Some(rx);
+    }
+
+    /// Whether `dir` is covered by a finished walk, so later changes under
+    /// it are reported.
+    #[doc(hidden)]
+    pub fn is_watching_dir(&self, dir: &Path) -> bool {
+        self.watched_files
+            .scan
+            .as_ref()
+            .is_some_and(|scan| scan.roots.iter().any(|root| root == dir))
+    }
+
+    /// Compare a finished walk with the previous one and send what changed.
+    /// The first walk only sets the baseline.
+    fn process_watched_files_scan(&mut self, scan: Scan) {
+        let changes = self
+            .watched_files
+            .scan
+            .as_ref()
+            .map(|previous| diff_scans(previous, &scan))
+            .unwrap_or_default();
+        self.watched_files.scan = Some(scan);
+        self.notify_lsp_watched_files(changes);
+    }
+
+    /// Tell every running language server which files were created,
+    /// changed or deleted on disk, so project-wide diagnostics catch up with changes
+    /// made outside the editor.
+    fn notify_lsp_watched_files(&self, changes: Vec<(PathBuf, FileChangeType)>) {
+        let Some(lsp) = &self.lsp else {
+            return;
+        };
+        let translation = self.authority.path_translation.as_ref();
+        let changes: Vec<FileEvent> = changes
+            .into_iter()
+            .filter_map(|(path, typ)| {
+                super::types::file_path_to_lsp_uri_with_translation(&path, translation)
+                    .map(|uri| FileEvent { uri, typ })
+            })
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+
+        for sh in lsp.all_handles() {
+            if let Err(e) = sh.handle.did_change_watched_files(changes.clone()) {
+                tracing::warn!(
+                    "Failed to send didChangeWatchedFiles to '{}': {}",
+                    sh.name,
+                    e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(files: &[(&str, u64)], truncated: bool) -> Scan {
+        Scan {
+            roots: vec![PathBuf::from("/p")],
+            files: files
+                .iter()
+                .map(|(path, secs)| {
+                    let mtime = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(*secs);
+                    (PathBuf::from(path), Some(mtime))
+                })
+                .collect(),
+            truncated,
+        }
+    }
+
+    #[test]
+    fn test_diff_scans_reports_created_changed_and_deleted() {
+        let previous = scan(&[("/p/a.rs", 1), ("/p/src/b.rs", 1), ("/p/c.rs", 1)], false);
+        let current = scan(&[("/p/a.rs", 1), ("/p/src/b.rs", 2), ("/p/d.rs", 1)], false);
+        assert_eq!(
+            diff_scans(&previous, &current),
+            vec![
+                (PathBuf::from("/p/d.rs"), FileChangeType::CREATED),
+                (PathBuf::from("/p/src/b.rs"), FileChangeType::CHANGED),
+                (PathBuf::from("/p/c.rs"), FileChangeType::DELETED),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_scans_skips_deletions_when_truncated() {
+        let previous = scan(&[("/p/a.rs", 1), ("/p/b.rs", 1)], false);
+        let current = scan(&[("/p/a.rs", 2)], true);
+        assert_eq!(
+            diff_scans(&previous, &current),
+            vec![(PathBuf::from("/p/a.rs"), FileChangeType::CHANGED)]
+        );
+    }
+
+    #[test]
+    fn test_scan_skips_hidden_and_gitignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/nested")).unwrap();
+        std::fs::create_dir_all(root.join("target")).unwrap();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        std::fs::write(root.join("src/nested/lib.rs"), "").unwrap();
+        std::fs::write(root.join("target/out.rs"), "").unwrap();
+        std::fs::write(root.join(".git/HEAD"), "").unwrap();
+
+        let scan = scan_watched_files(&crate::model::filesystem::StdFileSystem, root, &[]);
+        let files: Vec<&PathBuf> = scan.files.keys().collect();
+        assert_eq!(files, vec![&root.join("src/nested/lib.rs")]);
+        assert!(!scan.truncated);
+    }
+}
@@ -284,6 +284,7 @@ impl Editor {
         let config_overrides = WorkspaceConfigOverrides {
             line_numbers: Some(self.config.editor.line_numbers),
             relative_line_numbers: Some(self.config.editor.relative_line_numbers),
+            line_number_mode: Some(self.config.editor.line_number_mode),
             line_wrap: Some(self.config.editor.line_wrap),
             syntax_highlighting: Some(self.config.editor.syntax_highlighting),
             enable_inlay_hints: Some(self.config.editor.enable_inlay_hints),
@@ -292,6 +293,11 @@ impl Editor {
         };
 
         // Capture histories using the items() accessor from the prompt_histories HashMap
+        let command_history = self
+            .command_registry
+            .read()
+            .map(|registry| registry.history())
+            .unwrap_or_default();
         let histories = WorkspaceHistories {
             search: self
                 .prompt_histories
@@ -303,7 +309,11 @@ impl Editor {
                 .get("replace")
                 .map(|h| h.items().to_vec())
                 .unwrap_or_default(),
-            command_palette: Vec::new(), // Future: when command palette has history
+            command_palette: command_history
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            command_palette_counts: command_history.into_iter().collect(),
             goto_line: self
                 .prompt_histories
                 .get("goto_line")
@@ -358,6 +368,24 @@ impl Editor {
             })
             .collect();
 
+        // Capture breakpoints, keyed like read_only_files.
+        let breakpoints: HashMap<PathBuf, Vec<usize>> = self
+            .buffer_metadata
+            .iter()
+            .filter_map(|(&buffer_id, meta)| {
+                let path = meta.file_path().filter(|p| !p.as_os_str().is_empty())?;
+                let lines = self.breakpoint_lines(buffer_id);
+                if lines.is_empty() {
+                    return None;
+                }
+                let path = path
+                    .strip_prefix(&self.working_dir)
+                    .map(|rel| rel.to_path_buf())
+                    .unwrap_or_else(|_| path.clone());
+                Some((path, lines))
+            })
+            .collect();
+
         // Capture unnamed buffer references (for hot_exit)
         let unnamed_buffers: Vec<UnnamedBufferRef> = if self.config.editor.hot_exit {
             self.buffer_metadata
@@ -406,6 +434,7 @@ impl Editor {
             terminals,
             external_files,
             read_only_files,
+            breakpoints,
             unnamed_buffers,
             plugin_global_state: self.plugin_global_state.clone(),
             saved_at: std::time::SystemTime::now()
@@ -722,6 +751,7 @@ impl Editor {
         self.restore_external_files(&workspace.external_files, &mut path_to_buffer);
         self.apply_read_only_flags(&workspace.read_only_files, &path_to_buffer);
         self.restore_hot_exit_changes(&path_to_buffer);
+        self.restore_breakpoints_from_workspace(&workspace.breakpoints, &path_to_buffer);
 
         let unnamed_buffer_map = self.restore_unnamed_buffers(&workspace.unnamed_buffers);
         let terminal_buffer_map = self.restore_terminals_from_workspace(&workspace.terminals);
@@ -770,6 +800,9 @@ impl Editor {
         if let Some(relative_line_numbers) = overrides.relative_line_numbers {
             self.config_mut().editor.relative_line_numbers = relative_line_numbers;
         }
+        if let Some(line_number_mode) = overrides.line_number_mode {
+            self.config_mut().editor.line_number_mode = line_number_mode;
+        }
         if let Some(line_wrap) = overrides.line_wrap {
             self.config_mut().editor.line_wrap = line_wrap;
         }
@@ -814,6 +847,18 @@ impl Editor {
             self.get_or_create_prompt_history("goto_line")
                 .push(item.clone());
         }
+        if !histories.command_palette.is_empty() {
+            if let Ok(mut registry) = self.command_registry.write() {
+                registry.restore_history(histories.command_palette.iter().map(|name| {
+                    let count = histories
+                        .command_palette_counts
+                        .get(name)
+                        .copied()
+                        .unwrap_or(1);
+                    (name.clone(), count)
+                }));
+            }
+        }
     }
 
     fn restore_file_explorer_settings(&mut self, fe: &FileExplorerState) {
@@ -922,6 +967,24 @@ impl Editor {
         }
     }
 
+    /// Re-set the breakpoints of the saved session.
+    /// Paths may be relative (under `working_dir`) or absolute.
+    fn restore_breakpoints_from_workspace(
+        &mut self,
+        breakpoints: &HashMap<PathBuf, Vec<usize>>,
+        path_to_buffer: &HashMap<PathBuf, BufferId>,
+    ) {
+        for (path, lines) in breakpoints {
+            let buffer_id = path_to_buffer
+                .get(path)
+                .copied()
+                .or_else(|| path_to_buffer.get(&self.working_dir.join(path)).copied());
+            if let Some(id) = buffer_id {
+                self.restore_breakpoints(id, lines);
+            }
+        }
+    }
+
     /// Replay hot-exit recovery data onto file-backed buffers that were modified
     /// when the editor last exited.
     fn restore_hot_exit_changes(&mut self, path_to_buffer: &HashMap<PathBuf, BufferId>) {
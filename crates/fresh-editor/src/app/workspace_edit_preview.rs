@@ -0,0 +1,292 @@
+//! Side-by-side preview of LSP workspace edits before they are applied.
+//!
+//! With `editor.preview_workspace_edits` enabled, a rename opens one diff
+//! view per affected file showing the file before and after the edit. The
+//! edit is held until the user confirms it at the prompt; cancelling closes
+//! the previews, and the files they opened, and leaves every buffer
+//! untouched.
+
+use super::Editor;
+use crate::model::buffer::TextBuffer;
+use crate::model::event::BufferId;
+use crate::view::prompt::PromptType;
+use anyhow::Result as AnyhowResult;
+use lsp_types::{DocumentChangeOperation, DocumentChanges, TextEdit, WorkspaceEdit};
+use rust_i18n::t;
+use std::collections::{BTreeMap, HashSet};
+
+/// A workspace edit shown as diffs, waiting to be applied or discarded
+pub(crate) struct PendingWorkspaceEdit {
+    edit: WorkspaceEdit,
+    /// Diff views and the "after" buffers backing them
+    preview_buffers: Vec<BufferId>,
+    /// Buffer that was active before the preview opened
+    return_to: BufferId,
+    /// Files that were not open before the preview opened them
+    opened_buffers: Vec<BufferId>,
+}
+
+/// Text edits of a workspace edit grouped by document, ordered by URI.
+///
+/// Returns `None` when the edit also creates, renames or deletes files,
+/// which a text diff can't show.
+fn text_edits_by_uri(
+    edit: &WorkspaceEdit,
+) -> Option<BTreeMap<String, (lsp_types::Uri, Vec<TextEdit>)>> {
+    let mut by_uri: BTreeMap<String, (lsp_types::Uri, Vec<TextEdit>)> = BTreeMap::new();
+    let mut add = |uri: &lsp_types::Uri, edits: Vec<TextEdit>| {
+        by_uri
+            .entry(uri.as_str().to_string())
+            .or_insert_with(|| (uri.clone(), Vec::new()))
+            .1
+            .extend(edits);
+    };
+
+    if let Some(changes) = &edit.changes {
+        for (uri, edits) in changes {
+            add(uri, edits.clone());
+        }
+    }
+
+    if let Some(document_changes) = &edit.document_changes {
+        let document_edits = match document_changes {
+            DocumentChanges::Edits(edits) => edits.iter().collect::<Vec<_>>(),
+            DocumentChanges::Operations(ops) => {
+                let mut edits = Vec::new();
+                for op in ops {
+                    match op {
+                        DocumentChangeOperation::Edit(text_doc_edit) => edits.push(text_doc_edit),
+                        DocumentChangeOperation::Op(_) => return None,
+                    }
+                }
+                edits
+            }
+        };
+        for text_doc_edit in document_edits {
+            let edits = text_doc_edit
+                .edits
+                .iter()
+                .map(|one_of| match one_of {
+                    lsp_types::OneOf::Left(text_edit) => text_edit.clone(),
+                    lsp_types::OneOf::Right(annotated) => annotated.text_edit.clone(),
+                })
+                .collect();
+            add(&text_doc_edit.text_document.uri, edits);
+        }
+    }
+
+    Some(by_uri)
+}
+
+/// Apply LSP text edits directly to a buffer, last edit first so earlier
+/// positions stay valid
+fn apply_text_edits(buffer: &mut TextBuffer, mut edits: Vec<TextEdit>) {
+    edits.sort_by(|a, b| {
+        b.range
+            .start
+            .line
+            .cmp(&a.range.start.line)
+            .then(b.range.start.character.cmp(&a.range.start.character))
+    });
+    for edit in edits {
+        let start = buffer.lsp_position_to_byte(
+            edit.range.start.line as usize,
+            edit.range.start.character as usize,
+        );
+        let end = buffer.lsp_position_to_byte(
+            edit.range.end.line as usize,
+            edit.range.end.character as usize,
+        );
+        if start < end {
+            buffer.delete(start..end);
+        }
+        if !edit.new_text.is_empty() {
+            buffer.insert(start, &edit.new_text);
+        }
+    }
+}
+
+impl Editor {
+    /// Show a workspace edit as side-by-side diffs and ask before applying it.
+    ///
+    /// Edits that include file operations are applied straight away.
+    pub(crate) fn preview_workspace_edit(&mut self, edit: WorkspaceEdit) -> AnyhowResult<()> {
+        use crate::model::composite_buffer::{CompositeLayout, LineAlignment, SourcePane};
+        use crate::model::line_diff::diff_hunks;
+
+        let by_uri = match text_edits_by_uri(&edit) {
+            Some(by_uri) if !by_uri.is_empty() => by_uri,
+            _ => {
+                let total_changes = self.apply_workspace_edit(edit)?;
+                self.set_status_message(t!("lsp.renamed", count = total_changes).to_string());
+                return Ok(());
+            }
+        };
+
+        // Only one preview at a time
+        if let Some(previous) = self.close_workspace_edit_preview() {
+            self.close_files_opened_for_preview(&previous.opened_buffers);
+        }
+
+        let return_to = self.active_buffer();
+        let open_before: HashSet<BufferId> = self.buffers.keys().copied().collect();
+        let mut preview_buffers = Vec::new();
+        let mut opened_buffers = Vec::new();
+        let mut first_preview = None;
+        for (uri, edits) in by_uri.into_values() {
+            let uri = crate::app::types::LspUri::from_wire(uri);
+            let Ok(path) =
+                super::lsp_uri_to_host_path(&uri, self.authority.path_translation.as_ref())
+            else {
+                continue;
+            };
+            let buffer_id = match self.open_file(&path) {
+                Ok(id) => id,
+                Err(e) => {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                    continue;
+                }
+            };
+            if !open_before.contains(&buffer_id) {
+                opened_buffers.push(buffer_id);
+            }
+            let Some(before_text) = self
+                .buffers
+                .get(&buffer_id)
+                .and_then(|state| state.buffer.to_string())
+            else {
+                continue;
+            };
+
+            let name = self.get_buffer_display_name(buffer_id);
+            let after_buffer = self.create_virtual_buffer_detached(
+                t!("buffer.edit_preview_after_name", name = &name).to_string(),
+                "diff-view".to_string(),
+                true,
+            );
+            let Some(after_text) = self.buffers.get_mut(&after_buffer).map(|state| {
+                state.buffer.insert(0, &before_text);
+                apply_text_edits(&mut state.buffer, edits);
+                state.buffer.clear_modified();
+                state.buffer.to_string().unwrap_or_default()
+            }) else {
+                continue;
+            };
+
+            let hunks = diff_hunks(before_text.as_bytes(), after_text.as_bytes());
+            let composite = self.create_composite_buffer(
+                t!("buffer.edit_preview_name", name = &name).to_string(),
+                "diff-view".to_string(),
+                CompositeLayout::SideBySide {
+                    ratios: vec![0.5, 0.5],
+                    show_separator: true,
+                },
+                vec![
+                    SourcePane::new(
+                        buffer_id,
+                        t!("buffer.edit_preview_before").to_string(),
+                        false,
+                    ),
+                    SourcePane::new(
+                        after_buffer,
+                        t!("buffer.edit_preview_after").to_string(),
+                        false,
+                    ),
+                ],
+            );
+            let alignment = LineAlignment::from_hunks(
+                &hunks,
+                before_text.split('\n').count(),
+                after_text.split('\n').count(),
+            );
+            self.set_composite_alignment(composite, alignment);
+            preview_buffers.push(composite);
+            preview_buffers.push(after_buffer);
+            first_preview.get_or_insert(composite);
+        }
+
+        let Some(first_preview) = first_preview else {
+            self.close_files_opened_for_preview(&opened_buffers);
+            return Ok(());
+        };
+        let file_count = preview_buffers.len() / 2;
+        self.set_active_buffer_internal(first_preview);
+        self.pending_workspace_edit = Some(PendingWorkspaceEdit {
+            edit,
+            preview_buffers,
+            return_to,
+            opened_buffers,
+        });
+
+        let apply_key = t!("prompt.key.apply").to_string();
+        let cancel_key = t!("prompt.key.cancel").to_string();
+        self.start_prompt(
+            t!(
+                "prompt.workspace_edit_confirm",
+                count = file_count,
+                apply_key = apply_key,
+                cancel_key = cancel_key
+            )
+            .to_string(),
+            PromptType::ConfirmWorkspaceEdit,
+        );
+        Ok(())
+    }
+
+    /// Close the diff views and apply the previewed workspace edit
+    pub(crate) fn apply_previewed_workspace_edit(&mut self) {
+        let Some(pending) = self.close_workspace_edit_preview() else {
+            return;
+        };
+        match self.apply_workspace_edit(pending.edit) {
+            Ok(total_changes) => {
+                self.set_status_message(t!("lsp.renamed", count = total_changes).to_string());
+            }
+            Err(e) => {
+                self.set_status_message(t!("lsp.rename_failed", error = e.to_string()).to_string());
+            }
+        }
+    }
+
+    /// Close the diff views, and the files they opened, without applying the
+    /// previewed workspace edit
+    pub(crate) fn discard_workspace_edit_preview(&mut self) {
+        if let Some(pending) = self.close_workspace_edit_preview() {
+            self.close_files_opened_for_preview(&pending.opened_buffers);
+            self.set_status_message(t!("lsp.workspace_edit_discarded").to_string());
+        }
+    }
+
+    /// Close the files a preview opened, unless they were edited since
+    fn close_files_opened_for_preview(&mut self, opened_buffers: &[BufferId]) {
+        for &id in opened_buffers {
+            let unmodified = self
+                .buffers
+                .get(&id)
+                .is_some_and(|state| !state.buffer.is_modified());
+            if unmodified {
+                if let Err(e) = self.close_buffer(id) {
+                    tracing::debug!("Failed to close file opened for preview {:?}: {}", id, e);
+                }
+            }
+        }
+    }
+
+    fn close_workspace_edit_preview(&mut self) -> Option<PendingWorkspaceEdit> {
+        let pending = self.pending_workspace_edit.take()?;
+        if self.buffers.contains_key(&pending.return_to) {
+            self.set_active_buffer_internal(pending.return_to);
+        }
+        for &id in &pending.preview_buffers {
+            if let Err(e) = self.force_close_buffer(id) {
+                tracing::debug!("Failed to close workspace edit preview {:?}: {}", id, e);
+            }
+            if self.composite_buffers.contains_key(&id) {
+                self.close_composite_buffer(id);
+            }
+        }
+        Some(pending)
+    }
+}
@@ -15,6 +15,7 @@ use std::io;
 use std::sync::atomic::AtomicBool;
 #[cfg(unix)]
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::server::ipc::{ClientConnection, SocketPaths};
 use crate::server::protocol::{
@@ -22,7 +23,7 @@ use crate::server::protocol::{
 };
 
 #[cfg(unix)]
-mod relay_unix;
+pub(crate) mod relay_unix;
 
 /// Client configuration
 pub struct ClientConfig {
@@ -30,6 +31,9 @@ pub struct ClientConfig {
     pub socket_paths: SocketPaths,
     /// Initial terminal size
     pub term_size: TermSize,
+    /// Detach after this long without keyboard input. The server keeps
+    /// running. Unix only; ignored on Windows.
+    pub idle_detach: Option<Duration>,
 }
 
 /// Reason the client exited
@@ -107,15 +111,17 @@ pub fn run_client_with_connection(
         }
     }
 
-    run_client_relay(conn)
+    run_client_relay(conn, config.idle_detach)
 }
 
 /// Run the relay loop with an already-handshaked connection
 ///
 /// Use this when handshake has already been performed externally.
-/// Caller must have already enabled raw mode.
+/// Caller must have already enabled raw mode. With `idle_detach` set, the
+/// client detaches once stdin has been quiet for that long (Unix only).
 pub fn run_client_relay(
     #[allow(unused_mut)] mut conn: ClientConnection,
+    #[cfg_attr(windows, allow(unused_variables))] idle_detach: Option<Duration>,
 ) -> io::Result<ClientExitReason> {
     // Set up for relay
     // On Windows, don't set nonblocking here - the relay loop uses try_read() which handles this
@@ -128,7 +134,7 @@ pub fn run_client_relay(
     {
         let resize_flag = Arc::new(AtomicBool::new(false));
         relay_unix::setup_resize_handler(resize_flag.clone())?;
-        relay_unix::relay_loop(&mut conn, resize_flag)
+        relay_unix::relay_loop(&mut conn, resize_flag, idle_detach)
     }
 
     #[cfg(windows)]
@@ -193,6 +199,7 @@ impl fresh_winterm::RelayConnection for ClientConnection {
         if let Ok(ctrl) = serde_json::from_str::<ServerControl>(msg) {
             match ctrl {
                 ServerControl::Quit { .. } => Some(fresh_winterm::RelayExitReason::ServerQuit),
+                ServerControl::Detached => Some(fresh_winterm::RelayExitReason::Detached),
                 ServerControl::SetClipboard {
                     text,
                     use_osc52,
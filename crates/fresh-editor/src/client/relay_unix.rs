@@ -4,6 +4,7 @@ use std::io::{self, Read, Write};
 use std::os::unix::io::{AsRawFd, BorrowedFd};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use nix::poll::{poll, PollFd, PollFlags};
 
@@ -12,20 +13,48 @@ use crate::server::ipc::ClientConnection;
 use crate::server::protocol::ClientControl;
 
 /// Main relay loop - bidirectional byte forwarding using poll()
+///
+/// With `idle_detach` set, the client detaches once stdin has been quiet
+/// for that long; any input byte restarts the timer.
 pub fn relay_loop(
     conn: &mut ClientConnection,
     resize_flag: Arc<AtomicBool>,
+    idle_detach: Option<Duration>,
 ) -> io::Result<ClientExitReason> {
-    let mut stdin = io::stdin();
-    let mut stdout = io::stdout();
+    relay_loop_with_io(
+        conn,
+        &mut io::stdin(),
+        &mut io::stdout(),
+        resize_flag,
+        idle_detach,
+    )
+}
 
+/// Relay loop over arbitrary input/output, so tests can drive it without
+/// a terminal.
+pub(crate) fn relay_loop_with_io(
+    conn: &mut ClientConnection,
+    stdin: &mut (impl Read + AsRawFd),
+    stdout: &mut impl Write,
+    resize_flag: Arc<AtomicBool>,
+    idle_detach: Option<Duration>,
+) -> io::Result<ClientExitReason> {
     let mut stdin_buf = [0u8; 4096];
     let mut data_buf = [0u8; 4096];
 
     let stdin_fd = stdin.as_raw_fd();
     let (data_fd, ctrl_fd) = conn.as_raw_fds();
+    let mut last_input = Instant::now();
 
     loop {
+        // Detach after the idle timeout; the server keeps running
+        if idle_detach.is_some_and(|timeout| last_input.elapsed() >= timeout) {
+            tracing::debug!("No input for {:?}, detaching", idle_detach);
+            let detach_msg = serde_json::to_string(&ClientControl::Detach).unwrap();
+            conn.write_control(&detach_msg)?;
+            return Ok(ClientExitReason::Detached);
+        }
+
         // Check for resize
         if resize_flag.swap(false, Ordering::SeqCst) {
             if let Ok(size) = get_terminal_size() {
@@ -71,6 +100,7 @@ pub fn relay_loop(
                     return Ok(ClientExitReason::Detached);
                 }
                 Ok(n) => {
+                    last_input = Instant::now();
                     conn.write_data(&stdin_buf[..n])?;
                 }
                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
@@ -87,7 +117,7 @@ pub fn relay_loop(
             match conn.read_data(&mut data_buf) {
                 Ok(0) => {
                     // Server closed connection
-                    return Ok(ClientExitReason::ServerQuit);
+                    return Ok(exit_reason_after_close(conn));
                 }
                 Ok(n) => {
                     stdout.write_all(&data_buf[..n])?;
@@ -113,6 +143,10 @@ pub fn relay_loop(
                             tracing::debug!("Server sent quit: {}", reason);
                             return Ok(ClientExitReason::ServerQuit);
                         }
+                        crate::server::protocol::ServerControl::Detached => {
+                            tracing::debug!("Server detached this client");
+                            return Ok(ClientExitReason::Detached);
+                        }
                         crate::server::protocol::ServerControl::SetClipboard {
                             text,
                             use_osc52,
@@ -121,7 +155,7 @@ pub fn relay_loop(
                             super::set_client_clipboard(&text, use_osc52, use_system_clipboard);
                         }
                         crate::server::protocol::ServerControl::SuspendClient => {
-                            suspend_client(&mut stdout, conn)?;
+                            suspend_client(stdout, conn)?;
                         }
                         crate::server::protocol::ServerControl::Pong => {
                             // Ignore pong responses
@@ -140,9 +174,29 @@ pub fn relay_loop(
             .map(|r| r.contains(PollFlags::POLLHUP) || r.contains(PollFlags::POLLERR))
             .unwrap_or(false)
         {
-            return Ok(ClientExitReason::ServerQuit);
+            return Ok(exit_reason_after_close(conn));
+        }
+    }
+}
+
+/// Why the client is exiting once the server closed the data socket.
+///
+/// When the server detaches a client it sends `ServerControl::Detached`
+/// just before closing, which may still be unread on the control socket.
+fn exit_reason_after_close(conn: &ClientConnection) -> ClientExitReason {
+    let (_, ctrl_fd) = conn.as_raw_fds();
+    // SAFETY: The file descriptor is valid for the duration of this scope
+    let ctrl_borrowed = unsafe { BorrowedFd::borrow_raw(ctrl_fd) };
+    let mut fds = [PollFd::new(ctrl_borrowed, PollFlags::POLLIN)];
+    while matches!(poll(&mut fds, nix::poll::PollTimeout::from(100u8)), Ok(n) if n > 0) {
+        let Ok(Some(msg)) = conn.read_control() else {
+            break;
+        };
+        if let Ok(crate::server::protocol::ServerControl::Detached) = serde_json::from_str(&msg) {
+            return ClientExitReason::Detached;
         }
     }
+    ClientExitReason::ServerQuit
 }
 
 /// Suspend the client with SIGTSTP and restore its terminal on resume.
@@ -152,7 +206,7 @@ pub fn relay_loop(
 /// teardown bytes the server would have sent had the client detached, drop
 /// raw mode, raise SIGTSTP, and on resume re-enable raw mode and nudge the
 /// server to repaint by echoing the current terminal size back.
-fn suspend_client(stdout: &mut io::Stdout, conn: &mut ClientConnection) -> io::Result<()> {
+fn suspend_client(stdout: &mut impl Write, conn: &mut ClientConnection) -> io::Result<()> {
     use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
     use nix::sys::signal::{raise, Signal};
 
@@ -226,3 +280,49 @@ pub fn setup_resize_handler(flag: Arc<AtomicBool>) -> io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::ipc::{ServerListener, SocketPaths};
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+
+    #[test]
+    fn test_relay_detaches_after_idle_timeout() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let paths = SocketPaths::for_session_name_in_dir("idle", temp_dir.path());
+        let mut listener = ServerListener::bind(paths.clone()).unwrap();
+
+        let mut conn = ClientConnection::connect(&paths).unwrap();
+        let server_conn = loop {
+            match listener.accept().unwrap() {
+                Some(server_conn) => break server_conn,
+                None => thread::yield_now(),
+            }
+        };
+        conn.set_data_nonblocking(true).unwrap();
+
+        // Keep the writing end open so stdin stays quiet without hitting EOF
+        let (mut input, _input_writer) = UnixStream::pair().unwrap();
+        let mut output = Vec::new();
+        let started = Instant::now();
+        let reason = relay_loop_with_io(
+            &mut conn,
+            &mut input,
+            &mut output,
+            Arc::new(AtomicBool::new(false)),
+            Some(Duration::from_millis(200)),
+        )
+        .unwrap();
+
+        assert!(matches!(reason, ClientExitReason::Detached));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+
+        let msg = server_conn.read_control().unwrap().unwrap();
+        assert!(matches!(
+            serde_json::from_str::<ClientControl>(&msg).unwrap(),
+            ClientControl::Detach
+        ));
+    }
+}
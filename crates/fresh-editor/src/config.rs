@@ -400,6 +400,18 @@ pub struct Config {
     #[serde(default)]
     pub universal_lsp: HashMap<String, LspLanguageConfig>,
 
+    /// Build/run tasks by name, started with the "Run Task" command.
+    /// Output streams into a panel where `path:line:col` locations can be
+    /// clicked to jump to them.
+    #[serde(default)]
+    pub tasks: HashMap<String, TaskConfig>,
+
+    /// User snippets by language, each mapping a trigger word to a body in
+    /// LSP snippet syntax (`${1:name}`, `$0`). Typing the trigger and
+    /// pressing Tab expands it.
+    #[serde(default)]
+    pub snippets: HashMap<String, HashMap<String, String>>,
+
     /// Warning notification settings
     #[serde(default)]
     pub warnings: WarningsConfig,
@@ -442,6 +454,7 @@ pub struct WhitespaceVisibility {
     pub tabs_leading: bool,
     pub tabs_inner: bool,
     pub tabs_trailing: bool,
+    pub eol: bool,
 }
 
 impl Default for WhitespaceVisibility {
@@ -454,6 +467,7 @@ impl Default for WhitespaceVisibility {
             tabs_leading: true,
             tabs_inner: true,
             tabs_trailing: true,
+            eol: false,
         }
     }
 }
@@ -469,6 +483,7 @@ impl WhitespaceVisibility {
                 tabs_leading: false,
                 tabs_inner: false,
                 tabs_trailing: false,
+                eol: false,
             };
         }
         Self {
@@ -478,6 +493,7 @@ impl WhitespaceVisibility {
             tabs_leading: editor.whitespace_tabs_leading,
             tabs_inner: editor.whitespace_tabs_inner,
             tabs_trailing: editor.whitespace_tabs_trailing,
+            eol: editor.whitespace_eol,
         }
     }
 
@@ -502,9 +518,9 @@ impl WhitespaceVisibility {
         self.tabs_leading || self.tabs_inner || self.tabs_trailing
     }
 
-    /// Returns true if any indicator (space or tab) is enabled
+    /// Returns true if any indicator (space, tab or end-of-line) is enabled
     pub fn any_visible(&self) -> bool {
-        self.any_spaces() || self.any_tabs()
+        self.any_spaces() || self.any_tabs() || self.eol
     }
 
     /// Toggle all whitespace indicators on/off (master switch).
@@ -519,6 +535,7 @@ impl WhitespaceVisibility {
                 tabs_leading: false,
                 tabs_inner: false,
                 tabs_trailing: false,
+                eol: false,
             };
         } else {
             *self = Self::default();
@@ -530,10 +547,13 @@ impl WhitespaceVisibility {
 ///
 /// Elements are specified as strings in the config:
 /// - `"{filename}"` — file path with session/remote prefix, modified and read-only indicators
-/// - `"{cursor}"` — cursor position as `Ln 1, Col 1`
+/// - `"{cursor}"` — cursor position as `Ln 1, Col 1` (Col is the screen column, with tabs expanded)
 /// - `"{cursor:compact}"` — cursor position as `1:1`
+/// - `"{cursor:byte}"` — cursor byte offset in the file as `Byte 0`
+/// - `"{cursor:percent}"` — how far through the file the cursor is, as `42%`
 /// - `"{diagnostics}"` — error/warning/info counts (e.g. `E:1 W:2`)
 /// - `"{cursor_count}"` — number of active cursors (hidden when only 1)
+/// - `"{selection}"` — characters, lines and words selected (hidden when nothing is selected)
 /// - `"{messages}"` — editor and plugin status messages
 /// - `"{chord}"` — in-progress chord key sequence
 /// - `"{line_ending}"` — line ending format (LF, CRLF, Auto)
@@ -554,10 +574,16 @@ pub enum StatusBarElement {
     Cursor,
     /// Cursor position (compact format: `1:1`)
     CursorCompact,
+    /// Cursor byte offset in the file (`Byte 0`)
+    CursorByte,
+    /// Cursor position as a percentage of the file size (`42%`)
+    CursorPercent,
     /// Diagnostic counts (errors, warnings, info)
     Diagnostics,
     /// Active cursor count (hidden when 1)
     CursorCount,
+    /// Character, line and word counts of the selection (hidden without one)
+    Selection,
     /// Status messages from editor and plugins
     Messages,
     /// In-progress chord key sequence
@@ -597,8 +623,11 @@ impl TryFrom<String> for StatusBarElement {
             "filename" => Ok(Self::Filename),
             "cursor" => Ok(Self::Cursor),
             "cursor:compact" => Ok(Self::CursorCompact),
+            "cursor:byte" => Ok(Self::CursorByte),
+            "cursor:percent" => Ok(Self::CursorPercent),
             "diagnostics" => Ok(Self::Diagnostics),
             "cursor_count" => Ok(Self::CursorCount),
+            "selection" => Ok(Self::Selection),
             "messages" => Ok(Self::Messages),
             "chord" => Ok(Self::Chord),
             "line_ending" => Ok(Self::LineEnding),
@@ -621,8 +650,11 @@ impl From<StatusBarElement> for String {
             StatusBarElement::Filename => "{filename}".to_string(),
             StatusBarElement::Cursor => "{cursor}".to_string(),
             StatusBarElement::CursorCompact => "{cursor:compact}".to_string(),
+            StatusBarElement::CursorByte => "{cursor:byte}".to_string(),
+            StatusBarElement::CursorPercent => "{cursor:percent}".to_string(),
             StatusBarElement::Diagnostics => "{diagnostics}".to_string(),
             StatusBarElement::CursorCount => "{cursor_count}".to_string(),
+            StatusBarElement::Selection => "{selection}".to_string(),
             StatusBarElement::Messages => "{messages}".to_string(),
             StatusBarElement::Chord => "{chord}".to_string(),
             StatusBarElement::LineEnding => "{line_ending}".to_string(),
@@ -649,8 +681,11 @@ impl schemars::JsonSchema for StatusBarElement {
                 {"value": "{filename}", "name": "Filename"},
                 {"value": "{cursor}", "name": "Cursor"},
                 {"value": "{cursor:compact}", "name": "Cursor (compact)"},
+                {"value": "{cursor:byte}", "name": "Cursor Byte Offset"},
+                {"value": "{cursor:percent}", "name": "Cursor Percent"},
                 {"value": "{diagnostics}", "name": "Diagnostics"},
                 {"value": "{cursor_count}", "name": "Cursor Count"},
+                {"value": "{selection}", "name": "Selection Counts"},
                 {"value": "{messages}", "name": "Messages"},
                 {"value": "{chord}", "name": "Chord"},
                 {"value": "{line_ending}", "name": "Line Ending"},
@@ -685,6 +720,7 @@ fn default_status_bar_left() -> Vec<StatusBarElement> {
         StatusBarElement::Cursor,
         StatusBarElement::Diagnostics,
         StatusBarElement::CursorCount,
+        StatusBarElement::Selection,
         StatusBarElement::Messages,
     ]
 }
@@ -718,7 +754,7 @@ fn default_status_bar_right() -> Vec<StatusBarElement> {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StatusBarConfig {
     /// Elements shown on the left side of the status bar.
-    /// Default: ["{filename}", "{cursor}", "{diagnostics}", "{cursor_count}", "{messages}"]
+    /// Default: ["{filename}", "{cursor}", "{diagnostics}", "{cursor_count}", "{selection}", "{messages}"]
     #[serde(default = "default_status_bar_left")]
     #[schemars(extend("x-section" = "Status Bar", "x-dual-list-sibling" = "/editor/status_bar/right"))]
     pub left: Vec<StatusBarElement>,
@@ -757,11 +793,30 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub line_numbers: bool,
 
-    /// Show line numbers relative to cursor position
+    /// Show line numbers relative to cursor position, with the cursor line
+    /// keeping its absolute number. Selects the `hybrid` line number mode
+    /// when `line_number_mode` is left at `absolute`.
     #[serde(default = "default_false")]
     #[schemars(extend("x-section" = "Display"))]
     pub relative_line_numbers: bool,
 
+    /// How the gutter numbers lines: `absolute`, `relative` (distance from
+    /// the cursor line, which shows 0) or `hybrid` (relative, but the cursor
+    /// line shows its absolute number).
+    /// Default: absolute
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub line_number_mode: LineNumberMode,
+
+    /// Columns of the gutter, left to right: `line_number`, `git_sign`
+    /// (git changes and other line markers), `diagnostic_sign`, `fold` and
+    /// `breakpoint`. Leave a column out to hide it. Sign columns are one
+    /// cell wide. If not specified (`null`), the gutter shows one indicator
+    /// cell holding the most important sign, then the line numbers.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub gutter_columns: Option<Vec<GutterColumn>>,
+
     /// Highlight the line containing the cursor
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Display"))]
@@ -865,6 +920,38 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub show_horizontal_scrollbar: bool,
 
+    /// Whether a minimap (a downscaled overview of the whole buffer) is shown
+    /// next to the vertical scrollbar. Clicking the minimap jumps to that part
+    /// of the file. Not drawn for files above the large file threshold.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub show_minimap: bool,
+
+    /// Show git change signs in the gutter: `+` for added lines, `~` for
+    /// modified lines and `-` next to deleted lines. The buffer is diffed
+    /// against the file's version in git HEAD and the signs follow unsaved
+    /// edits. Not computed for files above the large file threshold.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub show_git_gutter: bool,
+
+    /// Show vertical indent guides in leading whitespace, one per
+    /// indentation level (`tab_size` columns). The guide of the block
+    /// containing the cursor is highlighted.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub show_indent_guides: bool,
+
+    /// Underline `http://` and `https://` URLs. "Open URL Under Cursor"
+    /// opens them in the browser either way.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub underline_urls: bool,
+
     /// Show tilde (~) markers on lines after the end of the file.
     /// These vim-style markers indicate lines that are not part of the file content.
     /// Default: true
@@ -955,6 +1042,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Whitespace"))]
     pub whitespace_tabs_trailing: bool,
 
+    /// Show an end-of-line indicator (¶) after the last character of each line.
+    /// The marker is drawn in the newline's cell, so byte offsets and the
+    /// cursor column are unaffected.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Whitespace"))]
+    pub whitespace_eol: bool,
+
     // ===== Editing =====
     /// Whether pressing Tab inserts a tab character instead of spaces.
     /// This is the global default; individual languages can override it
@@ -992,6 +1087,21 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub auto_surround: bool,
 
+    /// Make Ctrl+Left/Right and word deletion also stop at camelCase and
+    /// snake_case boundaries inside identifiers (`parse|HTTP|Server`,
+    /// `snake_|case`).
+    /// Default: false
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub subword_movement: bool,
+
+    /// Underline misspelled words in prose files (Markdown, plain text)
+    /// and in code comments. Can be toggled per buffer.
+    /// Default: false
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub spell_check: bool,
+
     /// Minimum lines to keep visible above/below cursor when scrolling
     #[serde(default = "default_scroll_offset")]
     #[schemars(extend("x-section" = "Editing"))]
@@ -1011,12 +1121,20 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub trim_trailing_whitespace_on_save: bool,
 
-    /// Ensure files end with a newline when saving.
+    /// Ensure files end with a newline when saving. Also accepted as
+    /// `insert_final_newline`.
     /// Default: false
-    #[serde(default = "default_false")]
+    #[serde(default = "default_false", alias = "insert_final_newline")]
     #[schemars(extend("x-section" = "Editing"))]
     pub ensure_final_newline_on_save: bool,
 
+    /// Remove blank lines at the end of the file when saving. A final
+    /// newline is kept if the file had one, but never added.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub trim_trailing_blank_lines_on_save: bool,
+
     // ===== Bracket Matching =====
     /// Highlight matching bracket pairs when cursor is on a bracket.
     /// Default: true
@@ -1075,10 +1193,19 @@ pub struct EditorConfig {
     /// Whether to request full-document LSP semantic tokens.
     /// Range requests are still used when supported.
     /// Default: false (range-only to avoid heavy full refreshes).
+    /// Buffers without a built-in syntax highlighter always request them,
+    /// so the language server provides their highlighting.
     #[serde(default = "default_false")]
     #[schemars(extend("x-section" = "LSP"))]
     pub enable_semantic_tokens_full: bool,
 
+    /// Show multi-file LSP edits (such as rename) as side-by-side diffs
+    /// and apply them only after confirmation.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "LSP"))]
+    pub preview_workspace_edits: bool,
+
     /// Whether to show inline diagnostic text at the end of lines with errors/warnings.
     /// When enabled, the highest-severity diagnostic message is rendered after the
     /// source code on each affected line.
@@ -1087,6 +1214,28 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Diagnostics"))]
     pub diagnostics_inline_text: bool,
 
+    /// Severities listed in the diagnostics panel and visited by next/previous
+    /// diagnostic: any of "error", "warning", "info" and "hint". The panel's
+    /// `e`, `w`, `i` and `h` keys toggle them.
+    /// Default: all four
+    #[serde(default = "default_diagnostics_panel_severities")]
+    #[schemars(extend("x-section" = "Diagnostics"))]
+    pub diagnostics_panel_severities: Vec<DiagnosticsPanelSeverity>,
+
+    /// Order of the diagnostics panel entries: by location (file, then line)
+    /// or by severity (errors first). The panel's `s` key switches it.
+    /// Default: location
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Diagnostics"))]
+    pub diagnostics_panel_sort: DiagnosticsPanelSort,
+
+    /// Soft-wrap long diagnostic messages to the diagnostics panel's width
+    /// instead of cutting them off. The panel's `l` key switches it.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Diagnostics"))]
+    pub diagnostics_panel_wrap: bool,
+
     // ===== Mouse =====
     /// Whether mouse hover triggers LSP hover requests.
     /// When enabled, hovering over code with the mouse will show documentation.
@@ -1115,9 +1264,20 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Mouse"))]
     pub double_click_time_ms: u64,
 
+    /// When to save modified buffers to their original files on disk: never
+    /// (`off`), whenever focus leaves a buffer (`on_focus_change`: switching
+    /// to another buffer or split, the terminal window losing focus, or
+    /// detaching from a session), or every `auto_save_interval_secs`
+    /// (`after_delay`). Buffers without a file are never auto-saved.
+    /// Default: off
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub auto_save: AutoSaveMode,
+
     /// Whether to enable persistent auto-save (save to original file on disk).
     /// When enabled, modified buffers are saved to their original file path
-    /// at a configurable interval.
+    /// at a configurable interval. Same as `auto_save: after_delay`; ignored
+    /// when `auto_save` is set.
     /// Default: false
     #[serde(default = "default_false")]
     #[schemars(extend("x-section" = "Recovery"))]
@@ -1125,7 +1285,7 @@ pub struct EditorConfig {
 
     /// Interval in seconds for persistent auto-save.
     /// Modified buffers are saved to their original file at this interval.
-    /// Only effective when auto_save_enabled is true.
+    /// Only effective when auto-saving after a delay.
     /// Default: 30 seconds
     #[serde(default = "default_auto_save_interval")]
     #[schemars(extend("x-section" = "Recovery"))]
@@ -1141,6 +1301,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Recovery"))]
     pub hot_exit: bool,
 
+    /// What closing the editor (Quit) does when attached to a session: ask
+    /// whether to detach (the session keeps running and can be reattached) or
+    /// quit (the session ends), or always do one of them without asking.
+    /// Default: ask
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub session_close_action: SessionCloseAction,
+
     /// Whether to auto-open previously opened files (session restore) when
     /// starting Fresh in a directory.  When enabled (the default), tabs,
     /// splits, cursor positions and the file explorer state are restored
@@ -1180,6 +1348,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Startup"))]
     pub auto_create_empty_buffer_on_last_buffer_close: bool,
 
+    /// Seconds a session server (`fresh -a`) keeps running after its last
+    /// client detaches. It then shuts down, unless a buffer has unsaved
+    /// changes. Set to 0 to keep servers running until killed.
+    /// Default: 3600 (1 hour)
+    #[serde(default = "default_session_idle_shutdown")]
+    #[schemars(extend("x-section" = "Startup"))]
+    pub session_idle_shutdown_secs: u64,
+
     // ===== Recovery =====
     /// Whether to enable file recovery (Emacs-style auto-save)
     /// When enabled, buffers are periodically saved to recovery files
@@ -1274,6 +1450,23 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Performance"))]
     pub estimated_line_length: usize,
 
+    /// Maximum number of 1MB chunks of a large file kept in memory.
+    /// When more are loaded, the least recently used one is dropped and
+    /// re-read from disk when needed again. Chunks holding unsaved edits
+    /// are never dropped. 0 means no limit.
+    /// Default: 64
+    #[serde(default = "default_large_file_max_resident_chunks")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub large_file_max_resident_chunks: usize,
+
+    /// Large files bigger than this many bytes are saved on a background
+    /// thread, with progress shown in the status bar. Edits to the file
+    /// are blocked until the write finishes. 0 disables background saves.
+    /// Default: 100MB (104857600 bytes)
+    #[serde(default = "default_background_save_threshold")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub background_save_threshold_bytes: u64,
+
     /// Maximum number of concurrent filesystem read requests.
     /// Used during line-feed scanning and other bulk I/O operations.
     /// Higher values improve throughput, especially for remote filesystems.
@@ -1284,11 +1477,21 @@ pub struct EditorConfig {
 
     /// Poll interval in milliseconds for refreshing expanded directories in the file explorer.
     /// Directory modification times are checked at this interval to detect new/deleted files.
+    /// The same interval applies to telling language servers about files created or deleted
+    /// in the project.
     /// Lower values detect changes faster but use more CPU.
     /// Default: 3000ms (3 seconds)
     #[serde(default = "default_file_tree_poll_interval")]
     #[schemars(extend("x-section" = "Performance"))]
     pub file_tree_poll_interval_ms: u64,
+
+    /// Maximum frames per second a session server (`fresh -a`) sends to its
+    /// clients. Changes made within one frame (a paste, a held key) are
+    /// drawn once. 0 is treated as 1.
+    /// Default: 60
+    #[serde(default = "default_session_max_fps")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub session_max_fps: u32,
 }
 
 fn default_tab_size() -> usize {
@@ -1324,6 +1527,15 @@ fn default_true() -> bool {
     true
 }
 
+fn default_diagnostics_panel_severities() -> Vec<DiagnosticsPanelSeverity> {
+    vec![
+        DiagnosticsPanelSeverity::Error,
+        DiagnosticsPanelSeverity::Warning,
+        DiagnosticsPanelSeverity::Info,
+        DiagnosticsPanelSeverity::Hint,
+    ]
+}
+
 fn default_false() -> bool {
     false
 }
@@ -1348,6 +1560,14 @@ fn default_estimated_line_length() -> usize {
     80
 }
 
+fn default_large_file_max_resident_chunks() -> usize {
+    crate::model::buffer::DEFAULT_MAX_RESIDENT_CHUNKS
+}
+
+fn default_background_save_threshold() -> u64 {
+    100 * 1024 * 1024
+}
+
 fn default_auto_save_interval() -> u32 {
     30 // 30 seconds between persistent auto-saves
 }
@@ -1372,6 +1592,14 @@ fn default_double_click_time() -> u64 {
     500 // 500ms window for detecting double-clicks
 }
 
+fn default_session_max_fps() -> u32 {
+    60
+}
+
+fn default_session_idle_shutdown() -> u64 {
+    3600
+}
+
 fn default_auto_revert_poll_interval() -> u64 {
     2000 // 2 seconds between file mtime checks
 }
@@ -1380,6 +1608,28 @@ fn default_file_tree_poll_interval() -> u64 {
     3000 // 3 seconds between directory mtime checks
 }
 
+impl EditorConfig {
+    /// The line number mode in effect, taking the legacy
+    /// `relative_line_numbers` switch into account.
+    pub fn effective_line_number_mode(&self) -> LineNumberMode {
+        if self.relative_line_numbers && self.line_number_mode == LineNumberMode::Absolute {
+            LineNumberMode::Hybrid
+        } else {
+            self.line_number_mode
+        }
+    }
+
+    /// The auto-save mode in effect, taking the legacy `auto_save_enabled`
+    /// switch into account.
+    pub fn auto_save_mode(&self) -> AutoSaveMode {
+        if self.auto_save_enabled && self.auto_save == AutoSaveMode::Off {
+            AutoSaveMode::AfterDelay
+        } else {
+            self.auto_save
+        }
+    }
+}
+
 impl Default for EditorConfig {
     fn default() -> Self {
         Self {
@@ -1388,9 +1638,13 @@ impl Default for EditorConfig {
             auto_indent: true,
             auto_close: true,
             auto_surround: true,
+            subword_movement: false,
+            spell_check: false,
             animations: true,
             line_numbers: true,
             relative_line_numbers: false,
+            line_number_mode: LineNumberMode::default(),
+            gutter_columns: None,
             scroll_offset: default_scroll_offset(),
             syntax_highlighting: true,
             highlight_current_line: true,
@@ -1403,12 +1657,20 @@ impl Default for EditorConfig {
             snapshot_interval: default_snapshot_interval(),
             large_file_threshold_bytes: default_large_file_threshold(),
             estimated_line_length: default_estimated_line_length(),
+            large_file_max_resident_chunks: default_large_file_max_resident_chunks(),
+            background_save_threshold_bytes: default_background_save_threshold(),
             enable_inlay_hints: true,
             enable_semantic_tokens_full: false,
+            preview_workspace_edits: false,
             diagnostics_inline_text: false,
+            diagnostics_panel_severities: default_diagnostics_panel_severities(),
+            diagnostics_panel_sort: DiagnosticsPanelSort::default(),
+            diagnostics_panel_wrap: true,
+            auto_save: AutoSaveMode::default(),
             auto_save_enabled: false,
             auto_save_interval_secs: default_auto_save_interval(),
             hot_exit: true,
+            session_close_action: SessionCloseAction::default(),
             restore_previous_session: true,
             skip_session_restore_when_files_passed: true,
             auto_create_empty_buffer_on_last_buffer_close: true,
@@ -1418,12 +1680,15 @@ impl Default for EditorConfig {
             mouse_hover_enabled: default_mouse_hover_enabled(),
             mouse_hover_delay_ms: default_mouse_hover_delay(),
             double_click_time_ms: default_double_click_time(),
+            session_idle_shutdown_secs: default_session_idle_shutdown(),
+            session_max_fps: default_session_max_fps(),
             auto_revert_poll_interval_ms: default_auto_revert_poll_interval(),
             read_concurrency: default_read_concurrency(),
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
             default_line_ending: LineEndingOption::default(),
             trim_trailing_whitespace_on_save: false,
             ensure_final_newline_on_save: false,
+            trim_trailing_blank_lines_on_save: false,
             highlight_matching_brackets: true,
             rainbow_brackets: true,
             cursor_style: CursorStyle::default(),
@@ -1443,6 +1708,10 @@ impl Default for EditorConfig {
             show_prompt_line: false,
             show_vertical_scrollbar: true,
             show_horizontal_scrollbar: false,
+            show_minimap: false,
+            show_git_gutter: false,
+            show_indent_guides: false,
+            underline_urls: false,
             show_tilde: true,
             use_terminal_bg: false,
             set_window_title: true,
@@ -1454,10 +1723,88 @@ impl Default for EditorConfig {
             whitespace_tabs_leading: true,
             whitespace_tabs_inner: true,
             whitespace_tabs_trailing: true,
+            whitespace_eol: false,
+        }
+    }
+}
+
+/// How line numbers are shown in the gutter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LineNumberMode {
+    #[default]
+    Absolute,
+    Relative,
+    Hybrid,
+}
+
+impl LineNumberMode {
+    /// The mode after this one when cycling: absolute → relative → hybrid.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Absolute => Self::Relative,
+            Self::Relative => Self::Hybrid,
+            Self::Hybrid => Self::Absolute,
         }
     }
 }
 
+/// A column of the gutter, see `gutter_columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GutterColumn {
+    LineNumber,
+    GitSign,
+    DiagnosticSign,
+    Fold,
+    Breakpoint,
+}
+
+/// Order of the entries in the diagnostics panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticsPanelSort {
+    #[default]
+    Location,
+    Severity,
+}
+
+/// A diagnostic severity the diagnostics panel can show or hide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticsPanelSeverity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+/// When modified buffers are saved automatically, see `auto_save`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoSaveMode {
+    /// Only save when asked to
+    #[default]
+    Off,
+    /// Save a buffer when focus leaves it
+    OnFocusChange,
+    /// Save every `auto_save_interval_secs`
+    AfterDelay,
+}
+
+/// What Quit does when attached to a session, see `session_close_action`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionCloseAction {
+    /// Ask whether to detach or quit
+    #[default]
+    Ask,
+    /// Detach this client and keep the session running
+    Detach,
+    /// End the session
+    Quit,
+}
+
 /// Side placement for the file explorer panel.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -1764,6 +2111,23 @@ pub struct ClipboardConfig {
     /// Disable this if you don't have a display server or it causes issues
     #[serde(default = "default_true")]
     pub use_system_clipboard: bool,
+
+    /// Largest OSC 52 payload (base64-encoded bytes) to send (default: 100000)
+    /// Many terminals drop or truncate longer sequences; larger copies only
+    /// reach the internal and system clipboards. 0 means no limit.
+    #[serde(default = "default_osc52_max_bytes")]
+    pub osc52_max_bytes: usize,
+
+    /// Paste by querying the terminal clipboard with OSC 52 (default: false)
+    /// Only used in session mode, where the server cannot reach the system
+    /// clipboard. The terminal must allow clipboard reads (e.g. kitty, foot,
+    /// or xterm with `allowWindowOps`); other terminals ignore the query.
+    #[serde(default)]
+    pub osc52_paste: bool,
+}
+
+fn default_osc52_max_bytes() -> usize {
+    crate::services::clipboard::DEFAULT_OSC52_MAX_BYTES
 }
 
 impl Default for ClipboardConfig {
@@ -1771,6 +2135,8 @@ impl Default for ClipboardConfig {
         Self {
             use_osc52: true,
             use_system_clipboard: true,
+            osc52_max_bytes: default_osc52_max_bytes(),
+            osc52_paste: false,
         }
     }
 }
@@ -1857,6 +2223,18 @@ impl Default for PackagesConfig {
     }
 }
 
+/// A build/run task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskConfig {
+    /// Shell command line to run (e.g. "cargo build")
+    pub command: String,
+
+    /// Working directory, relative to the project root.
+    /// Default: the project root
+    #[serde(default)]
+    pub cwd: Option<String>,
+}
+
 // Re-export PluginConfig from fresh-core for shared type usage
 pub use fresh_core::config::PluginConfig;
 
@@ -2016,6 +2394,15 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub comment_prefix: Option<String>,
 
+    /// Block comment start marker (e.g., "/*"), used to comment out a
+    /// selection that doesn't span whole lines
+    #[serde(default)]
+    pub block_comment_start: Option<String>,
+
+    /// Block comment end marker (e.g., "*/")
+    #[serde(default)]
+    pub block_comment_end: Option<String>,
+
     /// Whether to auto-indent
     #[serde(default = "default_true")]
     pub auto_indent: bool,
@@ -2080,7 +2467,7 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub formatter: Option<FormatterConfig>,
 
-    /// Whether to automatically format on save (uses the formatter above)
+    /// Whether to automatically format on save (uses the formatter above, or the language server when no formatter is set)
     #[serde(default)]
     pub format_on_save: bool,
 
@@ -2387,6 +2774,8 @@ impl Default for Config {
             default_language: None,
             lsp: Self::default_lsp_config(),
             universal_lsp: Self::default_universal_lsp_config(),
+            tasks: HashMap::new(),
+            snippets: HashMap::new(),
             warnings: WarningsConfig::default(),
             plugins: HashMap::new(), // Populated when scanning for plugins
             packages: PackagesConfig::default(),
@@ -3195,6 +3584,39 @@ impl Config {
     /// The config filename used throughout the application
     pub(crate) const FILENAME: &'static str = "config.json";
 
+    /// Settings that only take effect at startup and differ between `self`
+    /// and `other`, as dotted config paths.
+    ///
+    /// Used when reloading the config in a running session: everything else
+    /// is applied live, these are reported so the change isn't silently lost.
+    pub fn restart_required_changes(&self, other: &Config) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        let (a, b) = (&self.editor, &other.editor);
+        if self.check_for_updates != other.check_for_updates {
+            changed.push("check_for_updates");
+        }
+        if a.recovery_enabled != b.recovery_enabled {
+            changed.push("editor.recovery_enabled");
+        }
+        if a.session_idle_shutdown_secs != b.session_idle_shutdown_secs {
+            changed.push("editor.session_idle_shutdown_secs");
+        }
+        if a.keyboard_disambiguate_escape_codes != b.keyboard_disambiguate_escape_codes {
+            changed.push("editor.keyboard_disambiguate_escape_codes");
+        }
+        if a.keyboard_report_event_types != b.keyboard_report_event_types {
+            changed.push("editor.keyboard_report_event_types");
+        }
+        if a.keyboard_report_alternate_keys != b.keyboard_report_alternate_keys {
+            changed.push("editor.keyboard_report_alternate_keys");
+        }
+        if a.keyboard_report_all_keys_as_escape_codes != b.keyboard_report_all_keys_as_escape_codes
+        {
+            changed.push("editor.keyboard_report_all_keys_as_escape_codes");
+        }
+        changed
+    }
+
     /// Get the local config path (in the working directory)
     pub(crate) fn local_config_path(working_dir: &Path) -> std::path::PathBuf {
         working_dir.join(Self::FILENAME)
@@ -3293,6 +3715,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3323,6 +3747,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3353,6 +3779,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "typescript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3383,6 +3811,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3417,6 +3847,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "c".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3454,6 +3886,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "cpp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3484,6 +3918,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "C#".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3524,6 +3960,8 @@ impl Config {
                 ],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3553,6 +3991,8 @@ impl Config {
                 ],
                 grammar: "Makefile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -3578,6 +4018,8 @@ impl Config {
                 filenames: vec!["Dockerfile".to_string(), "Containerfile".to_string()],
                 grammar: "dockerfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3603,6 +4045,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "json".to_string(),
                 comment_prefix: None,
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3658,6 +4102,8 @@ impl Config {
                 ],
                 grammar: "jsonc".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3688,6 +4134,8 @@ impl Config {
                 filenames: vec!["Cargo.lock".to_string()],
                 grammar: "toml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3713,6 +4161,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "yaml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3743,6 +4193,8 @@ impl Config {
                 filenames: vec!["README".to_string()],
                 grammar: "markdown".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -3769,6 +4221,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3799,6 +4253,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "odin".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3824,6 +4280,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "zig".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3849,6 +4307,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "java".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3881,6 +4341,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "latex".to_string(),
                 comment_prefix: Some("%".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3906,6 +4368,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(), // Templ uses Go-like syntax
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -3932,6 +4396,8 @@ impl Config {
                 filenames: vec!["git-rebase-todo".to_string()],
                 grammar: "Git Rebase Todo".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -3962,6 +4428,8 @@ impl Config {
                 ],
                 grammar: "Git Commit Message".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -3992,6 +4460,8 @@ impl Config {
                 ],
                 grammar: "Gitignore".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -4017,6 +4487,8 @@ impl Config {
                 filenames: vec![".gitconfig".to_string(), ".gitmodules".to_string()],
                 grammar: "Git Config".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4042,6 +4514,8 @@ impl Config {
                 filenames: vec![".gitattributes".to_string()],
                 grammar: "Git Attributes".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -4067,6 +4541,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Typst".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4096,6 +4572,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Kotlin".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4121,6 +4599,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Swift".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4146,6 +4626,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Scala".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4171,6 +4653,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Dart".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4196,6 +4680,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Elixir".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4221,6 +4707,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Erlang".to_string(),
                 comment_prefix: Some("%".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4246,6 +4734,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Haskell".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("{-".to_string()),
+                block_comment_end: Some("-}".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4271,6 +4761,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "OCaml".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("(*".to_string()),
+                block_comment_end: Some("*)".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4301,6 +4793,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Clojure".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4326,6 +4820,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "R".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4351,6 +4847,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Julia".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("#=".to_string()),
+                block_comment_end: Some("=#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4376,6 +4874,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Perl".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4401,6 +4901,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nim".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("#[".to_string()),
+                block_comment_end: Some("]#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4426,6 +4928,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Gleam".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4456,6 +4960,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Racket".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: Some("#|".to_string()),
+                block_comment_end: Some("|#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4481,6 +4987,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "FSharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("(*".to_string()),
+                block_comment_end: Some("*)".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4506,6 +5014,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nix".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4531,6 +5041,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nushell".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4556,6 +5068,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Solidity".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4581,6 +5095,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Verilog".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4611,6 +5127,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SystemVerilog".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4636,6 +5154,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "VHDL".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4665,6 +5185,8 @@ impl Config {
                 ],
                 grammar: "Ruby".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4690,6 +5212,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "PHP".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4715,6 +5239,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Lua".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("--[[".to_string()),
+                block_comment_end: Some("]]".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4740,6 +5266,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "HTML".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4765,6 +5293,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "CSS".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4790,6 +5320,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SQL".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4815,6 +5347,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "GraphQL".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4840,6 +5374,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Protocol Buffers".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4865,6 +5401,8 @@ impl Config {
                 filenames: vec!["CMakeLists.txt".to_string()],
                 grammar: "CMake".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4890,6 +5428,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "HCL".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4915,6 +5455,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Vue".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4940,6 +5482,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Svelte".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4965,6 +5509,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Astro".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4992,6 +5538,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SCSS".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5017,6 +5565,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "LESS".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5042,6 +5592,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "PowerShell".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("<#".to_string()),
+                block_comment_end: Some("#>".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5067,6 +5619,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "KDL".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5092,6 +5646,8 @@ impl Config {
                 filenames: vec!["BUILD".to_string(), "WORKSPACE".to_string()],
                 grammar: "Starlark".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5121,6 +5677,8 @@ impl Config {
                 ],
                 grammar: "Justfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5146,6 +5704,8 @@ impl Config {
                 filenames: vec!["Earthfile".to_string()],
                 grammar: "Earthfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5171,6 +5731,8 @@ impl Config {
                 filenames: vec!["go.mod".to_string(), "go.sum".to_string()],
                 grammar: "Go Module".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5196,6 +5758,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "V".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5221,6 +5785,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "INI".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5246,6 +5812,8 @@ impl Config {
                 filenames: vec!["hyprland.conf".to_string()],
                 grammar: "Hyprlang".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6974,6 +7542,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -7268,4 +7838,23 @@ mod tests {
             "Default quicklsp should be merged from defaults"
         );
     }
+
+    #[test]
+    fn test_restart_required_changes() {
+        let old = Config::default();
+        let mut new = Config::default();
+        assert!(old.restart_required_changes(&new).is_empty());
+
+        // Live-reloadable settings are not reported
+        new.editor.tab_size = 2;
+        new.editor.rulers = vec![80];
+        assert!(old.restart_required_changes(&new).is_empty());
+
+        new.editor.recovery_enabled = !old.editor.recovery_enabled;
+        new.check_for_updates = !old.check_for_updates;
+        assert_eq!(
+            old.restart_required_changes(&new),
+            vec!["check_for_updates", "editor.recovery_enabled"]
+        );
+    }
 }
@@ -958,6 +958,11 @@ impl DirectoryContext {
         self.prompt_history_path("goto_line")
     }
 
+    /// Get the recently opened files list path
+    pub fn recent_files_path(&self) -> std::path::PathBuf {
+        self.data_dir.join("recent_files.json")
+    }
+
     /// Get the terminals root directory
     pub fn terminals_dir(&self) -> std::path::PathBuf {
         self.data_dir.join("terminals")
@@ -219,6 +219,7 @@ impl GuiApplication for EditorApp {
         if let Err(e) = self.editor.end_recovery_session() {
             tracing::warn!("Failed to end recovery session: {}", e);
         }
+        self.editor.save_recent_files();
         if self.workspace_enabled {
             if let Err(e) = self.editor.save_workspace() {
                 tracing::warn!("Failed to save workspace: {}", e);
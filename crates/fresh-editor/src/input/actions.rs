@@ -6,12 +6,12 @@ use crate::model::buffer::{Buffer, LineEnding};
 use crate::model::buffer_position::{byte_to_2d, pos_2d_to_byte};
 use crate::model::cursor::{Cursors, Position2D, SelectionMode};
 use crate::model::event::{CursorId, Event};
-use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
+use crate::primitives::display_width::{byte_offset_at_visual_column, char_width, str_width};
 use crate::primitives::highlighter::HighlightCategory;
 use crate::primitives::indent_pattern::PatternIndentCalculator;
 use crate::primitives::word_navigation::{
-    find_vi_word_end, find_word_end, find_word_end_right, find_word_start, find_word_start_left,
-    find_word_start_right,
+    find_vi_word_end, find_word_end, find_word_end_right, find_word_start, find_word_start_right,
+    word_delete_end, word_delete_start, word_motion_left, word_motion_right,
 };
 use crate::state::EditorState;
 use std::ops::Range;
@@ -47,6 +47,28 @@ fn calculate_visual_column(
     }
 }
 
+/// Visual column of `position` within its line, expanding tabs to `tab_size`
+/// stops. Used by the Tab/Backspace indentation logic, which works in tab stops
+/// rather than raw byte counts.
+pub(crate) fn tab_stop_column(
+    buffer: &mut Buffer,
+    position: usize,
+    tab_size: usize,
+    estimated_line_length: usize,
+) -> usize {
+    let tab_size = tab_size.max(1);
+    let line_start = buffer
+        .line_iterator(position, estimated_line_length)
+        .current_position();
+    let prefix = buffer.slice_bytes(line_start..position);
+    String::from_utf8_lossy(&prefix)
+        .chars()
+        .fold(0, |col, ch| match ch {
+            '\t' => col + tab_size - col % tab_size,
+            _ => col + char_width(ch),
+        })
+}
+
 /// Pattern for matching line ending characters (\r and \n)
 const LINE_ENDING_CHARS: &[char] = &['\r', '\n'];
 
@@ -142,6 +164,10 @@ fn collect_line_starts(
 
 /// Calculate how much leading whitespace to remove from a line for dedent
 ///
+/// Removes at most one indent level: leading spaces and tabs are consumed
+/// until they span `tab_size` columns, so mixed indentation like `"  \t"`
+/// is removed as a single level.
+///
 /// Returns (chars_to_remove, deleted_text) where chars_to_remove is the number
 /// of characters to delete, and deleted_text is the string being deleted.
 fn calculate_leading_whitespace_removal(
@@ -149,19 +175,35 @@ fn calculate_leading_whitespace_removal(
     line_start: usize,
     tab_size: usize,
 ) -> (usize, String) {
+    let tab_size = tab_size.max(1);
     let buffer_len = buffer.len();
     let line_bytes = buffer.slice_bytes(line_start..buffer_len.min(line_start + tab_size + 1));
 
-    if !line_bytes.is_empty() && line_bytes[0] == b'\t' {
-        (1, "\t".to_string())
-    } else {
-        let spaces_to_remove = line_bytes
-            .iter()
-            .take(tab_size)
-            .take_while(|&&b| b == b' ')
-            .count();
-        (spaces_to_remove, " ".repeat(spaces_to_remove))
+    let mut width = 0;
+    let mut chars_to_remove = 0;
+    for &b in &line_bytes {
+        if width >= tab_size {
+            break;
+        }
+        match b {
+            b' ' => width += 1,
+            b'\t' => width = (width / tab_size + 1) * tab_size,
+            _ => break,
+        }
+        chars_to_remove += 1;
+    }
+    let deleted_text = String::from_utf8_lossy(&line_bytes[..chars_to_remove]).into_owned();
+    (chars_to_remove, deleted_text)
+}
+
+/// Whether the line starting at `line_start` has no content at all
+fn is_empty_line(buffer: &Buffer, line_start: usize) -> bool {
+    let buffer_len = buffer.len();
+    if line_start >= buffer_len {
+        return true;
     }
+    let first = buffer.slice_bytes(line_start..line_start + 1);
+    matches!(first.first(), Some(b'\n') | Some(b'\r'))
 }
 
 /// Add a MoveCursor event to restore cursor position after indent/dedent
@@ -826,16 +868,51 @@ fn max_cursor_position(buffer: &Buffer) -> usize {
     buffer.len()
 }
 
-/// Transform selected text (or current word if no selection) using the given transform function.
-/// Processes cursors in reverse order to avoid position shifts.
-fn transform_case<F>(
+/// Case applied by `transform_selection_case`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseMode {
+    Upper,
+    Lower,
+    /// First letter of each word upper, the rest lower
+    Title,
+}
+
+impl CaseMode {
+    /// Transform `text` char by char. Case mappings are Unicode's default
+    /// (locale-independent) ones, so e.g. `i` always maps to `I`.
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            CaseMode::Upper => text.to_uppercase(),
+            CaseMode::Lower => text.to_lowercase(),
+            CaseMode::Title => {
+                let mut out = String::with_capacity(text.len());
+                let mut prev: Option<char> = None;
+                for ch in text.chars() {
+                    // An apostrophe inside a word ("don't") doesn't start a new one
+                    let in_word =
+                        prev.is_some_and(|p| p.is_alphanumeric() || p == '\'' || p == '\u{2019}');
+                    if in_word {
+                        out.extend(ch.to_lowercase());
+                    } else {
+                        out.extend(ch.to_uppercase());
+                    }
+                    prev = Some(ch);
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Change the case of the selected text (or the word under the cursor if
+/// there is no selection). Processes cursors in reverse order to avoid
+/// position shifts; the resulting events are applied as one undo step.
+fn transform_selection_case(
     state: &mut EditorState,
     cursors: &mut Cursors,
     events: &mut Vec<Event>,
-    transform: F,
-) where
-    F: Fn(&str) -> String,
-{
+    mode: CaseMode,
+) {
     let mut selections: Vec<_> = cursors
         .iter()
         .map(|(cursor_id, cursor)| {
@@ -854,7 +931,7 @@ fn transform_case<F>(
 
     for (cursor_id, start, end) in selections {
         let text = state.get_text_range(start, end);
-        let transformed = transform(&text);
+        let transformed = mode.apply(&text);
         if transformed != text {
             events.push(Event::Delete {
                 range: start..end,
@@ -1020,7 +1097,7 @@ fn handle_insert_newline(
     }
 }
 
-fn handle_dedent_selection(
+fn outdent_selection(
     state: &mut EditorState,
     cursors: &Cursors,
     events: &mut Vec<Event>,
@@ -1144,6 +1221,89 @@ fn handle_dedent_selection(
     }
 }
 
+/// Indent every line touched by a selection by one `tab_str`, keeping each
+/// selection spanning the same lines.
+fn indent_selection(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    tab_str: &str,
+    estimated_line_length: usize,
+) {
+    // Indent selected lines and preserve selections
+    // Collect all line starts from all cursors first to avoid position shifts
+    use std::collections::BTreeSet;
+    let mut all_line_starts = BTreeSet::new();
+    let mut cursor_info = Vec::new();
+
+    for (cursor_id, cursor) in cursors.iter() {
+        if let Some(range) = cursor.selection_range() {
+            let (start_pos, end_pos) = (range.start, range.end);
+
+            // Find all line starts in the range using helper function
+            let line_starts =
+                collect_line_starts(&mut state.buffer, start_pos, end_pos, estimated_line_length);
+
+            // Add to global set (automatically deduplicates and sorts).
+            // Empty lines are left untouched rather than gaining trailing
+            // whitespace.
+            all_line_starts.extend(
+                line_starts
+                    .iter()
+                    .filter(|&&line_start| !is_empty_line(&state.buffer, line_start)),
+            );
+
+            // Store cursor info for later restoration
+            cursor_info.push((
+                cursor_id,
+                cursor.position,
+                cursor.anchor,
+                cursor.sticky_column,
+                start_pos,
+                end_pos,
+            ));
+        }
+    }
+
+    // Create insert events for all line starts in reverse order
+    // This ensures later positions aren't shifted by earlier insertions
+    let first_cursor_id = cursors.iter().next().unwrap().0;
+    for &line_start in all_line_starts.iter().rev() {
+        events.push(Event::Insert {
+            position: line_start,
+            text: tab_str.to_string(),
+            cursor_id: first_cursor_id,
+        });
+    }
+
+    // Calculate new selection positions and add MoveCursor events
+    let indent_len = tab_str.len();
+    for (cursor_id, old_position, old_anchor, old_sticky_column, start_pos, end_pos) in cursor_info
+    {
+        // Count how many indents were inserted at or before each position
+        // Use <= for anchor because we insert at line starts, and positions >= line_start shift
+        // Use < for position to avoid double-counting the indent at position itself
+        let indents_at_or_before_anchor = all_line_starts
+            .iter()
+            .filter(|&&pos| pos <= start_pos)
+            .count();
+        let indents_before_position = all_line_starts.iter().filter(|&&pos| pos < end_pos).count();
+
+        let new_anchor = start_pos + (indents_at_or_before_anchor * indent_len);
+        let new_position = end_pos + (indents_before_position * indent_len);
+
+        add_move_cursor_event(
+            events,
+            cursor_id,
+            old_position,
+            new_position,
+            old_anchor,
+            Some(new_anchor),
+            old_sticky_column,
+        );
+    }
+}
+
 fn handle_insert_tab(
     state: &mut EditorState,
     cursors: &Cursors,
@@ -1164,89 +1324,31 @@ fn handle_insert_tab(
         .any(|(_, cursor)| cursor.selection_range().is_some());
 
     if has_selection {
-        // Indent selected lines and preserve selections
-        // Collect all line starts from all cursors first to avoid position shifts
-        use std::collections::BTreeSet;
-        let mut all_line_starts = BTreeSet::new();
-        let mut cursor_info = Vec::new();
-
-        for (cursor_id, cursor) in cursors.iter() {
-            if let Some(range) = cursor.selection_range() {
-                let (start_pos, end_pos) = (range.start, range.end);
-
-                // Find all line starts in the range using helper function
-                let line_starts = collect_line_starts(
-                    &mut state.buffer,
-                    start_pos,
-                    end_pos,
-                    estimated_line_length,
-                );
-
-                // Add to global set (automatically deduplicates and sorts)
-                all_line_starts.extend(line_starts.iter());
-
-                // Store cursor info for later restoration
-                cursor_info.push((
-                    cursor_id,
-                    cursor.position,
-                    cursor.anchor,
-                    cursor.sticky_column,
-                    start_pos,
-                    end_pos,
-                ));
-            }
-        }
-
-        // Create insert events for all line starts in reverse order
-        // This ensures later positions aren't shifted by earlier insertions
-        let first_cursor_id = cursors.iter().next().unwrap().0;
-        for &line_start in all_line_starts.iter().rev() {
-            events.push(Event::Insert {
-                position: line_start,
-                text: tab_str.clone(),
-                cursor_id: first_cursor_id,
-            });
-        }
-
-        // Calculate new selection positions and add MoveCursor events
-        let indent_len = tab_str.len();
-        for (cursor_id, old_position, old_anchor, old_sticky_column, start_pos, end_pos) in
-            cursor_info
-        {
-            // Count how many indents were inserted at or before each position
-            // Use <= for anchor because we insert at line starts, and positions >= line_start shift
-            // Use < for position to avoid double-counting the indent at position itself
-            let indents_at_or_before_anchor = all_line_starts
-                .iter()
-                .filter(|&&pos| pos <= start_pos)
-                .count();
-            let indents_before_position =
-                all_line_starts.iter().filter(|&&pos| pos < end_pos).count();
-
-            let new_anchor = start_pos + (indents_at_or_before_anchor * indent_len);
-            let new_position = end_pos + (indents_before_position * indent_len);
-
-            add_move_cursor_event(
-                events,
-                cursor_id,
-                old_position,
-                new_position,
-                old_anchor,
-                Some(new_anchor),
-                old_sticky_column,
-            );
-        }
+        indent_selection(state, cursors, events, &tab_str, estimated_line_length);
     } else {
         // No selection - insert tab character at cursor position
         // Sort cursors by position (reverse order) to avoid position shifts
         let mut cursor_vec: Vec<_> = cursors.iter().collect();
         cursor_vec.sort_by_key(|(_, c)| std::cmp::Reverse(c.position));
 
-        // Insert tabs
+        // Insert tabs. With spaces, pad up to the next tab stop so that Tab
+        // after "ab" lands on column 4 (tab_size 4) rather than column 6.
         for (cursor_id, cursor) in cursor_vec {
+            let text = if state.buffer_settings.use_tabs {
+                tab_str.clone()
+            } else {
+                let tab_size = tab_size.max(1);
+                let column = tab_stop_column(
+                    &mut state.buffer,
+                    cursor.position,
+                    tab_size,
+                    estimated_line_length,
+                );
+                " ".repeat(tab_size - column % tab_size)
+            };
             events.push(Event::Insert {
                 position: cursor.position,
-                text: tab_str.clone(),
+                text,
                 cursor_id,
             });
         }
@@ -1614,13 +1716,25 @@ fn handle_delete_backward(
                         let chars_to_remove = if last_byte == b'\t' {
                             1
                         } else {
-                            // Count trailing spaces and remove up to tab_size
+                            // Remove trailing spaces back to the previous tab
+                            // stop, so a misaligned indent snaps to a whole level
                             let trailing_spaces = prefix_bytes
                                 .iter()
                                 .rev()
                                 .take_while(|&&b| b == b' ')
                                 .count();
-                            trailing_spaces.min(tab_size)
+                            let tab_size = tab_size.max(1);
+                            let column = tab_stop_column(
+                                &mut state.buffer,
+                                cursor.position,
+                                tab_size,
+                                estimated_line_length,
+                            );
+                            let to_prev_stop = match column % tab_size {
+                                0 => tab_size,
+                                rem => rem,
+                            };
+                            trailing_spaces.min(to_prev_stop)
                         };
                         if chars_to_remove > 0 {
                             return Some((
@@ -1734,8 +1848,26 @@ fn handle_toggle_case(state: &mut EditorState, cursors: &Cursors, events: &mut V
     }
 }
 
-fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
-    // Sort selected lines alphabetically
+/// How [`handle_sort_lines`] rewrites the selected lines.
+#[derive(Debug, Clone, Copy)]
+enum LineTransform {
+    Sort {
+        descending: bool,
+        case_insensitive: bool,
+    },
+    /// Drop repeated lines, keeping the first occurrence in place
+    Dedupe,
+}
+
+fn handle_sort_lines(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    transform: LineTransform,
+    estimated_line_length: usize,
+) {
+    // Sort (or dedupe) the selected lines. A selection that starts or ends
+    // mid-line is widened to whole lines.
     // Process cursors in reverse order to avoid position shifts
     let line_ending = state.buffer.line_ending().as_str();
     let mut selections: Vec<_> = cursors
@@ -1745,6 +1877,21 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
     selections.sort_by_key(|(_, range)| std::cmp::Reverse(range.start));
 
     for (cursor_id, range) in selections {
+        let start = state
+            .buffer
+            .line_iterator(range.start, estimated_line_length)
+            .current_position();
+        let mut iter = state.buffer.line_iterator(range.end, estimated_line_length);
+        let end_line_start = iter.current_position();
+        let end = if end_line_start == range.end && range.end > start {
+            range.end
+        } else {
+            iter.next_line()
+                .map(|(_, content)| end_line_start + content.trim_end_matches(['\n', '\r']).len())
+                .unwrap_or(range.end)
+        };
+        let range = start..end;
+
         let text = state.get_text_range(range.start, range.end);
         // Split into lines, preserving the original line ending style
         let mut lines: Vec<&str> = text.lines().collect();
@@ -1752,7 +1899,25 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
         let ends_with_newline = text.ends_with('\n') || text.ends_with("\r\n");
 
         if lines.len() > 1 {
-            lines.sort();
+            match transform {
+                LineTransform::Sort {
+                    descending,
+                    case_insensitive,
+                } => {
+                    if case_insensitive {
+                        lines.sort_by_cached_key(|line| line.to_lowercase());
+                    } else {
+                        lines.sort();
+                    }
+                    if descending {
+                        lines.reverse();
+                    }
+                }
+                LineTransform::Dedupe => {
+                    let mut seen = std::collections::HashSet::new();
+                    lines.retain(|line| seen.insert(*line));
+                }
+            }
             let mut sorted_text = lines.join(line_ending);
             if ends_with_newline {
                 sorted_text.push_str(line_ending);
@@ -1857,6 +2022,215 @@ fn handle_duplicate_line(
     }
 }
 
+/// Join the current line with the next one, or all lines touched by a
+/// selection, like vim's `J`: the whitespace around each line break
+/// collapses to a single space, and a break before an empty line is simply
+/// removed. The cursor lands on the last join point.
+fn join_lines(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+) {
+    let mut blocks: Vec<_> = cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            let (start_pos, end_pos) = match cursor.selection_range() {
+                Some(range) => (range.start, range.end),
+                None => (cursor.position, cursor.position),
+            };
+
+            // Lines touched by the selection, plus the next one when only a
+            // single line is touched. A selection ending exactly at a line
+            // start doesn't touch that line.
+            let buffer_len = state.buffer.len();
+            let mut iter = state.buffer.line_iterator(start_pos, estimated_line_length);
+            let mut lines: Vec<(usize, String)> = Vec::new();
+            while let Some((line_start, content)) = iter.next_line() {
+                if !lines.is_empty() && line_start >= buffer_len {
+                    // The phantom line after a trailing newline
+                    break;
+                }
+                let touched = lines.is_empty()
+                    || (line_start <= end_pos && !(line_start == end_pos && end_pos > start_pos));
+                if !touched && lines.len() > 1 {
+                    break;
+                }
+                let has_newline = content.ends_with('\n');
+                lines.push((line_start, content));
+                if !touched || !has_newline {
+                    break;
+                }
+            }
+            if lines.len() < 2 {
+                return None;
+            }
+
+            let strip_ending = |content: &str| -> usize {
+                let body = content.strip_suffix('\n').unwrap_or(content);
+                body.strip_suffix('\r').unwrap_or(body).len()
+            };
+            let mut joined = String::new();
+            let mut join_point = 0;
+            for (i, (_, content)) in lines.iter().enumerate() {
+                let body = &content[..strip_ending(content)];
+                if i == 0 {
+                    joined.push_str(body);
+                    continue;
+                }
+                if body.is_empty() {
+                    join_point = joined.len();
+                    continue;
+                }
+                let trimmed = body.trim_start_matches([' ', '\t']);
+                joined.truncate(joined.trim_end_matches([' ', '\t']).len());
+                join_point = joined.len();
+                if !joined.is_empty() && !trimmed.is_empty() {
+                    joined.push(' ');
+                }
+                joined.push_str(trimmed);
+            }
+
+            let block_start = lines[0].0;
+            let (last_start, last_content) = lines.last()?;
+            let block_end = last_start + strip_ending(last_content);
+            Some((cursor_id, block_start, block_end, joined, join_point))
+        })
+        .collect();
+    // Process from the end of the buffer, skipping blocks that overlap one
+    // already joined for another cursor
+    blocks.sort_by_key(|(_, start, _, _, _)| std::cmp::Reverse(*start));
+
+    let mut processed_start = usize::MAX;
+    for (cursor_id, block_start, block_end, joined, join_point) in blocks {
+        if block_end > processed_start {
+            continue;
+        }
+        processed_start = block_start;
+
+        let Some(cursor) = cursors.get(cursor_id) else {
+            continue;
+        };
+        let (mut old_position, mut old_anchor) = (cursor.position, cursor.anchor);
+        let old_sticky = cursor.sticky_column;
+
+        let original = state.get_text_range(block_start, block_end);
+        if joined != original {
+            old_position = block_start + joined.len();
+            old_anchor = None;
+            events.push(Event::Delete {
+                range: block_start..block_end,
+                deleted_text: original,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: block_start,
+                text: joined,
+                cursor_id,
+            });
+        }
+
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position,
+            new_position: block_start + join_point,
+            old_anchor,
+            new_anchor: None,
+            old_sticky_column: old_sticky,
+            new_sticky_column: 0,
+        });
+    }
+}
+
+fn handle_duplicate_selection(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+) {
+    // With a selection, insert a copy of the selected text right after it
+    // and select the copy. Without one, duplicate the current line below,
+    // keeping the cursor's column. Process cursors in reverse order to
+    // avoid position shifts.
+    let mut cursor_data: Vec<_> = cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            if let Some(range) = cursor.selection_range().filter(|r| !r.is_empty()) {
+                Some((cursor_id, range.start, range.end, None))
+            } else {
+                let mut iter = state
+                    .buffer
+                    .line_iterator(cursor.position, estimated_line_length);
+                let line_start = iter.current_position();
+                iter.next_line().map(|(_, content)| {
+                    let line_end = line_start + content.len();
+                    (
+                        cursor_id,
+                        line_start,
+                        line_end,
+                        Some(cursor.position - line_start),
+                    )
+                })
+            }
+        })
+        .collect();
+    cursor_data.sort_by_key(|(_, start, _, _)| std::cmp::Reverse(*start));
+
+    for (cursor_id, start, end, column) in cursor_data {
+        let text = state.get_text_range(start, end);
+        if text.is_empty() {
+            continue;
+        }
+        let cursor = cursors.get(cursor_id);
+        let old_anchor = cursor.and_then(|c| c.anchor);
+        let old_sticky = cursor.map(|c| c.sticky_column).unwrap_or(0);
+
+        match column {
+            Some(column) => {
+                let line_ending = state.buffer.line_ending().as_str();
+                let has_trailing_newline = text.ends_with('\n') || text.ends_with("\r\n");
+                let (insert_text, new_line_start) = if has_trailing_newline {
+                    (text, end)
+                } else {
+                    (format!("{}{}", line_ending, text), end + line_ending.len())
+                };
+                let insert_len = insert_text.len();
+                events.push(Event::Insert {
+                    position: end,
+                    text: insert_text,
+                    cursor_id,
+                });
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: end + insert_len,
+                    new_position: new_line_start + column,
+                    old_anchor,
+                    new_anchor: None,
+                    old_sticky_column: old_sticky,
+                    new_sticky_column: old_sticky,
+                });
+            }
+            None => {
+                let insert_len = text.len();
+                events.push(Event::Insert {
+                    position: end,
+                    text,
+                    cursor_id,
+                });
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: end + insert_len,
+                    new_position: end + insert_len,
+                    old_anchor,
+                    new_anchor: Some(end),
+                    old_sticky_column: old_sticky,
+                    new_sticky_column: 0,
+                });
+            }
+        }
+    }
+}
+
 /// Convert an action into a sequence of events that can be applied to the editor state
 ///
 /// # Parameters
@@ -1929,7 +2303,7 @@ pub fn action_to_events(
         }
 
         Action::DedentSelection => {
-            handle_dedent_selection(state, cursors, &mut events, tab_size, estimated_line_length);
+            outdent_selection(state, cursors, &mut events, tab_size, estimated_line_length);
         }
 
         Action::InsertTab => {
@@ -2069,8 +2443,9 @@ pub fn action_to_events(
         }
 
         Action::MoveWordLeft => {
+            let subword = state.buffer_settings.subword_movement;
             for (cursor_id, cursor) in cursors.iter() {
-                let new_pos = find_word_start_left(&state.buffer, cursor.position);
+                let new_pos = word_motion_left(&state.buffer, cursor.position, subword);
                 let new_anchor = if cursor.deselect_on_move {
                     None
                 } else {
@@ -2089,8 +2464,9 @@ pub fn action_to_events(
         }
 
         Action::MoveWordRight => {
+            let subword = state.buffer_settings.subword_movement;
             for (cursor_id, cursor) in cursors.iter() {
-                let new_pos = find_word_start_right(&state.buffer, cursor.position);
+                let new_pos = word_motion_right(&state.buffer, cursor.position, subword);
                 let new_anchor = if cursor.deselect_on_move {
                     None
                 } else {
@@ -2488,8 +2864,9 @@ pub fn action_to_events(
         }
 
         Action::SelectWordLeft => {
+            let subword = state.buffer_settings.subword_movement;
             for (cursor_id, cursor) in cursors.iter() {
-                let new_pos = find_word_start_left(&state.buffer, cursor.position);
+                let new_pos = word_motion_left(&state.buffer, cursor.position, subword);
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
                 add_move_cursor_event(
                     &mut events,
@@ -2504,8 +2881,9 @@ pub fn action_to_events(
         }
 
         Action::SelectWordRight => {
+            let subword = state.buffer_settings.subword_movement;
             for (cursor_id, cursor) in cursors.iter() {
-                let new_pos = find_word_start_right(&state.buffer, cursor.position);
+                let new_pos = word_motion_right(&state.buffer, cursor.position, subword);
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
                 add_move_cursor_event(
                     &mut events,
@@ -2683,13 +3061,14 @@ pub fn action_to_events(
 
         Action::DeleteWordBackward => {
             // Collect ranges first to avoid borrow checker issues
+            let subword = state.buffer_settings.subword_movement;
             let deletions: Vec<_> = cursors
                 .iter()
                 .filter_map(|(cursor_id, cursor)| {
                     if let Some(range) = cursor.selection_range() {
                         Some((cursor_id, range))
                     } else {
-                        let word_start = find_word_start_left(&state.buffer, cursor.position);
+                        let word_start = word_delete_start(&state.buffer, cursor.position, subword);
                         if word_start < cursor.position {
                             Some((cursor_id, word_start..cursor.position))
                         } else {
@@ -2705,13 +3084,14 @@ pub fn action_to_events(
 
         Action::DeleteWordForward => {
             // Collect ranges first to avoid borrow checker issues
+            let subword = state.buffer_settings.subword_movement;
             let deletions: Vec<_> = cursors
                 .iter()
                 .filter_map(|(cursor_id, cursor)| {
                     if let Some(range) = cursor.selection_range() {
                         Some((cursor_id, range))
                     } else {
-                        let word_end = find_word_start_right(&state.buffer, cursor.position);
+                        let word_end = word_delete_end(&state.buffer, cursor.position, subword);
                         if cursor.position < word_end {
                             Some((cursor_id, cursor.position..word_end))
                         } else {
@@ -2867,11 +3247,15 @@ pub fn action_to_events(
         }
 
         Action::ToUpperCase => {
-            transform_case(state, cursors, &mut events, |s| s.to_uppercase());
+            transform_selection_case(state, cursors, &mut events, CaseMode::Upper);
         }
 
         Action::ToLowerCase => {
-            transform_case(state, cursors, &mut events, |s| s.to_lowercase());
+            transform_selection_case(state, cursors, &mut events, CaseMode::Lower);
+        }
+
+        Action::ToTitleCase => {
+            transform_selection_case(state, cursors, &mut events, CaseMode::Title);
         }
 
         Action::ToggleCase => {
@@ -2879,7 +3263,65 @@ pub fn action_to_events(
         }
 
         Action::SortLines => {
-            handle_sort_lines(state, cursors, &mut events);
+            handle_sort_lines(
+                state,
+                cursors,
+                &mut events,
+                LineTransform::Sort {
+                    descending: false,
+                    case_insensitive: false,
+                },
+                estimated_line_length,
+            );
+        }
+
+        Action::SortLinesDescending => {
+            handle_sort_lines(
+                state,
+                cursors,
+                &mut events,
+                LineTransform::Sort {
+                    descending: true,
+                    case_insensitive: false,
+                },
+                estimated_line_length,
+            );
+        }
+
+        Action::SortLinesCaseInsensitive => {
+            handle_sort_lines(
+                state,
+                cursors,
+                &mut events,
+                LineTransform::Sort {
+                    descending: false,
+                    case_insensitive: true,
+                },
+                estimated_line_length,
+            );
+        }
+
+        Action::SortLinesCaseInsensitiveDescending => {
+            handle_sort_lines(
+                state,
+                cursors,
+                &mut events,
+                LineTransform::Sort {
+                    descending: true,
+                    case_insensitive: true,
+                },
+                estimated_line_length,
+            );
+        }
+
+        Action::DedupeLines => {
+            handle_sort_lines(
+                state,
+                cursors,
+                &mut events,
+                LineTransform::Dedupe,
+                estimated_line_length,
+            );
         }
 
         Action::OpenLine => {
@@ -2913,6 +3355,14 @@ pub fn action_to_events(
             handle_duplicate_line(state, cursors, &mut events, estimated_line_length);
         }
 
+        Action::DuplicateSelection => {
+            handle_duplicate_selection(state, cursors, &mut events, estimated_line_length);
+        }
+
+        Action::JoinLines => {
+            join_lines(state, cursors, &mut events, estimated_line_length);
+        }
+
         Action::Recenter => {
             // Scroll so that the cursor is centered in the view
             // This is handled specially - we emit a Recenter event
@@ -2967,16 +3417,23 @@ pub fn action_to_events(
         | Action::Detach
         | Action::Save
         | Action::SaveAs
+        | Action::RenameFile
         | Action::Open
+        | Action::OpenRecentFile
         | Action::SwitchProject
         | Action::New
         | Action::Close
         | Action::CloseTab
+        | Action::TogglePinTab
         | Action::GotoLine
+        | Action::OpenFileUnderCursor
+        | Action::OpenUrlUnderCursor
         | Action::ScanLineIndex
         | Action::NextBuffer
         | Action::PrevBuffer
         | Action::SwitchToPreviousTab
+        | Action::CycleRecentBuffers
+        | Action::CycleRecentBuffersReverse
         | Action::SwitchToTabByName
         | Action::NavigateBack
         | Action::NavigateForward
@@ -2991,12 +3448,15 @@ pub fn action_to_events(
         | Action::CopyRelativeFilePath
         | Action::Cut
         | Action::Paste
+        | Action::PasteCyclePrevious
+        | Action::PasteCycleNext
         | Action::YankWordForward
         | Action::YankWordBackward
         | Action::YankToLineEnd
         | Action::YankToLineStart
         | Action::YankViWordEnd
         | Action::AddCursorNextMatch
+        | Action::SelectAllOccurrences
         | Action::AddCursorAbove
         | Action::AddCursorBelow
         | Action::CommandPalette
@@ -3021,16 +3481,22 @@ pub fn action_to_events(
         | Action::Undo
         | Action::Redo
         | Action::GoToMatchingBracket
+        | Action::ExpandSelectionToScope
+        | Action::ShrinkSelection
+        | Action::ExtractSelectionToNewBuffer
         | Action::JumpToNextError
         | Action::JumpToPreviousError
         | Action::ShowKeyboardShortcuts
         | Action::ShowWarnings
         | Action::ShowStatusLog
+        | Action::ShowNotificationLog
+        | Action::DismissNotifications
         | Action::ShowLspStatus
         | Action::ShowRemoteIndicatorMenu
         | Action::ClearWarnings
         | Action::SmartHome
         | Action::ToggleComment
+        | Action::ToggleBlockComment
         | Action::DabbrevExpand
         | Action::ToggleFold
         | Action::SetBookmark(_)
@@ -3137,11 +3603,16 @@ pub fn action_to_events(
         | Action::LspStop
         | Action::LspToggleForBuffer
         | Action::ToggleInlayHints
+        | Action::ToggleDiagnostics
+        | Action::ExplainDiagnostic
         | Action::ToggleMouseHover
         | Action::ToggleLineNumbers
+        | Action::CycleLineNumberMode
+        | Action::ToggleBreakpoint
         | Action::ToggleScrollSync
         | Action::ToggleMouseCapture
         | Action::DumpConfig
+        | Action::ReloadConfig
         | Action::RedrawScreen
         | Action::Search
         | Action::FindInSelection
@@ -3166,14 +3637,20 @@ pub fn action_to_events(
         | Action::ScrollTabsRight
         | Action::InspectThemeAtCursor
         | Action::SelectTheme
+        | Action::SetTheme(_)
+        | Action::ReloadTheme
         | Action::SelectKeybindingMap
         | Action::SelectCursorStyle
         | Action::SelectLocale
         | Action::Revert
+        | Action::DiffAgainstDisk
         | Action::ToggleAutoRevert
         | Action::FormatBuffer
         | Action::TrimTrailingWhitespace
         | Action::EnsureFinalNewline
+        | Action::RevertHunkAtCursor
+        | Action::StageHunks
+        | Action::ToggleGitBlame
         | Action::OpenTerminal
         | Action::CloseTerminal
         | Action::FocusTerminal
@@ -3197,12 +3674,18 @@ pub fn action_to_events(
         | Action::ReloadWithEncoding
         | Action::SetLanguage
         | Action::ToggleIndentationStyle
+        | Action::ConvertIndentationToSpaces
+        | Action::ConvertIndentationToTabs
+        | Action::ToggleSpellCheck
+        | Action::SpellingSuggestions
         | Action::ToggleTabIndicators
         | Action::ToggleWhitespaceIndicators
         | Action::ToggleDebugHighlights
         | Action::ResetBufferSettings
         | Action::ShellCommand
         | Action::ShellCommandReplace
+        | Action::RunTask
+        | Action::ShowCommandHistory
         | Action::CalibrateInput
         | Action::EventDebug
         | Action::SuspendProcess
@@ -5887,6 +6370,26 @@ mod tests {
 
         assert_eq!(state.buffer.to_string().unwrap(), "(bc)");
     }
+
+    #[test]
+    fn test_case_mode_upper_and_lower_handle_multibyte() {
+        assert_eq!(CaseMode::Upper.apply("éCOLE straße"), "ÉCOLE STRASSE");
+        assert_eq!(CaseMode::Lower.apply("ÉCOLE Ünïcode"), "école ünïcode");
+    }
+
+    #[test]
+    fn test_case_mode_title_at_word_boundaries() {
+        assert_eq!(CaseMode::Title.apply("hello WORLD"), "Hello World");
+        assert_eq!(
+            CaseMode::Title.apply("éCOLE élémentaire"),
+            "École Élémentaire"
+        );
+        assert_eq!(CaseMode::Title.apply("foo_bar-baz.qux"), "Foo_Bar-Baz.Qux");
+        assert_eq!(CaseMode::Title.apply("don't stop"), "Don't Stop");
+        assert_eq!(CaseMode::Title.apply("item2go  x"), "Item2go  X");
+        // Locale-independent: dotted/dotless i are not special-cased
+        assert_eq!(CaseMode::Title.apply("istanbul"), "Istanbul");
+    }
 }
 
 #[cfg(test)]
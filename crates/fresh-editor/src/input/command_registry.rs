@@ -7,6 +7,7 @@ use crate::input::commands::{get_all_commands, Command, CommandSource, Suggestio
 use crate::input::fuzzy::fuzzy_match;
 use crate::input::keybindings::Action;
 use crate::input::keybindings::KeyContext;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 /// Registry for managing editor commands
@@ -23,18 +24,30 @@ pub struct CommandRegistry {
     /// Command usage history (most recent first)
     /// Used to sort command palette suggestions by recency
     command_history: Vec<String>,
+
+    /// How many times each command in `command_history` has been used.
+    /// Combined with recency into a frecency score for ranking.
+    usage_counts: HashMap<String, u32>,
 }
 
 impl CommandRegistry {
     /// Maximum number of commands to keep in history
     const MAX_HISTORY_SIZE: usize = 50;
 
+    /// Frecency points for a single use of the most recent command
+    const FRECENCY_UNIT: u32 = 16;
+
+    /// Upper bound on the frecency bonus added to a fuzzy match score, so a
+    /// heavily used command never outranks a much better textual match
+    const MAX_FRECENCY_BONUS: i32 = 40;
+
     /// Create a new command registry with built-in commands
     pub fn new() -> Self {
         Self {
             builtin_commands: get_all_commands(),
             plugin_commands: Arc::new(RwLock::new(Vec::new())),
             command_history: Vec::new(),
+            usage_counts: HashMap::new(),
         }
     }
 
@@ -45,21 +58,68 @@ impl CommandRegistry {
 
     /// Record that a command was used (for history/sorting)
     ///
-    /// This moves the command to the front of the history list.
-    /// Recently used commands appear first in suggestions.
+    /// This moves the command to the front of the history list and bumps
+    /// its usage count. Frequently and recently used commands appear first
+    /// in suggestions.
     pub fn record_usage(&mut self, command_name: &str) {
         // Remove existing entry if present
         self.command_history.retain(|name| name != command_name);
 
         // Add to front (most recent)
         self.command_history.insert(0, command_name.to_string());
+        *self
+            .usage_counts
+            .entry(command_name.to_string())
+            .or_insert(0) += 1;
+
+        self.trim_history();
+    }
 
-        // Trim to max size
+    /// Drop history beyond `MAX_HISTORY_SIZE`, along with the usage counts
+    /// of the commands that fell off.
+    fn trim_history(&mut self) {
         if self.command_history.len() > Self::MAX_HISTORY_SIZE {
-            self.command_history.truncate(Self::MAX_HISTORY_SIZE);
+            for name in self.command_history.drain(Self::MAX_HISTORY_SIZE..) {
+                self.usage_counts.remove(&name);
+            }
         }
     }
 
+    /// Command history (most recent first) with each command's usage count,
+    /// for persisting in the workspace.
+    pub fn history(&self) -> Vec<(String, u32)> {
+        self.command_history
+            .iter()
+            .map(|name| (name.clone(), self.usage_count(name)))
+            .collect()
+    }
+
+    /// Replace the command history with one previously returned by
+    /// [`Self::history`].
+    pub fn restore_history(&mut self, history: impl IntoIterator<Item = (String, u32)>) {
+        self.command_history.clear();
+        self.usage_counts.clear();
+        for (name, count) in history {
+            if self.usage_counts.contains_key(&name) {
+                continue;
+            }
+            self.usage_counts.insert(name.clone(), count.max(1));
+            self.command_history.push(name);
+        }
+        self.trim_history();
+    }
+
+    fn usage_count(&self, command_name: &str) -> u32 {
+        self.usage_counts.get(command_name).copied().unwrap_or(1)
+    }
+
+    /// Frecency score of a command: its usage count weighted by how recently
+    /// it was last used. Returns None for commands that were never used.
+    fn frecency(&self, command_name: &str) -> Option<u32> {
+        let position = self.history_position(command_name)? as u32;
+        Some(self.usage_count(command_name) * Self::FRECENCY_UNIT / (position + 1))
+    }
+
     /// Get the position of a command in history (0 = most recent)
     /// Returns None if command is not in history
     fn history_position(&self, command_name: &str) -> Option<usize> {
@@ -182,9 +242,10 @@ impl CommandRegistry {
 
     /// Filter commands by fuzzy matching query with context awareness
     ///
-    /// When query is empty, commands are sorted by recency (most recently used first).
+    /// When query is empty, commands are sorted by frecency (usage count weighted by
+    /// recency), most relevant first.
     /// When query is not empty, commands are sorted by match quality (fzf-style scoring)
-    /// with recency as tiebreaker for equal scores.
+    /// plus a capped frecency bonus, with recency as tiebreaker for equal scores.
     /// Disabled commands always appear after enabled ones.
     ///
     /// `has_lsp_config` indicates whether the active buffer's language has an LSP server
@@ -242,20 +303,23 @@ impl CommandRegistry {
                 }
                 let keybinding = keybinding_resolver
                     .get_keybinding_for_action(&cmd.action, current_context_ref.clone());
+                let frecency = self.frecency(&cmd.name);
                 let history_pos = self.history_position(&cmd.name);
+                let score =
+                    score + frecency.map_or(0, |f| (f as i32).min(Self::MAX_FRECENCY_BONUS));
 
                 let suggestion = Suggestion::new(localized_name)
                     .with_description(localized_desc)
                     .set_disabled(!available)
                     .with_keybinding(keybinding)
                     .with_source(Some(cmd.source.clone()));
-                (suggestion, history_pos, score)
+                (suggestion, (frecency, history_pos), score)
             };
 
         // Match by name or description
         // Commands with unmet custom contexts are completely hidden
         // match_kind: 0 = name match, 1 = description match
-        let mut suggestions: Vec<(Suggestion, Option<usize>, i32, u8)> = commands
+        let mut suggestions: Vec<(Suggestion, (Option<u32>, Option<usize>), i32, u8)> = commands
             .iter()
             .filter(|cmd| is_visible(cmd))
             .filter_map(|cmd| {
@@ -285,11 +349,12 @@ impl CommandRegistry {
         // Sort by:
         // 1. Disabled status (enabled first)
         // 2. Match kind (name matches before description matches) - only when query is not empty
-        // 3. Fuzzy match score (higher is better) - only when query is not empty
-        // 4. History position (recent first, then never-used alphabetically)
+        // 3. Fuzzy match score plus frecency bonus (higher is better) - only when query is not empty
+        // 4. Frecency, then history position (recent first, then never-used alphabetically)
         let has_query = !query.is_empty();
         suggestions.sort_by(
-            |(a, a_hist, a_score, a_kind), (b, b_hist, b_score, b_kind)| {
+            |(a, (a_frecency, a_hist), a_score, a_kind),
+             (b, (b_frecency, b_hist), b_score, b_kind)| {
                 // First sort by disabled status
                 match a.disabled.cmp(&b.disabled) {
                     std::cmp::Ordering::Equal => {}
@@ -310,7 +375,12 @@ impl CommandRegistry {
                     }
                 }
 
-                // Then sort by history position (lower = more recent = better)
+                // Then sort by frecency (higher is better), falling back to
+                // history position (lower = more recent = better)
+                match b_frecency.cmp(a_frecency) {
+                    std::cmp::Ordering::Equal => {}
+                    other => return other,
+                }
                 match (a_hist, b_hist) {
                     (Some(a_pos), Some(b_pos)) => a_pos.cmp(b_pos),
                     (Some(_), None) => std::cmp::Ordering::Less, // In history beats not in history
@@ -706,6 +776,58 @@ mod tests {
         assert_eq!(registry.history_position("Command 0"), None);
     }
 
+    #[test]
+    fn test_frecency_prefers_frequent_commands() {
+        use crate::config::Config;
+        use crate::input::keybindings::KeybindingResolver;
+
+        let mut registry = CommandRegistry::new();
+        let config = Config::default();
+        let keybindings = KeybindingResolver::new(&config);
+
+        for _ in 0..5 {
+            registry.record_usage("Toggle Line Numbers");
+        }
+        // Used once, but more recently
+        registry.record_usage("Save File");
+
+        let empty_contexts = std::collections::HashSet::new();
+        let results = registry.filter(
+            "",
+            KeyContext::Normal,
+            &keybindings,
+            false,
+            &empty_contexts,
+            None,
+            true,
+        );
+        assert_eq!(results[0].text, "Toggle Line Numbers");
+        assert_eq!(results[1].text, "Save File");
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let mut registry = CommandRegistry::new();
+        registry.record_usage("Quit");
+        registry.record_usage("Save File");
+        registry.record_usage("Save File");
+
+        let history = registry.history();
+        assert_eq!(
+            history,
+            vec![("Save File".to_string(), 2), ("Quit".to_string(), 1)]
+        );
+
+        let mut restored = CommandRegistry::new();
+        restored.restore_history(history);
+        assert_eq!(restored.history_position("Save File"), Some(0));
+        assert_eq!(
+            restored.frecency("Save File"),
+            registry.frecency("Save File")
+        );
+        assert_eq!(restored.frecency("Quit"), registry.frecency("Quit"));
+    }
+
     #[test]
     fn test_unused_commands_alphabetical() {
         use crate::config::Config;
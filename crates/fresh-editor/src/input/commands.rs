@@ -155,6 +155,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.open_recent_file",
+        desc_key: "cmd.open_recent_file_desc",
+        action: || Action::OpenRecentFile,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.switch_project",
         desc_key: "cmd.switch_project_desc",
@@ -176,6 +183,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.rename_file",
+        desc_key: "cmd.rename_file_desc",
+        action: || Action::RenameFile,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.new_file",
         desc_key: "cmd.new_file_desc",
@@ -197,6 +211,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_pin_tab",
+        desc_key: "cmd.toggle_pin_tab_desc",
+        action: || Action::TogglePinTab,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.revert_file",
         desc_key: "cmd.revert_file_desc",
@@ -204,6 +225,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.diff_against_disk",
+        desc_key: "cmd.diff_against_disk_desc",
+        action: || Action::DiffAgainstDisk,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_auto_revert",
         desc_key: "cmd.toggle_auto_revert_desc",
@@ -232,6 +260,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.revert_hunk_at_cursor",
+        desc_key: "cmd.revert_hunk_at_cursor_desc",
+        action: || Action::RevertHunkAtCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.stage_hunks",
+        desc_key: "cmd.stage_hunks_desc",
+        action: || Action::StageHunks,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_git_blame",
+        desc_key: "cmd.toggle_git_blame_desc",
+        action: || Action::ToggleGitBlame,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.quit",
         desc_key: "cmd.quit_desc",
@@ -318,6 +367,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.paste_cycle_previous",
+        desc_key: "cmd.paste_cycle_previous_desc",
+        action: || Action::PasteCyclePrevious,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.paste_cycle_next",
+        desc_key: "cmd.paste_cycle_next_desc",
+        action: || Action::PasteCycleNext,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.delete_line",
         desc_key: "cmd.delete_line_desc",
@@ -367,6 +430,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.transform_titlecase",
+        desc_key: "cmd.transform_titlecase_desc",
+        action: || Action::ToTitleCase,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.sort_lines",
         desc_key: "cmd.sort_lines_desc",
@@ -374,6 +444,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.sort_lines_descending",
+        desc_key: "cmd.sort_lines_descending_desc",
+        action: || Action::SortLinesDescending,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_case_insensitive",
+        desc_key: "cmd.sort_lines_case_insensitive_desc",
+        action: || Action::SortLinesCaseInsensitive,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_case_insensitive_descending",
+        desc_key: "cmd.sort_lines_case_insensitive_descending_desc",
+        action: || Action::SortLinesCaseInsensitiveDescending,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.dedupe_lines",
+        desc_key: "cmd.dedupe_lines_desc",
+        action: || Action::DedupeLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.open_line",
         desc_key: "cmd.open_line_desc",
@@ -388,6 +486,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.duplicate_selection",
+        desc_key: "cmd.duplicate_selection_desc",
+        action: || Action::DuplicateSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.join_lines",
+        desc_key: "cmd.join_lines_desc",
+        action: || Action::JoinLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.recenter",
         desc_key: "cmd.recenter_desc",
@@ -431,6 +543,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.expand_selection_to_scope",
+        desc_key: "cmd.expand_selection_to_scope_desc",
+        action: || Action::ExpandSelectionToScope,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.shrink_selection",
+        desc_key: "cmd.shrink_selection_desc",
+        action: || Action::ShrinkSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.extract_selection_to_new_buffer",
+        desc_key: "cmd.extract_selection_to_new_buffer_desc",
+        action: || Action::ExtractSelectionToNewBuffer,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Multi-cursor
     CommandDef {
         name_key: "cmd.add_cursor_above",
@@ -453,6 +586,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.select_all_occurrences",
+        desc_key: "cmd.select_all_occurrences_desc",
+        action: || Action::SelectAllOccurrences,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.remove_secondary_cursors",
         desc_key: "cmd.remove_secondary_cursors_desc",
@@ -482,6 +622,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.cycle_recent_buffers",
+        desc_key: "cmd.cycle_recent_buffers_desc",
+        action: || Action::CycleRecentBuffers,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.switch_to_tab_by_name",
         desc_key: "cmd.switch_to_tab_by_name_desc",
@@ -554,6 +701,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.cycle_line_number_mode",
+        desc_key: "cmd.cycle_line_number_mode_desc",
+        action: || Action::CycleLineNumberMode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_breakpoint",
+        desc_key: "cmd.toggle_breakpoint_desc",
+        action: || Action::ToggleBreakpoint,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_scroll_sync",
         desc_key: "cmd.toggle_scroll_sync_desc",
@@ -633,6 +794,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.convert_indentation_to_spaces",
+        desc_key: "cmd.convert_indentation_to_spaces_desc",
+        action: || Action::ConvertIndentationToSpaces,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.convert_indentation_to_tabs",
+        desc_key: "cmd.convert_indentation_to_tabs_desc",
+        action: || Action::ConvertIndentationToTabs,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_spell_check",
+        desc_key: "cmd.toggle_spell_check_desc",
+        action: || Action::ToggleSpellCheck,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.spelling_suggestions",
+        desc_key: "cmd.spelling_suggestions_desc",
+        action: || Action::SpellingSuggestions,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_tab_indicators",
         desc_key: "cmd.toggle_tab_indicators_desc",
@@ -917,6 +1106,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.open_file_under_cursor",
+        desc_key: "cmd.open_file_under_cursor_desc",
+        action: || Action::OpenFileUnderCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.open_url_under_cursor",
+        desc_key: "cmd.open_url_under_cursor_desc",
+        action: || Action::OpenUrlUnderCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.scan_line_index",
         desc_key: "cmd.scan_line_index_desc",
@@ -1023,6 +1226,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_block_comment",
+        desc_key: "cmd.toggle_block_comment_desc",
+        action: || Action::ToggleBlockComment,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.dedent_selection",
         desc_key: "cmd.dedent_selection_desc",
@@ -1139,6 +1349,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_notification_log",
+        desc_key: "cmd.show_notification_log_desc",
+        action: || Action::ShowNotificationLog,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.dismiss_notifications",
+        desc_key: "cmd.dismiss_notifications_desc",
+        action: || Action::DismissNotifications,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_lsp_status",
         desc_key: "cmd.show_lsp_status_desc",
@@ -1168,6 +1392,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reload_config",
+        desc_key: "cmd.reload_config_desc",
+        action: || Action::ReloadConfig,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.redraw_screen",
         desc_key: "cmd.redraw_screen_desc",
@@ -1182,6 +1413,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_diagnostics",
+        desc_key: "cmd.toggle_diagnostics_desc",
+        action: || Action::ToggleDiagnostics,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.explain_diagnostic",
+        desc_key: "cmd.explain_diagnostic_desc",
+        action: || Action::ExplainDiagnostic,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Theme selection
     CommandDef {
         name_key: "cmd.select_theme",
@@ -1190,6 +1435,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reload_theme",
+        desc_key: "cmd.reload_theme_desc",
+        action: || Action::ReloadTheme,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Theme inspection
     CommandDef {
         name_key: "cmd.inspect_theme_at_cursor",
@@ -1290,6 +1542,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.run_task",
+        desc_key: "cmd.run_task_desc",
+        action: || Action::RunTask,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_command_history",
+        desc_key: "cmd.show_command_history_desc",
+        action: || Action::ShowCommandHistory,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Debugging
     CommandDef {
         name_key: "cmd.event_debug",
@@ -349,6 +349,9 @@ pub enum Action {
     SelectWord,
     SelectLine,
     ExpandSelection,
+    ExpandSelectionToScope,
+    ShrinkSelection,
+    ExtractSelectionToNewBuffer,
 
     // Block/rectangular selection (column-wise)
     BlockSelectLeft,
@@ -368,6 +371,8 @@ pub enum Action {
     TransposeChars,
     OpenLine,
     DuplicateLine,
+    DuplicateSelection,
+    JoinLines, // Join the current/selected lines with single spaces (vim J)
 
     // View
     Recenter,
@@ -380,6 +385,10 @@ pub enum Action {
     CopyWithTheme(String),
     Cut,
     Paste,
+    /// Replace the text just pasted with the previous yank ring entry
+    PasteCyclePrevious,
+    /// Replace the text just pasted with the next yank ring entry
+    PasteCycleNext,
     /// Copy the absolute filesystem path of the active buffer's file to the clipboard.
     CopyFilePath,
     /// Copy the active buffer's file path relative to the workspace root, falling
@@ -397,27 +406,37 @@ pub enum Action {
     AddCursorAbove,
     AddCursorBelow,
     AddCursorNextMatch,
+    SelectAllOccurrences,
     RemoveSecondaryCursors,
 
     // File operations
     Save,
     SaveAs,
+    RenameFile,
     Open,
+    OpenRecentFile,
     SwitchProject,
     New,
     Close,
     CloseTab,
+    TogglePinTab,
     Quit,
     ForceQuit,
     Detach,
     Revert,
+    DiffAgainstDisk,
     ToggleAutoRevert,
     FormatBuffer,
     TrimTrailingWhitespace,
     EnsureFinalNewline,
+    RevertHunkAtCursor,
+    StageHunks,
+    ToggleGitBlame,
 
     // Navigation
     GotoLine,
+    OpenFileUnderCursor,
+    OpenUrlUnderCursor,
     ScanLineIndex,
     GoToMatchingBracket,
     JumpToNextError,
@@ -427,6 +446,7 @@ pub enum Action {
     SmartHome,
     DedentSelection,
     ToggleComment,
+    ToggleBlockComment,
     DabbrevExpand,
     ToggleFold,
 
@@ -468,6 +488,8 @@ pub enum Action {
     ShowKeyboardShortcuts,
     ShowWarnings,
     ShowStatusLog,
+    ShowNotificationLog,
+    DismissNotifications,
     ShowLspStatus,
     ShowRemoteIndicatorMenu,
     ClearWarnings,
@@ -505,6 +527,10 @@ pub enum Action {
     SetPageWidth,
     InspectThemeAtCursor,
     SelectTheme,
+    /// Switch to the named theme (empty name opens the theme picker)
+    SetTheme(String),
+    /// Re-read theme files from disk and re-apply the active theme
+    ReloadTheme,
     SelectKeybindingMap,
     SelectCursorStyle,
     SelectLocale,
@@ -513,6 +539,8 @@ pub enum Action {
     NextBuffer,
     PrevBuffer,
     SwitchToPreviousTab,
+    CycleRecentBuffers,
+    CycleRecentBuffersReverse,
     SwitchToTabByName,
 
     // Tab scrolling
@@ -639,10 +667,14 @@ pub enum Action {
     LspStop,
     LspToggleForBuffer,
     ToggleInlayHints,
+    ToggleDiagnostics,
+    ExplainDiagnostic,
     ToggleMouseHover,
 
     // View toggles
     ToggleLineNumbers,
+    CycleLineNumberMode,
+    ToggleBreakpoint,
     ToggleScrollSync,
     ToggleMouseCapture,
     ToggleDebugHighlights, // Debug mode: show highlight/overlay byte ranges
@@ -656,6 +688,10 @@ pub enum Action {
     ReloadWithEncoding,
     SetLanguage,
     ToggleIndentationStyle,
+    ConvertIndentationToSpaces,
+    ConvertIndentationToTabs,
+    ToggleSpellCheck,
+    SpellingSuggestions,
     ToggleTabIndicators,
     ToggleWhitespaceIndicators,
     ResetBufferSettings,
@@ -664,6 +700,8 @@ pub enum Action {
 
     // Config operations
     DumpConfig,
+    /// Re-read the config files and apply the changes to the running session
+    ReloadConfig,
 
     // Force a full terminal clear + redraw (fixes display corruption from external output)
     RedrawScreen,
@@ -718,12 +756,19 @@ pub enum Action {
     // Shell command operations
     ShellCommand,        // Run shell command on buffer/selection, output to new buffer
     ShellCommandReplace, // Run shell command on buffer/selection, replace content
+    RunTask,             // Run a configured task, output streams into a panel
+    ShowCommandHistory,  // List executed commands and tasks in a panel
 
     // Case conversion
     ToUpperCase, // Convert selection to uppercase
     ToLowerCase, // Convert selection to lowercase
+    ToTitleCase, // Convert selection to title case
     ToggleCase,  // Toggle case of character under cursor (vim ~)
     SortLines,   // Sort selected lines alphabetically
+    SortLinesDescending,
+    SortLinesCaseInsensitive,
+    SortLinesCaseInsensitiveDescending,
+    DedupeLines, // Remove repeated selected lines, keeping the first
 
     // Input calibration
     CalibrateInput, // Open the input calibration wizard
@@ -869,6 +914,9 @@ impl Action {
             "select_word" => SelectWord,
             "select_line" => SelectLine,
             "expand_selection" => ExpandSelection,
+            "expand_selection_to_scope" => ExpandSelectionToScope,
+            "shrink_selection" => ShrinkSelection,
+            "extract_selection_to_new_buffer" => ExtractSelectionToNewBuffer,
 
             "block_select_left" => BlockSelectLeft,
             "block_select_right" => BlockSelectRight,
@@ -886,12 +934,16 @@ impl Action {
             "transpose_chars" => TransposeChars,
             "open_line" => OpenLine,
             "duplicate_line" => DuplicateLine,
+            "duplicate_selection" => DuplicateSelection,
+            "join_lines" => JoinLines,
             "recenter" => Recenter,
             "set_mark" => SetMark,
 
             "copy" => Copy,
             "cut" => Cut,
             "paste" => Paste,
+            "paste_cycle_previous" => PasteCyclePrevious,
+            "paste_cycle_next" => PasteCycleNext,
             "copy_file_path" => CopyFilePath,
             "copy_relative_file_path" => CopyRelativeFilePath,
 
@@ -904,24 +956,34 @@ impl Action {
             "add_cursor_above" => AddCursorAbove,
             "add_cursor_below" => AddCursorBelow,
             "add_cursor_next_match" => AddCursorNextMatch,
+            "select_all_occurrences" => SelectAllOccurrences,
             "remove_secondary_cursors" => RemoveSecondaryCursors,
 
             "save" => Save,
             "save_as" => SaveAs,
+            "rename_file" => RenameFile,
             "open" => Open,
+            "open_recent_file" => OpenRecentFile,
             "switch_project" => SwitchProject,
             "new" => New,
             "close" => Close,
             "close_tab" => CloseTab,
+            "toggle_pin_tab" => TogglePinTab,
             "quit" => Quit,
             "force_quit" => ForceQuit,
             "detach" => Detach,
             "revert" => Revert,
+            "diff_against_disk" => DiffAgainstDisk,
             "toggle_auto_revert" => ToggleAutoRevert,
             "format_buffer" => FormatBuffer,
             "trim_trailing_whitespace" => TrimTrailingWhitespace,
             "ensure_final_newline" => EnsureFinalNewline,
+            "revert_hunk_at_cursor" => RevertHunkAtCursor,
+            "stage_hunks" => StageHunks,
+            "toggle_git_blame" => ToggleGitBlame,
             "goto_line" => GotoLine,
+            "open_file_under_cursor" => OpenFileUnderCursor,
+            "open_url_under_cursor" => OpenUrlUnderCursor,
             "scan_line_index" => ScanLineIndex,
             "goto_matching_bracket" => GoToMatchingBracket,
             "jump_to_next_error" => JumpToNextError,
@@ -930,6 +992,7 @@ impl Action {
             "smart_home" => SmartHome,
             "dedent_selection" => DedentSelection,
             "toggle_comment" => ToggleComment,
+            "toggle_block_comment" => ToggleBlockComment,
             "dabbrev_expand" => DabbrevExpand,
             "toggle_fold" => ToggleFold,
 
@@ -959,6 +1022,8 @@ impl Action {
             "keyboard_shortcuts" => ShowKeyboardShortcuts,
             "show_warnings" => ShowWarnings,
             "show_status_log" => ShowStatusLog,
+            "show_notification_log" => ShowNotificationLog,
+            "dismiss_notifications" => DismissNotifications,
             "show_lsp_status" => ShowLspStatus,
             "show_remote_indicator_menu" => ShowRemoteIndicatorMenu,
             "clear_warnings" => ClearWarnings,
@@ -981,6 +1046,8 @@ impl Action {
             "next_buffer" => NextBuffer,
             "prev_buffer" => PrevBuffer,
             "switch_to_previous_tab" => SwitchToPreviousTab,
+            "cycle_recent_buffers" => CycleRecentBuffers,
+            "cycle_recent_buffers_reverse" => CycleRecentBuffersReverse,
             "switch_to_tab_by_name" => SwitchToTabByName,
             "scroll_tabs_left" => ScrollTabsLeft,
             "scroll_tabs_right" => ScrollTabsRight,
@@ -1083,9 +1150,13 @@ impl Action {
             "lsp_stop" => LspStop,
             "lsp_toggle_for_buffer" => LspToggleForBuffer,
             "toggle_inlay_hints" => ToggleInlayHints,
+            "toggle_diagnostics" => ToggleDiagnostics,
+            "explain_diagnostic" => ExplainDiagnostic,
             "toggle_mouse_hover" => ToggleMouseHover,
 
             "toggle_line_numbers" => ToggleLineNumbers,
+            "cycle_line_number_mode" => CycleLineNumberMode,
+            "toggle_breakpoint" => ToggleBreakpoint,
             "toggle_scroll_sync" => ToggleScrollSync,
             "toggle_mouse_capture" => ToggleMouseCapture,
             "toggle_debug_highlights" => ToggleDebugHighlights,
@@ -1093,6 +1164,7 @@ impl Action {
             "set_background_blend" => SetBackgroundBlend,
             "inspect_theme_at_cursor" => InspectThemeAtCursor,
             "select_theme" => SelectTheme,
+            "reload_theme" => ReloadTheme,
             "select_keybinding_map" => SelectKeybindingMap,
             "select_cursor_style" => SelectCursorStyle,
             "select_locale" => SelectLocale,
@@ -1103,6 +1175,10 @@ impl Action {
             "reload_with_encoding" => ReloadWithEncoding,
             "set_language" => SetLanguage,
             "toggle_indentation_style" => ToggleIndentationStyle,
+            "convert_indentation_to_spaces" => ConvertIndentationToSpaces,
+            "convert_indentation_to_tabs" => ConvertIndentationToTabs,
+            "toggle_spell_check" => ToggleSpellCheck,
+            "spelling_suggestions" => SpellingSuggestions,
             "toggle_tab_indicators" => ToggleTabIndicators,
             "toggle_whitespace_indicators" => ToggleWhitespaceIndicators,
             "reset_buffer_settings" => ResetBufferSettings,
@@ -1110,6 +1186,7 @@ impl Action {
             "remove_ruler" => RemoveRuler,
 
             "dump_config" => DumpConfig,
+            "reload_config" => ReloadConfig,
             "redraw_screen" => RedrawScreen,
 
             "search" => Search,
@@ -1138,11 +1215,18 @@ impl Action {
 
             "shell_command" => ShellCommand,
             "shell_command_replace" => ShellCommandReplace,
+            "run_task" => RunTask,
+            "show_command_history" => ShowCommandHistory,
 
             "to_upper_case" => ToUpperCase,
             "to_lower_case" => ToLowerCase,
+            "to_title_case" => ToTitleCase,
             "toggle_case" => ToggleCase,
             "sort_lines" => SortLines,
+            "sort_lines_descending" => SortLinesDescending,
+            "sort_lines_case_insensitive" => SortLinesCaseInsensitive,
+            "sort_lines_case_insensitive_descending" => SortLinesCaseInsensitiveDescending,
+            "dedupe_lines" => DedupeLines,
 
             "calibrate_input" => CalibrateInput,
             "event_debug" => EventDebug,
@@ -1173,6 +1257,7 @@ impl Action {
         alias {
             "toggle_compose_mode" => TogglePageView,
             "set_compose_width" => SetPageWidth,
+            "center_cursor" => Recenter,
         }
         with_char {
             "insert_char" => InsertChar,
@@ -1189,6 +1274,11 @@ impl Action {
                 let theme = args.get("theme").and_then(|v| v.as_str()).unwrap_or("");
                 Self::CopyWithTheme(theme.to_string())
             },
+            "set_theme" => SetTheme : {
+                // Empty name = open theme picker prompt
+                let name = args.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                Self::SetTheme(name.to_string())
+            },
             "menu_open" => MenuOpen : {
                 let name = args.get("name")?.as_str()?;
                 Self::MenuOpen(name.to_string())
@@ -1213,6 +1303,7 @@ impl Action {
     pub fn variant_arg_key(bare_action: &str) -> Option<&'static str> {
         match bare_action {
             "menu_open" => Some("name"),
+            "set_theme" => Some("name"),
             "switch_keybinding_map" => Some("map"),
             _ => None,
         }
@@ -1303,6 +1394,8 @@ impl Action {
                 | Action::SelectWord
                 | Action::SelectLine
                 | Action::ExpandSelection
+                | Action::ExpandSelectionToScope
+                | Action::ShrinkSelection
                 // Block selection
                 | Action::BlockSelectLeft
                 | Action::BlockSelectRight
@@ -1322,11 +1415,15 @@ impl Action {
                 | Action::TransposeChars
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::DuplicateSelection
+                | Action::JoinLines
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 // Clipboard editing (but not Copy)
                 | Action::Cut
                 | Action::Paste
+                | Action::PasteCyclePrevious
+                | Action::PasteCycleNext
                 // Undo/Redo
                 | Action::Undo
                 | Action::Redo
@@ -1352,10 +1449,14 @@ impl Action {
                 | Action::TransposeChars
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::DuplicateSelection
+                | Action::JoinLines
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 | Action::Cut
                 | Action::Paste
+                | Action::PasteCyclePrevious
+                | Action::PasteCycleNext
         )
     }
 }
@@ -1661,6 +1762,8 @@ impl KeybindingResolver {
                 // Tab/buffer navigation
                 | Action::NextBuffer
                 | Action::PrevBuffer
+                | Action::CycleRecentBuffers
+                | Action::CycleRecentBuffersReverse
                 | Action::Close
                 | Action::ScrollTabsLeft
                 | Action::ScrollTabsRight
@@ -2183,6 +2286,9 @@ impl KeybindingResolver {
             Action::SelectWord => t!("action.select_word"),
             Action::SelectLine => t!("action.select_line"),
             Action::ExpandSelection => t!("action.expand_selection"),
+            Action::ExpandSelectionToScope => t!("action.expand_selection_to_scope"),
+            Action::ShrinkSelection => t!("action.shrink_selection"),
+            Action::ExtractSelectionToNewBuffer => t!("action.extract_selection_to_new_buffer"),
             Action::BlockSelectLeft => t!("action.block_select_left"),
             Action::BlockSelectRight => t!("action.block_select_right"),
             Action::BlockSelectUp => t!("action.block_select_up"),
@@ -2198,6 +2304,8 @@ impl KeybindingResolver {
             Action::TransposeChars => t!("action.transpose_chars"),
             Action::OpenLine => t!("action.open_line"),
             Action::DuplicateLine => t!("action.duplicate_line"),
+            Action::DuplicateSelection => t!("action.duplicate_selection"),
+            Action::JoinLines => t!("action.join_lines"),
             Action::Recenter => t!("action.recenter"),
             Action::SetMark => t!("action.set_mark"),
             Action::Copy => t!("action.copy"),
@@ -2205,6 +2313,8 @@ impl KeybindingResolver {
             Action::CopyWithTheme(theme) => t!("action.copy_with_theme", theme = theme),
             Action::Cut => t!("action.cut"),
             Action::Paste => t!("action.paste"),
+            Action::PasteCyclePrevious => t!("action.paste_cycle_previous"),
+            Action::PasteCycleNext => t!("action.paste_cycle_next"),
             Action::CopyFilePath => t!("action.copy_file_path"),
             Action::CopyRelativeFilePath => t!("action.copy_relative_file_path"),
             Action::YankWordForward => t!("action.yank_word_forward"),
@@ -2215,23 +2325,33 @@ impl KeybindingResolver {
             Action::AddCursorAbove => t!("action.add_cursor_above"),
             Action::AddCursorBelow => t!("action.add_cursor_below"),
             Action::AddCursorNextMatch => t!("action.add_cursor_next_match"),
+            Action::SelectAllOccurrences => t!("action.select_all_occurrences"),
             Action::RemoveSecondaryCursors => t!("action.remove_secondary_cursors"),
             Action::Save => t!("action.save"),
             Action::SaveAs => t!("action.save_as"),
+            Action::RenameFile => t!("action.rename_file"),
             Action::Open => t!("action.open"),
+            Action::OpenRecentFile => t!("action.open_recent_file"),
             Action::SwitchProject => t!("action.switch_project"),
             Action::New => t!("action.new"),
             Action::Close => t!("action.close"),
             Action::CloseTab => t!("action.close_tab"),
+            Action::TogglePinTab => t!("action.toggle_pin_tab"),
             Action::Quit => t!("action.quit"),
             Action::ForceQuit => t!("action.force_quit"),
             Action::Detach => t!("action.detach"),
             Action::Revert => t!("action.revert"),
+            Action::DiffAgainstDisk => t!("action.diff_against_disk"),
             Action::ToggleAutoRevert => t!("action.toggle_auto_revert"),
             Action::FormatBuffer => t!("action.format_buffer"),
             Action::TrimTrailingWhitespace => t!("action.trim_trailing_whitespace"),
             Action::EnsureFinalNewline => t!("action.ensure_final_newline"),
+            Action::RevertHunkAtCursor => t!("action.revert_hunk_at_cursor"),
+            Action::StageHunks => t!("action.stage_hunks"),
+            Action::ToggleGitBlame => t!("action.toggle_git_blame"),
             Action::GotoLine => t!("action.goto_line"),
+            Action::OpenFileUnderCursor => t!("action.open_file_under_cursor"),
+            Action::OpenUrlUnderCursor => t!("action.open_url_under_cursor"),
             Action::ScanLineIndex => t!("action.scan_line_index"),
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
             Action::JumpToNextError => t!("action.jump_to_next_error"),
@@ -2239,6 +2359,7 @@ impl KeybindingResolver {
             Action::SmartHome => t!("action.smart_home"),
             Action::DedentSelection => t!("action.dedent_selection"),
             Action::ToggleComment => t!("action.toggle_comment"),
+            Action::ToggleBlockComment => t!("action.toggle_block_comment"),
             Action::DabbrevExpand => std::borrow::Cow::Borrowed("Expand abbreviation (dabbrev)"),
             Action::ToggleFold => t!("action.toggle_fold"),
             Action::SetBookmark(c) => t!("action.set_bookmark", key = c),
@@ -2268,6 +2389,8 @@ impl KeybindingResolver {
             Action::ShowKeyboardShortcuts => t!("action.show_keyboard_shortcuts"),
             Action::ShowWarnings => t!("action.show_warnings"),
             Action::ShowStatusLog => t!("action.show_status_log"),
+            Action::ShowNotificationLog => t!("action.show_notification_log"),
+            Action::DismissNotifications => t!("action.dismiss_notifications"),
             Action::ShowLspStatus => t!("action.show_lsp_status"),
             Action::ShowRemoteIndicatorMenu => t!("action.show_remote_indicator_menu"),
             Action::ClearWarnings => t!("action.clear_warnings"),
@@ -2388,8 +2511,12 @@ impl KeybindingResolver {
             Action::LspStop => t!("action.lsp_stop"),
             Action::LspToggleForBuffer => t!("action.lsp_toggle_for_buffer"),
             Action::ToggleInlayHints => t!("action.toggle_inlay_hints"),
+            Action::ToggleDiagnostics => t!("action.toggle_diagnostics"),
+            Action::ExplainDiagnostic => t!("action.explain_diagnostic"),
             Action::ToggleMouseHover => t!("action.toggle_mouse_hover"),
             Action::ToggleLineNumbers => t!("action.toggle_line_numbers"),
+            Action::CycleLineNumberMode => t!("action.cycle_line_number_mode"),
+            Action::ToggleBreakpoint => t!("action.toggle_breakpoint"),
             Action::ToggleScrollSync => t!("action.toggle_scroll_sync"),
             Action::ToggleMouseCapture => t!("action.toggle_mouse_capture"),
             Action::ToggleDebugHighlights => t!("action.toggle_debug_highlights"),
@@ -2403,10 +2530,15 @@ impl KeybindingResolver {
             Action::ReloadWithEncoding => t!("action.reload_with_encoding"),
             Action::SetLanguage => t!("action.set_language"),
             Action::ToggleIndentationStyle => t!("action.toggle_indentation_style"),
+            Action::ConvertIndentationToSpaces => t!("action.convert_indentation_to_spaces"),
+            Action::ConvertIndentationToTabs => t!("action.convert_indentation_to_tabs"),
+            Action::ToggleSpellCheck => t!("action.toggle_spell_check"),
+            Action::SpellingSuggestions => t!("action.spelling_suggestions"),
             Action::ToggleTabIndicators => t!("action.toggle_tab_indicators"),
             Action::ToggleWhitespaceIndicators => t!("action.toggle_whitespace_indicators"),
             Action::ResetBufferSettings => t!("action.reset_buffer_settings"),
             Action::DumpConfig => t!("action.dump_config"),
+            Action::ReloadConfig => t!("action.reload_config"),
             Action::RedrawScreen => t!("action.redraw_screen"),
             Action::Search => t!("action.search"),
             Action::FindInSelection => t!("action.find_in_selection"),
@@ -2429,10 +2561,15 @@ impl KeybindingResolver {
             Action::ScrollTabsLeft => t!("action.scroll_tabs_left"),
             Action::ScrollTabsRight => t!("action.scroll_tabs_right"),
             Action::SelectTheme => t!("action.select_theme"),
+            Action::SetTheme(name) if name.is_empty() => t!("action.select_theme"),
+            Action::SetTheme(name) => t!("action.set_theme", name = name),
+            Action::ReloadTheme => t!("action.reload_theme"),
             Action::SelectKeybindingMap => t!("action.select_keybinding_map"),
             Action::SelectCursorStyle => t!("action.select_cursor_style"),
             Action::SelectLocale => t!("action.select_locale"),
             Action::SwitchToPreviousTab => t!("action.switch_to_previous_tab"),
+            Action::CycleRecentBuffers => t!("action.cycle_recent_buffers"),
+            Action::CycleRecentBuffersReverse => t!("action.cycle_recent_buffers_reverse"),
             Action::SwitchToTabByName => t!("action.switch_to_tab_by_name"),
             Action::OpenTerminal => t!("action.open_terminal"),
             Action::CloseTerminal => t!("action.close_terminal"),
@@ -2453,10 +2590,19 @@ impl KeybindingResolver {
             Action::SettingsInherit => t!("action.settings_inherit"),
             Action::ShellCommand => t!("action.shell_command"),
             Action::ShellCommandReplace => t!("action.shell_command_replace"),
+            Action::RunTask => t!("action.run_task"),
+            Action::ShowCommandHistory => t!("action.show_command_history"),
             Action::ToUpperCase => t!("action.to_uppercase"),
             Action::ToLowerCase => t!("action.to_lowercase"),
+            Action::ToTitleCase => t!("action.to_titlecase"),
             Action::ToggleCase => t!("action.to_uppercase"),
             Action::SortLines => t!("action.sort_lines"),
+            Action::SortLinesDescending => t!("action.sort_lines_descending"),
+            Action::SortLinesCaseInsensitive => t!("action.sort_lines_case_insensitive"),
+            Action::SortLinesCaseInsensitiveDescending => {
+                t!("action.sort_lines_case_insensitive_descending")
+            }
+            Action::DedupeLines => t!("action.dedupe_lines"),
             Action::CalibrateInput => t!("action.calibrate_input"),
             Action::EventDebug => t!("action.event_debug"),
             Action::SuspendProcess => t!("action.suspend_process"),
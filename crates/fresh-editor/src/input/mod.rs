@@ -16,6 +16,7 @@ mod line_move;
 pub mod multi_cursor;
 pub mod position_history;
 pub mod quick_open;
+pub mod recent_files;
 
 #[cfg(test)]
 pub mod tests_language_features;
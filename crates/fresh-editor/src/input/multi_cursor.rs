@@ -3,6 +3,7 @@
 use crate::model::cursor::{Cursor, Cursors};
 use crate::primitives::word_navigation::{find_word_end, find_word_start};
 use crate::state::EditorState;
+use std::ops::Range;
 
 /// Result of attempting to add a cursor
 pub enum AddCursorResult {
@@ -63,6 +64,44 @@ fn adjust_position_for_newline(state: &mut EditorState, position: usize) -> usiz
     position
 }
 
+/// Range of the word at `cursor_pos`, as used by Ctrl+D and "select all
+/// occurrences". Empty when the cursor is on whitespace or punctuation.
+fn word_range_at(state: &mut EditorState, cursor_pos: usize) -> (usize, usize) {
+    let word_start = find_word_start(&state.buffer, cursor_pos);
+
+    // Determine word_end: if we're just past a word (at a non-word char but
+    // word_start < cursor_pos), use cursor_pos as the end. This handles the
+    // case where cursor is at the space right after a word.
+    let word_end = if word_start < cursor_pos {
+        // Check if we're at a word character
+        let at_word_char = if cursor_pos < state.buffer.len() {
+            if let Ok(bytes) = state.buffer.get_text_range_mut(cursor_pos, 1) {
+                bytes
+                    .first()
+                    .map(|&b| crate::primitives::word_navigation::is_word_char(b))
+                    .unwrap_or(false)
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+
+        if at_word_char {
+            // We're in the middle of a word, find the actual end
+            find_word_end(&state.buffer, cursor_pos)
+        } else {
+            // We're just past a word, use cursor position as end
+            cursor_pos
+        }
+    } else {
+        // word_start == cursor_pos, find the end normally
+        find_word_end(&state.buffer, cursor_pos)
+    };
+
+    (word_start, word_end)
+}
+
 /// Add a cursor at the next occurrence of the selected text
 /// If no selection, selects the entire word at cursor position first
 pub fn add_cursor_at_next_match(state: &mut EditorState, cursors: &Cursors) -> AddCursorResult {
@@ -72,38 +111,7 @@ pub fn add_cursor_at_next_match(state: &mut EditorState, cursors: &Cursors) -> A
         Some(range) => range,
         None => {
             // No selection - select the entire word at cursor position
-            let cursor_pos = primary.position;
-            let word_start = find_word_start(&state.buffer, cursor_pos);
-
-            // Determine word_end: if we're just past a word (at a non-word char but
-            // word_start < cursor_pos), use cursor_pos as the end. This handles the
-            // case where cursor is at the space right after a word.
-            let word_end = if word_start < cursor_pos {
-                // Check if we're at a word character
-                let at_word_char = if cursor_pos < state.buffer.len() {
-                    if let Ok(bytes) = state.buffer.get_text_range_mut(cursor_pos, 1) {
-                        bytes
-                            .first()
-                            .map(|&b| crate::primitives::word_navigation::is_word_char(b))
-                            .unwrap_or(false)
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                };
-
-                if at_word_char {
-                    // We're in the middle of a word, find the actual end
-                    find_word_end(&state.buffer, cursor_pos)
-                } else {
-                    // We're just past a word, use cursor position as end
-                    cursor_pos
-                }
-            } else {
-                // word_start == cursor_pos, find the end normally
-                find_word_end(&state.buffer, cursor_pos)
-            };
+            let (word_start, word_end) = word_range_at(state, primary.position);
 
             // If cursor is on whitespace or punctuation (word_start == word_end), fail
             if word_start == word_end {
@@ -199,6 +207,115 @@ pub fn add_cursor_at_next_match(state: &mut EditorState, cursors: &Cursors) -> A
     }
 }
 
+/// Most cursors "select all occurrences" will create. Beyond this the
+/// remaining matches are left alone, so a one-letter word in a huge file
+/// can't produce an unmanageable number of cursors.
+pub const MAX_OCCURRENCE_CURSORS: usize = 1000;
+
+/// Result of selecting all occurrences of the word under the cursor
+pub enum SelectOccurrencesResult {
+    /// Non-overlapping ranges to select, in buffer order
+    Selected {
+        ranges: Vec<Range<usize>>,
+        /// Index in `ranges` of the occurrence the primary cursor was on
+        primary_index: usize,
+        /// Whether more matches exist beyond `MAX_OCCURRENCE_CURSORS`
+        capped: bool,
+    },
+    /// Operation failed with a message
+    Failed { message: String },
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether `start..end` is delimited by non-word characters on both sides
+fn is_whole_word(state: &EditorState, start: usize, end: usize) -> bool {
+    let before = state.buffer.slice_bytes(start.saturating_sub(4)..start);
+    let after_end = (end + 4).min(state.buffer.len());
+    let after = state.buffer.slice_bytes(end..after_end);
+    let before_ok = String::from_utf8_lossy(&before)
+        .chars()
+        .last()
+        .is_none_or(|c| !is_word_char(c));
+    let after_ok = String::from_utf8_lossy(&after)
+        .chars()
+        .next()
+        .is_none_or(|c| !is_word_char(c));
+    before_ok && after_ok
+}
+
+/// Find every occurrence of the primary selection, or of the word under
+/// the primary cursor, for placing one cursor on each. A word from under
+/// the cursor only matches as a whole word; an explicit selection matches
+/// anywhere. Matches never overlap and are capped at
+/// `MAX_OCCURRENCE_CURSORS`.
+pub fn select_all_occurrences(
+    state: &mut EditorState,
+    cursors: &Cursors,
+) -> SelectOccurrencesResult {
+    let primary = cursors.primary();
+    let (origin, whole_word) = match primary.selection_range() {
+        Some(range) => (range, false),
+        None => {
+            let (word_start, word_end) = word_range_at(state, primary.position);
+            if word_start == word_end {
+                return SelectOccurrencesResult::Failed {
+                    message: "No word at cursor position".to_string(),
+                };
+            }
+            (word_start..word_end, true)
+        }
+    };
+    let pattern = state.get_text_range(origin.start, origin.end);
+    let pattern_len = pattern.len();
+
+    // Start at the primary's own occurrence so it is always included, then
+    // walk forward, wrapping around once.
+    let mut found = vec![origin.clone()];
+    let mut capped = false;
+    let mut search_start = origin.end;
+    let mut wrapped = false;
+    while let Some(pos) = state.buffer.find_next(&pattern, search_start) {
+        if pos < search_start {
+            wrapped = true;
+        }
+        if wrapped && pos + pattern_len > origin.start {
+            // Back at (or overlapping) the primary's occurrence
+            break;
+        }
+        if whole_word && !is_whole_word(state, pos, pos + pattern_len) {
+            search_start = pos + 1;
+            continue;
+        }
+        if found.len() >= MAX_OCCURRENCE_CURSORS {
+            capped = true;
+            break;
+        }
+        found.push(pos..pos + pattern_len);
+        search_start = pos + pattern_len;
+    }
+
+    found.sort_by_key(|r| r.start);
+    // Matches from before and after the wrap point can still touch each
+    // other; keep the earlier one
+    let mut ranges: Vec<Range<usize>> = Vec::with_capacity(found.len());
+    for range in found {
+        if ranges.last().is_some_and(|last| range.start < last.end) {
+            continue;
+        }
+        ranges.push(range);
+    }
+    let primary_index = ranges.iter().position(|r| *r == origin).unwrap_or_default();
+
+    SelectOccurrencesResult::Selected {
+        ranges,
+        primary_index,
+        capped,
+    }
+}
+
 /// Add a cursor above the primary cursor at the same column
 pub fn add_cursor_above(state: &mut EditorState, cursors: &Cursors) -> AddCursorResult {
     let position = cursors.primary().position;
@@ -49,6 +49,8 @@ pub enum GotoLineTarget {
     Absolute(usize),
     /// Signed offset from the current cursor line (input had `+`/`-` prefix).
     Relative(isize),
+    /// Percentage through the file (input had a trailing `%`).
+    Percent(usize),
 }
 
 /// Parse a goto-line input string.
@@ -56,15 +58,24 @@ pub enum GotoLineTarget {
 /// - `"500"` → `Absolute(500)`
 /// - `"+3"` → `Relative(3)`
 /// - `"-3"` → `Relative(-3)`
-/// - `"0"`, `"+0"`, `"-0"`, `""`, `"abc"` → `None`
+/// - `"50%"` → `Percent(50)`
+/// - `":42"` → `Absolute(42)` (vim-style leading colon)
+/// - `"0"`, `"+0"`, `"-0"`, `"+5%"`, `""`, `"abc"` → `None`
 ///
 /// Whitespace around the input is ignored. The leading-sign convention is
 /// independent of any display setting: the user's literal input decides.
 pub fn parse_goto_line_input(input: &str) -> Option<GotoLineTarget> {
     let trimmed = input.trim();
+    let trimmed = trimmed.strip_prefix(':').unwrap_or(trimmed).trim_start();
     if trimmed.is_empty() {
         return None;
     }
+    if let Some(percent) = trimmed.strip_suffix('%') {
+        if percent.starts_with('+') || percent.starts_with('-') {
+            return None;
+        }
+        return percent.parse::<usize>().ok().map(GotoLineTarget::Percent);
+    }
     if let Some(rest) = trimmed
         .strip_prefix('+')
         .or_else(|| trimmed.strip_prefix('-'))
@@ -328,6 +339,30 @@ mod tests {
         assert_eq!(parse_goto_line_input("+-3"), None);
         assert_eq!(parse_goto_line_input("abc"), None);
         assert_eq!(parse_goto_line_input("3a"), None);
+        assert_eq!(parse_goto_line_input(":"), None);
+        assert_eq!(parse_goto_line_input("%"), None);
+        assert_eq!(parse_goto_line_input("+5%"), None);
+        assert_eq!(parse_goto_line_input("5%%"), None);
+    }
+
+    #[test]
+    fn parse_goto_line_input_colon_and_percent() {
+        assert_eq!(
+            parse_goto_line_input(":42"),
+            Some(GotoLineTarget::Absolute(42))
+        );
+        assert_eq!(
+            parse_goto_line_input(": -2"),
+            Some(GotoLineTarget::Relative(-2))
+        );
+        assert_eq!(
+            parse_goto_line_input("50%"),
+            Some(GotoLineTarget::Percent(50))
+        );
+        assert_eq!(
+            parse_goto_line_input(" 0% "),
+            Some(GotoLineTarget::Percent(0))
+        );
     }
 
     #[test]
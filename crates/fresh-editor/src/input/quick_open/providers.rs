@@ -225,6 +225,9 @@ impl QuickOpenProvider for GotoLineProvider {
                         // Format with explicit sign so "+3" reads back as "+3", not "3".
                         t!("quick_open.goto_line", line = format!("{:+}", d)).to_string()
                     }
+                    GotoLineTarget::Percent(p) => {
+                        t!("quick_open.goto_line", line = format!("{}%", p)).to_string()
+                    }
                 };
                 vec![Suggestion::new(label)
                     .with_description(t!("quick_open.press_enter").to_string())
@@ -0,0 +1,133 @@
+//! Recently opened files, kept across sessions
+//!
+//! The list is most-recent-first, holds each path once and is capped at
+//! [`RecentFiles::DEFAULT_MAX_SIZE`] entries. It is stored as a JSON array of
+//! paths in the data directory, rewritten shortly after a file is opened so a
+//! crash loses at most the last few opens. Paths that no longer exist are
+//! dropped on load.
+
+use std::path::{Path, PathBuf};
+
+/// Most-recently-opened files, newest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentFiles {
+    paths: Vec<PathBuf>,
+    max_size: usize,
+}
+
+impl RecentFiles {
+    /// Default maximum number of remembered files
+    pub const DEFAULT_MAX_SIZE: usize = 50;
+
+    /// Create an empty list with the default capacity
+    pub fn new() -> Self {
+        Self {
+            paths: Vec::new(),
+            max_size: Self::DEFAULT_MAX_SIZE,
+        }
+    }
+
+    /// Record `path` as the most recently opened file, moving it to the
+    /// front if it was already listed
+    pub fn push(&mut self, path: PathBuf) {
+        self.paths.retain(|p| p != &path);
+        self.paths.insert(0, path);
+        self.paths.truncate(self.max_size);
+    }
+
+    /// Remembered files, most recent first
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Check if no files are remembered
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Save the list to a file
+    pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.paths).map_err(std::io::Error::other)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load the list from a file, leaving out files that no longer exist
+    pub fn load_from_file(path: &Path) -> std::io::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+
+        let json = std::fs::read_to_string(path)?;
+        let paths: Vec<PathBuf> = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        let mut recent = Self::new();
+        for path in paths {
+            if path.is_file() && !recent.paths.contains(&path) {
+                recent.paths.push(path);
+            }
+        }
+        recent.paths.truncate(recent.max_size);
+        Ok(recent)
+    }
+}
+
+impl Default for RecentFiles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_moves_existing_path_to_front() {
+        let mut recent = RecentFiles::new();
+        recent.push(PathBuf::from("/a"));
+        recent.push(PathBuf::from("/b"));
+        recent.push(PathBuf::from("/a"));
+
+        assert_eq!(recent.paths(), &[PathBuf::from("/a"), PathBuf::from("/b")]);
+    }
+
+    #[test]
+    fn test_push_drops_oldest_past_capacity() {
+        let mut recent = RecentFiles::new();
+        for i in 0..=RecentFiles::DEFAULT_MAX_SIZE {
+            recent.push(PathBuf::from(format!("/file{}", i)));
+        }
+
+        assert_eq!(recent.paths().len(), RecentFiles::DEFAULT_MAX_SIZE);
+        assert_eq!(
+            recent.paths()[0],
+            PathBuf::from(format!("/file{}", RecentFiles::DEFAULT_MAX_SIZE))
+        );
+        assert!(!recent.paths().contains(&PathBuf::from("/file0")));
+    }
+
+    #[test]
+    fn test_load_prunes_missing_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let kept = temp_dir.path().join("kept.txt");
+        std::fs::write(&kept, "").unwrap();
+        let deleted = temp_dir.path().join("deleted.txt");
+        std::fs::write(&deleted, "").unwrap();
+
+        let mut recent = RecentFiles::new();
+        recent.push(kept.clone());
+        recent.push(deleted.clone());
+        let state_file = temp_dir.path().join("recent_files.json");
+        recent.save_to_file(&state_file).unwrap();
+        std::fs::remove_file(&deleted).unwrap();
+
+        let loaded = RecentFiles::load_from_file(&state_file).unwrap();
+        assert_eq!(loaded.paths(), &[kept]);
+    }
+}
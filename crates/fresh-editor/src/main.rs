@@ -6,8 +6,8 @@
 use anyhow::{Context, Result as AnyhowResult};
 use clap::{CommandFactory, FromArgMatches, Parser};
 use crossterm::event::{
-    poll as event_poll, read as event_read, Event as CrosstermEvent, KeyEvent, KeyEventKind,
-    MouseEvent,
+    poll as event_poll, read as event_read, Event as CrosstermEvent, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, MouseEvent,
 };
 use fresh::input::key_translator::KeyTranslator;
 #[cfg(target_os = "linux")]
@@ -58,6 +58,11 @@ struct Cli {
     #[arg(short = 'a', long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
     attach: Option<String>,
 
+    /// When attached to a session, detach after this many seconds without
+    /// keyboard input. The session keeps running.
+    #[arg(long, value_name = "SECONDS")]
+    idle_detach: Option<u64>,
+
     /// Read content from stdin (alternative to using "-" as filename)
     #[arg(long)]
     stdin: bool,
@@ -175,9 +180,13 @@ struct Args {
     ssh_url: Option<String>,
     // Session-related fields (set via subcommands or -a shortcut)
     attach: bool,
+    /// Detach an attached client after this long without keyboard input
+    idle_detach: Option<Duration>,
     list_sessions: bool,
     session_name: Option<String>,
     kill: Option<Option<String>>,
+    /// Print a session's server metrics (`session info [NAME]`)
+    session_info: Option<Option<String>>,
     /// Open files in a session without attaching (session_name, files, wait)
     open_files_in_session: Option<(Option<String>, Vec<String>, bool)>,
     /// Launch in GUI mode
@@ -198,6 +207,20 @@ impl From<Cli> for Args {
             false
         };
 
+        // Session info prints the server's metrics (handled via session_info)
+        let session_info = if !cli.cmd.is_empty() {
+            let cmd_args: Vec<&str> = cli.cmd.iter().map(|s| s.as_str()).collect();
+            match cmd_args.as_slice() {
+                ["session", "info", name, ..] | ["s", "info", name, ..] => {
+                    Some(Some((*name).to_string()))
+                }
+                ["session", "info"] | ["s", "info"] => Some(None),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
         // Parse --cmd arguments to determine command
         let (
             list_sessions,
@@ -318,14 +341,10 @@ impl From<Cli> for Args {
                     None,
                 ),
 
-                ["session", "info", name, ..] | ["s", "info", name, ..] => {
-                    // Info not fully implemented, treat as list for now
-                    let _ = name;
-                    (true, None, false, None, false, false, None, cli.files, None)
-                }
-                ["session", "info"] | ["s", "info"] => {
-                    (true, None, false, None, false, false, None, cli.files, None)
-                }
+                // Session info (handled via session_info above)
+                ["session", "info", ..] | ["s", "info", ..] => (
+                    false, None, false, None, false, false, None, cli.files, None,
+                ),
                 // Config commands
                 ["config", "show"] | ["config", "dump"] => {
                     (false, None, false, None, true, false, None, cli.files, None)
@@ -421,9 +440,11 @@ impl From<Cli> for Args {
             server: cli.server,
             ssh_url: cli.ssh_url,
             attach,
+            idle_detach: cli.idle_detach.map(Duration::from_secs),
             list_sessions,
             session_name,
             kill,
+            session_info,
             open_files_in_session,
             #[cfg(feature = "gui")]
             gui: cli.gui,
@@ -2461,55 +2482,28 @@ fn list_grammars_command() -> AnyhowResult<()> {
 }
 
 fn list_sessions_command() -> AnyhowResult<()> {
-    let socket_dir = SocketPaths::socket_directory()?;
-
-    if !socket_dir.exists() {
-        println!("No active sessions.");
-        return Ok(());
-    }
+    let list = fresh::server::ipc::list_sessions()?;
 
     let mut sessions = Vec::new();
-    let mut stale_cleaned = 0;
-
-    for entry in std::fs::read_dir(&socket_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let filename = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-
-        // Look for control sockets (*.ctrl.sock)
-        if let Some(name) = filename.strip_suffix(".ctrl.sock") {
-            // Get socket paths for this session to check if server is alive
-            let socket_paths = SocketPaths::for_session_name(name)?;
-
-            // Check if server is actually running, clean up if stale
-            if socket_paths.cleanup_if_stale() {
-                stale_cleaned += 1;
-                continue;
-            }
-
-            // Only show sessions with running servers
-            if !socket_paths.is_server_alive() {
-                continue;
-            }
-
-            // Try to decode the session name (for working-dir based sessions)
-            // Only show the decoded path if it looks like a real absolute path
-            let display_name = if let Some(decoded_path) = workspace::decode_filename_to_path(name)
-            {
-                // Only use decoded path if it has more than one component
-                // (i.e., not just "/<name>" which happens with simple session names)
-                if decoded_path.components().count() > 2 {
-                    decoded_path.display().to_string()
-                } else {
-                    name.to_string()
-                }
+    for session in &list.sessions {
+        let name = session.id.as_str();
+        // Try to decode the session name (for working-dir based sessions)
+        // Only show the decoded path if it looks like a real absolute path
+        let display_name = if let Some(decoded_path) = workspace::decode_filename_to_path(name) {
+            // Only use decoded path if it has more than one component
+            // (i.e., not just "/<name>" which happens with simple session names)
+            if decoded_path.components().count() > 2 {
+                decoded_path.display().to_string()
             } else {
                 name.to_string()
-            };
+            }
+        } else {
+            name.to_string()
+        };
 
-            sessions.push((name.to_string(), display_name));
-        }
+        sessions.push((name.to_string(), display_name));
     }
+    let stale_cleaned = list.stale_cleaned;
 
     if stale_cleaned > 0 {
         eprintln!("Cleaned up {} stale session(s).", stale_cleaned);
@@ -2548,8 +2542,14 @@ fn list_sessions_command() -> AnyhowResult<()> {
 
 /// Kill a session (terminate the server)
 fn kill_session_command(session: Option<&str>, args: &Args) -> AnyhowResult<()> {
-    use fresh::server::ipc::ClientConnection;
-    use fresh::server::protocol::ClientControl;
+    if session == Some("--all") {
+        let list = fresh::server::ipc::list_sessions()?;
+        for info in &list.sessions {
+            fresh::server::ipc::kill_session_at(&info.paths)?;
+        }
+        println!("Terminated {} session(s).", list.sessions.len());
+        return Ok(());
+    }
 
     let working_dir = std::env::current_dir()?;
 
@@ -2559,59 +2559,23 @@ fn kill_session_command(session: Option<&str>, args: &Args) -> AnyhowResult<()>
         None => SocketPaths::for_working_dir(&working_dir)?,
     };
 
-    if !socket_paths.data.exists() || !socket_paths.control.exists() {
+    if fresh::server::ipc::kill_session_at(&socket_paths)? {
+        println!("Session terminated.");
+    } else {
         eprintln!("No session found to kill.");
-        return Ok(());
-    }
-
-    // Connect and send quit command
-    let conn = ClientConnection::connect(&socket_paths)?;
-
-    // We need to do a minimal handshake first
-    use fresh::server::protocol::{ClientHello, TermSize};
-    let hello = ClientHello::new(TermSize::new(80, 24));
-    let hello_json = serde_json::to_string(&ClientControl::Hello(hello))?;
-    conn.write_control(&hello_json)?;
-
-    // Read server response (we don't care about version mismatch here)
-    let _ = conn.read_control()?;
-
-    // Send quit command
-    let quit_msg = serde_json::to_string(&ClientControl::Quit)?;
-    conn.write_control(&quit_msg)?;
-
-    // Wait for server to close the connection (indicates shutdown)
-    conn.set_data_nonblocking(false)?;
-    let mut buf = [0u8; 1024];
-    let timeout = std::time::Duration::from_secs(5);
-    let start = std::time::Instant::now();
-
-    // Read until EOF or timeout
-    while start.elapsed() < timeout {
-        match conn.read_data(&mut buf) {
-            Ok(0) => break,    // EOF - server closed connection
-            Ok(_) => continue, // Keep draining
-            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                std::thread::sleep(std::time::Duration::from_millis(50));
-            }
-            Err(_) => break, // Error - connection closed
-        }
     }
+    Ok(())
+}
 
-    // Clean up stale socket files if they still exist
-    std::thread::sleep(std::time::Duration::from_millis(100));
-    if socket_paths.data.exists() {
-        // Best-effort cleanup of stale socket files
-        #[allow(clippy::let_underscore_must_use)]
-        let _ = std::fs::remove_file(&socket_paths.data);
-    }
-    if socket_paths.control.exists() {
-        // Best-effort cleanup of stale socket files
-        #[allow(clippy::let_underscore_must_use)]
-        let _ = std::fs::remove_file(&socket_paths.control);
+/// Print a running session's metrics as JSON
+fn session_info_command(session: Option<&str>) -> AnyhowResult<()> {
+    let socket_paths = resolve_session(session)?;
+    if !socket_paths.exists() {
+        eprintln!("No session found.");
+        return Ok(());
     }
-
-    println!("Session terminated.");
+    let stats = fresh::server::ipc::query_session_stats(&socket_paths)?;
+    println!("{}", serde_json::to_string_pretty(&stats)?);
     Ok(())
 }
 
@@ -2619,14 +2583,28 @@ fn kill_session_command(session: Option<&str>, args: &Args) -> AnyhowResult<()>
 fn run_server_command(args: &Args) -> AnyhowResult<()> {
     use fresh::server::{EditorServer, EditorServerConfig};
 
-    // Initialize tracing to stderr (will go to log file when spawned detached)
-    use tracing_subscriber::{fmt, EnvFilter};
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("debug"));
-    fmt()
-        .with_env_filter(filter)
-        .with_writer(std::io::stderr)
-        .with_ansi(false)
-        .init();
+    // Log to the session's rotating log file (the detached server has no
+    // stderr). `--log-file` overrides the path, `RUST_LOG` the level.
+    let session_id = match &args.session_name {
+        Some(name) => name.clone(),
+        None => workspace::encode_path_for_filename(&std::env::current_dir()?),
+    };
+    let log_path = args
+        .log_file
+        .clone()
+        .unwrap_or_else(|| fresh::services::log_dirs::session_log_path(&session_id));
+    let log_config = tracing_setup::SessionLogConfig::new(log_path);
+    if let Err(e) = tracing_setup::init_session(&log_config) {
+        eprintln!(
+            "[server] Failed to open session log {:?}: {}",
+            log_config.path, e
+        );
+    }
+    tracing::info!(
+        "Session server starting for {} (v{})",
+        session_id,
+        env!("CARGO_PKG_VERSION")
+    );
 
     eprintln!(
         "[server] Starting server process for session {:?}",
@@ -2680,7 +2658,10 @@ fn run_server_command(args: &Args) -> AnyhowResult<()> {
     let config = EditorServerConfig {
         working_dir: working_dir.clone(),
         session_name: args.session_name.clone(),
-        idle_timeout: Some(std::time::Duration::from_secs(3600)), // 1 hour default
+        idle_timeout: match editor_config.editor.session_idle_shutdown_secs {
+            0 => None,
+            secs => Some(std::time::Duration::from_secs(secs)),
+        },
         editor_config,
         dir_context,
         plugins_enabled: !args.no_plugins,
@@ -2748,6 +2729,7 @@ fn run_open_files_command(
     session_name: Option<&str>,
     files: &[String],
     wait: bool,
+    idle_detach: Option<Duration>,
 ) -> AnyhowResult<()> {
     use fresh::server::daemon::is_process_running;
     use fresh::server::protocol::{
@@ -2862,7 +2844,7 @@ fn run_open_files_command(
         // the files have been queued.
         drop(conn);
         if std::io::IsTerminal::is_terminal(&std::io::stdin()) {
-            return run_attach(session_name, &[]);
+            return run_attach(session_name, &[], idle_detach);
         } else {
             eprintln!(
                 "Started new session and opened {} file(s). Attach with: fresh -a{}",
@@ -2896,10 +2878,14 @@ fn run_open_files_command(
 
 /// Attach to an existing session, starting a server if needed
 fn run_attach_command(args: &Args) -> AnyhowResult<()> {
-    run_attach(args.session_name.as_deref(), &args.files)
+    run_attach(args.session_name.as_deref(), &args.files, args.idle_detach)
 }
 
-fn run_attach(session_name: Option<&str>, files: &[String]) -> AnyhowResult<()> {
+fn run_attach(
+    session_name: Option<&str>,
+    files: &[String],
+    idle_detach: Option<Duration>,
+) -> AnyhowResult<()> {
     use crossterm::terminal::enable_raw_mode;
     use fresh::server::protocol::{
         ClientControl, ClientHello, ServerControl, TermSize, PROTOCOL_VERSION,
@@ -3059,7 +3045,7 @@ fn run_attach(session_name: Option<&str>, files: &[String]) -> AnyhowResult<()>
     enable_raw_mode()?;
 
     // Run the client relay loop (handshake already done)
-    let result = client::run_client_relay(conn);
+    let result = client::run_client_relay(conn, idle_detach);
 
     // Best-effort: restore terminal state before printing any messages.
     // The server sends terminal setup sequences (alternate screen, mouse capture, etc.)
@@ -3126,6 +3112,7 @@ fn is_interactive_launch(args: &Args) -> bool {
         && !args.server
         && !args.list_sessions
         && args.kill.is_none()
+        && args.session_info.is_none()
         && args.open_files_in_session.is_none()
         && args.init.is_none()
         && !args.list_grammars
@@ -3185,6 +3172,9 @@ fn run_if_subcommand(args: &Args) -> Option<AnyhowResult<()>> {
     if let Some(ref session) = args.kill {
         return Some(kill_session_command(session.as_deref(), args));
     }
+    if let Some(ref session) = args.session_info {
+        return Some(session_info_command(session.as_deref()));
+    }
     if args.server {
         return Some(run_server_command(args));
     }
@@ -3193,6 +3183,7 @@ fn run_if_subcommand(args: &Args) -> Option<AnyhowResult<()>> {
             session_name.as_deref(),
             files,
             *wait,
+            args.idle_detach,
         ));
     }
     if args.attach {
@@ -3318,6 +3309,10 @@ fn build_localized_after_help() -> String {
         "  session kill [NAME]       {}\n",
         t("cli.cmd.session_kill")
     ));
+    out.push_str(&format!(
+        "  session info [NAME]       {}\n",
+        t("cli.cmd.session_info")
+    ));
     out.push_str(&format!(
         "  session open-file NAME FILES [--wait]   {}\n",
         t("cli.cmd.session_open_file")
@@ -3942,7 +3937,7 @@ where
 
         if editor.should_quit() {
             // Auto-save file-backed buffers to disk before exiting
-            if editor.config().editor.auto_save_enabled {
+            if editor.config().editor.auto_save_mode() != config::AutoSaveMode::Off {
                 match editor.save_all_on_exit() {
                     Ok(count) if count > 0 => {
                         tracing::info!("Auto-saved {} buffer(s) on exit", count);
@@ -3959,6 +3954,7 @@ where
             if let Err(e) = editor.end_recovery_session() {
                 tracing::warn!("Failed to end recovery session: {}", e);
             }
+            editor.save_recent_files();
             if workspace_enabled {
                 if let Err(e) = editor.save_workspace() {
                     tracing::warn!("Failed to save workspace: {}", e);
@@ -4044,11 +4040,25 @@ where
                         modifiers = ?key_event.modifiers,
                     )
                     .entered();
+                    // Without keyboard enhancement crossterm reports a bare
+                    // LF as Ctrl+J. Treat it as Enter, as the session input
+                    // parser does; Ctrl+J itself is only distinct when the
+                    // terminal reports it as an enhanced key.
+                    let key_event = if !terminal_modes.keyboard_enhancement_enabled()
+                        && key_event.code == KeyCode::Char('j')
+                        && key_event.modifiers == KeyModifiers::CONTROL
+                    {
+                        KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)
+                    } else {
+                        key_event
+                    };
                     // Apply key translation (for input calibration)
                     // Use editor's translator so calibration changes take effect immediately
                     let translated_event = editor.key_translator().translate(key_event);
                     handle_key_event(editor, translated_event)?;
                     needs_render = true;
+                } else if key_event.kind == KeyEventKind::Release {
+                    editor.handle_key_release(key_event.code, key_event.modifiers);
                 }
             }
             CrosstermEvent::Mouse(mouse_event) => {
@@ -4069,6 +4079,10 @@ where
                 editor.focus_gained();
                 needs_render = true;
             }
+            CrosstermEvent::FocusLost => {
+                editor.focus_lost();
+                needs_render = true;
+            }
             _ => {}
         }
     }
@@ -4232,6 +4246,17 @@ fn coalesce_mouse_moves(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_idle_detach_flag_reaches_attach_args() {
+        let cli = Cli::try_parse_from(["fresh", "-a", "work", "--idle-detach", "30"]).unwrap();
+        let args = Args::from(cli);
+        assert!(args.attach);
+        assert_eq!(args.idle_detach, Some(Duration::from_secs(30)));
+
+        let args = Args::from(Cli::try_parse_from(["fresh", "-a"]).unwrap());
+        assert_eq!(args.idle_detach, None);
+    }
+
     #[test]
     fn test_parse_file_location_simple_path() {
         let loc = parse_file_location("foo.txt");
@@ -0,0 +1,91 @@
+//! Residency bookkeeping for the file chunks of a large-file `TextBuffer`.
+//!
+//! Large files are loaded lazily, one chunk at a time, as the viewport or
+//! an edit reaches them. Without a cap every chunk ever visited stays in
+//! memory. `ChunkCache` remembers where each resident chunk was read from
+//! so the least recently used one can be dropped back to an unloaded
+//! region once more than the configured number are resident.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+/// Hit/miss counters and the current residency of a buffer's chunk cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChunkCacheStats {
+    /// Reads served by a chunk that was already resident
+    pub hits: u64,
+    /// Chunks that had to be read from disk
+    pub misses: u64,
+    /// Chunks currently held in memory
+    pub resident: usize,
+}
+
+/// A chunk read from disk and still held in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidentChunk {
+    pub buffer_id: usize,
+    pub file_path: PathBuf,
+    pub file_offset: usize,
+    pub bytes: usize,
+}
+
+/// Least-recently-used order of the resident chunks of one buffer.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkCache {
+    /// Front is least recently used
+    resident: VecDeque<ResidentChunk>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ChunkCache {
+    /// Record a chunk that was just read from disk.
+    pub fn record_load(&mut self, chunk: ResidentChunk) {
+        self.misses += 1;
+        self.resident.retain(|c| c.buffer_id != chunk.buffer_id);
+        self.resident.push_back(chunk);
+    }
+
+    /// Record a read served by an already-loaded buffer. Buffers the cache
+    /// doesn't track (fully loaded files, typed text) are ignored.
+    pub fn record_hit(&mut self, buffer_id: usize) {
+        let Some(index) = self.resident.iter().position(|c| c.buffer_id == buffer_id) else {
+            return;
+        };
+        self.hits += 1;
+        if index + 1 != self.resident.len() {
+            if let Some(chunk) = self.resident.remove(index) {
+                self.resident.push_back(chunk);
+            }
+        }
+    }
+
+    /// Stop tracking a chunk, e.g. because it now holds edits and can no
+    /// longer be re-read from disk.
+    pub fn forget(&mut self, buffer_id: usize) {
+        self.resident.retain(|c| c.buffer_id != buffer_id);
+    }
+
+    /// Remove and return the least recently used chunks beyond `max_resident`,
+    /// oldest first. `max_resident == 0` means no limit.
+    pub fn take_evictions(&mut self, max_resident: usize) -> Vec<ResidentChunk> {
+        if max_resident == 0 {
+            return Vec::new();
+        }
+        let excess = self.resident.len().saturating_sub(max_resident);
+        self.resident.drain(..excess).collect()
+    }
+
+    /// Drop all residency records, e.g. after the buffer list was rebuilt.
+    pub fn clear(&mut self) {
+        self.resident.clear();
+    }
+
+    pub fn stats(&self) -> ChunkCacheStats {
+        ChunkCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            resident: self.resident.len(),
+        }
+    }
+}
@@ -52,6 +52,12 @@ pub struct BufferFormat {
     original_line_ending: LineEnding,
     encoding: Encoding,
     original_encoding: Encoding,
+    /// The content was loaded with more than one kind of line ending.
+    /// Saving keeps them as-is unless the user picks a line ending.
+    mixed_line_endings: bool,
+    /// The user picked a line ending for mixed content, so the next save
+    /// rewrites every line ending even if the dominant one is unchanged.
+    normalize_line_endings: bool,
 }
 
 impl BufferFormat {
@@ -61,9 +67,17 @@ impl BufferFormat {
             original_line_ending: line_ending,
             encoding,
             original_encoding: encoding,
+            mixed_line_endings: false,
+            normalize_line_endings: false,
         }
     }
 
+    /// Record whether the loaded content mixes line ending kinds.
+    pub fn with_mixed_line_endings(mut self, mixed: bool) -> Self {
+        self.mixed_line_endings = mixed;
+        self
+    }
+
     pub fn line_ending(&self) -> LineEnding {
         self.line_ending
     }
@@ -80,8 +94,13 @@ impl BufferFormat {
         self.original_encoding
     }
 
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.mixed_line_endings
+    }
+
     pub fn set_line_ending(&mut self, le: LineEnding) {
         self.line_ending = le;
+        self.normalize_line_endings = self.mixed_line_endings;
     }
 
     pub fn set_encoding(&mut self, e: Encoding) {
@@ -99,7 +118,7 @@ impl BufferFormat {
     }
 
     pub fn line_ending_changed_since_load(&self) -> bool {
-        self.line_ending != self.original_line_ending
+        self.line_ending != self.original_line_ending || self.normalize_line_endings
     }
 
     pub fn encoding_changed_since_load(&self) -> bool {
@@ -109,6 +128,11 @@ impl BufferFormat {
     /// Called after a successful save to make the current values the
     /// new "original" baseline.
     pub(super) fn promote_current_to_original(&mut self) {
+        // A line ending conversion rewrites every line ending, mixed or not
+        if self.line_ending_changed_since_load() {
+            self.mixed_line_endings = false;
+            self.normalize_line_endings = false;
+        }
         self.original_line_ending = self.line_ending;
         self.original_encoding = self.encoding;
     }
@@ -121,6 +145,31 @@ impl BufferFormat {
 /// Uses majority voting: counts CRLF, LF-only, and CR-only
 /// occurrences and returns the most common format.
 pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
+    let (crlf_count, lf_only_count, cr_only_count) = count_line_endings(bytes);
+
+    // Use majority voting to determine line ending
+    if crlf_count > lf_only_count && crlf_count > cr_only_count {
+        LineEnding::CRLF
+    } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
+        LineEnding::CR
+    } else {
+        // Default to LF if no clear winner or if LF wins
+        LineEnding::LF
+    }
+}
+
+/// Detect whether a sample of bytes uses more than one kind of line ending
+pub fn detect_mixed_line_endings(bytes: &[u8]) -> bool {
+    let (crlf_count, lf_only_count, cr_only_count) = count_line_endings(bytes);
+    [crlf_count, lf_only_count, cr_only_count]
+        .iter()
+        .filter(|&&count| count > 0)
+        .count()
+        > 1
+}
+
+/// Count (CRLF, LF-only, CR-only) line endings in the first 8KB of `bytes`
+fn count_line_endings(bytes: &[u8]) -> (usize, usize, usize) {
     // Only check the first 8KB for line ending detection (same as binary detection)
     let check_len = bytes.len().min(8 * 1024);
     let sample = &bytes[..check_len];
@@ -148,15 +197,7 @@ pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
         i += 1;
     }
 
-    // Use majority voting to determine line ending
-    if crlf_count > lf_only_count && crlf_count > cr_only_count {
-        LineEnding::CRLF
-    } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
-        LineEnding::CR
-    } else {
-        // Default to LF if no clear winner or if LF wins
-        LineEnding::LF
-    }
+    (crlf_count, lf_only_count, cr_only_count)
 }
 
 /// Detect the text encoding from a sample of bytes.
@@ -19,15 +19,18 @@ use std::sync::Arc;
 // Re-export Encoding for backward compatibility
 pub use encoding::Encoding;
 
+pub mod chunk_cache;
 pub mod file_kind;
 pub mod format;
 pub mod persistence;
 pub mod save;
 pub mod search;
+pub use chunk_cache::ChunkCacheStats;
+use chunk_cache::{ChunkCache, ResidentChunk};
 pub use file_kind::BufferFileKind;
 pub use format::{BufferFormat, LineEnding};
 pub use persistence::Persistence;
-pub use save::SudoSaveRequired;
+pub use save::{BackgroundSave, SudoSaveRequired, WrittenSave};
 #[cfg(test)]
 pub(crate) use save::{RecipeAction, WriteRecipe};
 #[cfg(test)]
@@ -91,18 +94,30 @@ pub const LOAD_CHUNK_SIZE: usize = 1024 * 1024;
 /// Chunk alignment for lazy loading (64 KB)
 pub const CHUNK_ALIGNMENT: usize = 64 * 1024;
 
+/// Default cap on lazily loaded chunks kept in memory (64 × 1 MB)
+pub const DEFAULT_MAX_RESIDENT_CHUNKS: usize = 64;
+
+/// An edit can touch the chunks on both sides of a boundary, so a lower
+/// non-zero cap would evict a chunk the edit still needs.
+const MIN_RESIDENT_CHUNKS: usize = 2;
+
 /// Configuration passed to TextBuffer constructors.
 #[derive(Debug, Clone)]
 pub struct BufferConfig {
     /// Estimated average line length in bytes. Used for approximate line number
     /// display in large files and for goto-line byte offset estimation.
     pub estimated_line_length: usize,
+    /// Maximum number of lazily loaded file chunks kept in memory. Beyond
+    /// this the least recently used chunk is dropped and re-read from disk
+    /// when next needed. 0 means no limit.
+    pub max_resident_chunks: usize,
 }
 
 impl Default for BufferConfig {
     fn default() -> Self {
         Self {
             estimated_line_length: 80,
+            max_resident_chunks: DEFAULT_MAX_RESIDENT_CHUNKS,
         }
     }
 }
@@ -180,6 +195,10 @@ pub struct TextBuffer {
 
     /// Buffer configuration (estimated line length, etc.)
     config: BufferConfig,
+
+    /// Which lazily loaded chunks are resident, in LRU order — see
+    /// `chunk_cache.rs`.
+    chunk_cache: ChunkCache,
 }
 
 /// Snapshot of a TextBuffer's piece tree and associated string buffers.
@@ -211,6 +230,7 @@ impl TextBuffer {
             format: BufferFormat::new(line_ending, encoding),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -259,6 +279,7 @@ impl TextBuffer {
 
         // For binary files, detect line ending but don't convert encoding
         let line_ending = format::detect_line_ending(&content);
+        let mixed_line_endings = format::detect_mixed_line_endings(&content);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, content);
@@ -278,9 +299,11 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, true),
-            format: BufferFormat::new(line_ending, Encoding::Utf8),
+            format: BufferFormat::new(line_ending, Encoding::Utf8)
+                .with_mixed_line_endings(mixed_line_endings),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -293,6 +316,7 @@ impl TextBuffer {
 
         // Auto-detect line ending format from content
         let line_ending = format::detect_line_ending(&utf8_content);
+        let mixed_line_endings = format::detect_mixed_line_endings(&utf8_content);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, utf8_content);
@@ -312,9 +336,11 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, false),
-            format: BufferFormat::new(line_ending, encoding),
+            format: BufferFormat::new(line_ending, encoding)
+                .with_mixed_line_endings(mixed_line_endings),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -331,6 +357,7 @@ impl TextBuffer {
 
         // Auto-detect line ending format from content
         let line_ending = format::detect_line_ending(&utf8_content);
+        let mixed_line_endings = format::detect_mixed_line_endings(&utf8_content);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, utf8_content);
@@ -350,9 +377,11 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, false),
-            format: BufferFormat::new(line_ending, encoding),
+            format: BufferFormat::new(line_ending, encoding)
+                .with_mixed_line_endings(mixed_line_endings),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -380,6 +409,7 @@ impl TextBuffer {
             format: BufferFormat::new(line_ending, encoding),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -583,6 +613,7 @@ impl TextBuffer {
 
         // UTF-8/ASCII files can use lazy loading
         let line_ending = format::detect_line_ending(&sample);
+        let mixed_line_endings = format::detect_mixed_line_endings(&sample);
 
         // Create an unloaded buffer that references the entire file
         let buffer = StringBuffer {
@@ -621,9 +652,11 @@ impl TextBuffer {
                 Some(file_size),
             ),
             file_kind: BufferFileKind::new(true, is_binary),
-            format: BufferFormat::new(line_ending, encoding),
+            format: BufferFormat::new(line_ending, encoding)
+                .with_mixed_line_endings(mixed_line_endings),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         })
     }
 
@@ -737,6 +770,52 @@ impl TextBuffer {
         Ok(())
     }
 
+    /// Prepare a save of a large file whose write can run off the main thread.
+    ///
+    /// Returns `None` when the buffer should be saved with `save_to_file`
+    /// instead: small files, empty buffers, remote filesystems, files that
+    /// need an in-place write to keep their owner, or when no temp file can
+    /// be created next to the destination.
+    pub fn begin_background_save(
+        &self,
+        dest_path: &Path,
+    ) -> anyhow::Result<Option<BackgroundSave>> {
+        if !self.file_kind.is_large_file() || self.total_bytes() == 0 {
+            return Ok(None);
+        }
+        let fs = self.persistence.fs();
+        if fs.remote_connection_info().is_some() || save::should_use_inplace_write(fs, dest_path) {
+            return Ok(None);
+        }
+
+        let recipe = save::build_write_recipe(
+            &self.piece_tree,
+            &self.buffers,
+            &self.format,
+            &self.file_kind,
+            &self.persistence,
+        )?;
+        let temp_path = fs.temp_path_for(dest_path);
+        let Ok(temp_file) = fs.create_file(&temp_path) else {
+            return Ok(None);
+        };
+        Ok(Some(BackgroundSave::new(
+            Arc::clone(fs),
+            recipe,
+            dest_path.to_path_buf(),
+            temp_path,
+            temp_file,
+        )))
+    }
+
+    /// Move a finished background save into place and mark the buffer saved.
+    ///
+    /// The buffer must not have been edited since `begin_background_save`.
+    pub fn finish_background_save(&mut self, written: &WrittenSave) -> anyhow::Result<()> {
+        written.commit(&**self.persistence.fs())?;
+        self.finalize_save(written.dest_path())
+    }
+
     /// Finalize save state after successful write.
     fn finalize_save(&mut self, dest_path: &Path) -> anyhow::Result<()> {
         let new_size = self.persistence.fs().metadata(dest_path)?.size as usize;
@@ -777,6 +856,7 @@ impl TextBuffer {
     /// For large files, this creates a reference to the disk file to save memory and sync offsets.
     /// For small files, this flattens all edits into a single in-memory buffer.
     fn consolidate_after_save(&mut self, path: &Path, file_size: usize) {
+        self.chunk_cache.clear();
         if self.file_kind.is_large_file() {
             self.consolidate_large_file(path, file_size);
         } else {
@@ -1110,6 +1190,7 @@ impl TextBuffer {
         self.piece_tree = snapshot.piece_tree.clone();
         self.buffers = snapshot.buffers.clone();
         self.next_buffer_id = snapshot.next_buffer_id;
+        self.chunk_cache.clear();
         self.mark_content_modified();
     }
 
@@ -1253,9 +1334,13 @@ impl TextBuffer {
                     .map(|b| !b.is_loaded())
                     .unwrap_or(false);
 
-                if needs_loading && self.chunk_split_and_load(&piece_view, current_offset)? {
-                    restarted_iteration = true;
-                    break;
+                if needs_loading {
+                    if self.chunk_split_and_load(&piece_view, current_offset)? {
+                        restarted_iteration = true;
+                        break;
+                    }
+                } else {
+                    self.chunk_cache.record_hit(buffer_id);
                 }
 
                 // Calculate the range to read from this piece
@@ -1403,10 +1488,7 @@ impl TextBuffer {
                 buffer_id,
             )
             .entered();
-            self.buffers
-                .get_mut(buffer_id)
-                .context("Buffer not found")?
-                .load(&**self.persistence.fs())
+            self.load_buffer_chunk(buffer_id)
                 .context("Failed to load buffer")?;
             return Ok(false);
         }
@@ -1472,10 +1554,7 @@ impl TextBuffer {
         );
 
         // Load the chunk buffer
-        self.buffers
-            .get_mut(new_buffer_id)
-            .context("Chunk buffer not found")?
-            .load(&**self.persistence.fs())
+        self.load_buffer_chunk(new_buffer_id)
             .context("Failed to load chunk")?;
 
         // split_at_offset uses compute_line_feeds_static which returns None
@@ -1643,8 +1722,10 @@ impl TextBuffer {
     fn ensure_chunk_loaded_at(&mut self, offset: usize) {
         if let Some(piece_info) = self.piece_tree.find_by_offset(offset) {
             let buffer_id = piece_info.location.buffer_id();
-            if let Some(buffer) = self.buffers.get_mut(buffer_id) {
-                if !buffer.is_loaded() {
+            if let Some(buffer) = self.buffers.get(buffer_id) {
+                if buffer.is_loaded() {
+                    self.chunk_cache.record_hit(buffer_id);
+                } else {
                     let buf_bytes = buffer.unloaded_bytes().unwrap_or(0);
                     tracing::info!(
                         "ensure_chunk_loaded_at: loading buffer {} ({} bytes) for offset {}",
@@ -1652,7 +1733,7 @@ impl TextBuffer {
                         buf_bytes,
                         offset
                     );
-                    if let Err(e) = buffer.load(&**self.persistence.fs()) {
+                    if let Err(e) = self.load_buffer_chunk(buffer_id) {
                         tracing::warn!("Failed to load chunk at offset {offset}: {e}");
                     }
                 }
@@ -1660,6 +1741,67 @@ impl TextBuffer {
         }
     }
 
+    /// Load an unloaded buffer from disk and record it in the chunk cache,
+    /// dropping least recently used chunks beyond `max_resident_chunks`.
+    fn load_buffer_chunk(&mut self, buffer_id: usize) -> io::Result<()> {
+        let buffer = self
+            .buffers
+            .get_mut(buffer_id)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Buffer not found"))?;
+        let source = match &buffer.data {
+            BufferData::Unloaded {
+                file_path,
+                file_offset,
+                bytes,
+            } => Some(ResidentChunk {
+                buffer_id,
+                file_path: file_path.clone(),
+                file_offset: *file_offset,
+                bytes: *bytes,
+            }),
+            BufferData::Loaded { .. } => None,
+        };
+        buffer.load(&**self.persistence.fs())?;
+        if let Some(chunk) = source {
+            self.chunk_cache.record_load(chunk);
+            self.evict_resident_chunks();
+        }
+        Ok(())
+    }
+
+    /// Unload the least recently used chunks beyond the configured cap.
+    /// Chunks that typed text was appended to no longer match the file and
+    /// stay in memory; they just stop being tracked.
+    fn evict_resident_chunks(&mut self) {
+        let max = match self.config.max_resident_chunks {
+            0 => return,
+            n => n.max(MIN_RESIDENT_CHUNKS),
+        };
+        for chunk in self.chunk_cache.take_evictions(max) {
+            let Some(buffer) = self.buffers.get_mut(chunk.buffer_id) else {
+                continue;
+            };
+            let clean = buffer
+                .get_data()
+                .is_some_and(|data| data.len() == chunk.bytes);
+            if clean {
+                buffer.unload(chunk.file_path, chunk.file_offset);
+            }
+        }
+    }
+
+    /// Hit/miss counters and residency of the large-file chunk cache.
+    pub fn chunk_cache_stats(&self) -> ChunkCacheStats {
+        self.chunk_cache.stats()
+    }
+
+    /// Change the cap on resident large-file chunks (0 = no limit) and evict
+    /// immediately if the buffer is now over it.
+    pub fn set_max_resident_chunks(&mut self, max_resident_chunks: usize) {
+        self.config.max_resident_chunks = max_resident_chunks;
+        self.evict_resident_chunks();
+    }
+
     /// Check if this is a large file with lazy loading enabled
     pub fn is_large_file(&self) -> bool {
         self.file_kind.is_large_file()
@@ -2050,6 +2192,16 @@ impl TextBuffer {
         &self.buffers
     }
 
+    /// Bytes held in memory by the loaded string buffers. Unloaded regions
+    /// of large files are not counted.
+    pub fn loaded_bytes(&self) -> usize {
+        self.buffers
+            .iter()
+            .filter_map(|buffer| buffer.get_data())
+            .map(|data| data.len())
+            .sum()
+    }
+
     /// Apply the results of an incremental line scan.
     pub fn apply_scan_updates(&mut self, updates: &[(usize, usize)]) {
         self.piece_tree.update_leaf_line_feeds(updates);
@@ -2324,6 +2476,11 @@ impl TextBuffer {
         self.format.line_ending()
     }
 
+    /// Whether the file was loaded with more than one kind of line ending
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.format.has_mixed_line_endings()
+    }
+
     /// Set the line ending format for this buffer
     ///
     /// This marks the buffer as modified since the line ending format has changed.
@@ -2693,6 +2850,29 @@ impl TextBuffer {
         }
     }
 
+    /// Convert (line, column) to byte position - 0-indexed, with the column
+    /// counted in characters as compilers report it (a tab is one character).
+    /// Columns past the end of the line clamp to the end of its text, before
+    /// any line ending.
+    pub fn line_char_col_to_position(&self, line: usize, char_col: usize) -> usize {
+        let Some(start) = self.line_start_offset(line) else {
+            return self.len();
+        };
+        let Some(bytes) = self.get_line(line) else {
+            return start;
+        };
+        let text = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+        let text = text.strip_suffix(b"\r").unwrap_or(text);
+        let offset = text
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| (b & 0xC0) != 0x80)
+            .map(|(i, _)| i)
+            .nth(char_col)
+            .unwrap_or(text.len());
+        start + offset
+    }
+
     /// Convert byte position to LSP position (line, UTF-16 code units)
     /// LSP protocol uses UTF-16 code units for character offsets
     pub fn position_to_lsp_position(&self, byte_pos: usize) -> (usize, usize) {
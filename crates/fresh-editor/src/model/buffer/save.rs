@@ -1,6 +1,7 @@
 //! Save/write-recipe logic for `TextBuffer`.
 //!
-//! Types: `SudoSaveRequired`, `WriteRecipe`, `RecipeAction`.
+//! Types: `SudoSaveRequired`, `WriteRecipe`, `RecipeAction`,
+//! `BackgroundSave`, `WrittenSave`.
 //! Free fns: `build_write_recipe`, save-to-disk helpers that only
 //! need `&dyn FileSystem` + local arguments.
 
@@ -530,6 +531,147 @@ pub(super) fn write_recipe_to_file(
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// BackgroundSave
+// ---------------------------------------------------------------------------
+
+/// Largest single read issued while copying unchanged regions, so progress
+/// is reported regularly and memory stays bounded.
+const BACKGROUND_COPY_CHUNK: u64 = 1024 * 1024;
+
+/// A save whose file write can run on another thread.
+///
+/// Owns its write recipe and the temp file next to the destination, so the
+/// buffer can keep rendering (and loading chunks from the original file)
+/// while it runs. The destination is only replaced by
+/// `WrittenSave::commit`, back on the main thread.
+pub struct BackgroundSave {
+    fs: Arc<dyn FileSystem + Send + Sync>,
+    recipe: WriteRecipe,
+    dest_path: PathBuf,
+    temp_path: PathBuf,
+    temp_file: Box<dyn FileWriter>,
+}
+
+/// A background save whose temp file has been fully written and synced.
+#[derive(Debug)]
+pub struct WrittenSave {
+    dest_path: PathBuf,
+    temp_path: PathBuf,
+}
+
+impl BackgroundSave {
+    pub(super) fn new(
+        fs: Arc<dyn FileSystem + Send + Sync>,
+        recipe: WriteRecipe,
+        dest_path: PathBuf,
+        temp_path: PathBuf,
+        temp_file: Box<dyn FileWriter>,
+    ) -> Self {
+        Self {
+            fs,
+            recipe,
+            dest_path,
+            temp_path,
+            temp_file,
+        }
+    }
+
+    pub fn dest_path(&self) -> &Path {
+        &self.dest_path
+    }
+
+    /// Number of bytes the save will write.
+    pub fn total_bytes(&self) -> u64 {
+        self.recipe
+            .actions
+            .iter()
+            .map(|action| match action {
+                RecipeAction::Copy { len, .. } => *len,
+                RecipeAction::Insert { index } => self.recipe.insert_data[*index].len() as u64,
+            })
+            .sum()
+    }
+
+    /// Write the recipe to the temp file, calling `progress` with the bytes
+    /// written so far. On failure the temp file is removed and the
+    /// destination is left untouched.
+    pub fn run(mut self, mut progress: impl FnMut(u64)) -> io::Result<WrittenSave> {
+        if let Err(e) = self.write_temp_file(&mut progress) {
+            // Best-effort cleanup of temp file on write failure
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = self.fs.remove_file(&self.temp_path);
+            return Err(e);
+        }
+        Ok(WrittenSave {
+            dest_path: self.dest_path,
+            temp_path: self.temp_path,
+        })
+    }
+
+    fn write_temp_file(&mut self, progress: &mut impl FnMut(u64)) -> io::Result<()> {
+        let mut written = 0u64;
+        for action in &self.recipe.actions {
+            match *action {
+                RecipeAction::Copy { offset, len } => {
+                    let src_path = self.recipe.src_path.as_ref().ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "Copy action without source")
+                    })?;
+                    let mut done = 0u64;
+                    while done < len {
+                        let chunk_len = (len - done).min(BACKGROUND_COPY_CHUNK);
+                        let data =
+                            self.fs
+                                .read_range(src_path, offset + done, chunk_len as usize)?;
+                        self.temp_file.write_all(&data)?;
+                        done += chunk_len;
+                        written += chunk_len;
+                        progress(written);
+                    }
+                }
+                RecipeAction::Insert { index } => {
+                    let data = &self.recipe.insert_data[index];
+                    self.temp_file.write_all(data)?;
+                    written += data.len() as u64;
+                    progress(written);
+                }
+            }
+        }
+        self.temp_file.flush()?;
+        self.temp_file.sync_all()
+    }
+}
+
+impl WrittenSave {
+    pub fn dest_path(&self) -> &Path {
+        &self.dest_path
+    }
+
+    /// Replace the destination with the written temp file, keeping the
+    /// destination's permissions.
+    pub fn commit(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        if let Some(perms) = fs
+            .metadata_if_exists(&self.dest_path)
+            .and_then(|meta| meta.permissions)
+        {
+            // Best-effort permission restore; rename will proceed regardless
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = fs.set_permissions(&self.temp_path, &perms);
+        }
+        if let Err(e) = fs.rename(&self.temp_path, &self.dest_path) {
+            self.discard(fs);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Remove the temp file without touching the destination.
+    fn discard(&self, fs: &dyn FileSystem) {
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = fs.remove_file(&self.temp_path);
+    }
+}
+
 /// Internal helper to create a SudoSaveRequired error.
 pub(super) fn make_sudo_error(
     temp_path: PathBuf,
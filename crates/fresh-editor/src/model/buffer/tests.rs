@@ -39,6 +39,23 @@ fn test_line_positions_multiline() {
     assert_eq!(buffer.line_col_to_position(2, 0), 15); // Start of line 2
 }
 
+#[test]
+fn test_line_char_col_to_position() {
+    let buffer = TextBuffer::from_bytes("\tlet x;\r\nhé llo\nlast".as_bytes().to_vec(), test_fs());
+    // A tab is one character
+    assert_eq!(buffer.line_char_col_to_position(0, 0), 0);
+    assert_eq!(buffer.line_char_col_to_position(0, 5), 5);
+    // Past the end clamps before the CRLF
+    assert_eq!(buffer.line_char_col_to_position(0, 50), 7);
+    // Multi-byte characters count once: "hé " is 4 bytes
+    assert_eq!(buffer.line_char_col_to_position(1, 3), 9 + 4);
+    assert_eq!(buffer.line_char_col_to_position(1, 99), 9 + 7);
+    // Last line without a newline
+    assert_eq!(buffer.line_char_col_to_position(2, 99), 21);
+    // Missing line: end of buffer
+    assert_eq!(buffer.line_char_col_to_position(9, 0), buffer.len());
+}
+
 #[test]
 fn test_new_from_content() {
     let buffer = TextBuffer::from_bytes(b"hello\nworld".to_vec(), test_fs());
@@ -852,6 +869,170 @@ mod large_file_support {
             "Length should be original + edits"
         );
     }
+
+    /// Write a file of `chunks` LOAD_CHUNK_SIZE regions filled with 'A', 'B', ...
+    fn write_lettered_chunks(temp_dir: &TempDir, chunks: u8) -> std::path::PathBuf {
+        let file_path = temp_dir.path().join("lettered.txt");
+        let mut file = File::create(&file_path).unwrap();
+        for i in 0..chunks {
+            file.write_all(&vec![b'A' + i; LOAD_CHUNK_SIZE]).unwrap();
+        }
+        file.flush().unwrap();
+        file_path
+    }
+
+    #[test]
+    fn test_chunk_cache_evicts_least_recently_used_chunk() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = write_lettered_chunks(&temp_dir, 4);
+        let mut buffer = TextBuffer::load_from_file(&file_path, 1, test_fs()).unwrap();
+        buffer.set_max_resident_chunks(2);
+
+        for i in 0..4u8 {
+            let data = buffer
+                .get_text_range_mut(i as usize * LOAD_CHUNK_SIZE, 1024)
+                .unwrap();
+            assert!(data.iter().all(|&b| b == b'A' + i));
+        }
+
+        let stats = buffer.chunk_cache_stats();
+        assert_eq!(stats.resident, 2);
+        assert_eq!(stats.misses, 4);
+        assert!(buffer.loaded_bytes() <= 2 * LOAD_CHUNK_SIZE);
+
+        // 'D' is resident; 'A' was dropped and must be re-read from disk
+        buffer.get_text_range_mut(3 * LOAD_CHUNK_SIZE, 16).unwrap();
+        assert_eq!(buffer.chunk_cache_stats().misses, 4);
+        let data = buffer.get_text_range_mut(0, 1024).unwrap();
+        assert!(data.iter().all(|&b| b == b'A'));
+        let stats = buffer.chunk_cache_stats();
+        assert_eq!(stats.misses, 5);
+        assert_eq!(stats.resident, 2);
+    }
+
+    #[test]
+    fn test_chunk_cache_zero_limit_keeps_every_chunk() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = write_lettered_chunks(&temp_dir, 3);
+        let mut buffer = TextBuffer::load_from_file(&file_path, 1, test_fs()).unwrap();
+        buffer.set_max_resident_chunks(0);
+
+        for i in 0..3 {
+            buffer.get_text_range_mut(i * LOAD_CHUNK_SIZE, 16).unwrap();
+        }
+        assert_eq!(buffer.chunk_cache_stats().resident, 3);
+        assert_eq!(buffer.loaded_bytes(), 3 * LOAD_CHUNK_SIZE);
+    }
+
+    #[test]
+    fn test_chunk_cache_keeps_edited_chunk_in_memory() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = write_lettered_chunks(&temp_dir, 4);
+        let mut buffer = TextBuffer::load_from_file(&file_path, 1, test_fs()).unwrap();
+        buffer.set_max_resident_chunks(2);
+
+        // Typing at the end of a loaded chunk appends into the chunk itself
+        buffer.get_text_range_mut(0, 16).unwrap();
+        buffer.insert_bytes(LOAD_CHUNK_SIZE, b"XYZ".to_vec());
+
+        for i in 1..4 {
+            buffer
+                .get_text_range_mut(i * LOAD_CHUNK_SIZE + 3, 16)
+                .unwrap();
+        }
+        assert!(buffer.chunk_cache_stats().resident <= 2);
+
+        let edited = buffer.get_text_range_mut(LOAD_CHUNK_SIZE - 2, 5).unwrap();
+        assert_eq!(edited, b"AAXYZ");
+        let after = buffer.get_text_range_mut(LOAD_CHUNK_SIZE + 3, 4).unwrap();
+        assert_eq!(after, b"BBBB");
+        assert_eq!(buffer.total_bytes(), 4 * LOAD_CHUNK_SIZE + 3);
+    }
+
+    #[test]
+    fn test_background_save_writes_every_chunk() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = write_lettered_chunks(&temp_dir, 3);
+        let mut buffer = TextBuffer::load_from_file(&file_path, 1, test_fs()).unwrap();
+        buffer.insert_bytes(0, b"START".to_vec());
+
+        let job = buffer
+            .begin_background_save(&file_path)
+            .unwrap()
+            .expect("large local files can be saved in the background");
+        let total = job.total_bytes();
+        assert_eq!(total as usize, 3 * LOAD_CHUNK_SIZE + 5);
+
+        let mut reported = Vec::new();
+        let written = job.run(|n| reported.push(n)).unwrap();
+        assert_eq!(reported.last(), Some(&total));
+        assert!(reported.windows(2).all(|w| w[0] < w[1]));
+        // Nothing has replaced the file yet
+        assert_eq!(
+            std::fs::metadata(&file_path).unwrap().len() as usize,
+            3 * LOAD_CHUNK_SIZE
+        );
+
+        buffer.finish_background_save(&written).unwrap();
+        assert!(!buffer.is_modified());
+        let saved = std::fs::read(&file_path).unwrap();
+        assert_eq!(&saved[..6], b"STARTA");
+        assert_eq!(saved.len(), 3 * LOAD_CHUNK_SIZE + 5);
+        assert_eq!(saved[5 + 2 * LOAD_CHUNK_SIZE], b'C');
+    }
+
+    #[test]
+    fn test_background_save_failure_leaves_buffer_modified() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = write_lettered_chunks(&temp_dir, 3);
+        let mut buffer = TextBuffer::load_from_file(&file_path, 1, test_fs()).unwrap();
+        buffer.insert_bytes(0, b"START".to_vec());
+
+        let job = buffer
+            .begin_background_save(&file_path)
+            .unwrap()
+            .expect("large local files can be saved in the background");
+        // Unchanged regions are copied from the original, so removing it
+        // makes the write fail partway through
+        std::fs::remove_file(&file_path).unwrap();
+        assert!(job.run(|_| {}).is_err());
+
+        assert!(buffer.is_modified());
+        assert!(!file_path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_chunk_cache_lru_order() {
+        let chunk = |buffer_id| ResidentChunk {
+            buffer_id,
+            file_path: std::path::PathBuf::from("/tmp/f"),
+            file_offset: buffer_id * 10,
+            bytes: 10,
+        };
+        let mut cache = ChunkCache::default();
+        cache.record_load(chunk(1));
+        cache.record_load(chunk(2));
+        cache.record_load(chunk(3));
+        cache.record_hit(1);
+        // Untracked buffers (typed text, fully loaded files) are ignored
+        cache.record_hit(42);
+
+        assert!(cache.take_evictions(0).is_empty());
+        let evicted: Vec<usize> = cache
+            .take_evictions(1)
+            .into_iter()
+            .map(|c| c.buffer_id)
+            .collect();
+        assert_eq!(evicted, vec![2, 3]);
+        assert_eq!(
+            cache.stats(),
+            ChunkCacheStats {
+                hits: 1,
+                misses: 3,
+                resident: 1,
+            }
+        );
+    }
 }
 
 // ===== Offset to Position Tests =====
@@ -1139,6 +1320,31 @@ fn test_detect_lf() {
     );
 }
 
+#[test]
+fn test_detect_mixed_line_endings() {
+    assert!(super::format::detect_mixed_line_endings(
+        b"hello\r\nworld\n"
+    ));
+    assert!(!super::format::detect_mixed_line_endings(
+        b"hello\r\nworld\r\n"
+    ));
+    assert!(!super::format::detect_mixed_line_endings(b"no newline"));
+}
+
+#[test]
+fn test_set_line_ending_on_mixed_content_requests_conversion() {
+    let mut format =
+        BufferFormat::new(LineEnding::CRLF, Encoding::Utf8).with_mixed_line_endings(true);
+    assert!(!format.line_ending_changed_since_load());
+
+    format.set_line_ending(LineEnding::CRLF);
+    assert!(format.line_ending_changed_since_load());
+
+    format.promote_current_to_original();
+    assert!(!format.has_mixed_line_endings());
+    assert!(!format.line_ending_changed_since_load());
+}
+
 #[test]
 fn test_normalize_crlf() {
     let input = b"hello\r\nworld\r\n".to_vec();
@@ -1551,6 +1757,7 @@ mod rebuild_pristine_saved_root_tests {
             format: BufferFormat::new(LineEnding::LF, Encoding::Utf8),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -1795,6 +2002,7 @@ mod rebuild_pristine_saved_root_tests {
             format: BufferFormat::new(LineEnding::LF, Encoding::Utf8),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -2071,6 +2279,7 @@ mod rebuild_pristine_saved_root_tests {
             format: BufferFormat::new(LineEnding::LF, Encoding::Utf8),
             version: 0,
             config: BufferConfig::default(),
+            chunk_cache: ChunkCache::default(),
         };
 
         // Load a small viewport in the middle (forces chunk splitting).
@@ -241,6 +241,12 @@ impl Cursors {
         self.cursors.get_mut(&id)
     }
 
+    /// ID the next added cursor gets. Events that add several cursors
+    /// number them from here upwards.
+    pub fn next_id(&self) -> CursorId {
+        CursorId(self.next_id)
+    }
+
     /// Add a new cursor and return its ID
     pub fn add(&mut self, cursor: Cursor) -> CursorId {
         let id = CursorId(self.next_id);
@@ -440,6 +446,17 @@ mod tests {
         assert_eq!(cursors.count(), 1);
     }
 
+    #[test]
+    fn test_cursors_next_id_skips_removed_ids() {
+        let mut cursors = Cursors::new();
+        let first = cursors.add(Cursor::new(10));
+        let second = cursors.add(Cursor::new(20));
+        cursors.remove(second);
+        // A removed cursor's ID is not handed out again
+        assert_eq!(cursors.next_id(), CursorId(second.0 + 1));
+        assert_ne!(cursors.next_id(), first);
+    }
+
     #[test]
     fn test_cursors_remove_secondary() {
         let mut cursors = Cursors::new();
@@ -563,6 +563,11 @@ pub struct LogEntry {
     /// to their exact original positions.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub displaced_markers: Vec<(u64, usize)>,
+
+    /// Id of the linked undo group this write belongs to, shared with the
+    /// entries it undoes and redoes together with in other buffers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undo_link: Option<u64>,
 }
 
 impl LogEntry {
@@ -575,6 +580,7 @@ impl LogEntry {
                 .as_millis() as u64,
             description: None,
             displaced_markers: Vec::new(),
+            undo_link: None,
         }
     }
 
@@ -816,6 +822,56 @@ impl EventLog {
         }
     }
 
+    /// Put the last write action in linked undo group `link`.
+    pub fn link_last_write(&mut self, link: u64) {
+        if let Some(index) = self.last_write_index() {
+            self.entries[index].undo_link = Some(link);
+        }
+    }
+
+    /// Collapse every entry logged since `start` into a single `Batch` so
+    /// that one undo reverts them all (used for macro replay).
+    ///
+    /// Entries without an inverse (cursor moves, scrolls) are dropped from
+    /// the group — keeping them would make the whole batch non-invertible.
+    /// Does nothing if redo history exists or fewer than two invertible
+    /// entries were logged. Returns true if a group was created.
+    pub fn group_since(&mut self, start: usize, description: String) -> bool {
+        if start >= self.current_index || self.current_index != self.entries.len() {
+            return false;
+        }
+        let invertible = self.entries[start..]
+            .iter()
+            .filter(|entry| entry.event.inverse().is_some())
+            .count();
+        if invertible < 2 {
+            return false;
+        }
+
+        let events: Vec<Event> = self
+            .entries
+            .drain(start..)
+            .map(|entry| entry.event)
+            .filter(|event| event.inverse().is_some())
+            .collect();
+        self.entries.push(
+            LogEntry::new(Event::Batch {
+                events,
+                description: description.clone(),
+            })
+            .with_description(description),
+        );
+        self.current_index = self.entries.len();
+
+        // A save point inside the grouped range no longer exists.
+        if let Some(saved_idx) = self.saved_at_index {
+            if saved_idx > start {
+                self.saved_at_index = None;
+            }
+        }
+        true
+    }
+
     /// Get the current event index
     pub fn current_index(&self) -> usize {
         self.current_index
@@ -841,6 +897,33 @@ impl EventLog {
         self.current_index < self.entries.len()
     }
 
+    /// Index of the write action the next `undo()` would revert, if any.
+    pub fn last_write_index(&self) -> Option<usize> {
+        self.entries[..self.current_index]
+            .iter()
+            .rposition(|entry| entry.event.is_write_action())
+    }
+
+    /// Index of the write action the next `redo()` would reapply, if any.
+    pub fn next_write_index(&self) -> Option<usize> {
+        self.entries[self.current_index..]
+            .iter()
+            .position(|entry| entry.event.is_write_action())
+            .map(|offset| self.current_index + offset)
+    }
+
+    /// Linked undo group of the write the next `undo()` would revert.
+    pub fn undo_link(&self) -> Option<u64> {
+        self.last_write_index()
+            .and_then(|index| self.entries[index].undo_link)
+    }
+
+    /// Linked undo group of the write the next `redo()` would reapply.
+    pub fn redo_link(&self) -> Option<u64> {
+        self.next_write_index()
+            .and_then(|index| self.entries[index].undo_link)
+    }
+
     /// Move back through events (for undo)
     /// Collects all events up to and including the first write action, returns their inverses.
     /// Each inverse event is paired with displaced markers from the original event,
@@ -1135,6 +1218,82 @@ mod tests {
         assert_eq!(log.current_index(), 1);
     }
 
+    #[test]
+    fn test_group_since_undoes_in_one_step() {
+        let mut log = EventLog::new();
+        log.append(Event::Insert {
+            position: 0,
+            text: "a".to_string(),
+            cursor_id: CursorId(0),
+        });
+        let start = log.current_index();
+        log.append(Event::Insert {
+            position: 1,
+            text: "b".to_string(),
+            cursor_id: CursorId(0),
+        });
+        log.append(Event::SetViewport { top_line: 0 });
+        log.append(Event::Insert {
+            position: 2,
+            text: "c".to_string(),
+            cursor_id: CursorId(0),
+        });
+
+        assert!(log.group_since(start, "Macro".to_string()));
+        assert_eq!(log.entries().len(), 2);
+
+        let undone = log.undo();
+        assert_eq!(undone.len(), 1);
+        assert!(matches!(&undone[0].0, Event::Batch { events, .. } if events.len() == 2));
+        assert_eq!(log.current_index(), 1);
+
+        // Nothing logged since `start` any more: no-op.
+        assert!(!log.group_since(log.current_index(), "Macro".to_string()));
+    }
+
+    #[test]
+    fn test_write_index_skips_readonly_events() {
+        let mut log = EventLog::new();
+        assert_eq!(log.last_write_index(), None);
+
+        log.append(Event::Insert {
+            position: 0,
+            text: "a".to_string(),
+            cursor_id: CursorId(0),
+        });
+        log.append(Event::Scroll { line_offset: 1 });
+        assert_eq!(log.last_write_index(), Some(0));
+        assert_eq!(log.next_write_index(), None);
+
+        log.undo();
+        assert_eq!(log.last_write_index(), None);
+        assert_eq!(log.next_write_index(), Some(0));
+    }
+
+    #[test]
+    fn test_undo_link_dropped_with_redo_history() {
+        let mut log = EventLog::new();
+        log.append(Event::Insert {
+            position: 0,
+            text: "a".to_string(),
+            cursor_id: CursorId(0),
+        });
+        log.link_last_write(7);
+        assert_eq!(log.undo_link(), Some(7));
+
+        log.undo();
+        assert_eq!(log.undo_link(), None);
+        assert_eq!(log.redo_link(), Some(7));
+
+        log.append(Event::Insert {
+            position: 0,
+            text: "b".to_string(),
+            cursor_id: CursorId(0),
+        });
+        assert_eq!(log.undo_link(), None);
+        assert_eq!(log.redo_link(), None);
+    }
+
     #[test]
     fn test_event_inverse() {
         let insert = Event::Insert {
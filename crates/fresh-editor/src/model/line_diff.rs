@@ -4,6 +4,7 @@
 //! insertions, deletions, and modifications. It uses a longest common subsequence (LCS)
 //! approach to identify which lines are unchanged, then marks the ranges that differ.
 
+use crate::model::composite_buffer::DiffHunk;
 use std::ops::Range;
 
 /// Type of change detected for a line range
@@ -82,6 +83,63 @@ pub fn diff_lines(saved: &[u8], current: &[u8]) -> LineDiff {
     }
 }
 
+/// Compare two byte slices line by line and return the differing regions as
+/// [`DiffHunk`]s, with 0-indexed line ranges in both `old` and `new`.
+///
+/// Unlike [`diff_lines`], which only reports lines of the current buffer,
+/// each hunk keeps both sides, so a replaced line can be told apart from an
+/// inserted one. Common leading and trailing lines are stripped before the
+/// LCS runs, so typical edits only pay for the region that changed.
+pub fn diff_hunks(old: &[u8], new: &[u8]) -> Vec<DiffHunk> {
+    if old == new {
+        return Vec::new();
+    }
+
+    let old_lines: Vec<&[u8]> = old.split(|&b| b == b'\n').collect();
+    let new_lines: Vec<&[u8]> = new.split(|&b| b == b'\n').collect();
+
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let max_suffix = old_lines.len().min(new_lines.len()) - prefix;
+    let suffix = old_lines
+        .iter()
+        .rev()
+        .zip(new_lines.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old_lines[prefix..old_lines.len() - suffix];
+    let new_mid = &new_lines[prefix..new_lines.len() - suffix];
+    let lcs = longest_common_subsequence(old_mid, new_mid);
+
+    // Every gap between consecutive matches is a hunk; a sentinel match past
+    // the end closes the final one.
+    let end = LineMatch {
+        saved_idx: old_mid.len(),
+        current_idx: new_mid.len(),
+    };
+    let mut hunks = Vec::new();
+    let mut old_idx = 0;
+    let mut new_idx = 0;
+    for m in lcs.into_iter().chain(std::iter::once(end)) {
+        if m.saved_idx > old_idx || m.current_idx > new_idx {
+            hunks.push(DiffHunk::new(
+                prefix + old_idx,
+                m.saved_idx - old_idx,
+                prefix + new_idx,
+                m.current_idx - new_idx,
+            ));
+        }
+        old_idx = m.saved_idx + 1;
+        new_idx = m.current_idx + 1;
+    }
+    hunks
+}
+
 /// Represents a match between saved and current line indices
 #[derive(Debug, Clone, Copy)]
 struct LineMatch {
@@ -486,6 +544,36 @@ mod tests {
         assert!(!diff.equal);
         assert_eq!(diff.changed_lines, vec![0..1]);
     }
+
+    fn hunk_ranges(hunks: &[DiffHunk]) -> Vec<(usize, usize, usize, usize)> {
+        hunks
+            .iter()
+            .map(|h| (h.old_start, h.old_count, h.new_start, h.new_count))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_hunks_identical() {
+        let content = b"line 1\nline 2\n";
+        assert!(diff_hunks(content, content).is_empty());
+    }
+
+    #[test]
+    fn test_diff_hunks_modification() {
+        let old = b"line 1\nline 2\nline 3\n";
+        let new = b"line 1\nchanged\nline 3\n";
+        assert_eq!(hunk_ranges(&diff_hunks(old, new)), vec![(1, 1, 1, 1)]);
+    }
+
+    #[test]
+    fn test_diff_hunks_insertion_and_deletion() {
+        let old = b"a\nb\nc\nd\n";
+        let new = b"a\nnew\nb\nd\n";
+        assert_eq!(
+            hunk_ranges(&diff_hunks(old, new)),
+            vec![(1, 0, 1, 1), (2, 1, 3, 0)]
+        );
+    }
 }
 
 #[cfg(test)]
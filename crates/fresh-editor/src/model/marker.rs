@@ -10,7 +10,7 @@
 ///
 /// The Vec-based implementation is kept for compatibility and simplicity in
 /// situations where marker count is low (<100).
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::model::marker_tree::IntervalTree;
 
@@ -48,6 +48,10 @@ pub struct MarkerList {
     /// Track affinity for compatibility (though IntervalTree handles this through intervals)
     /// We don't strictly need this for the tree, but keep it for API compatibility
     _affinity_map: HashMap<MarkerId, bool>,
+
+    /// Point markers that stay put when text is inserted at their exact
+    /// position (see [`MarkerList::set_sticky`])
+    sticky: HashSet<MarkerId>,
 }
 
 impl MarkerList {
@@ -56,6 +60,7 @@ impl MarkerList {
         Self {
             tree: IntervalTree::new(),
             _affinity_map: HashMap::new(),
+            sticky: HashSet::new(),
         }
     }
 
@@ -97,6 +102,22 @@ impl MarkerList {
     pub fn delete(&mut self, id: MarkerId) {
         self.tree.delete(id.0);
         self._affinity_map.remove(&id);
+        self.sticky.remove(&id);
+    }
+
+    /// Make a point marker stay before text inserted at its exact position
+    /// (`true`), or move after it like every other marker (`false`).
+    ///
+    /// The interval tree always shifts a marker on an insert at its position,
+    /// whatever affinity it was created with; this is for the few markers
+    /// that need the other side, such as the start of a range being typed
+    /// into. Cost: O(k) per insert in the number of sticky markers.
+    pub fn set_sticky(&mut self, id: MarkerId, sticky: bool) {
+        if sticky {
+            self.sticky.insert(id);
+        } else {
+            self.sticky.remove(&id);
+        }
     }
 
     /// Move a marker to a new byte position, preserving its ID and affinity.
@@ -159,7 +180,16 @@ impl MarkerList {
             return;
         }
 
+        let pinned: Vec<MarkerId> = self
+            .sticky
+            .iter()
+            .copied()
+            .filter(|&id| self.get_position(id) == Some(position))
+            .collect();
         self.tree.adjust_for_edit(position as u64, length as i64);
+        for id in pinned {
+            self.set_position(id, position);
+        }
     }
 
     /// Adjust all markers for a deletion
@@ -407,6 +437,27 @@ mod tests {
         list.check_invariants().unwrap();
     }
 
+    #[test]
+    fn test_insert_at_sticky_marker() {
+        let mut list = MarkerList::new();
+
+        let m1 = list.create(10, true);
+        let m2 = list.create(10, false);
+        list.set_sticky(m1, true);
+
+        list.adjust_for_insert(10, 5);
+        assert_eq!(list.get_position(m1), Some(10));
+        assert_eq!(list.get_position(m2), Some(15));
+
+        // Inserts before it still shift a sticky marker
+        list.adjust_for_insert(0, 2);
+        assert_eq!(list.get_position(m1), Some(12));
+
+        list.set_sticky(m1, false);
+        list.adjust_for_insert(12, 1);
+        assert_eq!(list.get_position(m1), Some(13));
+    }
+
     #[test]
     fn test_insert_at_marker_right_affinity() {
         let mut list = MarkerList::new();
@@ -178,6 +178,20 @@ impl StringBuffer {
         }
     }
 
+    /// Drop loaded data and point back at the file region it was read from,
+    /// so a later `load()` re-reads it. The caller must ensure the data still
+    /// matches that region byte for byte.
+    pub fn unload(&mut self, file_path: PathBuf, file_offset: usize) {
+        if let BufferData::Loaded { data, .. } = &self.data {
+            let bytes = data.len();
+            self.data = BufferData::Unloaded {
+                file_path,
+                file_offset,
+                bytes,
+            };
+        }
+    }
+
     /// Create a new unloaded buffer representing a chunk of this buffer
     /// This is used for splitting large unloaded buffers into smaller chunks
     ///
@@ -4,9 +4,11 @@
 //! enabling a 4-level overlay architecture (System → User → Project → Session).
 
 use crate::config::{
-    ClipboardConfig, CursorStyle, FileBrowserConfig, FileExplorerConfig, FormatterConfig,
-    Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig, LineEndingOption, OnSaveAction,
-    PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
+    AutoSaveMode, ClipboardConfig, CursorStyle, DiagnosticsPanelSeverity, DiagnosticsPanelSort,
+    FileBrowserConfig, FileExplorerConfig, FormatterConfig, GutterColumn, Keybinding,
+    KeybindingMapName, KeymapConfig, LanguageConfig, LineEndingOption, LineNumberMode,
+    OnSaveAction, PluginConfig, SessionCloseAction, TaskConfig, TerminalConfig, ThemeName,
+    WarningsConfig,
 };
 use crate::types::LspLanguageConfig;
 use serde::{Deserialize, Serialize};
@@ -89,6 +91,8 @@ pub struct PartialConfig {
     pub default_language: Option<String>,
     pub lsp: Option<HashMap<String, LspLanguageConfig>>,
     pub universal_lsp: Option<HashMap<String, LspLanguageConfig>>,
+    pub tasks: Option<HashMap<String, TaskConfig>>,
+    pub snippets: Option<HashMap<String, HashMap<String, String>>>,
     pub warnings: Option<PartialWarningsConfig>,
     pub plugins: Option<HashMap<String, PartialPluginConfig>>,
     pub packages: Option<PartialPackagesConfig>,
@@ -119,6 +123,8 @@ impl Merge for PartialConfig {
         self.default_language.merge_from(&other.default_language);
         merge_hashmap(&mut self.lsp, &other.lsp);
         merge_hashmap(&mut self.universal_lsp, &other.universal_lsp);
+        merge_hashmap(&mut self.tasks, &other.tasks);
+        merge_hashmap(&mut self.snippets, &other.snippets);
         merge_hashmap_recursive(&mut self.plugins, &other.plugins);
 
         self.active_keybinding_map
@@ -144,9 +150,13 @@ pub struct PartialEditorConfig {
     pub auto_indent: Option<bool>,
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
+    pub subword_movement: Option<bool>,
+    pub spell_check: Option<bool>,
     pub animations: Option<bool>,
     pub line_numbers: Option<bool>,
     pub relative_line_numbers: Option<bool>,
+    pub line_number_mode: Option<LineNumberMode>,
+    pub gutter_columns: Option<Option<Vec<GutterColumn>>>,
     pub scroll_offset: Option<usize>,
     pub syntax_highlighting: Option<bool>,
     pub highlight_current_line: Option<bool>,
@@ -159,14 +169,22 @@ pub struct PartialEditorConfig {
     pub snapshot_interval: Option<usize>,
     pub large_file_threshold_bytes: Option<u64>,
     pub estimated_line_length: Option<usize>,
+    pub large_file_max_resident_chunks: Option<usize>,
+    pub background_save_threshold_bytes: Option<u64>,
     pub enable_inlay_hints: Option<bool>,
     pub enable_semantic_tokens_full: Option<bool>,
+    pub preview_workspace_edits: Option<bool>,
     pub diagnostics_inline_text: Option<bool>,
+    pub diagnostics_panel_severities: Option<Vec<DiagnosticsPanelSeverity>>,
+    pub diagnostics_panel_sort: Option<DiagnosticsPanelSort>,
+    pub diagnostics_panel_wrap: Option<bool>,
     pub recovery_enabled: Option<bool>,
     pub auto_recovery_save_interval_secs: Option<u32>,
+    pub auto_save: Option<AutoSaveMode>,
     pub auto_save_enabled: Option<bool>,
     pub auto_save_interval_secs: Option<u32>,
     pub hot_exit: Option<bool>,
+    pub session_close_action: Option<SessionCloseAction>,
     pub restore_previous_session: Option<bool>,
     pub skip_session_restore_when_files_passed: Option<bool>,
     pub auto_create_empty_buffer_on_last_buffer_close: Option<bool>,
@@ -174,12 +192,16 @@ pub struct PartialEditorConfig {
     pub mouse_hover_enabled: Option<bool>,
     pub mouse_hover_delay_ms: Option<u64>,
     pub double_click_time_ms: Option<u64>,
+    pub session_idle_shutdown_secs: Option<u64>,
+    pub session_max_fps: Option<u32>,
     pub auto_revert_poll_interval_ms: Option<u64>,
     pub read_concurrency: Option<usize>,
     pub file_tree_poll_interval_ms: Option<u64>,
     pub default_line_ending: Option<LineEndingOption>,
     pub trim_trailing_whitespace_on_save: Option<bool>,
+    #[serde(alias = "insert_final_newline")]
     pub ensure_final_newline_on_save: Option<bool>,
+    pub trim_trailing_blank_lines_on_save: Option<bool>,
     pub highlight_matching_brackets: Option<bool>,
     pub rainbow_brackets: Option<bool>,
     pub cursor_style: Option<CursorStyle>,
@@ -199,6 +221,10 @@ pub struct PartialEditorConfig {
     pub show_prompt_line: Option<bool>,
     pub show_vertical_scrollbar: Option<bool>,
     pub show_horizontal_scrollbar: Option<bool>,
+    pub show_minimap: Option<bool>,
+    pub show_git_gutter: Option<bool>,
+    pub show_indent_guides: Option<bool>,
+    pub underline_urls: Option<bool>,
     pub show_tilde: Option<bool>,
     pub use_terminal_bg: Option<bool>,
     pub set_window_title: Option<bool>,
@@ -210,6 +236,7 @@ pub struct PartialEditorConfig {
     pub whitespace_tabs_leading: Option<bool>,
     pub whitespace_tabs_inner: Option<bool>,
     pub whitespace_tabs_trailing: Option<bool>,
+    pub whitespace_eol: Option<bool>,
 }
 
 impl Merge for PartialEditorConfig {
@@ -219,10 +246,14 @@ impl Merge for PartialEditorConfig {
         self.auto_indent.merge_from(&other.auto_indent);
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
+        self.subword_movement.merge_from(&other.subword_movement);
+        self.spell_check.merge_from(&other.spell_check);
         self.animations.merge_from(&other.animations);
         self.line_numbers.merge_from(&other.line_numbers);
         self.relative_line_numbers
             .merge_from(&other.relative_line_numbers);
+        self.line_number_mode.merge_from(&other.line_number_mode);
+        self.gutter_columns.merge_from(&other.gutter_columns);
         self.scroll_offset.merge_from(&other.scroll_offset);
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
@@ -237,19 +268,34 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.large_file_threshold_bytes);
         self.estimated_line_length
             .merge_from(&other.estimated_line_length);
+        self.large_file_max_resident_chunks
+            .merge_from(&other.large_file_max_resident_chunks);
+        self.background_save_threshold_bytes
+            .merge_from(&other.background_save_threshold_bytes);
         self.enable_inlay_hints
             .merge_from(&other.enable_inlay_hints);
         self.enable_semantic_tokens_full
             .merge_from(&other.enable_semantic_tokens_full);
+        self.preview_workspace_edits
+            .merge_from(&other.preview_workspace_edits);
         self.diagnostics_inline_text
             .merge_from(&other.diagnostics_inline_text);
+        self.diagnostics_panel_severities
+            .merge_from(&other.diagnostics_panel_severities);
+        self.diagnostics_panel_sort
+            .merge_from(&other.diagnostics_panel_sort);
+        self.diagnostics_panel_wrap
+            .merge_from(&other.diagnostics_panel_wrap);
         self.recovery_enabled.merge_from(&other.recovery_enabled);
         self.auto_recovery_save_interval_secs
             .merge_from(&other.auto_recovery_save_interval_secs);
+        self.auto_save.merge_from(&other.auto_save);
         self.auto_save_enabled.merge_from(&other.auto_save_enabled);
         self.auto_save_interval_secs
             .merge_from(&other.auto_save_interval_secs);
         self.hot_exit.merge_from(&other.hot_exit);
+        self.session_close_action
+            .merge_from(&other.session_close_action);
         self.restore_previous_session
             .merge_from(&other.restore_previous_session);
         self.skip_session_restore_when_files_passed
@@ -264,6 +310,9 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.mouse_hover_delay_ms);
         self.double_click_time_ms
             .merge_from(&other.double_click_time_ms);
+        self.session_idle_shutdown_secs
+            .merge_from(&other.session_idle_shutdown_secs);
+        self.session_max_fps.merge_from(&other.session_max_fps);
         self.auto_revert_poll_interval_ms
             .merge_from(&other.auto_revert_poll_interval_ms);
         self.read_concurrency.merge_from(&other.read_concurrency);
@@ -275,6 +324,8 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.trim_trailing_whitespace_on_save);
         self.ensure_final_newline_on_save
             .merge_from(&other.ensure_final_newline_on_save);
+        self.trim_trailing_blank_lines_on_save
+            .merge_from(&other.trim_trailing_blank_lines_on_save);
         self.highlight_matching_brackets
             .merge_from(&other.highlight_matching_brackets);
         self.rainbow_brackets.merge_from(&other.rainbow_brackets);
@@ -307,6 +358,11 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.show_vertical_scrollbar);
         self.show_horizontal_scrollbar
             .merge_from(&other.show_horizontal_scrollbar);
+        self.show_minimap.merge_from(&other.show_minimap);
+        self.show_git_gutter.merge_from(&other.show_git_gutter);
+        self.show_indent_guides
+            .merge_from(&other.show_indent_guides);
+        self.underline_urls.merge_from(&other.underline_urls);
         self.show_tilde.merge_from(&other.show_tilde);
         self.use_terminal_bg.merge_from(&other.use_terminal_bg);
         self.set_window_title.merge_from(&other.set_window_title);
@@ -324,6 +380,7 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.whitespace_tabs_inner);
         self.whitespace_tabs_trailing
             .merge_from(&other.whitespace_tabs_trailing);
+        self.whitespace_eol.merge_from(&other.whitespace_eol);
     }
 }
 
@@ -379,6 +436,8 @@ impl Merge for PartialFileBrowserConfig {
 pub struct PartialClipboardConfig {
     pub use_osc52: Option<bool>,
     pub use_system_clipboard: Option<bool>,
+    pub osc52_max_bytes: Option<usize>,
+    pub osc52_paste: Option<bool>,
 }
 
 impl Merge for PartialClipboardConfig {
@@ -386,6 +445,8 @@ impl Merge for PartialClipboardConfig {
         self.use_osc52.merge_from(&other.use_osc52);
         self.use_system_clipboard
             .merge_from(&other.use_system_clipboard);
+        self.osc52_max_bytes.merge_from(&other.osc52_max_bytes);
+        self.osc52_paste.merge_from(&other.osc52_paste);
     }
 }
 
@@ -457,6 +518,8 @@ pub struct PartialLanguageConfig {
     pub filenames: Option<Vec<String>>,
     pub grammar: Option<String>,
     pub comment_prefix: Option<String>,
+    pub block_comment_start: Option<String>,
+    pub block_comment_end: Option<String>,
     pub auto_indent: Option<bool>,
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
@@ -480,6 +543,9 @@ impl Merge for PartialLanguageConfig {
         self.filenames.merge_from(&other.filenames);
         self.grammar.merge_from(&other.grammar);
         self.comment_prefix.merge_from(&other.comment_prefix);
+        self.block_comment_start
+            .merge_from(&other.block_comment_start);
+        self.block_comment_end.merge_from(&other.block_comment_end);
         self.auto_indent.merge_from(&other.auto_indent);
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
@@ -509,9 +575,13 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             auto_indent: Some(cfg.auto_indent),
             auto_close: Some(cfg.auto_close),
             auto_surround: Some(cfg.auto_surround),
+            subword_movement: Some(cfg.subword_movement),
+            spell_check: Some(cfg.spell_check),
             animations: Some(cfg.animations),
             line_numbers: Some(cfg.line_numbers),
             relative_line_numbers: Some(cfg.relative_line_numbers),
+            line_number_mode: Some(cfg.line_number_mode),
+            gutter_columns: Some(cfg.gutter_columns.clone()),
             scroll_offset: Some(cfg.scroll_offset),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             highlight_current_line: Some(cfg.highlight_current_line),
@@ -524,14 +594,22 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             snapshot_interval: Some(cfg.snapshot_interval),
             large_file_threshold_bytes: Some(cfg.large_file_threshold_bytes),
             estimated_line_length: Some(cfg.estimated_line_length),
+            large_file_max_resident_chunks: Some(cfg.large_file_max_resident_chunks),
+            background_save_threshold_bytes: Some(cfg.background_save_threshold_bytes),
             enable_inlay_hints: Some(cfg.enable_inlay_hints),
             enable_semantic_tokens_full: Some(cfg.enable_semantic_tokens_full),
+            preview_workspace_edits: Some(cfg.preview_workspace_edits),
             diagnostics_inline_text: Some(cfg.diagnostics_inline_text),
+            diagnostics_panel_severities: Some(cfg.diagnostics_panel_severities.clone()),
+            diagnostics_panel_sort: Some(cfg.diagnostics_panel_sort),
+            diagnostics_panel_wrap: Some(cfg.diagnostics_panel_wrap),
             recovery_enabled: Some(cfg.recovery_enabled),
             auto_recovery_save_interval_secs: Some(cfg.auto_recovery_save_interval_secs),
+            auto_save: Some(cfg.auto_save),
             auto_save_enabled: Some(cfg.auto_save_enabled),
             auto_save_interval_secs: Some(cfg.auto_save_interval_secs),
             hot_exit: Some(cfg.hot_exit),
+            session_close_action: Some(cfg.session_close_action),
             restore_previous_session: Some(cfg.restore_previous_session),
             skip_session_restore_when_files_passed: Some(
                 cfg.skip_session_restore_when_files_passed,
@@ -543,12 +621,15 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             mouse_hover_enabled: Some(cfg.mouse_hover_enabled),
             mouse_hover_delay_ms: Some(cfg.mouse_hover_delay_ms),
             double_click_time_ms: Some(cfg.double_click_time_ms),
+            session_idle_shutdown_secs: Some(cfg.session_idle_shutdown_secs),
+            session_max_fps: Some(cfg.session_max_fps),
             auto_revert_poll_interval_ms: Some(cfg.auto_revert_poll_interval_ms),
             read_concurrency: Some(cfg.read_concurrency),
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
             default_line_ending: Some(cfg.default_line_ending.clone()),
             trim_trailing_whitespace_on_save: Some(cfg.trim_trailing_whitespace_on_save),
             ensure_final_newline_on_save: Some(cfg.ensure_final_newline_on_save),
+            trim_trailing_blank_lines_on_save: Some(cfg.trim_trailing_blank_lines_on_save),
             highlight_matching_brackets: Some(cfg.highlight_matching_brackets),
             rainbow_brackets: Some(cfg.rainbow_brackets),
             cursor_style: Some(cfg.cursor_style),
@@ -570,6 +651,10 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             show_prompt_line: Some(cfg.show_prompt_line),
             show_vertical_scrollbar: Some(cfg.show_vertical_scrollbar),
             show_horizontal_scrollbar: Some(cfg.show_horizontal_scrollbar),
+            show_minimap: Some(cfg.show_minimap),
+            show_git_gutter: Some(cfg.show_git_gutter),
+            show_indent_guides: Some(cfg.show_indent_guides),
+            underline_urls: Some(cfg.underline_urls),
             show_tilde: Some(cfg.show_tilde),
             use_terminal_bg: Some(cfg.use_terminal_bg),
             set_window_title: Some(cfg.set_window_title),
@@ -581,6 +666,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             whitespace_tabs_leading: Some(cfg.whitespace_tabs_leading),
             whitespace_tabs_inner: Some(cfg.whitespace_tabs_inner),
             whitespace_tabs_trailing: Some(cfg.whitespace_tabs_trailing),
+            whitespace_eol: Some(cfg.whitespace_eol),
         }
     }
 }
@@ -594,11 +680,17 @@ impl PartialEditorConfig {
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             auto_close: self.auto_close.unwrap_or(defaults.auto_close),
             auto_surround: self.auto_surround.unwrap_or(defaults.auto_surround),
+            subword_movement: self.subword_movement.unwrap_or(defaults.subword_movement),
+            spell_check: self.spell_check.unwrap_or(defaults.spell_check),
             animations: self.animations.unwrap_or(defaults.animations),
             line_numbers: self.line_numbers.unwrap_or(defaults.line_numbers),
             relative_line_numbers: self
                 .relative_line_numbers
                 .unwrap_or(defaults.relative_line_numbers),
+            line_number_mode: self.line_number_mode.unwrap_or(defaults.line_number_mode),
+            gutter_columns: self
+                .gutter_columns
+                .unwrap_or_else(|| defaults.gutter_columns.clone()),
             scroll_offset: self.scroll_offset.unwrap_or(defaults.scroll_offset),
             syntax_highlighting: self
                 .syntax_highlighting
@@ -623,24 +715,46 @@ impl PartialEditorConfig {
             estimated_line_length: self
                 .estimated_line_length
                 .unwrap_or(defaults.estimated_line_length),
+            large_file_max_resident_chunks: self
+                .large_file_max_resident_chunks
+                .unwrap_or(defaults.large_file_max_resident_chunks),
+            background_save_threshold_bytes: self
+                .background_save_threshold_bytes
+                .unwrap_or(defaults.background_save_threshold_bytes),
             enable_inlay_hints: self
                 .enable_inlay_hints
                 .unwrap_or(defaults.enable_inlay_hints),
             enable_semantic_tokens_full: self
                 .enable_semantic_tokens_full
                 .unwrap_or(defaults.enable_semantic_tokens_full),
+            preview_workspace_edits: self
+                .preview_workspace_edits
+                .unwrap_or(defaults.preview_workspace_edits),
             diagnostics_inline_text: self
                 .diagnostics_inline_text
                 .unwrap_or(defaults.diagnostics_inline_text),
+            diagnostics_panel_severities: self
+                .diagnostics_panel_severities
+                .unwrap_or_else(|| defaults.diagnostics_panel_severities.clone()),
+            diagnostics_panel_sort: self
+                .diagnostics_panel_sort
+                .unwrap_or(defaults.diagnostics_panel_sort),
+            diagnostics_panel_wrap: self
+                .diagnostics_panel_wrap
+                .unwrap_or(defaults.diagnostics_panel_wrap),
             recovery_enabled: self.recovery_enabled.unwrap_or(defaults.recovery_enabled),
             auto_recovery_save_interval_secs: self
                 .auto_recovery_save_interval_secs
                 .unwrap_or(defaults.auto_recovery_save_interval_secs),
+            auto_save: self.auto_save.unwrap_or(defaults.auto_save),
             auto_save_enabled: self.auto_save_enabled.unwrap_or(defaults.auto_save_enabled),
             auto_save_interval_secs: self
                 .auto_save_interval_secs
                 .unwrap_or(defaults.auto_save_interval_secs),
             hot_exit: self.hot_exit.unwrap_or(defaults.hot_exit),
+            session_close_action: self
+                .session_close_action
+                .unwrap_or(defaults.session_close_action),
             restore_previous_session: self
                 .restore_previous_session
                 .unwrap_or(defaults.restore_previous_session),
@@ -662,6 +776,10 @@ impl PartialEditorConfig {
             double_click_time_ms: self
                 .double_click_time_ms
                 .unwrap_or(defaults.double_click_time_ms),
+            session_idle_shutdown_secs: self
+                .session_idle_shutdown_secs
+                .unwrap_or(defaults.session_idle_shutdown_secs),
+            session_max_fps: self.session_max_fps.unwrap_or(defaults.session_max_fps),
             auto_revert_poll_interval_ms: self
                 .auto_revert_poll_interval_ms
                 .unwrap_or(defaults.auto_revert_poll_interval_ms),
@@ -678,6 +796,9 @@ impl PartialEditorConfig {
             ensure_final_newline_on_save: self
                 .ensure_final_newline_on_save
                 .unwrap_or(defaults.ensure_final_newline_on_save),
+            trim_trailing_blank_lines_on_save: self
+                .trim_trailing_blank_lines_on_save
+                .unwrap_or(defaults.trim_trailing_blank_lines_on_save),
             highlight_matching_brackets: self
                 .highlight_matching_brackets
                 .unwrap_or(defaults.highlight_matching_brackets),
@@ -721,6 +842,12 @@ impl PartialEditorConfig {
             show_horizontal_scrollbar: self
                 .show_horizontal_scrollbar
                 .unwrap_or(defaults.show_horizontal_scrollbar),
+            show_minimap: self.show_minimap.unwrap_or(defaults.show_minimap),
+            show_git_gutter: self.show_git_gutter.unwrap_or(defaults.show_git_gutter),
+            show_indent_guides: self
+                .show_indent_guides
+                .unwrap_or(defaults.show_indent_guides),
+            underline_urls: self.underline_urls.unwrap_or(defaults.underline_urls),
             show_tilde: self.show_tilde.unwrap_or(defaults.show_tilde),
             use_terminal_bg: self.use_terminal_bg.unwrap_or(defaults.use_terminal_bg),
             set_window_title: self.set_window_title.unwrap_or(defaults.set_window_title),
@@ -744,6 +871,7 @@ impl PartialEditorConfig {
             whitespace_tabs_trailing: self
                 .whitespace_tabs_trailing
                 .unwrap_or(defaults.whitespace_tabs_trailing),
+            whitespace_eol: self.whitespace_eol.unwrap_or(defaults.whitespace_eol),
         }
     }
 }
@@ -803,6 +931,8 @@ impl From<&ClipboardConfig> for PartialClipboardConfig {
         Self {
             use_osc52: Some(cfg.use_osc52),
             use_system_clipboard: Some(cfg.use_system_clipboard),
+            osc52_max_bytes: Some(cfg.osc52_max_bytes),
+            osc52_paste: Some(cfg.osc52_paste),
         }
     }
 }
@@ -814,6 +944,8 @@ impl PartialClipboardConfig {
             use_system_clipboard: self
                 .use_system_clipboard
                 .unwrap_or(defaults.use_system_clipboard),
+            osc52_max_bytes: self.osc52_max_bytes.unwrap_or(defaults.osc52_max_bytes),
+            osc52_paste: self.osc52_paste.unwrap_or(defaults.osc52_paste),
         }
     }
 }
@@ -900,6 +1032,8 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             filenames: Some(cfg.filenames.clone()),
             grammar: Some(cfg.grammar.clone()),
             comment_prefix: cfg.comment_prefix.clone(),
+            block_comment_start: cfg.block_comment_start.clone(),
+            block_comment_end: cfg.block_comment_end.clone(),
             auto_indent: Some(cfg.auto_indent),
             auto_close: cfg.auto_close,
             auto_surround: cfg.auto_surround,
@@ -930,6 +1064,12 @@ impl PartialLanguageConfig {
             comment_prefix: self
                 .comment_prefix
                 .or_else(|| defaults.comment_prefix.clone()),
+            block_comment_start: self
+                .block_comment_start
+                .or_else(|| defaults.block_comment_start.clone()),
+            block_comment_end: self
+                .block_comment_end
+                .or_else(|| defaults.block_comment_end.clone()),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             auto_close: self.auto_close.or(defaults.auto_close),
             auto_surround: self.auto_surround.or(defaults.auto_surround),
@@ -1008,6 +1148,8 @@ impl From<&crate::config::Config> for PartialConfig {
                     })
                     .collect(),
             ),
+            tasks: Some(cfg.tasks.clone()),
+            snippets: Some(cfg.snippets.clone()),
             warnings: Some(PartialWarningsConfig::from(&cfg.warnings)),
             // Only include plugins that differ from defaults
             // Path is auto-discovered at runtime and should never be saved
@@ -1128,6 +1270,24 @@ impl PartialConfig {
             result
         };
 
+        // Resolve tasks HashMap - merge with defaults
+        let tasks = {
+            let mut result = defaults.tasks.clone();
+            if let Some(partial_tasks) = self.tasks {
+                result.extend(partial_tasks);
+            }
+            result
+        };
+
+        // Resolve snippets HashMap - merge with defaults
+        let snippets = {
+            let mut result = defaults.snippets.clone();
+            if let Some(partial_snippets) = self.snippets {
+                result.extend(partial_snippets);
+            }
+            result
+        };
+
         // Resolve plugins HashMap - merge with defaults
         let plugins = {
             let mut result = defaults.plugins.clone();
@@ -1180,6 +1340,8 @@ impl PartialConfig {
                 .or_else(|| defaults.default_language.clone()),
             lsp,
             universal_lsp,
+            tasks,
+            snippets,
             warnings: self
                 .warnings
                 .map(|e| e.resolve(&defaults.warnings))
@@ -1201,6 +1363,8 @@ impl Default for LanguageConfig {
             filenames: Vec::new(),
             grammar: String::new(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
@@ -1345,6 +1509,13 @@ mod tests {
         assert_eq!(higher.line_numbers, Some(true)); // Filled from lower
     }
 
+    #[test]
+    fn insert_final_newline_is_an_alias() {
+        let partial: PartialEditorConfig =
+            serde_json::from_str(r#"{"insert_final_newline": true}"#).unwrap();
+        assert_eq!(partial.ensure_final_newline_on_save, Some(true));
+    }
+
     #[test]
     fn merge_partial_config_combines_languages() {
         let mut higher = PartialConfig {
@@ -1662,6 +1662,8 @@ mod tests {
                 filenames: vec!["*.conf".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -1706,6 +1708,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -1753,6 +1757,8 @@ mod tests {
                 filenames: vec!["lfrc".to_string()],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -1779,6 +1785,8 @@ mod tests {
                 filenames: vec!["*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2011,6 +2019,8 @@ mod tests {
             filenames: filenames.iter().map(|s| s.to_string()).collect(),
             grammar: grammar.to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
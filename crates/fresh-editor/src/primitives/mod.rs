@@ -22,9 +22,11 @@ pub mod line_wrapping;
 pub mod path_utils;
 pub mod snippet;
 pub mod text_property;
+pub mod urls;
 
 // Modules depending on model::buffer - available for both runtime and WASM
 pub mod line_iterator;
+pub mod selection_scope;
 pub mod word_navigation;
 
 // Modules using ratatui types (Color, Style, etc.) - available for both runtime and WASM
@@ -0,0 +1,210 @@
+//! Structural selection growth for "expand selection to scope"
+//!
+//! Each expansion step picks the smallest structural range that strictly
+//! contains the current selection: the word under the cursor, the inside of
+//! the nearest enclosing bracket pair, that pair including its brackets, a
+//! folding range, the lines the selection covers, and finally the whole
+//! buffer. Brackets are matched on raw bytes, so brackets inside strings and
+//! comments are not skipped.
+
+use std::ops::Range;
+
+use crate::model::buffer::Buffer;
+use crate::primitives::word_navigation::{find_word_end, find_word_start};
+
+const BRACKET_PAIRS: &[(u8, u8)] = &[(b'(', b')'), (b'[', b']'), (b'{', b'}')];
+
+/// Bytes scanned on either side of the selection for an enclosing bracket
+/// pair, so a huge file can't turn one expansion into a full scan
+const MAX_SCOPE_SEARCH_BYTES: usize = 256 * 1024;
+
+/// The next larger scope around `selection`, or `None` once the selection
+/// already covers the whole buffer. `fold_lines` are the first and last
+/// lines (inclusive) of the buffer's folding ranges, e.g. from the
+/// language server.
+pub fn expand_selection(
+    buffer: &Buffer,
+    selection: Range<usize>,
+    fold_lines: &[(usize, usize)],
+) -> Option<Range<usize>> {
+    let mut candidates = Vec::with_capacity(fold_lines.len() + 5);
+
+    let word_start = find_word_start(buffer, selection.start);
+    candidates.push(word_start..find_word_end(buffer, word_start));
+
+    if let Some((open, close)) = enclosing_brackets(buffer, &selection) {
+        candidates.push(open + 1..close);
+        candidates.push(open..close + 1);
+    }
+
+    candidates.extend(
+        fold_lines
+            .iter()
+            .filter_map(|&(first, last)| line_span(buffer, first, last)),
+    );
+    let first_line = buffer.get_line_number(selection.start);
+    let last_line = buffer.get_line_number(selection.end);
+    if let Some(lines) = line_span(buffer, first_line, last_line) {
+        candidates.push(lines.start..lines.end.max(selection.end));
+    }
+    candidates.push(0..buffer.len());
+
+    candidates
+        .into_iter()
+        .filter(|r| {
+            r.start <= selection.start
+                && r.end >= selection.end
+                && r.end - r.start > selection.end - selection.start
+        })
+        .min_by_key(|r| r.end - r.start)
+}
+
+/// Offsets of the nearest bracket pair whose inside contains `selection`
+fn enclosing_brackets(buffer: &Buffer, selection: &Range<usize>) -> Option<(usize, usize)> {
+    let window_start = selection.start.saturating_sub(MAX_SCOPE_SEARCH_BYTES);
+    let window_end = (selection.end + MAX_SCOPE_SEARCH_BYTES).min(buffer.len());
+    let bytes = buffer.slice_bytes(window_start..window_end);
+
+    // Walk outwards through the openers left of the selection until one
+    // closes at or after its end
+    let mut search_end = (selection.start - window_start).min(bytes.len());
+    loop {
+        let open = unmatched_opener_before(&bytes, search_end)?;
+        if let Some(close) = matching_closer(&bytes, open) {
+            if window_start + close >= selection.end {
+                return Some((window_start + open, window_start + close));
+            }
+        }
+        search_end = open;
+    }
+}
+
+/// Index of the last opening bracket in `bytes[..end]` that is not closed
+/// before `end`
+fn unmatched_opener_before(bytes: &[u8], end: usize) -> Option<usize> {
+    let mut depths = [0usize; BRACKET_PAIRS.len()];
+    for idx in (0..end).rev() {
+        for (depth, &(open, close)) in depths.iter_mut().zip(BRACKET_PAIRS) {
+            if bytes[idx] == close {
+                *depth += 1;
+            } else if bytes[idx] == open {
+                if *depth == 0 {
+                    return Some(idx);
+                }
+                *depth -= 1;
+            }
+        }
+    }
+    None
+}
+
+/// Index of the bracket closing the opener at `open`
+fn matching_closer(bytes: &[u8], open: usize) -> Option<usize> {
+    let &(opener, closer) = BRACKET_PAIRS.iter().find(|(o, _)| *o == bytes[open])?;
+    let mut depth = 0usize;
+    for (idx, &b) in bytes.iter().enumerate().skip(open + 1) {
+        if b == opener {
+            depth += 1;
+        } else if b == closer {
+            if depth == 0 {
+                return Some(idx);
+            }
+            depth -= 1;
+        }
+    }
+    None
+}
+
+/// From the start of `first_line` to the end of `last_line`, not counting
+/// its line ending
+fn line_span(buffer: &Buffer, first_line: usize, last_line: usize) -> Option<Range<usize>> {
+    let start = buffer.line_start_offset(first_line)?;
+    let end = match buffer.line_start_offset(last_line + 1) {
+        Some(next_start) if next_start > start => {
+            let ending = buffer.slice_bytes(next_start.saturating_sub(2)..next_start);
+            match ending.as_slice() {
+                [b'\r', b'\n'] => next_start - 2,
+                [.., b'\n'] | [.., b'\r'] => next_start - 1,
+                _ => next_start,
+            }
+        }
+        _ => buffer.len(),
+    };
+    Some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_steps(text: &str, cursor: usize, steps: usize) -> Vec<String> {
+        let buffer = Buffer::from_str_test(text);
+        let mut selection = cursor..cursor;
+        let mut seen = Vec::new();
+        for _ in 0..steps {
+            match expand_selection(&buffer, selection.clone(), &[]) {
+                Some(next) => {
+                    seen.push(text[next.clone()].to_string());
+                    selection = next;
+                }
+                None => break,
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn grows_from_word_through_brackets_to_line() {
+        assert_eq!(
+            expand_steps("let x = foo(bar + baz);\nnext", 13, 5),
+            vec![
+                "bar",
+                "bar + baz",
+                "(bar + baz)",
+                "let x = foo(bar + baz);",
+                "let x = foo(bar + baz);\nnext",
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_brackets_expand_one_level_at_a_time() {
+        assert_eq!(
+            expand_steps("f([a, b], c)", 3, 5),
+            vec!["a", "a, b", "[a, b]", "[a, b], c", "([a, b], c)"]
+        );
+    }
+
+    #[test]
+    fn block_goes_line_then_inside_braces() {
+        let text = "fn f() {\n    body();\n}";
+        assert_eq!(
+            expand_steps(text, 15, 4),
+            vec![
+                "body",
+                "    body();",
+                "\n    body();\n",
+                "{\n    body();\n}",
+            ]
+        );
+    }
+
+    #[test]
+    fn fold_ranges_are_used_as_scopes() {
+        let text = "a\nb\nc\nd";
+        let buffer = Buffer::from_str_test(text);
+        let folds = [(1, 2)];
+        assert_eq!(expand_selection(&buffer, 2..3, &folds), Some(2..5));
+    }
+
+    #[test]
+    fn unbalanced_brackets_fall_back_to_lines() {
+        assert_eq!(expand_steps("x = (a", 5, 2), vec!["a", "x = (a"]);
+    }
+
+    #[test]
+    fn whole_buffer_is_the_last_step() {
+        let buffer = Buffer::from_str_test("abc");
+        assert_eq!(expand_selection(&buffer, 0..3, &[]), None);
+    }
+}
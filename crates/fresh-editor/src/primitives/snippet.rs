@@ -1,12 +1,29 @@
 //! LSP snippet parser and expander.
 //!
-//! Parses LSP snippet syntax and expands it to plain text with cursor positioning.
+//! Parses LSP snippet syntax and expands it to plain text with cursor positioning
+//! and the byte ranges of its tabstops.
 //! Supports:
 //! - `$0` - final cursor position
-//! - `$n` - tabstops (expanded as empty, cursor goes to $0 or end)
+//! - `$n` - tabstops (expanded as empty)
 //! - `${n:text}` - tabstops with default text (uses the default)
 //! - `${n|choice1,choice2|}` - choices (uses first choice)
 //! - `\\$` - escaped dollar sign
+//!
+//! A tabstop number used more than once (`$1 ... $1`) yields one
+//! [`SnippetTabStop`] with a range per occurrence, all expanded with the same
+//! default text; the caller mirrors edits between them.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// A numbered tabstop of an expanded snippet
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetTabStop {
+    /// The tabstop number (`1` for `$1`)
+    pub index: u32,
+    /// Byte ranges of each occurrence within the expanded text, in order
+    pub ranges: Vec<Range<usize>>,
+}
 
 /// Result of expanding a snippet
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +32,8 @@ pub struct ExpandedSnippet {
     pub text: String,
     /// Cursor offset from start of inserted text (where $0 was, or end if no $0)
     pub cursor_offset: usize,
+    /// Tabstops other than `$0`, in ascending order of their number
+    pub tab_stops: Vec<SnippetTabStop>,
 }
 
 /// Expand an LSP snippet to plain text
@@ -32,10 +51,65 @@ pub struct ExpandedSnippet {
 /// let result = expand_snippet("foo(${1:arg})");
 /// assert_eq!(result.text, "foo(arg)");
 /// assert_eq!(result.cursor_offset, 8); // cursor at end (no $0)
+/// assert_eq!(result.tab_stops[0].ranges, vec![4..7]);
 /// ```
 pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
-    let mut result = String::new();
-    let mut cursor_offset: Option<usize> = None;
+    let mut text = String::new();
+    let mut stops = Vec::new();
+    expand_into(snippet, &mut text, &mut stops, &HashMap::new());
+
+    // Occurrences of a tabstop without their own default mirror the text of
+    // the first one that has it.
+    let mut defaults: HashMap<u32, String> = HashMap::new();
+    for (index, range) in &stops {
+        if *index != 0 && !range.is_empty() {
+            defaults
+                .entry(*index)
+                .or_insert_with(|| text[range.clone()].to_string());
+        }
+    }
+    if !defaults.is_empty() {
+        text.clear();
+        stops.clear();
+        expand_into(snippet, &mut text, &mut stops, &defaults);
+    }
+
+    let cursor_offset = stops
+        .iter()
+        .find(|(index, _)| *index == 0)
+        .map(|(_, range)| range.start)
+        .unwrap_or(text.len());
+
+    // Nested placeholders are recorded before the one containing them, so
+    // order occurrences by position.
+    stops.sort_by_key(|(index, range)| (*index, range.start));
+    let mut tab_stops: Vec<SnippetTabStop> = Vec::new();
+    for (index, range) in stops.into_iter().filter(|(index, _)| *index != 0) {
+        match tab_stops.last_mut() {
+            Some(stop) if stop.index == index => stop.ranges.push(range),
+            _ => tab_stops.push(SnippetTabStop {
+                index,
+                ranges: vec![range],
+            }),
+        }
+    }
+
+    ExpandedSnippet {
+        text,
+        cursor_offset,
+        tab_stops,
+    }
+}
+
+/// Append the expansion of `snippet` to `result`, recording every tabstop
+/// occurrence (including `$0`) as `(number, byte range in result)`. Bare
+/// tabstops expand to their entry in `defaults`, if any.
+fn expand_into(
+    snippet: &str,
+    result: &mut String,
+    stops: &mut Vec<(u32, Range<usize>)>,
+    defaults: &HashMap<u32, String>,
+) {
     let mut chars = snippet.chars().peekable();
 
     while let Some(c) = chars.next() {
@@ -54,11 +128,12 @@ pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
                 if next == '{' {
                     // ${...} syntax
                     chars.next(); // consume '{'
-                    let (expanded, is_final) = parse_brace_placeholder(&mut chars);
-                    if is_final {
-                        cursor_offset = Some(result.len());
+                    let content = collect_brace_content(&mut chars);
+                    let start = result.len();
+                    let tabstop = expand_placeholder_content(&content, result, stops, defaults);
+                    if let Some(tabstop) = tabstop {
+                        stops.push((tabstop, start..result.len()));
                     }
-                    result.push_str(&expanded);
                 } else if next.is_ascii_digit() {
                     // $n syntax
                     let mut num = String::new();
@@ -69,10 +144,13 @@ pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
                             break;
                         }
                     }
-                    if num == "0" {
-                        cursor_offset = Some(result.len());
+                    if let Ok(tabstop) = num.parse::<u32>() {
+                        let start = result.len();
+                        if let Some(default) = defaults.get(&tabstop) {
+                            result.push_str(default);
+                        }
+                        stops.push((tabstop, start..result.len()));
                     }
-                    // Other tabstops expand to nothing
                 } else {
                     // Not a valid placeholder, keep the $
                     result.push(c);
@@ -84,16 +162,10 @@ pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
             result.push(c);
         }
     }
-
-    ExpandedSnippet {
-        cursor_offset: cursor_offset.unwrap_or(result.len()),
-        text: result,
-    }
 }
 
-/// Parse a ${...} placeholder
-/// Returns (expanded_text, is_final_cursor)
-fn parse_brace_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) -> (String, bool) {
+/// Collect the content of a ${...} placeholder, up to its matching '}'
+fn collect_brace_content(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
     let mut content = String::new();
     let mut depth = 1;
 
@@ -109,9 +181,14 @@ fn parse_brace_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) ->
             }
             content.push(c);
         } else if c == '\\' {
-            // Handle escapes inside braces
+            // Keep escapes for the nested expansion, except a brace-level `\|`
             if let Some(&next) = chars.peek() {
-                if next == '$' || next == '\\' || next == '}' || next == '|' {
+                if next == '|' {
+                    content.push(chars.next().unwrap());
+                    continue;
+                }
+                if next == '$' || next == '\\' || next == '}' {
+                    content.push(c);
                     content.push(chars.next().unwrap());
                     continue;
                 }
@@ -122,16 +199,17 @@ fn parse_brace_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) ->
         }
     }
 
-    // Parse the content: n or n:default or n|choices|
-    let (tabstop, default) = parse_placeholder_content(&content);
-
-    let is_final = tabstop == Some(0);
-    (default, is_final)
+    content
 }
 
-/// Parse placeholder content like "1", "1:default", or "1|a,b,c|"
-/// Returns (tabstop_number, default_text)
-fn parse_placeholder_content(content: &str) -> (Option<u32>, String) {
+/// Expand placeholder content like "1", "1:default", or "1|a,b,c|" into
+/// `result`. Returns the tabstop number, if the content has one.
+fn expand_placeholder_content(
+    content: &str,
+    result: &mut String,
+    stops: &mut Vec<(u32, Range<usize>)>,
+    defaults: &HashMap<u32, String>,
+) -> Option<u32> {
     // Find the tabstop number
     let mut chars = content.chars().peekable();
     let mut num_str = String::new();
@@ -153,8 +231,7 @@ fn parse_placeholder_content(content: &str) -> (Option<u32>, String) {
             chars.next(); // consume ':'
             let default: String = chars.collect();
             // Recursively expand nested snippets in default
-            let expanded = expand_snippet(&default);
-            (tabstop, expanded.text)
+            expand_into(&default, result, stops, defaults);
         }
         Some('|') => {
             // Choices: ${n|choice1,choice2|}
@@ -164,13 +241,35 @@ fn parse_placeholder_content(content: &str) -> (Option<u32>, String) {
             let choices = choices.trim_end_matches('|');
             // Use first choice
             let first_choice = choices.split(',').next().unwrap_or("");
-            (tabstop, first_choice.to_string())
+            result.push_str(&unescape(first_choice));
         }
         _ => {
             // Just a tabstop number, no default
-            (tabstop, String::new())
+            if let Some(default) = tabstop.and_then(|t| defaults.get(&t)) {
+                result.push_str(default);
+            }
         }
     }
+
+    tabstop
+}
+
+/// Drop the backslash of `\$`, `\\` and `\}` escapes
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(&next) = chars.peek() {
+                if next == '$' || next == '\\' || next == '}' {
+                    out.push(chars.next().unwrap());
+                    continue;
+                }
+            }
+        }
+        out.push(c);
+    }
+    out
 }
 
 /// Check if a string contains snippet syntax
@@ -271,6 +370,40 @@ mod tests {
         assert!(!is_snippet("\\$100")); // escaped
     }
 
+    #[test]
+    fn test_tab_stop_ranges() {
+        let result = expand_snippet("fn ${1:name}(${2}) { $0 }");
+        assert_eq!(result.text, "fn name() {  }");
+        assert_eq!(
+            result.tab_stops,
+            vec![
+                SnippetTabStop {
+                    index: 1,
+                    ranges: vec![3..7],
+                },
+                SnippetTabStop {
+                    index: 2,
+                    ranges: vec![8..8],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repeated_tab_stop_is_mirrored() {
+        let result = expand_snippet("let ${1:x} = 1; $1 + ${2:y}");
+        assert_eq!(result.text, "let x = 1; x + y");
+        assert_eq!(result.tab_stops[0].ranges, vec![4..5, 11..12]);
+        assert_eq!(result.tab_stops[1].ranges, vec![15..16]);
+    }
+
+    #[test]
+    fn test_nested_tab_stop_ranges() {
+        let result = expand_snippet("${1:foo${2:bar}}");
+        assert_eq!(result.tab_stops[0].ranges, vec![0..6]);
+        assert_eq!(result.tab_stops[1].ranges, vec![3..6]);
+    }
+
     #[test]
     fn test_empty_default() {
         let result = expand_snippet("foo(${1:})");
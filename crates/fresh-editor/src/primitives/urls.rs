@@ -0,0 +1,124 @@
+//! URL detection in plain text.
+//!
+//! Deliberately conservative: only `http://` and `https://` URLs with a
+//! dotted host (or `localhost`) count, they may not start inside a word,
+//! and they stop at the first character that can't appear in a URL. Trailing
+//! sentence punctuation and unbalanced closing brackets (as in
+//! `(see https://example.com)`) are left out.
+
+use std::ops::Range;
+
+const SCHEMES: &[&str] = &["https://", "http://"];
+
+/// Whether `b` can appear in a URL (RFC 3986 unreserved, reserved and `%`).
+fn is_url_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=%".contains(&b)
+}
+
+/// Length of `url` without trailing punctuation and unbalanced `)`/`]`.
+fn trimmed_len(url: &str) -> usize {
+    let mut url = url;
+    loop {
+        let unbalanced = |open: char, close: char| {
+            url.ends_with(close) && url.matches(open).count() < url.matches(close).count()
+        };
+        if url.ends_with(['.', ',', ';', ':', '!', '?', '\'', '*'])
+            || unbalanced('(', ')')
+            || unbalanced('[', ']')
+        {
+            url = &url[..url.len() - 1];
+        } else {
+            return url.len();
+        }
+    }
+}
+
+/// Whether the authority of a URL (after the scheme) names a plausible host.
+fn has_host(rest: &str) -> bool {
+    let host = rest
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .rsplit('@')
+        .next()
+        .unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+    host.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && (host == "localhost" || (host.contains('.') && !host.ends_with('.')))
+}
+
+/// Byte ranges of the URLs in `text`, in order.
+pub fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut urls = Vec::new();
+    let mut from = 0;
+    while let Some(found) = text[from..].find("http") {
+        let start = from + found;
+        from = start + "http".len();
+        if start > 0 && (bytes[start - 1].is_ascii_alphanumeric() || bytes[start - 1] == b'_') {
+            continue;
+        }
+        let Some(scheme) = SCHEMES.iter().find(|s| text[start..].starts_with(**s)) else {
+            continue;
+        };
+        let mut end = start + scheme.len();
+        while end < bytes.len() && is_url_byte(bytes[end]) {
+            end += 1;
+        }
+        let end = start + trimmed_len(&text[start..end]);
+        if end > start + scheme.len() && has_host(&text[start + scheme.len()..end]) {
+            urls.push(start..end);
+            from = end;
+        }
+    }
+    urls
+}
+
+/// The URL in `text` at byte `offset`, including just past its end.
+pub fn url_at(text: &str, offset: usize) -> Option<&str> {
+    find_urls(text)
+        .into_iter()
+        .find(|range| range.start <= offset && offset <= range.end)
+        .map(|range| &text[range])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(text: &str) -> Vec<&str> {
+        find_urls(text).into_iter().map(|r| &text[r]).collect()
+    }
+
+    #[test]
+    fn test_find_urls_trims_surrounding_punctuation() {
+        assert_eq!(
+            urls("See https://example.com/docs. Or (http://localhost:8080/x)."),
+            vec!["https://example.com/docs", "http://localhost:8080/x"]
+        );
+        assert_eq!(
+            urls("<https://en.wikipedia.org/wiki/Rust_(programming_language)>"),
+            vec!["https://en.wikipedia.org/wiki/Rust_(programming_language)"]
+        );
+        assert_eq!(
+            urls(r#"url = "https://a.io/q?x=1&y=2#top""#),
+            vec!["https://a.io/q?x=1&y=2#top"]
+        );
+    }
+
+    #[test]
+    fn test_find_urls_rejects_non_urls() {
+        assert!(urls("http_client.rs uses http:// and https://").is_empty());
+        assert!(urls("xhttps://example.com").is_empty());
+        assert!(urls("https://nohost and https://-bad.com").is_empty());
+        assert!(urls("ftp://example.com").is_empty());
+    }
+
+    #[test]
+    fn test_url_at() {
+        let text = "go to https://example.com now";
+        assert_eq!(url_at(text, 6), Some("https://example.com"));
+        assert_eq!(url_at(text, 25), Some("https://example.com"));
+        assert_eq!(url_at(text, 2), None);
+    }
+}
@@ -1,18 +1,51 @@
 //! Word boundary detection and navigation helpers
+//!
+//! Text is walked by grapheme cluster (`unicode-segmentation`) and each
+//! cluster is classified as word, whitespace, or punctuation. Word clusters
+//! also carry a script bucket so that runs of CJK text and adjacent Latin
+//! text (`hello世界`, `テキスト日本語`) are separate words.
 
 use crate::model::buffer::Buffer;
 use crate::primitives::grapheme::{next_grapheme_boundary, prev_grapheme_boundary};
 
+/// Scripts whose runs form words of their own. Everything else that is
+/// alphanumeric (Latin, Cyrillic, Greek, digits, ...) is `Other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordScript {
+    Other,
+    Han,
+    Hiragana,
+    Katakana,
+    Hangul,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CharClass {
-    Word,
+    Word(WordScript),
     Whitespace,
     Punctuation,
 }
 
+impl CharClass {
+    fn is_word(self) -> bool {
+        matches!(self, CharClass::Word(_))
+    }
+}
+
+fn word_script(c: char) -> WordScript {
+    match c as u32 {
+        0x3005 | 0x3007 | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => WordScript::Han,
+        0x20000..=0x3FFFF => WordScript::Han,
+        0x3041..=0x309F => WordScript::Hiragana,
+        0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => WordScript::Katakana,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => WordScript::Hangul,
+        _ => WordScript::Other,
+    }
+}
+
 fn get_grapheme_class(g: &str) -> CharClass {
-    if g.chars().any(|c| c.is_alphanumeric() || c == '_') {
-        CharClass::Word
+    if let Some(c) = g.chars().find(|c| c.is_alphanumeric() || *c == '_') {
+        CharClass::Word(word_script(c))
     } else if g.chars().all(|c| c.is_whitespace()) {
         CharClass::Whitespace
     } else {
@@ -210,22 +243,28 @@ pub fn find_word_start(buffer: &Buffer, pos: usize) -> usize {
     let mut current_idx = offset;
 
     // If we're at the end or at a non-word character, step left once
-    let is_non_word = current_idx >= text.len() || {
-        let next = next_grapheme_boundary(&text, current_idx);
-        get_grapheme_class(&text[current_idx..next]) != CharClass::Word
+    let class_at = |idx: usize| {
+        let next = next_grapheme_boundary(&text, idx);
+        get_grapheme_class(&text[idx..next])
     };
+    let is_non_word = current_idx >= text.len() || !class_at(current_idx).is_word();
     if is_non_word && current_idx > 0 {
         current_idx = prev_grapheme_boundary(&text, current_idx);
     }
 
-    // Scan left while we're on word characters
+    // Scan left while we're on word characters of the same script
+    let mut target = Some(current_idx)
+        .filter(|&idx| idx < text.len())
+        .map(class_at)
+        .filter(|class| class.is_word());
     while current_idx > 0 {
         let prev = prev_grapheme_boundary(&text, current_idx);
-        if get_grapheme_class(&text[prev..current_idx]) == CharClass::Word {
-            current_idx = prev;
-        } else {
+        let class = get_grapheme_class(&text[prev..current_idx]);
+        if !class.is_word() || target.is_some_and(|t| t != class) {
             break;
         }
+        target = Some(class);
+        current_idx = prev;
     }
 
     // Convert back to absolute position
@@ -252,19 +291,21 @@ pub fn find_word_end(buffer: &Buffer, pos: usize) -> usize {
     let mut current_idx = 0;
 
     // Skip non-word characters to find start of next word
+    let mut target = None;
     while current_idx < text.len() {
         let next = next_grapheme_boundary(&text, current_idx);
-        if get_grapheme_class(&text[current_idx..next]) != CharClass::Word {
-            current_idx = next;
-        } else {
+        let class = get_grapheme_class(&text[current_idx..next]);
+        if class.is_word() {
+            target = Some(class);
             break;
         }
+        current_idx = next;
     }
 
-    // Consume word characters
+    // Consume word characters of the same script
     while current_idx < text.len() {
         let next = next_grapheme_boundary(&text, current_idx);
-        if get_grapheme_class(&text[current_idx..next]) == CharClass::Word {
+        if Some(get_grapheme_class(&text[current_idx..next])) == target {
             current_idx = next;
         } else {
             break;
@@ -363,12 +404,12 @@ pub fn find_word_end_right(buffer: &Buffer, pos: usize) -> usize {
     let start_class = get_grapheme_class(&text[current_idx..next_bound]);
 
     match start_class {
-        CharClass::Word => {
+        CharClass::Word(_) => {
             // In a word: jump to end of current word
             while current_idx < text.len() {
                 let next = next_grapheme_boundary(&text, current_idx);
                 let g = &text[current_idx..next];
-                if get_grapheme_class(g) == CharClass::Word {
+                if get_grapheme_class(g) == start_class {
                     current_idx = next;
                 } else {
                     break;
@@ -546,6 +587,114 @@ pub fn find_word_start_right(buffer: &Buffer, pos: usize) -> usize {
     start + current_idx
 }
 
+/// Whether a camelCase / snake_case subword starts at `cur`, given the
+/// characters around it. Only meaningful between two word characters.
+fn is_subword_boundary(prev: char, cur: char, next: Option<char>) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    if !is_word(prev) || !is_word(cur) {
+        return false;
+    }
+    // fooBar, v2Beta
+    ((prev.is_lowercase() || prev.is_numeric()) && cur.is_uppercase())
+        // HTTPServer: the last capital of a run starts the next subword
+        || (prev.is_uppercase() && cur.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+        // snake_case: a subword starts after the underscores
+        || (prev == '_' && cur != '_')
+}
+
+/// Subword boundaries strictly inside `text`, as byte offsets.
+fn subword_boundaries(text: &str) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    (1..chars.len())
+        .filter(|&i| {
+            let next = chars.get(i + 1).map(|&(_, c)| c);
+            is_subword_boundary(chars[i - 1].1, chars[i].1, next)
+        })
+        .map(|i| chars[i].0)
+        .collect()
+}
+
+/// Target of a word-left motion (Ctrl+Left). With `subword`, camelCase and
+/// snake_case parts of an identifier are stops too.
+pub fn word_motion_left(buffer: &Buffer, pos: usize, subword: bool) -> usize {
+    let target = find_word_start_left(buffer, pos);
+    if !subword || target >= pos {
+        return target;
+    }
+    let bytes = buffer.slice_bytes(target..pos.min(buffer.len()));
+    let text = String::from_utf8_lossy(&bytes);
+    subword_boundaries(&text)
+        .last()
+        .map_or(target, |&offset| target + offset)
+}
+
+/// Target of a word-right motion (Ctrl+Right). With `subword`, camelCase
+/// and snake_case parts of an identifier are stops too.
+pub fn word_motion_right(buffer: &Buffer, pos: usize, subword: bool) -> usize {
+    let target = find_word_start_right(buffer, pos);
+    if !subword || target <= pos {
+        return target;
+    }
+    let bytes = buffer.slice_bytes(pos..target);
+    let text = String::from_utf8_lossy(&bytes);
+    subword_boundaries(&text)
+        .first()
+        .map_or(target, |&offset| pos + offset)
+}
+
+/// Start of the range deleted by Ctrl+Backspace. Uses the same stops as
+/// [`word_motion_left`] but never crosses into the previous line, except
+/// at the start of a line, where only the line break is deleted.
+pub fn word_delete_start(buffer: &Buffer, pos: usize, subword: bool) -> usize {
+    let pos = pos.min(buffer.len());
+    if pos == 0 {
+        return 0;
+    }
+    let start = pos.saturating_sub(1000);
+    let bytes = buffer.slice_bytes(start..pos);
+    let text = String::from_utf8_lossy(&bytes);
+    if text.ends_with("\r\n") {
+        return pos - 2;
+    }
+    if text.ends_with('\n') {
+        return pos - 1;
+    }
+    // Offsets are taken from the end of `text`, so a character cut in half
+    // at the start of the window doesn't shift them.
+    let line_start = text
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c == '\n')
+        .map_or(start, |(i, _)| pos - (text.len() - i) + 1);
+    word_motion_left(buffer, pos, subword).max(line_start)
+}
+
+/// End of the range deleted by Ctrl+Delete. Uses the same stops as
+/// [`word_motion_right`] but never crosses into the next line, except at
+/// the end of a line, where only the line break is deleted.
+pub fn word_delete_end(buffer: &Buffer, pos: usize, subword: bool) -> usize {
+    let buf_len = buffer.len();
+    if pos >= buf_len {
+        return buf_len;
+    }
+    let bytes = buffer.slice_bytes(pos..(pos + 1000).min(buf_len));
+    if bytes.starts_with(b"\r\n") {
+        return pos + 2;
+    }
+    if bytes.starts_with(b"\n") {
+        return pos + 1;
+    }
+    let target = word_motion_right(buffer, pos, subword);
+    let line_end = bytes.iter().position(|&b| b == b'\n').map(|i| {
+        if i > 0 && bytes[i - 1] == b'\r' {
+            pos + i - 1
+        } else {
+            pos + i
+        }
+    });
+    line_end.map_or(target, |end| target.min(end))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -593,6 +742,89 @@ mod tests {
         assert_eq!(find_word_start_right(&buffer, 6), 12); // From "world" to "test"
     }
 
+    #[test]
+    fn test_word_motion_splits_cjk_from_latin() {
+        // "hello" (5) + "世界" (6) + "テキスト" (12) + "日本語" (9)
+        let buffer = Buffer::from_str_test("hello世界テキスト日本語");
+        assert_eq!(find_word_start_right(&buffer, 0), 5);
+        assert_eq!(find_word_start_right(&buffer, 5), 11);
+        assert_eq!(find_word_start_right(&buffer, 11), 23);
+        assert_eq!(find_word_start_right(&buffer, 23), 32);
+        assert_eq!(find_word_start_left(&buffer, 32), 23);
+        assert_eq!(find_word_start_left(&buffer, 23), 11);
+        assert_eq!(find_word_start_left(&buffer, 11), 5);
+        assert_eq!(find_word_start_left(&buffer, 5), 0);
+        // Word under cursor stays within one script
+        assert_eq!(find_word_start(&buffer, 14), 11);
+        assert_eq!(find_word_end(&buffer, 14), 23);
+    }
+
+    #[test]
+    fn test_word_motion_over_cjk_sentence_with_punctuation() {
+        // "日本語" (9) + "、" (3) + "한국어" (9) + "。" (3)
+        let buffer = Buffer::from_str_test("日本語、한국어。");
+        assert_eq!(find_word_start_right(&buffer, 0), 9);
+        assert_eq!(find_word_start_right(&buffer, 9), 12);
+        assert_eq!(find_word_start_right(&buffer, 12), 21);
+        assert_eq!(find_word_start_left(&buffer, 21), 12);
+    }
+
+    #[test]
+    fn test_word_motion_over_punctuation_clusters() {
+        let buffer = Buffer::from_str_test("a->b ...!? c");
+        assert_eq!(find_word_start_right(&buffer, 0), 1);
+        assert_eq!(find_word_start_right(&buffer, 1), 3); // "->" is one stop
+        assert_eq!(find_word_start_right(&buffer, 3), 5);
+        assert_eq!(find_word_start_right(&buffer, 5), 11); // "...!?" then space
+        assert_eq!(find_word_start_left(&buffer, 11), 5);
+        assert_eq!(find_word_start_left(&buffer, 3), 1);
+    }
+
+    #[test]
+    fn test_word_motion_with_mixed_scripts_and_accents() {
+        // "naïve" (6) + " " + "Привет" (12) + "中文" (6)
+        let buffer = Buffer::from_str_test("naïve Привет中文");
+        assert_eq!(word_motion_right(&buffer, 0, false), 7);
+        assert_eq!(word_motion_right(&buffer, 7, false), 19);
+        assert_eq!(word_motion_left(&buffer, 25, false), 19);
+        assert_eq!(word_motion_left(&buffer, 19, false), 7);
+    }
+
+    #[test]
+    fn test_subword_motion() {
+        let buffer = Buffer::from_str_test("parseHTTPServer snake_case_name v2Beta");
+        assert_eq!(word_motion_right(&buffer, 0, true), 5); // parse|HTTP
+        assert_eq!(word_motion_right(&buffer, 5, true), 9); // HTTP|Server
+        assert_eq!(word_motion_right(&buffer, 9, true), 16); // Server, space
+        assert_eq!(word_motion_right(&buffer, 16, true), 22); // snake_|case
+        assert_eq!(word_motion_left(&buffer, 31, true), 27); // case_|name
+        assert_eq!(word_motion_left(&buffer, 38, true), 34); // v2|Beta
+                                                             // Without subwords the identifier is a single stop
+        assert_eq!(word_motion_right(&buffer, 0, false), 16);
+    }
+
+    #[test]
+    fn test_word_delete_stays_on_line() {
+        let buffer = Buffer::from_str_test("foo\n    bar  \r\nbaz");
+        // Mid-line: stops at the line start instead of eating "foo\n"
+        assert_eq!(word_delete_start(&buffer, 8, false), 4);
+        // At the line start only the line break goes
+        assert_eq!(word_delete_start(&buffer, 4, false), 3);
+        assert_eq!(word_delete_start(&buffer, 15, false), 13);
+        // Forward: trailing whitespace up to the CRLF, then the CRLF alone
+        assert_eq!(word_delete_end(&buffer, 11, false), 13);
+        assert_eq!(word_delete_end(&buffer, 13, false), 15);
+    }
+
+    #[test]
+    fn test_word_delete_start_after_multibyte_text() {
+        // "añó\n" is 6 bytes, so "bär" starts at byte 6
+        let buffer = Buffer::from_str_test("añó\nbär über");
+        assert_eq!(word_delete_start(&buffer, 10, false), 6);
+        assert_eq!(word_delete_start(&buffer, 6, false), 5);
+        assert_eq!(word_delete_start(&buffer, 16, false), 11);
+    }
+
     // ========================================================================
     // Tests for byte-level word navigation (shared by Buffer and String)
     // ========================================================================
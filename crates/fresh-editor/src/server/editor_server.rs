@@ -26,7 +26,8 @@ use crate::server::capture_backend::{
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths, StreamWrapper};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    ClientControl, ServerControl, ServerHello, ServerStats, TermSize, VersionMismatch,
+    PROTOCOL_VERSION,
 };
 use crate::view::color_support::ColorCapability;
 
@@ -36,7 +37,8 @@ pub struct EditorServerConfig {
     pub working_dir: PathBuf,
     /// Optional session name
     pub session_name: Option<String>,
-    /// Idle timeout before auto-shutdown
+    /// How long the server keeps running with no client attached before
+    /// it shuts down. Servers with unsaved changes stay alive regardless.
     pub idle_timeout: Option<Duration>,
     /// Editor configuration
     pub editor_config: Config,
@@ -93,6 +95,40 @@ pub struct EditorServer {
     /// server is dropped.
     #[allow(dead_code)]
     session_keepalive: Option<Box<dyn std::any::Any + Send>>,
+    /// When the server started (for `ServerStats::uptime_secs`)
+    started_at: Instant,
+    /// Recent render durations (for `ServerStats::render_avg_ms`)
+    render_times: RenderTimes,
+    /// A client asked for an immediate redraw (`ClientControl::Refresh`)
+    refresh_requested: bool,
+}
+
+/// Rolling window of recent render durations.
+#[derive(Default)]
+struct RenderTimes {
+    recent: std::collections::VecDeque<Duration>,
+    frames: u64,
+}
+
+impl RenderTimes {
+    /// Number of frames the rolling average covers
+    const WINDOW: usize = 60;
+
+    fn record(&mut self, elapsed: Duration) {
+        if self.recent.len() == Self::WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(elapsed);
+        self.frames += 1;
+    }
+
+    fn average_ms(&self) -> f64 {
+        if self.recent.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.recent.iter().sum();
+        total.as_secs_f64() * 1000.0 / self.recent.len() as f64
+    }
 }
 
 /// Buffered writer for sending data to a client without blocking the server loop.
@@ -205,9 +241,41 @@ impl EditorServer {
             waiting_clients: std::collections::HashMap::new(),
             current_authority,
             session_keepalive,
+            started_at: Instant::now(),
+            render_times: RenderTimes::default(),
+            refresh_requested: false,
         })
     }
 
+    /// Session identifier reported to clients: the session name, or the
+    /// encoded working directory for directory sessions.
+    fn session_id(&self) -> String {
+        self.config
+            .session_name
+            .clone()
+            .unwrap_or_else(|| crate::workspace::encode_path_for_filename(&self.config.working_dir))
+    }
+
+    /// Snapshot of the server's metrics
+    pub fn stats(&self) -> ServerStats {
+        let editor = self.editor.as_ref();
+        let chunk_cache = editor.map(|e| e.chunk_cache_stats()).unwrap_or_default();
+        ServerStats {
+            session_id: self.session_id(),
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            uptime_secs: self.started_at.elapsed().as_secs(),
+            attached_clients: self.clients.len(),
+            buffer_count: editor.map_or(0, |e| e.open_buffer_count()),
+            modified_buffer_count: editor.map_or(0, |e| e.modified_buffer_count()),
+            loaded_bytes: editor.map_or(0, |e| e.loaded_buffer_bytes() as u64),
+            frames_rendered: self.render_times.frames,
+            render_avg_ms: self.render_times.average_ms(),
+            chunk_cache_hits: chunk_cache.hits,
+            chunk_cache_misses: chunk_cache.misses,
+            chunk_cache_resident: chunk_cache.resident,
+        }
+    }
+
     /// Get a handle to request shutdown
     pub fn shutdown_handle(&self) -> Arc<AtomicBool> {
         self.shutdown.clone()
@@ -235,7 +303,10 @@ impl EditorServer {
         let mut next_client_id = 1u64;
         let mut needs_render = true;
         let mut last_render = Instant::now();
-        const FRAME_DURATION: Duration = Duration::from_millis(16); // 60fps
+        // Renders are capped at `session_max_fps` (0 counts as 1); state
+        // changes within one frame are drawn together by the next render.
+        let max_fps = self.config.editor_config.editor.session_max_fps.max(1);
+        let frame_duration = Duration::from_secs(1) / max_fps;
 
         loop {
             // Check for shutdown
@@ -244,9 +315,16 @@ impl EditorServer {
                 break;
             }
 
-            // Check idle timeout
+            // Check idle timeout. Unsaved changes keep the server alive so a
+            // later attach can still save them.
             if let Some(timeout) = self.config.idle_timeout {
-                if self.clients.is_empty() && self.last_client_activity.elapsed() > timeout {
+                if self.clients.is_empty()
+                    && self.last_client_activity.elapsed() > timeout
+                    && !self
+                        .editor
+                        .as_ref()
+                        .is_some_and(|editor| editor.has_modified_buffers())
+                {
                     tracing::info!("Idle timeout reached, shutting down");
                     break;
                 }
@@ -347,27 +425,29 @@ impl EditorServer {
                 .map(|e| e.should_detach())
                 .unwrap_or(false);
             if detach_requested {
+                if let Some(ref mut editor) = self.editor {
+                    editor.flush_auto_save();
+                }
                 // Detach only the client that triggered it (via last input)
                 if let Some(idx) = self.last_input_client.take() {
                     if idx < self.clients.len() {
                         tracing::info!("Client {} requested detach", self.clients[idx].id);
                         let client = self.clients.remove(idx);
+                        self.last_client_activity = Instant::now();
                         let teardown = terminal_teardown_sequences();
                         // Best-effort: client may already be disconnected
                         #[allow(clippy::let_underscore_must_use)]
                         let _ = client.data_writer.try_write(&teardown);
-                        let quit_msg = serde_json::to_string(&ServerControl::Quit {
-                            reason: "Detached".to_string(),
-                        })
-                        .unwrap_or_default();
+                        let detached_msg =
+                            serde_json::to_string(&ServerControl::Detached).unwrap_or_default();
                         // Best-effort: client may already be disconnected
                         #[allow(clippy::let_underscore_must_use)]
-                        let _ = client.conn.write_control(&quit_msg);
+                        let _ = client.conn.write_control(&detached_msg);
                     }
                 } else {
                     // Fallback: if we can't determine which client, detach all
                     tracing::info!("Detach requested but no input source, detaching all");
-                    self.disconnect_all_clients("Detached")?;
+                    self.disconnect_all_clients(&ServerControl::Detached)?;
                 }
                 // Reset the detach flag
                 if let Some(ref mut editor) = self.editor {
@@ -421,6 +501,7 @@ impl EditorServer {
                         needs_render = true;
                     }
                 }
+                self.send_osc52_query(input_source);
             }
 
             // Process async messages from editor
@@ -463,8 +544,15 @@ impl EditorServer {
                 if editor.check_mouse_hover_timer() {
                     needs_render = true;
                 }
+                if editor.check_hover_timeout() {
+                    needs_render = true;
+                }
+                editor.check_recent_files_save();
+                if editor.check_drag_auto_scroll() {
+                    needs_render = true;
+                }
 
-                // Active animations force a render every FRAME_DURATION so
+                // Active animations force a render every frame_duration so
                 // the slide settles on its own. Without this the loop only
                 // ticks when an external event (input, resize, async
                 // message) flips `needs_render`, so under tmux a buffer
@@ -476,8 +564,10 @@ impl EditorServer {
                 }
             }
 
-            // Render and broadcast if needed
-            if needs_render && last_render.elapsed() >= FRAME_DURATION {
+            // Render and broadcast if needed. A refresh request skips the
+            // frame-rate cap.
+            let refresh = std::mem::take(&mut self.refresh_requested);
+            if refresh || (needs_render && last_render.elapsed() >= frame_duration) {
                 self.render_and_broadcast()?;
                 last_render = Instant::now();
                 needs_render = false;
@@ -491,7 +581,7 @@ impl EditorServer {
         // in run_event_loop_common: auto-save, end recovery session, save workspace.
         if let Some(ref mut editor) = self.editor {
             // Auto-save file-backed buffers to disk before exiting
-            if editor.config().editor.auto_save_enabled {
+            if editor.config().editor.auto_save_mode() != crate::config::AutoSaveMode::Off {
                 match editor.save_all_on_exit() {
                     Ok(count) if count > 0 => {
                         tracing::info!("Auto-saved {} buffer(s) on exit", count);
@@ -508,6 +598,7 @@ impl EditorServer {
             if let Err(e) = editor.end_recovery_session() {
                 tracing::warn!("Failed to end recovery session: {}", e);
             }
+            editor.save_recent_files();
             if let Err(e) = editor.save_workspace() {
                 tracing::warn!("Failed to save workspace: {}", e);
             } else {
@@ -516,7 +607,9 @@ impl EditorServer {
         }
 
         // Clean shutdown
-        self.disconnect_all_clients("Server shutting down")?;
+        self.disconnect_all_clients(&ServerControl::Quit {
+            reason: "Server shutting down".to_string(),
+        })?;
 
         Ok(())
     }
@@ -534,7 +627,8 @@ impl EditorServer {
         // construction so plugins and init.ts load against the correct
         // backend from the first tick.
         let filesystem = self.current_authority.filesystem.clone();
-        let color_capability = ColorCapability::TrueColor; // Assume truecolor for now
+        // Replaced by the attached clients' capability before each render
+        let color_capability = ColorCapability::TrueColor;
 
         let mut editor = Editor::with_working_dir(
             self.config.editor_config.clone(),
@@ -647,7 +741,7 @@ impl EditorServer {
         // mirroring the standalone exit path.  On failure we log and
         // continue — rebuild should still succeed.
         if let Some(ref mut editor) = self.editor {
-            if editor.config().editor.auto_save_enabled {
+            if editor.config().editor.auto_save_mode() != crate::config::AutoSaveMode::Off {
                 if let Err(e) = editor.save_all_on_exit() {
                     tracing::warn!("Rebuild: failed to auto-save on exit: {}", e);
                 }
@@ -655,6 +749,7 @@ impl EditorServer {
             if let Err(e) = editor.end_recovery_session() {
                 tracing::warn!("Rebuild: failed to end recovery session: {}", e);
             }
+            editor.save_recent_files();
             if let Err(e) = editor.save_workspace() {
                 tracing::warn!("Rebuild: failed to save workspace: {}", e);
             }
@@ -760,9 +855,7 @@ impl EditorServer {
         }
 
         // Send server hello
-        let session_id = self.config.session_name.clone().unwrap_or_else(|| {
-            crate::workspace::encode_path_for_filename(&self.config.working_dir)
-        });
+        let session_id = self.session_id();
 
         let server_hello = ServerHello::new(session_id);
         let response = serde_json::to_string(&ServerControl::Hello(server_hello))
@@ -962,6 +1055,9 @@ impl EditorServer {
                 }
                 ClientControl::Detach => {
                     tracing::info!("Client {} detached", idx);
+                    if let Some(ref mut editor) = self.editor {
+                        editor.flush_auto_save();
+                    }
                     disconnected.push(idx);
                 }
                 ClientControl::OpenFiles { files, wait } => {
@@ -1011,6 +1107,22 @@ impl EditorServer {
                         resize_occurred = true; // Force re-render
                     }
                 }
+                ClientControl::Refresh => {
+                    if let Some(client) = self.clients.get_mut(idx) {
+                        client.needs_full_render = true;
+                        self.refresh_requested = true;
+                    }
+                }
+                ClientControl::Stats => {
+                    let stats = self.stats();
+                    if let Some(client) = self.clients.get_mut(idx) {
+                        let reply =
+                            serde_json::to_string(&ServerControl::Stats(stats)).unwrap_or_default();
+                        // Best-effort reply
+                        #[allow(clippy::let_underscore_must_use)]
+                        let _ = client.conn.write_control(&reply);
+                    }
+                }
                 ClientControl::Quit => unreachable!(), // Handled above
             }
         }
@@ -1030,6 +1142,8 @@ impl EditorServer {
         // Remove disconnected clients
         for idx in disconnected.into_iter().rev() {
             let client = self.clients.remove(idx);
+            // The idle grace period starts when the last client leaves
+            self.last_client_activity = Instant::now();
             // Clean up --wait tracking if this client was waiting
             if let Some(wait_id) = client.wait_id {
                 self.waiting_clients.remove(&wait_id);
@@ -1079,6 +1193,9 @@ impl EditorServer {
                         .map_err(|e| io::Error::other(e.to_string()))?;
                     Ok(true)
                 } else {
+                    if key_event.kind == KeyEventKind::Release {
+                        editor.handle_key_release(key_event.code, key_event.modifiers);
+                    }
                     Ok(false)
                 }
             }
@@ -1093,7 +1210,41 @@ impl EditorServer {
                 editor.paste_text(text);
                 Ok(true)
             }
-            _ => Ok(false),
+            Event::FocusGained => {
+                editor.focus_gained();
+                Ok(true)
+            }
+            Event::FocusLost => {
+                editor.focus_lost();
+                Ok(true)
+            }
+        }
+    }
+
+    /// Ask the terminal of the client whose input triggered a paste for its
+    /// clipboard. Only that client is queried, and only its parser turns
+    /// the reply into a paste.
+    fn send_osc52_query(&mut self, input_source: Option<usize>) {
+        let requested = self
+            .editor
+            .as_mut()
+            .is_some_and(|e| e.take_osc52_query_request());
+        if !requested {
+            return;
+        }
+        let Some(client) = input_source.and_then(|idx| self.clients.get_mut(idx)) else {
+            tracing::warn!("OSC 52 paste requested but no input source; ignoring");
+            return;
+        };
+        client.input_parser.expect_osc52_reply();
+        if !client
+            .data_writer
+            .try_write(crate::services::clipboard::OSC52_QUERY.as_bytes())
+        {
+            tracing::warn!(
+                "Client {} output buffer full, dropping OSC 52 query",
+                client.id
+            );
         }
     }
 
@@ -1121,13 +1272,25 @@ impl EditorServer {
             let _ = terminal.clear();
         }
 
+        // Downsample colors to what the least capable attached terminal shows
+        if let Some(capability) = self
+            .clients
+            .iter()
+            .map(ConnectedClient::color_capability)
+            .reduce(ColorCapability::least_capable)
+        {
+            editor.set_color_capability(capability);
+        }
+
         // Take any pending escape sequences (e.g., cursor style changes)
         let pending_sequences = editor.take_pending_escape_sequences();
 
         // Render to capture backend
+        let render_start = Instant::now();
         terminal
             .draw(|frame| editor.render(frame))
             .map_err(|e| io::Error::other(e.to_string()))?;
+        self.render_times.record(render_start.elapsed());
 
         // Get the captured output
         let output = terminal.backend_mut().take_buffer();
@@ -1163,20 +1326,17 @@ impl EditorServer {
         Ok(())
     }
 
-    /// Disconnect all clients
-    fn disconnect_all_clients(&mut self, reason: &str) -> io::Result<()> {
+    /// Disconnect all clients, telling them why with `message`
+    fn disconnect_all_clients(&mut self, message: &ServerControl) -> io::Result<()> {
         let teardown = terminal_teardown_sequences();
+        let msg = serde_json::to_string(message).unwrap_or_default();
         for client in &mut self.clients {
             // Best-effort: client may already be disconnected
             #[allow(clippy::let_underscore_must_use)]
             let _ = client.data_writer.try_write(&teardown);
-            let quit_msg = serde_json::to_string(&ServerControl::Quit {
-                reason: reason.to_string(),
-            })
-            .unwrap_or_default();
             // Best-effort: client may already be disconnected
             #[allow(clippy::let_underscore_must_use)]
-            let _ = client.conn.write_control(&quit_msg);
+            let _ = client.conn.write_control(&msg);
         }
         self.clients.clear();
         Ok(())
@@ -1199,4 +1359,9 @@ impl ConnectedClient {
             .map(|v| v == "truecolor" || v == "24bit")
             .unwrap_or(false)
     }
+
+    /// Color capability of the client's terminal, from its forwarded environment
+    pub fn color_capability(&self) -> ColorCapability {
+        ColorCapability::from_env(|key| self.env.get(key).cloned().flatten())
+    }
 }
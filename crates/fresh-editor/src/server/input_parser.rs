@@ -17,8 +17,19 @@ pub struct InputParser {
     /// When the buffer last received a byte (for ESC timeout)
     /// Buffer for bracketed paste content (between \x1b[200~ and \x1b[201~)
     paste_buffer: Option<Vec<u8>>,
+    /// Buffer for the body of an OSC 52 clipboard reply (after \x1b]52;)
+    osc52_buffer: Option<Vec<u8>>,
+    /// Whether this client's terminal was sent an OSC 52 query and its
+    /// reply hasn't arrived yet
+    osc52_reply_expected: bool,
 }
 
+/// Introducer of a terminal's reply to an OSC 52 clipboard query
+const OSC52_REPLY_PREFIX: &[u8] = b"\x1b]52;";
+
+/// Largest OSC 52 reply accepted before it is discarded
+const MAX_OSC52_REPLY: usize = 16 * 1024 * 1024;
+
 impl Default for InputParser {
     fn default() -> Self {
         Self::new()
@@ -31,9 +42,18 @@ impl InputParser {
             buffer: Vec::with_capacity(32),
             max_buffer_size: 256,
             paste_buffer: None,
+            osc52_buffer: None,
+            osc52_reply_expected: false,
         }
     }
 
+    /// Recognize the next OSC 52 reply as clipboard contents, after an OSC
+    /// 52 query was sent to this client's terminal. Replies nobody asked
+    /// for are not turned into pastes.
+    pub fn expect_osc52_reply(&mut self) {
+        self.osc52_reply_expected = true;
+    }
+
     /// Suggests a timeout for the next input read (matching Microsoft Edit).
     ///
     /// Returns 100ms if the parser has a buffered ESC (might be standalone
@@ -72,6 +92,60 @@ impl InputParser {
         }
 
         for &byte in bytes {
+            // A reply to an OSC 52 clipboard query is delivered as a paste.
+            // While one is expected, a partial introducer is held in the
+            // buffer across reads until it either completes or diverges.
+            if self.osc52_reply_expected
+                && self.paste_buffer.is_none()
+                && self.osc52_buffer.is_none()
+                && OSC52_REPLY_PREFIX.starts_with(&self.buffer)
+            {
+                let held = self.buffer.len();
+                if OSC52_REPLY_PREFIX[held] == byte {
+                    if held + 1 == OSC52_REPLY_PREFIX.len() {
+                        self.buffer.clear();
+                        self.osc52_buffer = Some(Vec::new());
+                    } else {
+                        self.buffer.push(byte);
+                    }
+                    continue;
+                }
+                // Not a reply after all: parse what was held (e.g. Alt+])
+                // as usual before this byte. A lone ESC needs no replay.
+                if held > 1 {
+                    let prefix = std::mem::take(&mut self.buffer);
+                    self.osc52_reply_expected = false;
+                    events.extend(self.parse(&prefix));
+                    self.osc52_reply_expected = true;
+                }
+            }
+
+            // Inside an OSC 52 reply, buffer bytes until BEL or ST (ESC \)
+            if let Some(ref mut osc_buf) = self.osc52_buffer {
+                osc_buf.push(byte);
+                let body_len = if byte == 0x07 {
+                    Some(osc_buf.len() - 1)
+                } else if osc_buf.ends_with(b"\x1b\\") {
+                    Some(osc_buf.len() - 2)
+                } else {
+                    None
+                };
+                if let Some(body_len) = body_len {
+                    if let Some(text) =
+                        crate::services::clipboard::decode_osc52_reply(&osc_buf[..body_len])
+                    {
+                        events.push(Event::Paste(text));
+                    }
+                    self.osc52_buffer = None;
+                    self.osc52_reply_expected = false;
+                } else if osc_buf.len() > MAX_OSC52_REPLY {
+                    tracing::warn!("InputParser: discarding oversized OSC 52 reply");
+                    self.osc52_buffer = None;
+                    self.osc52_reply_expected = false;
+                }
+                continue;
+            }
+
             // If we're inside a bracketed paste, buffer bytes until end marker
             if let Some(ref mut paste_buf) = self.paste_buffer {
                 paste_buf.push(byte);
@@ -602,7 +676,10 @@ fn byte_to_keycode(byte: u8) -> KeyCode {
     match byte {
         0 => KeyCode::Char('@'), // Ctrl+@
         9 => KeyCode::Tab,
-        10 | 13 => KeyCode::Enter,                          // LF or CR
+        13 => KeyCode::Enter,
+        // A bare LF is Enter, as in the direct event loop. Ctrl+J is only
+        // its own key when sent as CSI u or modifyOtherKeys.
+        10 => KeyCode::Enter,
         1..=26 => KeyCode::Char((b'a' + byte - 1) as char), // Ctrl+A through Ctrl+Z
         27 => KeyCode::Esc,
         28..=31 => KeyCode::Char((b'\\' + byte - 28) as char),
@@ -788,6 +865,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_enhanced_ctrl_j_is_not_enter() {
+        let mut parser = InputParser::new();
+        // Kitty keyboard protocol and xterm modifyOtherKeys encodings
+        for seq in [&b"\x1b[106;5u"[..], &b"\x1b[27;5;106~"[..]] {
+            let events = parser.parse(seq);
+            match &events[0] {
+                Event::Key(ke) => {
+                    assert_eq!(ke.code, KeyCode::Char('j'));
+                    assert_eq!(ke.modifiers, KeyModifiers::CONTROL);
+                }
+                _ => panic!("Expected Ctrl+J key event"),
+            }
+        }
+    }
+
     #[test]
     fn test_tab_key() {
         let mut parser = InputParser::new();
@@ -1157,6 +1250,71 @@ mod tests {
 
     // ---- UTF-8 multi-byte character tests ----
 
+    #[test]
+    fn test_osc52_reply_becomes_paste() {
+        let mut parser = InputParser::new();
+        parser.expect_osc52_reply();
+        let events = parser.parse(b"\x1b]52;c;aGVsbG8=\x07x");
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            Event::Paste(text) => assert_eq!(text, "hello"),
+            other => panic!("Expected Paste event, got {:?}", other),
+        }
+        assert!(matches!(events[1], Event::Key(_)));
+    }
+
+    #[test]
+    fn test_osc52_reply_split_across_chunks_with_st() {
+        let mut parser = InputParser::new();
+        parser.expect_osc52_reply();
+        assert!(parser.parse(b"\x1b]52;c;aGVs").is_empty());
+        let events = parser.parse(b"bG8=\x1b\\");
+        match events.as_slice() {
+            [Event::Paste(text)] => assert_eq!(text, "hello"),
+            other => panic!("Expected one Paste event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_osc52_reply_introducer_split_across_reads() {
+        let mut parser = InputParser::new();
+        parser.expect_osc52_reply();
+        assert!(parser.parse(b"\x1b]5").is_empty());
+        assert!(parser.parse(b"2;c;aGVsbG8=").is_empty());
+        match parser.parse(b"\x07").as_slice() {
+            [Event::Paste(text)] => assert_eq!(text, "hello"),
+            other => panic!("Expected one Paste event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_held_osc52_introducer_replays_as_keys() {
+        let mut parser = InputParser::new();
+        parser.expect_osc52_reply();
+        assert!(parser.parse(b"\x1b]").is_empty());
+        let events = parser.parse(b"x");
+        assert_eq!(events.len(), 2, "events: {:?}", events);
+        match &events[0] {
+            Event::Key(key) => {
+                assert_eq!(key.code, KeyCode::Char(']'));
+                assert_eq!(key.modifiers, KeyModifiers::ALT);
+            }
+            other => panic!("Expected Alt+], got {:?}", other),
+        }
+        assert!(matches!(events[1], Event::Key(_)));
+    }
+
+    #[test]
+    fn test_unrequested_osc52_reply_is_not_a_paste() {
+        let mut parser = InputParser::new();
+        let events = parser.parse(b"\x1b]52;c;aGVsbG8=\x07");
+        assert!(
+            !events.iter().any(|e| matches!(e, Event::Paste(_))),
+            "events: {:?}",
+            events
+        );
+    }
+
     #[test]
     fn test_utf8_three_byte_chinese_char() {
         let mut parser = InputParser::new();
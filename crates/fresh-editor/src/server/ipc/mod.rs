@@ -14,6 +14,7 @@ use interprocess::local_socket::{
     prelude::*, Listener, ListenerNonblockingMode, ListenerOptions, Stream,
 };
 
+use crate::server::protocol::ServerStats;
 use crate::workspace::encode_path_for_filename;
 
 // Platform-specific implementations
@@ -472,6 +473,199 @@ impl ClientConnection {
     }
 }
 
+/// A running session found in the socket directory
+#[derive(Debug, Clone)]
+pub struct SessionInfo {
+    /// Session id: the socket file stem, usable with `fresh -a <id>`
+    pub id: String,
+    /// Server process id, when the PID file is readable
+    pub pid: Option<u32>,
+    /// Socket paths of the session
+    pub paths: SocketPaths,
+}
+
+/// Result of scanning the socket directory for sessions
+#[derive(Debug, Default)]
+pub struct SessionList {
+    /// Sessions whose server is running, sorted by id
+    pub sessions: Vec<SessionInfo>,
+    /// Number of stale sessions whose files were removed during the scan
+    pub stale_cleaned: usize,
+}
+
+/// List the running sessions, removing the files of stale ones
+pub fn list_sessions() -> io::Result<SessionList> {
+    list_sessions_in_dir(&SocketPaths::socket_directory()?)
+}
+
+/// List the running sessions in a specific socket directory
+pub fn list_sessions_in_dir(socket_dir: &Path) -> io::Result<SessionList> {
+    let mut list = SessionList::default();
+    if !socket_dir.exists() {
+        return Ok(list);
+    }
+
+    for entry in std::fs::read_dir(socket_dir)? {
+        let path = entry?.path();
+        let filename = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+
+        // Each session has exactly one control socket (*.ctrl.sock)
+        let Some(id) = filename.strip_suffix(".ctrl.sock") else {
+            continue;
+        };
+        let paths = SocketPaths::for_session_name_in_dir(id, socket_dir);
+
+        if paths.cleanup_if_stale() {
+            list.stale_cleaned += 1;
+            continue;
+        }
+        if !paths.is_server_alive() {
+            continue;
+        }
+
+        list.sessions.push(SessionInfo {
+            id: id.to_string(),
+            pid: paths.read_pid().ok().flatten(),
+            paths,
+        });
+    }
+
+    list.sessions.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(list)
+}
+
+/// How long `query_session_stats` waits for each reply from the server
+const STATS_REPLY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Read the next newline-terminated control message, polling until
+/// `deadline`. Bytes past the newline stay in `pending` for the next call.
+fn read_control_line_until(
+    conn: &ClientConnection,
+    pending: &mut Vec<u8>,
+    deadline: std::time::Instant,
+) -> io::Result<String> {
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(pos) = pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = pending.drain(..=pos).collect();
+            return Ok(String::from_utf8_lossy(&line).into_owned());
+        }
+        match conn.control.try_read(&mut buf) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Server closed connection before replying",
+                ))
+            }
+            Ok(n) => pending.extend_from_slice(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                if std::time::Instant::now() >= deadline {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "Timed out waiting for the server to reply",
+                    ));
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Ask the session listening at `paths` for its metrics.
+///
+/// Connects as a short-lived client: handshake, `ClientControl::Stats`,
+/// then waits for the `ServerControl::Stats` reply (other control messages
+/// are skipped) and disconnects. Fails with `TimedOut` if the server does
+/// not answer within a few seconds.
+pub fn query_session_stats(paths: &SocketPaths) -> io::Result<ServerStats> {
+    use crate::server::protocol::{ClientControl, ClientHello, ServerControl, TermSize};
+
+    let conn = ClientConnection::connect(paths)?;
+    let to_json = |msg: &ClientControl| {
+        serde_json::to_string(msg).map_err(|e| io::Error::other(e.to_string()))
+    };
+    let mut pending = Vec::new();
+    let hello = ClientHello::new(TermSize::new(80, 24));
+    conn.write_control(&to_json(&ClientControl::Hello(hello))?)?;
+    read_control_line_until(
+        &conn,
+        &mut pending,
+        std::time::Instant::now() + STATS_REPLY_TIMEOUT,
+    )?;
+    conn.write_control(&to_json(&ClientControl::Stats)?)?;
+
+    let deadline = std::time::Instant::now() + STATS_REPLY_TIMEOUT;
+    loop {
+        let line = read_control_line_until(&conn, &mut pending, deadline)?;
+        if let Ok(ServerControl::Stats(stats)) = serde_json::from_str(&line) {
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = conn.write_control(&to_json(&ClientControl::Detach)?);
+            return Ok(stats);
+        }
+    }
+}
+
+/// Terminate the session with the given id.
+///
+/// Returns `Ok(false)` when no such session exists.
+pub fn kill_session(id: &str) -> io::Result<bool> {
+    kill_session_at(&SocketPaths::for_session_name(id)?)
+}
+
+/// Terminate the session listening at `paths`: connect, send
+/// `ClientControl::Quit`, and wait (up to 5 seconds) for the server to close
+/// the connection. Leftover socket files are removed.
+///
+/// Returns `Ok(false)` when no session is listening there.
+pub fn kill_session_at(paths: &SocketPaths) -> io::Result<bool> {
+    use crate::server::protocol::{ClientControl, ClientHello, TermSize};
+
+    if !paths.exists() {
+        return Ok(false);
+    }
+
+    let conn = ClientConnection::connect(paths)?;
+
+    // The server only accepts control messages after a handshake
+    let hello = ClientHello::new(TermSize::new(80, 24));
+    let to_json = |msg: &ClientControl| {
+        serde_json::to_string(msg).map_err(|e| io::Error::other(e.to_string()))
+    };
+    conn.write_control(&to_json(&ClientControl::Hello(hello))?)?;
+    // The reply doesn't matter, even a version mismatch can be told to quit
+    conn.read_control()?;
+    conn.write_control(&to_json(&ClientControl::Quit)?)?;
+
+    // Wait for the server to close the connection (indicates shutdown)
+    conn.set_data_nonblocking(false)?;
+    let mut buf = [0u8; 1024];
+    let timeout = std::time::Duration::from_secs(5);
+    let start = std::time::Instant::now();
+    while start.elapsed() < timeout {
+        match conn.read_data(&mut buf) {
+            Ok(0) => break,    // EOF - server closed connection
+            Ok(_) => continue, // Keep draining
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+            Err(_) => break, // Error - connection closed
+        }
+    }
+
+    // Clean up socket files the server left behind
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    for path in [&paths.data, &paths.control] {
+        if path.exists() {
+            // Best-effort cleanup of stale socket files
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -578,4 +772,59 @@ mod tests {
         // No sockets exist, should return false (nothing to clean)
         assert!(!paths.cleanup_if_stale());
     }
+
+    /// Start a fake server for `name` in `socket_dir` that answers the
+    /// handshake and shuts down (removing its files) when told to quit.
+    fn spawn_fake_server(name: &str, socket_dir: &Path) -> std::thread::JoinHandle<()> {
+        use crate::server::protocol::{ClientControl, ServerControl, ServerHello};
+
+        let paths = SocketPaths::for_session_name_in_dir(name, socket_dir);
+        let mut listener = ServerListener::bind(paths.clone()).unwrap();
+        paths.write_pid(std::process::id()).unwrap();
+        let name = name.to_string();
+
+        std::thread::spawn(move || loop {
+            let Some(conn) = listener.accept().unwrap() else {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                continue;
+            };
+            conn.read_control().unwrap();
+            let hello = ServerControl::Hello(ServerHello::new(name.clone()));
+            conn.write_control(&serde_json::to_string(&hello).unwrap())
+                .unwrap();
+            while let Ok(Some(msg)) = conn.read_control() {
+                if let Ok(ClientControl::Quit) = serde_json::from_str(&msg) {
+                    // Dropping the listener removes the socket and PID files
+                    return;
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_list_and_kill_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = spawn_fake_server("first", temp_dir.path());
+        let _second = spawn_fake_server("second", temp_dir.path());
+
+        let list = list_sessions_in_dir(temp_dir.path()).unwrap();
+        let ids: Vec<&str> = list.sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["first", "second"]);
+        assert_eq!(list.sessions[0].pid, Some(std::process::id()));
+
+        let paths = SocketPaths::for_session_name_in_dir("first", temp_dir.path());
+        assert!(kill_session_at(&paths).unwrap());
+        first.join().unwrap();
+
+        let list = list_sessions_in_dir(temp_dir.path()).unwrap();
+        let ids: Vec<&str> = list.sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["second"]);
+    }
+
+    #[test]
+    fn test_kill_missing_session_returns_false() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = SocketPaths::for_session_name_in_dir("missing", temp_dir.path());
+        assert!(!kill_session_at(&paths).unwrap());
+    }
 }
@@ -33,7 +33,7 @@ pub struct ClientHello {
     /// Initial terminal size
     pub term_size: TermSize,
     /// Environment variables relevant for rendering
-    /// Keys: TERM, COLORTERM, LANG, LC_ALL
+    /// Keys: TERM, COLORTERM, LANG, LC_ALL, FRESH_COLOR_MODE, WT_SESSION
     pub env: HashMap<String, Option<String>>,
 }
 
@@ -43,7 +43,14 @@ impl ClientHello {
         let mut env = HashMap::new();
 
         // Collect terminal-relevant environment variables
-        for key in &["TERM", "COLORTERM", "LANG", "LC_ALL"] {
+        for key in &[
+            "TERM",
+            "COLORTERM",
+            "LANG",
+            "LC_ALL",
+            "FRESH_COLOR_MODE",
+            "WT_SESSION",
+        ] {
             env.insert(key.to_string(), std::env::var(key).ok());
         }
 
@@ -121,6 +128,10 @@ pub enum ClientControl {
         #[serde(default)]
         wait: bool,
     },
+    /// Request server metrics (answered with `ServerControl::Stats`)
+    Stats,
+    /// Redraw this client's screen now, bypassing the frame-rate cap
+    Refresh,
 }
 
 /// A file to open with optional line/column position, range, and hover message
@@ -153,6 +164,8 @@ pub enum ServerControl {
     Bell,
     /// Server is shutting down
     Quit { reason: String },
+    /// This client was detached; the server keeps running
+    Detached,
     /// Error message
     Error { message: String },
     /// Signal that a --wait operation has completed
@@ -173,6 +186,38 @@ pub enum ServerControl {
     /// keeps running so the editor state is preserved and picked up cleanly
     /// when the client resumes.
     SuspendClient,
+    /// Server metrics, in reply to `ClientControl::Stats`
+    Stats(ServerStats),
+}
+
+/// Metrics describing a running server, for debugging and tooling
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServerStats {
+    /// Session identifier
+    pub session_id: String,
+    /// Server version
+    pub server_version: String,
+    /// Seconds since the server started
+    pub uptime_secs: u64,
+    /// Number of attached clients
+    pub attached_clients: usize,
+    /// Number of open buffers, including hidden ones
+    pub buffer_count: usize,
+    /// Number of buffers with unsaved changes
+    pub modified_buffer_count: usize,
+    /// Bytes of buffer content held in memory (unloaded regions of large
+    /// files are not counted)
+    pub loaded_bytes: u64,
+    /// Frames rendered since the server started
+    pub frames_rendered: u64,
+    /// Average render time over the most recent frames, in milliseconds
+    pub render_avg_ms: f64,
+    /// Large-file chunk reads served from memory, across all buffers
+    pub chunk_cache_hits: u64,
+    /// Large-file chunks read from disk, across all buffers
+    pub chunk_cache_misses: u64,
+    /// Large-file chunks currently held in memory, across all buffers
+    pub chunk_cache_resident: usize,
 }
 
 /// Wrapper for control channel messages (used for JSON serialization)
@@ -301,6 +346,8 @@ mod tests {
                 }],
                 wait: false,
             },
+            ClientControl::Stats,
+            ClientControl::Refresh,
         ];
 
         for variant in variants {
@@ -321,6 +368,7 @@ mod tests {
             ServerControl::Quit {
                 reason: "test".to_string(),
             },
+            ServerControl::Detached,
             ServerControl::Error {
                 message: "error".to_string(),
             },
@@ -331,6 +379,7 @@ mod tests {
                 use_system_clipboard: true,
             },
             ServerControl::SuspendClient,
+            ServerControl::Stats(ServerStats::default()),
         ];
 
         for variant in variants {
@@ -338,4 +387,20 @@ mod tests {
             let _: ServerControl = serde_json::from_str(&json).unwrap();
         }
     }
+
+    #[test]
+    fn test_stats_roundtrip() {
+        let stats = ServerStats {
+            session_id: "demo".to_string(),
+            buffer_count: 3,
+            render_avg_ms: 1.5,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&ServerControl::Stats(stats.clone())).unwrap();
+        assert!(json.contains("\"type\":\"stats\""));
+        match serde_json::from_str::<ServerControl>(&json).unwrap() {
+            ServerControl::Stats(parsed) => assert_eq!(parsed, stats),
+            other => panic!("Expected Stats, got {:?}", other),
+        }
+    }
 }
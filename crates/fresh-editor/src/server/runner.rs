@@ -16,7 +16,8 @@ use crate::server::capture_backend::{terminal_setup_sequences, terminal_teardown
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    ClientControl, ServerControl, ServerHello, ServerStats, TermSize, VersionMismatch,
+    PROTOCOL_VERSION,
 };
 use crossterm::event::Event;
 
@@ -378,6 +379,20 @@ impl Server {
                     client.id
                 );
             }
+            ClientControl::Refresh => {
+                // Nothing is rendered without an editor
+            }
+            ClientControl::Stats => {
+                // No editor, so only the connection-level numbers are known
+                let stats = ServerStats {
+                    server_version: env!("CARGO_PKG_VERSION").to_string(),
+                    attached_clients: self.clients.len(),
+                    ..Default::default()
+                };
+                let reply = serde_json::to_string(&ServerControl::Stats(stats))
+                    .map_err(|e| io::Error::other(e.to_string()))?;
+                self.clients[client_idx].conn.write_control(&reply)?;
+            }
         }
         Ok(())
     }
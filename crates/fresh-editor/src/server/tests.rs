@@ -924,6 +924,38 @@ mod integration_tests {
         thread::JoinHandle<std::io::Result<()>>,
         SocketPaths,
         std::path::PathBuf,
+    ) {
+        setup_editor_server_e2e_with_idle_timeout(test_name, Duration::from_secs(30))
+    }
+
+    /// Like `setup_editor_server_e2e`, with the server's idle timeout set.
+    fn setup_editor_server_e2e_with_idle_timeout(
+        test_name: &str,
+        idle_timeout: Duration,
+    ) -> (
+        ClientConnection,
+        Vec<u8>,
+        std::sync::Arc<std::sync::atomic::AtomicBool>,
+        thread::JoinHandle<std::io::Result<()>>,
+        SocketPaths,
+        std::path::PathBuf,
+    ) {
+        setup_editor_server_e2e_with_hello(test_name, idle_timeout, |_| {})
+    }
+
+    /// Like `setup_editor_server_e2e_with_idle_timeout`, letting the test
+    /// adjust the client's hello (e.g. its forwarded environment).
+    fn setup_editor_server_e2e_with_hello(
+        test_name: &str,
+        idle_timeout: Duration,
+        customize_hello: impl FnOnce(&mut ClientHello),
+    ) -> (
+        ClientConnection,
+        Vec<u8>,
+        std::sync::Arc<std::sync::atomic::AtomicBool>,
+        thread::JoinHandle<std::io::Result<()>>,
+        SocketPaths,
+        std::path::PathBuf,
     ) {
         use crate::config::Config;
         use crate::config_io::DirectoryContext;
@@ -941,7 +973,7 @@ mod integration_tests {
         let server_config = EditorServerConfig {
             working_dir: temp_dir.clone(),
             session_name: Some(session_name),
-            idle_timeout: Some(Duration::from_secs(30)),
+            idle_timeout: Some(idle_timeout),
             editor_config: config,
             dir_context,
             plugins_enabled: false,
@@ -972,7 +1004,8 @@ mod integration_tests {
 
         // Connect
         let conn = ClientConnection::connect(&socket_paths).expect("Failed to connect");
-        let hello = ClientHello::new(TermSize::new(80, 24));
+        let mut hello = ClientHello::new(TermSize::new(80, 24));
+        customize_hello(&mut hello);
         conn.write_control(&serde_json::to_string(&ClientControl::Hello(hello)).unwrap())
             .unwrap();
         let response = conn.read_control().unwrap().unwrap();
@@ -1010,6 +1043,212 @@ mod integration_tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    /// Send `ClientControl::Stats` and wait (up to 5 seconds) for the reply.
+    fn request_stats(conn: &ClientConnection) -> crate::server::protocol::ServerStats {
+        conn.write_control(&serde_json::to_string(&ClientControl::Stats).unwrap())
+            .unwrap();
+        let mut ctrl_buf = Vec::new();
+        let stats = wait_for_control(
+            conn,
+            &mut ctrl_buf,
+            Duration::from_secs(5),
+            |ctrl| match ctrl {
+                ServerControl::Stats(stats) => Some(stats.clone()),
+                _ => None,
+            },
+        )
+        .expect("timed out waiting for the stats reply");
+        // Restore blocking mode for the writes that follow
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = conn.control.set_nonblocking(false);
+        stats
+    }
+
+    /// The stats reply reflects opened buffers and the attached client.
+    #[test]
+    fn test_stats_reports_open_buffers() {
+        use crate::server::protocol::FileRequest;
+
+        let (conn, _output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("stats");
+
+        let before = request_stats(&conn);
+        assert_eq!(before.attached_clients, 1);
+        assert!(
+            before.frames_rendered > 0,
+            "initial render should be counted"
+        );
+
+        let files = ["one.txt", "two.txt"]
+            .iter()
+            .map(|name| {
+                let path = temp_dir.join(name);
+                std::fs::write(&path, format!("{}\n", name)).unwrap();
+                FileRequest {
+                    path: path.to_string_lossy().into_owned(),
+                    line: None,
+                    column: None,
+                    end_line: None,
+                    end_column: None,
+                    message: None,
+                }
+            })
+            .collect();
+        conn.write_control(
+            &serde_json::to_string(&ClientControl::OpenFiles { files, wait: false }).unwrap(),
+        )
+        .unwrap();
+
+        // File opens are queued; poll until both are loaded
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        let stats = loop {
+            let stats = request_stats(&conn);
+            if stats.buffer_count >= before.buffer_count + 1
+                && stats.loaded_bytes >= before.loaded_bytes + 16
+            {
+                break stats;
+            }
+            assert!(
+                std::time::Instant::now() < deadline,
+                "files never loaded, last stats: {:?}",
+                stats
+            );
+            thread::sleep(Duration::from_millis(20));
+        };
+        assert!(stats.buffer_count >= 2, "stats: {:?}", stats);
+        assert_eq!(stats.modified_buffer_count, 0);
+        assert!(stats.render_avg_ms >= 0.0);
+        // Small files are loaded whole and never touch the chunk cache
+        assert_eq!(stats.chunk_cache_resident, 0);
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// Rapid edits are coalesced: far fewer frames are rendered than edits
+    /// applied.
+    #[test]
+    fn test_rapid_edits_render_bounded_frames() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("frame-cap");
+
+        let before = request_stats(&conn);
+        const EDITS: u64 = 200;
+        for _ in 0..EDITS {
+            conn.write_data(b"x").unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+        conn.write_data(b"DONE").unwrap();
+        read_until_contains(&conn, &mut output, "DONE");
+
+        let frames = request_stats(&conn).frames_rendered - before.frames_rendered;
+        assert!(
+            frames < EDITS / 2,
+            "{} edits rendered {} frames; expected coalescing",
+            EDITS,
+            frames
+        );
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// `ClientControl::Refresh` renders a frame even with nothing changed.
+    #[test]
+    fn test_refresh_forces_a_frame() {
+        let (conn, _output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("refresh");
+
+        let before = request_stats(&conn).frames_rendered;
+        conn.write_control(&serde_json::to_string(&ClientControl::Refresh).unwrap())
+            .unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while request_stats(&conn).frames_rendered == before && std::time::Instant::now() < deadline
+        {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(
+            request_stats(&conn).frames_rendered > before,
+            "Refresh should render a frame"
+        );
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// Quit (Ctrl+Q) in a session asks whether to detach or quit; choosing
+    /// detach ends only this client, which exits as detached.
+    #[cfg(unix)]
+    #[test]
+    fn test_quit_in_session_offers_detach_or_quit() {
+        use crate::client::{relay_unix, ClientExitReason};
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let (mut conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("session-close");
+
+        // Ctrl+Q
+        conn.write_data(&[0x11]).unwrap();
+        read_until_contains(&conn, &mut output, "(d)etach");
+        read_until_contains(&conn, &mut output, "(q)uit");
+
+        conn.write_data(b"d\r").unwrap();
+
+        // Keep the writing end open so stdin stays quiet without hitting EOF
+        let (mut input, _input_writer) = std::os::unix::net::UnixStream::pair().unwrap();
+        let reason = relay_unix::relay_loop_with_io(
+            &mut conn,
+            &mut input,
+            &mut Vec::new(),
+            Arc::new(AtomicBool::new(false)),
+            None,
+        )
+        .unwrap();
+        assert!(matches!(reason, ClientExitReason::Detached));
+        assert!(
+            !server_handle.is_finished(),
+            "detaching must keep the session running"
+        );
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// With no client attached for longer than the idle timeout, the server
+    /// shuts itself down.
+    #[test]
+    fn test_idle_shutdown_after_last_client_detaches() {
+        let (conn, _output, _shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e_with_idle_timeout("idle-detach", Duration::from_millis(500));
+
+        conn.write_control(&serde_json::to_string(&ClientControl::Detach).unwrap())
+            .unwrap();
+
+        // No shutdown request: the server must exit on its own
+        server_handle.join().unwrap().unwrap();
+
+        drop(socket_paths.cleanup());
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    /// A buffer with unsaved changes keeps the server alive past the idle
+    /// timeout.
+    #[test]
+    fn test_idle_shutdown_skipped_with_unsaved_changes() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e_with_idle_timeout("idle-dirty", Duration::from_millis(500));
+
+        conn.write_data(b"unsaved").unwrap();
+        read_until_contains(&conn, &mut output, "unsaved");
+        conn.write_control(&serde_json::to_string(&ClientControl::Detach).unwrap())
+            .unwrap();
+
+        thread::sleep(Duration::from_millis(1200));
+        assert!(
+            !server_handle.is_finished(),
+            "server with unsaved changes should outlive the idle timeout"
+        );
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
     /// Parse accumulated ANSI output through a VT100 terminal emulator
     /// and return the visible screen text (all rows joined by newlines).
     fn vt100_screen_text(output: &[u8]) -> String {
@@ -1199,6 +1438,44 @@ mod integration_tests {
         }
     }
 
+    /// E2E test: a client on a 16-color terminal gets no 24-bit colors
+    ///
+    /// The default theme's white editor foreground (255,255,255) must reach
+    /// the client as the ANSI-16 bright white SGR code (97), not `38;2;...`.
+    #[test]
+    fn test_16_color_client_gets_downsampled_colors() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e_with_hello("color16", Duration::from_secs(30), |hello| {
+                hello
+                    .env
+                    .insert("TERM".to_string(), Some("linux".to_string()));
+                hello.env.insert("COLORTERM".to_string(), None);
+                hello.env.insert("FRESH_COLOR_MODE".to_string(), None);
+                hello.env.insert("WT_SESSION".to_string(), None);
+            });
+
+        conn.write_data(b"COLORS").unwrap();
+        read_until_contains(&conn, &mut output, "COLORS");
+
+        let text = String::from_utf8_lossy(&output);
+        assert!(
+            !text.contains("38;2;") && !text.contains("48;2;"),
+            "16-color client must not receive truecolor SGR sequences"
+        );
+        let sgr_params: Vec<&str> = text
+            .split("\x1b[")
+            .filter_map(|seq| seq.split_once('m').map(|(params, _)| params))
+            .collect();
+        assert!(
+            sgr_params
+                .iter()
+                .any(|params| params.split(';').any(|p| p == "97")),
+            "white theme foreground should map to ANSI bright white (97)"
+        );
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
     /// E2E test: Copy in session mode sends SetClipboard control message to client
     ///
     /// Verifies the full clipboard path in client-server mode:
@@ -1207,6 +1484,7 @@ mod integration_tests {
     /// 3. Clipboard queues a PendingClipboard instead of writing to stdout
     /// 4. Server main loop picks it up and broadcasts SetClipboard control message
     /// 5. Client receives SetClipboard with the correct text and config flags
+    /// 6. The OSC 52 sequence arrives in-band on the data stream
     #[test]
     fn test_copy_sends_set_clipboard_control_message() {
         let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
@@ -1254,9 +1532,16 @@ mod integration_tests {
             text, "CLIPTEST",
             "SetClipboard should contain the copied text"
         );
-        assert!(use_osc52, "use_osc52 should be true by default");
+        assert!(
+            !use_osc52,
+            "OSC 52 is sent on the data stream, so the client must not repeat it"
+        );
         assert!(use_sys, "use_system_clipboard should be true by default");
 
+        // base64("CLIPTEST") = "Q0xJUFRFU1Q="
+        read_until_contains(&conn, &mut output, "\x1b]52;c;Q0xJUFRFU1Q=\x07");
+        assert!(String::from_utf8_lossy(&output).contains("\x1b]52;c;Q0xJUFRFU1Q=\x07"));
+
         // Restore blocking mode before teardown writes Quit
         #[allow(clippy::let_underscore_must_use)]
         let _ = conn.control.set_nonblocking(false);
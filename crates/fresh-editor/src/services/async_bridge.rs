@@ -116,6 +116,19 @@ pub enum AsyncMessage {
         edits: Vec<lsp_types::TextEdit>,
     },
 
+    /// LSP textDocument/willSaveWaitUntil response (no edits on failure)
+    LspWillSaveWaitUntil {
+        request_id: u64,
+        uri: String,
+        edits: Vec<lsp_types::TextEdit>,
+    },
+
+    /// LSP workspace/willRenameFiles response (no edit on failure)
+    LspWillRenameFiles {
+        request_id: u64,
+        edit: Option<lsp_types::WorkspaceEdit>,
+    },
+
     /// LSP textDocument/prepareRename response
     LspPrepareRename {
         request_id: u64,
@@ -198,6 +211,15 @@ pub enum AsyncMessage {
     /// Terminal process exited
     TerminalExited { terminal_id: TerminalId },
 
+    /// Output (stdout or stderr) from a running task
+    TaskOutput { task_id: u64, text: String },
+
+    /// Task process exited (`exit_code` is None when killed by a signal)
+    TaskExited {
+        task_id: u64,
+        exit_code: Option<i32>,
+    },
+
     /// LSP progress notification ($/progress)
     LspProgress {
         language: String,
@@ -285,6 +307,67 @@ pub enum AsyncMessage {
         complete: bool,
     },
 
+    /// `git show HEAD:./<file>` finished for a buffer's git gutter base.
+    /// `content` is `None` when the file is not tracked or git failed.
+    GitHeadBlobLoaded {
+        buffer_id: crate::model::event::BufferId,
+        path: std::path::PathBuf,
+        content: Option<Vec<u8>>,
+    },
+
+    /// `git show HEAD:./<file>` finished for a hunk revert requested on
+    /// `buffer_id` at `version`, when the buffer had no git gutter base.
+    GitRevertBlobLoaded {
+        buffer_id: crate::model::event::BufferId,
+        path: std::path::PathBuf,
+        version: u64,
+        content: Option<Vec<u8>>,
+    },
+
+    /// A background diff of `buffer_id` at `version` against its git gutter
+    /// `base` finished. `line_count` is the buffer's line count at that
+    /// version.
+    GitGutterDiffed {
+        buffer_id: crate::model::event::BufferId,
+        base: std::sync::Arc<Vec<u8>>,
+        version: u64,
+        hunks: Vec<crate::model::composite_buffer::DiffHunk>,
+        line_count: usize,
+    },
+
+    /// `git blame --line-porcelain` finished for a buffer showing blame.
+    /// `blame` holds the porcelain output and the file content that was
+    /// blamed, or is `None` when git or the read failed.
+    GitBlameLoaded {
+        buffer_id: crate::model::event::BufferId,
+        path: std::path::PathBuf,
+        blame: Option<(String, Vec<u8>)>,
+    },
+
+    /// A background diff of `buffer_id` at `version` against its blamed
+    /// `content` finished. `origins` maps each current line to the blamed
+    /// line it still matches.
+    GitBlameDiffed {
+        buffer_id: crate::model::event::BufferId,
+        content: std::sync::Arc<Vec<u8>>,
+        version: u64,
+        origins: Vec<Option<usize>>,
+    },
+
+    /// `git show :./<file>` finished for a hunk staging walk started on
+    /// `buffer_id`. `content` is `None` when the file is not in the index.
+    GitIndexBlobLoaded {
+        buffer_id: crate::model::event::BufferId,
+        path: std::path::PathBuf,
+        content: Option<Vec<u8>>,
+    },
+
+    /// A hunk staging walk finished writing `count` hunks to the git index
+    GitHunksStaged {
+        count: usize,
+        result: Result<(), String>,
+    },
+
     /// Startup-async: a single plugin directory finished loading on the
     /// plugin thread. Carries the same payload as the blocking
     /// `load_plugins_from_dir_with_config` return value.
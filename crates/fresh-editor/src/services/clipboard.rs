@@ -2,14 +2,16 @@
 //!
 //! This module provides a unified clipboard interface that:
 //! - Maintains an internal clipboard for in-editor copy/paste
-//! - Uses crossterm's OSC 52 escape sequences for copying to system clipboard
+//! - Uses OSC 52 escape sequences for copying to system clipboard (in session
+//!   mode they travel in-band on the client's data stream)
 //! - Uses arboard crate for reading from system clipboard
 //! - Supports copying HTML-formatted text for rich text editors
 //! - Gracefully falls back to internal clipboard if system clipboard is unavailable
 //! - Respects clipboard configuration to disable problematic methods
+//! - Keeps a bounded yank ring of recent copies for paste-cycling
 
-use crossterm::clipboard::CopyToClipboard;
-use crossterm::execute;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use std::collections::VecDeque;
 use std::io::{stdout, Write};
 use std::sync::Mutex;
 
@@ -32,11 +34,9 @@ static SYSTEM_CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
 /// selection/data-source on drop, leaving the clipboard empty.
 pub fn copy_to_system_clipboard(text: &str, use_osc52: bool, use_system_clipboard: bool) {
     if use_osc52 {
-        if let Err(e) = execute!(stdout(), CopyToClipboard::to_clipboard_from(text)) {
-            tracing::debug!("OSC 52 clipboard copy failed: {}", e);
+        if let Some(sequence) = osc52_copy_sequence(text, 0) {
+            write_osc52(&sequence);
         }
-        #[allow(clippy::let_underscore_must_use)]
-        let _ = stdout().flush();
     }
 
     if use_system_clipboard {
@@ -44,6 +44,50 @@ pub fn copy_to_system_clipboard(text: &str, use_osc52: bool, use_system_clipboar
     }
 }
 
+/// Default cap on the base64 payload of an OSC 52 sequence
+pub const DEFAULT_OSC52_MAX_BYTES: usize = 100_000;
+
+/// OSC 52 query asking the terminal for its clipboard contents
+pub const OSC52_QUERY: &str = "\x1b]52;c;?\x07";
+
+/// Build the OSC 52 sequence that sets the terminal clipboard to `text`.
+///
+/// Returns None when the base64 payload exceeds `max_bytes` (0 = no limit),
+/// since many terminals silently drop or truncate oversized sequences.
+pub fn osc52_copy_sequence(text: &str, max_bytes: usize) -> Option<String> {
+    let encoded = BASE64.encode(text);
+    if max_bytes > 0 && encoded.len() > max_bytes {
+        tracing::debug!(
+            "OSC 52 payload of {} bytes exceeds limit of {}, skipping",
+            encoded.len(),
+            max_bytes
+        );
+        return None;
+    }
+    Some(format!("\x1b]52;c;{}\x07", encoded))
+}
+
+/// Decode the body of a terminal's OSC 52 reply (`<selection>;<base64>`,
+/// without the `ESC ] 52 ;` introducer and terminator) into text.
+pub fn decode_osc52_reply(body: &[u8]) -> Option<String> {
+    let separator = body.iter().position(|&b| b == b';')?;
+    let payload = &body[separator + 1..];
+    if payload == b"?" {
+        return None;
+    }
+    let decoded = BASE64.decode(payload).ok()?;
+    Some(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+fn write_osc52(sequence: &str) {
+    let mut out = stdout();
+    if let Err(e) = out.write_all(sequence.as_bytes()) {
+        tracing::debug!("OSC 52 clipboard copy failed: {}", e);
+    }
+    #[allow(clippy::let_underscore_must_use)]
+    let _ = out.flush();
+}
+
 /// Set text on the arboard system clipboard, creating it if needed.
 fn set_system_clipboard_text(text: &str) {
     if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
@@ -75,6 +119,18 @@ fn set_system_clipboard_text(text: &str) {
     }
 }
 
+/// Maximum number of entries kept in the yank ring
+pub const YANK_RING_SIZE: usize = 16;
+
+/// An entry in the yank ring
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YankEntry {
+    pub text: String,
+    /// Whole lines were copied (copy/cut without a selection); such entries
+    /// paste as whole lines above the cursor's line
+    pub linewise: bool,
+}
+
 /// Pending clipboard data to deliver to clients in session mode
 #[derive(Debug, Clone)]
 pub struct PendingClipboard {
@@ -94,11 +150,24 @@ pub struct Clipboard {
     use_osc52: bool,
     /// When true, system clipboard (arboard/X11/Wayland) is used for copy/paste
     use_system_clipboard: bool,
+    /// Largest OSC 52 payload to send (0 = no limit)
+    osc52_max_bytes: usize,
+    /// When true, paste in session mode queries the terminal via OSC 52
+    osc52_paste: bool,
     /// When true, skip direct stdout writes (OSC 52 / arboard) and queue text
     /// for the server to send to clients via control messages instead
     session_mode: bool,
     /// Clipboard data pending delivery to clients (session mode only)
     pending_clipboard: Option<PendingClipboard>,
+    /// OSC 52 sequence to send in-band on the clients' data stream (session mode only)
+    pending_osc52: Option<String>,
+    /// Whether a paste is waiting for the terminal of the client that asked
+    /// for it to be sent an OSC 52 query (session mode only)
+    osc52_query_requested: bool,
+    /// Recent copies, most recent first (bounded by `YANK_RING_SIZE`)
+    ring: VecDeque<YankEntry>,
+    /// Whether `internal` holds whole lines
+    linewise: bool,
 }
 
 impl Clipboard {
@@ -109,16 +178,56 @@ impl Clipboard {
             internal_only: false,
             use_osc52: true,
             use_system_clipboard: true,
+            osc52_max_bytes: DEFAULT_OSC52_MAX_BYTES,
+            osc52_paste: false,
             session_mode: false,
             pending_clipboard: None,
+            pending_osc52: None,
+            osc52_query_requested: false,
+            ring: VecDeque::new(),
+            linewise: false,
         }
     }
 
+    /// Make `text` the internal clipboard content and push it onto the yank ring
+    fn store(&mut self, text: &str, linewise: bool) {
+        self.internal = text.to_string();
+        self.linewise = linewise;
+        if text.is_empty() {
+            return;
+        }
+        let entry = YankEntry {
+            text: text.to_string(),
+            linewise,
+        };
+        if self.ring.front() != Some(&entry) {
+            self.ring.push_front(entry);
+            self.ring.truncate(YANK_RING_SIZE);
+        }
+    }
+
+    /// Whether the internal clipboard content was copied as whole lines
+    pub fn is_linewise(&self) -> bool {
+        self.linewise
+    }
+
+    /// Number of entries in the yank ring
+    pub fn ring_len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Yank ring entry at `index` (0 = most recent)
+    pub fn ring_entry(&self, index: usize) -> Option<&YankEntry> {
+        self.ring.get(index)
+    }
+
     /// Update clipboard configuration from editor config.
     /// Called on initialization and when config is reloaded.
     pub fn apply_config(&mut self, config: &crate::config::ClipboardConfig) {
         self.use_osc52 = config.use_osc52;
         self.use_system_clipboard = config.use_system_clipboard;
+        self.osc52_max_bytes = config.osc52_max_bytes;
+        self.osc52_paste = config.osc52_paste;
     }
 
     /// Enable internal-only mode (for testing)
@@ -139,13 +248,23 @@ impl Clipboard {
         self.pending_clipboard.take()
     }
 
+    /// Take the OSC 52 copy sequence queued in session mode
+    pub fn take_pending_osc52(&mut self) -> Option<String> {
+        self.pending_osc52.take()
+    }
+
+    /// Take the request to send [`OSC52_QUERY`] to the client that pasted
+    pub fn take_osc52_query_request(&mut self) -> bool {
+        std::mem::take(&mut self.osc52_query_requested)
+    }
+
     /// Copy HTML-formatted text to the system clipboard
     ///
     /// Uses arboard to copy HTML with a plain text fallback.
     /// This allows pasting styled/colored text into applications that support rich text.
     /// Returns true if successful, false otherwise.
     pub fn copy_html(&mut self, html: &str, plain_text: &str) -> bool {
-        self.internal = plain_text.to_string();
+        self.store(plain_text, false);
 
         if !self.use_system_clipboard {
             return false;
@@ -188,20 +307,44 @@ impl Clipboard {
     ///
     /// Methods can be disabled via clipboard configuration.
     pub fn copy(&mut self, text: String) {
-        self.internal = text.clone();
+        self.copy_with_kind(text, false);
+    }
+
+    /// Copy whole lines, so that pasting inserts them as lines rather than
+    /// at the cursor column
+    pub fn copy_lines(&mut self, text: String) {
+        self.copy_with_kind(text, true);
+    }
+
+    fn copy_with_kind(&mut self, text: String, linewise: bool) {
+        self.store(&text, linewise);
+
+        let osc52 = if self.use_osc52 {
+            osc52_copy_sequence(&text, self.osc52_max_bytes)
+        } else {
+            None
+        };
 
         // In session mode, the server process has no terminal or display server.
-        // Queue the text for delivery to clients via a control message instead.
+        // The OSC 52 sequence goes in-band on the data stream, so it reaches
+        // the terminal in order with rendered output; the system clipboard is
+        // set by the client via a control message.
         if self.session_mode {
+            self.pending_osc52 = osc52;
             self.pending_clipboard = Some(PendingClipboard {
                 text,
-                use_osc52: self.use_osc52,
+                use_osc52: false,
                 use_system_clipboard: self.use_system_clipboard,
             });
             return;
         }
 
-        copy_to_system_clipboard(&text, self.use_osc52, self.use_system_clipboard);
+        if let Some(sequence) = osc52 {
+            write_osc52(&sequence);
+        }
+        if self.use_system_clipboard {
+            set_system_clipboard_text(&text);
+        }
     }
 
     /// Get text from clipboard, preferring system clipboard
@@ -214,6 +357,13 @@ impl Clipboard {
             return self.paste_internal();
         }
 
+        // In session mode, ask the terminal for its clipboard; the reply
+        // arrives as a paste event from the input parser
+        if self.session_mode && self.use_osc52 && self.osc52_paste {
+            self.osc52_query_requested = true;
+            return None;
+        }
+
         // Try arboard crate via the static clipboard (reads from system clipboard)
         if self.use_system_clipboard {
             if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
@@ -227,7 +377,10 @@ impl Clipboard {
                 if let Some(clipboard) = guard.as_mut() {
                     if let Ok(text) = clipboard.get_text() {
                         if !text.is_empty() {
-                            self.internal = text.clone();
+                            // Text copied outside the editor becomes a new ring entry
+                            if text != self.internal {
+                                self.store(&text, false);
+                            }
                             return Some(text);
                         }
                     }
@@ -250,7 +403,7 @@ impl Clipboard {
 
     /// Set the internal clipboard content without updating system clipboard
     pub fn set_internal(&mut self, text: String) {
-        self.internal = text;
+        self.store(&text, false);
     }
 
     /// Get text from internal clipboard only (ignores system clipboard)
@@ -310,12 +463,97 @@ mod tests {
         assert_eq!(clipboard.get_internal(), "hello");
     }
 
+    #[test]
+    fn test_yank_ring_is_bounded_and_tracks_kind() {
+        let mut clipboard = Clipboard::new();
+        clipboard.apply_config(&crate::config::ClipboardConfig {
+            use_osc52: false,
+            use_system_clipboard: false,
+            ..Default::default()
+        });
+        clipboard.copy_lines("line\n".to_string());
+        assert!(clipboard.is_linewise());
+        clipboard.set_internal("word".to_string());
+        assert!(!clipboard.is_linewise());
+
+        assert_eq!(clipboard.ring_len(), 2);
+        assert_eq!(clipboard.ring_entry(0).unwrap().text, "word");
+        let older = clipboard.ring_entry(1).unwrap();
+        assert_eq!(older.text, "line\n");
+        assert!(older.linewise);
+
+        for i in 0..YANK_RING_SIZE + 4 {
+            clipboard.set_internal(format!("entry {}", i));
+        }
+        assert_eq!(clipboard.ring_len(), YANK_RING_SIZE);
+        assert_eq!(
+            clipboard.ring_entry(0).unwrap().text,
+            format!("entry {}", YANK_RING_SIZE + 3)
+        );
+    }
+
+    #[test]
+    fn test_osc52_copy_sequence() {
+        assert_eq!(
+            osc52_copy_sequence("hello", DEFAULT_OSC52_MAX_BYTES).as_deref(),
+            Some("\x1b]52;c;aGVsbG8=\x07")
+        );
+        // "hello" encodes to 8 bytes
+        assert_eq!(osc52_copy_sequence("hello", 7), None);
+        assert!(osc52_copy_sequence("hello", 0).is_some());
+    }
+
+    #[test]
+    fn test_decode_osc52_reply() {
+        assert_eq!(decode_osc52_reply(b"c;aGVsbG8=").as_deref(), Some("hello"));
+        assert_eq!(decode_osc52_reply(b"c;?"), None);
+        assert_eq!(decode_osc52_reply(b"garbage"), None);
+    }
+
+    #[test]
+    fn test_session_mode_copy_queues_osc52_in_band() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_session_mode(true);
+        clipboard.copy("hello".to_string());
+
+        assert_eq!(
+            clipboard.take_pending_osc52().as_deref(),
+            Some("\x1b]52;c;aGVsbG8=\x07")
+        );
+        let pending = clipboard.take_pending_clipboard().unwrap();
+        assert_eq!(pending.text, "hello");
+        assert!(!pending.use_osc52, "OSC 52 is already sent in-band");
+
+        clipboard.apply_config(&crate::config::ClipboardConfig {
+            osc52_max_bytes: 4,
+            ..Default::default()
+        });
+        clipboard.copy("hello".to_string());
+        assert_eq!(clipboard.take_pending_osc52(), None);
+    }
+
+    #[test]
+    fn test_session_mode_paste_queries_terminal() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_session_mode(true);
+        clipboard.apply_config(&crate::config::ClipboardConfig {
+            osc52_paste: true,
+            ..Default::default()
+        });
+        assert_eq!(clipboard.paste(), None);
+        assert!(clipboard.take_osc52_query_request());
+        assert!(!clipboard.take_osc52_query_request());
+        // The query isn't broadcast with the in-band sequences
+        assert_eq!(clipboard.take_pending_osc52(), None);
+    }
+
     #[test]
     fn test_clipboard_config_disables_osc52() {
         let mut clipboard = Clipboard::new();
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: true,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(!clipboard.use_osc52);
@@ -328,6 +566,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: true,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(clipboard.use_osc52);
@@ -340,6 +579,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
 
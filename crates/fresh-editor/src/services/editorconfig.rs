@@ -0,0 +1,412 @@
+//! EditorConfig (`.editorconfig`) support
+//!
+//! When a file is opened, `.editorconfig` files are read from its directory
+//! upwards until one declares `root = true`. Sections whose glob matches the
+//! file are applied from the outermost file to the nearest one, later
+//! sections overriding earlier ones, as the EditorConfig spec describes.
+//!
+//! The result overrides the editor and language config for that buffer.
+//! Per-buffer commands (toggle indentation, set tab size, ...) still
+//! override it for the rest of the session.
+
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::model::buffer::{Encoding, LineEnding};
+use crate::model::filesystem::FileSystem;
+use crate::state::BufferSettings;
+
+/// Name of the files looked up next to and above an opened file
+pub const EDITORCONFIG_FILE: &str = ".editorconfig";
+
+/// Ranges in `{n1..n2}` globs larger than this match any integer
+const MAX_GLOB_NUMBER_RANGE: i64 = 1000;
+
+/// Properties resolved for one file. `None` means "not set", leaving the
+/// editor's own setting in place.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditorConfigSettings {
+    /// `indent_style`: true for `tab`, false for `space`
+    pub use_tabs: Option<bool>,
+    /// `indent_size`, or `tab_width` when `indent_size = tab`
+    pub indent_size: Option<usize>,
+    /// `tab_width`
+    pub tab_width: Option<usize>,
+    /// `end_of_line`
+    pub end_of_line: Option<LineEnding>,
+    /// `charset`
+    pub charset: Option<Encoding>,
+    /// `trim_trailing_whitespace`
+    pub trim_trailing_whitespace: Option<bool>,
+    /// `insert_final_newline`
+    pub insert_final_newline: Option<bool>,
+}
+
+impl EditorConfigSettings {
+    /// Whether no property applied to the file
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Width of one indentation level, if set
+    pub fn tab_size(&self) -> Option<usize> {
+        self.indent_size.or(self.tab_width)
+    }
+
+    /// Override the indentation and save-time settings of a buffer. Line
+    /// ending and charset are applied separately, see
+    /// [`EditorConfigSettings::apply_format`].
+    pub fn apply(&self, settings: &mut BufferSettings) {
+        if let Some(use_tabs) = self.use_tabs {
+            settings.use_tabs = use_tabs;
+        }
+        if let Some(tab_size) = self.tab_size() {
+            settings.tab_size = tab_size;
+        }
+        if self.trim_trailing_whitespace.is_some() {
+            settings.trim_trailing_whitespace = self.trim_trailing_whitespace;
+        }
+        if self.insert_final_newline.is_some() {
+            settings.insert_final_newline = self.insert_final_newline;
+        }
+    }
+
+    /// Set the line ending and charset of an empty buffer. Files that
+    /// already have content keep what was detected on load, so opening a
+    /// file never marks it modified.
+    pub fn apply_format(&self, buffer: &mut crate::model::buffer::Buffer) {
+        if !buffer.is_empty() {
+            return;
+        }
+        if let Some(line_ending) = self.end_of_line {
+            buffer.set_default_line_ending(line_ending);
+        }
+        if let Some(encoding) = self.charset {
+            buffer.set_default_encoding(encoding);
+        }
+    }
+
+    /// Apply one `key = value` pair. Unknown keys and values are ignored;
+    /// `unset` clears a property.
+    fn set(&mut self, key: &str, value: &str) {
+        let value = value.to_ascii_lowercase();
+        let unset = value == "unset";
+        let flag = || match value.as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        };
+        match key {
+            "indent_style" => {
+                self.use_tabs = match value.as_str() {
+                    "tab" => Some(true),
+                    "space" => Some(false),
+                    _ if unset => None,
+                    _ => self.use_tabs,
+                }
+            }
+            "indent_size" if value == "tab" => self.indent_size = self.tab_width,
+            "indent_size" if unset => self.indent_size = None,
+            "indent_size" => {
+                if let Some(size) = value.parse().ok().filter(|&n: &usize| n > 0) {
+                    self.indent_size = Some(size);
+                }
+            }
+            "tab_width" if unset => self.tab_width = None,
+            "tab_width" => {
+                if let Some(width) = value.parse().ok().filter(|&n: &usize| n > 0) {
+                    self.tab_width = Some(width);
+                }
+            }
+            "end_of_line" => {
+                self.end_of_line = match value.as_str() {
+                    "lf" => Some(LineEnding::LF),
+                    "crlf" => Some(LineEnding::CRLF),
+                    "cr" => Some(LineEnding::CR),
+                    _ if unset => None,
+                    _ => self.end_of_line,
+                }
+            }
+            "charset" => {
+                self.charset = match value.as_str() {
+                    "utf-8" => Some(Encoding::Utf8),
+                    "utf-8-bom" => Some(Encoding::Utf8Bom),
+                    "utf-16le" => Some(Encoding::Utf16Le),
+                    "utf-16be" => Some(Encoding::Utf16Be),
+                    "latin1" => Some(Encoding::Latin1),
+                    _ if unset => None,
+                    _ => self.charset,
+                }
+            }
+            "trim_trailing_whitespace" if unset => self.trim_trailing_whitespace = None,
+            "trim_trailing_whitespace" => {
+                self.trim_trailing_whitespace = flag().or(self.trim_trailing_whitespace)
+            }
+            "insert_final_newline" if unset => self.insert_final_newline = None,
+            "insert_final_newline" => {
+                self.insert_final_newline = flag().or(self.insert_final_newline)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// One parsed `.editorconfig` file
+#[derive(Debug, Default)]
+struct EditorConfigFile {
+    root: bool,
+    sections: Vec<Section>,
+}
+
+#[derive(Debug)]
+struct Section {
+    glob: String,
+    properties: Vec<(String, String)>,
+}
+
+/// Resolve the EditorConfig properties for `path`
+pub fn resolve(fs: &dyn FileSystem, path: &Path) -> EditorConfigSettings {
+    let mut files: Vec<(PathBuf, EditorConfigFile)> = Vec::new();
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if let Ok(bytes) = fs.read_file(&current.join(EDITORCONFIG_FILE)) {
+            let file = parse(&String::from_utf8_lossy(&bytes));
+            let root = file.root;
+            files.push((current.to_path_buf(), file));
+            if root {
+                break;
+            }
+        }
+        dir = current.parent();
+    }
+
+    let mut settings = EditorConfigSettings::default();
+    for (dir, file) in files.iter().rev() {
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        for section in &file.sections {
+            if glob_matches(&section.glob, &relative) {
+                for (key, value) in &section.properties {
+                    settings.set(key, value);
+                }
+            }
+        }
+    }
+    settings
+}
+
+fn parse(contents: &str) -> EditorConfigFile {
+    let mut file = EditorConfigFile::default();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(glob) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            file.sections.push(Section {
+                glob: glob.to_string(),
+                properties: Vec::new(),
+            });
+            continue;
+        }
+        let Some((key, value)) = line.split_once(['=', ':']) else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim().to_string();
+        match file.sections.last_mut() {
+            Some(section) => section.properties.push((key, value)),
+            None if key == "root" => file.root = value.eq_ignore_ascii_case("true"),
+            None => {}
+        }
+    }
+    file
+}
+
+/// Whether a section glob matches a path relative to the `.editorconfig`
+/// directory. Globs without a `/` match the file name at any depth.
+fn glob_matches(glob: &str, relative: &str) -> bool {
+    let anchored = glob.contains('/');
+    let glob = glob.strip_prefix('/').unwrap_or(glob);
+    let prefix = if anchored { "" } else { "(?:.*/)?" };
+    Regex::new(&format!("^{prefix}{}$", glob_to_regex(glob)))
+        .map(|re| re.is_match(relative))
+        .unwrap_or(false)
+}
+
+/// Translate EditorConfig glob syntax (`*`, `**`, `?`, `[...]`, `{a,b}`,
+/// `{n1..n2}`) into a regex fragment
+fn glob_to_regex(glob: &str) -> String {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                out.push_str(".*");
+                i += 1;
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            '\\' if i + 1 < chars.len() => {
+                out.push_str(&regex::escape(&chars[i + 1].to_string()));
+                i += 1;
+            }
+            '[' => match chars[i + 1..].iter().position(|&c| c == ']') {
+                Some(len) => {
+                    let class: String = chars[i + 1..i + 1 + len].iter().collect();
+                    let class = match class.strip_prefix('!') {
+                        Some(rest) => format!("^{}", rest.replace('\\', "\\\\")),
+                        None => class.replace('\\', "\\\\"),
+                    };
+                    out.push_str(&format!("[{class}]"));
+                    i += len + 1;
+                }
+                None => out.push_str("\\["),
+            },
+            '{' => match closing_brace(&chars, i) {
+                Some(end) => {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    out.push_str(&brace_to_regex(&inner));
+                    i = end;
+                }
+                None => out.push_str("\\{"),
+            },
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Index of the `}` closing the `{` at `open`, allowing nesting
+fn closing_brace(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, &c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn brace_to_regex(inner: &str) -> String {
+    if let Some((lo, hi)) = inner.split_once("..") {
+        if let (Ok(lo), Ok(hi)) = (lo.parse::<i64>(), hi.parse::<i64>()) {
+            let (lo, hi) = (lo.min(hi), lo.max(hi));
+            if hi - lo > MAX_GLOB_NUMBER_RANGE {
+                return "[+-]?[0-9]+".to_string();
+            }
+            let numbers: Vec<String> = (lo..=hi).map(|n| n.to_string()).collect();
+            return format!("(?:{})", numbers.join("|"));
+        }
+    }
+
+    // Split on top-level commas only
+    let mut alternatives = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in inner.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    alternatives.push(current);
+    if alternatives.len() == 1 {
+        return regex::escape(&format!("{{{inner}}}"));
+    }
+    let alternatives: Vec<String> = alternatives.iter().map(|a| glob_to_regex(a)).collect();
+    format!("(?:{})", alternatives.join("|"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::filesystem::StdFileSystem;
+
+    #[test]
+    fn globs() {
+        assert!(glob_matches("*", "src/main.rs"));
+        assert!(glob_matches("*.rs", "src/main.rs"));
+        assert!(!glob_matches("*.rs", "src/main.py"));
+        assert!(glob_matches("*.{js,ts}", "web/app.ts"));
+        assert!(glob_matches("src/*.rs", "src/main.rs"));
+        assert!(!glob_matches("src/*.rs", "src/bin/main.rs"));
+        assert!(glob_matches("src/**.rs", "src/bin/main.rs"));
+        assert!(glob_matches("/Makefile", "Makefile"));
+        assert!(!glob_matches("/Makefile", "sub/Makefile"));
+        assert!(glob_matches("file[0-9].txt", "file3.txt"));
+        assert!(!glob_matches("file[!0-9].txt", "file3.txt"));
+        assert!(glob_matches("v{1..3}.md", "v2.md"));
+        assert!(!glob_matches("v{1..3}.md", "v4.md"));
+    }
+
+    #[test]
+    fn later_sections_override_earlier_ones() {
+        let file = parse(
+            "root = true\n\n[*]\nindent_style = space\nindent_size = 4\n\n[*.go]\nindent_style = tab\n",
+        );
+        assert!(file.root);
+        let mut settings = EditorConfigSettings::default();
+        for section in &file.sections {
+            if glob_matches(&section.glob, "main.go") {
+                for (key, value) in &section.properties {
+                    settings.set(key, value);
+                }
+            }
+        }
+        assert_eq!(settings.use_tabs, Some(true));
+        assert_eq!(settings.tab_size(), Some(4));
+    }
+
+    #[test]
+    fn indent_size_tab_uses_tab_width() {
+        let mut settings = EditorConfigSettings::default();
+        settings.set("tab_width", "8");
+        settings.set("indent_size", "tab");
+        assert_eq!(settings.tab_size(), Some(8));
+        settings.set("indent_size", "unset");
+        assert_eq!(settings.indent_size, None);
+    }
+
+    #[test]
+    fn nearer_files_win_and_root_stops_the_walk() {
+        let dir = tempfile::tempdir().unwrap();
+        let outer = dir.path().join("outer");
+        let inner = outer.join("inner");
+        std::fs::create_dir_all(&inner).unwrap();
+        std::fs::write(
+            dir.path().join(EDITORCONFIG_FILE),
+            "[*]\ninsert_final_newline = true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            outer.join(EDITORCONFIG_FILE),
+            "root = true\n[*]\nindent_size = 4\nend_of_line = crlf\n",
+        )
+        .unwrap();
+        std::fs::write(inner.join(EDITORCONFIG_FILE), "[*.txt]\nindent_size = 2\n").unwrap();
+
+        let settings = resolve(&StdFileSystem, &inner.join("a.txt"));
+        assert_eq!(settings.tab_size(), Some(2));
+        assert_eq!(settings.end_of_line, Some(LineEnding::CRLF));
+        assert_eq!(settings.insert_final_newline, None);
+    }
+}
@@ -111,6 +111,27 @@ pub fn lsp_log_path(language: &str) -> PathBuf {
     lsp_log_dir().join(format!("{}-{}.log", language, std::process::id()))
 }
 
+/// Get the directory for session server logs.
+///
+/// Returns `{log_dir}/sessions/`, creating it if necessary.
+pub fn session_log_dir() -> PathBuf {
+    let dir = log_dir().join("sessions");
+    if let Err(e) = fs::create_dir_all(&dir) {
+        tracing::warn!("Failed to create session log directory {:?}: {}", dir, e);
+    }
+    dir
+}
+
+/// Get the path for a session server's log file.
+///
+/// Returns `{log_dir}/sessions/{session_id}.log`. The name depends only on
+/// the session, so the log survives server restarts for post-mortem
+/// debugging; size-based rotation keeps it bounded. Clients attached to the
+/// session log to their own `fresh-client-{PID}.log` instead.
+pub fn session_log_path(session_id: &str) -> PathBuf {
+    session_log_dir().join(format!("{}.log", session_id))
+}
+
 /// Clean up stale log files from dead processes.
 ///
 /// This removes:
@@ -262,13 +262,15 @@ impl LspClientState {
 fn create_client_capabilities() -> ClientCapabilities {
     use lsp_types::{
         CodeActionClientCapabilities, CompletionClientCapabilities, DiagnosticClientCapabilities,
-        DiagnosticTag, DynamicRegistrationClientCapabilities, FoldingRangeCapability,
+        DiagnosticTag, DidChangeWatchedFilesClientCapabilities,
+        DynamicRegistrationClientCapabilities, FoldingRangeCapability,
         FoldingRangeClientCapabilities, FoldingRangeKind, FoldingRangeKindCapability,
         GeneralClientCapabilities, GotoCapability, HoverClientCapabilities,
         InlayHintClientCapabilities, MarkupKind, PublishDiagnosticsClientCapabilities,
         RenameClientCapabilities, SignatureHelpClientCapabilities, TagSupport,
         TextDocumentClientCapabilities, TextDocumentSyncClientCapabilities,
         WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
+        WorkspaceFileOperationsClientCapabilities,
     };
 
     ClientCapabilities {
@@ -283,11 +285,21 @@ fn create_client_capabilities() -> ClientCapabilities {
                 ..Default::default()
             }),
             workspace_folders: Some(true),
+            did_change_watched_files: Some(DidChangeWatchedFilesClientCapabilities {
+                dynamic_registration: Some(false),
+                ..Default::default()
+            }),
+            file_operations: Some(WorkspaceFileOperationsClientCapabilities {
+                will_rename: Some(true),
+                did_rename: Some(true),
+                ..Default::default()
+            }),
             ..Default::default()
         }),
         text_document: Some(TextDocumentClientCapabilities {
             synchronization: Some(TextDocumentSyncClientCapabilities {
                 did_save: Some(true),
+                will_save_wait_until: Some(true),
                 ..Default::default()
             }),
             completion: Some(CompletionClientCapabilities {
@@ -448,6 +460,33 @@ fn extract_capability_summary(caps: &ServerCapabilities) -> ServerCapabilitySumm
         })
         .unwrap_or((None, false, false, false));
 
+    let (did_save, did_save_include_text, will_save_wait_until) = match &caps.text_document_sync {
+        Some(lsp_types::TextDocumentSyncCapability::Options(opts)) => {
+            let (save, include_text) = match &opts.save {
+                Some(lsp_types::TextDocumentSyncSaveOptions::Supported(v)) => (*v, false),
+                Some(lsp_types::TextDocumentSyncSaveOptions::SaveOptions(o)) => {
+                    (true, o.include_text.unwrap_or(false))
+                }
+                None => (false, false),
+            };
+            (
+                save,
+                include_text,
+                opts.will_save_wait_until.unwrap_or(false),
+            )
+        }
+        // A bare sync kind says nothing about saves; keep sending didSave
+        // as servers announcing only a kind commonly expect it.
+        Some(lsp_types::TextDocumentSyncCapability::Kind(_)) | None => (true, false, false),
+    };
+
+    // Registration filters (which paths a server cares about) aren't
+    // checked; a server that registered for renames gets all of them.
+    let file_operations = caps
+        .workspace
+        .as_ref()
+        .and_then(|w| w.file_operations.as_ref());
+
     ServerCapabilitySummary {
         initialized: false, // set to true by set_server_capabilities
         hover: bool_or_options(&caps.hover_provider, |p| match p {
@@ -523,6 +562,11 @@ fn extract_capability_summary(caps: &ServerCapabilities) -> ServerCapabilitySumm
             lsp_types::OneOf::Right(_) => true,
         }),
         diagnostics: caps.diagnostic_provider.is_some(),
+        did_save,
+        did_save_include_text,
+        will_save_wait_until,
+        will_rename_files: file_operations.is_some_and(|ops| ops.will_rename.is_some()),
+        did_rename_files: file_operations.is_some_and(|ops| ops.did_rename.is_some()),
     }
 }
 
@@ -531,6 +575,36 @@ fn bool_or_options<T>(opt: &Option<T>, check: impl FnOnce(&T) -> bool) -> bool {
     opt.as_ref().is_some_and(check)
 }
 
+/// Normalize a `textDocument/definition` result to a list of locations.
+///
+/// Servers may answer with a single `Location`, an array of `Location`s, or
+/// `LocationLink`s (alone or in an array). Links are reduced to their target
+/// selection range, which is where the cursor should land. Anything else
+/// (including `null`) yields no locations.
+fn parse_definition_response(result: Value) -> Vec<lsp_types::Location> {
+    fn from_link(link: lsp_types::LocationLink) -> lsp_types::Location {
+        lsp_types::Location {
+            uri: link.target_uri,
+            range: link.target_selection_range,
+        }
+    }
+
+    let items = match result {
+        Value::Array(items) => items,
+        Value::Null => return Vec::new(),
+        single => vec![single],
+    };
+    items
+        .into_iter()
+        .filter_map(|item| {
+            serde_json::from_value::<lsp_types::LocationLink>(item.clone())
+                .map(from_link)
+                .or_else(|_| serde_json::from_value::<lsp_types::Location>(item))
+                .ok()
+        })
+        .collect()
+}
+
 /// Commands sent from the main loop to the LSP task
 #[derive(Debug)]
 enum LspCommand {
@@ -566,6 +640,12 @@ enum LspCommand {
         removed: Vec<lsp_types::WorkspaceFolder>,
     },
 
+    /// Notify files created, changed or deleted on disk
+    DidChangeWatchedFiles { changes: Vec<lsp_types::FileEvent> },
+
+    /// Notify files renamed by the editor (workspace/didRenameFiles)
+    DidRenameFiles { files: Vec<lsp_types::FileRename> },
+
     /// Request completion at position
     Completion {
         request_id: u64,
@@ -683,6 +763,15 @@ enum LspCommand {
         item: Box<lsp_types::CompletionItem>,
     },
 
+    /// Ask for edits to apply before saving (textDocument/willSaveWaitUntil)
+    WillSaveWaitUntil { request_id: u64, uri: Uri },
+
+    /// Ask for edits to apply before renaming files (workspace/willRenameFiles)
+    WillRenameFiles {
+        request_id: u64,
+        files: Vec<lsp_types::FileRename>,
+    },
+
     /// Format a document (textDocument/formatting)
     DocumentFormatting {
         request_id: u64,
@@ -831,6 +920,22 @@ impl LspState {
                         )
                         .await;
                 }
+                LspCommand::DidChangeWatchedFiles { changes } => {
+                    tracing::info!("Replaying DidChangeWatchedFiles: {} changes", changes.len());
+                    let _ = self
+                        .send_notification::<lsp_types::notification::DidChangeWatchedFiles>(
+                            lsp_types::DidChangeWatchedFilesParams { changes },
+                        )
+                        .await;
+                }
+                LspCommand::DidRenameFiles { files } => {
+                    tracing::info!("Replaying DidRenameFiles: {} files", files.len());
+                    let _ = self
+                        .send_notification::<lsp_types::notification::DidRenameFiles>(
+                            lsp_types::RenameFilesParams { files },
+                        )
+                        .await;
+                }
                 LspCommand::SemanticTokensFull { request_id, uri } => {
                     tracing::info!("Replaying semantic tokens request for {}", uri.as_str());
                     let s = self.clone();
@@ -1376,29 +1481,7 @@ impl LspState {
             .await
         {
             Ok(result) => {
-                // Parse the definition response (can be Location, Vec<Location>, or LocationLink)
-                let locations = if let Ok(loc) =
-                    serde_json::from_value::<lsp_types::Location>(result.clone())
-                {
-                    vec![loc]
-                } else if let Ok(locs) =
-                    serde_json::from_value::<Vec<lsp_types::Location>>(result.clone())
-                {
-                    locs
-                } else if let Ok(links) =
-                    serde_json::from_value::<Vec<lsp_types::LocationLink>>(result)
-                {
-                    // Convert LocationLink to Location
-                    links
-                        .into_iter()
-                        .map(|link| lsp_types::Location {
-                            uri: link.target_uri,
-                            range: link.target_selection_range,
-                        })
-                        .collect()
-                } else {
-                    vec![]
-                };
+                let locations = parse_definition_response(result);
 
                 // Send to main loop
                 let _ = self.async_tx.send(AsyncMessage::LspGotoDefinition {
@@ -1585,6 +1668,10 @@ impl LspState {
                 // Array of marked strings - format as markdown
                 let content = arr
                     .iter()
+                    .filter(|marked| match marked {
+                        MarkedString::String(s) => !s.trim().is_empty(),
+                        MarkedString::LanguageString(ls) => !ls.value.trim().is_empty(),
+                    })
                     .map(|marked| match marked {
                         MarkedString::String(s) => s.clone(),
                         MarkedString::LanguageString(ls) => {
@@ -1904,6 +1991,78 @@ impl LspState {
         }
     }
 
+    /// Handle textDocument/willSaveWaitUntil request. An answer is always
+    /// sent back (without edits on failure) since the save waits for it.
+    async fn handle_will_save_wait_until(
+        &self,
+        request_id: u64,
+        uri: Uri,
+        pending: &PendingRequests,
+    ) -> Result<(), String> {
+        use lsp_types::{TextDocumentSaveReason, WillSaveTextDocumentParams};
+
+        let params = WillSaveTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            reason: TextDocumentSaveReason::MANUAL,
+        };
+
+        let result = self
+            .send_request_sequential::<_, Value>(
+                "textDocument/willSaveWaitUntil",
+                Some(params),
+                pending,
+            )
+            .await;
+        let edits = match &result {
+            Ok(value) if !value.is_null() => {
+                serde_json::from_value::<Vec<lsp_types::TextEdit>>(value.clone())
+                    .unwrap_or_default()
+            }
+            Ok(_) => Vec::new(),
+            Err(e) => {
+                tracing::debug!("textDocument/willSaveWaitUntil failed: {}", e);
+                Vec::new()
+            }
+        };
+        let _ = self.async_tx.send(AsyncMessage::LspWillSaveWaitUntil {
+            request_id,
+            uri: uri.as_str().to_string(),
+            edits,
+        });
+        result.map(|_| ())
+    }
+
+    /// Handle workspace/willRenameFiles request. An answer is always sent
+    /// back (without an edit on failure) since the rename waits for it.
+    async fn handle_will_rename_files(
+        &self,
+        request_id: u64,
+        files: Vec<lsp_types::FileRename>,
+        pending: &PendingRequests,
+    ) -> Result<(), String> {
+        let result = self
+            .send_request_sequential::<_, Value>(
+                "workspace/willRenameFiles",
+                Some(lsp_types::RenameFilesParams { files }),
+                pending,
+            )
+            .await;
+        let edit = match &result {
+            Ok(value) if !value.is_null() => {
+                serde_json::from_value::<lsp_types::WorkspaceEdit>(value.clone()).ok()
+            }
+            Ok(_) => None,
+            Err(e) => {
+                tracing::debug!("workspace/willRenameFiles failed: {}", e);
+                None
+            }
+        };
+        let _ = self
+            .async_tx
+            .send(AsyncMessage::LspWillRenameFiles { request_id, edit });
+        result.map(|_| ())
+    }
+
     /// Handle textDocument/formatting request
     async fn handle_document_formatting(
         &self,
@@ -3049,6 +3208,37 @@ impl LspTask {
                             .push(LspCommand::DidChangeWorkspaceFolders { added, removed });
                     }
                 }
+                LspCommand::DidChangeWatchedFiles { changes } => {
+                    if initialized {
+                        tracing::info!(
+                            "Processing DidChangeWatchedFiles: {} changes",
+                            changes.len()
+                        );
+                        let _ = state
+                            .send_notification::<lsp_types::notification::DidChangeWatchedFiles>(
+                                lsp_types::DidChangeWatchedFilesParams { changes },
+                            )
+                            .await;
+                    } else {
+                        tracing::trace!(
+                            "Queueing DidChangeWatchedFiles until initialization completes"
+                        );
+                        pending_commands.push(LspCommand::DidChangeWatchedFiles { changes });
+                    }
+                }
+                LspCommand::DidRenameFiles { files } => {
+                    if initialized {
+                        tracing::info!("Processing DidRenameFiles: {} files", files.len());
+                        let _ = state
+                            .send_notification::<lsp_types::notification::DidRenameFiles>(
+                                lsp_types::RenameFilesParams { files },
+                            )
+                            .await;
+                    } else {
+                        tracing::trace!("Queueing DidRenameFiles until initialization completes");
+                        pending_commands.push(LspCommand::DidRenameFiles { files });
+                    }
+                }
                 LspCommand::Completion {
                     request_id,
                     uri,
@@ -3361,6 +3551,20 @@ impl LspTask {
                             .await);
                     }
                 }
+                LspCommand::WillSaveWaitUntil { request_id, uri } => {
+                    if initialized {
+                        spawn_request!(state, pending, |s, p| s
+                            .handle_will_save_wait_until(request_id, uri, &p)
+                            .await);
+                    }
+                }
+                LspCommand::WillRenameFiles { request_id, files } => {
+                    if initialized {
+                        spawn_request!(state, pending, |s, p| s
+                            .handle_will_rename_files(request_id, files, &p)
+                            .await);
+                    }
+                }
                 LspCommand::DocumentFormatting {
                     request_id,
                     uri,
@@ -4304,6 +4508,23 @@ impl LspHandle {
             .map_err(|_| "Failed to send workspace folder change".to_string())
     }
 
+    /// Notify the server of files created, changed or deleted on disk
+    pub fn did_change_watched_files(
+        &self,
+        changes: Vec<lsp_types::FileEvent>,
+    ) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::DidChangeWatchedFiles { changes })
+            .map_err(|_| "Failed to send did_change_watched_files command".to_string())
+    }
+
+    /// Notify the server of files renamed by the editor
+    pub fn did_rename_files(&self, files: Vec<lsp_types::FileRename>) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::DidRenameFiles { files })
+            .map_err(|_| "Failed to send did_rename_files command".to_string())
+    }
+
     /// Request completion at position
     pub fn completion(
         &self,
@@ -4484,6 +4705,24 @@ impl LspHandle {
             .map_err(|_| "Failed to send completion_resolve command".to_string())
     }
 
+    /// Ask for edits to apply before saving (textDocument/willSaveWaitUntil)
+    pub fn will_save_wait_until(&self, request_id: u64, uri: Uri) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::WillSaveWaitUntil { request_id, uri })
+            .map_err(|_| "Failed to send will_save_wait_until command".to_string())
+    }
+
+    /// Ask for edits to apply before renaming files (workspace/willRenameFiles)
+    pub fn will_rename_files(
+        &self,
+        request_id: u64,
+        files: Vec<lsp_types::FileRename>,
+    ) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::WillRenameFiles { request_id, files })
+            .map_err(|_| "Failed to send will_rename_files command".to_string())
+    }
+
     /// Format a document (textDocument/formatting)
     pub fn document_formatting(
         &self,
@@ -4732,6 +4971,58 @@ mod tests {
         Arc::new(LocalLongRunningSpawner)
     }
 
+    #[test]
+    fn test_extract_hover_contents_skips_empty_marked_strings() {
+        use lsp_types::{HoverContents, LanguageString, MarkedString};
+
+        let contents = HoverContents::Array(vec![
+            MarkedString::String("  ".to_string()),
+            MarkedString::LanguageString(LanguageString {
+                language: "rust".to_string(),
+                value: "fn helper()".to_string(),
+            }),
+            MarkedString::String(String::new()),
+        ]);
+        let (text, is_markdown) = LspState::extract_hover_contents(&contents);
+        assert!(is_markdown);
+        assert_eq!(text, "```rust\nfn helper()\n```");
+
+        let (text, _) = LspState::extract_hover_contents(&HoverContents::Array(vec![]));
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn test_parse_definition_response_shapes() {
+        let location = serde_json::json!({
+            "uri": "file:///a.rs",
+            "range": {"start": {"line": 3, "character": 4}, "end": {"line": 3, "character": 8}}
+        });
+        let link = serde_json::json!({
+            "targetUri": "file:///b.rs",
+            "targetRange": {"start": {"line": 10, "character": 0}, "end": {"line": 20, "character": 1}},
+            "targetSelectionRange": {"start": {"line": 12, "character": 7}, "end": {"line": 12, "character": 11}}
+        });
+
+        let single = parse_definition_response(location.clone());
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].uri.as_str(), "file:///a.rs");
+        assert_eq!(single[0].range.start.line, 3);
+
+        let links = parse_definition_response(serde_json::json!([link.clone()]));
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].uri.as_str(), "file:///b.rs");
+        assert_eq!(links[0].range.start.line, 12);
+        assert_eq!(links[0].range.start.character, 7);
+
+        assert_eq!(parse_definition_response(link).len(), 1);
+        assert_eq!(
+            parse_definition_response(serde_json::json!([location.clone(), location])).len(),
+            2
+        );
+        assert!(parse_definition_response(Value::Null).is_empty());
+        assert!(parse_definition_response(serde_json::json!([])).is_empty());
+    }
+
     #[test]
     fn test_json_rpc_request_serialization() {
         let request = JsonRpcRequest {
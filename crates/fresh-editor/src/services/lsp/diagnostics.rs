@@ -2,6 +2,7 @@
 //!
 //! This module handles converting LSP diagnostics to visual overlays in the editor.
 //! Diagnostics are displayed as colored underlines (red for errors, yellow for warnings, etc.)
+use crate::config::DiagnosticsPanelSeverity;
 use crate::model::buffer::Buffer;
 use crate::state::EditorState;
 use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
@@ -17,6 +18,31 @@ pub fn lsp_diagnostic_namespace() -> OverlayNamespace {
     OverlayNamespace::from_string("lsp-diagnostic".to_string())
 }
 
+/// Overlay priorities of diagnostics, by severity.
+pub const ERROR_PRIORITY: i32 = 100;
+pub const WARNING_PRIORITY: i32 = 50;
+pub const INFO_PRIORITY: i32 = 30;
+pub const HINT_PRIORITY: i32 = 10;
+
+/// Severity of a diagnostic overlay, as used by
+/// `diagnostics_panel_severities`.
+pub fn severity_for_priority(priority: i32) -> DiagnosticsPanelSeverity {
+    match priority {
+        ERROR_PRIORITY => DiagnosticsPanelSeverity::Error,
+        WARNING_PRIORITY => DiagnosticsPanelSeverity::Warning,
+        INFO_PRIORITY => DiagnosticsPanelSeverity::Info,
+        _ => DiagnosticsPanelSeverity::Hint,
+    }
+}
+
+/// A diagnostic's code (e.g. "E0308") as text, numeric codes included.
+pub fn diagnostic_code(diagnostic: &Diagnostic) -> Option<String> {
+    match diagnostic.code.as_ref()? {
+        lsp_types::NumberOrString::Number(n) => Some(n.to_string()),
+        lsp_types::NumberOrString::String(s) => Some(s.clone()),
+    }
+}
+
 /// Cache for diagnostic hash to avoid redundant updates, keyed by file path.
 /// This prevents diagnostics from one buffer from invalidating another buffer's cache.
 static DIAGNOSTIC_CACHE: LazyLock<Mutex<HashMap<String, u64>>> =
@@ -61,6 +87,27 @@ fn compute_diagnostic_hash(diagnostics: &[Diagnostic]) -> u64 {
     hasher.finish()
 }
 
+/// Drop diagnostics repeating an earlier one's range, message and source.
+///
+/// Several servers for one language (or a server's push and pull results)
+/// often report the same problem; it should show up once. The first
+/// occurrence is kept, so order otherwise stays as given.
+pub fn dedup_diagnostics(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut seen = std::collections::HashSet::new();
+    diagnostics
+        .into_iter()
+        .filter(|diag| {
+            let start = diag.range.start;
+            let end = diag.range.end;
+            seen.insert((
+                (start.line, start.character, end.line, end.character),
+                diag.message.clone(),
+                diag.source.clone(),
+            ))
+        })
+        .collect()
+}
+
 /// Invalidate the diagnostic cache for a specific file path.
 ///
 /// Call this when the buffer content changes (e.g., after a user edit) to ensure
@@ -184,28 +231,28 @@ pub fn diagnostic_to_overlay(
             OverlayFace::Background {
                 color: theme.diagnostic_error_bg,
             },
-            100, // Highest priority
+            ERROR_PRIORITY,
             "diagnostic.error_bg",
         ),
         Some(DiagnosticSeverity::WARNING) => (
             OverlayFace::Background {
                 color: theme.diagnostic_warning_bg,
             },
-            50, // Medium priority
+            WARNING_PRIORITY,
             "diagnostic.warning_bg",
         ),
         Some(DiagnosticSeverity::INFORMATION) => (
             OverlayFace::Background {
                 color: theme.diagnostic_info_bg,
             },
-            30, // Lower priority
+            INFO_PRIORITY,
             "diagnostic.info_bg",
         ),
         Some(DiagnosticSeverity::HINT) | None => (
             OverlayFace::Background {
                 color: theme.diagnostic_hint_bg,
             },
-            10, // Lowest priority
+            HINT_PRIORITY,
             "diagnostic.hint_bg",
         ),
         _ => return None, // Unknown severity
@@ -252,6 +299,44 @@ pub fn apply_diagnostics_to_state(
     }
 }
 
+/// Diagnostic counts of a buffer, shown in the status bar as `E:2 W:1`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    /// Info and hint diagnostics.
+    pub infos: usize,
+}
+
+impl DiagnosticCounts {
+    /// Count the diagnostic overlays among `overlays`.
+    pub fn from_overlays(overlays: &[Overlay]) -> Self {
+        let ns = lsp_diagnostic_namespace();
+        let mut counts = Self::default();
+        for overlay in overlays {
+            if overlay.namespace.as_ref() != Some(&ns) {
+                continue;
+            }
+            match overlay.priority {
+                ERROR_PRIORITY => counts.errors += 1,
+                WARNING_PRIORITY => counts.warnings += 1,
+                _ => counts.infos += 1,
+            }
+        }
+        counts
+    }
+
+    /// `E:2 W:1 I:3`, leaving out zero counts; `None` without diagnostics.
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [("E", self.errors), ("W", self.warnings), ("I", self.infos)]
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(label, count)| format!("{label}:{count}"))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,6 +344,60 @@ mod tests {
     use crate::view::theme;
     use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
 
+    fn diagnostic_at(line: u32, message: &str, source: Option<&str>) -> Diagnostic {
+        Diagnostic {
+            range: Range {
+                start: Position { line, character: 0 },
+                end: Position { line, character: 3 },
+            },
+            message: message.to_string(),
+            source: source.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dedup_diagnostics_by_range_message_and_source() {
+        let merged = dedup_diagnostics(vec![
+            diagnostic_at(0, "unused variable", Some("rustc")),
+            diagnostic_at(0, "unused variable", Some("clippy")),
+            diagnostic_at(0, "unused variable", Some("rustc")),
+            diagnostic_at(1, "unused variable", Some("rustc")),
+            diagnostic_at(0, "dead code", Some("rustc")),
+        ]);
+
+        let summary: Vec<_> = merged
+            .iter()
+            .map(|d| (d.range.start.line, d.message.as_str(), d.source.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "unused variable", Some("rustc")),
+                (0, "unused variable", Some("clippy")),
+                (1, "unused variable", Some("rustc")),
+                (0, "dead code", Some("rustc")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_counts_summary_skips_zero_counts() {
+        assert_eq!(DiagnosticCounts::default().summary(), None);
+        let counts = DiagnosticCounts {
+            errors: 2,
+            warnings: 1,
+            infos: 0,
+        };
+        assert_eq!(counts.summary().as_deref(), Some("E:2 W:1"));
+        let counts = DiagnosticCounts {
+            errors: 0,
+            warnings: 0,
+            infos: 3,
+        };
+        assert_eq!(counts.summary().as_deref(), Some("I:3"));
+    }
+
     #[test]
     fn test_lsp_position_to_byte() {
         let buffer = Buffer::from_str_test("hello\nworld\ntest");
@@ -216,6 +216,16 @@ pub struct ServerCapabilitySummary {
     pub document_symbols: bool,
     pub workspace_symbols: bool,
     pub diagnostics: bool,
+    /// Whether the server wants `textDocument/didSave` notifications.
+    pub did_save: bool,
+    /// Whether `didSave` should carry the full document text.
+    pub did_save_include_text: bool,
+    /// Whether the server answers `textDocument/willSaveWaitUntil`.
+    pub will_save_wait_until: bool,
+    /// Whether the server answers `workspace/willRenameFiles`.
+    pub will_rename_files: bool,
+    /// Whether the server wants `workspace/didRenameFiles` notifications.
+    pub did_rename_files: bool,
 }
 
 /// A named LSP handle with feature filter metadata and per-server capabilities.
@@ -749,6 +759,11 @@ impl LspManager {
             .collect()
     }
 
+    /// Get every running handle, whatever languages it serves.
+    pub fn all_handles(&self) -> impl Iterator<Item = &ServerHandle> {
+        self.handles.iter()
+    }
+
     /// Get all mutable handles that accept a language (both language-specific and universal).
     pub fn get_handles_mut(&mut self, language: &str) -> Vec<&mut ServerHandle> {
         self.handles
@@ -2029,6 +2044,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2053,6 +2070,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2077,6 +2096,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "c_sharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2142,6 +2163,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2257,6 +2280,8 @@ mod tests {
             filenames: vec![],
             grammar: String::new(),
             comment_prefix: Some("//".to_string()),
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
@@ -8,6 +8,7 @@ pub mod authority;
 pub mod clipboard;
 pub mod completion;
 pub mod counters;
+pub mod editorconfig;
 pub mod fs;
 #[cfg(target_os = "linux")]
 pub mod gpm;
@@ -22,6 +23,7 @@ pub mod recovery;
 pub mod release_checker;
 pub mod remote;
 pub mod signal_handler;
+pub mod spell_check;
 pub mod status_log;
 pub mod styled_html;
 pub mod telemetry;
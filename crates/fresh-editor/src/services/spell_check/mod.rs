@@ -0,0 +1,459 @@
+//! Spell checking for prose and comments
+//!
+//! Words are looked up in the Hunspell or system word lists found on the
+//! machine, in any word list placed in `dictionaries/` in the config
+//! directory, and in the user's own dictionary (`dictionary.txt` there).
+//! Common suffixes are stripped before lookup so plain word lists cover
+//! plurals and verb forms. Without any word list there is no checker and
+//! spell checking stays off.
+//!
+//! Tokens that look like code — identifiers with underscores, digits or
+//! inner capitals, paths, URLs, dotted names — are never reported.
+
+use std::collections::HashSet;
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+/// Word lists installed by the system or a package manager, when present
+const SYSTEM_WORD_LISTS: &[&str] = &[
+    "/usr/share/hunspell/en_US.dic",
+    "/usr/share/myspell/en_US.dic",
+    "/usr/share/myspell/dicts/en_US.dic",
+    "/usr/local/share/hunspell/en_US.dic",
+    "/opt/homebrew/share/hunspell/en_US.dic",
+    "/Library/Spelling/en_US.dic",
+    "/usr/share/dict/words",
+];
+
+/// Directory in the config directory whose files are all read as word
+/// lists, on every platform
+pub const DICTIONARIES_DIR: &str = "dictionaries";
+
+/// File in the config directory holding words added by the user
+pub const USER_DICTIONARY_FILE: &str = "dictionary.txt";
+
+/// Languages whose whole text is prose. In every other language only
+/// comments are checked.
+pub const PROSE_LANGUAGES: &[&str] = &["markdown", "text"];
+
+/// Suffixes stripped before lookup, each with the endings that may have
+/// been dropped from the stem ("making" → "make", "cities" → "city")
+const SUFFIXES: &[(&str, &[&str])] = &[
+    ("'s", &[""]),
+    ("n't", &["", "n"]),
+    ("'ll", &[""]),
+    ("'re", &[""]),
+    ("'ve", &[""]),
+    ("'d", &[""]),
+    ("'m", &[""]),
+    ("ies", &["y"]),
+    ("ied", &["y"]),
+    ("ier", &["y"]),
+    ("iest", &["y"]),
+    ("ily", &["y"]),
+    ("es", &["", "e"]),
+    ("s", &[""]),
+    ("ed", &["", "e"]),
+    ("ing", &["", "e"]),
+    ("er", &["", "e"]),
+    ("ers", &["", "e"]),
+    ("est", &["", "e"]),
+    ("ly", &[""]),
+    ("ment", &[""]),
+    ("ments", &[""]),
+    ("ness", &[""]),
+    ("able", &["", "e"]),
+    ("ation", &["", "e"]),
+    ("ations", &["", "e"]),
+];
+
+/// Characters that mark a whitespace-delimited chunk as code rather than prose
+const CODE_MARKERS: &[char] = &[
+    '_', '/', '\\', '@', '$', '%', '^', '=', '<', '>', '{', '}', '|', '~', '`',
+];
+
+/// A word list plus the words the user added during this session or in
+/// their user dictionary
+pub struct SpellChecker {
+    words: HashSet<String>,
+    user_words: RwLock<HashSet<String>>,
+}
+
+impl SpellChecker {
+    /// A checker knowing only `words`
+    pub fn from_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|w| w.trim().to_lowercase())
+                .filter(|w| !w.is_empty())
+                .collect(),
+            user_words: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Every system word list found, the word lists in the config
+    /// directory and the user dictionary. `None` when no word list was
+    /// found: the user dictionary alone is not enough to check prose.
+    fn load() -> Option<Self> {
+        let mut checker =
+            Self::from_words(TEST_WORD_LIST.get().copied().unwrap_or_default().lines());
+        let config_dir = USER_DICTIONARY_DIR.get();
+        let config_word_lists: Vec<PathBuf> = config_dir
+            .and_then(|dir| std::fs::read_dir(dir.join(DICTIONARIES_DIR)).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .collect();
+        for path in SYSTEM_WORD_LISTS
+            .iter()
+            .map(PathBuf::from)
+            .chain(config_word_lists)
+        {
+            if let Ok(contents) = std::fs::read_to_string(path) {
+                checker.words.extend(parse_word_list(&contents));
+            }
+        }
+        if checker.words.is_empty() {
+            tracing::info!("No spell check word list found");
+            return None;
+        }
+        let user_dictionary = config_dir.map(|dir| dir.join(USER_DICTIONARY_FILE));
+        if let Some(Ok(contents)) = user_dictionary.map(std::fs::read_to_string) {
+            checker
+                .user_words
+                .get_mut()
+                .unwrap_or_else(|e| e.into_inner())
+                .extend(parse_word_list(&contents));
+        }
+        Some(checker)
+    }
+
+    /// Whether `word` (or its stem) is in the dictionary
+    pub fn is_known(&self, word: &str) -> bool {
+        let user_words = self.user_words.read().unwrap_or_else(|e| e.into_inner());
+        let known = |w: &str| self.words.contains(w) || user_words.contains(w);
+
+        let lower = word.to_lowercase().replace('\u{2019}', "'");
+        if known(&lower) {
+            return true;
+        }
+        SUFFIXES.iter().any(|(suffix, endings)| {
+            let Some(stem) = lower.strip_suffix(suffix) else {
+                return false;
+            };
+            if stem.len() < 2 {
+                return false;
+            }
+            if endings
+                .iter()
+                .any(|ending| known(&format!("{stem}{ending}")))
+            {
+                return true;
+            }
+            // "running" → "run", "stopped" → "stop"
+            let mut chars = stem.chars().rev();
+            match (chars.next(), chars.next()) {
+                (Some(a), Some(b)) if a == b => known(&stem[..stem.len() - a.len_utf8()]),
+                _ => false,
+            }
+        })
+    }
+
+    /// Accept `word` from now on
+    pub fn add_user_word(&self, word: &str) {
+        self.user_words
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(word.to_lowercase());
+    }
+
+    /// Byte ranges of the unknown words in `text`, skipping anything that
+    /// looks like code
+    pub fn misspellings(&self, text: &str) -> Vec<Range<usize>> {
+        let mut found = Vec::new();
+        for chunk in chunks(text) {
+            let chunk_text = &text[chunk.clone()];
+            if looks_like_code(chunk_text) {
+                continue;
+            }
+            for word in words_in(chunk_text) {
+                let word_text = &chunk_text[word.clone()];
+                if is_checkable(word_text) && !self.is_known(word_text) {
+                    found.push(chunk.start + word.start..chunk.start + word.end);
+                }
+            }
+        }
+        found
+    }
+
+    /// Up to `limit` known words close to `word`, closest first, with the
+    /// capitalization of `word`
+    pub fn suggestions(&self, word: &str, limit: usize) -> Vec<String> {
+        let lower = word.to_lowercase();
+        let mut suggestions: Vec<String> = Vec::new();
+        let push_known = |candidates: Vec<String>, suggestions: &mut Vec<String>| {
+            for candidate in candidates {
+                if suggestions.len() >= limit {
+                    break;
+                }
+                if candidate != lower
+                    && !suggestions.contains(&candidate)
+                    && self.is_known(&candidate)
+                {
+                    suggestions.push(candidate);
+                }
+            }
+        };
+
+        let first = edits(&lower);
+        push_known(first.clone(), &mut suggestions);
+        if suggestions.len() < limit && lower.chars().count() <= 12 {
+            let mut second: Vec<String> = first.iter().flat_map(|w| edits(w)).collect();
+            second.sort();
+            second.dedup();
+            push_known(second, &mut suggestions);
+        }
+
+        let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+        suggestions
+            .into_iter()
+            .map(|s| if capitalized { capitalize(&s) } else { s })
+            .collect()
+    }
+}
+
+/// Config directory whose user dictionary is read when the shared checker
+/// is first loaded
+static USER_DICTIONARY_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Word list read before the system ones, set by tests that need the
+/// same dictionary on every machine
+static TEST_WORD_LIST: OnceLock<&'static str> = OnceLock::new();
+
+/// The shared checker, loaded on first use so editors that never check
+/// spelling don't read any word list. `None` when no dictionary is
+/// installed.
+pub fn spell_checker() -> Option<&'static SpellChecker> {
+    static CHECKER: OnceLock<Option<SpellChecker>> = OnceLock::new();
+    CHECKER.get_or_init(SpellChecker::load).as_ref()
+}
+
+/// Test-only hook: read `words` (one per line) in addition to the
+/// installed word lists. Must be called before the checker is first used;
+/// only the first call has an effect.
+#[doc(hidden)]
+pub fn set_word_list_for_tests(words: &'static str) {
+    let _ = TEST_WORD_LIST.set(words);
+}
+
+/// Read the user dictionary from `config_dir` when the checker loads. Only
+/// the first call has an effect.
+pub fn set_user_dictionary_dir(config_dir: &Path) {
+    let _ = USER_DICTIONARY_DIR.set(config_dir.to_path_buf());
+}
+
+/// Accept `word` and append it to the user dictionary in `config_dir`
+pub fn add_to_user_dictionary(config_dir: &Path, word: &str) -> std::io::Result<()> {
+    if let Some(checker) = spell_checker() {
+        checker.add_user_word(word);
+    }
+    std::fs::create_dir_all(config_dir)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(config_dir.join(USER_DICTIONARY_FILE))?;
+    writeln!(file, "{}", word.to_lowercase())
+}
+
+/// Words of a plain or Hunspell `.dic` word list. Hunspell affix flags
+/// after `/` and the leading entry count are dropped.
+fn parse_word_list(contents: &str) -> impl Iterator<Item = String> + '_ {
+    contents.lines().filter_map(|line| {
+        let word = line.split('/').next()?.trim();
+        if word.is_empty() || word.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some(word.to_lowercase())
+    })
+}
+
+/// Whitespace-delimited chunks of `text`
+fn chunks(text: &str) -> Vec<Range<usize>> {
+    let mut chunks = Vec::new();
+    let mut start = None;
+    for (idx, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                chunks.push(s..idx);
+                start = None;
+            }
+            (false, None) => start = Some(idx),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        chunks.push(s..text.len());
+    }
+    chunks
+}
+
+fn looks_like_code(chunk: &str) -> bool {
+    if chunk.contains(CODE_MARKERS) || chunk.contains("::") {
+        return true;
+    }
+    let chars: Vec<char> = chunk.chars().collect();
+    chars.windows(2).any(|pair| match pair {
+        // foo.bar, example.com
+        ['.', next] => next.is_alphanumeric(),
+        // call(
+        [prev, '('] => prev.is_alphanumeric(),
+        [c, _] => c.is_ascii_digit(),
+        _ => false,
+    }) || chars.last().is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Runs of letters (with inner apostrophes) in a chunk
+fn words_in(chunk: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    for (idx, c) in chunk.char_indices() {
+        let letter = c.is_alphabetic();
+        let apostrophe = matches!(c, '\'' | '\u{2019}');
+        match start {
+            None if letter => {
+                start = Some(idx);
+                end = idx + c.len_utf8();
+            }
+            Some(_) if letter => end = idx + c.len_utf8(),
+            Some(_) if apostrophe => {}
+            Some(s) => {
+                words.push(s..end);
+                start = None;
+            }
+            None => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push(s..end);
+    }
+    words
+}
+
+/// Whether a word is plain lowercase or capitalized English, as opposed to
+/// an acronym, a camelCase identifier or a word in another script
+fn is_checkable(word: &str) -> bool {
+    word.chars().count() >= 2 && word.is_ascii() && word.chars().skip(1).all(|c| !c.is_uppercase())
+}
+
+/// Every string one transposition, deletion, substitution or insertion
+/// away from `word`, in that order
+fn edits(word: &str) -> Vec<String> {
+    const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+    let chars: Vec<char> = word.chars().collect();
+    let join = |parts: &[&[char]]| parts.iter().flat_map(|p| p.iter()).collect::<String>();
+    let mut out = Vec::new();
+    for i in 0..chars.len().saturating_sub(1) {
+        let mut swapped = chars.clone();
+        swapped.swap(i, i + 1);
+        out.push(swapped.into_iter().collect());
+    }
+    for i in 0..chars.len() {
+        out.push(join(&[&chars[..i], &chars[i + 1..]]));
+    }
+    for i in 0..chars.len() {
+        for c in LETTERS.chars() {
+            if c != chars[i] {
+                out.push(join(&[&chars[..i], &[c], &chars[i + 1..]]));
+            }
+        }
+    }
+    for i in 0..=chars.len() {
+        for c in LETTERS.chars() {
+            out.push(join(&[&chars[..i], &[c], &chars[i..]]));
+        }
+    }
+    out
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: &str = include_str!("../../../tests/fixtures/spell_words_en.txt");
+
+    fn checker() -> SpellChecker {
+        SpellChecker::from_words(WORDS.lines())
+    }
+
+    fn flagged<'a>(checker: &SpellChecker, text: &'a str) -> Vec<&'a str> {
+        checker
+            .misspellings(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect()
+    }
+
+    #[test]
+    fn flags_unknown_words() {
+        let checker = checker();
+        assert_eq!(
+            flagged(&checker, "We recieve the message, then edit it."),
+            vec!["recieve"]
+        );
+    }
+
+    #[test]
+    fn stems_are_known() {
+        let checker = checker();
+        for word in [
+            "files", "cities", "making", "running", "opened", "editor's", "don't",
+        ] {
+            assert!(checker.is_known(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn code_like_tokens_are_skipped() {
+        let checker = checker();
+        let text =
+            "call frobnicate_all() and xyzzyQux or HTTPX at src/qwzx.rs, foo.qwzx qwzx( v2qwzx";
+        assert!(flagged(&checker, text).is_empty());
+    }
+
+    #[test]
+    fn suggestions_are_close_known_words() {
+        let checker = checker();
+        let suggestions = checker.suggestions("recieve", 5);
+        assert_eq!(suggestions.first().map(String::as_str), Some("receive"));
+        assert_eq!(
+            checker.suggestions("Teh", 3).first().map(String::as_str),
+            Some("The")
+        );
+    }
+
+    #[test]
+    fn user_words_are_accepted() {
+        let checker = checker();
+        assert!(!checker.is_known("qwzx"));
+        checker.add_user_word("Qwzx");
+        assert!(checker.is_known("qwzx"));
+    }
+
+    #[test]
+    fn hunspell_flags_and_counts_are_dropped() {
+        let words: Vec<String> = parse_word_list("3\nhello/MS\nworld\n").collect();
+        assert_eq!(words, vec!["hello", "world"]);
+    }
+}
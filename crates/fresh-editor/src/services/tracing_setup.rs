@@ -1,11 +1,13 @@
 //! Tracing subscriber setup
 //!
 //! This module provides shared tracing configuration used by both
-//! the main application and tests.
+//! the main application and tests, plus the rotating per-session log file
+//! used by session servers (`fresh -a`).
 
-use std::fs::File;
-use std::path::Path;
-use std::sync::Arc;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::{fmt, EnvFilter};
 
@@ -72,6 +74,134 @@ pub fn build_subscriber(
         .with(status_layer)
 }
 
+/// Settings for a session server's log file.
+#[derive(Debug, Clone)]
+pub struct SessionLogConfig {
+    /// Log file path, usually `log_dirs::session_log_path(session_id)`
+    pub path: PathBuf,
+    /// Default level filter (e.g. `"info"`); `RUST_LOG` overrides it
+    pub level: String,
+    /// Rotate once the file would grow past this many bytes
+    pub max_bytes: u64,
+    /// Number of rotated files to keep (`<path>.1` is the newest)
+    pub max_files: usize,
+}
+
+impl SessionLogConfig {
+    /// Defaults: `info` level, rotated at 10 MiB, three old files kept.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            level: "info".to_string(),
+            max_bytes: 10 * 1024 * 1024,
+            max_files: 3,
+        }
+    }
+}
+
+/// Append-only log file that rotates when it reaches a size limit.
+///
+/// Rotation renames `<path>` to `<path>.1`, shifting older copies up to
+/// `<path>.<max_files>` and dropping the oldest one.
+pub struct RotatingLogFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    len: u64,
+}
+
+impl RotatingLogFile {
+    /// Open (or create) the log file at `config.path` for appending.
+    pub fn open(config: &SessionLogConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = Self::open_append(&config.path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            path: config.path.clone(),
+            max_bytes: config.max_bytes,
+            max_files: config.max_files,
+            file,
+            len,
+        })
+    }
+
+    fn open_append(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    std::fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, self.rotated_path(1))?;
+            self.file = Self::open_append(&self.path)?;
+        }
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Never rotate an empty file, so a single oversized line still lands
+        if self.len > 0 && self.len + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Build the subscriber for a session server: plain-text lines appended to
+/// the rotating session log, filtered by `RUST_LOG` or `config.level`.
+pub fn build_session_subscriber(
+    config: &SessionLogConfig,
+) -> io::Result<impl tracing::Subscriber + Send + Sync> {
+    let writer = RotatingLogFile::open(config)?;
+    let env_filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&config.level))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?
+        // Suppress noisy SWC debug logs
+        .add_directive("swc_ecma_transforms_base=info".parse().unwrap())
+        .add_directive("swc_common=info".parse().unwrap());
+
+    let fmt_layer = fmt::layer()
+        .with_writer(Mutex::new(writer))
+        .with_ansi(false);
+
+    Ok(tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(env_filter))
+}
+
+/// Install the session log as the global tracing subscriber.
+pub fn init_session(config: &SessionLogConfig) -> io::Result<()> {
+    build_session_subscriber(config)?
+        .try_init()
+        .map_err(|e| io::Error::other(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +357,46 @@ mod tests {
             "Log should contain second warning"
         );
     }
+
+    #[test]
+    fn test_session_log_writes_info_lines() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = SessionLogConfig::new(temp_dir.path().join("sessions").join("demo.log"));
+        let subscriber = build_session_subscriber(&config).unwrap();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("Editor server starting for session demo");
+            tracing::debug!("filtered out at info level");
+        });
+
+        let contents = std::fs::read_to_string(&config.path).unwrap();
+        assert!(contents.contains("Editor server starting for session demo"));
+        assert!(!contents.contains("filtered out"));
+    }
+
+    #[test]
+    fn test_rotating_log_file_rotates_by_size() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = SessionLogConfig {
+            max_bytes: 16,
+            max_files: 2,
+            ..SessionLogConfig::new(temp_dir.path().join("demo.log"))
+        };
+        let mut file = RotatingLogFile::open(&config).unwrap();
+        for line in [
+            "first line 0001\n",
+            "second line 002\n",
+            "third line 0003\n",
+        ] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        let read = |suffix: &str| {
+            std::fs::read_to_string(temp_dir.path().join(format!("demo.log{}", suffix))).unwrap()
+        };
+        assert_eq!(read(""), "third line 0003\n");
+        assert_eq!(read(".1"), "second line 002\n");
+        assert_eq!(read(".2"), "first line 0001\n");
+    }
 }
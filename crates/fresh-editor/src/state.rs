@@ -15,6 +15,7 @@ use crate::primitives::highlight_engine::HighlightEngine;
 use crate::primitives::indent::IndentCalculator;
 use crate::primitives::reference_highlighter::ReferenceHighlighter;
 use crate::primitives::text_property::TextPropertyManager;
+use crate::services::editorconfig::EditorConfigSettings;
 use crate::view::bracket_highlight_overlay::BracketHighlightOverlay;
 use crate::view::conceal::ConcealManager;
 use crate::view::folding::LspFoldRanges;
@@ -25,6 +26,8 @@ use crate::view::popup::{
 };
 use crate::view::reference_highlight_overlay::ReferenceHighlightOverlay;
 use crate::view::soft_break::SoftBreakManager;
+use crate::view::spell_check_overlay::SpellCheckOverlay;
+use crate::view::url_overlay::UrlOverlay;
 use crate::view::virtual_text::VirtualTextManager;
 use anyhow::Result;
 use ratatui::style::{Color, Style};
@@ -114,6 +117,29 @@ pub struct BufferSettings {
     /// Extra characters (beyond alphanumeric + `_`) considered part of
     /// identifiers for this language. Used by completion providers.
     pub word_characters: String,
+
+    /// Whether word motions and word deletes also stop at camelCase and
+    /// snake_case boundaries. Set from the global editor config.
+    pub subword_movement: bool,
+
+    /// Whether indent guides are drawn in leading whitespace. Set from the
+    /// global editor config.
+    pub indent_guides: bool,
+
+    /// Whether misspelled words are underlined. Set from the global editor
+    /// config; can be toggled per-buffer by user
+    pub spell_check: bool,
+
+    /// Whether URLs are underlined. Set from the global editor config.
+    pub underline_urls: bool,
+
+    /// Per-buffer override of `trim_trailing_whitespace_on_save`, e.g. from
+    /// `.editorconfig`. `None` uses the editor config.
+    pub trim_trailing_whitespace: Option<bool>,
+
+    /// Per-buffer override of `ensure_final_newline_on_save`, e.g. from
+    /// `.editorconfig`. `None` uses the editor config.
+    pub insert_final_newline: Option<bool>,
 }
 
 impl Default for BufferSettings {
@@ -125,6 +151,12 @@ impl Default for BufferSettings {
             auto_close: true,
             auto_surround: true,
             word_characters: String::new(),
+            subword_movement: false,
+            indent_guides: false,
+            spell_check: false,
+            underline_urls: false,
+            trim_trailing_whitespace: None,
+            insert_final_newline: None,
         }
     }
 }
@@ -209,6 +241,16 @@ pub struct EditorState {
     /// Bracket matching highlight overlay
     pub bracket_highlight_overlay: BracketHighlightOverlay,
 
+    /// Misspelled-word underline overlay
+    pub spell_check_overlay: SpellCheckOverlay,
+
+    /// URL underline overlay
+    pub url_overlay: UrlOverlay,
+
+    /// Properties from the `.editorconfig` files that apply to this
+    /// buffer's file, resolved when it was opened
+    pub editorconfig: EditorConfigSettings,
+
     /// Cached LSP semantic tokens (converted to buffer byte ranges)
     pub semantic_tokens: Option<SemanticTokenStore>,
 
@@ -293,6 +335,9 @@ impl EditorState {
             debug_highlight_mode: false,
             reference_highlight_overlay: ReferenceHighlightOverlay::new(),
             bracket_highlight_overlay: BracketHighlightOverlay::new(),
+            spell_check_overlay: SpellCheckOverlay::new(),
+            url_overlay: UrlOverlay::new(),
+            editorconfig: EditorConfigSettings::default(),
             semantic_tokens: None,
             folding_ranges: LspFoldRanges::new(),
             language: "text".to_string(),
@@ -113,7 +113,7 @@ pub enum LspFeature {
     DocumentSymbols,
     /// Workspace symbols (merged: combined from all servers)
     WorkspaceSymbols,
-    /// Hover information (exclusive: first eligible server wins)
+    /// Hover information (merged: answers stacked in one popup)
     Hover,
     /// Go to definition, declaration, type definition, implementation (exclusive)
     Definition,
@@ -147,6 +147,7 @@ impl LspFeature {
                 | LspFeature::CodeAction
                 | LspFeature::DocumentSymbols
                 | LspFeature::WorkspaceSymbols
+                | LspFeature::Hover
         )
     }
 }
@@ -417,8 +418,8 @@ mod tests {
         assert!(LspFeature::CodeAction.is_merged());
         assert!(LspFeature::DocumentSymbols.is_merged());
         assert!(LspFeature::WorkspaceSymbols.is_merged());
+        assert!(LspFeature::Hover.is_merged());
 
-        assert!(!LspFeature::Hover.is_merged());
         assert!(!LspFeature::Definition.is_merged());
         assert!(!LspFeature::References.is_merged());
         assert!(!LspFeature::Format.is_merged());
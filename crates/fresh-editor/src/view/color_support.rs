@@ -30,8 +30,15 @@ impl ColorCapability {
     /// Detect the terminal's color capability
     /// Can be overridden with FRESH_COLOR_MODE env var: "truecolor", "256", or "16"
     pub fn detect() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+
+    /// Detect the color capability from terminal environment variables
+    /// (`FRESH_COLOR_MODE`, `TERM`, `COLORTERM`, `WT_SESSION`) looked up through
+    /// `var`. Used with a client's forwarded environment in session mode.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
         // Check for manual override first
-        if let Ok(mode) = std::env::var("FRESH_COLOR_MODE") {
+        if let Some(mode) = var("FRESH_COLOR_MODE") {
             match mode.to_lowercase().as_str() {
                 "truecolor" | "24bit" | "true" => return ColorCapability::TrueColor,
                 "256" | "256color" => return ColorCapability::Color256,
@@ -42,7 +49,7 @@ impl ColorCapability {
 
         // Check TERM first for multiplexers that don't support truecolor
         // (they may pass through COLORTERM from the outer terminal)
-        if let Ok(term) = std::env::var("TERM") {
+        if let Some(term) = var("TERM") {
             let t = term.to_lowercase();
 
             // GNU Screen doesn't support truecolor - cap at 256
@@ -56,7 +63,7 @@ impl ColorCapability {
                     return ColorCapability::TrueColor;
                 }
                 // Check COLORTERM - tmux can pass through truecolor if configured
-                if let Ok(colorterm) = std::env::var("COLORTERM") {
+                if let Some(colorterm) = var("COLORTERM") {
                     let ct = colorterm.to_lowercase();
                     if ct == "truecolor" || ct == "24bit" {
                         return ColorCapability::TrueColor;
@@ -67,7 +74,7 @@ impl ColorCapability {
         }
 
         // Check COLORTERM - reliable for truecolor (but not inside Screen/tmux)
-        if let Ok(colorterm) = std::env::var("COLORTERM") {
+        if let Some(colorterm) = var("COLORTERM") {
             let ct = colorterm.to_lowercase();
             if ct == "truecolor" || ct == "24bit" {
                 return ColorCapability::TrueColor;
@@ -75,12 +82,12 @@ impl ColorCapability {
         }
 
         // Windows Terminal sets WT_SESSION and supports truecolor
-        if std::env::var("WT_SESSION").is_ok() {
+        if var("WT_SESSION").is_some() {
             return ColorCapability::TrueColor;
         }
 
         // Check TERM for other indicators
-        if let Ok(term) = std::env::var("TERM") {
+        if let Some(term) = var("TERM") {
             let t = term.to_lowercase();
 
             // Check for truecolor indicators
@@ -119,6 +126,23 @@ impl ColorCapability {
         // Default to 256 as safe middle ground
         ColorCapability::Color256
     }
+
+    /// The less capable of two color capabilities, for output shared by
+    /// several terminals
+    pub fn least_capable(self, other: Self) -> Self {
+        fn depth(capability: ColorCapability) -> u8 {
+            match capability {
+                ColorCapability::TrueColor => 2,
+                ColorCapability::Color256 => 1,
+                ColorCapability::Color16 => 0,
+            }
+        }
+        if depth(other) < depth(self) {
+            other
+        } else {
+            self
+        }
+    }
 }
 
 /// Convert an RGB color to the nearest 256-color palette index
@@ -632,6 +656,43 @@ mod tests {
         assert_eq!(rgb_to_16(255, 255, 255), Color::White);
     }
 
+    #[test]
+    fn test_from_env() {
+        fn detect(vars: &[(&str, &str)]) -> ColorCapability {
+            ColorCapability::from_env(|key| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| v.to_string())
+            })
+        }
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+            ColorCapability::TrueColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color")]),
+            ColorCapability::Color256
+        );
+        assert_eq!(detect(&[("TERM", "linux")]), ColorCapability::Color16);
+        assert_eq!(
+            detect(&[("TERM", "screen"), ("COLORTERM", "truecolor")]),
+            ColorCapability::Color256
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("FRESH_COLOR_MODE", "16")]),
+            ColorCapability::Color16
+        );
+        assert_eq!(detect(&[]), ColorCapability::Color256);
+    }
+
+    #[test]
+    fn test_least_capable() {
+        use ColorCapability::*;
+        assert_eq!(TrueColor.least_capable(Color16), Color16);
+        assert_eq!(Color16.least_capable(Color256), Color16);
+        assert_eq!(Color256.least_capable(TrueColor), Color256);
+    }
+
     #[test]
     fn test_convert_color_truecolor() {
         let color = Color::Rgb(100, 150, 200);
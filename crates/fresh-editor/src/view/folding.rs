@@ -670,3 +670,213 @@ pub mod indent_folding {
         }
     }
 }
+
+/// Folding ranges computed locally, for buffers whose language server does
+/// not provide any (or that have no language server at all).
+///
+/// Brace languages fold on `{ }` / `[ ]` pairs, everything else on
+/// indentation. The result uses the same line-based shape as LSP ranges so
+/// it can be stored in [`LspFoldRanges`] and handled by the same code.
+pub mod local_folding {
+    use lsp_types::FoldingRange;
+
+    /// How folds are found for a language.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LocalFoldStrategy {
+        /// A block is a line followed by more-indented lines (Python, YAML, …).
+        Indent,
+        /// A block runs from an opening bracket to its matching close.
+        Brackets,
+    }
+
+    /// Languages whose blocks are delimited by braces.
+    const BRACKET_LANGUAGES: &[&str] = &[
+        "c",
+        "cpp",
+        "csharp",
+        "css",
+        "dart",
+        "go",
+        "java",
+        "javascript",
+        "json",
+        "jsonc",
+        "kotlin",
+        "less",
+        "php",
+        "rust",
+        "scala",
+        "scss",
+        "swift",
+        "typescript",
+        "zig",
+    ];
+
+    /// Pick the strategy for a language ID.
+    pub fn strategy_for_language(language: &str) -> LocalFoldStrategy {
+        if BRACKET_LANGUAGES.contains(&language) {
+            LocalFoldStrategy::Brackets
+        } else {
+            LocalFoldStrategy::Indent
+        }
+    }
+
+    /// Compute folding ranges for `text` with the given strategy.
+    pub fn folding_ranges(
+        text: &[u8],
+        strategy: LocalFoldStrategy,
+        tab_size: usize,
+    ) -> Vec<FoldingRange> {
+        match strategy {
+            LocalFoldStrategy::Indent => indent_ranges(text, tab_size),
+            LocalFoldStrategy::Brackets => bracket_ranges(text),
+        }
+    }
+
+    fn range(start_line: usize, end_line: usize) -> FoldingRange {
+        FoldingRange {
+            start_line: start_line as u32,
+            end_line: end_line as u32,
+            start_character: None,
+            end_character: None,
+            kind: None,
+            collapsed_text: None,
+        }
+    }
+
+    /// Leading indent width, or `None` for a blank line.
+    fn line_indent(line: &[u8], tab_size: usize) -> Option<usize> {
+        let mut indent = 0;
+        for &b in line {
+            match b {
+                b' ' => indent += 1,
+                b'\t' => indent += tab_size.max(1) - (indent % tab_size.max(1)),
+                b'\r' => {}
+                _ => return Some(indent),
+            }
+        }
+        None
+    }
+
+    /// A range for every non-blank line followed by more-indented lines,
+    /// ending at the last non-blank line of the block.
+    fn indent_ranges(text: &[u8], tab_size: usize) -> Vec<FoldingRange> {
+        let mut ranges = Vec::new();
+        // Open block headers as (indent, line), innermost last.
+        let mut open: Vec<(usize, usize)> = Vec::new();
+        let mut last_non_blank = 0;
+
+        for (line, content) in text.split(|&b| b == b'\n').enumerate() {
+            let Some(indent) = line_indent(content, tab_size) else {
+                continue;
+            };
+            while let Some(&(header_indent, header_line)) = open.last() {
+                if header_indent < indent {
+                    break;
+                }
+                open.pop();
+                if last_non_blank > header_line {
+                    ranges.push(range(header_line, last_non_blank));
+                }
+            }
+            open.push((indent, line));
+            last_non_blank = line;
+        }
+        for (_, header_line) in open.into_iter().rev() {
+            if last_non_blank > header_line {
+                ranges.push(range(header_line, last_non_blank));
+            }
+        }
+
+        ranges.sort_by_key(|r| r.start_line);
+        ranges
+    }
+
+    /// A range for every `{ }` / `[ ]` pair spanning more than two lines,
+    /// hiding the lines between them so the closing line stays visible.
+    /// Brackets inside string literals and comments are ignored.
+    fn bracket_ranges(text: &[u8]) -> Vec<FoldingRange> {
+        let mut ranges = Vec::new();
+        let mut open: Vec<usize> = Vec::new();
+        let mut line = 0;
+        let mut i = 0;
+
+        while i < text.len() {
+            match text[i] {
+                b'\n' => line += 1,
+                b'"' => {
+                    i += 1;
+                    while i < text.len() && text[i] != b'"' {
+                        match text[i] {
+                            b'\\' => i += 1,
+                            b'\n' => line += 1,
+                            _ => {}
+                        }
+                        i += 1;
+                    }
+                }
+                b'/' if text.get(i + 1) == Some(&b'/') => {
+                    while i + 1 < text.len() && text[i + 1] != b'\n' {
+                        i += 1;
+                    }
+                }
+                b'/' if text.get(i + 1) == Some(&b'*') => {
+                    i += 2;
+                    while i < text.len() && !(text[i] == b'*' && text.get(i + 1) == Some(&b'/')) {
+                        if text[i] == b'\n' {
+                            line += 1;
+                        }
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                b'{' | b'[' => open.push(line),
+                b'}' | b']' => {
+                    if let Some(start_line) = open.pop() {
+                        if line > start_line + 1 {
+                            ranges.push(range(start_line, line - 1));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        ranges.sort_by_key(|r| r.start_line);
+        ranges
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn lines(ranges: &[FoldingRange]) -> Vec<(u32, u32)> {
+            ranges.iter().map(|r| (r.start_line, r.end_line)).collect()
+        }
+
+        #[test]
+        fn test_indent_ranges_nested_blocks() {
+            let text = b"def foo():\n    if x:\n        a()\n\n    b()\nc()\n";
+            assert_eq!(lines(&indent_ranges(text, 4)), vec![(0, 4), (1, 2)]);
+        }
+
+        #[test]
+        fn test_indent_ranges_block_at_end_of_file() {
+            let text = b"class A:\n    x = 1\n    y = 2";
+            assert_eq!(lines(&indent_ranges(text, 4)), vec![(0, 2)]);
+        }
+
+        #[test]
+        fn test_bracket_ranges_keep_closing_line_visible() {
+            let text = b"fn main() {\n    let v = [\n        1,\n    ];\n}\n";
+            assert_eq!(lines(&bracket_ranges(text)), vec![(0, 3), (1, 2)]);
+        }
+
+        #[test]
+        fn test_bracket_ranges_ignore_strings_and_comments() {
+            let text = b"fn f() {\n    // {\n    let s = \"{\";\n    /* [ */\n}\n";
+            assert_eq!(lines(&bracket_ranges(text)), vec![(0, 3)]);
+        }
+    }
+}
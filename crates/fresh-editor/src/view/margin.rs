@@ -1,3 +1,4 @@
+use crate::config::GutterColumn;
 use crate::model::marker::{MarkerId, MarkerList};
 use ratatui::style::{Color, Style};
 use std::collections::BTreeMap;
@@ -9,6 +10,10 @@ use std::collections::BTreeMap;
 /// number column that feels cramped next to the indicator and separator.
 pub const MIN_LINE_NUMBER_DIGITS: usize = 2;
 
+/// Line indicators whose namespace starts with this are breakpoints, shown in
+/// the `breakpoint` gutter column instead of the `git_sign` one.
+pub const BREAKPOINT_NAMESPACE_PREFIX: &str = "breakpoint";
+
 /// Position of a margin in the editor
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MarginPosition {
@@ -29,6 +34,11 @@ pub struct LineIndicator {
     pub symbol: String,
     /// The color of the indicator
     pub color: Color,
+    /// Theme key (e.g. "diagnostic.info_fg") taking precedence over `color`,
+    /// resolved at render time so the indicator follows theme changes
+    pub theme_key: Option<String>,
+    /// Render the symbol bold
+    pub bold: bool,
     /// Priority for display when multiple indicators exist (higher wins)
     pub priority: i32,
     /// Marker ID anchoring this indicator to a byte position
@@ -42,6 +52,8 @@ impl LineIndicator {
         Self {
             symbol: symbol.into(),
             color,
+            theme_key: None,
+            bold: false,
             priority,
             marker_id: MarkerId(0), // Placeholder, set by MarginManager
         }
@@ -57,10 +69,24 @@ impl LineIndicator {
         Self {
             symbol: symbol.into(),
             color,
+            theme_key: None,
+            bold: false,
             priority,
             marker_id,
         }
     }
+
+    /// Resolve the indicator color from a theme key at render time
+    pub fn with_theme_key(mut self, theme_key: impl Into<String>) -> Self {
+        self.theme_key = Some(theme_key.into());
+        self
+    }
+
+    /// Render the symbol bold
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
 }
 
 /// Content type for a margin at a specific line
@@ -152,6 +178,10 @@ pub struct MarginConfig {
 
     /// Default separator style
     pub separator_style: Style,
+
+    /// Gutter columns in display order (left margin only). `None` is the
+    /// classic layout: one indicator cell followed by the line number.
+    pub columns: Option<Vec<GutterColumn>>,
 }
 
 impl MarginConfig {
@@ -165,6 +195,7 @@ impl MarginConfig {
             separator: " │ ".to_string(), // Separator with spaces: " │ " (space before for indicators, space after for readability)
             style: Style::default().fg(Color::DarkGray),
             separator_style: Style::default().fg(Color::DarkGray),
+            columns: None,
         }
     }
 
@@ -178,22 +209,63 @@ impl MarginConfig {
             separator: String::new(),
             style: Style::default(),
             separator_style: Style::default(),
+            columns: None,
         }
     }
 
     /// Calculate the total width including indicator column and separator
     /// Format: [indicator (1 char)][line_number (N chars)][separator (3 chars)]
+    /// With custom `columns`, each sign column takes 1 char and the line
+    /// number column N chars; an empty column list hides the margin.
     pub fn total_width(&self) -> usize {
-        if self.enabled {
-            // 1 char for indicator column + line number width + separator
-            1 + self.width
-                + if self.show_separator {
-                    self.separator.chars().count()
-                } else {
-                    0
-                }
-        } else {
-            0
+        if !self.enabled {
+            return 0;
+        }
+        let columns_width = match &self.columns {
+            None => 1 + self.width,
+            Some(columns) if columns.is_empty() => return 0,
+            Some(columns) => columns.iter().map(|c| self.column_width(*c)).sum(),
+        };
+        columns_width
+            + if self.show_separator {
+                self.separator.chars().count()
+            } else {
+                0
+            }
+    }
+
+    /// Width of one custom gutter column
+    fn column_width(&self, column: GutterColumn) -> usize {
+        match column {
+            GutterColumn::LineNumber => self.width,
+            _ => 1,
+        }
+    }
+
+    /// The custom column at `col` (counted from the margin's left edge), or
+    /// `None` in the classic layout and over the separator
+    pub fn column_at(&self, col: usize) -> Option<GutterColumn> {
+        if !self.enabled {
+            return None;
+        }
+        let mut start = 0;
+        for &column in self.columns.as_ref()? {
+            let end = start + self.column_width(column);
+            if col < end {
+                return Some(column);
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// Whether a click at `col` toggles a breakpoint: the `breakpoint`
+    /// column of a custom layout, or the line numbers of the classic one
+    /// (whose indicator cell toggles folds)
+    pub fn toggles_breakpoint_at(&self, col: usize) -> bool {
+        match self.columns {
+            Some(_) => self.column_at(col) == Some(GutterColumn::Breakpoint),
+            None => self.enabled && (1..1 + self.width).contains(&col),
         }
     }
 }
@@ -407,6 +479,20 @@ impl MarginManager {
         }
     }
 
+    /// Marker IDs and byte positions of the line indicators in `namespace`
+    pub fn line_indicator_positions(&self, namespace: &str) -> Vec<(MarkerId, usize)> {
+        self.line_indicators
+            .iter()
+            .filter(|(_, indicators)| indicators.contains_key(namespace))
+            .filter_map(|(&marker_id, _)| {
+                let marker_id = MarkerId(marker_id);
+                self.indicator_markers
+                    .get_position(marker_id)
+                    .map(|position| (marker_id, position))
+            })
+            .collect()
+    }
+
     /// Get the line indicator for a specific line number
     ///
     /// This looks up all indicators whose markers resolve to the given line.
@@ -451,6 +537,18 @@ impl MarginManager {
         viewport_start: usize,
         viewport_end: usize,
         get_line_fn: impl Fn(usize) -> usize,
+    ) -> BTreeMap<usize, LineIndicator> {
+        self.get_indicators_for_viewport_in(viewport_start, viewport_end, get_line_fn, |_| true)
+    }
+
+    /// Like [`Self::get_indicators_for_viewport`], but only considers
+    /// indicators whose namespace passes `include_namespace`.
+    pub fn get_indicators_for_viewport_in(
+        &self,
+        viewport_start: usize,
+        viewport_end: usize,
+        get_line_fn: impl Fn(usize) -> usize,
+        include_namespace: impl Fn(&str) -> bool,
     ) -> BTreeMap<usize, LineIndicator> {
         let mut by_line: BTreeMap<usize, LineIndicator> = BTreeMap::new();
 
@@ -464,7 +562,12 @@ impl MarginManager {
                 let line = get_line_fn(byte_pos);
 
                 // Get highest priority indicator for this marker
-                if let Some(indicator) = indicators.values().max_by_key(|ind| ind.priority) {
+                if let Some(indicator) = indicators
+                    .iter()
+                    .filter(|(namespace, _)| include_namespace(namespace))
+                    .map(|(_, indicator)| indicator)
+                    .max_by_key(|ind| ind.priority)
+                {
                     // Check if this is higher priority than existing indicator on this line
                     if let Some(existing) = by_line.get(&line) {
                         if indicator.priority > existing.priority {
@@ -653,6 +756,11 @@ impl MarginManager {
         }
     }
 
+    /// Set the left margin's column layout (`None` for the classic layout).
+    pub fn set_gutter_columns(&mut self, columns: Option<Vec<GutterColumn>>) {
+        self.left_config.columns = columns;
+    }
+
     /// Get the number of annotations in a position
     pub fn annotation_count(&self, position: MarginPosition) -> usize {
         match position {
@@ -702,6 +810,53 @@ mod tests {
         assert_eq!(config.total_width(), 0);
     }
 
+    #[test]
+    fn test_margin_config_total_width_with_columns() {
+        let mut config = MarginConfig::left_default();
+        config.width = 3;
+
+        config.columns = Some(vec![
+            GutterColumn::GitSign,
+            GutterColumn::LineNumber,
+            GutterColumn::Fold,
+        ]);
+        assert_eq!(config.total_width(), 8); // 1 (sign) + 3 (line num) + 1 (fold) + 3 (separator)
+
+        config.columns = Some(vec![GutterColumn::Fold]);
+        assert_eq!(config.total_width(), 4); // 1 (fold) + 3 (separator)
+
+        config.columns = Some(Vec::new());
+        assert_eq!(config.total_width(), 0);
+    }
+
+    #[test]
+    fn test_margin_config_column_at() {
+        let mut config = MarginConfig::left_default();
+        config.width = 2;
+        assert_eq!(config.column_at(0), None);
+
+        config.columns = Some(vec![GutterColumn::Breakpoint, GutterColumn::LineNumber]);
+        assert_eq!(config.column_at(0), Some(GutterColumn::Breakpoint));
+        assert_eq!(config.column_at(1), Some(GutterColumn::LineNumber));
+        assert_eq!(config.column_at(2), Some(GutterColumn::LineNumber));
+        assert_eq!(config.column_at(3), None); // separator
+    }
+
+    #[test]
+    fn test_margin_config_toggles_breakpoint_at() {
+        let mut config = MarginConfig::left_default();
+        config.width = 2;
+        // Classic layout: the indicator cell folds, the line numbers toggle
+        assert!(!config.toggles_breakpoint_at(0));
+        assert!(config.toggles_breakpoint_at(1));
+        assert!(config.toggles_breakpoint_at(2));
+        assert!(!config.toggles_breakpoint_at(3)); // separator
+
+        config.columns = Some(vec![GutterColumn::LineNumber, GutterColumn::Breakpoint]);
+        assert!(!config.toggles_breakpoint_at(0));
+        assert!(config.toggles_breakpoint_at(2));
+    }
+
     #[test]
     fn test_margin_annotation_helpers() {
         let line_num = MarginAnnotation::line_number(5);
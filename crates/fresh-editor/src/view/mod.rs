@@ -79,6 +79,12 @@ pub mod query_replace_input;
 #[cfg(feature = "runtime")]
 pub mod reference_highlight_overlay;
 #[cfg(feature = "runtime")]
+pub mod spell_check_overlay;
+#[cfg(feature = "runtime")]
 pub mod split;
 #[cfg(feature = "runtime")]
 pub mod stream;
+#[cfg(feature = "runtime")]
+pub mod toast;
+#[cfg(feature = "runtime")]
+pub mod url_overlay;
@@ -111,6 +111,13 @@ pub enum PopupResolver {
     /// Confirm dispatches the selected row's `data` through
     /// `handle_remote_indicator_action`.
     RemoteIndicator,
+    /// LSP signature help. Behaves like `None`, but stays open while call
+    /// arguments are typed and is replaced (not stacked) on re-trigger.
+    SignatureHelp,
+    /// Spelling suggestions for the misspelled word at `start..end`.
+    /// Confirm replaces the word with the selected row's `data`, or adds
+    /// the word to the user dictionary.
+    SpellSuggestion { start: usize, end: usize },
 }
 
 /// Content of a popup window
@@ -21,6 +21,8 @@ pub enum PromptType {
     SwitchProject,
     /// Save current buffer to a new file
     SaveFileAs,
+    /// Rename the current file on disk
+    RenameCurrentFile,
     /// Search for text in buffer
     Search,
     /// Search for text in buffer (for replace operation - will prompt for replacement after)
@@ -80,6 +82,8 @@ pub enum PromptType {
     RemoveRuler,
     /// Set tab size for current buffer
     SetTabSize,
+    /// Convert the buffer's indentation; prompts for the indent width
+    ConvertIndentation { to_spaces: bool },
     /// Set line ending format for current buffer
     SetLineEnding,
     /// Set text encoding format for current buffer
@@ -105,6 +109,16 @@ pub enum PromptType {
     ConfirmRevert,
     /// Confirm saving over a file that changed on disk
     ConfirmSaveConflict,
+    /// Confirm applying a previewed LSP workspace edit
+    ConfirmWorkspaceEdit,
+    /// Stage, skip or split the hunk shown in the hunk staging view
+    StageHunk,
+    /// Ask whether to reload, keep, or diff a modified buffer whose file
+    /// changed on disk
+    ConfirmFileChangedOnDisk {
+        buffer_id: crate::model::event::BufferId,
+        path: std::path::PathBuf,
+    },
     /// Confirm saving with sudo after permission denied
     ConfirmSudoSave {
         info: crate::model::buffer::SudoSaveRequired,
@@ -120,6 +134,8 @@ pub enum PromptType {
     },
     /// Confirm quitting with modified buffers
     ConfirmQuitWithModified,
+    /// Ask whether closing a session client detaches or quits
+    ConfirmSessionClose,
     /// File Explorer rename operation
     /// Stores the original path and name for the file/directory being renamed
     FileExplorerRename {
@@ -162,10 +178,16 @@ pub enum PromptType {
     ConfirmLargeFileEncoding { path: std::path::PathBuf },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Pick one of the recently opened files
+    OpenRecentFile,
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
     ShellCommand { replace: bool },
+    /// Pick a configured task to run
+    RunTask,
+    /// Pick one of several go-to-definition results
+    GotoDefinition { locations: Vec<lsp_types::Location> },
     /// Async prompt from plugin (for editor.prompt() API)
     /// The result is returned via callback resolution
     AsyncPrompt,
@@ -0,0 +1,112 @@
+//! Spell check squiggles using the overlay system
+//!
+//! Misspelled words in the viewport get a wavy underline. In prose
+//! languages (Markdown, plain text) everything except code spans and links
+//! is checked; in other languages only comments are.
+
+use crate::model::buffer::Buffer;
+use crate::model::marker::MarkerList;
+use crate::primitives::highlighter::{HighlightCategory, HighlightSpan};
+use crate::services::spell_check::spell_checker;
+use crate::view::overlay::{
+    Overlay, OverlayFace, OverlayManager, OverlayNamespace, UnderlineStyle,
+};
+use ratatui::style::Color;
+use rust_i18n::t;
+
+/// Namespace for spell check overlays
+pub fn spell_check_namespace() -> OverlayNamespace {
+    OverlayNamespace::from_string("spell-check".to_string())
+}
+
+/// Below diagnostics, so a real error on the same word wins
+const SPELL_CHECK_PRIORITY: i32 = 5;
+
+/// Manager for spell check overlays
+pub struct SpellCheckOverlay {
+    /// Buffer version, viewport and enabled flag of the last check
+    last_key: Option<(u64, usize, usize, bool)>,
+}
+
+impl SpellCheckOverlay {
+    /// Create a new spell check overlay manager
+    pub fn new() -> Self {
+        Self { last_key: None }
+    }
+
+    /// Re-check the viewport when the buffer or viewport changed
+    ///
+    /// Returns true if overlays were updated
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        buffer: &Buffer,
+        overlays: &mut OverlayManager,
+        marker_list: &mut MarkerList,
+        enabled: bool,
+        prose: bool,
+        highlight_spans: &[HighlightSpan],
+        viewport_start: usize,
+        viewport_end: usize,
+        color: Color,
+    ) -> bool {
+        let key = (buffer.version(), viewport_start, viewport_end, enabled);
+        if self.last_key == Some(key) || (!enabled && self.last_key.is_none()) {
+            return false;
+        }
+        self.last_key = Some(key);
+
+        let ns = spell_check_namespace();
+        overlays.clear_namespace(&ns, marker_list);
+        if !enabled || viewport_start >= viewport_end {
+            return true;
+        }
+        let Some(checker) = spell_checker() else {
+            return true;
+        };
+
+        let bytes = buffer.slice_bytes(viewport_start..viewport_end.min(buffer.len()));
+        let text = match std::str::from_utf8(&bytes) {
+            Ok(text) => text,
+            Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        };
+
+        let in_span = |range: &std::ops::Range<usize>, categories: &[HighlightCategory]| {
+            highlight_spans.iter().any(|span| {
+                span.category.is_some_and(|c| categories.contains(&c))
+                    && span.range.start < range.end
+                    && range.start < span.range.end
+            })
+        };
+
+        for word in checker.misspellings(text) {
+            let range = viewport_start + word.start..viewport_start + word.end;
+            let checked = if prose {
+                !in_span(
+                    &range,
+                    &[HighlightCategory::String, HighlightCategory::Function],
+                )
+            } else {
+                in_span(&range, &[HighlightCategory::Comment])
+            };
+            if !checked {
+                continue;
+            }
+            let face = OverlayFace::Underline {
+                color,
+                style: UnderlineStyle::Wavy,
+            };
+            let overlay = Overlay::with_namespace(marker_list, range, face, ns.clone())
+                .with_priority_value(SPELL_CHECK_PRIORITY)
+                .with_message(t!("spell_check.unknown_word").to_string());
+            overlays.add(overlay);
+        }
+        true
+    }
+}
+
+impl Default for SpellCheckOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -199,6 +199,19 @@ impl BufferViewState {
         self.viewport.ensure_visible(buffer, &cursor, &hidden);
     }
 
+    /// Scroll so the primary cursor's line is in the middle of the viewport,
+    /// skipping folded lines like [`Self::ensure_cursor_visible`].
+    pub fn center_cursor(&mut self, buffer: &mut Buffer, marker_list: &MarkerList) {
+        let hidden: Vec<(usize, usize)> = self
+            .folds
+            .resolved_ranges(buffer, marker_list)
+            .into_iter()
+            .map(|r| (r.start_byte, r.end_byte))
+            .collect();
+        let position = self.cursors.primary().position;
+        self.viewport.center_on(buffer, position, &hidden);
+    }
+
     /// Create a new buffer view state with defaults
     pub fn new(width: u16, height: u16) -> Self {
         Self {
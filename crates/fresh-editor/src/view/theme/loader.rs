@@ -40,8 +40,11 @@ pub(crate) fn expand_env_vars(input: &str) -> String {
     let mut i = 0;
     while i < bytes.len() {
         if bytes[i] != b'$' {
-            out.push(bytes[i] as char);
-            i += 1;
+            // Copy up to the next `$` as a whole slice, so multi-byte
+            // characters stay intact.
+            let next = input[i..].find('$').map_or(bytes.len(), |n| i + n);
+            out.push_str(&input[i..next]);
+            i = next;
             continue;
         }
         // `$` at end of string — keep literal.
@@ -249,6 +252,15 @@ impl ThemeRegistry {
         Some(info.key.clone())
     }
 
+    /// Source file of a theme loaded from the user themes directory.
+    ///
+    /// Only user themes carry their path (as a `file://` key); built-in and
+    /// package themes return `None`.
+    pub fn source_path(&self, key_or_name: &str) -> Option<PathBuf> {
+        let key = self.resolve_key(key_or_name)?;
+        key.strip_prefix("file://").map(PathBuf::from)
+    }
+
     /// List all available themes with metadata.
     pub fn list(&self) -> &[ThemeInfo] {
         &self.theme_list
@@ -637,6 +649,20 @@ mod tests {
         assert_eq!(dark.name, "dark");
     }
 
+    #[test]
+    fn test_source_path_only_for_user_themes() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let theme_path = temp_dir.path().join("dev.json");
+        std::fs::write(&theme_path, r#"{"name": "dev", "editor": {}, "ui": {}, "search": {}, "diagnostic": {}, "syntax": {}}"#)
+            .expect("Failed to write theme file");
+
+        let registry = ThemeLoader::new(temp_dir.path().to_path_buf()).load_all(&[]);
+
+        assert_eq!(registry.source_path("dev"), Some(theme_path));
+        assert_eq!(registry.source_path("dark"), None);
+        assert_eq!(registry.source_path("missing"), None);
+    }
+
     /// Test that custom themes in user themes directory are loaded and available.
     /// This is a regression test for the macOS bug where themes in ~/.config/fresh/themes/
     /// were not appearing in the "Select Theme" command because ThemeLoader was using
@@ -1041,6 +1067,11 @@ mod tests {
         assert_eq!(expand_env_vars("${FRESH_TEST_VAR_A}/x"), "/foo/bar/x");
         assert_eq!(expand_env_vars("$FRESH_TEST_VAR_A/x"), "/foo/bar/x");
         assert_eq!(expand_env_vars("a/${FRESH_TEST_VAR_B}/c"), "a/baz/c");
+        // Non-ASCII text around a reference is kept as is.
+        assert_eq!(
+            expand_env_vars("café/$FRESH_TEST_VAR_B/日本.txt"),
+            "café/baz/日本.txt"
+        );
         // Unknown vars remain literal.
         assert_eq!(
             expand_env_vars("${FRESH_NO_SUCH_VAR_XYZ}/x"),
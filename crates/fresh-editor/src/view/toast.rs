@@ -0,0 +1,217 @@
+//! Toast notifications
+//!
+//! Short-lived messages ("LSP server started", ...) stacked in the bottom-right
+//! corner of the editor area, separate from the status bar, which keeps its
+//! one-line message. Each toast disappears after a timeout that depends on
+//! its severity; at most [`MAX_VISIBLE`] are shown at once, and showing
+//! another pushes the oldest out early. Dismissed toasts go to a log that
+//! can be reviewed later.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
+
+use crate::view::theme::Theme;
+
+/// Toasts shown at the same time.
+pub const MAX_VISIBLE: usize = 3;
+
+/// Dismissed toasts kept in the log, oldest dropped first.
+const MAX_LOG: usize = 200;
+
+/// Widest a toast gets, borders included.
+const MAX_WIDTH: u16 = 60;
+
+/// How important a toast is; decides its color and how long it stays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    /// How long a toast of this severity is shown.
+    pub fn timeout(self) -> Duration {
+        match self {
+            Self::Info => Duration::from_secs(4),
+            Self::Warning => Duration::from_secs(6),
+            Self::Error => Duration::from_secs(10),
+        }
+    }
+
+    /// Short label used in the notification log.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            Self::Info => theme.diagnostic_info_fg,
+            Self::Warning => theme.diagnostic_warning_fg,
+            Self::Error => theme.diagnostic_error_fg,
+        }
+    }
+}
+
+/// A single notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub severity: ToastSeverity,
+    /// Wall-clock time it was shown, for the log
+    pub time: DateTime<Local>,
+    /// When it was shown, on the editor's time source
+    shown_at: Instant,
+}
+
+/// The visible toasts and the log of dismissed ones.
+#[derive(Debug, Default)]
+pub struct ToastManager {
+    /// Visible toasts, oldest first
+    visible: VecDeque<Toast>,
+    /// Dismissed toasts, oldest first
+    log: VecDeque<Toast>,
+}
+
+impl ToastManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a toast, dismissing the oldest if [`MAX_VISIBLE`] are already up.
+    pub fn push(&mut self, message: String, severity: ToastSeverity, now: Instant) {
+        self.visible.push_back(Toast {
+            message,
+            severity,
+            time: Local::now(),
+            shown_at: now,
+        });
+        while self.visible.len() > MAX_VISIBLE {
+            if let Some(toast) = self.visible.pop_front() {
+                self.log_dismissed(toast);
+            }
+        }
+    }
+
+    /// Dismiss toasts whose timeout has passed. Returns true if any were.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.visible.len();
+        let (expired, kept) = std::mem::take(&mut self.visible)
+            .into_iter()
+            .partition::<Vec<_>, _>(|toast| {
+                now.saturating_duration_since(toast.shown_at) >= toast.severity.timeout()
+            });
+        self.visible = kept.into();
+        for toast in expired {
+            self.log_dismissed(toast);
+        }
+        self.visible.len() != before
+    }
+
+    /// Dismiss all visible toasts. Returns true if there were any.
+    pub fn dismiss_all(&mut self) -> bool {
+        let any = !self.visible.is_empty();
+        while let Some(toast) = self.visible.pop_front() {
+            self.log_dismissed(toast);
+        }
+        any
+    }
+
+    /// Visible toasts, oldest first.
+    pub fn visible(&self) -> impl Iterator<Item = &Toast> {
+        self.visible.iter()
+    }
+
+    /// Dismissed toasts, oldest first.
+    pub fn log(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.log.iter()
+    }
+
+    fn log_dismissed(&mut self, toast: Toast) {
+        self.log.push_back(toast);
+        while self.log.len() > MAX_LOG {
+            self.log.pop_front();
+        }
+    }
+}
+
+/// Draw the visible toasts stacked upwards from the bottom-right of `area`,
+/// newest at the bottom.
+pub fn render_toasts(frame: &mut Frame, area: Rect, toasts: &ToastManager, theme: &Theme) {
+    let max_width = area.width.saturating_sub(2).min(MAX_WIDTH);
+    if max_width < 5 {
+        return;
+    }
+    let mut bottom = area.y + area.height;
+    for toast in toasts.visible().rev() {
+        if bottom < area.y + 3 {
+            break;
+        }
+        let width = (toast.message.width() as u16 + 4).min(max_width);
+        let rect = Rect {
+            x: area.x + area.width - 1 - width,
+            y: bottom - 3,
+            width,
+            height: 3,
+        };
+        bottom = rect.y;
+
+        let color = toast.severity.color(theme);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color).bg(theme.popup_bg))
+            .style(Style::default().bg(theme.popup_bg));
+        let text = Line::from(Span::styled(
+            format!(" {}", toast.message),
+            Style::default().fg(theme.popup_text_fg),
+        ));
+        frame.render_widget(Clear, rect);
+        frame.render_widget(Paragraph::new(text).block(block), rect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(toasts: &ToastManager) -> Vec<&str> {
+        toasts.visible().map(|t| t.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_push_caps_visible_toasts() {
+        let now = Instant::now();
+        let mut toasts = ToastManager::new();
+        for i in 0..MAX_VISIBLE + 1 {
+            toasts.push(format!("t{i}"), ToastSeverity::Info, now);
+        }
+        assert_eq!(messages(&toasts), vec!["t1", "t2", "t3"]);
+        assert_eq!(toasts.log().next().unwrap().message, "t0");
+    }
+
+    #[test]
+    fn test_expire_uses_severity_timeout() {
+        let now = Instant::now();
+        let mut toasts = ToastManager::new();
+        toasts.push("info".into(), ToastSeverity::Info, now);
+        toasts.push("error".into(), ToastSeverity::Error, now);
+
+        assert!(!toasts.expire(now + Duration::from_secs(1)));
+        assert!(toasts.expire(now + ToastSeverity::Info.timeout()));
+        assert_eq!(messages(&toasts), vec!["error"]);
+        assert!(toasts.expire(now + ToastSeverity::Error.timeout()));
+        assert_eq!(toasts.log().count(), 2);
+    }
+}
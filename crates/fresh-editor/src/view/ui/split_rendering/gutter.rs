@@ -8,11 +8,12 @@
 use super::folding::FoldIndicator;
 use super::layout::ComposeLayout;
 use super::spans::push_span_with_map;
+use crate::config::{GutterColumn, LineNumberMode};
 use crate::state::{EditorState, ViewMode};
 use crate::view::margin::{LineIndicator, MarginPosition};
 use crate::view::theme::Theme;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Span;
 use ratatui::widgets::Block;
 use ratatui::Frame;
@@ -33,10 +34,11 @@ pub(super) struct LeftMarginContext<'a> {
     pub estimated_lines: usize,
     pub diagnostic_lines: &'a HashSet<usize>,
     pub line_indicators: &'a BTreeMap<usize, LineIndicator>,
+    pub breakpoint_indicators: &'a BTreeMap<usize, LineIndicator>,
     pub fold_indicators: &'a BTreeMap<usize, FoldIndicator>,
     pub cursor_line_start_byte: usize,
     pub cursor_line_number: usize,
-    pub relative_line_numbers: bool,
+    pub line_number_mode: LineNumberMode,
     pub show_line_numbers: bool,
     pub byte_offset_mode: bool,
     pub highlight_current_line: bool,
@@ -62,54 +64,143 @@ pub(super) fn render_left_margin(
     } else {
         None
     };
+    let is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
+    let use_cursor_line_bg = is_cursor_line && ctx.highlight_current_line && ctx.is_active;
 
-    // For continuation lines, don't show any indicators
-    if ctx.is_continuation {
-        let mut style = Style::default();
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
+    match &ctx.state.margins.left_config.columns {
+        None => {
+            render_indicator_cell(ctx, indicator_bg, line_spans, line_view_map);
+            render_line_number(
+                ctx,
+                is_cursor_line,
+                use_cursor_line_bg,
+                line_spans,
+                line_view_map,
+            );
         }
-        push_span_with_map(line_spans, line_view_map, " ".to_string(), style, None);
-    } else if lookup_key.is_some_and(|k| ctx.diagnostic_lines.contains(&k)) {
-        // Diagnostic indicators have highest priority
-        let mut style = Style::default().fg(Color::Red);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
+        Some(columns) if columns.is_empty() => return,
+        Some(columns) => {
+            // Continuation rows of a wrapped line show no signs.
+            let key = lookup_key.filter(|_| !ctx.is_continuation);
+            for column in columns {
+                let sign = match column {
+                    GutterColumn::LineNumber => {
+                        render_line_number(
+                            ctx,
+                            is_cursor_line,
+                            use_cursor_line_bg,
+                            line_spans,
+                            line_view_map,
+                        );
+                        continue;
+                    }
+                    GutterColumn::DiagnosticSign => key
+                        .filter(|k| ctx.diagnostic_lines.contains(k))
+                        .map(|_| ("●".to_string(), Style::default().fg(Color::Red))),
+                    GutterColumn::Fold => key
+                        .and_then(|k| ctx.fold_indicators.get(&k))
+                        .map(|fold| fold_sign(ctx, fold)),
+                    GutterColumn::GitSign => key
+                        .and_then(|k| ctx.line_indicators.get(&k))
+                        .map(|indicator| indicator_sign(ctx, indicator)),
+                    GutterColumn::Breakpoint => key
+                        .and_then(|k| ctx.breakpoint_indicators.get(&k))
+                        .map(|indicator| indicator_sign(ctx, indicator)),
+                };
+                let (symbol, mut style) =
+                    sign.unwrap_or_else(|| (" ".to_string(), Style::default()));
+                if let Some(bg) = indicator_bg {
+                    style = style.bg(bg);
+                }
+                push_span_with_map(line_spans, line_view_map, symbol, style, None);
+            }
         }
-        push_span_with_map(line_spans, line_view_map, "●".to_string(), style, None);
-    } else if lookup_key.is_some_and(|k| {
-        ctx.fold_indicators.contains_key(&k) && !ctx.line_indicators.contains_key(&k)
-    }) {
-        let fold = ctx.fold_indicators.get(&lookup_key.unwrap()).unwrap();
-        let symbol = if fold.collapsed { "▸" } else { "▾" };
-        let mut style = Style::default().fg(ctx.theme.line_number_fg);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, symbol.to_string(), style, None);
-    } else if let Some(indicator) = lookup_key.and_then(|k| ctx.line_indicators.get(&k)) {
-        let mut style = Style::default().fg(indicator.color);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
+    }
+
+    if ctx.state.margins.left_config.show_separator {
+        let mut separator_style = Style::default().fg(ctx.theme.line_number_fg);
+        if use_cursor_line_bg {
+            separator_style = separator_style.bg(ctx.theme.current_line_bg);
         }
         push_span_with_map(
             line_spans,
             line_view_map,
-            indicator.symbol.clone(),
-            style,
+            ctx.state.margins.left_config.separator.clone(),
+            separator_style,
             None,
         );
-    } else {
-        let mut style = Style::default();
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, " ".to_string(), style, None);
     }
+}
 
-    let is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
-    let use_cursor_line_bg = is_cursor_line && ctx.highlight_current_line && ctx.is_active;
+/// Symbol and style of a fold marker.
+fn fold_sign(ctx: &LeftMarginContext, fold: &FoldIndicator) -> (String, Style) {
+    let symbol = if fold.collapsed { "▸" } else { "▾" };
+    (
+        symbol.to_string(),
+        Style::default().fg(ctx.theme.line_number_fg),
+    )
+}
+
+/// Symbol and style of a line indicator (git sign, breakpoint, ...).
+fn indicator_sign(ctx: &LeftMarginContext, indicator: &LineIndicator) -> (String, Style) {
+    let fg = indicator
+        .theme_key
+        .as_deref()
+        .and_then(|key| ctx.theme.resolve_theme_key(key))
+        .unwrap_or(indicator.color);
+    let mut style = Style::default().fg(fg);
+    if indicator.bold {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    (indicator.symbol.clone(), style)
+}
+
+/// The classic single indicator cell: the diagnostic sign, else the fold
+/// marker (unless a line indicator is present), else the highest-priority
+/// line indicator.
+fn render_indicator_cell(
+    ctx: &LeftMarginContext,
+    indicator_bg: Option<Color>,
+    line_spans: &mut Vec<Span<'static>>,
+    line_view_map: &mut Vec<Option<usize>>,
+) {
+    // For continuation lines, don't show any indicators
+    let lookup_key = ctx.line_start_byte.filter(|_| !ctx.is_continuation);
+    let line_indicator = lookup_key.and_then(|k| {
+        match (
+            ctx.line_indicators.get(&k),
+            ctx.breakpoint_indicators.get(&k),
+        ) {
+            (Some(a), Some(b)) => Some(if b.priority > a.priority { b } else { a }),
+            (a, b) => a.or(b),
+        }
+    });
+    // Diagnostic indicators have highest priority
+    let sign = if lookup_key.is_some_and(|k| ctx.diagnostic_lines.contains(&k)) {
+        Some(("●".to_string(), Style::default().fg(Color::Red)))
+    } else if let Some(fold) = lookup_key
+        .and_then(|k| ctx.fold_indicators.get(&k))
+        .filter(|_| line_indicator.is_none())
+    {
+        Some(fold_sign(ctx, fold))
+    } else {
+        line_indicator.map(|indicator| indicator_sign(ctx, indicator))
+    };
+    let (symbol, mut style) = sign.unwrap_or_else(|| (" ".to_string(), Style::default()));
+    if let Some(bg) = indicator_bg {
+        style = style.bg(bg);
+    }
+    push_span_with_map(line_spans, line_view_map, symbol, style, None);
+}
 
+/// The line number (or byte offset) column.
+fn render_line_number(
+    ctx: &LeftMarginContext,
+    is_cursor_line: bool,
+    use_cursor_line_bg: bool,
+    line_spans: &mut Vec<Span<'static>>,
+    line_view_map: &mut Vec<Option<usize>>,
+) {
     if ctx.is_continuation {
         let blank = " ".repeat(ctx.state.margins.left_config.width);
         let mut style = Style::default().fg(ctx.theme.line_number_fg);
@@ -132,11 +223,13 @@ pub(super) fn render_left_margin(
             margin_style = margin_style.bg(ctx.theme.current_line_bg);
         }
         push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
-    } else if ctx.relative_line_numbers {
-        let display_num = if is_cursor_line {
+    } else if ctx.line_number_mode != LineNumberMode::Absolute {
+        let display_num = if !is_cursor_line {
+            ctx.gutter_num.abs_diff(ctx.cursor_line_number)
+        } else if ctx.line_number_mode == LineNumberMode::Hybrid {
             ctx.gutter_num + 1
         } else {
-            ctx.gutter_num.abs_diff(ctx.cursor_line_number)
+            0
         };
         let rendered_text = format!(
             "{:>width$}",
@@ -169,20 +262,6 @@ pub(super) fn render_left_margin(
 
         push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
     }
-
-    if ctx.state.margins.left_config.show_separator {
-        let mut separator_style = Style::default().fg(ctx.theme.line_number_fg);
-        if use_cursor_line_bg {
-            separator_style = separator_style.bg(ctx.theme.current_line_bg);
-        }
-        push_span_with_map(
-            line_spans,
-            line_view_map,
-            ctx.state.margins.left_config.separator.clone(),
-            separator_style,
-            None,
-        );
-    }
 }
 
 /// Paper-on-desk compose-mode margins flanking the content area.
@@ -33,11 +33,15 @@ pub(super) struct ComposeLayout {
     pub right_pad: u16,
 }
 
-/// Rectangle partitioning for one split: tabs, content, vertical scrollbar,
-/// horizontal scrollbar.
+/// Width in columns of the minimap column, when enabled.
+pub(super) const MINIMAP_WIDTH: u16 = 10;
+
+/// Rectangle partitioning for one split: tabs, content, minimap, vertical
+/// scrollbar, horizontal scrollbar.
 pub(super) struct SplitLayout {
     pub tabs_rect: Rect,
     pub content_rect: Rect,
+    pub minimap_rect: Rect,
     pub scrollbar_rect: Rect,
     pub horizontal_scrollbar_rect: Rect,
 }
@@ -56,15 +60,25 @@ pub(super) struct ViewPreferences {
     pub highlight_current_line: bool,
 }
 
-/// Partition a split area into tabs / content / scrollbar rectangles.
+/// Partition a split area into tabs / content / minimap / scrollbar
+/// rectangles.
+///
+/// The minimap sits between the content and the vertical scrollbar. It is
+/// dropped for splits too narrow to leave the content a useful width.
 pub(super) fn split_layout(
     split_area: Rect,
     tab_bar_visible: bool,
     show_vertical_scrollbar: bool,
     show_horizontal_scrollbar: bool,
+    show_minimap: bool,
 ) -> SplitLayout {
     let tabs_height = if tab_bar_visible { 1u16 } else { 0u16 };
     let scrollbar_width = if show_vertical_scrollbar { 1u16 } else { 0u16 };
+    let minimap_width = if show_minimap && split_area.width >= MINIMAP_WIDTH * 4 {
+        MINIMAP_WIDTH
+    } else {
+        0u16
+    };
     let hscrollbar_height = if show_horizontal_scrollbar {
         1u16
     } else {
@@ -75,12 +89,21 @@ pub(super) fn split_layout(
     let content_rect = Rect::new(
         split_area.x,
         split_area.y + tabs_height,
-        split_area.width.saturating_sub(scrollbar_width),
+        split_area
+            .width
+            .saturating_sub(scrollbar_width)
+            .saturating_sub(minimap_width),
         split_area
             .height
             .saturating_sub(tabs_height)
             .saturating_sub(hscrollbar_height),
     );
+    let minimap_rect = Rect::new(
+        content_rect.x + content_rect.width,
+        content_rect.y,
+        minimap_width,
+        content_rect.height,
+    );
     let scrollbar_rect = Rect::new(
         split_area.x + split_area.width.saturating_sub(scrollbar_width),
         split_area.y + tabs_height,
@@ -100,6 +123,7 @@ pub(super) fn split_layout(
     SplitLayout {
         tabs_rect,
         content_rect,
+        minimap_rect,
         scrollbar_rect,
         horizontal_scrollbar_rect,
     }
@@ -0,0 +1,122 @@
+//! Minimap rendering: a downscaled overview of the whole buffer drawn in a
+//! narrow column next to the vertical scrollbar.
+//!
+//! Each minimap row stands for `lines_per_row` buffer lines (the first of
+//! which is sampled) and each cell for [`CHARS_PER_CELL`] display columns.
+//! Cells are coloured from the highlighter's cached spans, so only the
+//! region around the viewport picks up syntax colours; everything else
+//! falls back to the editor foreground.
+
+use crate::primitives::highlighter::highlight_color;
+use crate::state::EditorState;
+use crate::view::theme::Theme;
+use crate::view::viewport::Viewport;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::Frame;
+
+/// Number of display columns folded into one minimap cell.
+const CHARS_PER_CELL: usize = 4;
+
+/// Number of buffer lines represented by each minimap row.
+pub(crate) fn minimap_lines_per_row(total_lines: usize, height: usize) -> usize {
+    if height == 0 {
+        return 1;
+    }
+    total_lines.div_ceil(height).max(1)
+}
+
+/// Render the minimap for a split.
+///
+/// `total_lines` is the buffer's logical line count; pass 0 for large files,
+/// in which case only the background is painted.
+pub(super) fn render_minimap(
+    frame: &mut Frame,
+    state: &EditorState,
+    viewport: &Viewport,
+    minimap_rect: Rect,
+    theme: &Theme,
+    total_lines: usize,
+) {
+    let height = minimap_rect.height as usize;
+    let width = minimap_rect.width as usize;
+    if height == 0 || width == 0 {
+        return;
+    }
+
+    let buf = frame.buffer_mut();
+    let base_style = Style::default().bg(theme.editor_bg);
+    let visible_style = Style::default().bg(theme.current_line_bg);
+
+    if total_lines == 0 {
+        for row in 0..height {
+            for col in 0..width {
+                buf[(minimap_rect.x + col as u16, minimap_rect.y + row as u16)]
+                    .set_symbol(" ")
+                    .set_style(base_style);
+            }
+        }
+        return;
+    }
+
+    let lines_per_row = minimap_lines_per_row(total_lines, height);
+    let top_line = state.buffer.get_line_number(viewport.top_byte);
+    let bottom_line = top_line + viewport.height as usize;
+    let tab_size = state.buffer_settings.tab_size.max(1);
+
+    for row in 0..height {
+        let line = row * lines_per_row;
+        let row_end = line + lines_per_row;
+        let bg_style = if line < bottom_line && row_end > top_line {
+            visible_style
+        } else {
+            base_style
+        };
+
+        // (non-whitespace count, byte of first non-whitespace char) per cell
+        let mut cells: Vec<(usize, Option<usize>)> = vec![(0, None); width];
+        if line < total_lines {
+            if let (Some(start), Some(bytes)) = (
+                state.buffer.line_start_offset(line),
+                state.buffer.get_line(line),
+            ) {
+                let text = String::from_utf8_lossy(&bytes);
+                let mut column = 0usize;
+                for (offset, ch) in text.char_indices() {
+                    if ch == '\n' || ch == '\r' {
+                        break;
+                    }
+                    let cell = column / CHARS_PER_CELL;
+                    if cell >= width {
+                        break;
+                    }
+                    if ch == '\t' {
+                        column += tab_size - column % tab_size;
+                        continue;
+                    }
+                    if !ch.is_whitespace() {
+                        let entry = &mut cells[cell];
+                        entry.0 += 1;
+                        entry.1.get_or_insert(start + offset);
+                    }
+                    column += 1;
+                }
+            }
+        }
+
+        for (col, (count, first_byte)) in cells.into_iter().enumerate() {
+            let symbol = match count {
+                0 => " ",
+                1 | 2 => "▪",
+                _ => "■",
+            };
+            let fg = first_byte
+                .and_then(|byte| state.highlighter.category_at_position(byte))
+                .map(|category| highlight_color(category, theme))
+                .unwrap_or(theme.editor_fg);
+            buf[(minimap_rect.x + col as u16, minimap_rect.y + row as u16)]
+                .set_symbol(symbol)
+                .set_style(bg_style.fg(fg));
+        }
+    }
+}
@@ -3,8 +3,8 @@
 //! This module is organized into two tiers:
 //!
 //! - **Self-contained leaves** (`spans`, `style`, `char_style`, `base_tokens`,
-//!   `transforms`, `view_data`, `folding`, `scrollbar`, `layout`, `gutter`,
-//!   `post_pass`) — none of these depend on any shared render-time carrier.
+//!   `transforms`, `view_data`, `folding`, `scrollbar`, `minimap`, `layout`,
+//!   `gutter`, `post_pass`) — none of these depend on any shared render-time carrier.
 //! - **Orchestration** (`orchestration::*`) — the only files that share
 //!   `SelectionContext` / `DecorationContext`. Quarantined in a subdirectory
 //!   so the coupling is visible from `ls` alone.
@@ -17,6 +17,7 @@ mod char_style;
 mod folding;
 mod gutter;
 mod layout;
+pub(crate) mod minimap;
 mod orchestration;
 mod post_pass;
 mod scrollbar;
@@ -27,6 +28,7 @@ mod view_data;
 
 use crate::app::types::ViewLineMapping;
 use crate::app::BufferMetadata;
+use crate::config::LineNumberMode;
 use crate::model::buffer::Buffer;
 use crate::model::event::{BufferId, EventLog, LeafId, SplitDirection};
 use crate::primitives::ansi_background::AnsiBackground;
@@ -80,13 +82,14 @@ impl SplitRenderer {
         hovered_close_split: Option<LeafId>,
         hovered_maximize_split: Option<LeafId>,
         is_maximized: bool,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
         tab_bar_visible: bool,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
         show_vertical_scrollbar: bool,
         show_horizontal_scrollbar: bool,
+        show_minimap: bool,
         diagnostics_inline_text: bool,
         show_tilde: bool,
         highlight_current_column: bool,
@@ -107,6 +110,7 @@ impl SplitRenderer {
             u16,
             u16,
         )>,
+        Vec<(LeafId, BufferId, Rect)>,
     ) {
         orchestration::render_content(
             frame,
@@ -132,13 +136,14 @@ impl SplitRenderer {
             hovered_close_split,
             hovered_maximize_split,
             is_maximized,
-            relative_line_numbers,
+            line_number_mode,
             tab_bar_visible,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
             show_vertical_scrollbar,
             show_horizontal_scrollbar,
+            show_minimap,
             diagnostics_inline_text,
             show_tilde,
             highlight_current_column,
@@ -158,13 +163,14 @@ impl SplitRenderer {
         lsp_waiting: bool,
         estimated_line_length: usize,
         highlight_context_bytes: usize,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
         tab_bar_visible: bool,
         show_vertical_scrollbar: bool,
         show_horizontal_scrollbar: bool,
+        show_minimap: bool,
         diagnostics_inline_text: bool,
         show_tilde: bool,
     ) -> HashMap<LeafId, Vec<ViewLineMapping>> {
@@ -177,13 +183,14 @@ impl SplitRenderer {
             lsp_waiting,
             estimated_line_length,
             highlight_context_bytes,
-            relative_line_numbers,
+            line_number_mode,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
             tab_bar_visible,
             show_vertical_scrollbar,
             show_horizontal_scrollbar,
+            show_minimap,
             diagnostics_inline_text,
             show_tilde,
         )
@@ -217,7 +224,7 @@ impl SplitRenderer {
         estimated_line_length: usize,
         highlight_context_bytes: usize,
         buffer_id: BufferId,
-        relative_line_numbers: bool,
+        line_number_mode: LineNumberMode,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
@@ -260,7 +267,7 @@ impl SplitRenderer {
             highlight_context_bytes,
             buffer_id,
             /* hide_cursor */ true,
-            relative_line_numbers,
+            line_number_mode,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
@@ -415,7 +422,7 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: LineNumberMode::Absolute,
             session_mode: false,
             software_cursor_only: false,
             show_line_numbers: true, // Tests show line numbers
@@ -2201,7 +2208,7 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: LineNumberMode::Absolute,
             session_mode: false,
             software_cursor_only: false,
             show_line_numbers: false,
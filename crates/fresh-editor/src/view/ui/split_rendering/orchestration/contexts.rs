@@ -39,6 +39,8 @@ pub(crate) struct DecorationContext {
     pub diagnostic_inline_texts: HashMap<usize, (String, Style)>,
     /// Line indicators indexed by line-start byte offset.
     pub line_indicators: BTreeMap<usize, LineIndicator>,
+    /// Breakpoint indicators indexed by line-start byte offset.
+    pub breakpoint_indicators: BTreeMap<usize, LineIndicator>,
     /// Fold indicators indexed by line-start byte offset.
     pub fold_indicators: BTreeMap<usize, FoldIndicator>,
 }
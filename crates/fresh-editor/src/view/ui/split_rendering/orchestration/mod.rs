@@ -21,12 +21,14 @@ use super::layout::{
     render_separator, resolve_view_preferences, split_buffers_for_tabs, split_layout,
     sync_viewport_to_content, SplitLayout,
 };
+use super::minimap::render_minimap;
 use super::scrollbar::{
     compute_max_line_length, render_composite_scrollbar, render_horizontal_scrollbar,
     render_scrollbar, scrollbar_line_counts,
 };
 use crate::app::types::ViewLineMapping;
 use crate::app::BufferMetadata;
+use crate::config::LineNumberMode;
 use crate::model::buffer::Buffer;
 use crate::model::event::{BufferId, EventLog, LeafId, SplitDirection};
 use crate::primitives::ansi_background::AnsiBackground;
@@ -48,6 +50,7 @@ use std::collections::HashMap;
 
 /// # Returns
 /// * Vec of (split_id, buffer_id, content_rect, scrollbar_rect, thumb_start, thumb_end) for mouse handling
+/// * Vec of (split_id, buffer_id, minimap_rect) for minimap click handling
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub(crate) fn render_content(
@@ -77,13 +80,14 @@ pub(crate) fn render_content(
     hovered_close_split: Option<LeafId>,
     hovered_maximize_split: Option<LeafId>,
     is_maximized: bool,
-    relative_line_numbers: bool,
+    line_number_mode: LineNumberMode,
     tab_bar_visible: bool,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
     show_vertical_scrollbar: bool,
     show_horizontal_scrollbar: bool,
+    show_minimap: bool,
     diagnostics_inline_text: bool,
     show_tilde: bool,
     highlight_current_column: bool,
@@ -104,6 +108,7 @@ pub(crate) fn render_content(
         u16,
         u16,
     )>, // hit areas for separators inside active Grouped subtrees
+    Vec<(LeafId, BufferId, Rect)>,                      // minimap areas
 ) {
     let _span = tracing::trace_span!("render_content").entered();
 
@@ -154,6 +159,7 @@ pub(crate) fn render_content(
                     split_tab_bar_visible,
                     show_vertical_scrollbar,
                     show_horizontal_scrollbar,
+                    false,
                 );
                 let inner_leaves = grouped.get_leaves_with_rects(main_layout.content_rect);
                 visible_buffers.push((
@@ -199,6 +205,7 @@ pub(crate) fn render_content(
     let mut split_areas = Vec::new();
     let mut horizontal_scrollbar_areas: Vec<(LeafId, BufferId, Rect, usize, usize, usize)> =
         Vec::new();
+    let mut minimap_areas: Vec<(LeafId, BufferId, Rect)> = Vec::new();
     let mut tab_layouts: HashMap<LeafId, crate::view::ui::tabs::TabLayout> = HashMap::new();
     let mut close_split_areas = Vec::new();
     let mut maximize_split_areas = Vec::new();
@@ -230,6 +237,12 @@ pub(crate) fn render_content(
         // scrollbar — their content is pinned to the panel size.
         let is_non_scrollable = buffers.get(&buffer_id).is_some_and(|s| !s.scrollable);
         let panel_show_vscroll = show_vertical_scrollbar && !is_non_scrollable;
+        // The minimap only makes sense for scrollable text buffers.
+        let split_show_minimap = show_minimap
+            && !is_non_scrollable
+            && !buffers
+                .get(&buffer_id)
+                .is_some_and(|s| s.is_composite_buffer);
 
         let layout = if is_inner_group_leaf {
             // Inner leaf: split_area IS the content rect already.
@@ -243,6 +256,7 @@ pub(crate) fn render_content(
                         .saturating_sub(if panel_show_vscroll { 1 } else { 0 }),
                     split_area.height,
                 ),
+                minimap_rect: Rect::new(0, 0, 0, 0),
                 scrollbar_rect: Rect::new(
                     split_area.x + split_area.width.saturating_sub(1),
                     split_area.y,
@@ -257,6 +271,7 @@ pub(crate) fn render_content(
                 split_tab_bar_visible,
                 show_vertical_scrollbar && !is_non_scrollable,
                 show_horizontal_scrollbar && !is_non_scrollable,
+                split_show_minimap,
             )
         };
         let (split_buffers, tab_scroll_offset) = if is_inner_group_leaf {
@@ -590,7 +605,7 @@ pub(crate) fn render_content(
                 highlight_context_bytes,
                 buffer_id,
                 hide_cursor,
-                relative_line_numbers,
+                line_number_mode,
                 use_terminal_bg,
                 session_mode,
                 software_cursor_only,
@@ -635,6 +650,27 @@ pub(crate) fn render_content(
                 (0, 0)
             };
 
+            if layout.minimap_rect.width > 0 {
+                // Logical line count; 0 for large files, where the minimap
+                // stays blank rather than scanning the buffer.
+                let minimap_total_lines = if buffer_len > large_file_threshold_bytes as usize {
+                    0
+                } else if buffer_len > 0 {
+                    state.buffer.get_line_number(buffer_len - 1) + 1
+                } else {
+                    1
+                };
+                render_minimap(
+                    frame,
+                    state,
+                    &viewport,
+                    layout.minimap_rect,
+                    theme,
+                    minimap_total_lines,
+                );
+                minimap_areas.push((split_id, buffer_id, layout.minimap_rect));
+            }
+
             // Compute the actual max line length for horizontal scrollbar
             let max_content_width = if show_horizontal_scrollbar && !viewport.line_wrap_enabled {
                 let mcw = compute_max_line_length(state, &mut viewport);
@@ -736,6 +772,7 @@ pub(crate) fn render_content(
                     split_tab_bar_visible,
                     show_vertical_scrollbar,
                     show_horizontal_scrollbar,
+                    false,
                 );
                 if let crate::view::split::SplitNode::Grouped { layout, .. } = grouped {
                     for (id, direction, x, y, length) in
@@ -757,6 +794,7 @@ pub(crate) fn render_content(
         view_line_mappings,
         horizontal_scrollbar_areas,
         grouped_separator_areas,
+        minimap_areas,
     )
 }
 /// Layout-only path: computes view_line_mappings for all visible splits
@@ -772,13 +810,14 @@ pub(crate) fn compute_content_layout(
     lsp_waiting: bool,
     estimated_line_length: usize,
     highlight_context_bytes: usize,
-    relative_line_numbers: bool,
+    line_number_mode: LineNumberMode,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
     tab_bar_visible: bool,
     show_vertical_scrollbar: bool,
     show_horizontal_scrollbar: bool,
+    show_minimap: bool,
     diagnostics_inline_text: bool,
     show_tilde: bool,
 ) -> HashMap<LeafId, Vec<ViewLineMapping>> {
@@ -795,11 +834,16 @@ pub(crate) fn compute_content_layout(
                 .get(&split_id)
                 .is_some_and(|vs| vs.suppress_chrome);
 
+        let split_show_minimap = show_minimap
+            && buffers
+                .get(&buffer_id)
+                .is_some_and(|s| s.scrollable && !s.is_composite_buffer);
         let layout = split_layout(
             split_area,
             split_tab_bar_visible,
             show_vertical_scrollbar,
             show_horizontal_scrollbar,
+            split_show_minimap,
         );
 
         let state = match buffers.get_mut(&buffer_id) {
@@ -881,7 +925,7 @@ pub(crate) fn compute_content_layout(
             view_prefs.view_transform,
             estimated_line_length,
             highlight_context_bytes,
-            relative_line_numbers,
+            line_number_mode,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
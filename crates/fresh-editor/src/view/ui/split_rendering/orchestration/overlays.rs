@@ -9,6 +9,7 @@ use super::contexts::{DecorationContext, SelectionContext};
 use crate::model::cursor::{Cursors, SelectionMode};
 use crate::state::{EditorState, ViewMode};
 use crate::view::folding::FoldManager;
+use crate::view::margin::BREAKPOINT_NAMESPACE_PREFIX;
 use crate::view::theme::Theme;
 use crate::view::ui::view_pipeline::ViewLine;
 use ratatui::style::Style;
@@ -136,6 +137,33 @@ pub(crate) fn decoration_context(
         primary_cursor_position,
     );
 
+    // Update spell check overlays (only re-checks when the buffer or the
+    // viewport changed).
+    let prose = crate::services::spell_check::PROSE_LANGUAGES.contains(&state.language.as_str());
+    state.spell_check_overlay.update(
+        &state.buffer,
+        &mut state.overlays,
+        &mut state.marker_list,
+        state.buffer_settings.spell_check,
+        prose,
+        &highlight_spans,
+        viewport_start,
+        viewport_end,
+        theme.diagnostic_info_fg,
+    );
+
+    // Update URL underline overlays (only re-scans when the buffer or the
+    // viewport changed).
+    state.url_overlay.update(
+        &state.buffer,
+        &mut state.overlays,
+        &mut state.marker_list,
+        state.buffer_settings.underline_urls,
+        viewport_start,
+        viewport_end,
+        theme.editor_fg,
+    );
+
     // Semantic tokens are stored as overlays so their ranges track edits.
     // Convert them into highlight spans for the render pipeline.
     let is_compose = matches!(view_mode, ViewMode::PageView);
@@ -228,13 +256,22 @@ pub(crate) fn decoration_context(
         .map(|(position, texts)| (position, texts.into_iter().cloned().collect()))
         .collect();
 
-    // Pre-compute line indicators for the viewport.
-    let mut line_indicators =
-        state
-            .margins
-            .get_indicators_for_viewport(viewport_start, viewport_end, |byte_offset| {
-                indent_folding::find_line_start_byte(&state.buffer, byte_offset)
-            });
+    // Pre-compute line indicators for the viewport. Breakpoints are kept
+    // apart so a custom gutter layout can give them their own column.
+    let is_breakpoint = |namespace: &str| namespace.starts_with(BREAKPOINT_NAMESPACE_PREFIX);
+    let line_start = |byte_offset| indent_folding::find_line_start_byte(&state.buffer, byte_offset);
+    let mut line_indicators = state.margins.get_indicators_for_viewport_in(
+        viewport_start,
+        viewport_end,
+        line_start,
+        |namespace| !is_breakpoint(namespace),
+    );
+    let breakpoint_indicators = state.margins.get_indicators_for_viewport_in(
+        viewport_start,
+        viewport_end,
+        line_start,
+        is_breakpoint,
+    );
 
     // Merge native diff-since-saved indicators (cornflower blue │ for unsaved edits).
     // These have priority 5, lower than git gutter (10).
@@ -254,6 +291,7 @@ pub(crate) fn decoration_context(
         diagnostic_lines,
         diagnostic_inline_texts,
         line_indicators,
+        breakpoint_indicators,
         fold_indicators,
     }
 }
@@ -12,16 +12,19 @@ use super::super::layout::{
     calculate_compose_layout, calculate_view_anchor, calculate_viewport_end, ComposeLayout,
 };
 use super::super::post_pass::{
-    apply_background_to_lines, render_column_guides, render_cursor_column_bg, render_ruler_bg,
+    apply_background_to_lines, render_column_guides, render_cursor_column_bg, render_indent_guides,
+    render_ruler_bg, IndentGuideRow,
 };
 use super::super::view_data::build_view_data;
 use super::contexts::SelectionContext;
 use super::overlays::{decoration_context, selection_context};
 use super::render_line::{render_view_lines, LastLineEnd, LineRenderInput, LineRenderOutput};
 use crate::app::types::{CellThemeInfo, ViewLineMapping};
+use crate::config::LineNumberMode;
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, EventLog};
 use crate::primitives::ansi_background::AnsiBackground;
+use crate::primitives::indent_pattern::PatternIndentCalculator;
 use crate::state::{EditorState, ViewMode};
 use crate::view::folding::FoldManager;
 use crate::view::theme::Theme;
@@ -84,6 +87,35 @@ pub(crate) fn resolve_cursor_fallback(
     last_line_end.map(|end| end.pos)
 }
 
+/// Largest number the gutter can show for the current viewport, so its width
+/// fits that number rather than the line count. Relative numbers only go up
+/// to the distance from the cursor line to the farthest visible line; hybrid
+/// mode also shows the cursor line's absolute number.
+fn widest_line_number(
+    state: &EditorState,
+    viewport: &Viewport,
+    line_number_mode: LineNumberMode,
+    total_lines: usize,
+) -> usize {
+    if line_number_mode == LineNumberMode::Absolute {
+        return total_lines;
+    }
+    let last_line = total_lines.saturating_sub(1);
+    let cursor_line = state.primary_cursor_line_number.value().min(last_line);
+    let top_line = state
+        .buffer
+        .get_line_number(viewport.top_byte)
+        .min(last_line);
+    let bottom_line = (top_line + viewport.visible_line_count()).min(last_line);
+    let farthest = cursor_line
+        .abs_diff(top_line)
+        .max(cursor_line.abs_diff(bottom_line));
+    match line_number_mode {
+        LineNumberMode::Hybrid => farthest.max(cursor_line + 1),
+        _ => farthest,
+    }
+}
+
 /// Pure layout computation for a buffer in a split pane.
 /// No frame/drawing involved — produces a `BufferLayoutOutput` that the
 /// drawing phase can consume.
@@ -102,7 +134,7 @@ pub(crate) fn compute_buffer_layout(
     view_transform: Option<ViewTransformPayload>,
     estimated_line_length: usize,
     highlight_context_bytes: usize,
-    relative_line_numbers: bool,
+    line_number_mode: LineNumberMode,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
@@ -140,7 +172,8 @@ pub(crate) fn compute_buffer_layout(
         // for the largest byte offset (file size)
         buffer_len.max(1)
     } else {
-        state.buffer.line_count().unwrap_or(1)
+        let total_lines = state.buffer.line_count().unwrap_or(1);
+        widest_line_number(state, viewport, line_number_mode, total_lines)
     };
     state
         .margins
@@ -346,7 +379,7 @@ pub(crate) fn compute_buffer_layout(
         line_wrap,
         estimated_lines,
         left_column: viewport.left_column,
-        relative_line_numbers,
+        line_number_mode,
         session_mode,
         software_cursor_only,
         show_line_numbers,
@@ -471,6 +504,24 @@ pub(crate) fn draw_buffer_in_split(
         );
     }
 
+    if state.buffer_settings.indent_guides && !state.buffer.is_large_file() {
+        let rows = indent_guide_rows(
+            state,
+            &layout_output.view_line_mappings,
+            cursors.primary().position,
+            layout_output.left_column,
+        );
+        render_indent_guides(
+            frame,
+            &rows,
+            state.buffer_settings.tab_size,
+            theme.whitespace_indicator_fg,
+            theme.line_number_fg,
+            render_area,
+            gutter_width,
+        );
+    }
+
     // Highlight the cursor column (same bg tint as the current line) when
     // `highlight_current_column` is enabled and the split is active.
     if highlight_current_column && is_active && !hide_cursor {
@@ -537,6 +588,65 @@ pub(crate) fn draw_buffer_in_split(
     }
 }
 
+/// Describe each rendered row for the indent guides. Only the first row of
+/// a source line gets an entry; wrapped continuations and virtual rows are
+/// `None`.
+fn indent_guide_rows(
+    state: &EditorState,
+    view_line_mappings: &[ViewLineMapping],
+    cursor_position: usize,
+    left_column: usize,
+) -> Vec<Option<IndentGuideRow>> {
+    use crate::view::folding::indent_folding::find_line_start_byte;
+
+    let buffer = &state.buffer;
+    let tab_size = state.buffer_settings.tab_size.max(1);
+    let cursor_line_start = find_line_start_byte(buffer, cursor_position);
+    let mut prev_line_start = None;
+
+    view_line_mappings
+        .iter()
+        .map(|mapping| {
+            let first_byte = mapping.char_source_bytes.iter().find_map(|b| *b)?;
+            let line_start = find_line_start_byte(buffer, first_byte);
+            if prev_line_start.replace(line_start) == Some(line_start) {
+                return None;
+            }
+
+            let mut indent = 0;
+            let mut blank = true;
+            let mut pos = line_start;
+            while let Some(byte) = PatternIndentCalculator::byte_at(buffer, pos) {
+                match byte {
+                    b' ' => indent += 1,
+                    b'\t' => indent += tab_size - indent % tab_size,
+                    b'\r' => {}
+                    b'\n' => break,
+                    _ => {
+                        blank = false;
+                        break;
+                    }
+                }
+                pos += 1;
+            }
+
+            let origin = match mapping
+                .char_source_bytes
+                .iter()
+                .position(|b| *b == Some(line_start))
+            {
+                Some(column) => column as isize,
+                None => -(left_column as isize),
+            };
+            Some(IndentGuideRow {
+                indent: (!blank).then_some(indent),
+                origin,
+                is_cursor_line: line_start == cursor_line_start,
+            })
+        })
+        .collect()
+}
+
 /// Render a single buffer in a split pane (convenience wrapper).
 /// Calls [`compute_buffer_layout`] then [`draw_buffer_in_split`].
 /// Returns the view line mappings for mouse click handling.
@@ -562,7 +672,7 @@ pub(crate) fn render_buffer_in_split(
     highlight_context_bytes: usize,
     _buffer_id: BufferId,
     hide_cursor: bool,
-    relative_line_numbers: bool,
+    line_number_mode: LineNumberMode,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
@@ -590,7 +700,7 @@ pub(crate) fn render_buffer_in_split(
         view_transform,
         estimated_line_length,
         highlight_context_bytes,
-        relative_line_numbers,
+        line_number_mode,
         use_terminal_bg,
         session_mode,
         software_cursor_only,
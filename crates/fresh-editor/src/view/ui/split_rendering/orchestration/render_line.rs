@@ -17,6 +17,7 @@ use super::super::spans::{
 use super::super::style::dim_color_for_tilde;
 use super::contexts::{DecorationContext, SelectionContext};
 use crate::app::types::ViewLineMapping;
+use crate::config::LineNumberMode;
 use crate::primitives::ansi::AnsiParser;
 use crate::primitives::display_width::char_width;
 use crate::state::EditorState;
@@ -61,7 +62,7 @@ pub(crate) struct LineRenderInput<'a> {
     /// Left column offset for horizontal scrolling
     pub left_column: usize,
     /// Whether to show relative line numbers (distance from cursor)
-    pub relative_line_numbers: bool,
+    pub line_number_mode: LineNumberMode,
     /// Skip REVERSED style on the primary cursor (session mode or non-block cursor style)
     pub session_mode: bool,
     /// No hardware cursor: always render software cursor indicators
@@ -98,7 +99,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
         line_wrap,
         estimated_lines,
         left_column,
-        relative_line_numbers,
+        line_number_mode,
         session_mode,
         software_cursor_only,
         show_line_numbers,
@@ -371,10 +372,11 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 estimated_lines,
                 diagnostic_lines,
                 line_indicators,
+                breakpoint_indicators: &decorations.breakpoint_indicators,
                 fold_indicators: &decorations.fold_indicators,
                 cursor_line_start_byte,
                 cursor_line_number: state.primary_cursor_line_number.value(),
-                relative_line_numbers,
+                line_number_mode,
                 show_line_numbers,
                 byte_offset_mode,
                 highlight_current_line,
@@ -713,6 +715,10 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     }
                 };
 
+                // End-of-line indicator (¶) occupies the newline's own cell, so it
+                // never shifts byte offsets or the columns of real characters.
+                let ws_show_eol = ch == '\n' && state.buffer_settings.whitespace.eol;
+
                 let display_char: &str = if is_cursor && lsp_waiting && is_active {
                     "⋯"
                 } else if debug_tracker.is_some() && ch == '\r' {
@@ -721,6 +727,9 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 } else if debug_tracker.is_some() && ch == '\n' {
                     // Debug mode: show LF explicitly
                     "\\n"
+                } else if ws_show_eol {
+                    is_whitespace_indicator = true;
+                    '¶'.encode_utf8(&mut indicator_buf)
                 } else if ch == '\n' {
                     ""
                 } else if ws_show_tab {
@@ -844,7 +853,8 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     }
                 }
 
-                if is_cursor && ch == '\n' {
+                // When the ¶ marker is drawn, the cursor styling already lands on it.
+                if is_cursor && ch == '\n' && !ws_show_eol {
                     let should_add_indicator = if is_active { is_secondary_cursor } else { true };
                     if should_add_indicator {
                         // Flush accumulated text before adding cursor indicator
@@ -887,8 +897,10 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 .unwrap_or(line_total_visual_width);
             let ch_width = next_col_for_char.saturating_sub(col_offset);
             // `\n` gets visual width 1 from the view pipeline but renders as
-            // empty — don't count it as an on-screen cell.
-            let was_rendered = col_offset >= left_col && ch != '\n';
+            // empty — don't count it as an on-screen cell unless the ¶
+            // end-of-line indicator was drawn there.
+            let was_rendered =
+                col_offset >= left_col && (ch != '\n' || state.buffer_settings.whitespace.eol);
             col_offset = next_col_for_char;
             visible_char_count += ch_width;
             if was_rendered {
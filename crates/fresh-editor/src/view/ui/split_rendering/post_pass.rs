@@ -257,3 +257,122 @@ pub(super) fn apply_background_to_lines(
         line.spans = compress_chars(chars_with_style);
     }
 }
+
+/// A rendered row as seen by the indent guides: the first row of a source
+/// line. Wrapped continuations and virtual rows are `None` in the row list.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct IndentGuideRow {
+    /// Visual width of the line's leading whitespace, tabs expanded; `None`
+    /// for blank lines, which take their guides from the lines around them.
+    pub indent: Option<usize>,
+    /// Content-area column of the line's first character (negative when it
+    /// is scrolled off to the left).
+    pub origin: isize,
+    /// Whether the primary cursor is on this line.
+    pub is_cursor_line: bool,
+}
+
+/// Indent width used for each row: its own, or for blank rows the smaller
+/// of the nearest non-blank rows above and below, so guides run through
+/// empty lines inside a block.
+fn effective_indents(rows: &[Option<IndentGuideRow>]) -> Vec<Option<usize>> {
+    let own: Vec<Option<usize>> = rows.iter().map(|r| r.and_then(|r| r.indent)).collect();
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let row = (*row)?;
+            if row.indent.is_some() {
+                return row.indent;
+            }
+            let above = own[..i].iter().rev().find_map(|&indent| indent);
+            let below = own[i + 1..].iter().find_map(|&indent| indent);
+            Some(match (above, below) {
+                (Some(a), Some(b)) => a.min(b),
+                (a, b) => a.or(b).unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// Guide cells as `(row, content column, active)`. A line indented by `n`
+/// levels gets a guide at the start of each level. The active guide is the
+/// one of the innermost block around the cursor line (or of the block it
+/// opens, when the next line is indented further).
+pub(super) fn indent_guide_cells(
+    rows: &[Option<IndentGuideRow>],
+    indent_unit: usize,
+) -> Vec<(usize, usize, bool)> {
+    let unit = indent_unit.max(1);
+    let indents = effective_indents(rows);
+
+    // Active guide column and the row range it spans.
+    let active = rows
+        .iter()
+        .position(|r| r.is_some_and(|r| r.is_cursor_line))
+        .and_then(|cursor_row| {
+            let indent = indents[cursor_row]?;
+            let next = indents[cursor_row + 1..].iter().find_map(|&i| i);
+            let (column, first) = match next {
+                Some(next) if next > indent => (indent / unit * unit, cursor_row + 1),
+                _ if indent > 0 => ((indent - 1) / unit * unit, cursor_row),
+                _ => return None,
+            };
+            let inside = |row: usize| indents[row].is_none_or(|i| i > column);
+            let mut start = first;
+            while start > 0 && inside(start - 1) {
+                start -= 1;
+            }
+            let mut end = first;
+            while end < rows.len() && inside(end) {
+                end += 1;
+            }
+            Some((column, start..end))
+        });
+
+    let mut cells = Vec::new();
+    for (row, (info, indent)) in rows.iter().zip(&indents).enumerate() {
+        let (Some(info), Some(indent)) = (info, indent) else {
+            continue;
+        };
+        for level in (0..*indent).step_by(unit) {
+            let Ok(column) = usize::try_from(info.origin + level as isize) else {
+                continue;
+            };
+            let is_active = active
+                .as_ref()
+                .is_some_and(|(col, range)| *col == level && range.contains(&row));
+            cells.push((row, column, is_active));
+        }
+    }
+    cells
+}
+
+/// Draw indent guides into the leading whitespace of each row. Only blank
+/// cells (or whitespace indicators) are overwritten, so text, virtual text
+/// and backgrounds are left alone.
+pub(super) fn render_indent_guides(
+    frame: &mut Frame,
+    rows: &[Option<IndentGuideRow>],
+    indent_unit: usize,
+    color: Color,
+    active_color: Color,
+    render_area: Rect,
+    gutter_width: usize,
+) {
+    let content_x = render_area.x as usize + gutter_width;
+    let right = render_area.x as usize + render_area.width as usize;
+    let bottom = render_area.y as usize + render_area.height as usize;
+    for (row, column, active) in indent_guide_cells(rows, indent_unit) {
+        let x = content_x + column;
+        let y = render_area.y as usize + row;
+        if x >= right || y >= bottom {
+            continue;
+        }
+        let cell = &mut frame.buffer_mut()[(x as u16, y as u16)];
+        if !matches!(cell.symbol(), " " | "·" | "→") {
+            continue;
+        }
+        cell.set_symbol("│");
+        cell.set_fg(if active { active_color } else { color });
+    }
+}
@@ -164,6 +164,35 @@ impl RemoteIndicatorOverride {
     }
 }
 
+/// Which side of the status bar a plugin segment is drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginSegmentAlign {
+    Left,
+    Right,
+}
+
+impl PluginSegmentAlign {
+    /// Parse the `align` string sent with `SetStatusBarSegment`.
+    pub fn parse(align: &str) -> Option<Self> {
+        match align {
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// A status bar segment supplied by a plugin through
+/// `registerStatusBarSegment`. Drawn after the configured elements of its
+/// side, so plugin segments are the first to be truncated or dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginStatusSegment {
+    pub id: String,
+    pub align: PluginSegmentAlign,
+    pub priority: i32,
+    pub text: String,
+}
+
 /// A single rendered status bar element with its text and styling info.
 struct RenderedElement {
     text: String,
@@ -202,6 +231,9 @@ pub struct StatusBarContext<'a> {
     pub status_message: &'a Option<String>,
     pub plugin_status_message: &'a Option<String>,
     pub lsp_status: &'a str,
+    /// Progress text of the running LSP work (`$/progress`), shown with
+    /// the status messages until the work ends.
+    pub lsp_progress_message: Option<&'a str>,
     /// Three-state LSP indicator: On / Off / Error / None.  Drives the
     /// indicator's background color independently of `warning_level` (the
     /// latter still scopes whether a warning badge is shown on the right
@@ -240,6 +272,8 @@ pub struct StatusBarContext<'a> {
     /// is redundant; when it's not, the filename keeps the prefix
     /// so users still see the connection at a glance.
     pub remote_indicator_on_bar: bool,
+    /// Plugin-registered segments, highest priority first.
+    pub plugin_segments: &'a [PluginStatusSegment],
 }
 
 /// Layout information returned from status bar rendering for mouse click detection
@@ -513,6 +547,33 @@ fn truncate_to_width(s: &str, max_width: usize) -> String {
     format!("{}...", truncated)
 }
 
+/// Selections larger than this aren't counted: the status bar is redrawn
+/// on every frame and a select-all in a huge file would stall it.
+const MAX_COUNTED_SELECTION_BYTES: usize = 4 * 1024 * 1024;
+
+/// Grapheme, line and word counts of selected text. A trailing newline
+/// doesn't count as an extra line, so selecting whole lines reports the
+/// number of lines highlighted.
+fn selection_counts(text: &str) -> (usize, usize, usize) {
+    use unicode_segmentation::UnicodeSegmentation;
+    let chars = text.graphemes(true).count();
+    let lines = text
+        .strip_suffix('\n')
+        .unwrap_or(text)
+        .matches('\n')
+        .count()
+        + 1;
+    let words = text.unicode_words().count();
+    (chars, lines, words)
+}
+
+/// Zero-based screen column of `position`, with tabs expanded to the buffer's
+/// tab size, so `Col` matches the column the cursor is drawn in.
+fn display_column(state: &mut EditorState, position: usize) -> usize {
+    let tab_size = state.buffer_settings.tab_size;
+    crate::input::actions::tab_stop_column(&mut state.buffer, position, tab_size, 80)
+}
+
 /// Renders the status bar and prompt/minibuffer
 pub struct StatusBarRenderer;
 
@@ -707,7 +768,10 @@ impl StatusBarRenderer {
                 StatusBarElement::Filename
                     | StatusBarElement::Cursor
                     | StatusBarElement::CursorCompact
+                    | StatusBarElement::CursorByte
+                    | StatusBarElement::CursorPercent
                     | StatusBarElement::CursorCount
+                    | StatusBarElement::Selection
                     | StatusBarElement::Diagnostics
                     | StatusBarElement::LineEnding
                     | StatusBarElement::Encoding
@@ -771,9 +835,7 @@ impl StatusBarRenderer {
                 let text = if byte_offset_mode {
                     format!("Byte {}", cursor.position)
                 } else {
-                    let cursor_iter = ctx.state.buffer.line_iterator(cursor.position, 80);
-                    let line_start = cursor_iter.current_position();
-                    let col = cursor.position.saturating_sub(line_start);
+                    let col = display_column(ctx.state, cursor.position);
                     let line = ctx.state.primary_cursor_line_number.value();
                     format!("Ln {}, Col {}", line + 1, col + 1)
                 };
@@ -791,9 +853,7 @@ impl StatusBarRenderer {
                 let text = if byte_offset_mode {
                     format!("{}", cursor.position)
                 } else {
-                    let cursor_iter = ctx.state.buffer.line_iterator(cursor.position, 80);
-                    let line_start = cursor_iter.current_position();
-                    let col = cursor.position.saturating_sub(line_start);
+                    let col = display_column(ctx.state, cursor.position);
                     let line = ctx.state.primary_cursor_line_number.value();
                     format!("{}:{}", line + 1, col + 1)
                 };
@@ -802,36 +862,36 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
-            StatusBarElement::Diagnostics => {
-                let diagnostics = ctx.state.overlays.all();
-                let mut error_count = 0usize;
-                let mut warning_count = 0usize;
-                let mut info_count = 0usize;
-                let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
-                for overlay in diagnostics {
-                    if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                        match overlay.priority {
-                            100 => error_count += 1,
-                            50 => warning_count += 1,
-                            _ => info_count += 1,
-                        }
-                    }
-                }
-                if error_count + warning_count + info_count == 0 {
+            StatusBarElement::CursorByte => {
+                if !ctx.state.show_cursors {
                     return None;
                 }
-                let mut parts = Vec::new();
-                if error_count > 0 {
-                    parts.push(format!("E:{}", error_count));
-                }
-                if warning_count > 0 {
-                    parts.push(format!("W:{}", warning_count));
-                }
-                if info_count > 0 {
-                    parts.push(format!("I:{}", info_count));
+                Some(RenderedElement {
+                    text: format!("Byte {}", ctx.cursors.primary().position),
+                    kind: ElementKind::Normal,
+                })
+            }
+            StatusBarElement::CursorPercent => {
+                if !ctx.state.show_cursors {
+                    return None;
                 }
+                let len = ctx.state.buffer.len();
+                let percent = if len == 0 {
+                    0
+                } else {
+                    ctx.cursors.primary().position.min(len) * 100 / len
+                };
+                Some(RenderedElement {
+                    text: format!("{}%", percent),
+                    kind: ElementKind::Normal,
+                })
+            }
+            StatusBarElement::Diagnostics => {
+                let counts = crate::services::lsp::diagnostics::DiagnosticCounts::from_overlays(
+                    ctx.state.overlays.all(),
+                );
                 Some(RenderedElement {
-                    text: parts.join(" "),
+                    text: counts.summary()?,
                     kind: ElementKind::Normal,
                 })
             }
@@ -844,6 +904,24 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
+            StatusBarElement::Selection => {
+                let range = ctx.cursors.primary().selection_range()?;
+                if range.is_empty() || range.len() > MAX_COUNTED_SELECTION_BYTES {
+                    return None;
+                }
+                let bytes = ctx.state.buffer.slice_bytes(range);
+                let (chars, lines, words) = selection_counts(&String::from_utf8_lossy(&bytes));
+                Some(RenderedElement {
+                    text: t!(
+                        "status.selection",
+                        chars = chars,
+                        lines = lines,
+                        words = words
+                    )
+                    .to_string(),
+                    kind: ElementKind::Normal,
+                })
+            }
             StatusBarElement::Messages => {
                 let mut parts: Vec<&str> = Vec::new();
                 if let Some(msg) = ctx.status_message {
@@ -856,6 +934,9 @@ impl StatusBarRenderer {
                         parts.push(msg);
                     }
                 }
+                if let Some(msg) = ctx.lsp_progress_message {
+                    parts.push(msg);
+                }
                 if parts.is_empty() {
                     return None;
                 }
@@ -881,10 +962,18 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
-            StatusBarElement::LineEnding => Some(RenderedElement {
-                text: format!(" {} ", ctx.state.buffer.line_ending().display_name()),
-                kind: ElementKind::LineEnding,
-            }),
+            StatusBarElement::LineEnding => {
+                let name = ctx.state.buffer.line_ending().display_name();
+                let text = if ctx.state.buffer.has_mixed_line_endings() {
+                    format!(" {} ", t!("status.line_ending_mixed", name = name))
+                } else {
+                    format!(" {} ", name)
+                };
+                Some(RenderedElement {
+                    text,
+                    kind: ElementKind::LineEnding,
+                })
+            }
             StatusBarElement::Encoding => Some(RenderedElement {
                 text: format!(" {} ", ctx.state.buffer.encoding().display_name()),
                 kind: ElementKind::Encoding,
@@ -1236,6 +1325,30 @@ impl StatusBarRenderer {
             .collect()
     }
 
+    /// Render the plugin segments for one side, in the order given (highest
+    /// priority first).
+    fn render_plugin_side(
+        ctx: &StatusBarContext<'_>,
+        align: PluginSegmentAlign,
+    ) -> Vec<(Vec<Span<'static>>, usize, ElementKind)> {
+        let style = Style::default()
+            .fg(ctx.theme.status_bar_fg)
+            .bg(ctx.theme.status_bar_bg);
+        ctx.plugin_segments
+            .iter()
+            .filter(|seg| seg.align == align && !seg.text.is_empty())
+            .map(|seg| {
+                let text: String = seg
+                    .text
+                    .chars()
+                    .map(|c| if c.is_control() { ' ' } else { c })
+                    .collect();
+                let width = str_width(&text);
+                (vec![Span::styled(text, style)], width, ElementKind::Normal)
+            })
+            .collect()
+    }
+
     /// Render the normal status bar (config-driven).
     fn render_status(
         frame: &mut Frame,
@@ -1263,8 +1376,10 @@ impl StatusBarRenderer {
             .chain(config.right.iter())
             .any(|e| matches!(e, StatusBarElement::RemoteIndicator));
 
-        let left_items = Self::render_side(&config.left, ctx);
+        let mut left_items = Self::render_side(&config.left, ctx);
+        left_items.extend(Self::render_plugin_side(ctx, PluginSegmentAlign::Left));
         let mut right_items = Self::render_side(&config.right, ctx);
+        right_items.extend(Self::render_plugin_side(ctx, PluginSegmentAlign::Right));
 
         const SEPARATOR: &str = " | ";
         let separator_width = str_width(SEPARATOR);
@@ -1976,6 +2091,15 @@ mod tests {
         assert_ne!(theme.status_lsp_on_bg, theme.status_bar_bg);
     }
 
+    #[test]
+    fn test_selection_counts() {
+        assert_eq!(selection_counts("hello world"), (11, 1, 2));
+        assert_eq!(selection_counts("one\ntwo three\n"), (14, 2, 3));
+        // Combining accent is part of the same grapheme; CRLF is one grapheme
+        assert_eq!(selection_counts("e\u{301}\r\nx"), (3, 2, 2));
+        assert_eq!(selection_counts("\n"), (1, 1, 0));
+    }
+
     #[test]
     fn test_remote_indicator_override_state_projection() {
         assert_eq!(
@@ -36,6 +36,18 @@ fn preview_suffix(t: &TabTarget, buffer_metadata: &HashMap<BufferId, BufferMetad
     }
 }
 
+/// Marker shown before the label of a pinned tab
+const PIN_INDICATOR: &str = "▪ ";
+
+/// Returns the pin marker to put before a tab's label, or an empty string if
+/// the tab is not pinned. Groups are never pinned.
+fn pin_prefix(t: &TabTarget, buffer_metadata: &HashMap<BufferId, BufferMetadata>) -> &'static str {
+    match t {
+        TabTarget::Buffer(id) if buffer_metadata.get(id).is_some_and(|m| m.pinned) => PIN_INDICATOR,
+        _ => "",
+    }
+}
+
 /// Hit area for a single tab
 #[derive(Debug, Clone)]
 pub struct TabHitArea {
@@ -68,6 +80,10 @@ pub struct TabLayout {
     pub left_scroll_area: Option<Rect>,
     /// Hit area for the right scroll button (if shown)
     pub right_scroll_area: Option<Rect>,
+    /// Hit area for the overflow menu button (shown when not all tabs fit)
+    pub overflow_area: Option<Rect>,
+    /// Tabs that are scrolled out of view or only partly visible
+    pub hidden_targets: Vec<TabTarget>,
 }
 
 /// Hit test result for tab interactions
@@ -83,6 +99,8 @@ pub enum TabHit {
     ScrollLeft,
     /// Hit the right scroll button
     ScrollRight,
+    /// Hit the overflow menu button
+    Overflow,
 }
 
 impl TabLayout {
@@ -93,6 +111,8 @@ impl TabLayout {
             bar_area,
             left_scroll_area: None,
             right_scroll_area: None,
+            overflow_area: None,
+            hidden_targets: Vec::new(),
         }
     }
 
@@ -123,6 +143,11 @@ impl TabLayout {
                 return Some(TabHit::ScrollRight);
             }
         }
+        if let Some(overflow_area) = self.overflow_area {
+            if point_in_rect(overflow_area, x, y) {
+                return Some(TabHit::Overflow);
+            }
+        }
 
         for tab in &self.tabs {
             // Check close button first (it's inside the tab area)
@@ -144,6 +169,9 @@ impl TabLayout {
     }
 }
 
+/// Width of the overflow menu button at the right end of a crowded tab bar
+pub const OVERFLOW_BUTTON_WIDTH: usize = 1;
+
 /// Renders the tab bar showing open buffers
 pub struct TabsRenderer;
 
@@ -352,9 +380,10 @@ pub fn calculate_tab_widths(
         };
 
         let preview_indicator = preview_suffix(t, buffer_metadata);
+        let pin = pin_prefix(t, buffer_metadata);
 
-        // Same format as render_for_split: " {name}{modified}{preview_indicator}{binary_indicator} " + "× "
-        let tab_name_text = format!(" {name}{modified}{preview_indicator}{binary_indicator} ");
+        // Same format as render_for_split: " {pin}{name}{modified}{preview_indicator}{binary_indicator} " + "× "
+        let tab_name_text = format!(" {pin}{name}{modified}{preview_indicator}{binary_indicator} ");
         let close_text = "× ";
         let tab_width = str_width(&tab_name_text) + str_width(close_text);
 
@@ -405,6 +434,7 @@ impl TabsRenderer {
         const SCROLL_INDICATOR_LEFT: &str = "<";
         const SCROLL_INDICATOR_RIGHT: &str = ">";
         const SCROLL_INDICATOR_WIDTH: usize = 1; // Width of "<" or ">"
+        const OVERFLOW_BUTTON: &str = "▼";
 
         let mut all_tab_spans: Vec<(Span, usize)> = Vec::new(); // Store (Span, display_width)
         let mut tab_ranges: Vec<(usize, usize, usize)> = Vec::new(); // (start, end, close_start) positions for each tab
@@ -460,6 +490,7 @@ impl TabsRenderer {
             // single-click open.
             let is_preview = is_preview_tab(t, buffer_metadata);
             let preview_indicator = preview_suffix(t, buffer_metadata);
+            let pin = pin_prefix(t, buffer_metadata);
 
             let is_active = *t == active_target;
 
@@ -510,8 +541,9 @@ impl TabsRenderer {
                 base_style
             };
 
-            // Build tab content: " {name}{modified}{preview_indicator}{binary_indicator} "
-            let tab_name_text = format!(" {name}{modified}{preview_indicator}{binary_indicator} ");
+            // Build tab content: " {pin}{name}{modified}{preview_indicator}{binary_indicator} "
+            let tab_name_text =
+                format!(" {pin}{name}{modified}{preview_indicator}{binary_indicator} ");
             let tab_name_width = str_width(&tab_name_text);
 
             // Close button: "× "
@@ -567,9 +599,16 @@ impl TabsRenderer {
         let all_tab_spans = final_spans;
 
         let mut current_spans: Vec<Span> = Vec::new();
-        let max_width = area.width as usize;
-
         let total_width: usize = all_tab_spans.iter().map(|(_, w)| w).sum();
+        // When not every tab fits, the rightmost column holds the overflow
+        // button and the tabs get the rest of the bar
+        let show_overflow = total_width > area.width as usize;
+        let max_width = (area.width as usize).saturating_sub(if show_overflow {
+            OVERFLOW_BUTTON_WIDTH
+        } else {
+            0
+        });
+
         // Use rendered_targets (not tab_targets) to find active index,
         // since some targets may have been skipped
         let _active_tab_idx = rendered_targets.iter().position(|t| *t == active_target);
@@ -671,6 +710,21 @@ impl TabsRenderer {
             ));
         }
 
+        if show_overflow {
+            current_spans.push(Span::styled(
+                OVERFLOW_BUTTON,
+                Style::default()
+                    .fg(theme.tab_inactive_fg)
+                    .bg(theme.tab_separator_bg),
+            ));
+            layout.overflow_area = Some(Rect::new(
+                area.x + max_width as u16,
+                area.y,
+                OVERFLOW_BUTTON_WIDTH as u16,
+                1,
+            ));
+        }
+
         let line = Line::from(current_spans);
         let block = Block::default().style(Style::default().bg(theme.tab_separator_bg));
         let paragraph = Paragraph::new(line).block(block);
@@ -705,8 +759,12 @@ impl TabsRenderer {
 
             // Skip tabs that are completely scrolled out of view
             if logical_end <= visible_start || logical_start >= visible_end {
+                layout.hidden_targets.push(*target);
                 continue;
             }
+            if logical_start < visible_start || logical_end > visible_end {
+                layout.hidden_targets.push(*target);
+            }
 
             // Calculate visible portion of this tab
             let screen_start = if logical_start >= visible_start {
@@ -925,6 +983,10 @@ mod tests {
         // Hit bar background
         assert_eq!(layout.hit_test(50, 0), Some(TabHit::BarBackground));
 
+        // Hit overflow button
+        layout.overflow_area = Some(Rect::new(79, 0, 1, 1));
+        assert_eq!(layout.hit_test(79, 0), Some(TabHit::Overflow));
+
         // Outside everything
         assert_eq!(layout.hit_test(50, 5), None);
     }
@@ -0,0 +1,84 @@
+//! URL underlines using the overlay system
+//!
+//! URLs in the viewport (as found by [`find_urls`]) get a straight
+//! underline when `underline_urls` is on for the buffer.
+
+use crate::model::buffer::Buffer;
+use crate::model::marker::MarkerList;
+use crate::primitives::urls::find_urls;
+use crate::view::overlay::{
+    Overlay, OverlayFace, OverlayManager, OverlayNamespace, UnderlineStyle,
+};
+use ratatui::style::Color;
+
+/// Namespace for URL underline overlays
+pub fn url_namespace() -> OverlayNamespace {
+    OverlayNamespace::from_string("url-underline".to_string())
+}
+
+/// Below spell check, so a misspelling inside a URL still shows
+const URL_PRIORITY: i32 = 4;
+
+/// Manager for URL underline overlays
+pub struct UrlOverlay {
+    /// Buffer version, viewport and enabled flag of the last scan
+    last_key: Option<(u64, usize, usize, bool)>,
+}
+
+impl UrlOverlay {
+    /// Create a new URL overlay manager
+    pub fn new() -> Self {
+        Self { last_key: None }
+    }
+
+    /// Re-scan the viewport when the buffer or viewport changed
+    ///
+    /// Returns true if overlays were updated
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        buffer: &Buffer,
+        overlays: &mut OverlayManager,
+        marker_list: &mut MarkerList,
+        enabled: bool,
+        viewport_start: usize,
+        viewport_end: usize,
+        color: Color,
+    ) -> bool {
+        let key = (buffer.version(), viewport_start, viewport_end, enabled);
+        if self.last_key == Some(key) || (!enabled && self.last_key.is_none()) {
+            return false;
+        }
+        self.last_key = Some(key);
+
+        let ns = url_namespace();
+        overlays.clear_namespace(&ns, marker_list);
+        if !enabled || viewport_start >= viewport_end {
+            return true;
+        }
+
+        let bytes = buffer.slice_bytes(viewport_start..viewport_end.min(buffer.len()));
+        let text = match std::str::from_utf8(&bytes) {
+            Ok(text) => text,
+            Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        };
+
+        for url in find_urls(text) {
+            let range = viewport_start + url.start..viewport_start + url.end;
+            let face = OverlayFace::Underline {
+                color,
+                style: UnderlineStyle::Straight,
+            };
+            let overlay = Overlay::with_namespace(marker_list, range, face, ns.clone())
+                .with_priority_value(URL_PRIORITY);
+            overlays.add(overlay);
+        }
+        true
+    }
+}
+
+impl Default for UrlOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -162,6 +162,42 @@ impl Viewport {
             .copied()
     }
 
+    /// Scroll so the line containing `position` sits in the middle row.
+    ///
+    /// Lines inside `hidden_ranges` (collapsed folds) take no rows, so they
+    /// are skipped while counting upwards. The next render keeps this
+    /// position instead of re-applying the scroll margin.
+    pub(crate) fn center_on(
+        &mut self,
+        buffer: &mut Buffer,
+        position: usize,
+        hidden_ranges: &[(usize, usize)],
+    ) {
+        let target_rows_from_top = self.visible_line_count() / 2;
+        let prev_line_start =
+            |buffer: &mut Buffer, pos: usize| buffer.line_iterator(pos, 80).prev().map(|(s, _)| s);
+        let mut top = buffer.line_iterator(position, 80).current_position();
+        'rows: for _ in 0..target_rows_from_top {
+            let Some(mut line) = prev_line_start(buffer, top) else {
+                break;
+            };
+            // Jump over each collapsed fold in one step, to the line above
+            // it. Every jump moves strictly backwards, so this ends.
+            while let Some((start, _end)) = Self::containing_hidden_range(hidden_ranges, line) {
+                match prev_line_start(buffer, start) {
+                    Some(above) => line = above,
+                    // A fold at the start of the buffer has nothing above it
+                    None => break 'rows,
+                }
+            }
+            top = line;
+        }
+        self.top_byte = top;
+        self.top_view_line_offset = 0;
+        self.scrolled_up_in_wrap = false;
+        self.set_skip_ensure_visible();
+    }
+
     /// Mark viewport to skip sync on next resize (used after session restore)
     pub fn set_skip_resize_sync(&mut self) {
         self.skip_resize_sync = true;
@@ -2356,6 +2392,21 @@ mod tests {
     use crate::model::buffer::Buffer;
     use crate::model::cursor::Cursor;
 
+    #[test]
+    fn test_center_on_skips_fold_at_buffer_start() {
+        let content: String = (0..40).map(|i| format!("line{:02}\n", i)).collect();
+        let mut buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(80, 10);
+        // Lines 0..=4 are hidden, so line 5 is the first visible one
+        let hidden = [(0, 35)];
+
+        vp.center_on(&mut buffer, 7 * 7, &hidden);
+        assert_eq!(vp.top_byte, 5 * 7);
+
+        vp.center_on(&mut buffer, 20 * 7, &hidden);
+        assert_eq!(vp.top_byte, (20 - vp.visible_line_count() / 2) * 7);
+    }
+
     #[test]
     fn test_viewport_new() {
         let vp = Viewport::new(80, 24);
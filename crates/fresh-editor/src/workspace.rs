@@ -29,6 +29,7 @@ use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::config::LineNumberMode;
 use crate::input::input_history::get_data_dir;
 
 /// Current workspace file format version
@@ -88,6 +89,11 @@ pub struct Workspace {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub read_only_files: Vec<PathBuf>,
 
+    /// Breakpoint lines (0-indexed) per file; re-applied on restore.
+    /// Relative to `working_dir` when possible, otherwise absolute.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub breakpoints: HashMap<PathBuf, Vec<usize>>,
+
     /// Unnamed buffers that should be restored from recovery files
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub unnamed_buffers: Vec<UnnamedBufferRef>,
@@ -284,6 +290,8 @@ pub struct WorkspaceConfigOverrides {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub relative_line_numbers: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_number_mode: Option<LineNumberMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub line_wrap: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub syntax_highlighting: Option<bool>,
@@ -350,8 +358,12 @@ pub struct WorkspaceHistories {
     pub search: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub replace: Vec<String>,
+    /// Command palette history, most recent first
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub command_palette: Vec<String>,
+    /// Usage count per command palette entry, for frecency ranking
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub command_palette_counts: HashMap<String, u32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub goto_line: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -917,6 +929,7 @@ impl Workspace {
             terminals: Vec::new(),
             external_files: Vec::new(),
             read_only_files: Vec::new(),
+            breakpoints: HashMap::new(),
             unnamed_buffers: Vec::new(),
             plugin_global_state: HashMap::new(),
             saved_at: SystemTime::now()
@@ -2250,6 +2250,220 @@ impl Drop for FakeLspServer {
     }
 }
 
+/// Write a fake LSP server that publishes `diagnostics` (raw JSON array)
+/// for every document it is told about. It answers hover with no content,
+/// so the hover card shows only the diagnostics.
+pub fn write_diagnostics_server(
+    dir: &std::path::Path,
+    name: &str,
+    diagnostics: &str,
+) -> anyhow::Result<std::path::PathBuf> {
+    let script = format!(
+        r##"#!/bin/bash
+
+DIAGNOSTICS='{diagnostics}'
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1,"hoverProvider":true}}}}}}'
+            ;;
+        "textDocument/didOpen")
+            uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+            send_message '{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{{"uri":"'$uri'","diagnostics":'"$DIAGNOSTICS"'}}}}'
+            ;;
+        "textDocument/hover")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join(format!("fake_{name}_lsp.sh"));
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// Write a fake LSP server that holds back its answer to `method` until the
+/// request is cancelled, then sends `late_result` anyway (as a slow server
+/// would) followed by a "late reply sent" warning, so tests can tell the
+/// late reply is out. Requests and cancels are logged to `log_file` as
+/// `RECV: request <id>` and `RECV: cancel <id>`.
+pub fn write_slow_reply_server(
+    dir: &std::path::Path,
+    log_file: &std::path::Path,
+    method: &str,
+    late_result: &str,
+) -> anyhow::Result<std::path::PathBuf> {
+    let log_path = log_file.display();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+REQUEST_ID=""
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"completionProvider":{{}},"hoverProvider":true,"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "{method}")
+            REQUEST_ID=$msg_id
+            echo "RECV: request $msg_id" >> "$LOG_FILE"
+            ;;
+        "$/cancelRequest")
+            echo "RECV: cancel $msg_id" >> "$LOG_FILE"
+            if [ "$msg_id" = "$REQUEST_ID" ]; then
+                send_message '{{"jsonrpc":"2.0","id":'$REQUEST_ID',"result":{late_result}}}'
+                send_message '{{"jsonrpc":"2.0","method":"window/showMessage","params":{{"type":2,"message":"late reply sent"}}}}'
+                echo "SENT: late reply" >> "$LOG_FILE"
+            fi
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "textDocument/semanticTokens/full"|"textDocument/semanticTokens/full/delta"|"textDocument/semanticTokens/range")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"data":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_slow_reply_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// A diagnostic on the first four columns of `line`, as raw JSON.
+pub fn diagnostic_json(line: u32, severity: u8, message: &str, source: &str) -> String {
+    format!(
+        r#"{{"range":{{"start":{{"line":{line},"character":0}},"end":{{"line":{line},"character":4}}}},"severity":{severity},"message":"{message}","source":"{source}"}}"#
+    )
+}
+
+/// LSP config entry named `name` that runs the script at `script_path`.
+pub fn diagnostics_server_config(
+    name: &str,
+    script_path: &std::path::Path,
+) -> fresh::services::lsp::LspServerConfig {
+    fresh::services::lsp::LspServerConfig {
+        command: script_path.to_string_lossy().to_string(),
+        args: vec![],
+        enabled: true,
+        auto_start: true,
+        process_limits: fresh::services::process_limits::ProcessLimits::default(),
+        initialization_options: None,
+        env: Default::default(),
+        language_id_overrides: Default::default(),
+        root_markers: Default::default(),
+        name: Some(name.to_string()),
+        only_features: None,
+        except_features: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1671,13 +1671,14 @@ impl EditorTestHarness {
 
     /// Perform a clean shutdown, mirroring `run_event_loop_common` exit path.
     ///
-    /// Calls auto-save (if enabled), `end_recovery_session`, and `save_workspace`
-    /// in the same order as the production shutdown code.
+    /// Calls auto-save (if enabled), `end_recovery_session`, `save_recent_files`
+    /// and `save_workspace` in the same order as the production shutdown code.
     pub fn shutdown(&mut self, workspace_enabled: bool) -> anyhow::Result<()> {
-        if self.editor.config().editor.auto_save_enabled {
+        if self.editor.config().editor.auto_save_mode() != fresh::config::AutoSaveMode::Off {
             self.editor.save_all_on_exit()?;
         }
         self.editor.end_recovery_session()?;
+        self.editor.save_recent_files();
         if workspace_enabled {
             self.editor.save_workspace()?;
         }
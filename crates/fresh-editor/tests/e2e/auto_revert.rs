@@ -466,3 +466,73 @@ fn test_auto_revert_with_temp_rename_save() {
         harness.assert_buffer_content(&new_content);
     }
 }
+
+/// Open a file, edit it locally, then change it on disk: the editor asks
+/// whether to reload, keep, or diff, and reloading picks up the new content.
+#[test]
+fn test_external_change_prompts_and_reloads_modified_buffer() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("conflict.txt");
+
+    write_and_sync(&file_path, "Original content");
+    harness.open_file(&file_path).unwrap();
+
+    use crossterm::event::{KeyCode, KeyModifiers};
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(" - local edit").unwrap();
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&file_path, "External change");
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("changed on disk"))
+        .expect("a modified buffer should prompt when its file changes on disk");
+    harness.assert_buffer_content("Original content - local edit");
+
+    harness.type_text("r").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("External change");
+}
+
+/// Choosing diff opens a side-by-side view of the local and disk versions
+/// and leaves the local edits in place.
+#[test]
+fn test_external_change_diff_opens_side_by_side_view() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("conflict_diff.txt");
+
+    write_and_sync(&file_path, "shared line\nlocal line\n");
+    harness.open_file(&file_path).unwrap();
+    let file_buffer = harness.editor().active_buffer();
+
+    use crossterm::event::{KeyCode, KeyModifiers};
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("edited ").unwrap();
+
+    harness.sleep(FILE_CHANGE_DELAY);
+    write_and_sync(&file_path, "shared line\ndisk line\n");
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("changed on disk"))
+        .expect("a modified buffer should prompt when its file changes on disk");
+
+    harness.type_text("d").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let active = harness.editor().active_buffer();
+    assert_ne!(active, file_buffer);
+    assert!(harness.editor().is_composite_buffer(active));
+    harness.assert_screen_contains("disk line");
+    harness.assert_screen_contains("edited shared line");
+}
@@ -0,0 +1,72 @@
+//! Tests for breakpoints and the `breakpoint` gutter column.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, GutterColumn};
+use tempfile::TempDir;
+
+/// A breakpoint set by clicking the gutter stays on its line when a line
+/// is inserted above it.
+#[test]
+fn test_breakpoint_click_follows_line_through_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("main.py");
+    std::fs::write(&file_path, "alpha = 1\nbeta = 2\ngamma = 3\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.gutter_columns = Some(vec![GutterColumn::Breakpoint, GutterColumn::LineNumber]);
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    let buffer_id = harness.editor().active_buffer();
+
+    let (_, gamma_row) = harness.find_text_on_screen("gamma = 3").unwrap();
+    harness.mouse_click(0, gamma_row).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().breakpoint_lines(buffer_id), vec![2]);
+    assert_eq!(harness.get_cell(0, gamma_row).unwrap(), "◉");
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("first = 0").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().breakpoint_lines(buffer_id), vec![3]);
+    let (_, gamma_row) = harness.find_text_on_screen("gamma = 3").unwrap();
+    assert_eq!(harness.get_cell(0, gamma_row).unwrap(), "◉");
+    let (_, beta_row) = harness.find_text_on_screen("beta = 2").unwrap();
+    assert_ne!(harness.get_cell(0, beta_row).unwrap(), "◉");
+
+    // Clicking the glyph again clears the breakpoint.
+    harness.mouse_click(0, gamma_row).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().breakpoint_lines(buffer_id).is_empty());
+}
+
+/// In the default gutter layout, clicking a line number toggles a
+/// breakpoint, shown in the indicator cell.
+#[test]
+fn test_breakpoint_click_on_line_number_in_default_layout() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("main.py");
+    std::fs::write(&file_path, "alpha = 1\nbeta = 2\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    let buffer_id = harness.editor().active_buffer();
+
+    let (_, beta_row) = harness.find_text_on_screen("beta = 2").unwrap();
+    harness.mouse_click(1, beta_row).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().breakpoint_lines(buffer_id), vec![1]);
+    assert_eq!(harness.get_cell(0, beta_row).unwrap(), "◉");
+
+    harness.mouse_click(1, beta_row).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().breakpoint_lines(buffer_id).is_empty());
+}
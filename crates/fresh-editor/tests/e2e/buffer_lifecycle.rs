@@ -477,6 +477,77 @@ fn test_click_tab_close_modified_cancel() {
     }
 }
 
+/// Closing a modified buffer from the keyboard lists the choices, which can
+/// be picked with the arrow keys; Enter alone cancels
+#[test]
+fn test_close_modified_buffer_prompt_keyboard_choices() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("Keep this").unwrap();
+    let modified = harness.editor().active_buffer();
+
+    // Enter on its own keeps the buffer
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::CloseTab);
+    harness.render().unwrap();
+    harness.assert_screen_contains("modified. (s)ave, (d)iscard, (C)ancel? ");
+    harness.assert_screen_contains("Save");
+    harness.assert_screen_contains("Discard changes");
+    harness.assert_screen_contains("Cancel");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Close cancelled");
+    assert_eq!(harness.editor().active_buffer(), modified);
+
+    // Up from Cancel highlights Discard
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::CloseTab);
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.editor().is_prompting());
+    assert_ne!(harness.editor().active_buffer(), modified);
+    harness.assert_screen_not_contains("Keep this");
+}
+
+/// Closing the only modified buffer also offers to save everything and quit
+#[test]
+fn test_close_last_modified_buffer_offers_save_all_and_quit() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("saved on quit").unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::CloseTab);
+    harness.render().unwrap();
+    harness.assert_screen_contains("or (q) save all and quit?");
+    harness.assert_screen_contains("Save all and quit");
+
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    assert!(harness.should_quit());
+    assert_eq!(
+        std::fs::read_to_string(&file_path).unwrap(),
+        "saved on quit"
+    );
+}
+
 /// Test that next/previous buffer commands skip hidden buffers
 /// Bug: When cycling through buffers with next_buffer/prev_buffer,
 /// the editor would focus hidden buffers instead of skipping them
@@ -398,3 +398,77 @@ fn test_case_conversion_from_command_palette() {
         "Text should be converted to uppercase via command palette"
     );
 }
+
+/// Test converting selected text to title case with Alt+C
+#[test]
+fn test_to_title_case() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("hello WORLD éCOLE").unwrap();
+
+    // Select all
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    // Convert to title case with Alt+C
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+
+    let buffer_content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        buffer_content, "Hello World École",
+        "Each word should start uppercase, including multi-byte letters"
+    );
+
+    // The whole transform is a single undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello WORLD éCOLE");
+}
+
+/// Test title case with no selection converts the word under the cursor
+#[test]
+fn test_to_title_case_no_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("éTÉ hiver").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_no_selection();
+
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+
+    let buffer_content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        buffer_content, "Été hiver",
+        "Only the word under the cursor should be title-cased"
+    );
+}
+
+/// Test lowercase with a multi-byte accented selection
+#[test]
+fn test_to_lowercase_unicode() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("CAFÉ RÉSUMÉ").unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "café résumé");
+}
@@ -0,0 +1,41 @@
+//! E2E tests for the command history panel.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_palette_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Executed commands are listed newest first, and Enter on one runs it
+/// again in the editor split.
+#[test]
+fn test_command_history_lists_and_reruns_commands() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+    let _fixture = harness.load_buffer_from_text("hello").unwrap();
+
+    run_palette_command(&mut harness, "Toggle Line Numbers");
+    run_palette_command(&mut harness, "Duplicate Line");
+    harness.assert_buffer_content("hello\nhello");
+
+    run_palette_command(&mut harness, "Command History");
+    harness.render().unwrap();
+    let panel = harness.get_buffer_content().unwrap();
+    let labels: Vec<&str> = panel
+        .lines()
+        .map(|line| line.split_once("  ").map_or(line, |(_, label)| label))
+        .collect();
+    assert_eq!(labels, vec!["Duplicate Line", "Toggle Line Numbers"]);
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("hello\nhello\nhello");
+}
@@ -141,6 +141,44 @@ fn test_command_palette_execute() {
     harness.assert_screen_not_contains("1 │");
 }
 
+/// Frequently used commands float to the top of an empty palette query
+#[test]
+fn test_command_palette_ranks_frequent_commands_first() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+    for _ in 0..4 {
+        run_command(&mut harness, "Toggle Line Numbers");
+    }
+    // A single, more recent use of another command
+    run_command(&mut harness, "Select All");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let top: Vec<String> = harness
+        .editor_mut()
+        .prompt_mut()
+        .expect("palette should be open")
+        .suggestions
+        .iter()
+        .take(2)
+        .map(|s| s.text.clone())
+        .collect();
+    assert_eq!(top, vec!["Toggle Line Numbers", "Select All"]);
+}
+
 /// Test Quick Open file mode with path:line:col
 #[test]
 fn test_quick_open_file_path_line_col() {
@@ -931,6 +969,114 @@ fn test_goto_line_prompt_unsigned_input_is_absolute_with_relative_setting() {
         .expect("Unsigned `25` should jump to absolute line 25 regardless of the setting");
 }
 
+/// Goto Line accepts vim-style `:N` input, previewing and confirming it
+/// like a plain line number.
+#[test]
+fn test_goto_line_prompt_accepts_colon_prefix() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let jump_path = project_root.join("jump.txt");
+    write_numbered_lines(&jump_path, 100);
+
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(":42").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(" 42 │ LINE42"))
+        .expect("`:42` should live-preview line 42");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Ln 42,"))
+        .expect("`:42` should jump to line 42");
+}
+
+/// `N%` jumps that far through the file; Esc after the preview restores the
+/// original position.
+#[test]
+fn test_goto_line_prompt_percent_previews_and_cancels() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let jump_path = project_root.join("jump.txt");
+    write_numbered_lines(&jump_path, 100);
+
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("50%").unwrap();
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains(" 50 │ LINE50") && !screen.contains("  1 │ LINE1")
+        })
+        .expect("`50%` should preview the middle of the file");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("Ln 1,") && screen.contains("  1 │ LINE1")
+        })
+        .expect("Esc should restore the cursor to line 1");
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("50%").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Ln 50,"))
+        .expect("Confirming `50%` should land on line 50 of 101");
+}
+
+/// A line number past the end of the file lands on the last line.
+#[test]
+fn test_goto_line_prompt_clamps_to_last_line() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let jump_path = project_root.join("jump.txt");
+    write_numbered_lines(&jump_path, 100);
+
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("5000").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // 100 newline-terminated lines plus the empty line after the last newline
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Ln 101,"))
+        .expect("Out-of-range input should clamp to the last line");
+}
+
 /// Test command palette fuzzy matching
 #[test]
 fn test_command_palette_fuzzy_matching() {
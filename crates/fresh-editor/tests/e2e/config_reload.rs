@@ -0,0 +1,102 @@
+//! E2E tests for reloading the config in a running session
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use fresh::config_io::DirectoryContext;
+use fresh::input::keybindings::Action;
+use ratatui::style::Color;
+use std::fs;
+use tempfile::TempDir;
+
+/// The default ruler background color (see vertical_rulers.rs)
+const RULER_BG: Color = Color::Rgb(50, 50, 50);
+
+/// Harness sharing `temp_dir`'s config directory, plus the user config path
+fn harness_with_user_config(temp_dir: &TempDir) -> (EditorTestHarness, std::path::PathBuf) {
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let config_path = dir_context.config_path();
+    fs::create_dir_all(&dir_context.config_dir).unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+
+    let harness = EditorTestHarness::create(
+        120,
+        24,
+        HarnessOptions::new()
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    (harness, config_path)
+}
+
+/// Write a user config, keeping the settings the harness forces for tests
+fn write_user_config(path: &std::path::Path, editor: &str) {
+    fs::write(
+        path,
+        format!(
+            r#"{{"check_for_updates": false, "editor": {{"show_prompt_line": true{editor}}}}}"#
+        ),
+    )
+    .unwrap();
+}
+
+/// A ruler added to the config file shows up after reloading, in a buffer
+/// that was already open
+#[test]
+fn test_reload_config_applies_rulers() {
+    let temp_dir = TempDir::new().unwrap();
+    let (mut harness, config_path) = harness_with_user_config(&temp_dir);
+    let _fixture = harness.load_buffer_from_text(&"X".repeat(60)).unwrap();
+    harness.render().unwrap();
+
+    let gutter = harness.editor().active_state().margins.left_total_width() as u16;
+    let (row, _) = harness.content_area_rows();
+    let ruler_bg = |h: &EditorTestHarness| {
+        h.get_cell_style(gutter + 10, row as u16).and_then(|s| s.bg) == Some(RULER_BG)
+    };
+    assert!(!ruler_bg(&harness));
+
+    write_user_config(&config_path, r#", "rulers": [10], "tab_size": 2"#);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ReloadConfig);
+    harness.render().unwrap();
+
+    assert!(ruler_bg(&harness), "ruler should appear at column 10");
+    assert_eq!(harness.editor().active_state().buffer_settings.tab_size, 2);
+    harness.assert_screen_contains("Config reloaded");
+    harness.assert_screen_not_contains("restart required");
+}
+
+/// Settings only read at startup are reported instead of silently ignored
+#[test]
+fn test_reload_config_reports_restart_required_settings() {
+    let temp_dir = TempDir::new().unwrap();
+    let (mut harness, config_path) = harness_with_user_config(&temp_dir);
+
+    write_user_config(&config_path, r#", "recovery_enabled": false"#);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ReloadConfig);
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("restart required for: editor.recovery_enabled");
+}
+
+/// A config file that fails to parse leaves the running config untouched
+#[test]
+fn test_reload_config_keeps_config_on_parse_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let (mut harness, config_path) = harness_with_user_config(&temp_dir);
+    let tab_size = harness.editor().config().editor.tab_size;
+
+    fs::write(&config_path, r#"{"editor": {"tab_size": "#).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ReloadConfig);
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Could not reload config");
+    assert_eq!(harness.editor().config().editor.tab_size, tab_size);
+}
@@ -0,0 +1,48 @@
+//! E2E tests for converting a buffer's indentation between tabs and spaces
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SPACES: &str =
+    "fn main() {\n    let s = \"a\tb\";\n    if true {\n        call(  x);\n      odd();\n    }\n\n}\n";
+const TABS: &str =
+    "fn main() {\n\tlet s = \"a\tb\";\n\tif true {\n\t\tcall(  x);\n\t  odd();\n\t}\n\n}\n";
+
+/// Space indentation converts to tabs and back without changing anything
+/// beyond the leading whitespace
+#[test]
+fn test_convert_indentation_round_trip() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(SPACES).unwrap();
+
+    harness.editor_mut().retab_buffer(false, 4);
+    harness.assert_buffer_content(TABS);
+
+    harness.editor_mut().retab_buffer(true, 4);
+    harness.assert_buffer_content(SPACES);
+}
+
+/// The whole conversion is a single undo step
+#[test]
+fn test_convert_indentation_is_one_undo_step() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(SPACES).unwrap();
+
+    harness.editor_mut().retab_buffer(false, 4);
+    harness.assert_buffer_content(TABS);
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content(SPACES);
+}
+
+/// Tabs expand to the chosen width, not the one the buffer was using
+#[test]
+fn test_convert_indentation_to_spaces_uses_chosen_width() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("a\n\tb\n\t\tc\n").unwrap();
+
+    harness.editor_mut().retab_buffer(true, 2);
+    harness.assert_buffer_content("a\n  b\n    c\n");
+}
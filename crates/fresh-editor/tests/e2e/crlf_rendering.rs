@@ -776,3 +776,52 @@ fn test_set_line_ending_converts_on_save_crlf_to_lf() {
         "All line endings should be converted to LF"
     );
 }
+
+/// A file mixing CRLF and LF is reported in the status bar, saved untouched
+/// after an edit, and normalized once a line ending is picked explicitly.
+#[test]
+fn test_mixed_line_endings_reported_and_normalized_on_request() {
+    use fresh::model::buffer::LineEnding;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed_save.txt");
+    std::fs::write(&file_path, "Line 1\r\nLine 2\r\nLine 3\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("CRLF (mixed)");
+
+    // An ordinary edit must not silently convert the odd line ending
+    harness.type_text("X").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.editor().active_state().buffer.is_modified())
+        .unwrap();
+    assert_eq!(
+        std::fs::read(&file_path).unwrap(),
+        b"XLine 1\r\nLine 2\r\nLine 3\n"
+    );
+
+    // Explicitly choosing CRLF rewrites every line ending on save
+    harness
+        .editor_mut()
+        .active_state_mut()
+        .buffer
+        .set_line_ending(LineEnding::CRLF);
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.editor().active_state().buffer.is_modified())
+        .unwrap();
+    assert_eq!(
+        std::fs::read(&file_path).unwrap(),
+        b"XLine 1\r\nLine 2\r\nLine 3\r\n"
+    );
+
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("(mixed)");
+}
@@ -0,0 +1,90 @@
+//! E2E tests for "Diff Against Disk": the active buffer's unsaved content
+//! shown side by side with its file on disk
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+/// Screen column where `needle` starts in `row`, searching from `from_col`
+fn column_of(row: &str, needle: &str, from_col: usize) -> Option<u16> {
+    let chars: Vec<char> = row.chars().collect();
+    let needle: Vec<char> = needle.chars().collect();
+    (from_col..chars.len().saturating_sub(needle.len() - 1))
+        .find(|&col| chars[col..col + needle.len()] == needle[..])
+        .map(|col| col as u16)
+}
+
+#[test]
+fn test_diff_against_disk_highlights_changed_line_on_both_sides() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("notes.txt");
+    std::fs::write(&file_path, "alpha\nbeta\ngamma\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    let file_buffer = harness.editor().active_buffer();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" edited").unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DiffAgainstDisk);
+    harness.render().unwrap();
+
+    let active = harness.editor().active_buffer();
+    assert!(harness.editor().is_composite_buffer(active));
+    harness.assert_screen_contains("Yours");
+    harness.assert_screen_contains("Disk");
+
+    let (local_col, changed_row) = harness
+        .find_text_on_screen("beta edited")
+        .expect("buffer side shows the edited line");
+    let changed_text = harness.screen_row_text(changed_row);
+    let disk_col = column_of(&changed_text, "beta", local_col as usize + 4)
+        .expect("disk side shows the original line on the same row");
+
+    let context_row = changed_row + 1;
+    let context_text = harness.screen_row_text(context_row);
+    let local_context_col = column_of(&context_text, "gamma", 0).unwrap();
+    let disk_context_col = column_of(&context_text, "gamma", local_context_col as usize + 5)
+        .expect("unchanged line is shown on both sides");
+
+    let bg = |h: &EditorTestHarness, x: u16, y: u16| h.get_cell_style(x, y).and_then(|s| s.bg);
+    assert_ne!(
+        bg(&harness, local_col, changed_row),
+        bg(&harness, local_context_col, context_row),
+        "changed line should be highlighted on the buffer side"
+    );
+    assert_ne!(
+        bg(&harness, disk_col, changed_row),
+        bg(&harness, disk_context_col, context_row),
+        "changed line should be highlighted on the disk side"
+    );
+
+    // Neither side of the diff can be edited
+    harness.type_text("x").unwrap();
+    assert_eq!(
+        harness.editor().get_buffer_content(file_buffer).unwrap(),
+        "alpha\nbeta edited\ngamma\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&file_path).unwrap(),
+        "alpha\nbeta\ngamma\n"
+    );
+}
+
+#[test]
+fn test_diff_against_disk_without_file_shows_message() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("scratch").unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DiffAgainstDisk);
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Buffer has no file to compare with");
+    assert!(!harness
+        .editor()
+        .is_composite_buffer(harness.editor().active_buffer()));
+}
@@ -0,0 +1,62 @@
+//! E2E tests for applying `.editorconfig` settings to opened files
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use tempfile::TempDir;
+
+const EDITORCONFIG: &str =
+    "root = true\n\n[*]\nindent_style = space\nindent_size = 2\n\n[*.go]\nindent_style = tab\n";
+
+/// `indent_size = 2` makes Tab insert two spaces instead of the default four
+#[test]
+fn test_editorconfig_indent_size() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join(".editorconfig"), EDITORCONFIG).unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "Hello").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+
+    harness.assert_buffer_content("  Hello");
+}
+
+/// A later, more specific section wins, and sections apply to files in
+/// subdirectories
+#[test]
+fn test_editorconfig_section_override_in_subdirectory() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join(".editorconfig"), EDITORCONFIG).unwrap();
+    std::fs::create_dir(temp_dir.path().join("cmd")).unwrap();
+    let file_path = temp_dir.path().join("cmd").join("main.go");
+    std::fs::write(&file_path, "x").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    let settings = &harness.editor().active_state().buffer_settings;
+    assert!(settings.use_tabs);
+    assert_eq!(settings.tab_size, 2);
+}
+
+/// Per-buffer commands still override `.editorconfig` for the session
+#[test]
+fn test_editorconfig_can_be_overridden_per_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join(".editorconfig"), EDITORCONFIG).unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "Hello").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleIndentationStyle);
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+
+    harness.assert_buffer_content("\tHello");
+}
@@ -0,0 +1,112 @@
+//! E2E tests for expanding the selection by syntax scope and shrinking it back
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+fn selection(harness: &EditorTestHarness) -> Option<std::ops::Range<usize>> {
+    harness
+        .editor()
+        .active_cursors()
+        .primary()
+        .selection_range()
+}
+
+/// Expanding inside a bracketed block selects the word, then the bracket
+/// contents, then the brackets themselves
+#[test]
+fn test_expand_selection_word_then_brackets() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("call(alpha, beta);").unwrap();
+
+    // Cursor inside "alpha"
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..7 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExpandSelectionToScope);
+    assert_eq!(selection(&harness), Some(5..10));
+    assert_eq!(harness.get_selected_text(), "alpha");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExpandSelectionToScope);
+    assert_eq!(selection(&harness), Some(5..16));
+    assert_eq!(harness.get_selected_text(), "alpha, beta");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExpandSelectionToScope);
+    assert_eq!(harness.get_selected_text(), "(alpha, beta)");
+}
+
+/// Shrinking walks back through exactly the selections expansion produced
+#[test]
+fn test_shrink_selection_undoes_expand() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("call(alpha, beta);").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..7 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    for _ in 0..3 {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::ExpandSelectionToScope);
+    }
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShrinkSelection);
+    assert_eq!(selection(&harness), Some(5..16));
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShrinkSelection);
+    assert_eq!(selection(&harness), Some(5..10));
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShrinkSelection);
+    harness.assert_no_selection();
+    assert_eq!(harness.cursor_position(), 7);
+
+    // Nothing left to shrink
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShrinkSelection);
+    harness.assert_no_selection();
+    assert_eq!(harness.cursor_position(), 7);
+}
+
+/// Moving the cursor by other means forgets the expansion history
+#[test]
+fn test_shrink_selection_after_cursor_move_does_nothing() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("call(alpha, beta);").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..7 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExpandSelectionToScope);
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+    let moved = selection(&harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShrinkSelection);
+    assert_eq!(selection(&harness), moved);
+}
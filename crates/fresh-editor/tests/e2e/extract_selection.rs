@@ -0,0 +1,59 @@
+//! E2E tests for copying the selection into a new scratch buffer
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use std::fs;
+
+const SOURCE: &str =
+    "fn one() {}\nfn two() {}\nfn three() {}\nfn four() {}\nfn five() {}\nfn six() {}\n";
+
+/// Selecting lines 3–5 and extracting them opens a new buffer holding just
+/// those lines, highlighted as the same language, and leaves the file as it was
+#[test]
+fn test_extract_selection_to_new_buffer() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let file = harness.project_dir().unwrap().join("lib.rs");
+    fs::write(&file, SOURCE).unwrap();
+    harness.open_file(&file).unwrap();
+    let source_id = harness.editor().active_buffer();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExtractSelectionToNewBuffer);
+    harness.render().unwrap();
+
+    assert_ne!(harness.editor().active_buffer(), source_id);
+    harness.assert_buffer_content("fn three() {}\nfn four() {}\nfn five() {}\n");
+    assert_eq!(harness.editor().active_state().language, "rust");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::PrevBuffer);
+    assert_eq!(harness.editor().active_buffer(), source_id);
+    harness.assert_buffer_content(SOURCE);
+}
+
+/// Without a selection there is nothing to extract
+#[test]
+fn test_extract_selection_without_selection_does_nothing() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello").unwrap();
+    let source_id = harness.editor().active_buffer();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExtractSelectionToNewBuffer);
+
+    assert_eq!(harness.editor().active_buffer(), source_id);
+    harness.assert_buffer_content("hello");
+}
@@ -3080,6 +3080,58 @@ fn test_externally_creating_gitignore_loads_rules_via_poll() {
     );
 }
 
+/// A file created outside the editor in an expanded directory shows up in
+/// the file tree through the directory poll, with no manual refresh.
+#[test]
+fn test_externally_created_file_appears_in_tree_via_poll() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap().canonicalize().unwrap();
+
+    fs::write(project_root.join("existing.txt"), "").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.render().unwrap();
+
+    // Prime the dir-mtime store so the later change is detected as a
+    // difference, not as a first-sighting. Two ticks: spawn + receive.
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness.editor_mut().poll_file_tree_changes();
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness.editor_mut().poll_file_tree_changes();
+
+    // Sleep past 1s filesystem mtime granularity, then create the file.
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    let new_file = project_root.join("created_outside.txt");
+    fs::write(&new_file, "hello").unwrap();
+
+    let mut picked_up = false;
+    for _ in 0..50 {
+        harness.advance_time(std::time::Duration::from_secs(5));
+        harness.editor_mut().poll_file_tree_changes();
+        if harness
+            .editor()
+            .file_explorer()
+            .unwrap()
+            .tree()
+            .get_node_by_path(&new_file)
+            .is_some()
+        {
+            picked_up = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    assert!(
+        picked_up,
+        "externally created file should appear in the tree via the dir poll"
+    );
+
+    harness.render().unwrap();
+    harness.assert_screen_contains("created_outside.txt");
+}
+
 /// Helper: find the right border column of the file explorer on screen.
 ///
 /// Scans for the box-drawing corner characters that ratatui's `Block` draws
@@ -1509,3 +1509,36 @@ fn test_fold_unfold_at_end_of_large_file_gutter_click() {
     harness.assert_screen_contains("b_body_1");
     harness.assert_screen_contains("b_body_3");
 }
+
+/// Without any language server, opening a Python file computes indent-based
+/// folding ranges locally, so an indented block can be folded.
+#[test]
+fn test_local_folding_ranges_for_python_without_lsp() {
+    let content = "def foo():\n    first = 1\n    second = 2\nafter = 3\n";
+    let fixture = TestFixture::new("local_fold.py", content).unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    let resolved = {
+        let state = harness.editor().active_state();
+        state
+            .folding_ranges
+            .resolved(&state.buffer, &state.marker_list)
+    };
+    assert_eq!(
+        resolved
+            .iter()
+            .map(|r| (r.start_line, r.end_line))
+            .collect::<Vec<_>>(),
+        vec![(0, 2)]
+    );
+
+    let buffer_id = harness.editor().active_buffer();
+    harness.editor_mut().toggle_fold_at_line(buffer_id, 0);
+    harness.render().unwrap();
+    harness.assert_screen_contains("def foo():");
+    harness.assert_screen_not_contains("first = 1");
+    harness.assert_screen_not_contains("second = 2");
+    harness.assert_screen_contains("after = 3");
+}
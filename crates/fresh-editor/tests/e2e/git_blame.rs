@@ -0,0 +1,88 @@
+//! Tests for the native git blame annotations ("Toggle Git Blame").
+
+use crate::common::git_test_helper::GitTestRepo;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+fn row_of(harness: &EditorTestHarness, text: &str) -> u16 {
+    harness
+        .find_text_on_screen(text)
+        .unwrap_or_else(|| panic!("{:?} not on screen", text))
+        .1
+}
+
+/// Row of `text` once its row also shows `annotation`; blame and its line
+/// remap arrive in the background.
+fn wait_for_annotation(harness: &mut EditorTestHarness, text: &str, annotation: &str) -> String {
+    harness
+        .wait_until(|h| {
+            h.find_text_on_screen(text)
+                .is_some_and(|(_, row)| h.screen_row_text(row).contains(annotation))
+        })
+        .unwrap();
+    harness.screen_row_text(row_of(harness, text))
+}
+
+/// Committed lines show the author; a line edited in the buffer shows
+/// "uncommitted", and still does once it is saved.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_blame_marks_edited_line_uncommitted() {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", "alpha\nbeta\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let mut harness = EditorTestHarness::with_working_dir(100, 24, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleGitBlame);
+
+    wait_for_annotation(&mut harness, "alpha", "Test User");
+    assert!(!harness.screen_to_string().contains("uncommitted"));
+
+    // The cursor starts on the first line.
+    harness.type_text("new ").unwrap();
+
+    let edited_row = wait_for_annotation(&mut harness, "new alpha", "uncommitted");
+    assert!(!edited_row.contains("Test User"), "row: {:?}", edited_row);
+    let beta_row = harness.screen_row_text(row_of(&harness, "beta"));
+    assert!(beta_row.contains("Test User"), "row: {:?}", beta_row);
+
+    // Saving re-blames the file on disk, where the line isn't committed.
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    wait_for_annotation(&mut harness, "new alpha", "uncommitted");
+
+    // The buffer itself is untouched by the annotations.
+    assert_eq!(harness.get_buffer_content().unwrap(), "new alpha\nbeta\n");
+}
+
+/// Toggling again removes the annotations.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_toggle_git_blame_off() {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", "alpha\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let mut harness = EditorTestHarness::with_working_dir(100, 24, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleGitBlame);
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Test User"))
+        .unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleGitBlame);
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Test User");
+}
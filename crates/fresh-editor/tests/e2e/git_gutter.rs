@@ -0,0 +1,166 @@
+//! Tests for the native git gutter (`editor.show_git_gutter`).
+
+use crate::common::git_test_helper::GitTestRepo;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::input::keybindings::Action;
+
+/// Commit `notes.txt` in a fresh repo and open it with the git gutter on.
+fn open_committed_file() -> (GitTestRepo, EditorTestHarness) {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", "alpha\nbeta\ngamma\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let mut config = Config::default();
+    config.editor.show_git_gutter = true;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, config, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    wait_for_git_base(&mut harness);
+    (repo, harness)
+}
+
+/// Wait until the HEAD blob fetched in the background is the gutter's base.
+fn wait_for_git_base(harness: &mut EditorTestHarness) {
+    let buffer_id = harness.editor().active_buffer();
+    harness
+        .wait_until(|h| h.editor().has_git_gutter_base(buffer_id))
+        .unwrap();
+}
+
+/// Wait until the background diff puts `sign` in the gutter of the line
+/// showing `text`.
+fn wait_for_sign(harness: &mut EditorTestHarness, text: &str, sign: &str) {
+    harness
+        .wait_until(|h| {
+            h.find_text_on_screen(text)
+                .is_some_and(|(_, row)| h.get_cell(0, row).as_deref() == Some(sign))
+        })
+        .unwrap();
+}
+
+/// Editing a committed line puts a modified sign in that line's gutter,
+/// before the file is saved.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_marks_modified_line() {
+    let (_repo, mut harness) = open_committed_file();
+
+    let (_, beta_row) = harness.find_text_on_screen("beta").expect("beta on screen");
+    assert_eq!(harness.get_cell(0, beta_row).as_deref(), Some(" "));
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("!").unwrap();
+    wait_for_sign(&mut harness, "beta!", "~");
+
+    let (_, alpha_row) = harness
+        .find_text_on_screen("alpha")
+        .expect("alpha on screen");
+    assert_eq!(harness.get_cell(0, alpha_row).as_deref(), Some(" "));
+}
+
+/// A new line gets an added sign; removing it again clears the signs.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_marks_added_line_and_clears_when_reverted() {
+    let (_repo, mut harness) = open_committed_file();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("delta").unwrap();
+    wait_for_sign(&mut harness, "delta", "+");
+
+    for _ in 0.."delta".len() + 1 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    harness.assert_screen_not_contains("delta");
+    harness
+        .wait_until(|h| (0..24).all(|row| h.get_cell(0, row).as_deref() != Some("+")))
+        .unwrap();
+}
+
+/// Reverting the hunk under the cursor restores the committed lines, including
+/// a final line without a trailing newline, and can be undone in one step.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_revert_hunk_at_cursor_restores_head_and_is_undoable() {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", "alpha\nbeta\ngamma");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let mut config = Config::default();
+    config.editor.show_git_gutter = true;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, config, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    wait_for_git_base(&mut harness);
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("!").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("delta").unwrap();
+    harness.render().unwrap();
+    let edited = "alpha\nbeta\ngamma!\ndelta";
+    assert_eq!(harness.get_buffer_content().as_deref(), Some(edited));
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Revert Hunk").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("alpha\nbeta\ngamma")
+    );
+    wait_for_sign(&mut harness, "gamma", " ");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().as_deref(), Some(edited));
+}
+
+/// With the gutter off there is no base to reuse, so reverting fetches HEAD
+/// in the background and reverts once it arrives.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_revert_hunk_without_git_gutter() {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", "alpha\nbeta\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+
+    let mut harness = EditorTestHarness::with_working_dir(80, 24, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    harness.type_text("new ").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("new alpha\nbeta\n")
+    );
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RevertHunkAtCursor);
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("alpha\nbeta\n"))
+        .unwrap();
+}
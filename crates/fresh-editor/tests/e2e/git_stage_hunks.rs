@@ -0,0 +1,93 @@
+//! Tests for interactive hunk staging ("Stage Hunks", like `git add -p`).
+
+use crate::common::git_test_helper::GitTestRepo;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use std::process::Command;
+
+const COMMITTED: &str = "one\ntwo\nthree\nfour\nfive\n";
+const EDITED: &str = "one\nTWO\nthree\nfour\nFIVE\n";
+
+/// Commit `notes.txt`, change two separate lines on disk and open it.
+fn open_file_with_two_hunks() -> (GitTestRepo, EditorTestHarness) {
+    let repo = GitTestRepo::new();
+    repo.create_file("notes.txt", COMMITTED);
+    repo.git_add_all();
+    repo.git_commit("initial");
+    repo.modify_file("notes.txt", EDITED);
+
+    let mut harness = EditorTestHarness::with_working_dir(100, 24, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    (repo, harness)
+}
+
+fn index_content(repo: &GitTestRepo) -> String {
+    let output = Command::new("git")
+        .args(["show", ":notes.txt"])
+        .current_dir(&repo.path)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn answer(harness: &mut EditorTestHarness, key: &str) {
+    harness.type_text(key).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Staging the first hunk and skipping the second leaves only the first
+/// change in the index; the working tree keeps both.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_stage_one_of_two_hunks() {
+    let (repo, mut harness) = open_file_with_two_hunks();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::StageHunks);
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Stage hunk 1/2?"))
+        .unwrap();
+    harness.assert_screen_contains("Index");
+    harness.assert_screen_contains("Working tree");
+
+    answer(&mut harness, "y");
+    harness.assert_screen_contains("Stage hunk 2/2?");
+    answer(&mut harness, "n");
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Staged 1 hunk(s)"))
+        .unwrap();
+    assert_eq!(index_content(&repo), "one\nTWO\nthree\nfour\nfive\n");
+    assert_eq!(
+        std::fs::read_to_string(repo.path.join("notes.txt")).unwrap(),
+        EDITED
+    );
+    assert!(!harness
+        .editor()
+        .is_composite_buffer(harness.editor().active_buffer()));
+}
+
+/// Escape closes the staging view without touching the index.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_cancel_staging_leaves_index_untouched() {
+    let (repo, mut harness) = open_file_with_two_hunks();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::StageHunks);
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Stage hunk 1/2?"))
+        .unwrap();
+    answer(&mut harness, "y");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Staging cancelled");
+    assert_eq!(index_content(&repo), COMMITTED);
+}
@@ -1032,3 +1032,78 @@ fn test_shift_tab_dedent_does_not_dedent_line_at_selection_boundary() {
         "Shift+Tab should not dedent line 4 when selection ends at its start (issue #1304)"
     );
 }
+
+/// Test that Tab indents a three-line selection, skips the empty line in it,
+/// keeps the selection on the same lines, and undoes in one step
+#[test]
+fn test_tab_indent_three_lines_skips_empty_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "one\n\ntwo").unwrap();
+
+    let mut harness = harness_with_spaces();
+    harness.open_file(&file_path).unwrap();
+
+    // Select all three lines
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    one\n\n    two",
+        "The empty line should not gain trailing whitespace"
+    );
+
+    // The selection still spans the first through the third line
+    let selection = harness
+        .editor()
+        .active_cursors()
+        .primary()
+        .selection_range()
+        .expect("Selection should be preserved after indenting");
+    assert_eq!(selection, 4..16);
+
+    // One undo restores the original text
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one\n\ntwo");
+}
+
+/// Test that Shift+Tab removes one indent level from lines whose leading
+/// whitespace mixes tabs and spaces
+#[test]
+fn test_shift_tab_dedent_mixed_leading_whitespace() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "  \tfoo\n\t  bar\n      baz\n  qux").unwrap();
+
+    let mut harness = harness_with_spaces();
+    harness.open_file(&file_path).unwrap();
+
+    // Select all
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::BackTab, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+
+    // "  \t" spans one tab stop (4 columns) so it goes entirely; the others
+    // lose at most 4 columns of whitespace
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(content, "foo\n  bar\n  baz\nqux");
+
+    let selection = harness
+        .editor()
+        .active_cursors()
+        .primary()
+        .selection_range()
+        .expect("Selection should be preserved after dedenting");
+    assert_eq!(selection, 0..content.len());
+}
@@ -1,3 +1,4 @@
+use crate::common::fake_lsp::FakeLspServer;
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::model::event::{Event, OverlayFace};
@@ -255,3 +256,82 @@ fn test_multiline_diagnostic_highlight_visible_when_partially_scrolled() {
         "Overlay background should still be visible on line 4 after scrolling lines 1-3 out of view"
     );
 }
+
+/// A diagnostic published by a language server shows its message after the
+/// line's code. The message is display-only: the buffer is unchanged and End
+/// still puts the cursor right after the code.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_inline_diagnostic_from_lsp_follows_line_content() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    // One error on line 0, columns 0-5: "Error 0 from fake LSP".
+    let _fake_server = FakeLspServer::spawn_many_diagnostics(temp_dir.path(), 1).unwrap();
+
+    let content = "line 0 content here\nline 1 content here\n";
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, content).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.diagnostics_inline_text = true;
+    config.editor.line_numbers = false;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::many_diagnostics_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        10,
+        config,
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&test_file).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Error 0 from fake LSP"))
+        .unwrap();
+
+    let (code_x, code_row) = harness
+        .find_text_on_screen("line 0 content here")
+        .expect("line 0 should be on screen");
+    let (diag_x, diag_row) = harness
+        .find_text_on_screen("Error 0 from fake LSP")
+        .expect("the diagnostic message should be on screen");
+    assert_eq!(
+        diag_row, code_row,
+        "message should be on the diagnostic's line"
+    );
+    assert!(
+        diag_x > code_x + "line 0 content here".len() as u16,
+        "message should come after the line's code"
+    );
+    let (_, line1_row) = harness
+        .find_text_on_screen("line 1 content here")
+        .expect("line 1 should be on screen");
+    assert!(!harness
+        .screen_row_text(line1_row)
+        .contains("Error 0 from fake LSP"));
+
+    assert_eq!(harness.get_buffer_content().unwrap(), content);
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), "line 0 content here".len());
+    let (cursor_x, cursor_y) = harness.screen_cursor_position();
+    assert_eq!(cursor_y, code_row);
+    assert_eq!(cursor_x, code_x + "line 0 content here".len() as u16);
+}
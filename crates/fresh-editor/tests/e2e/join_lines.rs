@@ -0,0 +1,99 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+fn harness_with_file(content: &str) -> (EditorTestHarness, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+/// Ctrl+J collapses the trailing and leading whitespace around the line
+/// break into one space and leaves the cursor at the join point
+#[test]
+fn test_join_lines_collapses_whitespace() {
+    let (mut harness, _dir) = harness_with_file("foo   \n    bar\nbaz");
+
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo bar\nbaz");
+    assert_eq!(harness.cursor_position(), 3);
+
+    // A single undo restores both lines
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "foo   \n    bar\nbaz"
+    );
+}
+
+/// Joining with an empty next line only removes the line break
+#[test]
+fn test_join_lines_with_empty_next_line() {
+    let (mut harness, _dir) = harness_with_file("foo\n\nbar");
+
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo\nbar");
+    assert_eq!(harness.cursor_position(), 3);
+}
+
+/// Joining on the last line does nothing
+#[test]
+fn test_join_lines_on_last_line_is_noop() {
+    let (mut harness, _dir) = harness_with_file("foo\nbar");
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo\nbar");
+}
+
+/// A selection spanning three lines (ending mid-line) joins all three
+#[test]
+fn test_join_lines_selection_of_three_lines() {
+    let (mut harness, _dir) = harness_with_file("one\n  two\n  three\nfour");
+
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "one two three\nfour");
+    // The cursor sits at the last join point and the selection is gone
+    assert_eq!(harness.cursor_position(), 7);
+    harness.assert_no_selection();
+}
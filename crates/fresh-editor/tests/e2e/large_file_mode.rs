@@ -1769,3 +1769,129 @@ fn test_large_file_gutter_indicators_viewport_filtering() {
         screen_back_eof
     );
 }
+
+/// Opening a file above the large-file threshold must not read it into
+/// memory: only the chunks around the viewport are loaded, and jumping to
+/// the end loads the chunk holding the last lines without pulling in the
+/// rest of the file.
+#[test]
+fn test_large_file_memory_stays_bounded_while_scrolling() {
+    let big_txt_path = TestFixture::big_txt_for_test("memory_bounded_scroll").unwrap();
+    let file_size = std::fs::metadata(&big_txt_path).unwrap().len() as usize;
+    // A few load chunks, far below the 61MB fixture
+    let budget = 8 * 1024 * 1024;
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&big_txt_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("@00000000:");
+
+    let loaded = harness.editor().loaded_buffer_bytes();
+    assert!(
+        loaded < budget,
+        "opening loaded {} of {} bytes",
+        loaded,
+        file_size
+    );
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("@00000000:");
+    assert!(
+        harness.cursor_position() + 100 >= file_size,
+        "cursor should reach the end of the file"
+    );
+
+    let loaded = harness.editor().loaded_buffer_bytes();
+    assert!(
+        loaded < budget,
+        "scrolling to the end loaded {} of {} bytes",
+        loaded,
+        file_size
+    );
+}
+
+/// With `large_file_max_resident_chunks` set, jumping back and forth across
+/// a large file keeps only that many chunks in memory, and chunks dropped
+/// from the cache are re-read correctly when revisited.
+#[test]
+fn test_large_file_chunk_cache_respects_resident_limit() {
+    let big_txt_path = TestFixture::big_txt_for_test("chunk_cache_limit").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.large_file_max_resident_chunks = 2;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&big_txt_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("@00000000:");
+
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::End, KeyModifiers::CONTROL)
+            .unwrap();
+        harness.render().unwrap();
+        harness.assert_screen_not_contains("@00000000:");
+
+        harness
+            .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+            .unwrap();
+        harness.render().unwrap();
+        harness.assert_screen_contains("@00000000:");
+    }
+
+    let stats = harness.editor().chunk_cache_stats();
+    assert!(
+        stats.resident <= 2,
+        "expected at most 2 resident chunks, got {:?}",
+        stats
+    );
+    assert!(
+        stats.misses > 2,
+        "revisiting evicted chunks should re-read them: {:?}",
+        stats
+    );
+}
+
+/// Saving a large file past `background_save_threshold_bytes` writes it on a
+/// background thread: the buffer is read-only until the write finishes, then
+/// it is marked saved and the file on disk has the edit plus every chunk.
+#[test]
+fn test_large_file_background_save_writes_all_chunks() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("multi_chunk.txt");
+    let line = "0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrst\n";
+    let original = line.repeat(3 * 1024 * 1024 / line.len() + 1);
+    std::fs::write(&file_path, &original).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.large_file_threshold_bytes = 1024;
+    config.editor.background_save_threshold_bytes = 1;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.type_text("EDIT ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(
+        harness.editor().active_state().editing_disabled,
+        "edits should be blocked while the save is in flight"
+    );
+
+    harness
+        .wait_until(|h| !h.editor().active_state().buffer.is_modified())
+        .unwrap();
+    assert!(!harness.editor().active_state().editing_disabled);
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Saved")
+    );
+
+    let saved = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(saved.len(), original.len() + 5);
+    assert!(saved.starts_with("EDIT 0123"));
+    assert_eq!(&saved[5..], original);
+}
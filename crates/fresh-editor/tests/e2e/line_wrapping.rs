@@ -1908,6 +1908,65 @@ fn test_visual_line_movement_up_down() {
     );
 }
 
+/// Down walks through every wrapped segment of a long line before moving to
+/// the next logical line, keeping the same visual column the whole way, and
+/// Up retraces the same segments
+#[test]
+fn test_visual_line_movement_through_all_wrapped_segments() {
+    const TERMINAL_WIDTH: u16 = 60;
+    const TERMINAL_HEIGHT: u16 = 24;
+
+    let mut harness = EditorTestHarness::new(TERMINAL_WIDTH, TERMINAL_HEIGHT).unwrap();
+
+    // No spaces, so the line breaks exactly at the text width
+    let long_line = "0123456789".repeat(12);
+    harness.type_text(&long_line).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("Second logical line.").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let (start_x, start_y) = harness.screen_cursor_position();
+
+    let mut positions = Vec::new();
+    for row in 1..=3u16 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+        positions.push(harness.cursor_position());
+        assert_eq!(
+            harness.screen_cursor_position(),
+            (start_x, start_y + row),
+            "Down #{row} should move one row and keep the visual column"
+        );
+    }
+
+    // Two Downs stay inside the long line, one segment width apart
+    let segment_width = positions[0] - 5;
+    assert!(positions[0] < long_line.len() && positions[1] < long_line.len());
+    assert_eq!(positions[1], positions[0] + segment_width);
+    assert!(
+        long_line.len() <= segment_width * 3,
+        "Long line should wrap onto exactly three rows"
+    );
+    // The third reaches the next logical line at the same column
+    assert_eq!(positions[2], long_line.len() + 1 + 5);
+
+    // Up from the next logical line lands on the last wrapped segment
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), positions[1]);
+    assert_eq!(harness.screen_cursor_position(), (start_x, start_y + 2));
+}
+
 /// Test that End key navigates to end of visual (wrapped) line segment
 /// and Home key navigates to start of visual segment
 /// This is the fix for issue #979: End key should go to end of visual line, not physical line
@@ -5727,9 +5727,94 @@ fn test_completion_snippet_with_default() -> anyhow::Result<()> {
         "Snippet should expand with default text"
     );
 
-    // Verify cursor is at $0 position (after the 4 spaces on line 2)
+    // The first tabstop's placeholder is selected
+    let cursor = *harness.editor().active_cursors().primary();
+    assert_eq!((cursor.anchor, cursor.position), (Some(3), 7));
+
+    // Tab moves to the empty second tabstop inside the parens
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    let cursor = *harness.editor().active_cursors().primary();
+    assert_eq!((cursor.anchor, cursor.position), (None, 8));
+
+    // Shift+Tab goes back to the first one
+    harness.send_key(KeyCode::BackTab, KeyModifiers::NONE)?;
+    let cursor = *harness.editor().active_cursors().primary();
+    assert_eq!((cursor.anchor, cursor.position), (Some(3), 7));
+
+    // Past the last tabstop the cursor lands on $0 (after the 4 spaces on
+    // line 2), and no tab was inserted along the way
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
     let cursor_pos = harness.editor().active_cursors().primary().position;
     assert_eq!(cursor_pos, 16, "Cursor should be at $0 position");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn name() {\n    \n}"
+    );
+
+    Ok(())
+}
+
+/// Typing into a snippet tabstop edits all of its occurrences
+#[test]
+fn test_completion_snippet_mirrored_tabstop() -> anyhow::Result<()> {
+    use fresh::model::event::{
+        Event, PopupContentData, PopupData, PopupKindHint, PopupListItemData, PopupPositionData,
+    };
+
+    let mut harness = EditorTestHarness::new(80, 24)?;
+    harness.type_text("le")?;
+
+    harness.apply_event(Event::ShowPopup {
+        popup: PopupData {
+            kind: PopupKindHint::Completion,
+            title: Some("Completion".to_string()),
+            description: None,
+            transient: false,
+            content: PopupContentData::List {
+                items: vec![PopupListItemData {
+                    text: "let".to_string(),
+                    detail: None,
+                    icon: None,
+                    data: Some("let ${1:x} = ${2:0};\nprint($1)$0".to_string()),
+                }],
+                selected: 0,
+            },
+            position: PopupPositionData::BelowCursor,
+            width: 50,
+            max_height: 15,
+            bordered: true,
+        },
+    })?;
+    harness.render()?;
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let x = 0;\nprint(x)"
+    );
+
+    harness.type_text("total")?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let total = 0;\nprint(total)"
+    );
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    harness.type_text("42")?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "let total = 42;\nprint(total)"
+    );
+
+    // Escape ends the snippet: Tab indents again
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    assert_eq!(harness.editor().active_cursors().count(), 1);
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    assert_ne!(
+        harness.get_buffer_content().unwrap(),
+        "let total = 42;\nprint(total)"
+    );
 
     Ok(())
 }
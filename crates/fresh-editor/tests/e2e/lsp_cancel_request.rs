@@ -0,0 +1,110 @@
+//! E2E tests for LSP request cancellation: moving the cursor while a
+//! completion or hover request is in flight sends `$/cancelRequest`, and the
+//! reply the server sends anyway is dropped instead of opening a popup.
+
+use crate::common::fake_lsp::{diagnostics_server_config, write_slow_reply_server};
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use std::path::{Path, PathBuf};
+
+fn log_contents(log_file: &Path) -> String {
+    std::fs::read_to_string(log_file).unwrap_or_default()
+}
+
+/// An editor with `main.rs` open, the cursor at the end of `stale`, and a
+/// server that holds back its answer to `method` until it is cancelled.
+fn harness_with_slow_server(
+    project_root: PathBuf,
+    method: &str,
+    late_result: &str,
+) -> anyhow::Result<(EditorTestHarness, PathBuf)> {
+    let log_file = project_root.join("lsp_log.txt");
+    let server = write_slow_reply_server(&project_root, &log_file, method, late_result)?;
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {\n    stale\n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config("slow", &server)]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| log_contents(&log_file).contains("ACTION: initialized"))?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    Ok((harness, log_file))
+}
+
+/// Move the cursor once the server has the request, then check that the
+/// cancel names it and that the late reply, once processed, left no popup
+/// showing `stale_text`.
+fn move_cursor_and_check_cancelled(
+    harness: &mut EditorTestHarness,
+    log_file: &Path,
+    stale_text: &str,
+) -> anyhow::Result<()> {
+    harness.wait_until(|_| log_contents(log_file).contains("RECV: request"))?;
+
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE)?;
+    harness.wait_until(|_| log_contents(log_file).contains("SENT: late reply"))?;
+
+    let log = log_contents(log_file);
+    let request_id = log
+        .lines()
+        .find_map(|line| line.strip_prefix("RECV: request "))
+        .expect("request should be logged");
+    assert!(
+        log.contains(&format!("RECV: cancel {request_id}")),
+        "$/cancelRequest should name the request. Log:\n{log}"
+    );
+
+    // The warning is sent after the late reply, so once it shows the reply
+    // has been processed.
+    harness.wait_until(|h| h.get_status_bar().contains("late reply sent"))?;
+    let screen = harness.screen_to_string();
+    assert!(
+        !screen.contains(stale_text),
+        "a cancelled request must not open a popup. Screen:\n{screen}"
+    );
+    Ok(())
+}
+
+/// Request completion, move the cursor before the server answers: the editor
+/// cancels the request it sent, and the late reply opens no popup.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_cursor_move_cancels_in_flight_completion() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let (mut harness, log_file) = harness_with_slow_server(
+        temp_dir.path().canonicalize()?,
+        "textDocument/completion",
+        r#"[{"label":"stale_completion_item"}]"#,
+    )?;
+
+    harness.send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)?;
+    move_cursor_and_check_cancelled(&mut harness, &log_file, "stale_completion_item")
+}
+
+/// Same for hover: moving away cancels the hover request and its late
+/// reply opens no hover popup.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_cursor_move_cancels_in_flight_hover() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let (mut harness, log_file) = harness_with_slow_server(
+        temp_dir.path().canonicalize()?,
+        "textDocument/hover",
+        r#"{"contents":{"kind":"markdown","value":"stale_hover_text"}}"#,
+    )?;
+
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE)?;
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::LspHover);
+    move_cursor_and_check_cancelled(&mut harness, &log_file, "stale_hover_text")
+}
@@ -879,3 +879,37 @@ fn test_completion_popup_aligns_with_word_start() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Typing narrows the open popup by fuzzy-matching the stored items, without
+/// asking the server again, and backspace brings the filtered items back.
+#[test]
+fn test_completion_filters_locally_without_new_request() -> anyhow::Result<()> {
+    let mut harness = setup_completion_popup("calc")?;
+
+    // "calcs" is not a prefix of any item, but fuzzy-matches calculate_sum.
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::NONE)?;
+    harness.render()?;
+
+    assert!(harness.editor().active_state().popups.is_visible());
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("calculate_sum"), "screen:\n{screen}");
+    assert!(
+        !screen.contains("calculate_difference"),
+        "screen:\n{screen}"
+    );
+    assert!(!screen.contains("calculate_product"), "screen:\n{screen}");
+
+    // The stored list is untouched and nothing was re-requested.
+    assert_eq!(harness.editor().completion_items_count(), 3);
+    assert_eq!(harness.editor().pending_completion_requests_count(), 0);
+
+    harness.send_key(KeyCode::Backspace, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("calculate_difference"), "screen:\n{screen}");
+    assert!(screen.contains("calculate_product"), "screen:\n{screen}");
+    assert!(screen.contains("calculate_sum"), "screen:\n{screen}");
+
+    Ok(())
+}
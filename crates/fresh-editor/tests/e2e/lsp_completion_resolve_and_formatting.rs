@@ -1,4 +1,5 @@
-//! E2E tests for completionItem/resolve and textDocument/formatting.
+//! E2E tests for completionItem/resolve, textDocument/formatting and
+//! textDocument/rangeFormatting (on command and on save).
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -6,7 +7,8 @@ use crossterm::event::{KeyCode, KeyModifiers};
 /// Create a fake LSP server that supports:
 /// - Completion with additional_text_edits (auto-imports)
 /// - completionItem/resolve (adds additional_text_edits on resolve)
-/// - textDocument/formatting (returns edits to reformat)
+/// - textDocument/formatting (returns an edit indenting line 2)
+/// - textDocument/rangeFormatting (same edit, logged separately)
 /// - Logs all received methods to a file for verification
 fn create_completion_and_formatting_lsp_script(dir: &std::path::Path) -> std::path::PathBuf {
     let script = r##"#!/bin/bash
@@ -53,7 +55,7 @@ while true; do
     case "$method" in
         "initialize")
             # Completion with resolveProvider, formatting support
-            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":2,"completionProvider":{"resolveProvider":true,"triggerCharacters":["."]},"documentFormattingProvider":true,"diagnosticProvider":{"interFileDependencies":false,"workspaceDiagnostics":false},"inlayHintProvider":{"resolveProvider":false}}}}'
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":2,"completionProvider":{"resolveProvider":true,"triggerCharacters":["."]},"documentFormattingProvider":true,"documentRangeFormattingProvider":true,"diagnosticProvider":{"interFileDependencies":false,"workspaceDiagnostics":false},"inlayHintProvider":{"resolveProvider":false}}}}'
             ;;
         "textDocument/didOpen")
             DOC_URI=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
@@ -72,8 +74,13 @@ while true; do
         "textDocument/formatting")
             echo "FORMAT_BODY:$msg" >> "$LOG_FILE"
             echo "---" >> "$LOG_FILE"
-            # Return edits that add a newline at end of file and fix indentation
-            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":4}},"newText":"    "}]}'
+            # Return an edit that reindents the second line
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":0}},"newText":"    "}]}'
+            ;;
+        "textDocument/rangeFormatting")
+            echo "RANGE_FORMAT_BODY:$msg" >> "$LOG_FILE"
+            echo "---" >> "$LOG_FILE"
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":0}},"newText":"    "}]}'
             ;;
         "textDocument/diagnostic")
             send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"items":[]}}'
@@ -109,12 +116,29 @@ fn setup_editor(
     initial_content: &str,
     file_ext: &str,
     language: &str,
+) -> anyhow::Result<(EditorTestHarness, std::path::PathBuf)> {
+    setup_editor_with_config(
+        temp_dir,
+        log_file,
+        initial_content,
+        file_ext,
+        language,
+        fresh::config::Config::default(),
+    )
+}
+
+fn setup_editor_with_config(
+    temp_dir: &tempfile::TempDir,
+    log_file: &std::path::Path,
+    initial_content: &str,
+    file_ext: &str,
+    language: &str,
+    mut config: fresh::config::Config,
 ) -> anyhow::Result<(EditorTestHarness, std::path::PathBuf)> {
     let script_path = create_completion_and_formatting_lsp_script(temp_dir.path());
     let test_file = temp_dir.path().join(format!("test.{}", file_ext));
     std::fs::write(&test_file, initial_content)?;
 
-    let mut config = fresh::config::Config::default();
     config.lsp.insert(
         language.to_string(),
         fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
@@ -250,3 +274,150 @@ fn test_format_buffer_via_lsp() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test: the edits of one Format Buffer are undone in a single step.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_buffer_is_one_undo_step() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("lsp_format_undo_log.txt");
+    let (mut harness, _test_file) = setup_editor(
+        &temp_dir,
+        &log_file,
+        "function main()\nlocal x = 5\nend\n",
+        "lua",
+        "lua",
+    )?;
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("format buffer")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    harness.wait_for_screen_contains("Formatted")?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap_or_default(),
+        "function main()\n    local x = 5\nend\n"
+    );
+
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap_or_default(),
+        "function main()\nlocal x = 5\nend\n"
+    );
+
+    Ok(())
+}
+
+/// Test: with a selection, Format Buffer sends textDocument/rangeFormatting
+/// for the selected range.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_selection_uses_range_formatting() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("lsp_range_format_log.txt");
+    let (mut harness, _test_file) = setup_editor(
+        &temp_dir,
+        &log_file,
+        "function main()\nlocal x = 5\nend\n",
+        "lua",
+        "lua",
+    )?;
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    // Select the second line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT)?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("format buffer")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    harness.wait_for_screen_contains("Formatted")?;
+    let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+    assert!(log.contains("METHOD:textDocument/rangeFormatting"));
+    assert!(
+        log.contains(r#""start":{"line":1,"character":0},"end":{"line":1,"character":11}"#),
+        "range should cover the selected line. Log:\n{}",
+        log
+    );
+    assert!(!log.contains("METHOD:textDocument/formatting"));
+    assert_eq!(
+        harness.get_buffer_content().unwrap_or_default(),
+        "function main()\n    local x = 5\nend\n"
+    );
+
+    Ok(())
+}
+
+/// Test: with `format_on_save` and no external formatter, saving asks the
+/// language server to format first, writes the formatted text once, and
+/// keeps the cursor on the same code.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_on_save_via_lsp() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("lsp_format_on_save_log.txt");
+    let mut config = fresh::config::Config::default();
+    config
+        .languages
+        .get_mut("lua")
+        .expect("lua language config")
+        .format_on_save = true;
+    let (mut harness, test_file) = setup_editor_with_config(
+        &temp_dir,
+        &log_file,
+        "function main()\nlocal x = 5\nend\n",
+        "lua",
+        "lua",
+        config,
+    )?;
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    // Put the cursor on `x` and make an edit so there is something to save
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text("0")?;
+    for _ in 0..6 {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE)?;
+    }
+
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&test_file).unwrap_or_default()
+            == "function main()\n    local x = 50\nend\n"
+    })?;
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap_or_default(),
+        "function main()\n    local x = 50\nend\n"
+    );
+    assert!(
+        !harness.editor().active_state().buffer.is_modified(),
+        "formatted buffer should be saved"
+    );
+    // Formatting happens before the write, so there is a single save
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("METHOD:textDocument/didSave")
+    })?;
+    let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+    let formatted_at = log.find("METHOD:textDocument/formatting");
+    let saved_at = log.find("METHOD:textDocument/didSave");
+    assert!(
+        formatted_at.is_some() && formatted_at < saved_at,
+        "formatting should be requested before the save. Log:\n{}",
+        log
+    );
+    assert_eq!(log.matches("METHOD:textDocument/didSave").count(), 1);
+    let cursor = harness.cursor_position();
+    let content = harness.get_buffer_content().unwrap_or_default();
+    assert_eq!(
+        &content[cursor..cursor + 1],
+        "x",
+        "cursor should stay on `x` after reindenting"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! E2E tests for next/previous diagnostic navigation on diagnostics
+//! published by a language server: position order, wrap-around, the jump
+//! list, and the `diagnostics_panel_severities` filter.
+
+use crate::common::fake_lsp::{
+    diagnostic_json, diagnostics_server_config, write_diagnostics_server,
+};
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::DiagnosticsPanelSeverity;
+
+/// Open a 12-line `main.rs` against a server publishing `diagnostics` and
+/// wait until they are stored.
+fn open_with_diagnostics(
+    temp_dir: &tempfile::TempDir,
+    mut config: fresh::config::Config,
+    diagnostics: &[String],
+) -> anyhow::Result<EditorTestHarness> {
+    let project_root = temp_dir.path().canonicalize()?;
+    let server = write_diagnostics_server(
+        &project_root,
+        "navigation",
+        &format!("[{}]", diagnostics.join(",")),
+    )?;
+
+    let test_file = project_root.join("main.rs");
+    let content: String = (0..12).map(|i| format!("fn f{i}() {{}}\n")).collect();
+    std::fs::write(&test_file, content)?;
+
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config(
+            "navigation",
+            &server,
+        )]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    let expected = diagnostics.len();
+    harness.wait_until(|h| {
+        h.editor()
+            .get_stored_diagnostics()
+            .values()
+            .any(|diags| diags.len() == expected)
+    })?;
+    harness.process_async_and_render()?;
+
+    Ok(harness)
+}
+
+fn cursor_line(harness: &EditorTestHarness) -> usize {
+    let position = harness.cursor_position();
+    harness
+        .editor()
+        .active_state()
+        .buffer
+        .get_line_number(position)
+}
+
+/// From line 0, "next diagnostic" visits line 2, then line 10, then wraps
+/// back to line 2 with a status message. Navigate Back returns to line 10.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_next_diagnostic_in_position_order_and_wraps() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    // Published out of order; navigation follows buffer positions.
+    let diagnostics = [
+        diagnostic_json(10, 1, "late problem", "fake"),
+        diagnostic_json(2, 2, "early problem", "fake"),
+    ];
+    let mut harness =
+        open_with_diagnostics(&temp_dir, fresh::config::Config::default(), &diagnostics)?;
+    assert_eq!(cursor_line(&harness), 0);
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE)?;
+    assert_eq!(cursor_line(&harness), 2);
+    assert!(harness.get_status_bar().contains("early problem"));
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE)?;
+    assert_eq!(cursor_line(&harness), 10);
+    assert!(harness.get_status_bar().contains("late problem"));
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE)?;
+    assert_eq!(cursor_line(&harness), 2);
+    assert!(
+        harness.get_status_bar().contains("Wrapped around"),
+        "wrapping should be reported. Status: {}",
+        harness.get_status_bar()
+    );
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT)?;
+    assert_eq!(
+        cursor_line(&harness),
+        10,
+        "Navigate Back should return to where the jump started"
+    );
+
+    Ok(())
+}
+
+/// Severities left out of `diagnostics_panel_severities` are skipped.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_diagnostic_navigation_skips_filtered_severities() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let mut config = fresh::config::Config::default();
+    config.editor.diagnostics_panel_severities = vec![DiagnosticsPanelSeverity::Error];
+    let diagnostics = [
+        diagnostic_json(2, 2, "a warning", "fake"),
+        diagnostic_json(5, 1, "an error", "fake"),
+    ];
+    let mut harness = open_with_diagnostics(&temp_dir, config, &diagnostics)?;
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE)?;
+    assert_eq!(cursor_line(&harness), 5, "the warning on line 2 is skipped");
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::SHIFT)?;
+    assert_eq!(
+        cursor_line(&harness),
+        5,
+        "the only error is the one under the cursor"
+    );
+
+    Ok(())
+}
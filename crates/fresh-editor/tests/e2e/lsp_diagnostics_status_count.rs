@@ -0,0 +1,192 @@
+//! E2E test for the status bar's diagnostics summary (`E:2 W:1`): it
+//! reflects the diagnostics a server publishes and clears when they do.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// A fake server that publishes two errors and a warning when a document
+/// opens, and clears them on the first change.
+fn write_counting_server(dir: &std::path::Path) -> anyhow::Result<std::path::PathBuf> {
+    let script = format!(
+        r##"#!/bin/bash
+
+DIAGNOSTICS='[{{"range":{{"start":{{"line":0,"character":0}},"end":{{"line":0,"character":2}}}},"severity":1,"message":"first error"}},{{"range":{{"start":{{"line":1,"character":0}},"end":{{"line":1,"character":2}}}},"severity":1,"message":"second error"}},{{"range":{{"start":{{"line":2,"character":0}},"end":{{"line":2,"character":2}}}},"severity":2,"message":"a warning"}}]'
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+    uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1}}}}}}'
+            ;;
+        "textDocument/didOpen")
+            send_message '{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{{"uri":"'$uri'","diagnostics":'"$DIAGNOSTICS"'}}}}'
+            ;;
+        "textDocument/didChange")
+            send_message '{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{{"uri":"'$uri'","diagnostics":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_counting_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// Two errors and a warning show as `E:2 W:1`; once the server clears them
+/// the summary disappears.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_status_bar_counts_diagnostics_by_severity() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let script_path = write_counting_server(&project_root)?;
+
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn a() {}\nfn b() {}\nfn c() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|h| h.get_status_bar().contains("E:2 W:1"))?;
+
+    harness.send_key(KeyCode::Char('x'), KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        let status = h.get_status_bar();
+        !status.contains("E:") && !status.contains("W:")
+    })?;
+
+    Ok(())
+}
+
+/// "Toggle Diagnostics" hides the underlines and the summary while the
+/// server keeps running, and brings them back without asking it again.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_toggle_diagnostics_hides_and_restores_them() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let script_path = write_counting_server(&project_root)?;
+
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn a() {}\nfn b() {}\nfn c() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|h| h.get_status_bar().contains("E:2 W:1"))?;
+
+    let diagnostic_overlays = |h: &EditorTestHarness| {
+        let ns = fresh::services::lsp::diagnostics::lsp_diagnostic_namespace();
+        h.editor()
+            .active_state()
+            .overlays
+            .all()
+            .iter()
+            .filter(|o| o.namespace.as_ref() == Some(&ns))
+            .count()
+    };
+    assert_eq!(diagnostic_overlays(&harness), 3);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::ToggleDiagnostics);
+    harness.render()?;
+    assert_eq!(diagnostic_overlays(&harness), 0);
+    assert!(!harness.get_status_bar().contains("E:2"));
+
+    // Shown again straight from the stored diagnostics, no server round-trip
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::ToggleDiagnostics);
+    harness.render()?;
+    assert_eq!(diagnostic_overlays(&harness), 3);
+    assert!(harness.get_status_bar().contains("E:2 W:1"));
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! E2E tests for diagnostic codes and "Explain Diagnostic": the hover card
+//! shows a diagnostic's code, and explaining it opens the code's
+//! documentation or, without one, shows the full message.
+
+use crate::common::fake_lsp::{diagnostics_server_config, write_diagnostics_server};
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use std::sync::Mutex;
+
+/// URLs passed to the test opener
+static OPENED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_url(url: &str) -> std::io::Result<()> {
+    OPENED.lock().unwrap().push(url.to_string());
+    Ok(())
+}
+
+/// An error with a documented code on line 1 and a warning with a bare
+/// numeric code on line 2
+const CODED_DIAGNOSTICS: &str = r#"[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":2}},"severity":1,"code":"E0308","codeDescription":{"href":"https://example.com/E0308"},"source":"rustc","message":"mismatched types"},{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":2}},"severity":2,"code":42,"source":"lint","message":"unused thing\nsee the full explanation"}]"#;
+
+/// An error whose documentation link is a local file
+const FILE_LINK_DIAGNOSTICS: &str = r#"[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":2}},"severity":1,"code":"E1","codeDescription":{"href":"file:///tmp/E1.html"},"source":"tool","message":"bad thing"}]"#;
+
+/// An editor with `main.rs` open and the fake server's diagnostics in,
+/// once the status bar shows `counts`.
+fn harness_with_diagnostics(
+    project_root: std::path::PathBuf,
+    diagnostics: &str,
+    counts: &str,
+) -> anyhow::Result<EditorTestHarness> {
+    let server = write_diagnostics_server(&project_root, "coded", diagnostics)?;
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn a() {}\nfn b() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config("coded", &server)]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|h| h.get_status_bar().contains(counts))?;
+    Ok(harness)
+}
+
+/// The hover card shows the code next to the severity, and explaining the
+/// diagnostic hands its `codeDescription.href` to the URL opener.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_explain_diagnostic_opens_code_description() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let mut harness = harness_with_diagnostics(project_root, CODED_DIAGNOSTICS, "E:1 W:1")?;
+    harness.editor_mut().set_url_opener(record_url);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::LspHover);
+    harness.wait_until(|h| h.screen_to_string().contains("[E0308]"))?;
+    harness.assert_screen_contains("mismatched types");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExplainDiagnostic);
+    harness.render()?;
+
+    assert_eq!(
+        *OPENED.lock().unwrap(),
+        vec!["https://example.com/E0308".to_string()]
+    );
+    harness.assert_screen_contains("Opening: https://example.com/E0308");
+
+    Ok(())
+}
+
+/// Without a documentation link, explaining shows the source, the code
+/// and every line of the message in a popup.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_explain_diagnostic_without_link_shows_full_message() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let mut harness = harness_with_diagnostics(project_root, CODED_DIAGNOSTICS, "E:1 W:1")?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExplainDiagnostic);
+    harness.render()?;
+
+    harness.assert_screen_contains("lint [42]");
+    harness.assert_screen_contains("unused thing");
+    harness.assert_screen_contains("see the full explanation");
+
+    Ok(())
+}
+
+/// A link that is not http(s) is never opened; it is shown in the popup
+/// with the message.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_explain_diagnostic_shows_non_web_link() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let mut harness = harness_with_diagnostics(project_root, FILE_LINK_DIAGNOSTICS, "E:1")?;
+    harness.editor_mut().set_url_opener(|url| {
+        panic!("{} should not be opened", url);
+    });
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ExplainDiagnostic);
+    harness.render()?;
+
+    harness.assert_screen_contains("tool [E1]");
+    harness.assert_screen_contains("bad thing");
+    harness.assert_screen_contains("file:///tmp/E1.html");
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+//! E2E tests for LSP Goto Definition navigation: `LocationLink` results,
+//! the picker shown for multiple results, and the jump-list entry that lets
+//! "Navigate Back" return to the call site.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server that answers every `textDocument/definition`
+/// request with `result` (raw JSON) and logs each answer to `log_file`.
+fn write_definition_server(dir: &Path, log_file: &Path, result: &str) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+RESULT='{result}'
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"definitionProvider":true,"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/definition")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":'"$RESULT"'}}'
+            echo "SENT: definition" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "textDocument/semanticTokens/full"|"textDocument/semanticTokens/full/delta"|"textDocument/semanticTokens/range")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"data":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_definition_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// Open `main.rs` with the fake server attached, wait for it to initialize,
+/// then put the cursor on line 2 and request Goto Definition.
+fn goto_definition_from_main(
+    project_root: &Path,
+    result: &str,
+) -> anyhow::Result<(EditorTestHarness, PathBuf)> {
+    let log_file = project_root.join("lsp_log.txt");
+    let script_path = write_definition_server(project_root, &log_file, result)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        project_root.to_path_buf(),
+    )?;
+    harness.open_file(&project_root.join("main.rs"))?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("ACTION: initialized")
+    })?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    harness.process_async_and_render()?;
+    harness.send_key(KeyCode::F(12), KeyModifiers::NONE)?;
+
+    Ok((harness, log_file))
+}
+
+/// Project with `main.rs` calling into `lib.rs`. Returns the canonical root
+/// (URIs must match the editor's canonicalized paths on macOS).
+fn create_project(temp_dir: &tempfile::TempDir) -> anyhow::Result<PathBuf> {
+    let project_root = temp_dir.path().canonicalize()?;
+    std::fs::write(
+        project_root.join("main.rs"),
+        "fn main() {\n    helper();\n}\n",
+    )?;
+    std::fs::write(
+        project_root.join("lib.rs"),
+        "// helpers\n\nfn other() {}\n\nfn helper() {}\n",
+    )?;
+    Ok(project_root)
+}
+
+fn active_file_name(harness: &EditorTestHarness) -> String {
+    harness
+        .editor()
+        .active_state()
+        .buffer
+        .file_path()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn cursor_line(harness: &EditorTestHarness) -> usize {
+    let position = harness.cursor_position();
+    harness
+        .editor()
+        .active_state()
+        .buffer
+        .get_line_number(position)
+}
+
+/// A `LocationLink[]` answer jumps to the link's target selection range, and
+/// Navigate Back returns to the call site.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_goto_definition_location_link_and_navigate_back() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = create_project(&temp_dir)?;
+    let lib_uri = format!("file://{}", project_root.join("lib.rs").display());
+    let result = format!(
+        r#"[{{"targetUri":"{lib_uri}","targetRange":{{"start":{{"line":4,"character":0}},"end":{{"line":4,"character":14}}}},"targetSelectionRange":{{"start":{{"line":4,"character":3}},"end":{{"line":4,"character":9}}}}}}]"#
+    );
+
+    let (mut harness, _log_file) = goto_definition_from_main(&project_root, &result)?;
+
+    harness.wait_until(|h| active_file_name(h) == "lib.rs")?;
+    assert_eq!(cursor_line(&harness), 4, "cursor should land on helper()");
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT)?;
+    harness.wait_until(|h| active_file_name(h) == "main.rs")?;
+    assert_eq!(
+        cursor_line(&harness),
+        1,
+        "Navigate Back should return to the call site"
+    );
+
+    Ok(())
+}
+
+/// Several definitions open a picker; choosing an entry jumps there.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_goto_definition_multiple_results_shows_picker() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = create_project(&temp_dir)?;
+    let lib_uri = format!("file://{}", project_root.join("lib.rs").display());
+    let result = format!(
+        r#"[{{"uri":"{lib_uri}","range":{{"start":{{"line":2,"character":3}},"end":{{"line":2,"character":8}}}}}},{{"uri":"{lib_uri}","range":{{"start":{{"line":4,"character":3}},"end":{{"line":4,"character":9}}}}}}]"#
+    );
+
+    let (mut harness, _log_file) = goto_definition_from_main(&project_root, &result)?;
+
+    harness.wait_for_prompt()?;
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("lib.rs:3:4") && screen.contains("lib.rs:5:4")
+    })?;
+    assert_eq!(
+        active_file_name(&harness),
+        "main.rs",
+        "nothing should be opened before a result is picked"
+    );
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| active_file_name(h) == "lib.rs")?;
+    assert_eq!(cursor_line(&harness), 4);
+
+    Ok(())
+}
+
+/// Typing in the definition picker narrows the list like the other pickers.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_goto_definition_picker_filters_on_input() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = create_project(&temp_dir)?;
+    let lib_uri = format!("file://{}", project_root.join("lib.rs").display());
+    let result = format!(
+        r#"[{{"uri":"{lib_uri}","range":{{"start":{{"line":2,"character":3}},"end":{{"line":2,"character":8}}}}}},{{"uri":"{lib_uri}","range":{{"start":{{"line":4,"character":3}},"end":{{"line":4,"character":9}}}}}}]"#
+    );
+
+    let (mut harness, _log_file) = goto_definition_from_main(&project_root, &result)?;
+
+    harness.wait_for_prompt()?;
+    harness.wait_until(|h| h.screen_to_string().contains("lib.rs:3:4"))?;
+
+    harness.type_text("5:4")?;
+    harness.render()?;
+    harness.assert_screen_contains("lib.rs:5:4");
+    harness.assert_screen_not_contains("lib.rs:3:4");
+
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| active_file_name(h) == "lib.rs")?;
+    assert_eq!(cursor_line(&harness), 4);
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+//! E2E tests for keyboard-triggered LSP hover: markdown rendering inside
+//! the popup, dismissal, and empty hover results.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Modifier;
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server whose every `textDocument/hover` answer is
+/// `result` (raw JSON).
+fn write_hover_server(dir: &Path, log_file: &Path, result: &str) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+RESULT='{result}'
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"hoverProvider":true,"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/hover")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":'"$RESULT"'}}'
+            echo "SENT: hover" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "textDocument/semanticTokens/full"|"textDocument/semanticTokens/full/delta"|"textDocument/semanticTokens/range")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"data":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_hover_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// Open `main.rs` against a server answering hovers with `result`, put the
+/// cursor on `helper` and trigger hover from the keyboard (Alt+K). Returns
+/// once the server has answered.
+fn hover_on_helper(
+    temp_dir: &tempfile::TempDir,
+    result: &str,
+) -> anyhow::Result<EditorTestHarness> {
+    let project_root = temp_dir.path().canonicalize()?;
+    let log_file = project_root.join("lsp_log.txt");
+    let script_path = write_hover_server(&project_root, &log_file, result)?;
+
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {\n    helper();\n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("ACTION: initialized")
+    })?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    for _ in 0..6 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.process_async_and_render()?;
+    harness.send_key(KeyCode::Char('k'), KeyModifiers::ALT)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("SENT: hover")
+    })?;
+
+    Ok(harness)
+}
+
+/// Markdown hover content is rendered (markers stripped, bold applied, code
+/// fence body shown) and the popup closes on the next key press.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_hover_popup_renders_markdown() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let result = r#"{"contents":{"kind":"markdown","value":"```rust\nfn helper() -> u32\n```\n\n**Returns** the `answer` value."}}"#;
+    let mut harness = hover_on_helper(&temp_dir, result)?;
+
+    harness.wait_until(|h| h.screen_to_string().contains("fn helper() -> u32"))?;
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("Returns the answer value."),
+        "bold and code-span markers should be stripped. Screen:\n{}",
+        screen
+    );
+    assert!(!screen.contains("**Returns**"));
+    assert!(!screen.contains("```"));
+
+    let (x, y) = harness
+        .find_text_on_screen("Returns")
+        .expect("hover text should be on screen");
+    let style = harness
+        .get_cell_style(x, y)
+        .expect("cell should have a style");
+    assert!(
+        style.add_modifier.contains(Modifier::BOLD),
+        "**Returns** should render bold, got {:?}",
+        style
+    );
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.wait_until(|h| !h.screen_to_string().contains("fn helper() -> u32"))?;
+
+    Ok(())
+}
+
+/// Moving the cursor dismisses the hover popup.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_hover_popup_dismissed_on_cursor_movement() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let result = r#"{"contents":{"kind":"plaintext","value":"helper: fn() -> u32"}}"#;
+    let mut harness = hover_on_helper(&temp_dir, result)?;
+
+    harness.wait_until(|h| h.screen_to_string().contains("helper: fn() -> u32"))?;
+    harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    harness.wait_until(|h| !h.screen_to_string().contains("helper: fn() -> u32"))?;
+
+    Ok(())
+}
+
+/// A whitespace-only hover shows no popup, just the "no hover" status.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_empty_hover_shows_no_popup() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let result = r#"{"contents":{"kind":"markdown","value":"  \n"}}"#;
+    let mut harness = hover_on_helper(&temp_dir, result)?;
+
+    harness.wait_until(|h| h.get_status_bar().contains("No hover information"))?;
+    assert!(
+        !harness.editor().has_transient_popup(),
+        "an empty hover must not open a popup"
+    );
+
+    Ok(())
+}
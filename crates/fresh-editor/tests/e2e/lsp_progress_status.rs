@@ -0,0 +1,151 @@
+//! E2E test for LSP `$/progress` reporting: the running task's text shows
+//! in the status bar, concurrent tasks are counted, and the text clears when
+//! the work ends.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server that creates a progress token and begins an
+/// "Indexing" task once initialized. The first document change starts a
+/// second task and advances the first; the second change ends both.
+fn write_progress_server(dir: &Path, log_file: &Path) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+CHANGES=0
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            send_message '{{"jsonrpc":"2.0","id":900,"method":"window/workDoneProgress/create","params":{{"token":"idx"}}}}'
+            send_message '{{"jsonrpc":"2.0","method":"$/progress","params":{{"token":"idx","value":{{"kind":"begin","title":"Indexing","message":"1/4","percentage":25}}}}}}'
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/didChange")
+            CHANGES=$((CHANGES + 1))
+            if [ $CHANGES -eq 1 ]; then
+                send_message '{{"jsonrpc":"2.0","method":"$/progress","params":{{"token":"fetch","value":{{"kind":"begin","title":"Fetching"}}}}}}'
+                send_message '{{"jsonrpc":"2.0","method":"$/progress","params":{{"token":"idx","value":{{"kind":"report","message":"3/4","percentage":75}}}}}}'
+            elif [ $CHANGES -eq 2 ]; then
+                send_message '{{"jsonrpc":"2.0","method":"$/progress","params":{{"token":"fetch","value":{{"kind":"end"}}}}}}'
+                send_message '{{"jsonrpc":"2.0","method":"$/progress","params":{{"token":"idx","value":{{"kind":"end","message":"done"}}}}}}'
+            fi
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_progress_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_progress_shown_in_status_bar_until_end() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let log_file = project_root.join("lsp_log.txt");
+    let script_path = write_progress_server(&project_root, &log_file)?;
+
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+
+    harness.wait_until(|h| h.get_status_bar().contains("Indexing: 1/4 (25%)"))?;
+
+    // A second task starts and the first advances: the most recent update
+    // is shown, with the other task counted
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text("a")?;
+    harness.wait_until(|h| h.get_status_bar().contains("Indexing: 3/4 (75%) (+1 more)"))?;
+
+    // Both tasks end: the progress text goes away
+    harness.type_text("b")?;
+    harness.wait_until(|h| {
+        let status = h.get_status_bar();
+        !status.contains("Indexing") && !status.contains("Fetching")
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+//! E2E test for LSP rename across files: `prepareRename` validation, the
+//! returned `WorkspaceEdit` applied to every affected file (opening the ones
+//! that aren't open yet), and a single undo reverting all of it.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_rename_applies_workspace_edit_to_two_files_and_undoes_as_one() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    // URIs must use the canonical path to match the editor's (macOS /private).
+    let project_root = temp_dir.path().canonicalize()?;
+
+    let main_file = project_root.join("main.rs");
+    let lib_file = project_root.join("lib.rs");
+    let main_content = "fn main() {\n    helper();\n}\n";
+    let lib_content = "fn helper() {}\n";
+    std::fs::write(&main_file, main_content)?;
+    std::fs::write(&lib_file, lib_content)?;
+
+    let main_uri = format!("file://{}", main_file.display());
+    let lib_uri = format!("file://{}", lib_file.display());
+    let log_file = project_root.join("lsp_log.txt");
+    let log_path = log_file.display();
+
+    let script = format!(
+        r##"#!/bin/bash
+
+MAIN_URI="{main_uri}"
+LIB_URI="{lib_uri}"
+LOG_FILE="{log_path}"
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"renameProvider":{{"prepareProvider":true}},"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/prepareRename")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"start":{{"line":1,"character":4}},"end":{{"line":1,"character":10}}}}}}'
+            echo "SENT: prepareRename" >> "$LOG_FILE"
+            ;;
+        "textDocument/rename")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"changes":{{"'"$MAIN_URI"'":[{{"range":{{"start":{{"line":1,"character":4}},"end":{{"line":1,"character":10}}}},"newText":"assist"}}],"'"$LIB_URI"'":[{{"range":{{"start":{{"line":0,"character":3}},"end":{{"line":0,"character":9}}}},"newText":"assist"}}]}}}}}}'
+            echo "SENT: rename" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = project_root.join("fake_rename_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root.clone())?;
+    harness.open_file(&main_file)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("ACTION: initialized")
+    })?;
+
+    // Cursor on `helper` in main.rs, then rename (F2). The prompt only
+    // opens once prepareRename has confirmed the symbol.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    for _ in 0..6 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.process_async_and_render()?;
+    harness.send_key(KeyCode::F(2), KeyModifiers::NONE)?;
+    harness.wait_for_prompt()?;
+    assert!(std::fs::read_to_string(&log_file)?.contains("SENT: prepareRename"));
+
+    for _ in 0.."helper".len() {
+        harness.send_key(KeyCode::Backspace, KeyModifiers::NONE)?;
+    }
+    harness.type_text("assist")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.get_status_bar().contains("Renamed successfully"))?;
+
+    harness.open_file(&main_file)?;
+    harness.assert_buffer_content("fn main() {\n    assist();\n}\n");
+    harness.open_file(&lib_file)?;
+    harness.assert_buffer_content("fn assist() {}\n");
+
+    // One undo, from either file, reverts the rename in both.
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.assert_buffer_content(lib_content);
+    harness.open_file(&main_file)?;
+    harness.assert_buffer_content(main_content);
+
+    // And one redo reapplies it everywhere.
+    harness.send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)?;
+    harness.assert_buffer_content("fn main() {\n    assist();\n}\n");
+    harness.open_file(&lib_file)?;
+    harness.assert_buffer_content("fn assist() {}\n");
+
+    // Undoing from lib.rs keeps it focused while main.rs reverts too.
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.assert_buffer_content(lib_content);
+
+    // A new edit in lib.rs drops its redo history and its part of the
+    // group, so redoing in main.rs leaves lib.rs alone.
+    harness.type_text("x")?;
+    let lib_edited = harness.get_buffer_content().unwrap();
+    harness.open_file(&main_file)?;
+    harness.assert_buffer_content(main_content);
+    harness.send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)?;
+    harness.assert_buffer_content("fn main() {\n    assist();\n}\n");
+    harness.open_file(&lib_file)?;
+    harness.assert_buffer_content(&lib_edited);
+
+    Ok(())
+}
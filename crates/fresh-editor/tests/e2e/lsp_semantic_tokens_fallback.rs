@@ -0,0 +1,185 @@
+//! E2E tests for LSP semantic tokens as the highlighter of last resort:
+//! buffers whose language has no built-in grammar request full-document
+//! tokens (and deltas after edits) even with `enable_semantic_tokens_full`
+//! off.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server with full (delta-capable) semantic tokens. The
+/// first three characters of the file are reported as a `keyword`; deltas
+/// keep them unchanged.
+fn write_semantic_tokens_server(dir: &Path, log_file: &Path) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1,"semanticTokensProvider":{{"legend":{{"tokenTypes":["keyword","variable"],"tokenModifiers":[]}},"full":{{"delta":true}},"range":false}}}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/semanticTokens/full")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"resultId":"1","data":[0,0,3,0,0]}}}}'
+            echo "SENT: full" >> "$LOG_FILE"
+            ;;
+        "textDocument/semanticTokens/full/delta")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"resultId":"2","edits":[]}}}}'
+            echo "SENT: delta" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_semantic_tokens_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// A `.fake` file (a language with no grammar) gets the keyword style from
+/// the server's tokens, and an edit asks for a delta instead of a full
+/// re-tokenization.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_semantic_tokens_highlight_language_without_grammar() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let log_file = project_root.join("lsp_log.txt");
+    let script_path = write_semantic_tokens_server(&project_root, &log_file)?;
+
+    let test_file = project_root.join("main.fake");
+    std::fs::write(&test_file, "let value = 1\n")?;
+
+    let mut config = fresh::config::Config::default();
+    assert!(!config.editor.enable_semantic_tokens_full);
+    config.languages.insert(
+        "fakelang".to_string(),
+        fresh::config::LanguageConfig {
+            extensions: vec!["fake".to_string()],
+            ..Default::default()
+        },
+    );
+    config.lsp.insert(
+        "fakelang".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    assert!(
+        !harness
+            .editor()
+            .active_state()
+            .highlighter
+            .has_highlighting(),
+        "the test language must not have a built-in highlighter"
+    );
+
+    let keyword = harness.editor().theme().syntax_keyword;
+    let style_at = |h: &EditorTestHarness, text: &str| {
+        h.find_text_on_screen(text)
+            .and_then(|(x, y)| h.get_cell_style(x, y))
+    };
+    harness.wait_until(|h| style_at(h, "let value").is_some_and(|s| s.fg == Some(keyword)))?;
+    assert_ne!(
+        style_at(&harness, "value").and_then(|s| s.fg),
+        Some(keyword),
+        "only the token range should get the keyword style"
+    );
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text("0")?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("SENT: delta")
+    })?;
+    assert_eq!(
+        std::fs::read_to_string(&log_file)?
+            .matches("SENT: full")
+            .count(),
+        1,
+        "edits should be fetched as deltas"
+    );
+    assert_eq!(
+        style_at(&harness, "let value").and_then(|s| s.fg),
+        Some(keyword)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! E2E test for LSP signature help while typing call arguments: the active
+//! parameter is emphasized, follows commas, and `)` closes the popup.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Modifier;
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server offering `fn add(a: i32, b: i32) -> i32` as the
+/// signature. The active parameter is the second one once the request's
+/// cursor column is past `add(1,` on line 2, the first one before that.
+fn write_signature_server(dir: &Path, log_file: &Path) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"signatureHelpProvider":{{"triggerCharacters":["(",","]}},"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/signatureHelp")
+            character=$(echo "$msg" | grep -o '"character":[0-9]*' | head -1 | cut -d':' -f2)
+            active=0
+            if [ "$character" -ge 10 ]; then
+                active=1
+            fi
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"signatures":[{{"label":"fn add(a: i32, b: i32) -> i32","parameters":[{{"label":[7,13]}},{{"label":[15,21]}}]}}],"activeSignature":0,"activeParameter":'$active'}}}}'
+            echo "SENT: signatureHelp $active" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/inlayHint")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[]}}'
+            ;;
+        "textDocument/semanticTokens/full"|"textDocument/semanticTokens/full/delta"|"textDocument/semanticTokens/range")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"data":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_signature_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+/// Whether the first on-screen occurrence of `text` is rendered bold.
+fn is_bold_on_screen(harness: &EditorTestHarness, text: &str) -> bool {
+    harness
+        .find_text_on_screen(text)
+        .and_then(|(x, y)| harness.get_cell_style(x, y))
+        .is_some_and(|style| style.add_modifier.contains(Modifier::BOLD))
+}
+
+/// Typing `add(` shows the signature with `a: i32` emphasized; the popup
+/// survives typing the first argument, moves the emphasis to `b: i32` after
+/// the comma, and closes on `)`.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_signature_help_tracks_active_parameter() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let log_file = project_root.join("lsp_log.txt");
+    let script_path = write_signature_server(&project_root, &log_file)?;
+
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("ACTION: initialized")
+    })?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text("add(")?;
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("fn add(a: i32, b: i32) -> i32")
+    })?;
+    assert!(is_bold_on_screen(&harness, "a: i32"));
+    assert!(!is_bold_on_screen(&harness, "b: i32"));
+
+    harness.type_text("1")?;
+    assert!(
+        harness.editor().has_transient_popup(),
+        "typing an argument must keep signature help open"
+    );
+
+    harness.type_text(",")?;
+    harness.wait_until(|h| is_bold_on_screen(h, "b: i32"))?;
+    assert!(
+        !is_bold_on_screen(&harness, "a: i32"),
+        "only the active parameter should be emphasized"
+    );
+    assert_eq!(
+        harness.screen_to_string().matches("fn add(a: i32").count(),
+        1,
+        "the re-triggered popup should replace the previous one"
+    );
+
+    harness.type_text(")")?;
+    harness.wait_until(|h| !h.screen_to_string().contains("fn add(a: i32"))?;
+
+    Ok(())
+}
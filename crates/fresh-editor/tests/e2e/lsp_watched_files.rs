@@ -0,0 +1,78 @@
+//! E2E test for `workspace/didChangeWatchedFiles`: files created or
+//! modified anywhere in the project outside the editor are reported to
+//! running language servers whether or not the file explorer has ever been
+//! opened.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore = "FakeLspServer uses Bash")]
+fn test_created_file_reported_without_file_explorer() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let _fake_server = FakeLspServer::spawn_with_logging(&project_root)?;
+
+    // Outside the project, so the server's own logging isn't reported as a
+    // change.
+    let log_dir = tempfile::tempdir()?;
+    let log_file = log_dir.path().join("watched_files_log.txt");
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {}\n")?;
+    let nested_file = project_root.join("src/util/mod.rs");
+    std::fs::create_dir_all(nested_file.parent().unwrap())?;
+    std::fs::write(&nested_file, "pub fn util() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::logging_script_path(&project_root)
+                .to_string_lossy()
+                .to_string(),
+            args: vec![log_file.to_string_lossy().to_string()],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_root.clone())?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| {
+        let content = std::fs::read_to_string(&log_file).unwrap_or_default();
+        content.lines().any(|line| line == "initialized")
+    })?;
+    assert!(harness.editor().file_explorer().is_none());
+
+    // Let the first poll record the project's files, and step past their
+    // current mtimes on coarse-grained filesystems.
+    harness.wait_until(|h| h.editor().is_watching_dir(&project_root))?;
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+
+    let notifications = || {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| *line == "workspace/didChangeWatchedFiles")
+            .count()
+    };
+
+    std::fs::write(project_root.join("lib.rs"), "pub fn lib() {}\n")?;
+    harness.wait_until(|_| notifications() >= 1)?;
+
+    // Modifying a file two directories down is reported too.
+    let sent = notifications();
+    std::fs::write(&nested_file, "pub fn util() -> u8 { 1 }\n")?;
+    harness.wait_until(|_| notifications() > sent)?;
+
+    Ok(())
+}
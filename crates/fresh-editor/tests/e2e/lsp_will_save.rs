@@ -0,0 +1,223 @@
+//! E2E tests for save notifications: `textDocument/willSaveWaitUntil` edits
+//! are applied before the file is written, and `textDocument/didSave` follows
+//! the server's `save` capability.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+/// Write a fake LSP server announcing `sync` as its `textDocumentSync`
+/// capability. It answers `willSaveWaitUntil` with an edit adding an import,
+/// logging what the file on disk held at that moment, and logs every
+/// `didSave` / `didChange` notification it receives.
+fn write_save_server(
+    dir: &Path,
+    log_file: &Path,
+    source_file: &Path,
+    sync: &str,
+) -> anyhow::Result<PathBuf> {
+    let log_path = log_file.to_str().unwrap();
+    let source_path = source_file.to_str().unwrap();
+    let script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+SOURCE_FILE="{source_path}"
+SYNC='{sync}'
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":'"$SYNC"'}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/willSaveWaitUntil")
+            echo "DISK AT WILLSAVE: $(head -n 1 "$SOURCE_FILE")" >> "$LOG_FILE"
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[{{"range":{{"start":{{"line":0,"character":0}},"end":{{"line":0,"character":0}}}},"newText":"use std::io;\n"}}]}}'
+            ;;
+        "textDocument/didSave")
+            echo "DIDSAVE: $msg" >> "$LOG_FILE"
+            ;;
+        "textDocument/didChange")
+            echo "DIDCHANGE" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##
+    );
+
+    let script_path = dir.join("fake_save_lsp.sh");
+    std::fs::write(&script_path, &script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    Ok(script_path)
+}
+
+fn log_contents(log_file: &Path) -> String {
+    std::fs::read_to_string(log_file).unwrap_or_default()
+}
+
+/// Open `main.rs` against a server announcing `sync`, wait for it to
+/// initialize and append ` // edited` to the first line.
+fn edit_main_rs(
+    project_root: &Path,
+    sync: &str,
+) -> anyhow::Result<(EditorTestHarness, PathBuf, PathBuf)> {
+    let log_file = project_root.join("lsp_log.txt");
+    let test_file = project_root.join("main.rs");
+    std::fs::write(&test_file, "fn main() {}\n")?;
+    let script_path = write_save_server(project_root, &log_file, &test_file, sync)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        project_root.to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| log_contents(&log_file).contains("ACTION: initialized"))?;
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text(" // edited")?;
+
+    Ok((harness, log_file, test_file))
+}
+
+/// The willSaveWaitUntil edit lands in the buffer before the file is
+/// written, and didSave carries the saved text as the server asked.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_will_save_wait_until_edits_are_saved() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let sync =
+        r#"{"openClose":true,"change":1,"save":{"includeText":true},"willSaveWaitUntil":true}"#;
+    let (mut harness, log_file, test_file) = edit_main_rs(&project_root, sync)?;
+
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)?;
+    harness.wait_until(|_| log_contents(&log_file).contains("DIDSAVE"))?;
+
+    let log = log_contents(&log_file);
+    assert!(
+        log.contains("DISK AT WILLSAVE: fn main() {}\n"),
+        "the file must not be written before the server answers. Log:\n{log}"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&test_file)?,
+        "use std::io;\nfn main() {} // edited\n"
+    );
+    assert!(
+        log.contains(r#""text":"use std::io;\nfn main() {} // edited\n""#),
+        "didSave should carry the saved text. Log:\n{log}"
+    );
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "use std::io;\nfn main() {} // edited\n"
+    );
+    assert!(
+        !harness.editor().active_state().buffer.is_modified(),
+        "the buffer should be saved with the server edit"
+    );
+
+    Ok(())
+}
+
+/// A server whose sync options leave out `save` gets no didSave.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_did_save_not_sent_without_save_capability() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+    let sync = r#"{"openClose":true,"change":1}"#;
+    let (mut harness, log_file, test_file) = edit_main_rs(&project_root, sync)?;
+
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&test_file).unwrap_or_default() == "fn main() {} // edited\n"
+    })?;
+
+    // Notifications go out in order: once a later didChange arrives, a
+    // didSave would already have been logged.
+    let changes_before = log_contents(&log_file).matches("DIDCHANGE").count();
+    harness.type_text("!")?;
+    harness
+        .wait_until(|_| log_contents(&log_file).matches("DIDCHANGE").count() > changes_before)?;
+
+    let log = log_contents(&log_file);
+    assert!(
+        !log.contains("DIDSAVE"),
+        "didSave must not be sent to a server without the save capability. Log:\n{log}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Tests for the minimap overview column.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::Config;
+
+/// 200 numbered lines, so the minimap folds several lines into each row.
+fn numbered_lines() -> String {
+    (1..=200)
+        .map(|i| format!("line {i:03}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn config_with_minimap() -> Config {
+    let mut config = Config::default();
+    config.editor.show_minimap = true;
+    config
+}
+
+/// Clicking near the bottom of the minimap scrolls the viewport to the
+/// corresponding region of the file.
+#[test]
+fn test_minimap_click_jumps_to_region() {
+    let mut harness = EditorTestHarness::with_config(80, 24, config_with_minimap()).unwrap();
+    harness.load_buffer_from_text(&numbered_lines()).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("line 001");
+    assert_eq!(harness.top_line_number(), 0);
+
+    // The minimap is the 10 columns left of the vertical scrollbar.
+    let minimap_col = 80 - 1 - 5;
+    let (first_row, last_row) = harness.content_area_rows();
+    let click_row = first_row + (last_row - first_row) * 9 / 10;
+    harness.mouse_click(minimap_col, click_row as u16).unwrap();
+    harness.render().unwrap();
+
+    let top_line = harness.top_line_number();
+    assert!(
+        top_line > 150,
+        "viewport should jump near the end of the file, top line is {top_line}"
+    );
+    harness.assert_screen_not_contains("line 001");
+}
+
+/// Without the option, the same click lands in the text area and does not
+/// scroll.
+#[test]
+fn test_minimap_hidden_by_default() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text(&numbered_lines()).unwrap();
+    harness.render().unwrap();
+
+    let (first_row, last_row) = harness.content_area_rows();
+    let click_row = first_row + (last_row - first_row) * 9 / 10;
+    harness.mouse_click(80 - 1 - 5, click_row as u16).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.top_line_number(), 0);
+    harness.assert_screen_contains("line 001");
+}
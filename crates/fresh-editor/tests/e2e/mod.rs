@@ -9,13 +9,17 @@ pub mod basic;
 pub mod binary_file;
 pub mod block_selection;
 pub mod blog_showcases;
+pub mod breakpoints;
 pub mod buffer_groups;
 pub mod buffer_lifecycle;
 pub mod buffer_settings_commands;
 pub mod capslock_shortcuts;
 pub mod case_conversion;
+pub mod command_history;
 pub mod command_palette;
 pub mod config_language_selector;
+pub mod config_reload;
+pub mod convert_indentation;
 pub mod copy_buffer_path;
 pub mod crash_repro;
 pub mod crlf_rendering;
@@ -25,19 +29,26 @@ pub mod ctrl_end_wrapped;
 pub mod cursor_style_rendering;
 pub mod cursor_under_popup;
 pub mod dabbrev_completion;
+pub mod diff_against_disk;
 pub mod document_model;
 pub mod duplicate_line;
+pub mod editorconfig;
 pub mod emacs_actions;
 pub mod encoding;
+pub mod expand_selection_scope;
 pub mod explorer_bugs;
 pub mod explorer_context_menu;
 pub mod explorer_menu;
 pub mod external_file_save_as_tab;
+pub mod extract_selection;
 pub mod file_browser;
 pub mod file_explorer;
 pub mod file_permissions;
 pub mod flash;
 pub mod folding;
+pub mod git_blame;
+pub mod git_gutter;
+pub mod git_stage_hunks;
 pub mod glob_language_detection;
 pub mod goto_matching_bracket;
 #[cfg(feature = "gui")]
@@ -70,6 +81,7 @@ pub mod issue_779_after_eof_shade;
 pub mod redraw_screen;
 pub mod suspend_process;
 
+pub mod join_lines;
 pub mod keybinding_editor;
 pub mod language_features_e2e;
 pub mod large_file_inplace_write_bug;
@@ -87,6 +99,7 @@ pub mod lsp;
 pub mod lsp_auto_start_prompt;
 pub mod lsp_autostart_selective;
 pub mod lsp_bulk_edit_undo_desync;
+pub mod lsp_cancel_request;
 pub mod lsp_code_action_modal;
 pub mod lsp_code_action_resolve_and_commands;
 pub mod lsp_completion_duplicate_entries_1514;
@@ -97,8 +110,13 @@ pub mod lsp_config;
 pub mod lsp_crash_loop;
 pub mod lsp_cross_language_diagnostic_pull;
 pub mod lsp_diagnostic_flow;
+pub mod lsp_diagnostic_navigation;
+pub mod lsp_diagnostics_status_count;
 pub mod lsp_env;
+pub mod lsp_explain_diagnostic;
+pub mod lsp_goto_definition;
 pub mod lsp_goto_definition_readonly;
+pub mod lsp_hover_markdown;
 pub mod lsp_inlay_hints_capability;
 pub mod lsp_lifecycle_visibility;
 pub mod lsp_missing_binary_and_dismiss;
@@ -106,12 +124,18 @@ pub mod lsp_multi_semantic_tokens;
 pub mod lsp_no_config;
 pub mod lsp_order;
 pub mod lsp_popup_focus_keybinding;
+pub mod lsp_progress_status;
 pub mod lsp_publish_diagnostics_capability;
+pub mod lsp_rename_workspace_edit;
+pub mod lsp_semantic_tokens_fallback;
 pub mod lsp_server_lifecycle_cleanup;
+pub mod lsp_signature_help;
 pub mod lsp_stop_stale_indicator;
 pub mod lsp_toggle_desync;
 pub mod lsp_unified_code_actions;
 pub mod lsp_unresponsive_capability_does_not_block;
+pub mod lsp_watched_files;
+pub mod lsp_will_save;
 pub mod macros;
 pub mod margin;
 pub mod markdown_compose;
@@ -121,15 +145,19 @@ pub mod menu_bar;
 pub mod menu_cursor_bleed;
 pub mod menu_tab_color_bleed;
 pub mod merge_conflict;
+pub mod minimap;
 pub mod mouse;
 pub mod movement;
 pub mod multi_file_opening;
 pub mod multibyte_characters;
 pub mod multicursor;
 pub mod on_save_actions;
+pub mod open_file_under_cursor;
 pub mod open_folder;
+pub mod open_url;
 pub mod overlay_extend_to_line_end;
 pub mod paste;
+pub mod pinned_tabs;
 #[cfg(feature = "plugins")]
 pub mod plugins;
 pub mod popup_selection;
@@ -142,9 +170,12 @@ pub mod preview_lsp_popup_focus;
 pub mod preview_tabs;
 pub mod prompt;
 pub mod prompt_editing;
+pub mod recent_buffers;
+pub mod recent_files;
 pub mod recovery;
 pub mod remote_fs_test;
 pub mod remote_indicator_popup;
+pub mod rename_file;
 pub mod rendering;
 pub mod save_as_language_detection;
 pub mod save_nonexistent_directory;
@@ -180,6 +211,7 @@ pub mod slow_filesystem;
 pub mod smart_editing;
 pub mod smart_home;
 pub mod sort_lines;
+pub mod spell_check;
 pub mod split_focus_tab_click;
 pub mod split_tabs;
 pub mod split_view;
@@ -198,6 +230,7 @@ pub mod tab_config;
 pub mod tab_drag;
 pub mod tab_indent_selection;
 pub mod tab_scrolling;
+pub mod task_runner;
 pub mod terminal;
 pub mod terminal_close;
 pub mod terminal_resize;
@@ -205,6 +238,7 @@ pub mod terminal_split_focus_live;
 pub mod test_scrollbar_keybinds_cursor;
 pub mod theme;
 pub mod theme_screenshots;
+pub mod toasts;
 pub mod toggle_bars;
 pub mod toggle_comment;
 pub mod triple_click;
@@ -216,6 +250,7 @@ pub mod unicode_prompt_bugs;
 pub mod universal_lsp;
 pub mod unnamed_buffer_persistence;
 pub mod update_notification;
+pub mod user_snippets;
 pub mod vertical_rulers;
 #[cfg(feature = "plugins")]
 pub mod vi_mode;
@@ -225,4 +260,6 @@ pub mod virtual_line_bg_and_wrap;
 pub mod virtual_lines;
 pub mod visual_regression;
 pub mod warning_indicators;
+pub mod whitespace_indicators;
 pub mod workspace;
+pub mod workspace_edit_preview;
@@ -1417,6 +1417,75 @@ fn test_shift_click_can_shrink_selection() {
     );
 }
 
+/// Shift+click after placing the cursor with the keyboard selects from the
+/// cursor to the clicked cell, across lines; a second shift+click keeps the
+/// same anchor and only moves the head.
+#[test]
+fn test_shift_click_selects_from_keyboard_cursor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let content = "alpha beta\ngamma delta\nepsilon zeta\n";
+    let _fixture = harness.load_buffer_from_text(content).unwrap();
+    harness.render().unwrap();
+
+    // Cursor after "alp" on the first line
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let gutter_width = harness.editor().active_state().margins.left_total_width() as u16;
+
+    // Shift+click after "eps" on the third line (byte 23 + 3)
+    harness
+        .mouse_shift_click(gutter_width + 3, content_first_row as u16 + 2)
+        .unwrap();
+    assert_eq!(harness.get_selection_range(), Some(3..26));
+    assert_eq!(harness.get_selected_text(), "ha beta\ngamma delta\neps");
+
+    // Shift+click before the anchor flips the selection around it
+    harness.advance_time(std::time::Duration::from_millis(
+        harness.config().editor.double_click_time_ms * 2,
+    ));
+    harness
+        .mouse_shift_click(gutter_width + 1, content_first_row as u16)
+        .unwrap();
+    assert_eq!(harness.get_selection_range(), Some(1..3));
+    assert_eq!(harness.cursor_position(), 1);
+}
+
+/// Shift+click keeps the start of a selection made with the keyboard
+#[test]
+fn test_shift_click_preserves_keyboard_selection_anchor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let content = "hello world test content\n";
+    let _fixture = harness.load_buffer_from_text(content).unwrap();
+    harness.render().unwrap();
+
+    // Select "ello" with Shift+Right, starting after "h"
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    assert_eq!(harness.get_selection_range(), Some(1..5));
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let gutter_width = harness.editor().active_state().margins.left_total_width() as u16;
+    harness
+        .mouse_shift_click(gutter_width + 16, content_first_row as u16)
+        .unwrap();
+
+    assert_eq!(harness.get_selection_range(), Some(1..16));
+    assert_eq!(harness.get_selected_text(), "ello world test");
+}
+
 /// Test tab hover with real files (which have line numbers, actual filenames, etc.)
 /// This more closely matches real-world usage
 #[test]
@@ -2253,6 +2322,65 @@ fn test_double_click_drag_backward_keeps_initial_word_selected() {
     );
 }
 
+/// Holding a drag selection past the bottom edge of the content area keeps
+/// scrolling the view as time passes, with no further drag events, and
+/// extends the selection beyond the lines that were visible.
+#[test]
+fn test_drag_past_bottom_edge_auto_scrolls() {
+    let mut harness = EditorTestHarness::new_no_wrap(80, 24).unwrap();
+    let content: String = (1..=100).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    let (first_row, last_row) = harness.content_area_rows();
+    let visible_lines = last_row - first_row + 1;
+    let gutter_width = harness.editor().active_state().margins.left_total_width() as u16;
+    let col = gutter_width + 2;
+
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: col,
+            row: first_row as u16,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    // Move the pointer two rows below the content area and hold it there
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Left),
+            column: col,
+            row: last_row as u16 + 2,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+    for _ in 0..4 {
+        harness.advance_time(std::time::Duration::from_millis(100));
+        harness.tick_and_render().unwrap();
+    }
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            column: col,
+            row: last_row as u16 + 2,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(
+        harness.top_line_number() > 1,
+        "Viewport should scroll while dragging past the bottom edge"
+    );
+    let selected = harness.get_selected_text();
+    assert!(selected.starts_with("line 1\n"), "Selected: {selected:?}");
+    assert!(
+        selected.contains(&format!("line {}\n", visible_lines + 5)),
+        "Selection should reach past the originally visible lines. Selected: {selected:?}"
+    );
+}
+
 /// Test that hovering over the scrollbar track highlights only the hovered cell,
 /// not the entire track.
 #[test]
@@ -189,6 +189,76 @@ fn test_add_cursor_next_match() {
     assert_eq!(cursors.iter().count(), 3);
 }
 
+/// Test selecting every whole-word occurrence of the word under the cursor
+#[test]
+fn test_select_all_occurrences_of_word() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness
+        .type_text("let count = count + 1; // count, not counter")
+        .unwrap();
+
+    // Cursor inside the second "count", no selection
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..14 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    harness
+        .send_key(
+            KeyCode::Char('l'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+
+    // "counter" is not a whole-word match
+    let cursors = harness.editor().active_cursors();
+    assert_eq!(cursors.iter().count(), 3);
+    let mut ranges: Vec<_> = cursors
+        .iter()
+        .filter_map(|(_, c)| c.selection_range())
+        .collect();
+    ranges.sort_by_key(|r| r.start);
+    assert_eq!(ranges, vec![4..9, 12..17, 26..31]);
+    assert_eq!(
+        harness
+            .editor()
+            .active_cursors()
+            .primary()
+            .selection_range(),
+        Some(12..17)
+    );
+
+    harness.type_text("total").unwrap();
+    harness.assert_buffer_content("let total = total + 1; // total, not counter");
+}
+
+/// Test that an explicit selection matches inside longer words too
+#[test]
+fn test_select_all_occurrences_of_selection() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("ab abc cab").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+        .unwrap();
+
+    harness.editor_mut().select_all_occurrences();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_cursors().iter().count(), 3);
+
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("X Xc cX");
+}
+
 /// Test adding cursor above with Ctrl+Alt+Up
 #[test]
 fn test_add_cursor_above() {
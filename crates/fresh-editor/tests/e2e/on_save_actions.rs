@@ -41,6 +41,8 @@ fn test_format_on_save() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -110,6 +112,8 @@ fn test_on_save_linter_style() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -179,6 +183,8 @@ fn test_on_save_action_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -257,6 +263,8 @@ fn test_on_save_file_placeholder() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -329,6 +337,8 @@ fn test_formatter_stdin_mode() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -408,6 +418,8 @@ fn test_on_save_stops_on_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -508,6 +520,8 @@ fn test_formatter_not_found_shows_message() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -617,6 +631,115 @@ fn test_ensure_final_newline_on_save() {
     assert_eq!(disk_content, "line 1\nline 2\n");
 }
 
+/// Without ensure_final_newline_on_save, a file kept without a final
+/// newline is saved as-is
+#[test]
+fn test_final_newline_not_added_when_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1\nline 2").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, Config::default(), project_dir)
+            .unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("x").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("xline 1\nline 2");
+    let disk_content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(disk_content, "xline 1\nline 2");
+}
+
+/// The added final newline is a single undo step
+#[test]
+fn test_final_newline_on_save_is_undoable() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1\nline 2").unwrap();
+
+    let mut config = Config::default();
+    config.editor.ensure_final_newline_on_save = true;
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("line 1\nline 2\n");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("line 1\nline 2");
+}
+
+/// trim_trailing_blank_lines_on_save leaves exactly one final newline
+#[test]
+fn test_trim_trailing_blank_lines_on_save() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1\nline 2\n\n  \n\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_blank_lines_on_save = true;
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("line 1\nline 2\n");
+    let disk_content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(disk_content, "line 1\nline 2\n");
+}
+
+/// Trimming blank lines never adds a final newline on its own
+#[test]
+fn test_trim_trailing_blank_lines_keeps_missing_newline() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("test.rs");
+    std::fs::write(&file_path, "line 1\nline 2").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_blank_lines_on_save = true;
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("line 1\nline 2");
+    let disk_content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(disk_content, "line 1\nline 2");
+}
+
 /// Test both whitespace cleanup options together
 #[test]
 fn test_whitespace_cleanup_combined() {
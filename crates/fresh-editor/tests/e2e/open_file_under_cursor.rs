@@ -0,0 +1,113 @@
+//! E2E tests for "Open File Under Cursor".
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Ctrl+Alt+G on a relative `path:line` opens the file at that line.
+#[test]
+fn test_open_file_under_cursor_at_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir(project_dir.join("src")).unwrap();
+    std::fs::write(
+        project_dir.join("src/target.rs"),
+        "fn one() {}\nfn two() {}\nfn three() {}\n",
+    )
+    .unwrap();
+    let notes = project_dir.join("notes.txt");
+    std::fs::write(&notes, "see src/target.rs:3 for details\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.open_file(&notes).unwrap();
+    for _ in 0..8 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+
+    let editor = harness.editor();
+    let path = editor
+        .active_state()
+        .buffer
+        .file_path()
+        .map(|p| p.to_path_buf());
+    assert_eq!(path, Some(project_dir.join("src/target.rs")));
+    let line = editor
+        .active_state()
+        .buffer
+        .get_line_number(editor.active_cursors().primary().position);
+    assert_eq!(line, 2, "cursor should be on line 3 (0-indexed 2)");
+}
+
+/// A path with non-ASCII characters opens the file it names.
+#[test]
+fn test_open_file_under_cursor_non_ascii_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir(project_dir.join("café")).unwrap();
+    std::fs::write(project_dir.join("café/日本.txt"), "contenu\n").unwrap();
+    let notes = project_dir.join("notes.txt");
+    std::fs::write(&notes, "voir « café/日本.txt\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.open_file(&notes).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+
+    let path = harness
+        .editor()
+        .active_state()
+        .buffer
+        .file_path()
+        .map(|p| p.to_path_buf());
+    assert_eq!(path, Some(project_dir.join("café/日本.txt")));
+}
+
+/// A word that names no existing file leaves the buffer open and says so.
+#[test]
+fn test_open_file_under_cursor_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    let notes = temp_dir.path().join("notes.txt");
+    std::fs::write(&notes, "missing.rs\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+    harness.open_file(&notes).unwrap();
+    harness
+        .send_key(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.editor().active_state().buffer.file_path(),
+        Some(notes.as_path())
+    );
+    harness.assert_screen_contains("File not found: missing.rs");
+}
@@ -0,0 +1,84 @@
+//! E2E tests for URL underlines and "Open URL Under Cursor"
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::input::keybindings::Action;
+use std::sync::Mutex;
+
+const NOTES: &str = "Docs live at https://example.com/guide?page=2. Read them.\n";
+
+/// URLs passed to the test opener
+static OPENED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_url(url: &str) -> std::io::Result<()> {
+    OPENED.lock().unwrap().push(url.to_string());
+    Ok(())
+}
+
+/// Text of every URL underline overlay in the active buffer
+fn underlined_urls(harness: &mut EditorTestHarness) -> Vec<String> {
+    let state = harness.editor_mut().active_state_mut();
+    let ranges: Vec<_> = state
+        .overlays
+        .all()
+        .iter()
+        .filter(|o| o.namespace.as_ref().map(|ns| ns.as_str()) == Some("url-underline"))
+        .map(|o| o.range(&state.marker_list))
+        .collect();
+    ranges
+        .into_iter()
+        .map(|r| state.get_text_range(r.start, r.end))
+        .collect()
+}
+
+/// The URL under the cursor, without the sentence's full stop, is handed
+/// to the opener
+#[test]
+fn test_open_url_under_cursor_launches_url() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.txt", NOTES)
+        .unwrap();
+    harness.editor_mut().set_url_opener(record_url);
+
+    // Into "example.com"
+    for _ in 0..25 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::OpenUrlUnderCursor);
+    harness.render().unwrap();
+
+    assert_eq!(
+        *OPENED.lock().unwrap(),
+        vec!["https://example.com/guide?page=2".to_string()]
+    );
+    harness.assert_screen_contains("Opening: https://example.com/guide?page=2");
+}
+
+/// URLs are underlined only with `underline_urls` on
+#[test]
+fn test_urls_underlined_when_enabled() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.txt", NOTES)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(underlined_urls(&mut harness).is_empty());
+
+    let mut config = Config::default();
+    config.editor.underline_urls = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.txt", NOTES)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        underlined_urls(&mut harness),
+        vec!["https://example.com/guide?page=2".to_string()]
+    );
+}
@@ -4,6 +4,8 @@
 //! - Paste with selection (should replace selection)
 //! - Multi-cursor paste
 //! - Paste undo atomicity
+//! - Bracketed paste sequences from the terminal inserted verbatim
+//! - Yank ring paste-cycling and line-wise paste
 //!
 //! Issue #372: External paste should behave like internal paste
 
@@ -166,6 +168,41 @@ fn test_paste_undo_is_atomic() {
     harness.assert_buffer_content("hello world");
 }
 
+/// Test that a bracketed paste block arriving from the terminal is inserted
+/// verbatim as a single undo step, without auto-pairing or auto-indent
+/// touching the bracket characters inside it.
+#[test]
+fn test_bracketed_paste_inserted_verbatim_as_one_undo_step() {
+    use fresh::server::input_parser::InputParser;
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("x").unwrap();
+
+    let pasted = "if (a) {\n    b[0] = \"c\";\n}\n";
+    let raw = format!("\x1b[200~{}\x1b[201~", pasted);
+
+    // Feed the raw bytes in small chunks, as a terminal read loop would.
+    let mut parser = InputParser::new();
+    let mut events = Vec::new();
+    for chunk in raw.as_bytes().chunks(5) {
+        events.extend(parser.parse(chunk));
+    }
+    assert_eq!(events.len(), 1, "paste should arrive as one event");
+    match events.remove(0) {
+        crossterm::event::Event::Paste(text) => harness.editor_mut().paste_text(text),
+        other => panic!("Expected Paste event, got {:?}", other),
+    }
+    harness.render().unwrap();
+
+    harness.assert_buffer_content(&format!("x{}", pasted));
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("x");
+}
+
 /// Test that multi-cursor paste is atomic for undo
 #[test]
 fn test_multi_cursor_paste_undo_is_atomic() {
@@ -326,6 +363,69 @@ fn test_paste_in_middle() {
     harness.assert_buffer_content("hello world");
 }
 
+/// Paste-cycling swaps the just-pasted text for an earlier yank ring entry
+#[test]
+fn test_paste_cycle_previous_replaces_pasted_text() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("x ").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("first".to_string());
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("second".to_string());
+    harness.editor_mut().paste_for_test();
+    harness.assert_buffer_content("x second");
+
+    harness.editor_mut().paste_cycle_previous();
+    harness.assert_buffer_content("x first");
+
+    harness.editor_mut().paste_cycle_next();
+    harness.assert_buffer_content("x second");
+
+    // The whole paste-and-cycle sequence stays undoable
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("x first");
+}
+
+/// Paste-cycling only applies immediately after a paste
+#[test]
+fn test_paste_cycle_requires_preceding_paste() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("first".to_string());
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("second".to_string());
+    harness.editor_mut().paste_for_test();
+    harness.type_text("!").unwrap();
+
+    harness.editor_mut().paste_cycle_previous();
+    harness.assert_buffer_content("second!");
+}
+
+/// A line copied without a selection pastes as a whole line above the cursor
+#[test]
+fn test_linewise_copy_pastes_whole_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("one\ntwo").unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    // Cursor on "two" without a selection: Ctrl+C copies the line
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("two\none\ntwo");
+}
+
 // ============================================================================
 // Prompt paste tests
 // ============================================================================
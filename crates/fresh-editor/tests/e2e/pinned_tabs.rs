@@ -0,0 +1,74 @@
+//! E2E tests for pinned tabs
+
+use crate::common::harness::EditorTestHarness;
+use fresh::input::keybindings::Action;
+use tempfile::TempDir;
+
+/// Open `names` as files and return the harness with the last one active
+fn harness_with_files(names: &[&str]) -> (EditorTestHarness, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    for name in names {
+        let path = temp_dir.path().join(name);
+        std::fs::write(&path, name).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    (harness, temp_dir)
+}
+
+/// A pinned tab survives "Close Others" and moves to the left of the tab bar
+#[test]
+fn test_pinned_tab_survives_close_others() {
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+
+    // Pin c.txt, then close everything except a.txt
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::TogglePinTab);
+    harness.render().unwrap();
+    let tab_bar = harness.screen_row_text(1);
+    let pinned_pos = tab_bar.find("▪ c.txt").expect("pin indicator on c.txt");
+    assert!(pinned_pos < tab_bar.find("a.txt").unwrap());
+
+    let (a_col, a_row) = harness.find_text_on_screen("a.txt").unwrap();
+    harness.mouse_click(a_col, a_row).unwrap();
+    let split = harness.editor().get_active_split();
+    let keep = harness.editor().active_buffer();
+    harness.editor_mut().close_other_tabs_in_split(keep, split);
+    harness.render().unwrap();
+
+    let tab_bar = harness.screen_row_text(1);
+    assert!(tab_bar.contains("a.txt"), "tab bar: {tab_bar:?}");
+    assert!(tab_bar.contains("c.txt"), "tab bar: {tab_bar:?}");
+    assert!(!tab_bar.contains("b.txt"), "tab bar: {tab_bar:?}");
+}
+
+/// "Close to the Left" skips pinned tabs, and unpinning makes them closable
+/// again
+#[test]
+fn test_close_to_left_skips_pinned_tab() {
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+    let split = harness.editor().get_active_split();
+    let c_txt = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::TogglePinTab);
+    harness.render().unwrap();
+
+    // Tabs are now [c.txt, a.txt, b.txt]
+    let (b_col, b_row) = harness.find_text_on_screen("b.txt").unwrap();
+    harness.mouse_click(b_col, b_row).unwrap();
+    let b_txt = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .close_tabs_to_left_in_split(b_txt, split);
+    harness.render().unwrap();
+    let tab_bar = harness.screen_row_text(1);
+    assert!(tab_bar.contains("c.txt"), "tab bar: {tab_bar:?}");
+    assert!(!tab_bar.contains("a.txt"), "tab bar: {tab_bar:?}");
+
+    harness.editor_mut().toggle_tab_pin(c_txt);
+    harness.editor_mut().close_all_tabs_in_split(split);
+    harness.render().unwrap();
+    assert!(!harness.screen_row_text(1).contains("c.txt"));
+}
@@ -0,0 +1,122 @@
+//! E2E tests for the diagnostics panel's severity filter: the panel keys
+//! hide severities, and `diagnostics_panel_severities` sets the filter the
+//! panel opens with.
+
+use crate::common::fake_lsp::{
+    diagnostic_json, diagnostics_server_config, write_diagnostics_server,
+};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::DiagnosticsPanelSeverity;
+use std::fs;
+
+/// Open `main.rs` against a server publishing one diagnostic of each
+/// severity, then open the diagnostics panel.
+fn open_panel_with_mixed_severities(
+    temp_dir: &tempfile::TempDir,
+    mut config: fresh::config::Config,
+) -> anyhow::Result<EditorTestHarness> {
+    let project_root = temp_dir.path().canonicalize()?;
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir)?;
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let diagnostics = [
+        diagnostic_json(0, 1, "error one", "fake"),
+        diagnostic_json(1, 2, "warning one", "fake"),
+        diagnostic_json(2, 3, "info one", "fake"),
+        diagnostic_json(3, 4, "hint one", "fake"),
+    ];
+    let server = write_diagnostics_server(
+        &project_root,
+        "mixed",
+        &format!("[{}]", diagnostics.join(",")),
+    )?;
+
+    let test_file = project_root.join("main.rs");
+    fs::write(&test_file, "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\n")?;
+
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config("mixed", &server)]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|h| {
+        h.editor()
+            .get_stored_diagnostics()
+            .values()
+            .any(|diags| diags.len() == 4)
+    })?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Show Diagnostics Panel")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.screen_to_string().contains("error one"))?;
+
+    Ok(harness)
+}
+
+/// Hiding warnings, info and hints from the panel leaves only `[E]` entries.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_diagnostics_panel_errors_only_filter() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let mut harness =
+        open_panel_with_mixed_severities(&temp_dir, fresh::config::Config::default())?;
+
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("[W]") && screen.contains("[I]") && screen.contains("[H]")
+    })?;
+
+    harness.send_key(KeyCode::Char('w'), KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char('i'), KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char('h'), KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        !screen.contains("[W]") && !screen.contains("[I]") && !screen.contains("[H]")
+    })?;
+
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("[E] 1:1 error one"),
+        "the error should stay listed. Screen:\n{screen}"
+    );
+    for hidden in ["warning one", "info one", "hint one"] {
+        assert!(
+            !screen.contains(hidden),
+            "{hidden:?} should be filtered out. Screen:\n{screen}"
+        );
+    }
+
+    // Showing warnings again brings them back.
+    harness.send_key(KeyCode::Char('w'), KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.screen_to_string().contains("[W] 2:1 warning one"))?;
+
+    Ok(())
+}
+
+/// The panel opens with the severities configured in
+/// `diagnostics_panel_severities`.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_diagnostics_panel_filter_from_config() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let mut config = fresh::config::Config::default();
+    config.editor.diagnostics_panel_severities = vec![DiagnosticsPanelSeverity::Error];
+    let harness = open_panel_with_mixed_severities(&temp_dir, config)?;
+
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("[E] 1:1 error one"));
+    assert!(
+        !screen.contains("[W]") && !screen.contains("[I]") && !screen.contains("[H]"),
+        "only errors should be listed. Screen:\n{screen}"
+    );
+
+    Ok(())
+}
@@ -4,8 +4,11 @@
 //! 1. Pressing Enter on a diagnostic entry jumps to the location and focuses the editor
 //! 2. Moving up/down in the panel scrolls the editor to show the diagnostic location
 //!    while keeping focus in the panel
+//! 3. Enter on a related-information entry jumps to that location, even in
+//!    another file
+//! 4. Enter on any row of a soft-wrapped long message jumps to its diagnostic
 
-use crate::common::fake_lsp::FakeLspServer;
+use crate::common::fake_lsp::{diagnostics_server_config, write_diagnostics_server, FakeLspServer};
 use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
 use crate::common::tracing::init_tracing_from_env;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -296,3 +299,176 @@ fn test_diagnostics_panel_cursor_move_scrolls_editor() {
         screen
     );
 }
+
+/// A diagnostic's related location is listed as a child entry under it,
+/// and Enter on that entry jumps to the location in the other file.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_diagnostics_panel_related_information_jumps_to_location() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().canonicalize().unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let test_file = project_root.join("test.rs");
+    fs::write(&test_file, "fn original() {}\n").unwrap();
+    let other_file = project_root.join("other.rs");
+    // Line 3, column 5 is byte 17
+    fs::write(&other_file, "first\nsecond\nfn original() {}\n").unwrap();
+
+    let related_uri = format!("file://{}", other_file.display());
+    let diagnostics = format!(
+        r#"[{{"range":{{"start":{{"line":0,"character":0}},"end":{{"line":0,"character":4}}}},"severity":1,"message":"duplicate definition","relatedInformation":[{{"location":{{"uri":"{related_uri}","range":{{"start":{{"line":2,"character":4}},"end":{{"line":2,"character":12}}}}}},"message":"first defined here"}}]}}]"#
+    );
+    let server = write_diagnostics_server(&project_root, "related", &diagnostics).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config("related", &server)]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root).unwrap();
+    harness.open_file(&test_file).unwrap();
+    harness.render().unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("E:1"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show Diagnostics Panel").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            h.screen_to_string()
+                .contains("↳ other.rs:3:5 first defined here")
+        })
+        .unwrap();
+
+    // Panel layout: line 1=title, line 2=blank, line 3=filename,
+    // line 4=the diagnostic, line 5=its related location
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Item 2/"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            let content = h.get_buffer_content().unwrap_or_default();
+            content.starts_with("first\nsecond\n") && h.cursor_position() == 17
+        })
+        .unwrap();
+}
+
+/// With wrapping switched on (`l`), a long message is shown in full over
+/// several rows, and Enter on a continuation row still jumps to that
+/// diagnostic.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_diagnostics_panel_wraps_long_messages() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().canonicalize().unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let test_file = project_root.join("test.rs");
+    // Line 3 starts at byte 14
+    fs::write(&test_file, "line 0\nline 1\nline 2\n").unwrap();
+
+    let long_message = (0..50)
+        .map(|i| format!("word{i:02}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let diagnostics = format!(
+        "[{},{}]",
+        diagnostic_json(0, 1, "short one", "fake"),
+        diagnostic_json(2, 1, &long_message, "fake")
+    );
+    let server = write_diagnostics_server(&project_root, "long", &diagnostics).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.diagnostics_panel_wrap = false;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![diagnostics_server_config("long", &server)]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 40, config, project_root).unwrap();
+    harness.open_file(&test_file).unwrap();
+    harness.render().unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("E:2"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show Diagnostics Panel").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("short one"))
+        .unwrap();
+    // Cut off while wrapping is off
+    harness.assert_screen_not_contains("word49");
+
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("word49"))
+        .unwrap();
+    let screen = harness.screen_to_string();
+    let rows = screen.lines().filter(|row| row.contains("word")).count();
+    assert!(
+        rows >= 3,
+        "Expected the long message to wrap over several rows.\nScreen:\n{}",
+        screen
+    );
+
+    // Panel layout: line 1=title, line 2=blank, line 3=filename,
+    // line 4=short diagnostic, line 5=long diagnostic; one more Down
+    // lands on the long diagnostic's second row
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Item 2/2"))
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            let content = h.get_buffer_content().unwrap_or_default();
+            content.starts_with("line 0\n") && h.cursor_position() == 14
+        })
+        .unwrap();
+}
@@ -0,0 +1,95 @@
+//! E2E test for remapping a key chord to a plugin command.
+//!
+//! Users who want a leader-key style binding for "Show Diagnostics Panel"
+//! declare a multi-key `keys` sequence in their config. The custom chord is
+//! resolved before the built-in defaults and dispatched to the plugin action.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_remapped_chord_opens_diagnostics_panel() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().to_path_buf();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let test_file = project_root.join("test.rs");
+    fs::write(&test_file, "line 00 content here\nline 01 content here\n").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::many_diagnostics_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+    // Leader chord: Alt+G, then D
+    config.keybindings.push(fresh::config::Keybinding {
+        key: String::new(),
+        modifiers: vec![],
+        keys: vec![
+            fresh::config::KeyPress {
+                key: "g".to_string(),
+                modifiers: vec!["alt".to_string()],
+            },
+            fresh::config::KeyPress {
+                key: "d".to_string(),
+                modifiers: vec![],
+            },
+        ],
+        action: "show_diagnostics_panel".to_string(),
+        args: std::collections::HashMap::new(),
+        when: None,
+    });
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 40, config, project_root).unwrap();
+    harness.open_file(&test_file).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .wait_until(|h| {
+            let overlays = h.editor().active_state().overlays.all();
+            let diagnostic_ns = fresh::services::lsp::diagnostics::lsp_diagnostic_namespace();
+            overlays
+                .iter()
+                .any(|o| o.namespace.as_ref() == Some(&diagnostic_ns))
+        })
+        .unwrap();
+    harness.assert_screen_not_contains("Diagnostics (");
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::ALT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("Diagnostics (") && screen.contains("[E]")
+        })
+        .unwrap();
+}
@@ -0,0 +1,81 @@
+//! E2E test for diagnostics from several LSP servers of one language: the
+//! diagnostics panel lists every server's diagnostics, with a diagnostic
+//! reported by both servers shown once.
+
+use crate::common::fake_lsp::{
+    diagnostic_json, diagnostics_server_config, write_diagnostics_server,
+};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// A language server and a linter both publish diagnostics for `main.rs`.
+/// The panel shows the diagnostics of both, and the one they share once.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_diagnostics_from_two_servers_are_merged_in_panel() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir)?;
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let shared = diagnostic_json(0, 1, "shared problem", "both");
+    let server = write_diagnostics_server(
+        &project_root,
+        "server",
+        &format!(
+            "[{shared},{}]",
+            diagnostic_json(1, 1, "server only problem", "server")
+        ),
+    )?;
+    let linter = write_diagnostics_server(
+        &project_root,
+        "linter",
+        &format!(
+            "[{},{shared}]",
+            diagnostic_json(2, 1, "linter only problem", "linter")
+        ),
+    )?;
+
+    let test_file = project_root.join("main.rs");
+    fs::write(&test_file, "fn a() {}\nfn b() {}\nfn c() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![
+            diagnostics_server_config("server", &server),
+            diagnostics_server_config("linter", &linter),
+        ]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root)?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|h| {
+        h.editor()
+            .get_stored_diagnostics()
+            .values()
+            .any(|diags| diags.len() == 3)
+    })?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Show Diagnostics Panel")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("server only problem") && screen.contains("linter only problem")
+    })?;
+
+    let screen = harness.screen_to_string();
+    assert_eq!(
+        screen.matches("shared problem").count(),
+        1,
+        "a diagnostic reported by both servers should be listed once. Screen:\n{screen}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! Tests for plugin event subscriptions via `editor.on()` and the event
+//! aliases (`buffer_saved`, `buffer_opened`, `text_changed`).
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// A plugin subscribed to `buffer_saved` gets the saved path and can react by
+/// writing a marker into a virtual buffer.
+#[test]
+fn test_buffer_saved_event_writes_marker_to_virtual_buffer() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+registerHandler("on_buffer_saved", async (data: { buffer_id: number; path: string }) => {
+    await editor.createVirtualBuffer({
+        name: "*save-log*",
+        readOnly: true,
+        entries: [{ text: `SAVED-MARKER ${editor.pathBasename(data.path)}\n` }],
+    });
+});
+
+editor.on("buffer_saved", "on_buffer_saved");
+"#;
+    fs::write(plugins_dir.join("save_log.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("notes.txt", "hello\n").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.type_text("x").unwrap();
+    harness.assert_screen_not_contains("SAVED-MARKER");
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("SAVED-MARKER notes.txt"))
+        .unwrap();
+}
@@ -0,0 +1,89 @@
+//! Tests for plugin gutter signs (`editor.setLineSign`).
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// Write `plugin_source` into a fresh project's `plugins/` dir and start an
+/// editor there.
+fn harness_with_plugin(plugin_source: &str) -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    fs::write(plugins_dir.join("signs.ts"), plugin_source).unwrap();
+
+    let harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    (temp_dir, harness)
+}
+
+const SIGN_ON_THIRD_LINE: &str = r#"
+const editor = getEditor();
+registerHandler("place_sign", (data: { buffer_id: number; path: string }) => {
+    editor.setLineSign(data.buffer_id, 2, "bookmarks", "★", { fg: "diagnostic.info_fg", priority: 5 });
+});
+editor.on("buffer_opened", "place_sign");
+"#;
+
+/// A sign is anchored to its line's text: inserting a line above moves the
+/// sign down with it instead of leaving it on the third row.
+#[test]
+fn test_line_sign_follows_text_when_line_inserted_above() {
+    let (_temp_dir, mut harness) = harness_with_plugin(SIGN_ON_THIRD_LINE);
+    let fixture = TestFixture::new("notes.txt", "alpha\nbeta\ngamma\ndelta\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.find_text_on_screen("gamma")
+                .is_some_and(|(_, row)| h.get_cell(0, row).as_deref() == Some("★"))
+        })
+        .unwrap();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.type_text("inserted").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let (_, gamma_row) = harness.find_text_on_screen("gamma").unwrap();
+    let (_, inserted_row) = harness.find_text_on_screen("inserted").unwrap();
+    assert_eq!(inserted_row + 1, gamma_row);
+    assert_eq!(harness.get_cell(0, gamma_row).as_deref(), Some("★"));
+    assert_ne!(harness.get_cell(0, inserted_row).as_deref(), Some("★"));
+}
+
+/// When two namespaces sign the same line, the higher priority wins.
+#[test]
+fn test_line_sign_priority_resolution() {
+    let plugin = r#"
+const editor = getEditor();
+registerHandler("place_signs", (data: { buffer_id: number; path: string }) => {
+    editor.setLineSign(data.buffer_id, 1, "low", "L", { priority: 1 });
+    editor.setLineSign(data.buffer_id, 1, "high", "H", { fg: [255, 0, 0], bold: true, priority: 9 });
+});
+editor.on("buffer_opened", "place_signs");
+"#;
+    let (_temp_dir, mut harness) = harness_with_plugin(plugin);
+    let fixture = TestFixture::new("notes.txt", "alpha\nbeta\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.find_text_on_screen("beta")
+                .is_some_and(|(_, row)| h.get_cell(0, row).as_deref() == Some("H"))
+        })
+        .unwrap();
+    let (_, beta_row) = harness.find_text_on_screen("beta").unwrap();
+    let style = harness.get_cell_style(0, beta_row).unwrap();
+    assert_eq!(style.fg, Some(ratatui::style::Color::Rgb(255, 0, 0)));
+    assert!(style.add_modifier.contains(ratatui::style::Modifier::BOLD));
+}
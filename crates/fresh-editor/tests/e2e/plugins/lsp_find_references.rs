@@ -1,6 +1,6 @@
 //! LSP find references tests that depend on the find_references plugin
 
-use crate::common::harness::{copy_plugin, EditorTestHarness};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
 use crossterm::event::{KeyCode, KeyModifiers};
 
 /// Test find references functionality with a fake LSP server
@@ -173,6 +173,157 @@ done
     Ok(())
 }
 
+/// References land in the References panel in the Utility Dock, one line
+/// per location, and Enter on a line jumps to it.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_find_references_populates_panel() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().canonicalize()?;
+
+    let plugins_dir = project_root.join("plugins");
+    std::fs::create_dir(&plugins_dir)?;
+    copy_plugin(&plugins_dir, "find_references");
+    copy_plugin_lib(&plugins_dir);
+
+    let log_file = project_root.join("lsp_log.txt");
+    let fake_lsp_script = format!(
+        r##"#!/bin/bash
+
+LOG_FILE="{log_path}"
+
+> "$LOG_FILE"
+
+read_message() {{
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+
+    if [ -z "$msg" ]; then
+        break
+    fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"referencesProvider":true,"textDocumentSync":1}}}}}}'
+            ;;
+        "initialized")
+            echo "ACTION: initialized" >> "$LOG_FILE"
+            ;;
+        "textDocument/diagnostic")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"kind":"full","items":[]}}}}'
+            ;;
+        "textDocument/references")
+            uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":[{{"uri":"'$uri'","range":{{"start":{{"line":0,"character":3}},"end":{{"line":0,"character":12}}}}}},{{"uri":"'$uri'","range":{{"start":{{"line":2,"character":4}},"end":{{"line":2,"character":13}}}}}},{{"uri":"'$uri'","range":{{"start":{{"line":4,"character":4}},"end":{{"line":4,"character":13}}}}}}]}}'
+            echo "SENT: references" >> "$LOG_FILE"
+            ;;
+        "shutdown")
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+            break
+            ;;
+    esac
+done
+"##,
+        log_path = log_file.display()
+    );
+
+    let script_path = project_root.join("fake_lsp.sh");
+    std::fs::write(&script_path, fake_lsp_script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms)?;
+    }
+
+    let test_file = project_root.join("test.rs");
+    std::fs::write(
+        &test_file,
+        "fn test_func() {\n    println!(\"hello\");\n    test_func();\n    let x = 1;\n    test_func();\n}\n",
+    )?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root.clone())?;
+    harness.open_file(&test_file)?;
+    harness.wait_until(|_| {
+        std::fs::read_to_string(&log_file)
+            .unwrap_or_default()
+            .contains("ACTION: initialized")
+    })?;
+
+    // Cursor on `test_func` in the definition line.
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.process_async_and_render()?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.wait_for_prompt()?;
+    harness.type_text("Find References")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("*References*")
+            && screen.contains("test.rs:1")
+            && screen.contains("test.rs:3")
+            && screen.contains("test.rs:5")
+    })?;
+    assert!(
+        !harness.editor().is_prompting(),
+        "references should be shown in a panel, not a prompt"
+    );
+
+    Ok(())
+}
+
 /// Test find references with real rust-analyzer on a cargo project
 ///
 /// This test creates a temporary cargo project, starts rust-analyzer,
@@ -26,12 +26,17 @@ pub mod devcontainer_spec_conformance;
 pub mod devcontainer_spec_repros;
 pub mod devcontainer_usability_repros;
 pub mod diagnostics_panel_bugs;
+pub mod diagnostics_panel_filter;
 pub mod diagnostics_panel_jump;
+pub mod diagnostics_panel_keybinding;
+pub mod diagnostics_panel_multi_server;
 pub mod diff_cursor;
+pub mod event_subscription;
 pub mod find_file;
 pub mod git;
 pub mod git_log_split_tab_focus;
 pub mod gutter;
+pub mod gutter_signs;
 pub mod init_script;
 pub mod language_pack;
 pub mod live_diff;
@@ -39,9 +44,11 @@ pub mod load_from_buffer;
 pub mod lsp_find_references;
 pub mod markdown_source;
 pub mod package_manager;
+pub mod palette_commands;
 pub mod plugin;
 pub mod plugin_keybinding_execution;
 pub mod plugins_dir_in_working_dir;
 pub mod review_diff_ux_bugs;
+pub mod status_bar_segment;
 pub mod theme_editor;
 pub mod unified_keybindings;
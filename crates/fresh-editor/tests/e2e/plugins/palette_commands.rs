@@ -0,0 +1,62 @@
+//! Tests for plugin commands contributed to the command palette.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// Run a palette command by typing `query` and pressing Enter.
+fn run_palette_command(harness: &mut EditorTestHarness, query: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(query).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains(query))
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// A plugin command is listed in the palette and runs its handler. A second
+/// plugin reusing the same handler name still runs its own function.
+#[test]
+fn test_plugin_palette_command_runs_handler() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let my_plugin = r#"
+const editor = getEditor();
+registerHandler("do_thing", () => editor.setStatus("MY-PLUGIN-DID-THING"));
+editor.registerCommand("My Plugin: Do Thing", "Does the thing", "do_thing", null);
+"#;
+    let other_plugin = r#"
+const editor = getEditor();
+registerHandler("do_thing", () => editor.setStatus("OTHER-PLUGIN-DID-THING"));
+editor.registerCommand("Other Plugin: Do Thing", "Does another thing", "do_thing", null);
+"#;
+    fs::write(plugins_dir.join("my_plugin.ts"), my_plugin).unwrap();
+    fs::write(plugins_dir.join("other_plugin.ts"), other_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "hello\n").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_palette_command(&mut harness, "My Plugin: Do Thing");
+    harness
+        .wait_until(|h| h.get_status_bar().contains("MY-PLUGIN-DID-THING"))
+        .unwrap();
+
+    run_palette_command(&mut harness, "Other Plugin: Do Thing");
+    harness
+        .wait_until(|h| h.get_status_bar().contains("OTHER-PLUGIN-DID-THING"))
+        .unwrap();
+}
@@ -0,0 +1,113 @@
+//! Tests for plugin status bar segments (`editor.registerStatusBarSegment`).
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use std::fs;
+
+/// Write `plugin_source` into a fresh project's `plugins/` dir and start an
+/// editor there.
+fn harness_with_plugin(width: u16, plugin_source: &str) -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    fs::write(plugins_dir.join("segments.ts"), plugin_source).unwrap();
+
+    let harness =
+        EditorTestHarness::with_config_and_working_dir(width, 24, Default::default(), project_root)
+            .unwrap();
+    (temp_dir, harness)
+}
+
+/// Segments with fixed text render on their side of the status bar.
+#[test]
+fn test_plugin_status_bar_segments_render() {
+    let plugin = r#"
+const editor = getEditor();
+registerHandler("left_segment", () => "SEG-LEFT");
+registerHandler("right_segment", () => "SEG-RIGHT");
+editor.registerStatusBarSegment("test-left", "left", 0, "left_segment");
+editor.registerStatusBarSegment("test-right", "right", 0, "right_segment");
+"#;
+    let (_temp_dir, mut harness) = harness_with_plugin(120, plugin);
+    let fixture = TestFixture::new("test.txt", "hello\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness
+        .wait_until(|h| h.get_status_bar().contains("SEG-RIGHT"))
+        .unwrap();
+    let status = harness.get_status_bar();
+    let left = status.find("SEG-LEFT").expect("left segment rendered");
+    let right = status.find("SEG-RIGHT").unwrap();
+    assert!(
+        left < right,
+        "left segment should precede the right one: {status:?}"
+    );
+    assert!(
+        status.trim_end().ends_with("SEG-RIGHT"),
+        "right segment should be the last right-side element: {status:?}"
+    );
+}
+
+/// The render handler is re-run after editor events, so the segment follows
+/// state such as the active buffer.
+#[test]
+fn test_plugin_status_bar_segment_recomputed_on_state_change() {
+    let plugin = r#"
+const editor = getEditor();
+registerHandler("file_segment", () => {
+    const path = editor.getBufferPath(editor.getActiveBufferId());
+    return path ? `FILE:${editor.pathBasename(path)}` : "";
+});
+editor.registerStatusBarSegment("test-file", "right", 0, "file_segment");
+"#;
+    let (_temp_dir, mut harness) = harness_with_plugin(120, plugin);
+    let first = TestFixture::new("first.txt", "one\n").unwrap();
+    let second = TestFixture::new("second.txt", "two\n").unwrap();
+
+    harness.open_file(&first.path).unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("FILE:first.txt"))
+        .unwrap();
+
+    harness.open_file(&second.path).unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("FILE:second.txt"))
+        .unwrap();
+    assert!(!harness.get_status_bar().contains("FILE:first.txt"));
+}
+
+/// On a narrow terminal the lowest-priority right segment is dropped first
+/// and the bar still fits on one row.
+#[test]
+fn test_plugin_status_bar_segments_dropped_when_narrow() {
+    let plugin = r#"
+const editor = getEditor();
+registerHandler("high_segment", () => "HIGH");
+registerHandler("low_segment", () => "LOW-PRIORITY-SEGMENT-WITH-LONG-TEXT");
+editor.registerStatusBarSegment("test-high", "right", 10, "high_segment");
+editor.registerStatusBarSegment("test-low", "right", 1, "low_segment");
+"#;
+    let (_temp_dir, mut harness) = harness_with_plugin(160, plugin);
+    let fixture = TestFixture::new("test.txt", "hello\n").unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("LOW-PRIORITY"))
+        .unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.find("HIGH").unwrap() < status.find("LOW-PRIORITY").unwrap(),
+        "higher priority segment sits closer to the built-in elements: {status:?}"
+    );
+
+    harness.resize(50, 24).unwrap();
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        !status.contains("LOW-PRIORITY"),
+        "low priority segment dropped: {status:?}"
+    );
+    assert!(status.contains("test.txt"), "filename kept: {status:?}");
+}
@@ -0,0 +1,131 @@
+//! E2E tests for cycling buffers in most-recently-used order (Ctrl+Tab)
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+/// Open `names` as files, in order, and return the harness
+fn harness_with_files(names: &[&str]) -> (EditorTestHarness, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    for name in names {
+        let path = temp_dir.path().join(name);
+        std::fs::write(&path, format!("contents of {name}")).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    (harness, temp_dir)
+}
+
+fn active_name(harness: &EditorTestHarness) -> String {
+    harness
+        .editor()
+        .active_state()
+        .buffer
+        .file_path()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Holding Ctrl and pressing Tab repeatedly walks back through the buffers
+/// in the order they were focused, not in tab bar order
+#[test]
+fn test_ctrl_tab_cycles_in_mru_order() {
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+    assert_eq!(active_name(&harness), "c.txt");
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "b.txt");
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "a.txt");
+
+    // Any other key ends the cycle; a.txt is now the most recent buffer and
+    // c.txt, where the cycle started, the one before it
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "c.txt");
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "a.txt");
+}
+
+/// Ctrl+Shift+Tab walks the same order backwards
+#[test]
+fn test_ctrl_shift_tab_cycles_backwards() {
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+
+    harness
+        .send_key(
+            KeyCode::BackTab,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    assert_eq!(active_name(&harness), "a.txt");
+    harness
+        .send_key(
+            KeyCode::BackTab,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    assert_eq!(active_name(&harness), "b.txt");
+}
+
+/// Releasing Ctrl ends the cycle, so separate Ctrl+Tab presses flip
+/// between the two most recent buffers
+#[test]
+fn test_ctrl_release_ends_cycle() {
+    use crossterm::event::ModifierKeyCode;
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+    let release_ctrl = |harness: &mut EditorTestHarness| {
+        harness.editor_mut().handle_key_release(
+            KeyCode::Modifier(ModifierKeyCode::LeftControl),
+            KeyModifiers::CONTROL,
+        );
+    };
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "b.txt");
+    release_ctrl(&mut harness);
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "c.txt");
+    release_ctrl(&mut harness);
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "b.txt");
+}
+
+/// Without key release events, a pause between presses ends the cycle
+#[test]
+fn test_pause_ends_cycle() {
+    let (mut harness, _temp_dir) = harness_with_files(&["a.txt", "b.txt", "c.txt"]);
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "b.txt");
+    harness.advance_time(std::time::Duration::from_secs(2));
+
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_name(&harness), "c.txt");
+}
@@ -0,0 +1,123 @@
+//! E2E tests for the "Open Recent File" list persisted across sessions
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use fresh::input::keybindings::Action;
+use tempfile::TempDir;
+
+fn create_harness(
+    dir_context: &DirectoryContext,
+    project_dir: &std::path::Path,
+) -> EditorTestHarness {
+    EditorTestHarness::create(
+        100,
+        24,
+        HarnessOptions::new()
+            .with_config(Config::default())
+            .with_working_dir(project_dir.to_path_buf())
+            .with_shared_dir_context(dir_context.clone()),
+    )
+    .unwrap()
+}
+
+fn recent_file_names(harness: &EditorTestHarness) -> Vec<String> {
+    harness
+        .editor()
+        .recent_files()
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Files opened in one session are listed, most recent first, in the next
+#[test]
+fn test_recent_files_persist_across_sessions_in_mru_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let first = project_dir.join("first.txt");
+    let second = project_dir.join("second.txt");
+    std::fs::write(&first, "first file\n").unwrap();
+    std::fs::write(&second, "second file\n").unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    {
+        let mut harness = create_harness(&dir_context, &project_dir);
+        harness.open_file(&first).unwrap();
+        harness.open_file(&second).unwrap();
+        harness.shutdown(false).unwrap();
+    }
+
+    let mut harness = create_harness(&dir_context, &project_dir);
+    assert_eq!(recent_file_names(&harness), vec!["second.txt", "first.txt"]);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::OpenRecentFile);
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    let second_pos = screen.find("second.txt").expect("second.txt listed");
+    let first_pos = screen.find("first.txt").expect("first.txt listed");
+    assert!(
+        second_pos < first_pos,
+        "most recent file should be listed first"
+    );
+
+    // Typing filters the list; Enter opens the match
+    harness.type_text("first").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("first file");
+    assert_eq!(recent_file_names(&harness), vec!["first.txt", "second.txt"]);
+}
+
+/// Files deleted since they were opened are dropped from the list
+#[test]
+fn test_recent_files_skip_deleted_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let kept = project_dir.join("kept.txt");
+    let deleted = project_dir.join("deleted.txt");
+    std::fs::write(&kept, "").unwrap();
+    std::fs::write(&deleted, "").unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    {
+        let mut harness = create_harness(&dir_context, &project_dir);
+        harness.open_file(&kept).unwrap();
+        harness.open_file(&deleted).unwrap();
+        harness.shutdown(false).unwrap();
+    }
+    std::fs::remove_file(&deleted).unwrap();
+
+    let harness = create_harness(&dir_context, &project_dir);
+    assert_eq!(recent_file_names(&harness), vec!["kept.txt"]);
+}
+
+/// Opening files writes the list once, after a short delay, not per open
+#[test]
+fn test_recent_files_written_after_delay() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let file = project_dir.join("notes.txt");
+    std::fs::write(&file, "").unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let state_path = dir_context.recent_files_path();
+
+    let mut harness = create_harness(&dir_context, &project_dir);
+    harness.open_file(&file).unwrap();
+    harness.editor_mut().check_recent_files_save();
+    assert!(!state_path.exists());
+
+    harness.advance_time(std::time::Duration::from_secs(2));
+    harness.editor_mut().check_recent_files_save();
+    assert!(std::fs::read_to_string(&state_path)
+        .unwrap()
+        .contains("notes.txt"));
+}
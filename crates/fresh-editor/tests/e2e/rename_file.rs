@@ -0,0 +1,68 @@
+//! E2E tests for "Rename File".
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Renaming moves the file on disk and re-points the buffer and its tab.
+#[test]
+fn test_rename_current_file_moves_file_and_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    let old_path = project_dir.join("old_name.txt");
+    std::fs::write(&old_path, "hello\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.open_file(&old_path).unwrap();
+
+    harness
+        .editor_mut()
+        .rename_current_file(std::path::Path::new("new_name.txt"));
+    harness.render().unwrap();
+
+    let new_path = project_dir.join("new_name.txt");
+    assert!(!old_path.exists());
+    assert_eq!(std::fs::read_to_string(&new_path).unwrap(), "hello\n");
+    assert_eq!(
+        harness.editor().active_state().buffer.file_path(),
+        Some(new_path.as_path())
+    );
+    assert!(harness.get_tab_bar().contains("new_name.txt"));
+}
+
+/// An existing target is never overwritten.
+#[test]
+fn test_rename_current_file_refuses_existing_target() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    let old_path = project_dir.join("a.txt");
+    let taken = project_dir.join("b.txt");
+    std::fs::write(&old_path, "a\n").unwrap();
+    std::fs::write(&taken, "b\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.open_file(&old_path).unwrap();
+
+    harness.editor_mut().rename_current_file(&taken);
+    harness.render().unwrap();
+
+    assert_eq!(std::fs::read_to_string(&old_path).unwrap(), "a\n");
+    assert_eq!(std::fs::read_to_string(&taken).unwrap(), "b\n");
+    assert_eq!(
+        harness.editor().active_state().buffer.file_path(),
+        Some(old_path.as_path())
+    );
+    harness.assert_screen_contains("already exists");
+}
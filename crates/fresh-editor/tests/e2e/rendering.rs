@@ -875,3 +875,158 @@ fn test_current_column_highlight_disabled_is_noop() {
         "No column should be tinted when highlight_current_column is disabled"
     );
 }
+
+/// `show_indent_guides` draws a guide at each indentation level inside the
+/// leading whitespace (tabs expanded to `tab_size`), and the guide of the
+/// block containing the cursor uses a different colour.
+#[test]
+fn test_indent_guides_at_tab_expanded_columns() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::config::Config;
+    use ratatui::style::Color;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("indent_guides_test.txt");
+    std::fs::write(&file_path, "a:\n\tb:\n\t\tc\n\t\td\n\te\n").unwrap();
+
+    let mut config = Config {
+        theme: "dark".into(),
+        ..Default::default()
+    };
+    config.editor.show_indent_guides = true;
+    config.editor.tab_size = 4;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    // Put the cursor on "c", inside the innermost block (lines 2-3).
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let (x0, y0) = harness.find_text_on_screen("a:").unwrap();
+    let guide_fg = Color::Rgb(70, 70, 70);
+    let active_fg = Color::Rgb(100, 100, 100);
+    let guide = |harness: &EditorTestHarness, x: u16, y: u16| {
+        (
+            harness.get_cell(x, y).unwrap(),
+            harness.get_cell_style(x, y).unwrap().fg,
+        )
+    };
+
+    // One level: a guide at column 0 only.
+    assert_eq!(
+        guide(&harness, x0, y0 + 1),
+        ("│".to_string(), Some(guide_fg))
+    );
+    assert_ne!(harness.get_cell(x0 + 4, y0 + 1).unwrap(), "│");
+
+    // Two levels: guides at columns 0 and 4; the inner one is active.
+    for row in [y0 + 2, y0 + 3] {
+        assert_eq!(guide(&harness, x0, row), ("│".to_string(), Some(guide_fg)));
+        assert_eq!(
+            guide(&harness, x0 + 4, row),
+            ("│".to_string(), Some(active_fg))
+        );
+        assert_ne!(harness.get_cell(x0 + 2, row).unwrap(), "│");
+    }
+
+    // Back to one level, outside the active block.
+    assert_eq!(
+        guide(&harness, x0, y0 + 4),
+        ("│".to_string(), Some(guide_fg))
+    );
+    // No guide in front of unindented text.
+    assert_eq!(harness.get_cell(x0, y0).unwrap(), "a");
+}
+
+/// Relative line numbers count from the cursor line, which shows 0 (or its
+/// absolute number in hybrid mode), and the gutter is only as wide as the
+/// largest number shown.
+#[test]
+fn test_relative_and_hybrid_line_number_modes() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::config::{Config, LineNumberMode};
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("relative_numbers_test.txt");
+    let content: String = (1..=200).map(|i| format!("line{}\n", i)).collect();
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.line_number_mode = LineNumberMode::Relative;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+
+    for expected in [
+        " 3 │ line1",
+        " 2 │ line2",
+        " 1 │ line3",
+        " 0 │ line4",
+        " 1 │ line5",
+        " 2 │ line6",
+    ] {
+        harness.assert_screen_contains(expected);
+    }
+    let (relative_x, _) = harness.find_text_on_screen("line4").unwrap();
+
+    // Hybrid: the cursor line shows its absolute number instead.
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CycleLineNumberMode);
+    harness.render().unwrap();
+    harness.assert_screen_contains(" 4 │ line4");
+    harness.assert_screen_contains(" 1 │ line3");
+    harness.assert_screen_contains(" 1 │ line5");
+
+    // Absolute numbers need three digits for 200 lines; the relative
+    // distances on screen only needed two.
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CycleLineNumberMode);
+    harness.render().unwrap();
+    harness.assert_screen_contains("  4 │ line4");
+    let (absolute_x, _) = harness.find_text_on_screen("line4").unwrap();
+    assert_eq!(absolute_x, relative_x + 1);
+}
+
+/// A gutter layout without the line number column still draws the fold
+/// markers, and the content starts that many columns further left.
+#[test]
+fn test_gutter_columns_without_line_numbers_keep_fold_markers() {
+    use fresh::config::{Config, GutterColumn};
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("gutter_columns_test.py");
+    std::fs::write(&file_path, "def foo():\n    return 1\nafter = 2\n").unwrap();
+
+    let content_x = |config: Config| {
+        let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+        harness.open_file(&file_path).unwrap();
+        harness.render().unwrap();
+        let (x, y) = harness.find_text_on_screen("def foo():").unwrap();
+        (harness, x, y)
+    };
+
+    let (_, default_x, _) = content_x(Config::default());
+
+    let mut config = Config::default();
+    config.editor.gutter_columns = Some(vec![GutterColumn::DiagnosticSign, GutterColumn::Fold]);
+    let (harness, x, y) = content_x(config);
+
+    // Two one-cell sign columns replace the indicator cell and the
+    // two-digit line numbers: the content moves one column left.
+    assert_eq!(x + 1, default_x);
+    assert_eq!(harness.get_cell(1, y).unwrap(), "▾");
+    let row = harness.screen_row_text(y);
+    assert!(
+        row.starts_with(" ▾ │ def foo():"),
+        "unexpected gutter: {row:?}"
+    );
+    harness.assert_screen_not_contains("1 │");
+}
@@ -193,3 +193,37 @@ fn test_save_as_detects_language() {
         "Language should be detected as rust after Save As"
     );
 }
+
+/// Saving an opened `.txt` file as `.rs` writes a copy, leaves the original
+/// alone and switches the buffer to Rust highlighting.
+#[test]
+fn test_save_buffer_as_copies_file_and_switches_language() {
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_project_root()
+            .with_full_grammar_registry(),
+    )
+    .unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let original = project_dir.join("notes.txt");
+    fs::write(&original, "fn main() {}\n").unwrap();
+
+    harness.open_file(&original).unwrap();
+    assert_eq!(harness.editor().active_state().language, "text");
+
+    harness
+        .editor_mut()
+        .save_buffer_as(std::path::Path::new("main.rs"));
+    harness.render().unwrap();
+
+    let copy = project_dir.join("main.rs");
+    assert_eq!(fs::read_to_string(&copy).unwrap(), "fn main() {}\n");
+    assert_eq!(fs::read_to_string(&original).unwrap(), "fn main() {}\n");
+
+    let state = harness.editor().active_state();
+    assert_eq!(state.buffer.file_path(), Some(copy.as_path()));
+    assert_eq!(state.language, "rust");
+    assert!(state.highlighter.has_highlighting());
+}
@@ -2906,3 +2906,62 @@ fn test_page_up_changes_view_until_top() {
         );
     }
 }
+
+/// Fixture of `count` lines of eight bytes each ("line 000" plus newline)
+fn numbered_lines(count: usize) -> String {
+    (0..count).map(|i| format!("line {i:03}\n")).collect()
+}
+
+/// Moving down keeps `scroll_offset` lines visible below the cursor once the
+/// view starts scrolling
+#[test]
+fn test_scroll_offset_keeps_lines_below_cursor() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut config = fresh::config::Config::default();
+    config.editor.scroll_offset = 5;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(60)).unwrap();
+    harness.render().unwrap();
+
+    let (_, last_row) = harness.content_area_rows();
+    let mut scrolled = false;
+    for _ in 0..40 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        let (_, cursor_row) = harness.screen_cursor_position();
+        let rows_below = last_row - cursor_row as usize;
+        assert!(rows_below >= 5, "only {rows_below} rows below the cursor");
+        if harness.top_line_number() > 0 {
+            scrolled = true;
+            assert_eq!(
+                rows_below, 5,
+                "scrolled view should keep exactly the margin"
+            );
+        }
+    }
+    assert!(scrolled, "fixture should be long enough to scroll");
+}
+
+/// `center_cursor` (Recenter) puts the cursor line in the middle row
+#[test]
+fn test_center_cursor_puts_cursor_line_in_middle_row() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::input::keybindings::Action;
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(&numbered_lines(100)).unwrap();
+    harness.render().unwrap();
+
+    for _ in 0..50 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Recenter);
+    harness.render().unwrap();
+
+    let visible = harness.editor().active_viewport().visible_line_count();
+    let (first_row, _) = harness.content_area_rows();
+    let (_, cursor_row) = harness.screen_cursor_position();
+    assert_eq!(cursor_row as usize - first_row, visible / 2);
+    assert_eq!(harness.top_line_number(), 50 - visible / 2);
+    harness.assert_screen_contains("line 050");
+}
@@ -222,6 +222,75 @@ fn test_shell_command_tr_transform() {
     harness.assert_buffer_content("HELLO WORLD\n");
 }
 
+/// Run the "Shell Command (Replace)" palette command with `command`.
+fn run_shell_command_replace(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("shell command (replace)").unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(command).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+/// Only the selected text is piped through the filter and replaced.
+#[test]
+#[cfg_attr(not(unix), ignore = "Shell commands require Unix-like environment")]
+fn test_shell_command_replace_filters_selection_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed.txt");
+    std::fs::write(&file_path, "hello world\nsecond line\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    for _ in 0.."hello".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    run_shell_command_replace(&mut harness, "tr a-z A-Z");
+
+    harness.assert_buffer_content("HELLO world\nsecond line\n");
+}
+
+/// A failing filter leaves the selection untouched and reports its stderr.
+#[test]
+#[cfg_attr(not(unix), ignore = "Shell commands require Unix-like environment")]
+fn test_shell_command_replace_failure_keeps_selection() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed.txt");
+    std::fs::write(&file_path, "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    for _ in 0.."hello".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+
+    run_shell_command_replace(&mut harness, "echo filter-broke >&2; exit 3");
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("hello world\n");
+    assert_eq!(harness.get_selected_text(), "hello");
+    assert!(
+        harness.get_status_bar().contains("filter-broke"),
+        "stderr should be shown: {:?}",
+        harness.get_status_bar()
+    );
+}
+
 /// Test shell command undo after replace
 /// The shell command replace creates a Batch event for atomic undo
 #[test]
@@ -4,6 +4,7 @@
 // interactions within composite buffer diff views.
 
 use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use fresh::model::composite_buffer::{
     CompositeLayout, DiffHunk, LineAlignment, PaneStyle, SourcePane,
 };
@@ -424,3 +425,55 @@ fn test_side_by_side_diff_scroll_to_later_content() {
         screen
     );
 }
+
+/// Holding a drag selection below the diff view keeps scrolling it as time
+/// passes and extends the selection in the pane the drag started in.
+#[test]
+fn test_side_by_side_diff_drag_selection_auto_scrolls() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+
+    let (old_content, new_content, hunks) = generate_diff_content(200, 10);
+    let composite_id = setup_side_by_side_diff(&mut harness, &old_content, &new_content, &hunks);
+
+    let drag = |kind, row| MouseEvent {
+        kind,
+        column: 10,
+        row,
+        modifiers: KeyModifiers::empty(),
+    };
+    // Press on the first content row of the left pane (row 2 is its header)
+    harness
+        .send_mouse(drag(MouseEventKind::Down(MouseButton::Left), 3))
+        .unwrap();
+    harness.render().unwrap();
+    harness
+        .send_mouse(drag(MouseEventKind::Drag(MouseButton::Left), 39))
+        .unwrap();
+    harness.render().unwrap();
+    for _ in 0..4 {
+        harness.advance_time(std::time::Duration::from_millis(100));
+        harness.tick_and_render().unwrap();
+    }
+    harness
+        .send_mouse(drag(MouseEventKind::Up(MouseButton::Left), 39))
+        .unwrap();
+    harness.render().unwrap();
+
+    let split_id = harness.editor().get_active_split();
+    let view_state = harness
+        .editor_mut()
+        .get_composite_view_state(split_id, composite_id)
+        .unwrap();
+    assert!(view_state.scroll_row > 0, "Diff view should scroll");
+    assert!(view_state.visual_mode, "Drag should select text");
+    assert_eq!(view_state.selection_anchor_row, 0);
+    assert!(
+        view_state.cursor_row > 36,
+        "Selection should reach past the initially visible rows, got row {}",
+        view_state.cursor_row
+    );
+    assert!(
+        !harness.screen_to_string().contains("Line 1 original"),
+        "Line 1 should have scrolled out of view"
+    );
+}
@@ -86,3 +86,48 @@ fn test_smart_home_respects_soft_wrap() {
         pos_after_end
     );
 }
+
+/// Home toggles between the first non-whitespace character and column 0 on
+/// an indented line, and keeps toggling on further presses.
+#[test]
+fn test_smart_home_toggles_on_repeated_presses() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("    indented").unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 12);
+
+    let mut positions = Vec::new();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+        positions.push(harness.cursor_position());
+    }
+    assert_eq!(
+        positions,
+        vec![4, 0, 4],
+        "Home should go to the first non-whitespace, then column 0, then back"
+    );
+}
+
+/// The first non-whitespace target is a byte offset, so multi-byte leading
+/// whitespace (ideographic spaces here, 3 bytes each) is skipped correctly.
+#[test]
+fn test_smart_home_with_multibyte_leading_whitespace() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("\u{3000}\u{3000}text")
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 6);
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+}
@@ -0,0 +1,97 @@
+//! E2E tests for spell check squiggles and the suggestions popup
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::input::keybindings::Action;
+
+const NOTES: &str = "# Notes\n\nWe recieve the `snake_case` value from parseHTTP.\n";
+
+/// Word list used instead of whatever the machine has installed
+const WORDS: &str = include_str!("../fixtures/spell_words_en.txt");
+
+fn spell_check_harness() -> EditorTestHarness {
+    fresh::services::spell_check::set_word_list_for_tests(WORDS);
+    let mut config = Config::default();
+    config.editor.spell_check = true;
+    EditorTestHarness::with_config(80, 24, config).unwrap()
+}
+
+/// Text of every spell check overlay in the active buffer
+fn flagged_words(harness: &mut EditorTestHarness) -> Vec<String> {
+    let state = harness.editor_mut().active_state_mut();
+    let ranges: Vec<_> = state
+        .overlays
+        .all()
+        .iter()
+        .filter(|o| o.namespace.as_ref().map(|ns| ns.as_str()) == Some("spell-check"))
+        .map(|o| o.range(&state.marker_list))
+        .collect();
+    ranges
+        .into_iter()
+        .map(|r| state.get_text_range(r.start, r.end))
+        .collect()
+}
+
+/// A misspelled word in Markdown prose gets an overlay; code spans and
+/// identifiers do not
+#[test]
+fn test_spell_check_flags_misspelled_prose_word() {
+    let mut harness = spell_check_harness();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.md", NOTES)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(flagged_words(&mut harness), vec!["recieve".to_string()]);
+}
+
+/// Picking the first suggestion replaces the misspelled word
+#[test]
+fn test_spelling_suggestion_replaces_word() {
+    let mut harness = spell_check_harness();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.md", NOTES)
+        .unwrap();
+    harness.render().unwrap();
+
+    // Into "recieve" on the third line
+    for _ in 0..2 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SpellingSuggestions);
+    harness.render().unwrap();
+    harness.assert_screen_contains("receive");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("# Notes\n\nWe receive the `snake_case` value from parseHTTP.\n");
+    assert!(flagged_words(&mut harness).is_empty());
+}
+
+/// Nothing is flagged until spell checking is turned on for the buffer
+#[test]
+fn test_toggle_spell_check() {
+    fresh::services::spell_check::set_word_list_for_tests(WORDS);
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text_named("notes.md", NOTES)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(flagged_words(&mut harness).is_empty());
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleSpellCheck);
+    harness.render().unwrap();
+    assert_eq!(flagged_words(&mut harness), vec!["recieve".to_string()]);
+}
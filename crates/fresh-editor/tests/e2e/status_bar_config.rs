@@ -4,6 +4,7 @@
 //! control which elements appear (and don't appear) in the rendered status bar.
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::{Config, StatusBarConfig, StatusBarElement};
 use std::fs;
 
@@ -154,6 +155,79 @@ fn test_compact_cursor_format() {
     );
 }
 
+/// `Col` counts screen columns, so a cursor just after a tab is at the next
+/// tab stop; the byte offset and percentage elements report the raw position.
+#[test]
+fn test_cursor_column_expands_tabs() {
+    let config = config_with_status_bar(
+        vec![
+            StatusBarElement::Filename,
+            StatusBarElement::Cursor,
+            StatusBarElement::CursorByte,
+            StatusBarElement::CursorPercent,
+        ],
+        vec![],
+    );
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 30, config).unwrap();
+
+    let dir = harness.project_dir().unwrap();
+    let file = dir.join("test.txt");
+    fs::write(&file, "\tabc\n").unwrap();
+    harness.open_file(&file).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("Ln 1, Col 5"),
+        "Cursor after a tab should be at Col 5 with tab size 4.\nStatus bar: {status}"
+    );
+    assert!(
+        status.contains("Byte 1"),
+        "Byte offset should count the tab as one byte.\nStatus bar: {status}"
+    );
+    assert!(
+        status.contains("20%"),
+        "Cursor at byte 1 of 5 should be 20% through the file.\nStatus bar: {status}"
+    );
+}
+
+/// `{selection}` reports grapheme, line and word counts for the selection
+/// and disappears once it collapses.
+#[test]
+fn test_selection_counts() {
+    let config = config_with_status_bar(
+        vec![StatusBarElement::Filename, StatusBarElement::Selection],
+        vec![],
+    );
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 30, config).unwrap();
+
+    let dir = harness.project_dir().unwrap();
+    let file = dir.join("test.txt");
+    fs::write(&file, "héllo wörld\nfoo bar baz\nend\n").unwrap();
+    harness.open_file(&file).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("23 chars, 2 lines, 5 words selected"),
+        "Selection counts should be shown.\nStatus bar: {status}"
+    );
+
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        !status.contains("selected"),
+        "Selection counts should clear when the selection collapses.\nStatus bar: {status}"
+    );
+}
+
 /// Adding the `{remote}` element to the local status bar should render a
 /// visible "Local" indicator so the bottom-left remote-authority entry point
 /// is always present.
@@ -968,3 +968,78 @@ fn test_issue_1068_skip_over_with_dedent_uses_tabs_in_go() {
         );
     }
 }
+
+// =============================================================================
+// Tab stop alignment Tests
+// =============================================================================
+
+/// A per-language `tab_size` controls how wide a tab renders, independent of
+/// the global editor default.
+#[test]
+fn test_language_tab_size_controls_display_width() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "\tX").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 8;
+    if let Some(rust_config) = config.languages.get_mut("rust") {
+        rust_config.tab_size = Some(4);
+    }
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let (x_col, row) = harness.find_text_on_screen("X").unwrap();
+    assert_eq!(
+        harness.get_cell(x_col - 4, row).as_deref(),
+        Some("→"),
+        "With tab_size=4 the tab should span exactly 4 columns before X"
+    );
+}
+
+/// With spaces for indentation, Tab pads to the next tab stop rather than
+/// always inserting `tab_size` spaces.
+#[test]
+fn test_tab_inserts_spaces_to_next_tab_stop() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "ab").unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, Config::default()).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("c").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "ab  c");
+
+    // From an aligned column a full tab_size run is inserted
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "    ab  c");
+}
+
+/// Backspace inside space indentation removes spaces back to the previous tab
+/// stop, so a misaligned indent snaps to a whole level.
+#[test]
+fn test_backspace_snaps_space_indent_to_previous_tab_stop() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "      \n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, Config::default()).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "    \n");
+
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "\n");
+}
@@ -1,5 +1,5 @@
 use crate::common::harness::EditorTestHarness;
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use tempfile::TempDir;
 
 const NARROW_WIDTH: u16 = 40;
@@ -215,3 +215,82 @@ fn test_tab_scroll_button_click() {
         harness.render().unwrap();
     }
 }
+
+/// When tabs overflow, the "▼" button at the right end of the tab bar lists
+/// the hidden tabs and switches to the one picked
+#[test]
+fn test_tab_overflow_menu_switches_to_hidden_tab() {
+    let temp_dir = TempDir::new().unwrap();
+    let files = create_dummy_files(&temp_dir);
+
+    let mut harness = EditorTestHarness::new(NARROW_WIDTH, TEST_HEIGHT).unwrap();
+    for file_path in &files {
+        harness.open_file(file_path).unwrap();
+    }
+    harness.render().unwrap();
+
+    let tab_row = 1;
+    let tab_bar = harness.screen_row_text(tab_row);
+    assert!(
+        tab_bar.trim_end().ends_with('▼'),
+        "Expected overflow button at the end of the tab bar: {tab_bar:?}"
+    );
+    assert!(!tab_bar.contains("number_00"));
+
+    harness.mouse_click(NARROW_WIDTH - 1, tab_row).unwrap();
+    harness.assert_screen_contains("Hidden tabs:");
+    harness.type_text("number_00").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness
+            .editor()
+            .active_state()
+            .buffer
+            .file_path()
+            .and_then(|p| p.file_name()),
+        files[0].file_name()
+    );
+    assert!(harness.screen_row_text(tab_row).contains("number_00"));
+}
+
+/// Middle-clicking a tab closes it
+#[test]
+fn test_middle_click_closes_tab() {
+    let temp_dir = TempDir::new().unwrap();
+    let files = create_dummy_files(&temp_dir);
+
+    let mut harness = EditorTestHarness::new(80, TEST_HEIGHT).unwrap();
+    harness.open_file(&files[0]).unwrap();
+    harness.open_file(&files[1]).unwrap();
+    harness.render().unwrap();
+
+    let (col, row) = harness
+        .find_text_on_screen("long_file_name_number_01.txt")
+        .expect("tab for the second file should be visible");
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Middle),
+            column: col + 2,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness
+            .editor()
+            .active_state()
+            .buffer
+            .file_path()
+            .and_then(|p| p.file_name()),
+        files[0].file_name()
+    );
+    assert!(!harness
+        .screen_row_text(row)
+        .contains("long_file_name_number_01.txt"));
+}
@@ -0,0 +1,189 @@
+//! E2E tests for configured build/run tasks ("Run Task").
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, TaskConfig};
+use tempfile::TempDir;
+
+/// Running a task streams its output into a panel; clicking a
+/// `path:line:col` location in it opens that file at the line.
+#[test]
+#[cfg_attr(not(unix), ignore = "Tasks run through a Unix shell")]
+fn test_task_output_location_click_opens_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    std::fs::write(
+        project_dir.join("foo.rs"),
+        "fn one() {}\nfn two() {}\nfn three() {}\nfn four() {}\n",
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.tasks.insert(
+        "check".to_string(),
+        TaskConfig {
+            command: r#"echo "foo.rs:3:1: error""#.to_string(),
+            cwd: None,
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_dir.clone())
+            .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Run Task").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("[exit code 0]"))
+        .unwrap();
+    let (col, row) = harness
+        .find_text_on_screen("foo.rs:3:1: error")
+        .expect("task output in panel");
+
+    harness.mouse_click(col + 2, row).unwrap();
+    harness.render().unwrap();
+
+    let editor = harness.editor();
+    let path = editor
+        .active_state()
+        .buffer
+        .file_path()
+        .map(|p| p.to_path_buf());
+    assert_eq!(path, Some(project_dir.join("foo.rs")));
+    let line = editor
+        .active_state()
+        .buffer
+        .get_line_number(editor.active_cursors().primary().position);
+    assert_eq!(line, 2, "cursor should be on line 3 (0-indexed 2)");
+}
+
+/// The column of a `path:line:col` location counts characters, so a
+/// leading tab on the target line is a single column.
+#[test]
+#[cfg_attr(not(unix), ignore = "Tasks run through a Unix shell")]
+fn test_task_output_location_click_lands_on_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    std::fs::write(
+        project_dir.join("foo.rs"),
+        "fn one() {}\nfn two() {}\n\tlet value = 1;\n",
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.tasks.insert(
+        "check".to_string(),
+        TaskConfig {
+            command: r#"echo "foo.rs:3:7: error""#.to_string(),
+            cwd: None,
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_dir.clone())
+            .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Run Task").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("[exit code 0]"))
+        .unwrap();
+    let (col, row) = harness
+        .find_text_on_screen("foo.rs:3:7: error")
+        .expect("task output in panel");
+    harness.mouse_click(col + 2, row).unwrap();
+    harness.render().unwrap();
+
+    let editor = harness.editor();
+    let position = editor.active_cursors().primary().position;
+    // Line 3 starts at byte 24. Counting the tab as one character, column
+    // 7 is the 'a' of "value", six bytes into the line.
+    assert_eq!(position, 24 + 6);
+    let line = editor.active_state().buffer.get_line_number(position);
+    assert_eq!(line, 2);
+}
+
+/// With no tasks configured the command explains where to add them.
+#[test]
+fn test_run_task_without_tasks_shows_hint() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Run Task").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.get_status_bar().contains("No tasks configured"));
+}
+
+/// Re-running a task that is still running kills the previous process, so
+/// only the new run gets to finish.
+#[test]
+#[cfg_attr(not(unix), ignore = "Tasks run through a Unix shell")]
+fn test_rerunning_task_kills_previous_run() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+
+    let mut config = Config::default();
+    config.tasks.insert(
+        "slow".to_string(),
+        TaskConfig {
+            command: "echo started; sleep 1; echo run >> runs.txt".to_string(),
+            cwd: None,
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_dir.clone())
+            .unwrap();
+
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("Run Task").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("slow").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .wait_until(|h| h.screen_to_string().contains("started"))
+            .unwrap();
+    }
+
+    harness
+        .wait_until(|h| h.screen_to_string().contains("[exit code 0]"))
+        .unwrap();
+    let runs = std::fs::read_to_string(project_dir.join("runs.txt")).unwrap();
+    assert_eq!(runs, "run\n", "the first run should have been killed");
+}
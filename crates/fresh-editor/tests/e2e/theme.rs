@@ -784,3 +784,112 @@ fn test_theme_selector_strips_url_scheme_from_display() {
 
     drop(temp_dir);
 }
+
+/// `set_theme` re-renders syntax highlighting with the new theme's colors
+/// and leaves the buffer and cursor untouched
+#[test]
+fn test_set_theme_recolors_syntax_highlighting() {
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("main.rs");
+    fs::write(&file_path, "fn main() {}\n").unwrap();
+
+    let config = Config {
+        theme: "dark".into(),
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    let keyword_fg = |h: &EditorTestHarness| {
+        h.find_text_on_screen("fn main")
+            .and_then(|(x, y)| h.get_cell_style(x, y))
+            .and_then(|s| s.fg)
+    };
+    let dark_keyword = harness.editor().theme().syntax_keyword;
+    harness
+        .wait_until(|h| keyword_fg(h) == Some(dark_keyword))
+        .unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetTheme("light".to_string()));
+    harness.render().unwrap();
+
+    let light_keyword = harness.editor().theme().syntax_keyword;
+    assert_eq!(harness.editor().theme().name, "light");
+    assert_ne!(light_keyword, dark_keyword);
+    assert_eq!(keyword_fg(&harness), Some(light_keyword));
+    harness.assert_buffer_content("fn main() {}\n");
+    assert_eq!(harness.cursor_position(), 1);
+}
+
+/// Editing the active user theme's file is picked up by `reload_theme`, and
+/// automatically by polling the file
+#[test]
+fn test_reload_theme_applies_edited_theme_file() {
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    let theme_path = themes_dir.join("dev.json");
+    let write_theme = |bg: [u8; 3]| {
+        fs::write(
+            &theme_path,
+            format!(
+                r#"{{"name": "dev", "editor": {{ "bg": [{}, {}, {}] }}, "ui": {{}}, "search": {{}}, "diagnostic": {{}}, "syntax": {{}}}}"#,
+                bg[0], bg[1], bg[2]
+            ),
+        )
+        .unwrap();
+    };
+    write_theme([10, 20, 30]);
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+    let mut harness = EditorTestHarness::create(
+        100,
+        40,
+        HarnessOptions::new()
+            .with_config(Config {
+                theme: "dev".into(),
+                ..Default::default()
+            })
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+    let editor_bg = |h: &EditorTestHarness| h.get_cell_style(5, 2).and_then(|s| s.bg);
+    assert_eq!(editor_bg(&harness), Some(Color::Rgb(10, 20, 30)));
+
+    // Explicit reload
+    write_theme([40, 50, 60]);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ReloadTheme);
+    harness.render().unwrap();
+    assert_eq!(editor_bg(&harness), Some(Color::Rgb(40, 50, 60)));
+
+    // Let the watcher record the file's current state, then edit it again
+    harness.advance_time(std::time::Duration::from_secs(10));
+    harness.tick_and_render().unwrap();
+    write_theme([70, 80, 90]);
+    let bumped = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+    fs::File::options()
+        .write(true)
+        .open(&theme_path)
+        .unwrap()
+        .set_modified(bumped)
+        .unwrap();
+    harness
+        .wait_until(|h| editor_bg(h) == Some(Color::Rgb(70, 80, 90)))
+        .unwrap();
+}
@@ -0,0 +1,44 @@
+//! E2E tests for toast notifications.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::view::toast::ToastSeverity;
+use std::time::Duration;
+
+/// Two toasts stack in the corner; each is dismissed once its own timeout
+/// has passed, and dismissed toasts show up in the notification log.
+#[test]
+fn test_toasts_stack_and_auto_dismiss() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+
+    harness
+        .editor_mut()
+        .show_toast("First toast".to_string(), ToastSeverity::Info);
+    harness.advance_time(Duration::from_secs(2));
+    harness
+        .editor_mut()
+        .show_toast("Second toast".to_string(), ToastSeverity::Info);
+    harness.render().unwrap();
+    harness.assert_screen_contains("First toast");
+    harness.assert_screen_contains("Second toast");
+
+    // Past the first toast's timeout but not the second's
+    harness.advance_time(ToastSeverity::Info.timeout() - Duration::from_secs(1));
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_not_contains("First toast");
+    harness.assert_screen_contains("Second toast");
+
+    harness.advance_time(Duration::from_secs(1));
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_not_contains("Second toast");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::ShowNotificationLog);
+    harness.render().unwrap();
+    let log = harness.get_buffer_content().unwrap();
+    let messages: Vec<&str> = log
+        .lines()
+        .map(|line| line.rsplit_once("  ").map_or(line, |(_, message)| message))
+        .collect();
+    assert_eq!(messages, vec!["Second toast", "First toast"]);
+}
@@ -1,4 +1,6 @@
 use crate::common::harness::EditorTestHarness;
+use fresh::config::Config;
+use std::time::Duration;
 
 /// Test triple-click selects the entire line
 /// Issue #597: Support click 3 times to select the whole line
@@ -97,3 +99,41 @@ fn test_double_click_still_selects_word() {
         selected
     );
 }
+
+/// Two clicks count as a double-click only within `double_click_time_ms`
+#[test]
+fn test_double_click_respects_double_click_time() {
+    let mut config = Config::default();
+    config.editor.double_click_time_ms = 200;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    let content = "hello world test\n";
+    let _fixture = harness.load_buffer_from_text(content).unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let row = content_first_row as u16;
+    let gutter_width = harness.editor().active_state().margins.left_total_width() as u16;
+    let col = gutter_width + 8; // Over "world"
+
+    // Too slow: the second click just places the cursor
+    harness.mouse_click(col, row).unwrap();
+    harness.advance_time(Duration::from_millis(300));
+    harness.mouse_click(col, row).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_selected_text(), "");
+
+    // Within the threshold: the word under the pointer is selected
+    harness.advance_time(Duration::from_millis(300));
+    harness.mouse_click(col, row).unwrap();
+    harness.advance_time(Duration::from_millis(100));
+    harness.mouse_click(col, row).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_selected_text(), "world");
+
+    // A third quick click selects the whole line
+    harness.advance_time(Duration::from_millis(100));
+    harness.mouse_click(col, row).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_selected_text(), "hello world test\n");
+}
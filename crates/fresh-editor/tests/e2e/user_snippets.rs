@@ -0,0 +1,56 @@
+//! E2E tests for user snippets from the `snippets` config.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+/// Tab after a trigger word expands the language's snippet and selects its
+/// first tabstop; Tab again moves to `$0`. Other words still get a tab.
+#[test]
+fn test_user_snippet_expands_on_tab() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("main.rs");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut config = Config::default();
+    config.snippets.insert(
+        "rust".to_string(),
+        HashMap::from([(
+            "main".to_string(),
+            "fn main() {\n    ${1:todo!()}\n}$0".to_string(),
+        )]),
+    );
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("main").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn main() {\n    todo!()\n}"
+    );
+    let cursor = *harness.editor().active_cursors().primary();
+    assert_eq!((cursor.anchor, cursor.position), (Some(16), 23));
+
+    harness.type_text("run()").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn main() {\n    run()\n}"
+    );
+    let cursor = *harness.editor().active_cursors().primary();
+    assert_eq!((cursor.anchor, cursor.position), (None, 23));
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("mai").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(
+        content.starts_with("fn main() {\n    run()\n}\nmai") && !content.ends_with("mai"),
+        "a word that is no trigger gets a tab: {content:?}"
+    );
+}
@@ -0,0 +1,77 @@
+//! Tests for whitespace indicator rendering (tabs →, spaces ·, end-of-line ¶).
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+fn config_with_eol_markers() -> Config {
+    let mut config = Config::default();
+    config.editor.whitespace_spaces_trailing = true;
+    config.editor.whitespace_eol = true;
+    config
+}
+
+/// Tabs, trailing spaces and line ends each get their marker glyph in the
+/// cell the underlying character occupies.
+#[test]
+fn test_whitespace_markers_render_at_expected_cells() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("ws.txt");
+    std::fs::write(&file_path, "\tfoo  \nbar\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config_with_eol_markers()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    println!("Screen:\n{}", screen);
+
+    let (foo_col, foo_row) = harness.find_text_on_screen("foo").expect("foo on screen");
+    let line_start = foo_col - 4; // tab expands to 4 columns
+
+    assert_eq!(harness.get_cell(line_start, foo_row).as_deref(), Some("→"));
+    assert_eq!(harness.get_cell(foo_col + 3, foo_row).as_deref(), Some("·"));
+    assert_eq!(harness.get_cell(foo_col + 4, foo_row).as_deref(), Some("·"));
+    assert_eq!(harness.get_cell(foo_col + 5, foo_row).as_deref(), Some("¶"));
+
+    let (bar_col, bar_row) = harness.find_text_on_screen("bar").expect("bar on screen");
+    assert_eq!(bar_col, line_start);
+    assert_eq!(bar_row, foo_row + 1);
+    assert_eq!(harness.get_cell(bar_col + 3, bar_row).as_deref(), Some("¶"));
+}
+
+/// The ¶ marker sits in the newline's cell, so End puts the cursor on it
+/// rather than one column further right.
+#[test]
+fn test_eol_marker_keeps_cursor_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("ws.txt");
+    std::fs::write(&file_path, "\tfoo  \nbar\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config_with_eol_markers()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let (bar_col, bar_row) = harness.find_text_on_screen("bar").expect("bar on screen");
+    assert_eq!(harness.get_cell(bar_col + 3, bar_row).as_deref(), Some("¶"));
+    let (cursor_x, cursor_y) = harness.screen_cursor_position();
+    assert_eq!((cursor_x, cursor_y), (bar_col + 3, bar_row));
+}
+
+/// With the option off (the default), line ends render nothing.
+#[test]
+fn test_eol_marker_hidden_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("ws.txt");
+    std::fs::write(&file_path, "foo\nbar\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("foo");
+    harness.assert_screen_not_contains("¶");
+}
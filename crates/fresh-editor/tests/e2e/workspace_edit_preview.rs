@@ -0,0 +1,120 @@
+//! E2E tests for previewing LSP workspace edits before applying them
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use lsp_types::{Position, Range, TextEdit, WorkspaceEdit};
+use std::collections::HashMap;
+use std::path::Path;
+
+const SOURCE: &str = "fn calculate(value: i32) -> i32 {\n    value * 2\n}\n";
+
+fn preview_harness() -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.preview_workspace_edits = true;
+    EditorTestHarness::with_config(120, 30, config).unwrap()
+}
+
+/// Rename `value` to `amount` on both lines of `SOURCE`
+fn rename_edit(path: &Path) -> WorkspaceEdit {
+    let edit = |line, start, end| TextEdit {
+        range: Range {
+            start: Position {
+                line,
+                character: start,
+            },
+            end: Position {
+                line,
+                character: end,
+            },
+        },
+        new_text: "amount".to_string(),
+    };
+    let uri = fresh_core::file_uri::path_to_lsp_uri(path).unwrap();
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri, vec![edit(0, 13, 18), edit(1, 4, 9)])])),
+        document_changes: None,
+        change_annotations: None,
+    }
+}
+
+/// The rename opens a before/after diff and only changes the buffer once
+/// the prompt is confirmed
+#[test]
+fn test_rename_preview_applies_on_confirm() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("calc.rs");
+    std::fs::write(&path, SOURCE)?;
+
+    let mut harness = preview_harness();
+    harness.open_file(&path)?;
+    harness
+        .editor_mut()
+        .handle_rename_response(0, Ok(rename_edit(&path)))?;
+    harness.render()?;
+
+    harness.assert_screen_contains("Before");
+    harness.assert_screen_contains("After");
+    harness.assert_screen_contains("fn calculate(amount: i32)");
+    harness.assert_screen_contains("Apply changes to 1 file(s)?");
+
+    harness.type_text("a")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.assert_buffer_content("fn calculate(amount: i32) -> i32 {\n    amount * 2\n}\n");
+    harness.assert_screen_not_contains("Before");
+    Ok(())
+}
+
+/// Cancelling the prompt closes the diff and leaves the buffer untouched
+#[test]
+fn test_rename_preview_discarded_on_cancel() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path().join("calc.rs");
+    std::fs::write(&path, SOURCE)?;
+
+    let mut harness = preview_harness();
+    harness.open_file(&path)?;
+    harness
+        .editor_mut()
+        .handle_rename_response(0, Ok(rename_edit(&path)))?;
+    harness.render()?;
+    harness.assert_screen_contains("After");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.assert_buffer_content(SOURCE);
+    harness.assert_screen_not_contains("fn calculate(amount");
+    Ok(())
+}
+
+/// Cancelling closes a file that only the preview opened
+#[test]
+fn test_rename_preview_cancel_closes_files_it_opened() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let open_path = temp_dir.path().join("main.rs");
+    let other_path = temp_dir.path().join("calc.rs");
+    std::fs::write(&open_path, "fn main() {}\n")?;
+    std::fs::write(&other_path, SOURCE)?;
+
+    let mut harness = preview_harness();
+    harness.open_file(&open_path)?;
+    harness
+        .editor_mut()
+        .handle_rename_response(0, Ok(rename_edit(&other_path)))?;
+    harness.render()?;
+    harness.assert_screen_contains("After");
+    assert_eq!(harness.editor().buffer_ids_under_path(&other_path).len(), 1);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    assert!(harness
+        .editor()
+        .buffer_ids_under_path(&other_path)
+        .is_empty());
+    assert_eq!(harness.editor().buffer_ids_under_path(&open_path).len(), 1);
+    Ok(())
+}
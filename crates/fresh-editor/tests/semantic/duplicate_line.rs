@@ -7,6 +7,10 @@
 //! so the theorem version is dramatically shorter.
 //!
 //! Issue #591: Duplicate line or selected lines.
+//!
+//! `Action::DuplicateSelection` (Ctrl+Shift+D) is the column-preserving
+//! variant: it copies a selection in place, or the line without moving
+//! the cursor's column.
 
 use crate::common::theorem::buffer_theorem::{assert_buffer_theorem, BufferTheorem, CursorExpect};
 use crate::common::theorem::trace_theorem::{assert_trace_theorem, TraceTheorem};
@@ -106,3 +110,59 @@ fn theorem_duplicate_line_undo_restores_original() {
         undo_count: 1,
     });
 }
+
+#[test]
+fn theorem_duplicate_selection_without_selection_keeps_column() {
+    // Cursor at column 3 of "first". The copy lands on the next line and
+    // the cursor keeps its column there.
+    assert_buffer_theorem(BufferTheorem {
+        description: "DuplicateSelection with no selection duplicates the line, keeping the column",
+        initial_text: "first\nsecond",
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::DuplicateSelection,
+        ],
+        expected_text: "first\nfirst\nsecond",
+        expected_primary: CursorExpect::at(9),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+    });
+}
+
+#[test]
+fn theorem_duplicate_selection_copies_multi_line_range_after_itself() {
+    // Select from the middle of line 1 to the middle of line 2. The exact
+    // selected range is inserted right after it, and the copy is selected.
+    assert_buffer_theorem(BufferTheorem {
+        description: "DuplicateSelection inserts the selected range immediately after it",
+        initial_text: "abc\ndef\nghi",
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveRight,
+            Action::SelectDown,
+            Action::DuplicateSelection,
+        ],
+        expected_text: "abc\ndbc\ndef\nghi",
+        expected_primary: CursorExpect::range(5, 9),
+        expected_extra_cursors: vec![],
+        expected_selection_text: Some("bc\nd"),
+    });
+}
+
+#[test]
+fn theorem_duplicate_selection_undo_restores_original() {
+    assert_trace_theorem(TraceTheorem {
+        description: "DuplicateSelection is one undo unit — Undo restores the input",
+        initial_text: "abc\ndef",
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::SelectDown,
+            Action::DuplicateSelection,
+        ],
+        expected_text: "abc\nabc\ndef",
+        undo_count: 1,
+    });
+}
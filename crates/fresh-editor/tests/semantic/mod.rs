@@ -7,6 +7,7 @@ pub mod duplicate_line;
 pub mod emacs_actions;
 pub mod indent_dedent;
 pub mod layout;
+pub mod move_lines;
 pub mod multicursor;
 pub mod properties;
 pub mod regressions;
@@ -0,0 +1,88 @@
+//! Theorems for `Action::MoveLineUp` / `Action::MoveLineDown`
+//! (Alt+Up / Alt+Down): whole lines swap with their neighbour, the
+//! cursor column and selection travel with the moved block, and the
+//! move is a single undo unit.
+
+use crate::common::theorem::buffer_theorem::{assert_buffer_theorem, BufferTheorem, CursorExpect};
+use crate::common::theorem::trace_theorem::{assert_trace_theorem, TraceTheorem};
+use fresh::test_api::Action;
+
+#[test]
+fn theorem_move_middle_line_down_keeps_column() {
+    // Cursor at column 1 of "two". After the swap "two" starts at byte 10,
+    // so the cursor lands at 11.
+    assert_buffer_theorem(BufferTheorem {
+        description: "MoveLineDown swaps the middle line with the one below",
+        initial_text: "one\ntwo\nthree\n",
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveDown,
+            Action::MoveRight,
+            Action::MoveLineDown,
+        ],
+        expected_text: "one\nthree\ntwo\n",
+        expected_primary: CursorExpect::at(11),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+    });
+}
+
+#[test]
+fn theorem_move_two_line_selection_up_keeps_selection() {
+    // Select from column 1 of "b2" to column 1 of "c3"; both lines move
+    // above "a1" and the selection follows them.
+    assert_buffer_theorem(BufferTheorem {
+        description: "MoveLineUp moves every line touched by the selection",
+        initial_text: "a1\nb2\nc3\nd4\n",
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveDown,
+            Action::MoveRight,
+            Action::SelectDown,
+            Action::MoveLineUp,
+        ],
+        expected_text: "b2\nc3\na1\nd4\n",
+        expected_primary: CursorExpect::range(1, 4),
+        expected_extra_cursors: vec![],
+        expected_selection_text: Some("2\nc"),
+    });
+}
+
+#[test]
+fn theorem_move_last_line_without_newline_up() {
+    // The final line has no trailing newline; after moving it up the
+    // buffer still ends without one.
+    assert_buffer_theorem(BufferTheorem {
+        description: "MoveLineUp on an unterminated last line keeps the file unterminated",
+        initial_text: "a\nb",
+        actions: vec![Action::MoveDocumentEnd, Action::MoveLineUp],
+        expected_text: "b\na",
+        expected_primary: CursorExpect::at(1),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+    });
+}
+
+#[test]
+fn theorem_move_line_up_at_top_is_noop() {
+    assert_buffer_theorem(BufferTheorem {
+        description: "MoveLineUp on the first line leaves the buffer unchanged",
+        initial_text: "a\nb\n",
+        actions: vec![Action::MoveDocumentStart, Action::MoveLineUp],
+        expected_text: "a\nb\n",
+        expected_primary: CursorExpect::at(0),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+    });
+}
+
+#[test]
+fn theorem_move_line_down_undo_restores_original() {
+    assert_trace_theorem(TraceTheorem {
+        description: "MoveLineDown is one undo unit — Undo restores the input",
+        initial_text: "one\ntwo\nthree\n",
+        actions: vec![Action::MoveDocumentStart, Action::MoveLineDown],
+        expected_text: "two\none\nthree\n",
+        undo_count: 1,
+    });
+}
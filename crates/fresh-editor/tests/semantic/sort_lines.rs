@@ -62,3 +62,80 @@ fn theorem_sort_lines_undo_restores_original_order() {
         undo_count: 1,
     });
 }
+
+#[test]
+fn theorem_sort_lines_three_out_of_order_with_trailing_newline() {
+    assert_trace_theorem(TraceTheorem {
+        description: "SortLines keeps the trailing newline and is one undo unit",
+        initial_text: "pear\nfig\nkiwi\n",
+        actions: vec![Action::SelectAll, Action::SortLines],
+        expected_text: "fig\nkiwi\npear\n",
+        undo_count: 1,
+    });
+}
+
+#[test]
+fn theorem_sort_lines_expands_partial_selection_to_full_lines() {
+    // Selection runs from the middle of "cherry" to the middle of
+    // "banana"; both lines are sorted whole, the line below is untouched.
+    assert_buffer_theorem(BufferTheorem {
+        description: "SortLines widens a mid-line selection to whole lines",
+        initial_text: "cherry\nbanana\nzebra",
+        actions: vec![Action::MoveRight, Action::MoveRight, Action::SelectDown],
+        expected_text: "cherry\nbanana\nzebra",
+        expected_primary: CursorExpect::range(2, 9),
+        expected_extra_cursors: vec![],
+        expected_selection_text: Some("erry\nba"),
+    });
+    assert_trace_theorem(TraceTheorem {
+        description: "SortLines sorts both partially selected lines",
+        initial_text: "cherry\nbanana\nzebra",
+        actions: vec![
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::SelectDown,
+            Action::SortLines,
+        ],
+        expected_text: "banana\ncherry\nzebra",
+        undo_count: 1,
+    });
+}
+
+#[test]
+fn theorem_sort_lines_descending_and_case_insensitive() {
+    assert_trace_theorem(TraceTheorem {
+        description: "SortLinesDescending reverses alphabetical order",
+        initial_text: "b\nc\na",
+        actions: vec![Action::SelectAll, Action::SortLinesDescending],
+        expected_text: "c\nb\na",
+        undo_count: 1,
+    });
+    assert_trace_theorem(TraceTheorem {
+        description: "SortLinesCaseInsensitive ignores case",
+        initial_text: "banana\nApple\ncherry",
+        actions: vec![Action::SelectAll, Action::SortLinesCaseInsensitive],
+        expected_text: "Apple\nbanana\ncherry",
+        undo_count: 1,
+    });
+    assert_trace_theorem(TraceTheorem {
+        description: "SortLinesCaseInsensitiveDescending ignores case in reverse",
+        initial_text: "Banana\napple\nCherry",
+        actions: vec![
+            Action::SelectAll,
+            Action::SortLinesCaseInsensitiveDescending,
+        ],
+        expected_text: "Cherry\nBanana\napple",
+        undo_count: 1,
+    });
+}
+
+#[test]
+fn theorem_dedupe_lines_keeps_first_occurrence() {
+    assert_trace_theorem(TraceTheorem {
+        description: "DedupeLines drops repeats, keeps order and trailing newline",
+        initial_text: "b\na\nb\nc\na\n",
+        actions: vec![Action::SelectAll, Action::DedupeLines],
+        expected_text: "b\na\nc\n",
+        undo_count: 1,
+    });
+}
@@ -15,7 +15,7 @@
 //! `theorem_toggle_comment_single_line_no_newline` below.
 
 use crate::common::theorem::buffer_theorem::{
-    assert_buffer_theorem_with_file, BufferTheorem, CursorExpect,
+    assert_buffer_theorem_with_file, repeat, BufferTheorem, CursorExpect,
 };
 use fresh::test_api::Action;
 
@@ -229,3 +229,135 @@ fn theorem_toggle_comment_selection_at_buffer_end() {
         "x.rs",
     );
 }
+
+// ─────────────────────────────────────────────────────────────────────────
+// Indentation, mixed lines, and repeated toggles
+// ─────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn theorem_toggle_comment_rust_adds_then_removes_prefix() {
+    // One toggle adds '// ' at the shallowest indentation of every line;
+    // a second toggle removes it again.
+    let text = "fn main() {\n    let x = 1;\n}\n";
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "First ToggleComment adds '// ' to every selected line",
+            initial_text: text,
+            actions: vec![Action::SelectAll, Action::ToggleComment],
+            expected_text: "// fn main() {\n//     let x = 1;\n// }\n",
+            expected_primary: CursorExpect::range(0, 38),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some("// fn main() {\n//     let x = 1;\n// }\n"),
+        },
+        "x.rs",
+    );
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "Second ToggleComment removes the '// ' again",
+            initial_text: text,
+            actions: vec![
+                Action::SelectAll,
+                Action::ToggleComment,
+                Action::ToggleComment,
+            ],
+            expected_text: text,
+            expected_primary: CursorExpect::range(0, 29),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some(text),
+        },
+        "x.rs",
+    );
+}
+
+#[test]
+fn theorem_toggle_comment_respects_indentation() {
+    // The prefix goes after the common indentation, not at column 0.
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "ToggleComment inserts '// ' after the shared indentation",
+            initial_text: "fn f() {\n    a();\n    b();\n}\n",
+            actions: vec![
+                Action::MoveDown,
+                Action::SelectDown,
+                Action::SelectDown,
+                Action::ToggleComment,
+            ],
+            expected_text: "fn f() {\n    // a();\n    // b();\n}\n",
+            expected_primary: CursorExpect::range(9, 33),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some("    // a();\n    // b();\n"),
+        },
+        "x.rs",
+    );
+}
+
+#[test]
+fn theorem_toggle_comment_mixed_lines_comments_all() {
+    // Only the first line is commented, so the toggle comments both.
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "ToggleComment over partly-commented lines comments every line",
+            initial_text: "// a\nb\n",
+            actions: vec![Action::SelectAll, Action::ToggleComment],
+            expected_text: "// // a\n// b\n",
+            expected_primary: CursorExpect::range(0, 14),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some("// // a\n// b\n"),
+        },
+        "x.rs",
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Block comments
+// ─────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn theorem_toggle_block_comment_wraps_and_unwraps_selection() {
+    let mut actions: Vec<Action> = repeat(Action::MoveRight, 8).collect();
+    actions.extend(repeat(Action::SelectRight, 5));
+    actions.push(Action::ToggleBlockComment);
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "ToggleBlockComment wraps the selection in '/* ... */'",
+            initial_text: "let x = 1 + 2;",
+            actions: actions.clone(),
+            expected_text: "let x = /* 1 + 2 */;",
+            expected_primary: CursorExpect::range(8, 19),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some("/* 1 + 2 */"),
+        },
+        "x.rs",
+    );
+
+    actions.push(Action::ToggleBlockComment);
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "A second ToggleBlockComment unwraps it",
+            initial_text: "let x = 1 + 2;",
+            actions,
+            expected_text: "let x = 1 + 2;",
+            expected_primary: CursorExpect::range(8, 13),
+            expected_extra_cursors: vec![],
+            expected_selection_text: Some("1 + 2"),
+        },
+        "x.rs",
+    );
+}
+
+#[test]
+fn theorem_toggle_comment_css_falls_back_to_block_comment() {
+    // CSS has no line comment, so ToggleComment wraps the line instead.
+    assert_buffer_theorem_with_file(
+        BufferTheorem {
+            description: "ToggleComment in CSS wraps the line in '/* ... */'",
+            initial_text: "a { color: red; }\n",
+            actions: vec![Action::ToggleComment],
+            expected_text: "/* a { color: red; } */\n",
+            expected_primary: CursorExpect::at(3),
+            expected_extra_cursors: vec![],
+            expected_selection_text: None,
+        },
+        "x.css",
+    );
+}
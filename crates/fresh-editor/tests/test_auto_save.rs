@@ -1,7 +1,7 @@
 mod common;
 
 use common::harness::EditorTestHarness;
-use fresh::config::Config;
+use fresh::config::{AutoSaveMode, Config};
 use std::fs;
 use std::time::Duration;
 
@@ -129,3 +129,53 @@ fn test_auto_recovery_save_throttled_before_interval() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_auto_save_on_focus_change_saves_buffer_left_behind() -> anyhow::Result<()> {
+    let mut config = Config::default();
+    config.editor.auto_save = AutoSaveMode::OnFocusChange;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let first = temp_dir.join("first.txt");
+    let second = temp_dir.join("second.txt");
+    fs::write(&first, "first")?;
+    fs::write(&second, "second")?;
+
+    harness.open_file(&first)?;
+    harness.type_text("X")?;
+    assert_eq!(fs::read_to_string(&first)?, "first");
+
+    // Switching to another buffer saves the one focus left
+    harness.open_file(&second)?;
+    assert!(fs::read_to_string(&first)?.contains('X'));
+    assert_eq!(fs::read_to_string(&second)?, "second");
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_save_on_focus_change_skips_internal_switch() -> anyhow::Result<()> {
+    let mut config = Config::default();
+    config.editor.auto_save = AutoSaveMode::OnFocusChange;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let file = temp_dir.join("file.txt");
+    fs::write(&file, "first")?;
+
+    harness.open_file(&file)?;
+    harness.type_text("X")?;
+    let file_buffer = harness.editor().active_buffer();
+
+    // Opening the diff against disk focuses the diff view, but the user
+    // did not leave the buffer: saving it would empty the diff
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::DiffAgainstDisk);
+    harness.render()?;
+    assert_ne!(harness.editor().active_buffer(), file_buffer);
+    assert_eq!(fs::read_to_string(&file)?, "first");
+
+    Ok(())
+}
@@ -666,12 +666,61 @@ pub struct PluginTrackedState {
 /// Shared between QuickJsBackend (plugin thread) and PluginThreadHandle (main thread).
 pub type AsyncResourceOwners = Arc<std::sync::Mutex<HashMap<u64, String>>>;
 
+/// Event names plugins can subscribe to with `editor.on()` in addition to
+/// the raw hook names, as `(hook, event)` pairs. The event fires right after
+/// its hook's own handlers, with the hook's payload.
+const EVENT_ALIASES: &[(&str, &str)] = &[
+    ("after_file_open", "buffer_opened"),
+    ("after_file_save", "buffer_saved"),
+    ("after_insert", "text_changed"),
+    ("after_delete", "text_changed"),
+];
+
+/// The names a hook is delivered under: the hook itself, then its aliases.
+fn event_names_for_hook(hook_name: &str) -> impl Iterator<Item = &str> {
+    std::iter::once(hook_name).chain(
+        EVENT_ALIASES
+            .iter()
+            .filter(move |(hook, _)| *hook == hook_name)
+            .map(|(_, event)| *event),
+    )
+}
+
 #[derive(Debug, Clone)]
 pub struct PluginHandler {
     pub plugin_name: String,
     pub handler_name: String,
 }
 
+/// A status bar segment registered by a plugin. `last_text` is what was
+/// last sent to the editor, so refreshes only send a command on change.
+#[derive(Debug, Clone)]
+pub struct StatusBarSegmentHandler {
+    pub plugin_name: String,
+    pub handler_name: String,
+    pub align: String,
+    pub priority: i32,
+    pub last_text: String,
+}
+
+/// Call a status bar segment's render handler. Errors and non-string
+/// results render as an empty (hidden) segment.
+fn render_status_bar_segment(ctx: &rquickjs::Ctx<'_>, handler_name: &str) -> String {
+    let Ok(func) = ctx.globals().get::<_, rquickjs::Function>(handler_name) else {
+        return String::new();
+    };
+    match func.call::<_, rquickjs::Value>(()) {
+        Ok(value) => value
+            .as_string()
+            .and_then(|s| s.to_string().ok())
+            .unwrap_or_default(),
+        Err(e) => {
+            log_js_error(ctx, e, &format!("status bar segment {}", handler_name));
+            String::new()
+        }
+    }
+}
+
 /// Parse an `AnimationRect` from a JS object. Missing fields are treated
 /// as 0, which renders as a zero-area rect the runner drops immediately.
 fn parse_animation_rect(
@@ -744,6 +793,9 @@ pub struct JsEditorApi {
     /// Tracks command name → owning plugin name (first-writer-wins collision detection)
     #[qjs(skip_trace)]
     registered_command_names: Rc<RefCell<HashMap<String, String>>>,
+    /// Tracks handler name → commands of every plugin that call it
+    #[qjs(skip_trace)]
+    command_handlers: Rc<RefCell<HashMap<String, Vec<Command>>>>,
     /// Tracks grammar language → owning plugin name (first-writer-wins collision detection)
     #[qjs(skip_trace)]
     registered_grammar_languages: Rc<RefCell<HashMap<String, String>>>,
@@ -753,6 +805,10 @@ pub struct JsEditorApi {
     /// Tracks LSP server language → owning plugin name (first-writer-wins collision detection)
     #[qjs(skip_trace)]
     registered_lsp_servers: Rc<RefCell<HashMap<String, String>>>,
+    /// Status bar segment id → render handler, shared so the backend can
+    /// refresh every segment after each event
+    #[qjs(skip_trace)]
+    status_bar_segments: Rc<RefCell<HashMap<String, StatusBarSegmentHandler>>>,
     /// Plugin-configuration plane (design M3): name → (exporter plugin_name,
     /// persistent JS Object). Shared across every plugin context on the
     /// same Runtime so init.ts can reach another plugin's typed API.
@@ -1003,28 +1059,54 @@ impl JsEditorApi {
             .borrow_mut()
             .insert(tracking_key, plugin_name.clone());
 
-        // Store action handler mapping with its plugin name
-        self.registered_actions.borrow_mut().insert(
-            handler_name.clone(),
-            PluginHandler {
-                plugin_name: self.plugin_name.clone(),
-                handler_name: handler_name.clone(),
-            },
-        );
-
-        // Register with editor
-        let command = Command {
+        let mut command = Command {
             name: name.clone(),
             description,
-            action_name: handler_name,
-            plugin_name,
+            action_name: handler_name.clone(),
+            plugin_name: plugin_name.clone(),
             custom_contexts: context_str.into_iter().collect(),
         };
 
-        Ok(self
-            .command_sender
-            .send(PluginCommand::RegisterCommand { command })
-            .is_ok())
+        // Handlers are globals of each plugin's own context, so two plugins
+        // may both use e.g. "run". Once commands of more than one plugin
+        // call the same handler name, all of them dispatch by
+        // `plugin:handler`, whichever plugin loaded first.
+        let mut handlers = self.command_handlers.borrow_mut();
+        let same_handler = handlers.entry(handler_name.clone()).or_default();
+        same_handler.retain(|c| !(c.plugin_name == plugin_name && c.name == name));
+        let mut rekeyed = Vec::new();
+        if same_handler.iter().any(|c| c.plugin_name != plugin_name) {
+            command.action_name = format!("{}:{}", plugin_name, handler_name);
+            for other in same_handler
+                .iter_mut()
+                .filter(|c| c.action_name == handler_name)
+            {
+                other.action_name = format!("{}:{}", other.plugin_name, handler_name);
+                rekeyed.push(other.clone());
+            }
+        }
+        same_handler.push(command.clone());
+        drop(handlers);
+
+        // Store action handler mappings with their plugin names, and update
+        // the commands that moved to a namespaced action
+        for command in rekeyed.into_iter().chain(std::iter::once(command)) {
+            self.registered_actions.borrow_mut().insert(
+                command.action_name.clone(),
+                PluginHandler {
+                    plugin_name: command.plugin_name.clone(),
+                    handler_name: handler_name.clone(),
+                },
+            );
+            if self
+                .command_sender
+                .send(PluginCommand::RegisterCommand { command })
+                .is_err()
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 
     /// Unregister a command by name
@@ -1039,6 +1121,9 @@ impl JsEditorApi {
         self.registered_command_names
             .borrow_mut()
             .remove(&tracking_key);
+        for commands in self.command_handlers.borrow_mut().values_mut() {
+            commands.retain(|c| !(c.plugin_name == self.plugin_name && c.name == name));
+        }
         self.command_sender
             .send(PluginCommand::UnregisterCommand { name })
             .is_ok()
@@ -1521,7 +1606,12 @@ impl JsEditorApi {
 
     // === Event Handling ===
 
-    /// Subscribe to an editor event
+    /// Subscribe to an editor event. `eventName` is any hook name (e.g.
+    /// `"cursor_moved"`, `"diagnostics_updated"`, `"after_file_save"`) or one
+    /// of the event aliases `"buffer_opened"`, `"buffer_saved"` and
+    /// `"text_changed"` (fired after inserts and deletes). Events are
+    /// delivered in order, after the mutation that triggered them has been
+    /// applied.
     pub fn on<'js>(&self, _ctx: rquickjs::Ctx<'js>, event_name: String, handler_name: String) {
         // If registering for lines_changed, clear all seen_byte_ranges so lines
         // that were already marked "seen" (before this plugin initialized) get
@@ -3404,6 +3494,55 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Set a styled sign in the gutter. Signs are anchored to the line's
+    /// text, so they follow edits above them; when several namespaces put a
+    /// sign on the same line, the highest `priority` is shown.
+    ///
+    /// The `options` object accepts:
+    ///   * `fg` — an `[r, g, b]` array or a theme-key string (e.g.
+    ///     `"diagnostic.info_fg"`), resolved at render time. Defaults to the
+    ///     line number color.
+    ///   * `bold` — render the symbol bold (default `false`).
+    ///   * `priority` — default `0`.
+    pub fn set_line_sign<'js>(
+        &self,
+        _ctx: rquickjs::Ctx<'js>,
+        buffer_id: u32,
+        line: u32,
+        namespace: String,
+        symbol: String,
+        options: rquickjs::Object<'js>,
+    ) -> bool {
+        use fresh_core::api::OverlayColorSpec;
+
+        let fg = if let Ok(theme_key) = options.get::<_, String>("fg") {
+            Some(OverlayColorSpec::ThemeKey(theme_key))
+        } else if let Ok(rgb) = options.get::<_, Vec<u8>>("fg") {
+            (rgb.len() >= 3).then(|| OverlayColorSpec::Rgb(rgb[0], rgb[1], rgb[2]))
+        } else {
+            None
+        };
+
+        self.plugin_tracked_state
+            .borrow_mut()
+            .entry(self.plugin_name.clone())
+            .or_default()
+            .line_indicator_namespaces
+            .push((BufferId(buffer_id as usize), namespace.clone()));
+
+        self.command_sender
+            .send(PluginCommand::SetLineSign {
+                buffer_id: BufferId(buffer_id as usize),
+                line: line as usize,
+                namespace,
+                symbol,
+                fg,
+                bold: options.get("bold").unwrap_or(false),
+                priority: options.get("priority").unwrap_or(0),
+            })
+            .is_ok()
+    }
+
     /// Batch set line indicators in the gutter
     #[allow(clippy::too_many_arguments)]
     pub fn set_line_indicators(
@@ -3716,7 +3855,18 @@ impl JsEditorApi {
         &self,
         ctx: rquickjs::Ctx<'js>,
     ) -> rquickjs::Result<Value<'js>> {
-        use fresh_core::api::{JsDiagnostic, JsPosition, JsRange};
+        use fresh_core::api::{JsDiagnostic, JsDiagnosticRelatedInformation, JsPosition, JsRange};
+
+        let js_range = |range: &lsp_types::Range| JsRange {
+            start: JsPosition {
+                line: range.start.line,
+                character: range.start.character,
+            },
+            end: JsPosition {
+                line: range.end.line,
+                character: range.end.character,
+            },
+        };
 
         let diagnostics = if let Ok(s) = self.state_snapshot.read() {
             // Convert to JsDiagnostic format for JS
@@ -3733,17 +3883,26 @@ impl JsEditorApi {
                             lsp_types::DiagnosticSeverity::HINT => 4,
                             _ => 0,
                         }),
-                        range: JsRange {
-                            start: JsPosition {
-                                line: diag.range.start.line,
-                                character: diag.range.start.character,
-                            },
-                            end: JsPosition {
-                                line: diag.range.end.line,
-                                character: diag.range.end.character,
-                            },
-                        },
+                        range: js_range(&diag.range),
                         source: diag.source.clone(),
+                        code: diag.code.as_ref().map(|code| match code {
+                            lsp_types::NumberOrString::Number(n) => n.to_string(),
+                            lsp_types::NumberOrString::String(s) => s.clone(),
+                        }),
+                        code_href: diag
+                            .code_description
+                            .as_ref()
+                            .map(|d| d.href.as_str().to_string()),
+                        related_information: diag.related_information.as_ref().map(|related| {
+                            related
+                                .iter()
+                                .map(|info| JsDiagnosticRelatedInformation {
+                                    uri: info.location.uri.as_str().to_string(),
+                                    range: js_range(&info.location.range),
+                                    message: info.message.clone(),
+                                })
+                                .collect()
+                        }),
                     });
                 }
             }
@@ -4175,6 +4334,68 @@ impl JsEditorApi {
             .send(PluginCommand::ClearRemoteIndicatorState);
     }
 
+    // === Status Bar Segments ===
+
+    /// Register a status bar segment rendered by `handlerName`, a global
+    /// function that takes no arguments and returns the segment's text
+    /// (an empty string hides it). The handler runs right away and again
+    /// after every editor event; the bar only updates when the text changes.
+    ///
+    /// `align` is `"left"` or `"right"`. Within a side, higher `priority`
+    /// segments sit closer to the built-in elements and are dropped last on
+    /// narrow terminals. Registering an existing `id` replaces it.
+    ///
+    /// ```ts
+    /// registerHandler("git_branch_segment", () => `⎇ ${branch}`);
+    /// editor.registerStatusBarSegment("git-branch", "right", 10, "git_branch_segment");
+    /// ```
+    pub fn register_status_bar_segment<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        id: String,
+        align: String,
+        priority: i32,
+        handler_name: String,
+    ) -> bool {
+        if align != "left" && align != "right" {
+            tracing::warn!(
+                "registerStatusBarSegment: invalid align '{}' for segment '{}'",
+                align,
+                id
+            );
+            return false;
+        }
+        let text = render_status_bar_segment(&ctx, &handler_name);
+        self.status_bar_segments.borrow_mut().insert(
+            id.clone(),
+            StatusBarSegmentHandler {
+                plugin_name: self.plugin_name.clone(),
+                handler_name,
+                align: align.clone(),
+                priority,
+                last_text: text.clone(),
+            },
+        );
+        self.command_sender
+            .send(PluginCommand::SetStatusBarSegment {
+                id,
+                align,
+                priority,
+                text,
+            })
+            .is_ok()
+    }
+
+    /// Remove a status bar segment registered with `registerStatusBarSegment`.
+    pub fn remove_status_bar_segment(&self, id: String) -> bool {
+        if self.status_bar_segments.borrow_mut().remove(&id).is_none() {
+            return false;
+        }
+        self.command_sender
+            .send(PluginCommand::RemoveStatusBarSegment { id })
+            .is_ok()
+    }
+
     /// Wait for a process to complete and get its result (async)
     #[plugin_api(async_promise, js_name = "spawnProcessWait", ts_return = "SpawnResult")]
     #[qjs(rename = "_spawnProcessWaitStart")]
@@ -4704,12 +4925,16 @@ pub struct QuickJsBackend {
     async_resource_owners: AsyncResourceOwners,
     /// Tracks command name → owning plugin name (first-writer-wins collision detection)
     registered_command_names: Rc<RefCell<HashMap<String, String>>>,
+    /// Tracks handler name → commands of every plugin that call it
+    command_handlers: Rc<RefCell<HashMap<String, Vec<Command>>>>,
     /// Tracks grammar language → owning plugin name (first-writer-wins)
     registered_grammar_languages: Rc<RefCell<HashMap<String, String>>>,
     /// Tracks language config language → owning plugin name (first-writer-wins)
     registered_language_configs: Rc<RefCell<HashMap<String, String>>>,
     /// Tracks LSP server language → owning plugin name (first-writer-wins)
     registered_lsp_servers: Rc<RefCell<HashMap<String, String>>>,
+    /// Status bar segment id → render handler (see `registerStatusBarSegment`)
+    status_bar_segments: Rc<RefCell<HashMap<String, StatusBarSegmentHandler>>>,
     /// Plugin-configuration plane (design M3): name → (exporter, persistent
     /// JS Object). Shared across every JsEditorApi instance on this
     /// Runtime.
@@ -4816,9 +5041,11 @@ impl QuickJsBackend {
         let callback_contexts = Rc::new(RefCell::new(HashMap::new()));
         let plugin_tracked_state = Rc::new(RefCell::new(HashMap::new()));
         let registered_command_names = Rc::new(RefCell::new(HashMap::new()));
+        let command_handlers = Rc::new(RefCell::new(HashMap::new()));
         let registered_grammar_languages = Rc::new(RefCell::new(HashMap::new()));
         let registered_language_configs = Rc::new(RefCell::new(HashMap::new()));
         let registered_lsp_servers = Rc::new(RefCell::new(HashMap::new()));
+        let status_bar_segments = Rc::new(RefCell::new(HashMap::new()));
         let plugin_api_exports = Rc::new(RefCell::new(HashMap::new()));
 
         let backend = Self {
@@ -4836,9 +5063,11 @@ impl QuickJsBackend {
             plugin_tracked_state,
             async_resource_owners,
             registered_command_names,
+            command_handlers,
             registered_grammar_languages,
             registered_language_configs,
             registered_lsp_servers,
+            status_bar_segments,
             plugin_api_exports,
         };
 
@@ -4857,9 +5086,11 @@ impl QuickJsBackend {
         let registered_actions = Rc::clone(&self.registered_actions);
         let next_request_id = Rc::clone(&self.next_request_id);
         let registered_command_names = Rc::clone(&self.registered_command_names);
+        let command_handlers = Rc::clone(&self.command_handlers);
         let registered_grammar_languages = Rc::clone(&self.registered_grammar_languages);
         let registered_language_configs = Rc::clone(&self.registered_language_configs);
         let registered_lsp_servers = Rc::clone(&self.registered_lsp_servers);
+        let status_bar_segments = Rc::clone(&self.status_bar_segments);
         let plugin_api_exports = Rc::clone(&self.plugin_api_exports);
 
         context.with(|ctx| {
@@ -4881,9 +5112,11 @@ impl QuickJsBackend {
                 plugin_tracked_state: Rc::clone(&self.plugin_tracked_state),
                 async_resource_owners: Arc::clone(&self.async_resource_owners),
                 registered_command_names: Rc::clone(&registered_command_names),
+                command_handlers: Rc::clone(&command_handlers),
                 registered_grammar_languages: Rc::clone(&registered_grammar_languages),
                 registered_language_configs: Rc::clone(&registered_language_configs),
                 registered_lsp_servers: Rc::clone(&registered_lsp_servers),
+                status_bar_segments: Rc::clone(&status_bar_segments),
                 plugin_api_exports: Rc::clone(&plugin_api_exports),
                 plugin_name: plugin_name.to_string(),
             };
@@ -5488,6 +5721,21 @@ impl QuickJsBackend {
             owners.retain(|_, name| name != plugin_name);
         }
 
+        // Remove status bar segments owned by this plugin
+        let mut segments = self.status_bar_segments.borrow_mut();
+        let owned: Vec<String> = segments
+            .iter()
+            .filter(|(_, seg)| seg.plugin_name == plugin_name)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in owned {
+            segments.remove(&id);
+            let _ = self
+                .command_sender
+                .send(PluginCommand::RemoveStatusBarSegment { id });
+        }
+        drop(segments);
+
         // Drop any plugin-API exports (design M3) this plugin published.
         self.plugin_api_exports
             .borrow_mut()
@@ -5497,6 +5745,9 @@ impl QuickJsBackend {
         self.registered_command_names
             .borrow_mut()
             .retain(|_, pname| pname != plugin_name);
+        for commands in self.command_handlers.borrow_mut().values_mut() {
+            commands.retain(|c| c.plugin_name != plugin_name);
+        }
         self.registered_grammar_languages
             .borrow_mut()
             .retain(|_, pname| pname != plugin_name);
@@ -5520,8 +5771,11 @@ impl QuickJsBackend {
         self.services
             .set_js_execution_state(format!("hook '{}'", event_name));
 
-        let handlers = self.event_handlers.borrow().get(event_name).cloned();
-        if let Some(handler_pairs) = handlers {
+        for name in event_names_for_hook(event_name) {
+            let handlers = self.event_handlers.borrow().get(name).cloned();
+            let Some(handler_pairs) = handlers else {
+                continue;
+            };
             let plugin_contexts = self.plugin_contexts.borrow();
             for handler in &handler_pairs {
                 let Some(context) = plugin_contexts.get(&handler.plugin_name) else {
@@ -5533,17 +5787,50 @@ impl QuickJsBackend {
             }
         }
 
+        self.refresh_status_bar_segments();
+
         self.services.clear_js_execution_state();
         Ok(true)
     }
 
+    /// Re-run every status bar segment's render handler and send the ones
+    /// whose text changed.
+    fn refresh_status_bar_segments(&self) {
+        // Snapshot first: a render handler may itself (re)register segments.
+        let segments: Vec<(String, StatusBarSegmentHandler)> = self
+            .status_bar_segments
+            .borrow()
+            .iter()
+            .map(|(id, seg)| (id.clone(), seg.clone()))
+            .collect();
+        let plugin_contexts = self.plugin_contexts.borrow();
+        for (id, segment) in segments {
+            let Some(context) = plugin_contexts.get(&segment.plugin_name) else {
+                continue;
+            };
+            let text = context.with(|ctx| render_status_bar_segment(&ctx, &segment.handler_name));
+            if text == segment.last_text {
+                continue;
+            }
+            if let Some(entry) = self.status_bar_segments.borrow_mut().get_mut(&id) {
+                entry.last_text = text.clone();
+            }
+            let _ = self
+                .command_sender
+                .send(PluginCommand::SetStatusBarSegment {
+                    id,
+                    align: segment.align,
+                    priority: segment.priority,
+                    text,
+                });
+        }
+    }
+
     /// Check if any handlers are registered for an event
     pub fn has_handlers(&self, event_name: &str) -> bool {
-        self.event_handlers
-            .borrow()
-            .get(event_name)
-            .map(|v| !v.is_empty())
-            .unwrap_or(false)
+        let handlers = self.event_handlers.borrow();
+        event_names_for_hook(event_name)
+            .any(|name| handlers.get(name).is_some_and(|v| !v.is_empty()))
     }
 
     /// Start an action without waiting for async operations to complete.
@@ -6456,6 +6743,38 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_emit_event_alias() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.onBufferSaved = function(data) {
+                editor.setStatus("buffer_saved: " + data.path);
+            };
+            editor.on("buffer_saved", "onBufferSaved");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+        while rx.try_recv().is_ok() {}
+
+        assert!(backend.has_handlers("after_file_save"));
+        assert!(!backend.has_handlers("after_file_open"));
+
+        let event_data: serde_json::Value = serde_json::json!({"path": "/test.txt"});
+        backend.emit("after_file_save", &event_data).await.unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetStatus { message } => {
+                assert_eq!(message, "buffer_saved: /test.txt");
+            }
+            cmd => panic!("Expected SetStatus from aliased event, got {:?}", cmd),
+        }
+    }
+
     #[test]
     fn test_api_copy_to_clipboard() {
         let (mut backend, rx) = create_test_backend();
@@ -8721,6 +9040,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_register_command_same_handler_name_namespaced_by_plugin() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.run = function() { };
+            editor.registerCommand("A: Run", "From A", "run", null);
+        "#,
+                "plugin_a.js",
+            )
+            .unwrap();
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.run = function() { };
+            editor.registerCommand("B: Run", "From B", "run", null);
+        "#,
+                "plugin_b.js",
+            )
+            .unwrap();
+
+        let action_names: Vec<String> = std::iter::from_fn(|| rx.try_recv().ok())
+            .filter_map(|cmd| match cmd {
+                PluginCommand::RegisterCommand { command } => Some(command.action_name),
+                _ => None,
+            })
+            .collect();
+        // A's command is re-sent under its namespaced action once B clashes
+        assert_eq!(action_names, vec!["run", "plugin_a:run", "plugin_b:run"]);
+
+        let actions = backend.registered_actions.borrow();
+        assert_eq!(actions["plugin_a:run"].plugin_name, "plugin_a");
+        assert_eq!(actions["plugin_b:run"].plugin_name, "plugin_b");
+        assert_eq!(actions["plugin_b:run"].handler_name, "run");
+    }
+
     #[test]
     fn test_register_command_same_plugin_allowed() {
         let (mut backend, _rx) = create_test_backend();
@@ -480,6 +480,7 @@ interface HookEventMap {
   action_popup_result: { popup_id: string; action_id: string };
   process_output: { process_id: number; data: string };
   language_changed: { buffer_id: number; language: string };
+  breakpoints_changed: { buffer_id: number; path: string | null; lines: number[] };
   theme_inspect_key: { theme_name: string; key: string };
   keyboard_shortcuts: { bindings: { key: string; action: string }[] };
 }